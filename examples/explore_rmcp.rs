@@ -10,6 +10,6 @@ fn main() {
     println!("Default capabilities: {:?}", capabilities);
     
     // Check transport options
-    let transport = stdio();
+    let _transport = stdio();
     println!("Transport created");
 }
\ No newline at end of file
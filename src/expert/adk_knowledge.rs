@@ -1,7 +1,24 @@
 //! Google ADK knowledge base and version management
 
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
 use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use crate::expert::knowledge_source::{KnowledgePack, KnowledgeSource};
+use crate::utils::error::{ArkaftMcpError, ArkaftResult};
+
+/// Root of the official Google ADK documentation site (serves the latest version)
+pub const DOCS_BASE_URL: &str = "https://google.github.io/adk-docs";
+
+/// Release page prefix of the ADK repository; the tag is `v` followed by the version
+pub const RELEASE_NOTES_BASE_URL: &str = "https://github.com/google/adk-python/releases/tag";
+
+/// Environment variable that turns on strict version validation ("true" or "1")
+pub const STRICT_VERSIONS_ENV: &str = "ADK_STRICT_VERSIONS";
+
+/// Environment variable naming a JSON file of extra versions and alias overrides
+pub const VERSION_ALIASES_FILE_ENV: &str = "ADK_VERSION_ALIASES_FILE";
 
 /// ADK knowledge base structure for storing comprehensive documentation knowledge
 #[derive(Clone, Debug)]
@@ -12,6 +29,10 @@ pub struct AdkKnowledgeBase {
     pub default_version: String,
     /// Configuration for version tracking
     pub version_config: VersionConfig,
+    /// When each version's content was last refreshed, in seconds since the Unix epoch
+    pub updated_at: HashMap<String, i64>,
+    /// Names of the sources that contributed each version's content, in merge order
+    pub sources: HashMap<String, Vec<String>>,
 }
 
 /// Documentation references for a specific ADK version
@@ -29,10 +50,25 @@ pub struct VersionDocs {
     pub implementation_patterns: HashMap<String, ImplementationPattern>,
     /// Version-specific features and changes
     pub version_features: Vec<VersionFeature>,
+    /// Glossary of ADK terminology
+    #[serde(default)]
+    pub glossary: Vec<GlossaryEntry>,
+    /// Project templates for bootstrapping agents
+    #[serde(default)]
+    pub templates: HashMap<String, ProjectTemplate>,
+    /// Changes shipped in this release, from ingested changelog data
+    #[serde(default)]
+    pub changelog: Vec<ChangelogEntry>,
+    /// Provenance of non-official entries, keyed by entry kind and id (entries absent here are official)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub provenance: HashMap<String, Provenance>,
+    /// Documentation sections supporting entries, keyed by entry kind and id like `provenance`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub citations: HashMap<String, Citation>,
 }
 
 /// Categorized official documentation URLs
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DocumentationUrls {
     /// Main quickstart guide
     pub quickstart: String,
@@ -59,6 +95,12 @@ pub struct ConceptInfo {
     pub related_concepts: Vec<String>,
     /// Official documentation references
     pub documentation_refs: Vec<String>,
+    /// Page the entry was taken from, when it is not the first documentation reference
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+    /// When the entry was last updated, in seconds since the Unix epoch (`None` for bundled content)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated: Option<i64>,
 }
 
 /// Best practice information
@@ -74,6 +116,12 @@ pub struct BestPractice {
     pub examples: Vec<String>,
     /// Official documentation reference
     pub documentation_ref: String,
+    /// Page the practice was taken from, when it is not the documentation reference
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+    /// When the practice was last updated, in seconds since the Unix epoch (`None` for bundled content)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated: Option<i64>,
 }
 
 /// Implementation pattern information
@@ -104,6 +152,28 @@ pub struct CodeExample {
     pub explanation: String,
 }
 
+/// Ready-to-use ADK project scaffold
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProjectTemplate {
+    /// Template name
+    pub name: String,
+    /// What the template sets up
+    pub description: String,
+    /// Files to create, with paths relative to the project root
+    pub files: Vec<TemplateFile>,
+    /// Commands and steps to run the project
+    pub setup_steps: Vec<String>,
+}
+
+/// File in a project template
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TemplateFile {
+    /// Path relative to the project root
+    pub path: String,
+    /// File contents
+    pub content: String,
+}
+
 /// Version-specific feature information
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct VersionFeature {
@@ -119,6 +189,317 @@ pub struct VersionFeature {
     pub migration_notes: Option<String>,
 }
 
+/// Kind of change recorded in a release changelog
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// Change that requires users to update their code
+    Breaking,
+    /// New capability
+    Feature,
+    /// Capability scheduled for removal
+    Deprecation,
+    /// Notable bug fix
+    Fix,
+}
+
+/// Single change shipped in a release
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChangelogEntry {
+    /// Kind of change
+    pub kind: ChangeKind,
+    /// Short title of the change
+    pub title: String,
+    /// What changed and how it affects users
+    #[serde(default)]
+    pub description: String,
+    /// Pull request, issue, or documentation URL for the change
+    #[serde(default)]
+    pub link: Option<String>,
+}
+
+/// Concept search result with a relevance score
+#[derive(Clone, Debug)]
+pub struct ConceptMatch<'a> {
+    /// Knowledge base key of the matched concept
+    pub key: &'a str,
+    /// Matched concept
+    pub concept: &'a ConceptInfo,
+    /// Relevance score between 0.0 and 1.0
+    pub score: f32,
+    /// Provenance when the entry is not official
+    pub provenance: Option<&'a Provenance>,
+    /// Documentation section supporting the concept, if known
+    pub citation: Option<&'a Citation>,
+}
+
+/// Implementation pattern search result with a relevance score
+#[derive(Clone, Debug)]
+pub struct PatternMatch<'a> {
+    /// Knowledge base key of the matched pattern
+    pub key: &'a str,
+    /// Matched implementation pattern
+    pub pattern: &'a ImplementationPattern,
+    /// Relevance score between 0.0 and 1.0
+    pub score: f32,
+    /// Provenance when the entry is not official
+    pub provenance: Option<&'a Provenance>,
+}
+
+/// Code example search result with a relevance score
+#[derive(Clone, Debug)]
+pub struct CodeExampleMatch<'a> {
+    /// Knowledge base key of the pattern holding the example
+    pub pattern_key: &'a str,
+    /// Pattern holding the example
+    pub pattern: &'a ImplementationPattern,
+    /// Matched code example
+    pub example: &'a CodeExample,
+    /// Relevance score between 0.0 and 1.0
+    pub score: f32,
+    /// Provenance when the entry is not official
+    pub provenance: Option<&'a Provenance>,
+}
+
+/// Best practice search result with a relevance score
+#[derive(Clone, Debug)]
+pub struct PracticeMatch<'a> {
+    /// Matched best practice
+    pub practice: &'a BestPractice,
+    /// Relevance score between 0.0 and 1.0
+    pub score: f32,
+    /// Provenance when the entry is not official
+    pub provenance: Option<&'a Provenance>,
+    /// Documentation section supporting the practice, if known
+    pub citation: Option<&'a Citation>,
+}
+
+/// Kind of knowledge base entry a retrieved passage comes from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PassageKind {
+    /// Concept description
+    Concept,
+    /// Glossary definition
+    Glossary,
+    /// Best practice
+    BestPractice,
+    /// Implementation pattern
+    Pattern,
+    /// Version feature or migration note
+    VersionFeature,
+}
+
+impl PassageKind {
+    /// Human-readable label for display in responses
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Concept => "Concept",
+            Self::Glossary => "Glossary",
+            Self::BestPractice => "Best Practice",
+            Self::Pattern => "Pattern",
+            Self::VersionFeature => "Version Feature",
+        }
+    }
+}
+
+/// Passage of knowledge base text retrieved for a query
+#[derive(Clone, Debug)]
+pub struct Passage {
+    /// Kind of entry the passage comes from
+    pub kind: PassageKind,
+    /// Entry title
+    pub title: String,
+    /// Passage text
+    pub text: String,
+    /// Documentation URL backing the passage, if any
+    pub reference: Option<String>,
+    /// Relevance score between 0.0 and 1.0
+    pub score: f32,
+    /// Provenance when the entry is not official
+    pub provenance: Option<Provenance>,
+    /// Documentation section supporting the passage, if known
+    pub citation: Option<Citation>,
+}
+
+/// Glossary entry defining a piece of ADK terminology
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GlossaryEntry {
+    /// Term being defined
+    pub term: String,
+    /// Short, single-sentence definition
+    pub definition: String,
+    /// Key of the related concept in the knowledge base, if any
+    pub concept_ref: Option<String>,
+}
+
+/// Section of an official documentation page that supports a knowledge base entry
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Citation {
+    /// Documentation page URL
+    pub url: String,
+    /// Fragment identifying the section on the page, without the leading '#'
+    pub anchor: String,
+    /// Headings from the page down to the section, e.g. ["Sessions", "State", "Prefixes"]
+    pub headings: Vec<String>,
+}
+
+impl Citation {
+    /// Create a citation for a section of a documentation page
+    pub fn new(url: &str, anchor: &str, headings: &[&str]) -> Self {
+        Self {
+            url: url.to_string(),
+            anchor: anchor.trim_start_matches('#').to_string(),
+            headings: headings.iter().map(|heading| heading.to_string()).collect(),
+        }
+    }
+    
+    /// URL pointing at the section itself
+    pub fn deep_link(&self) -> String {
+        format!("{}#{}", self.url.split('#').next().unwrap_or_default(), self.anchor)
+    }
+    
+    /// Heading path shown as the link text, e.g. "Sessions → State → Prefixes"
+    pub fn title(&self) -> String {
+        self.headings.join(" → ")
+    }
+}
+
+/// Where a knowledge base entry came from
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Provenance {
+    /// Name of the knowledge pack or source
+    pub source_name: String,
+    /// Homepage or repository of the source
+    #[serde(default)]
+    pub url: Option<String>,
+    /// How far the source is trusted
+    pub trust_level: TrustLevel,
+}
+
+/// Trust level of a knowledge source
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrustLevel {
+    /// Official Google ADK documentation
+    Official,
+    /// Third-party content reviewed by the maintainers
+    Verified,
+    /// Unreviewed third-party content
+    Community,
+}
+
+/// Support status of an ADK version
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VersionStatus {
+    /// Version used when a request names none
+    Default,
+    /// Documentation is loaded for the version
+    Supported,
+    /// Version is listed but no documentation is loaded for it
+    Unavailable,
+}
+
+/// Summary of one ADK version for version pickers
+#[derive(Clone, Debug)]
+pub struct VersionSummary {
+    /// Concrete version string
+    pub version: String,
+    /// Support status
+    pub status: VersionStatus,
+    /// Aliases resolving to this version, sorted
+    pub aliases: Vec<String>,
+}
+
+/// Size and origin of one version's loaded content
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionStats {
+    /// Concrete version string
+    pub version: String,
+    /// Number of concepts
+    pub concepts: usize,
+    /// Number of best practices
+    pub best_practices: usize,
+    /// Number of implementation patterns
+    pub patterns: usize,
+    /// Last refresh in seconds since the Unix epoch (zero for bundled or undated content)
+    pub updated_at: i64,
+    /// Sources that contributed content, in merge order
+    pub sources: Vec<String>,
+}
+
+/// Whether a concept or feature can be used in a given ADK version
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Availability {
+    /// Supported in the version
+    Available,
+    /// Still present but deprecated in the version
+    Deprecated,
+    /// Not present in the version
+    NotAvailable,
+    /// No documentation is loaded to decide
+    Unknown,
+}
+
+/// Availability of a concept or feature in one ADK version
+#[derive(Clone, Debug)]
+pub struct VersionAvailability {
+    /// Concrete version string
+    pub version: String,
+    /// Availability in that version
+    pub availability: Availability,
+}
+
+/// Cross-version availability of a concept or feature
+#[derive(Clone, Debug)]
+pub struct FeatureAvailability {
+    /// Display name of the concept or feature
+    pub name: String,
+    /// Version the feature was introduced in, when recorded
+    pub introduced_in: Option<String>,
+    /// Version the feature was deprecated in, when recorded
+    pub deprecated_in: Option<String>,
+    /// Migration notes for deprecated features
+    pub migration_notes: Option<String>,
+    /// Availability per known version, oldest first
+    pub versions: Vec<VersionAvailability>,
+}
+
+impl FeatureAvailability {
+    /// Oldest version in which the feature is available
+    pub fn minimum_version(&self) -> Option<&str> {
+        self.versions
+            .iter()
+            .find(|v| v.availability == Availability::Available)
+            .map(|v| v.version.as_str())
+    }
+}
+
+/// Release channel a version is published on
+///
+/// Channel names double as version aliases, so early adopters can pass "preview" or
+/// "next" as the version while requests naming no version stay on stable.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleaseChannel {
+    /// Current general-availability release
+    #[default]
+    Stable,
+    /// Release candidate published ahead of the next stable release
+    Preview,
+    /// Development builds of the upcoming release
+    Next,
+}
+
+/// Version and documentation source tracked by a release channel
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelConfig {
+    /// Version the channel currently points to
+    pub version: String,
+    /// JSON feed of `VersionDocs` for the channel's documentation, fetched at startup
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_url: Option<String>,
+}
+
 /// Configuration for version management and tracking
 #[derive(Clone, Debug)]
 pub struct VersionConfig {
@@ -130,33 +511,135 @@ pub struct VersionConfig {
     pub version_aliases: HashMap<String, String>,
     /// Auto-update configuration
     pub auto_update_enabled: bool,
+    /// Reject unknown versions instead of passing them through unchanged
+    pub strict_versions: bool,
+    /// Release channels and the versions they point to
+    pub channels: HashMap<ReleaseChannel, ChannelConfig>,
+}
+
+/// Operator-supplied versions and aliases applied over the version configuration
+///
+/// Lets operators point "stable" at a new release without rebuilding the crate, either
+/// from a config file at startup or through the admin_version_aliases tool.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionOverrides {
+    /// Versions to add to the available versions
+    #[serde(default)]
+    pub versions: Vec<String>,
+    /// Aliases to define or repoint (e.g., "stable" -> "1.2.0")
+    #[serde(default)]
+    pub aliases: HashMap<String, String>,
+    /// Release channels to point at a version, optionally with their own documentation feed
+    #[serde(default)]
+    pub channels: HashMap<ReleaseChannel, ChannelConfig>,
+}
+
+impl VersionOverrides {
+    /// Read overrides from a JSON file such as `{"versions": ["1.2.0"], "aliases": {"stable": "1.2.0"}}`
+    pub async fn read_from(path: &Path) -> ArkaftResult<Self> {
+        let content = tokio::fs::read_to_string(path).await?;
+        serde_json::from_str(&content).map_err(|e| {
+            ArkaftMcpError::Configuration(format!("Invalid version aliases file {}: {}", path.display(), e))
+        })
+    }
+    
+    /// Documentation feeds of the configured channels, ordered by channel
+    pub fn channel_feeds(&self) -> Vec<(ReleaseChannel, String)> {
+        let mut feeds: Vec<(ReleaseChannel, String)> = self.channels
+            .iter()
+            .filter_map(|(channel, config)| {
+                let feed_url = config.feed_url.as_deref()?.trim();
+                (!feed_url.is_empty()).then(|| (*channel, feed_url.to_string()))
+            })
+            .collect();
+        feeds.sort();
+        feeds
+    }
 }
 
 impl AdkKnowledgeBase {
     /// Create a new knowledge base with default ADK information
     pub fn new() -> Self {
-        let mut version_docs = HashMap::new();
+        let mut knowledge_base = Self::empty();
+        
+        // Initialize with latest version information
+        let latest_docs = Self::bundled_version_docs(knowledge_base.default_version.clone());
+        knowledge_base.record_source(&latest_docs.version, "bundled");
+        knowledge_base.version_docs.insert(knowledge_base.default_version.clone(), latest_docs);
         
-        // Initialize version configuration
+        knowledge_base
+    }
+    
+    /// Create a knowledge base with default version configuration and no content
+    pub fn empty() -> Self {
         let version_config = VersionConfig::new();
         let default_version = version_config.resolve_version("latest");
         
-        // Initialize with latest version information
-        let latest_docs = VersionDocs {
-            version: default_version.clone(),
+        Self {
+            version_docs: HashMap::new(),
+            default_version,
+            version_config,
+            updated_at: HashMap::new(),
+            sources: HashMap::new(),
+        }
+    }
+    
+    /// Build a knowledge base by merging content from several sources
+    pub async fn from_sources(sources: &[Arc<dyn KnowledgeSource>]) -> Self {
+        let mut knowledge_base = Self::empty();
+        knowledge_base.merge_sources(sources).await;
+        knowledge_base
+    }
+    
+    /// Merge content from sources in ascending priority so higher-priority sources win conflicts
+    ///
+    /// Sources with equal priority are merged in the order given. A failing source is
+    /// logged and skipped. Returns the number of sources merged successfully.
+    pub async fn merge_sources(&mut self, sources: &[Arc<dyn KnowledgeSource>]) -> usize {
+        let mut ordered: Vec<&Arc<dyn KnowledgeSource>> = sources.iter().collect();
+        ordered.sort_by_key(|source| source.priority());
+        
+        let mut merged = 0;
+        for source in ordered {
+            match source.fetch_all().await {
+                Ok(all_docs) => {
+                    info!("Merging {} version(s) from knowledge source '{}'", all_docs.len(), source.name());
+                    for docs in all_docs {
+                        self.record_source(&docs.version, source.name());
+                        self.merge_version_docs(docs);
+                    }
+                    merged += 1;
+                }
+                Err(e) => warn!("Skipping knowledge source '{}': {}", source.name(), e),
+            }
+        }
+        
+        merged
+    }
+    
+    /// Merge a third-party knowledge pack, attributing its entries to the pack
+    pub fn merge_knowledge_pack(&mut self, pack: KnowledgePack) {
+        let name = pack.name.clone();
+        for docs in pack.into_version_docs() {
+            self.record_source(&docs.version, &name);
+            self.merge_version_docs(docs);
+        }
+    }
+    
+    /// Compiled-in documentation published under `version`
+    pub fn bundled_version_docs(version: String) -> VersionDocs {
+        VersionDocs {
+            version,
             official_urls: DocumentationUrls::default(),
             concepts: Self::initialize_default_concepts(),
             best_practices: Self::initialize_default_best_practices(),
             implementation_patterns: Self::initialize_default_patterns(),
-            version_features: Vec::new(),
-        };
-        
-        version_docs.insert(default_version.clone(), latest_docs);
-        
-        Self {
-            version_docs,
-            default_version,
-            version_config,
+            version_features: Self::initialize_default_features(),
+            glossary: Self::initialize_default_glossary(),
+            templates: Self::initialize_default_templates(),
+            changelog: Vec::new(),
+            provenance: HashMap::new(),
+            citations: Self::initialize_default_citations(),
         }
     }
     
@@ -180,6 +663,54 @@ impl AdkKnowledgeBase {
         self.version_docs.insert(version, docs);
     }
     
+    /// Merge documentation into a version, creating the version if it is new
+    pub fn merge_version_docs(&mut self, docs: VersionDocs) {
+        let version = docs.version.clone();
+        match self.version_docs.get_mut(&version) {
+            Some(existing) => existing.merge_from(docs),
+            None => {
+                self.version_docs.insert(version.clone(), docs);
+            }
+        }
+        self.version_config.add_version(version);
+    }
+    
+    /// When a version's content was last refreshed (zero for bundled or undated content)
+    pub fn content_updated_at(&self, version: &str) -> i64 {
+        self.updated_at.get(version).copied().unwrap_or(0)
+    }
+    
+    /// Record that a version's content was refreshed at `timestamp`, keeping the latest time
+    pub fn mark_updated(&mut self, version: &str, timestamp: i64) {
+        let updated_at = self.updated_at.entry(version.to_string()).or_insert(timestamp);
+        *updated_at = (*updated_at).max(timestamp);
+    }
+    
+    /// Record that `source` contributed content to a version, once per source
+    pub fn record_source(&mut self, version: &str, source: &str) {
+        let sources = self.sources.entry(version.to_string()).or_default();
+        if !sources.iter().any(|s| s == source) {
+            sources.push(source.to_string());
+        }
+    }
+    
+    /// Entry counts, last refresh time, and contributing sources for every loaded version, oldest first
+    pub fn version_stats(&self) -> Vec<VersionStats> {
+        let mut stats: Vec<VersionStats> = self.version_docs
+            .values()
+            .map(|docs| VersionStats {
+                version: docs.version.clone(),
+                concepts: docs.concepts.len(),
+                best_practices: docs.best_practices.len(),
+                patterns: docs.implementation_patterns.len(),
+                updated_at: self.content_updated_at(&docs.version),
+                sources: self.sources.get(&docs.version).cloned().unwrap_or_default(),
+            })
+            .collect();
+        stats.sort_by(|a, b| compare_versions(&a.version, &b.version));
+        stats
+    }
+    
     /// Get available versions
     pub fn get_available_versions(&self) -> Vec<String> {
         self.version_config.available_versions.clone()
@@ -190,6 +721,107 @@ impl AdkKnowledgeBase {
         self.version_config.resolve_version(version)
     }
     
+    /// Concrete versions with their aliases and support status, in configured order
+    ///
+    /// Alias names listed in `available_versions` are folded into the version they resolve to.
+    pub fn version_summaries(&self) -> Vec<VersionSummary> {
+        let mut loaded: Vec<&String> = self.version_docs.keys().collect();
+        loaded.sort();
+        
+        let mut versions: Vec<String> = Vec::new();
+        let listed = self.version_config.available_versions
+            .iter()
+            .chain(loaded)
+            .chain(std::iter::once(&self.default_version));
+        for version in listed {
+            let resolved = self.version_config.resolve_version(version);
+            if !versions.contains(&resolved) {
+                versions.push(resolved);
+            }
+        }
+        
+        versions
+            .into_iter()
+            .map(|version| {
+                let status = if version == self.default_version {
+                    VersionStatus::Default
+                } else if self.version_docs.contains_key(&version) {
+                    VersionStatus::Supported
+                } else {
+                    VersionStatus::Unavailable
+                };
+                let mut aliases: Vec<String> = self.version_config.version_aliases
+                    .iter()
+                    .filter(|(_, target)| **target == version)
+                    .map(|(alias, _)| alias.clone())
+                    .collect();
+                aliases.sort();
+                
+                VersionSummary { version, status, aliases }
+            })
+            .collect()
+    }
+    
+    /// Report which known versions support a concept or feature
+    ///
+    /// Matches `VersionFeature` names and concept keys or names case-insensitively across
+    /// every loaded version. When feature data records `introduced_in`/`deprecated_in`,
+    /// availability follows from version order; otherwise it follows from whether each
+    /// version's documentation contains the concept. Returns `None` when nothing matches.
+    pub fn feature_availability(&self, name: &str) -> Option<FeatureAvailability> {
+        let needle = name.trim().to_lowercase();
+        if needle.is_empty() {
+            return None;
+        }
+        
+        let mut loaded: Vec<&VersionDocs> = self.version_docs.values().collect();
+        loaded.sort_by(|a, b| compare_versions(&a.version, &b.version));
+        
+        let feature = loaded
+            .iter()
+            .flat_map(|docs| docs.version_features.iter())
+            .find(|feature| feature.name.to_lowercase() == needle);
+        let concept_in = |docs: &VersionDocs| docs.concept_named(&needle).map(|c| c.name.clone());
+        
+        let display_name = match feature {
+            Some(feature) => feature.name.clone(),
+            None => loaded.iter().find_map(|docs| concept_in(docs))?,
+        };
+        
+        let mut versions: Vec<String> = self.version_summaries().into_iter().map(|s| s.version).collect();
+        versions.sort_by(|a, b| compare_versions(a, b));
+        
+        let versions = versions
+            .into_iter()
+            .map(|version| {
+                let by_feature = feature.map(|feature| {
+                    if compare_versions(&version, &feature.introduced_in).is_lt() {
+                        return Availability::NotAvailable;
+                    }
+                    match &feature.deprecated_in {
+                        Some(deprecated) if compare_versions(&version, deprecated).is_ge() => Availability::Deprecated,
+                        _ => Availability::Available,
+                    }
+                });
+                let availability = by_feature.unwrap_or_else(|| match self.version_docs.get(&version) {
+                    Some(docs) if concept_in(docs).is_some() => Availability::Available,
+                    Some(_) => Availability::NotAvailable,
+                    None => Availability::Unknown,
+                });
+                
+                VersionAvailability { version, availability }
+            })
+            .collect();
+        
+        Some(FeatureAvailability {
+            name: display_name,
+            introduced_in: feature.map(|f| f.introduced_in.clone()),
+            deprecated_in: feature.and_then(|f| f.deprecated_in.clone()),
+            migration_notes: feature.and_then(|f| f.migration_notes.clone()),
+            versions,
+        })
+    }
+    
     /// Search concepts by query string
     pub fn search_concepts(&self, query: &str, version: Option<&str>) -> Vec<&ConceptInfo> {
         let version = version.unwrap_or(&self.default_version);
@@ -206,6 +838,382 @@ impl AdkKnowledgeBase {
         }
     }
     
+    /// Rank concepts by relevance to the query, returning at most `limit` matches
+    ///
+    /// Each query term scores highest when it appears in the concept name or key,
+    /// lower when it only appears in the description, and lowest when it only
+    /// appears in examples or related concepts. Scores are normalized to 0.0-1.0.
+    pub fn rank_concepts(&self, query: &str, version: Option<&str>, limit: usize) -> Vec<ConceptMatch<'_>> {
+        let version = version.unwrap_or(&self.default_version);
+        let Some(docs) = self.get_version_docs(version) else {
+            return Vec::new();
+        };
+        
+        let terms = query_terms(query);
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        let query_lower = query.trim().to_lowercase();
+        
+        let mut matches: Vec<ConceptMatch<'_>> = docs.concepts
+            .iter()
+            .filter_map(|(key, concept)| {
+                let name = format!("{} {}", concept.name, key.replace('_', " "));
+                let details = concept.examples
+                    .iter()
+                    .chain(concept.related_concepts.iter())
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                
+                let score = if concept.name.to_lowercase() == query_lower {
+                    1.0
+                } else {
+                    relevance_score(&terms, &name, &concept.description, &details)
+                };
+                
+                (score > 0.0).then_some(ConceptMatch {
+                    key: key.as_str(),
+                    concept,
+                    score,
+                    provenance: docs.concept_provenance(key),
+                    citation: docs.concept_citation(key),
+                })
+            })
+            .collect();
+        
+        matches.sort_by(|a, b| compare_scores(a.score, b.score).then_with(|| a.key.cmp(b.key)));
+        matches.truncate(limit);
+        matches
+    }
+    
+    /// Rank implementation patterns by relevance to the query
+    pub fn rank_patterns(&self, query: &str, version: Option<&str>, limit: usize) -> Vec<PatternMatch<'_>> {
+        let version = version.unwrap_or(&self.default_version);
+        let Some(docs) = self.get_version_docs(version) else {
+            return Vec::new();
+        };
+        
+        let terms = query_terms(query);
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        
+        let mut matches: Vec<PatternMatch<'_>> = docs.implementation_patterns
+            .iter()
+            .filter_map(|(key, pattern)| {
+                let name = format!("{} {}", pattern.name, key.replace('_', " "));
+                let details = pattern.use_cases
+                    .iter()
+                    .chain(pattern.related_practices.iter())
+                    .cloned()
+                    .chain(pattern.code_examples.iter().map(|example| example.title.clone()))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                
+                let score = relevance_score(&terms, &name, &pattern.description, &details);
+                (score > 0.0).then_some(PatternMatch { key: key.as_str(), pattern, score, provenance: docs.pattern_provenance(key) })
+            })
+            .collect();
+        
+        matches.sort_by(|a, b| compare_scores(a.score, b.score).then_with(|| a.key.cmp(b.key)));
+        matches.truncate(limit);
+        matches
+    }
+    
+    /// Rank code examples across all implementation patterns by relevance to the query
+    ///
+    /// Example titles and their pattern names weigh most, then explanations, then the
+    /// code itself and the pattern's use cases. `language` filters examples case-insensitively.
+    pub fn search_code_examples(
+        &self,
+        query: &str,
+        language: Option<&str>,
+        version: Option<&str>,
+        limit: usize,
+    ) -> Vec<CodeExampleMatch<'_>> {
+        let version = version.unwrap_or(&self.default_version);
+        let Some(docs) = self.get_version_docs(version) else {
+            return Vec::new();
+        };
+        
+        let terms = query_terms(query);
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        
+        let mut matches: Vec<CodeExampleMatch<'_>> = docs.implementation_patterns
+            .iter()
+            .flat_map(|(key, pattern)| {
+                pattern.code_examples.iter().map(move |example| (key, pattern, example))
+            })
+            .filter(|(_, _, example)| language.is_none_or(|lang| example.language.eq_ignore_ascii_case(lang.trim())))
+            .filter_map(|(key, pattern, example)| {
+                let name = format!("{} {} {}", example.title, pattern.name, key.replace('_', " "));
+                let details = format!("{} {}", example.code, pattern.use_cases.join(" "));
+                
+                let score = relevance_score(&terms, &name, &example.explanation, &details);
+                (score > 0.0).then_some(CodeExampleMatch {
+                    pattern_key: key.as_str(),
+                    pattern,
+                    example,
+                    score,
+                    provenance: docs.pattern_provenance(key),
+                })
+            })
+            .collect();
+        
+        matches.sort_by(|a, b| {
+            compare_scores(a.score, b.score)
+                .then_with(|| a.pattern_key.cmp(b.pattern_key))
+                .then_with(|| a.example.title.cmp(&b.example.title))
+        });
+        matches.truncate(limit);
+        matches
+    }
+    
+    /// Rank best practices by relevance to the query
+    pub fn rank_best_practices(&self, query: &str, version: Option<&str>, limit: usize) -> Vec<PracticeMatch<'_>> {
+        let version = version.unwrap_or(&self.default_version);
+        let Some(docs) = self.get_version_docs(version) else {
+            return Vec::new();
+        };
+        
+        let terms = query_terms(query);
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        
+        let mut matches: Vec<PracticeMatch<'_>> = docs.best_practices
+            .iter()
+            .filter_map(|practice| {
+                let name = format!("{} {}", practice.title, practice.category);
+                let details = practice.examples.join(" ");
+                
+                let score = relevance_score(&terms, &name, &practice.description, &details);
+                (score > 0.0).then_some(PracticeMatch {
+                    practice,
+                    score,
+                    provenance: docs.practice_provenance(&practice.title),
+                    citation: docs.practice_citation(&practice.title),
+                })
+            })
+            .collect();
+        
+        matches.sort_by(|a, b| compare_scores(a.score, b.score).then_with(|| a.practice.title.cmp(&b.practice.title)));
+        matches.truncate(limit);
+        matches
+    }
+    
+    /// Retrieve the passages most relevant to a query across every kind of entry
+    ///
+    /// Concepts, glossary definitions, best practices, patterns, and version features
+    /// are scored on the same scale so the strongest evidence surfaces first.
+    pub fn retrieve_passages(&self, query: &str, version: Option<&str>, limit: usize) -> Vec<Passage> {
+        let version = version.unwrap_or(&self.default_version);
+        let Some(docs) = self.get_version_docs(version) else {
+            return Vec::new();
+        };
+        
+        let terms = query_terms(query);
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        
+        let mut passages = Vec::new();
+        let mut push = |kind, title: &str, text: &str, reference: Option<&String>, score: f32, provenance: Option<&Provenance>, citation: Option<&Citation>| {
+            if score > 0.0 {
+                passages.push(Passage {
+                    kind,
+                    title: title.to_string(),
+                    text: text.to_string(),
+                    reference: reference.cloned(),
+                    score,
+                    provenance: provenance.cloned(),
+                    citation: citation.cloned(),
+                });
+            }
+        };
+        
+        for (key, concept) in &docs.concepts {
+            let name = format!("{} {}", concept.name, key.replace('_', " "));
+            let score = relevance_score(&terms, &name, &concept.description, &concept.examples.join(" "));
+            push(PassageKind::Concept, &concept.name, &concept.description, concept.documentation_refs.first(), score, docs.concept_provenance(key), docs.concept_citation(key));
+        }
+        
+        for entry in &docs.glossary {
+            let score = relevance_score(&terms, &entry.term, &entry.definition, "");
+            let reference = entry.concept_ref
+                .as_ref()
+                .and_then(|key| docs.concepts.get(key))
+                .and_then(|concept| concept.documentation_refs.first());
+            let citation = entry.concept_ref.as_ref().and_then(|key| docs.concept_citation(key));
+            push(PassageKind::Glossary, &entry.term, &entry.definition, reference, score, None, citation);
+        }
+        
+        for practice in &docs.best_practices {
+            let name = format!("{} {}", practice.title, practice.category);
+            let score = relevance_score(&terms, &name, &practice.description, &practice.examples.join(" "));
+            push(PassageKind::BestPractice, &practice.title, &practice.description, Some(&practice.documentation_ref), score, docs.practice_provenance(&practice.title), docs.practice_citation(&practice.title));
+        }
+        
+        for (key, pattern) in &docs.implementation_patterns {
+            let name = format!("{} {}", pattern.name, key.replace('_', " "));
+            let score = relevance_score(&terms, &name, &pattern.description, &pattern.use_cases.join(" "));
+            push(PassageKind::Pattern, &pattern.name, &pattern.description, None, score, docs.pattern_provenance(key), None);
+        }
+        
+        for feature in &docs.version_features {
+            let details = feature.migration_notes.clone().unwrap_or_default();
+            let score = relevance_score(&terms, &feature.name, &feature.description, &details);
+            push(PassageKind::VersionFeature, &feature.name, &feature.description, None, score, None, None);
+        }
+        
+        passages.sort_by(|a, b| compare_scores(a.score, b.score).then_with(|| a.title.cmp(&b.title)));
+        passages.truncate(limit);
+        passages
+    }
+    
+    /// Changes shipped in a release, ordered breaking changes first, then features, deprecations, and fixes
+    ///
+    /// Combines the version's changelog with version features introduced or deprecated
+    /// in it, so feature data alone still produces a digest. Unknown versions have no changes.
+    pub fn changes_in(&self, version: &str) -> Vec<ChangelogEntry> {
+        let version = self.resolve_version(version);
+        let mut changes: Vec<ChangelogEntry> = self.version_docs
+            .get(&version)
+            .map(|docs| docs.changelog.clone())
+            .unwrap_or_default();
+        
+        let is_version = |v: &str| compare_versions(v, &version) == std::cmp::Ordering::Equal;
+        let mut features: Vec<&VersionFeature> = self.version_docs
+            .values()
+            .flat_map(|docs| docs.version_features.iter())
+            .collect();
+        features.sort_by(|a, b| a.name.cmp(&b.name));
+        for feature in features {
+            let mut derived = Vec::new();
+            if is_version(&feature.introduced_in) {
+                derived.push((ChangeKind::Feature, feature.description.clone()));
+            }
+            if feature.deprecated_in.as_deref().is_some_and(is_version) {
+                let description = feature.migration_notes.clone().unwrap_or_else(|| feature.description.clone());
+                derived.push((ChangeKind::Deprecation, description));
+            }
+            for (kind, description) in derived {
+                if !changes.iter().any(|c| c.kind == kind && c.title.eq_ignore_ascii_case(&feature.name)) {
+                    changes.push(ChangelogEntry { kind, title: feature.name.clone(), description, link: None });
+                }
+            }
+        }
+        
+        changes.sort_by_key(|change| change.kind);
+        changes
+    }
+    
+    /// Release notes page for a version
+    pub fn release_notes_url(&self, version: &str) -> String {
+        let version = self.resolve_version(version);
+        format!("{}/v{}", RELEASE_NOTES_BASE_URL, version.trim_start_matches('v'))
+    }
+    
+    /// Suggest topics whose names are spelled most like the query's words
+    ///
+    /// Used when nothing matches the query exactly, so near misses such as
+    /// typos or alternative spellings still point at related content.
+    pub fn closest_topics(&self, query: &str, version: Option<&str>, limit: usize) -> Vec<String> {
+        const MIN_SIMILARITY: f32 = 0.4;
+        
+        let version = version.unwrap_or(&self.default_version);
+        let Some(docs) = self.get_version_docs(version) else {
+            return Vec::new();
+        };
+        
+        let words: Vec<String> = query
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| word.len() >= 3)
+            .map(str::to_string)
+            .collect();
+        
+        let candidates = docs.concepts
+            .iter()
+            .map(|(key, concept)| (format!("{} (`{}`)", concept.name, key), format!("{} {}", concept.name, key)))
+            .chain(docs.implementation_patterns
+                .iter()
+                .map(|(key, pattern)| (format!("{} (pattern `{}`)", pattern.name, key), format!("{} {}", pattern.name, key))))
+            .chain(docs.glossary
+                .iter()
+                .map(|entry| (format!("{} (glossary)", entry.term), entry.term.clone())));
+        
+        let mut scored: Vec<(String, f32)> = candidates
+            .filter_map(|(label, text)| {
+                let text = text.to_lowercase();
+                let similarity = text
+                    .split(|c: char| !c.is_alphanumeric())
+                    .filter(|word| word.len() >= 3)
+                    .flat_map(|candidate| words.iter().map(move |word| bigram_similarity(word, candidate)))
+                    .fold(0.0, f32::max);
+                (similarity >= MIN_SIMILARITY).then_some((label, similarity))
+            })
+            .collect();
+        
+        scored.sort_by(|a, b| compare_scores(a.1, b.1).then_with(|| a.0.cmp(&b.0)));
+        scored.truncate(limit);
+        scored.into_iter().map(|(label, _)| label).collect()
+    }
+    
+    /// Suggest follow-up questions from entries related to or co-occurring with the query
+    ///
+    /// Draws on the related concepts of the best-matching concept, the best-matching
+    /// implementation pattern, and the category of the best-matching practice, taking one
+    /// from each source in turn. Subjects the query already covers are skipped.
+    pub fn suggest_follow_up_questions(&self, query: &str, version: Option<&str>, limit: usize) -> Vec<String> {
+        const CANDIDATES_PER_SOURCE: usize = 3;
+        
+        let version = version.unwrap_or(&self.default_version);
+        let Some(docs) = self.get_version_docs(version) else {
+            return Vec::new();
+        };
+        let asked = query_terms(query);
+        let is_new = |subject: &str| !query_terms(subject).iter().all(|term| asked.contains(term));
+        
+        let related: Vec<String> = self.rank_concepts(query, Some(version), 1)
+            .first()
+            .map(|top| top.concept.related_concepts
+                .iter()
+                .filter_map(|key| docs.concepts.get(key))
+                .map(|concept| concept.name.clone())
+                .filter(|name| is_new(name))
+                .map(|name| format!("What is {}?", name))
+                .collect())
+            .unwrap_or_default();
+        let patterns: Vec<String> = self.rank_patterns(query, Some(version), CANDIDATES_PER_SOURCE)
+            .iter()
+            .filter(|m| is_new(&m.pattern.name))
+            .map(|m| format!("How do I implement {}?", m.pattern.name.to_lowercase()))
+            .collect();
+        let practices: Vec<String> = self.rank_best_practices(query, Some(version), CANDIDATES_PER_SOURCE)
+            .iter()
+            .filter(|m| is_new(&m.practice.category))
+            .map(|m| format!("What are the best practices for {}?", m.practice.category.to_lowercase()))
+            .collect();
+        
+        let mut suggestions: Vec<String> = Vec::new();
+        let rounds = related.len().max(patterns.len()).max(practices.len());
+        for index in 0..rounds {
+            for source in [&related, &patterns, &practices] {
+                if let Some(question) = source.get(index) {
+                    if !suggestions.contains(question) {
+                        suggestions.push(question.clone());
+                    }
+                }
+            }
+        }
+        suggestions.truncate(limit);
+        suggestions
+    }
+    
     /// Get best practices by category
     pub fn get_best_practices_by_category(&self, category: &str, version: Option<&str>) -> Vec<&BestPractice> {
         let version = version.unwrap_or(&self.default_version);
@@ -219,6 +1227,23 @@ impl AdkKnowledgeBase {
         }
     }
     
+    /// Best practice categories for version with the number of practices in each, sorted by name
+    ///
+    /// Categories are matched case-insensitively, so they are reported in lowercase.
+    pub fn best_practice_categories(&self, version: Option<&str>) -> Vec<(String, usize)> {
+        let version = version.unwrap_or(&self.default_version);
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        if let Some(docs) = self.get_version_docs(version) {
+            for practice in &docs.best_practices {
+                *counts.entry(practice.category.to_lowercase()).or_default() += 1;
+            }
+        }
+        
+        let mut categories: Vec<(String, usize)> = counts.into_iter().collect();
+        categories.sort();
+        categories
+    }
+    
     /// Get implementation pattern by name
     pub fn get_implementation_pattern(&self, pattern_name: &str, version: Option<&str>) -> Option<&ImplementationPattern> {
         let version = version.unwrap_or(&self.default_version);
@@ -227,26 +1252,203 @@ impl AdkKnowledgeBase {
             .get(pattern_name)
     }
     
-    /// Get official documentation URLs for version
-    pub fn get_official_urls(&self, version: Option<&str>) -> Option<&DocumentationUrls> {
+    /// Get project template by key
+    pub fn get_template(&self, template_name: &str, version: Option<&str>) -> Option<&ProjectTemplate> {
         let version = version.unwrap_or(&self.default_version);
-        self.get_version_docs(version).map(|docs| &docs.official_urls)
+        self.get_version_docs(version)?
+            .templates
+            .get(template_name)
     }
     
-    /// Initialize default ADK concepts
-    fn initialize_default_concepts() -> HashMap<String, ConceptInfo> {
-        let mut concepts = HashMap::new();
+    /// Get all glossary entries for version, sorted by term
+    pub fn get_glossary(&self, version: Option<&str>) -> Vec<&GlossaryEntry> {
+        let version = version.unwrap_or(&self.default_version);
+        let mut entries: Vec<&GlossaryEntry> = self.get_version_docs(version)
+            .map(|docs| docs.glossary.iter().collect())
+            .unwrap_or_default();
+        entries.sort_by_key(|entry| entry.term.to_lowercase());
+        entries
+    }
+    
+    /// Look up a glossary entry by term (case-insensitive)
+    pub fn get_glossary_entry(&self, term: &str, version: Option<&str>) -> Option<&GlossaryEntry> {
+        let version = version.unwrap_or(&self.default_version);
+        let term = term.trim();
+        self.get_version_docs(version)?
+            .glossary
+            .iter()
+            .find(|entry| entry.term.eq_ignore_ascii_case(term))
+    }
+    
+    /// Get official documentation URLs for version
+    pub fn get_official_urls(&self, version: Option<&str>) -> DocumentationUrls {
+        let default_urls = || {
+            self.version_docs
+                .get(&self.default_version)
+                .map(|docs| docs.official_urls.clone())
+                .unwrap_or_default()
+        };
         
-        // Core ADK concepts - these will be expanded with actual Google ADK information
-        concepts.insert("application_development_kit".to_string(), ConceptInfo {
-            name: "Application Development Kit (ADK)".to_string(),
-            description: "Google's comprehensive toolkit for building applications with best practices and official patterns.".to_string(),
-            examples: vec![
+        let Some(version) = self.linked_version(version) else {
+            return default_urls();
+        };
+        match self.version_docs.get(&version) {
+            Some(docs) if docs.official_urls != DocumentationUrls::default() => docs.official_urls.clone(),
+            _ => DocumentationUrls::for_version(&version),
+        }
+    }
+    
+    /// Known non-default version whose documentation links should be version-specific
+    ///
+    /// Returns `None` for the default version, which the unversioned site serves, and for
+    /// unknown versions, which fall back to the default version's documentation.
+    pub fn linked_version(&self, version: Option<&str>) -> Option<String> {
+        let resolved = self.resolve_version(version?);
+        (self.is_known_version(&resolved) && resolved != self.default_version).then_some(resolved)
+    }
+    
+    /// Add versions and repoint aliases and channels, validating every change before applying any
+    ///
+    /// Channel versions are added to the available versions. Alias targets must be
+    /// concrete versions (loaded, configured, or added by the same overrides), and an
+    /// alias may not shadow a concrete version. When "latest" moves to a version with
+    /// loaded documentation, that version becomes the default.
+    pub fn apply_version_overrides(&mut self, overrides: VersionOverrides) -> ArkaftResult<()> {
+        let mut channels: Vec<(ReleaseChannel, ChannelConfig)> = overrides.channels
+            .into_iter()
+            .map(|(channel, config)| (channel, ChannelConfig { version: config.version.trim().to_string(), ..config }))
+            .collect();
+        channels.sort_by_key(|(channel, _)| *channel);
+        
+        let versions: Vec<String> = overrides.versions
+            .iter()
+            .map(|version| version.trim().to_string())
+            .chain(channels.iter().map(|(_, config)| config.version.clone()))
+            .collect();
+        if versions.iter().any(|version| version.is_empty()) {
+            return Err(ArkaftMcpError::parameter_validation("Version names cannot be empty"));
+        }
+        
+        let mut aliases: Vec<(String, String)> = overrides.aliases
+            .iter()
+            .map(|(alias, target)| (alias.trim().to_string(), target.trim().to_string()))
+            .collect();
+        aliases.sort();
+        
+        let is_concrete = |version: &str| {
+            versions.iter().any(|v| v == version)
+                || self.version_docs.contains_key(version)
+                || (self.version_config.available_versions.iter().any(|v| v == version)
+                    && !self.version_config.version_aliases.contains_key(version))
+        };
+        for (alias, target) in &aliases {
+            if alias.is_empty() || target.is_empty() {
+                return Err(ArkaftMcpError::parameter_validation("Alias names and targets cannot be empty"));
+            }
+            if is_concrete(alias) {
+                return Err(ArkaftMcpError::parameter_validation(format!(
+                    "'{}' is a concrete version and cannot be used as an alias", alias
+                )));
+            }
+            if !is_concrete(target) {
+                return Err(ArkaftMcpError::parameter_validation(format!(
+                    "Alias target '{}' is not a known version; add it to the available versions first", target
+                )));
+            }
+        }
+        
+        for version in versions {
+            self.version_config.add_version(version);
+        }
+        for (channel, config) in channels {
+            info!("Release channel '{}' now points to {}", channel.name(), config.version);
+            self.version_config.set_channel(channel, config);
+        }
+        for (alias, target) in aliases {
+            info!("Version alias '{}' now points to {}", alias, target);
+            self.version_config.set_alias(alias, target);
+        }
+        
+        let latest = self.version_config.resolve_version("latest");
+        if self.version_docs.contains_key(&latest) {
+            self.default_version = latest;
+        }
+        
+        Ok(())
+    }
+    
+    /// Whether a version or alias names a loaded or configured version
+    pub fn is_known_version(&self, version: &str) -> bool {
+        let resolved = self.resolve_version(version);
+        self.version_docs.contains_key(&resolved) || self.version_config.is_version_available(&resolved)
+    }
+    
+    /// Resolve a requested version, rejecting unknown versions when strict validation is enabled
+    ///
+    /// In lenient mode unknown versions resolve unchanged and later fall back to the
+    /// default documentation. In strict mode they produce a parameter validation error
+    /// listing the available versions and the closest match.
+    pub fn validate_version(&self, version: &str) -> ArkaftResult<String> {
+        let resolved = self.resolve_version(version);
+        if !self.version_config.strict_versions || self.is_known_version(version) {
+            return Ok(resolved);
+        }
+        
+        let suggestion = self.closest_version(version)
+            .map(|closest| format!(" Did you mean '{}'?", closest))
+            .unwrap_or_default();
+        Err(ArkaftMcpError::parameter_validation(format!(
+            "Unknown ADK version '{}'.{} Available versions: {}",
+            version,
+            suggestion,
+            self.known_version_names().join(", ")
+        )))
+    }
+    
+    /// Known version or alias most similar to an unknown version string
+    pub fn closest_version(&self, version: &str) -> Option<String> {
+        let normalize = |name: &str| {
+            let name = name.trim().to_lowercase();
+            name.strip_prefix('v').map(str::to_string).unwrap_or(name)
+        };
+        let wanted = normalize(version);
+        
+        self.known_version_names()
+            .into_iter()
+            .map(|name| (bigram_similarity(&wanted, &normalize(&name)), name))
+            .filter(|(score, _)| *score > 0.0)
+            .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal).then_with(|| b.1.cmp(&a.1)))
+            .map(|(_, name)| name)
+    }
+    
+    /// Loaded versions, configured versions, and aliases, sorted and deduplicated
+    fn known_version_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.version_docs.keys()
+            .chain(self.version_config.available_versions.iter())
+            .chain(self.version_config.version_aliases.keys())
+            .cloned()
+            .collect();
+        names.sort_by(|a, b| compare_versions(a, b).then_with(|| a.cmp(b)));
+        names.dedup();
+        names
+    }
+    
+    /// Initialize default ADK concepts
+    fn initialize_default_concepts() -> HashMap<String, ConceptInfo> {
+        let mut concepts = HashMap::new();
+        
+        // Core ADK concepts - these will be expanded with actual Google ADK information
+        concepts.insert("application_development_kit".to_string(), ConceptInfo {
+            name: "Application Development Kit (ADK)".to_string(),
+            description: "Google's comprehensive toolkit for building applications with best practices and official patterns.".to_string(),
+            examples: vec![
                 "Basic ADK project setup".to_string(),
                 "ADK configuration patterns".to_string(),
             ],
             related_concepts: vec!["best_practices".to_string(), "architecture_patterns".to_string()],
             documentation_refs: vec!["https://google.github.io/adk-docs/get-started/quickstart/".to_string()],
+            source_url: None,
+            last_updated: None,
         });
         
         concepts.insert("best_practices".to_string(), ConceptInfo {
@@ -258,61 +1460,3115 @@ impl AdkKnowledgeBase {
             ],
             related_concepts: vec!["architecture_patterns".to_string(), "application_development_kit".to_string()],
             documentation_refs: vec!["https://google.github.io/adk-docs/get-started/quickstart/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.extend(Self::session_concepts());
+        concepts.extend(Self::callback_concepts());
+        concepts.extend(Self::deployment_concepts());
+        concepts.extend(Self::model_concepts());
+        concepts.extend(Self::a2a_concepts());
+        concepts.extend(Self::evaluation_concepts());
+        concepts.extend(Self::streaming_concepts());
+        concepts.extend(Self::mcp_concepts());
+        concepts.extend(Self::safety_concepts());
+        
+        concepts
+    }
+    
+    /// Initialize default best practices
+    fn initialize_default_best_practices() -> Vec<BestPractice> {
+        let mut practices = vec![
+            BestPractice {
+                title: "Follow Official ADK Patterns".to_string(),
+                description: "Always use official Google ADK architectural patterns and conventions.".to_string(),
+                category: "architecture".to_string(),
+                examples: vec![
+                    "Use recommended project structure".to_string(),
+                    "Follow naming conventions".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/get-started/quickstart/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Version-Aware Development".to_string(),
+                description: "Always specify and track ADK versions for consistent development.".to_string(),
+                category: "versioning".to_string(),
+                examples: vec![
+                    "Pin ADK version in configuration".to_string(),
+                    "Use version-specific features appropriately".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/get-started/quickstart/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+        ];
+        
+        practices.extend(Self::session_best_practices());
+        practices.extend(Self::callback_best_practices());
+        practices.extend(Self::deployment_best_practices());
+        practices.extend(Self::model_best_practices());
+        practices.extend(Self::a2a_best_practices());
+        practices.extend(Self::evaluation_best_practices());
+        practices.extend(Self::streaming_best_practices());
+        practices.extend(Self::mcp_best_practices());
+        practices.extend(Self::safety_best_practices());
+        practices.extend(Self::security_best_practices());
+        practices.extend(Self::performance_best_practices());
+        practices.extend(Self::tool_design_best_practices());
+        
+        practices
+    }
+    
+    /// Initialize default implementation patterns
+    fn initialize_default_patterns() -> HashMap<String, ImplementationPattern> {
+        let mut patterns = HashMap::new();
+        
+        patterns.insert("basic_setup".to_string(), ImplementationPattern {
+            name: "Basic ADK Setup".to_string(),
+            description: "Standard pattern for setting up a new ADK project.".to_string(),
+            use_cases: vec![
+                "New project initialization".to_string(),
+                "ADK integration into existing projects".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Basic Configuration".to_string(),
+                    language: "rust".to_string(),
+                    code: "// ADK setup example - to be populated with actual patterns".to_string(),
+                    explanation: "Basic ADK project configuration following official guidelines.".to_string(),
+                },
+            ],
+            related_practices: vec!["Follow Official ADK Patterns".to_string()],
+        });
+        
+        patterns.extend(Self::session_patterns());
+        patterns.extend(Self::callback_patterns());
+        patterns.extend(Self::deployment_patterns());
+        patterns.extend(Self::model_patterns());
+        patterns.extend(Self::a2a_patterns());
+        patterns.extend(Self::evaluation_patterns());
+        patterns.extend(Self::streaming_patterns());
+        patterns.extend(Self::mcp_patterns());
+        patterns.extend(Self::safety_patterns());
+        
+        patterns
+    }
+    
+    /// Initialize default version-specific features
+    fn initialize_default_features() -> Vec<VersionFeature> {
+        let mut features = Vec::new();
+        
+        features.extend(Self::streaming_features());
+        
+        features
+    }
+    
+    /// Initialize default glossary of ADK terminology
+    fn initialize_default_glossary() -> Vec<GlossaryEntry> {
+        let entries: [(&str, &str, Option<&str>); 26] = [
+            ("A2A", "The Agent2Agent protocol through which independently deployed agents discover and call each other.", Some("a2a_protocol")),
+            ("Agent", "A self-contained unit that uses a model, tools, and instructions to act toward a goal.", None),
+            ("Agent Card", "The JSON document an A2A server publishes to describe an agent's skills, capabilities, and endpoint.", Some("agent_card")),
+            ("Artifact", "A named, versioned binary blob (file, image, document) saved and loaded through an ArtifactService.", None),
+            ("Callback", "A user function invoked at a fixed point of agent, model, or tool execution to observe or override it.", Some("callbacks")),
+            ("Eval Set", "A file of eval cases, each a recorded conversation with expected tool calls and reference responses.", Some("eval_sets")),
+            ("Event", "An immutable record of one step in a conversation (user message, model response, tool call, or state change).", Some("session")),
+            ("Flow", "The internal LLM request/response loop of an LlmAgent that handles tool calls and agent transfers.", None),
+            ("Gemini", "Google's family of models used by ADK agents by name, through Google AI Studio or Vertex AI.", Some("gemini_models")),
+            ("Guardrail", "A check, usually a callback, that blocks or rewrites model requests, responses, or tool calls that violate policy.", Some("safety_guardrails")),
+            ("Invocation", "One end-to-end run of the agent system in response to a single user message.", None),
+            ("LiteLlm", "A model wrapper that runs ADK agents on non-Gemini providers through the LiteLLM library.", Some("litellm_integration")),
+            ("LiveRequestQueue", "The queue through which an application streams text, audio, or video into a live agent session.", Some("live_request_queue")),
+            ("MCP", "The Model Context Protocol, a standard through which servers expose tools and resources to LLM applications.", Some("mcp_tools")),
+            ("MCPToolset", "The toolset that connects an agent to an MCP server and adapts the server's tools into ADK tools.", Some("mcp_toolset")),
+            ("Memory", "Long-term, searchable knowledge spanning multiple sessions, managed by a MemoryService.", Some("memory_service")),
+            ("Planner", "A component that lets an agent plan multi-step reasoning before acting, such as BuiltInPlanner or PlanReActPlanner.", None),
+            ("RunConfig", "Per-run settings such as the streaming mode, response modalities, and LLM call limits.", Some("run_config")),
+            ("Runner", "The engine that executes an agent for a session, processing events and committing state changes.", None),
+            ("Session", "A single conversation thread holding its events and session-scoped state.", Some("session")),
+            ("SessionService", "The service that creates, stores, and retrieves sessions (in-memory, database, or Vertex AI).", Some("session_service")),
+            ("State", "A key-value scratchpad on a session whose key prefixes (user:, app:, temp:) control scope.", Some("session_state")),
+            ("Tool", "A capability exposed to an agent, such as a Python function, an OpenAPI operation, or another agent.", None),
+            ("Tool Confirmation", "A pause before a tool runs until a person approves the call, enabled with require_confirmation.", Some("tool_confirmation")),
+            ("Trajectory", "The sequence of tool calls an agent makes while answering, compared against expectations during evaluation.", Some("trajectory_evaluation")),
+            ("Workflow Agent", "An agent (SequentialAgent, ParallelAgent, LoopAgent) that orchestrates sub-agents deterministically.", None),
+        ];
+        
+        entries
+            .into_iter()
+            .map(|(term, definition, concept_ref)| GlossaryEntry {
+                term: term.to_string(),
+                definition: definition.to_string(),
+                concept_ref: concept_ref.map(|c| c.to_string()),
+            })
+            .collect()
+    }
+    
+    /// Concepts for sessions, state, and memory
+    fn session_concepts() -> HashMap<String, ConceptInfo> {
+        let mut concepts = HashMap::new();
+        
+        concepts.insert("session".to_string(), ConceptInfo {
+            name: "Session".to_string(),
+            description: "A Session tracks a single conversation thread between a user and an agent application. \
+                It holds the chronological list of Events, the session-scoped state dictionary, and identifiers \
+                (id, app_name, user_id, last_update_time). Sessions are created and retrieved through a SessionService.".to_string(),
+            examples: vec![
+                "session = await session_service.create_session(app_name=APP_NAME, user_id=USER_ID)".to_string(),
+                "session = await session_service.get_session(app_name=APP_NAME, user_id=USER_ID, session_id=SESSION_ID)".to_string(),
+            ],
+            related_concepts: vec!["session_state".to_string(), "session_service".to_string(), "memory_service".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/sessions/session/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("session_state".to_string(), ConceptInfo {
+            name: "Session State".to_string(),
+            description: "State is a key-value scratchpad attached to a session. Key prefixes control scope and persistence: \
+                no prefix is session-scoped, 'user:' is shared across all sessions of a user, 'app:' is shared across all \
+                users of the application, and 'temp:' lives only for the current invocation and is never persisted. \
+                State should only be changed through events (output_key, EventActions.state_delta, or context.state in callbacks and tools).".to_string(),
+            examples: vec![
+                "LlmAgent(name=\"greeter\", output_key=\"last_greeting\") saves the final response into state".to_string(),
+                "tool_context.state[\"user:preferred_language\"] = \"fr\" persists a per-user preference".to_string(),
+                "callback_context.state[\"temp:validation_passed\"] = True keeps a value for the current invocation only".to_string(),
+            ],
+            related_concepts: vec!["session".to_string(), "session_service".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/sessions/state/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("session_service".to_string(), ConceptInfo {
+            name: "SessionService".to_string(),
+            description: "The SessionService manages the lifecycle and persistence of sessions: create, get, list, delete, and \
+                append_event. ADK ships InMemorySessionService (no persistence, for local development and tests), \
+                DatabaseSessionService (SQL persistence via a database URL), and VertexAiSessionService \
+                (managed persistence backed by Vertex AI Agent Engine).".to_string(),
+            examples: vec![
+                "InMemorySessionService() for prototyping and unit tests".to_string(),
+                "DatabaseSessionService(db_url=\"sqlite:///./sessions.db\") for durable local or self-hosted storage".to_string(),
+                "VertexAiSessionService(project=PROJECT_ID, location=LOCATION) for deployments on Agent Engine".to_string(),
+            ],
+            related_concepts: vec!["session".to_string(), "session_state".to_string(), "memory_service".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/sessions/session/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("memory_service".to_string(), ConceptInfo {
+            name: "MemoryService".to_string(),
+            description: "Memory is long-term, searchable knowledge that spans multiple sessions. A MemoryService ingests \
+                completed sessions with add_session_to_memory and answers search_memory queries. ADK provides \
+                InMemoryMemoryService (keyword matching, no persistence) and Vertex AI backed services \
+                (VertexAiMemoryBankService, VertexAiRagMemoryService) for persistent, semantic recall. Agents read memory \
+                through the built-in load_memory and preload_memory tools.".to_string(),
+            examples: vec![
+                "await memory_service.add_session_to_memory(completed_session)".to_string(),
+                "LlmAgent(..., tools=[load_memory]) lets the model search past conversations on demand".to_string(),
+            ],
+            related_concepts: vec!["session".to_string(), "session_service".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/sessions/memory/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts
+    }
+    
+    /// Best practices for sessions, state, and memory
+    fn session_best_practices() -> Vec<BestPractice> {
+        vec![
+            BestPractice {
+                title: "Choose the Session Service for the Environment".to_string(),
+                description: "Use InMemorySessionService only for local development and tests, since all sessions are lost \
+                    on restart. Use DatabaseSessionService when you host the agent yourself and need durable sessions, and \
+                    VertexAiSessionService when deploying to Vertex AI Agent Engine.".to_string(),
+                category: "sessions".to_string(),
+                examples: vec![
+                    "Select the session service from configuration instead of hard-coding InMemorySessionService".to_string(),
+                    "Point DatabaseSessionService at a managed database (e.g. Cloud SQL) in production".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/sessions/session/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Update State Through Events".to_string(),
+                description: "Never mutate session.state on a session object retrieved from the SessionService directly; the change \
+                    bypasses event history and is not persisted. Use output_key, EventActions.state_delta, or context.state \
+                    inside callbacks and tools so that changes are recorded by append_event.".to_string(),
+                category: "sessions".to_string(),
+                examples: vec![
+                    "Set output_key on an LlmAgent to store its final response".to_string(),
+                    "Write to tool_context.state inside a tool instead of modifying the retrieved session".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/sessions/state/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Use State Prefixes Deliberately".to_string(),
+                description: "Pick the narrowest scope that works: unprefixed keys for the current conversation, 'user:' for \
+                    per-user preferences, 'app:' for global settings, and 'temp:' for intermediate values that must not be \
+                    persisted. Keep state values small and JSON-serializable.".to_string(),
+                category: "sessions".to_string(),
+                examples: vec![
+                    "Store the user's locale as 'user:locale'".to_string(),
+                    "Keep raw tool payloads under 'temp:' keys so they are not written to storage".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/sessions/state/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Separate Short-Term State from Long-Term Memory".to_string(),
+                description: "Session state is for the current conversation; cross-session recall belongs in a MemoryService. \
+                    Add completed sessions to memory explicitly and give agents load_memory or preload_memory rather than \
+                    copying old conversations into state.".to_string(),
+                category: "sessions".to_string(),
+                examples: vec![
+                    "Call add_session_to_memory when a conversation ends".to_string(),
+                    "Use a Vertex AI memory service when recall must survive restarts".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/sessions/memory/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+        ]
+    }
+    
+    /// Implementation patterns for sessions, state, and memory
+    fn session_patterns() -> HashMap<String, ImplementationPattern> {
+        let mut patterns = HashMap::new();
+        
+        patterns.insert("session_management".to_string(), ImplementationPattern {
+            name: "Session Management with a Runner".to_string(),
+            description: "Create a session service, open a session for a user, and run the agent against it so that \
+                events and state changes are persisted automatically.".to_string(),
+            use_cases: vec![
+                "Multi-turn conversations".to_string(),
+                "Resuming a conversation after a restart".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Runner with a database-backed session service".to_string(),
+                    language: "python".to_string(),
+                    code: r#"from google.adk.runners import Runner
+from google.adk.sessions import DatabaseSessionService
+
+session_service = DatabaseSessionService(db_url="sqlite:///./sessions.db")
+session = await session_service.create_session(app_name="my_app", user_id="user_1")
+runner = Runner(agent=root_agent, app_name="my_app", session_service=session_service)"#.to_string(),
+                    explanation: "Swap DatabaseSessionService for InMemorySessionService in tests, or VertexAiSessionService on Agent Engine.".to_string(),
+                },
+            ],
+            related_practices: vec!["Choose the Session Service for the Environment".to_string()],
+        });
+        
+        patterns.insert("state_management".to_string(), ImplementationPattern {
+            name: "Scoped State Updates".to_string(),
+            description: "Record state changes through events using prefixed keys so that each value has the intended \
+                scope and persistence.".to_string(),
+            use_cases: vec![
+                "Remembering user preferences across sessions".to_string(),
+                "Passing intermediate results between agents in a workflow".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Writing state from a tool".to_string(),
+                    language: "python".to_string(),
+                    code: r#"from google.adk.tools import ToolContext
+
+def set_language(language: str, tool_context: ToolContext) -> dict:
+    tool_context.state["user:preferred_language"] = language
+    tool_context.state["temp:last_tool"] = "set_language"
+    return {"status": "ok"}"#.to_string(),
+                    explanation: "Changes made through tool_context.state are captured in the event's state_delta and persisted by the session service.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Update State Through Events".to_string(),
+                "Use State Prefixes Deliberately".to_string(),
+            ],
+        });
+        
+        patterns.insert("long_term_memory".to_string(), ImplementationPattern {
+            name: "Long-Term Memory".to_string(),
+            description: "Ingest finished sessions into a MemoryService and expose the load_memory tool so agents can \
+                recall information from earlier conversations.".to_string(),
+            use_cases: vec![
+                "Personal assistants that remember past requests".to_string(),
+                "Support agents that reference earlier tickets".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Memory-enabled agent".to_string(),
+                    language: "python".to_string(),
+                    code: r#"from google.adk.agents import LlmAgent
+from google.adk.memory import InMemoryMemoryService
+from google.adk.tools import load_memory
+
+memory_service = InMemoryMemoryService()
+agent = LlmAgent(name="assistant", model="gemini-2.0-flash", tools=[load_memory])
+runner = Runner(agent=agent, app_name="my_app", session_service=session_service, memory_service=memory_service)
+
+# After a conversation completes
+await memory_service.add_session_to_memory(completed_session)"#.to_string(),
+                    explanation: "InMemoryMemoryService is suitable for prototypes; use a Vertex AI memory service for persistent semantic search.".to_string(),
+                },
+            ],
+            related_practices: vec!["Separate Short-Term State from Long-Term Memory".to_string()],
+        });
+        
+        patterns
+    }
+    
+    /// Concepts for callbacks and lifecycle hooks
+    fn callback_concepts() -> HashMap<String, ConceptInfo> {
+        let mut concepts = HashMap::new();
+        
+        concepts.insert("callbacks".to_string(), ConceptInfo {
+            name: "Callbacks".to_string(),
+            description: "Callbacks are user functions that ADK invokes at fixed points of an agent's execution. They are \
+                registered on the agent when it is created and receive a context object (CallbackContext or ToolContext) \
+                giving access to state, artifacts, and invocation details. Returning None lets the default behavior \
+                continue; returning a value overrides or skips the step being intercepted.".to_string(),
+            examples: vec![
+                "Input and output guardrails".to_string(),
+                "Logging and tracing of model and tool calls".to_string(),
+                "Caching responses to skip redundant model calls".to_string(),
+            ],
+            related_concepts: vec![
+                "agent_callbacks".to_string(),
+                "model_callbacks".to_string(),
+                "tool_callbacks".to_string(),
+            ],
+            documentation_refs: vec!["https://google.github.io/adk-docs/callbacks/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("agent_callbacks".to_string(), ConceptInfo {
+            name: "Agent Lifecycle Callbacks".to_string(),
+            description: "before_agent_callback runs before an agent's main logic and after_agent_callback runs after it \
+                completes. Both receive a CallbackContext. Returning types.Content from before_agent_callback skips the \
+                agent run and uses that content as its response; returning content from after_agent_callback replaces \
+                the agent's output.".to_string(),
+            examples: vec![
+                "Skip an agent when a required state key is missing".to_string(),
+                "Append a standard disclaimer to every agent response".to_string(),
+            ],
+            related_concepts: vec!["callbacks".to_string(), "session_state".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/callbacks/types-of-callbacks/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("model_callbacks".to_string(), ConceptInfo {
+            name: "Model Callbacks".to_string(),
+            description: "before_model_callback(callback_context, llm_request) runs before each LLM call and can inspect or \
+                modify the request; returning an LlmResponse skips the model call entirely. \
+                after_model_callback(callback_context, llm_response) runs after the model responds and can modify or \
+                replace the response.".to_string(),
+            examples: vec![
+                "Block requests containing disallowed content before they reach the model".to_string(),
+                "Inject dynamic instructions into llm_request.config.system_instruction".to_string(),
+                "Redact sensitive data from model responses".to_string(),
+            ],
+            related_concepts: vec!["callbacks".to_string(), "tool_callbacks".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/callbacks/types-of-callbacks/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("tool_callbacks".to_string(), ConceptInfo {
+            name: "Tool Callbacks".to_string(),
+            description: "before_tool_callback(tool, args, tool_context) runs before a tool executes and can validate or \
+                rewrite the arguments; returning a dict skips the tool and uses the dict as its result. \
+                after_tool_callback(tool, args, tool_context, tool_response) runs after the tool and can post-process \
+                or replace the result.".to_string(),
+            examples: vec![
+                "Enforce argument policies such as allowed regions or account ids".to_string(),
+                "Normalize tool output before the model sees it".to_string(),
+            ],
+            related_concepts: vec!["callbacks".to_string(), "model_callbacks".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/callbacks/types-of-callbacks/".to_string()],
+            source_url: None,
+            last_updated: None,
         });
         
         concepts
     }
     
-    /// Initialize default best practices
-    fn initialize_default_best_practices() -> Vec<BestPractice> {
+    /// Best practices for callbacks and lifecycle hooks
+    fn callback_best_practices() -> Vec<BestPractice> {
+        vec![
+            BestPractice {
+                title: "Keep Callbacks Focused and Fast".to_string(),
+                description: "Each callback should do one thing (validate, log, or transform) and avoid long-running or \
+                    blocking work, because callbacks run synchronously inside the agent loop and add latency to every \
+                    model or tool call.".to_string(),
+                category: "callbacks".to_string(),
+                examples: vec![
+                    "Use separate callbacks for logging and guardrails instead of one large function".to_string(),
+                    "Move expensive lookups into tools or cache them in state".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/callbacks/design-patterns-and-best-practices/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Return None Unless Overriding".to_string(),
+                description: "The return value of a callback controls the flow. Return None to continue with default behavior \
+                    and only return a value (Content, LlmResponse, or dict) when you intend to skip or replace the step.".to_string(),
+                category: "callbacks".to_string(),
+                examples: vec![
+                    "Return an LlmResponse from before_model_callback only when blocking a request".to_string(),
+                    "Return a dict from before_tool_callback to serve a cached tool result".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/callbacks/types-of-callbacks/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Handle Errors Inside Callbacks".to_string(),
+                description: "Wrap callback logic in error handling and log failures. An uncaught exception in a callback \
+                    aborts the invocation, so a logging callback should never be able to break the agent.".to_string(),
+                category: "callbacks".to_string(),
+                examples: vec![
+                    "Catch and log exceptions in telemetry callbacks".to_string(),
+                    "Fail closed in guardrail callbacks by returning a safe response".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/callbacks/design-patterns-and-best-practices/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Use Context State for Callback Coordination".to_string(),
+                description: "Share data between callbacks and tools through callback_context.state or tool_context.state, \
+                    using the 'temp:' prefix for values that only matter during the current invocation.".to_string(),
+                category: "callbacks".to_string(),
+                examples: vec![
+                    "Record a guardrail decision in 'temp:guardrail_triggered' for later callbacks".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/callbacks/design-patterns-and-best-practices/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+        ]
+    }
+    
+    /// Implementation patterns for callbacks and lifecycle hooks
+    fn callback_patterns() -> HashMap<String, ImplementationPattern> {
+        let mut patterns = HashMap::new();
+        
+        patterns.insert("input_guardrail_callback".to_string(), ImplementationPattern {
+            name: "Input Guardrail with before_model_callback".to_string(),
+            description: "Inspect the outgoing LLM request and short-circuit with a canned response when it violates a \
+                policy, so the model is never called.".to_string(),
+            use_cases: vec![
+                "Blocking prohibited topics or keywords".to_string(),
+                "Rejecting requests that contain sensitive data".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Keyword guardrail".to_string(),
+                    language: "python".to_string(),
+                    code: r#"from typing import Optional
+from google.adk.agents.callback_context import CallbackContext
+from google.adk.models import LlmRequest, LlmResponse
+from google.genai import types
+
+def block_keyword_guardrail(
+    callback_context: CallbackContext, llm_request: LlmRequest
+) -> Optional[LlmResponse]:
+    last_text = llm_request.contents[-1].parts[0].text or ""
+    if "BLOCK" in last_text.upper():
+        callback_context.state["temp:guardrail_triggered"] = True
+        return LlmResponse(
+            content=types.Content(role="model", parts=[types.Part(text="I cannot process that request.")])
+        )
+    return None
+
+agent = LlmAgent(name="guarded", model="gemini-2.0-flash", before_model_callback=block_keyword_guardrail)"#.to_string(),
+                    explanation: "Returning an LlmResponse skips the model call; returning None lets the request proceed.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Return None Unless Overriding".to_string(),
+                "Use Context State for Callback Coordination".to_string(),
+            ],
+        });
+        
+        patterns.insert("tool_argument_validation".to_string(), ImplementationPattern {
+            name: "Tool Argument Validation with before_tool_callback".to_string(),
+            description: "Validate or rewrite tool arguments before execution and return an error result instead of \
+                running the tool when the arguments are not allowed.".to_string(),
+            use_cases: vec![
+                "Enforcing allow-lists on tool inputs".to_string(),
+                "Serving cached tool results".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Argument policy check".to_string(),
+                    language: "python".to_string(),
+                    code: r#"from typing import Any, Dict, Optional
+from google.adk.tools.base_tool import BaseTool
+from google.adk.tools.tool_context import ToolContext
+
+def validate_city(tool: BaseTool, args: Dict[str, Any], tool_context: ToolContext) -> Optional[Dict]:
+    if tool.name == "get_weather" and args.get("city", "").lower() == "paris":
+        return {"status": "error", "error_message": "Policy restriction: this city is not supported."}
+    return None
+
+agent = LlmAgent(name="weather", model="gemini-2.0-flash", tools=[get_weather], before_tool_callback=validate_city)"#.to_string(),
+                    explanation: "The returned dict is used as the tool result, so the model receives a structured error.".to_string(),
+                },
+            ],
+            related_practices: vec!["Return None Unless Overriding".to_string()],
+        });
+        
+        patterns.insert("callback_logging".to_string(), ImplementationPattern {
+            name: "Observability with Lifecycle Callbacks".to_string(),
+            description: "Log agent, model, and tool activity from callbacks without changing the agent's behavior.".to_string(),
+            use_cases: vec![
+                "Debugging agent behavior".to_string(),
+                "Collecting latency and token usage metrics".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Logging model responses".to_string(),
+                    language: "python".to_string(),
+                    code: r#"import logging
+from typing import Optional
+from google.adk.agents.callback_context import CallbackContext
+from google.adk.models import LlmResponse
+
+logger = logging.getLogger(__name__)
+
+def log_model_response(callback_context: CallbackContext, llm_response: LlmResponse) -> Optional[LlmResponse]:
+    try:
+        logger.info("agent=%s usage=%s", callback_context.agent_name, llm_response.usage_metadata)
+    except Exception:
+        logger.exception("logging callback failed")
+    return None
+
+agent = LlmAgent(name="observed", model="gemini-2.0-flash", after_model_callback=log_model_response)"#.to_string(),
+                    explanation: "The callback always returns None so the original response is kept, and never raises.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Keep Callbacks Focused and Fast".to_string(),
+                "Handle Errors Inside Callbacks".to_string(),
+            ],
+        });
+        
+        patterns
+    }
+    
+    /// Concepts for deploying ADK agents
+    fn deployment_concepts() -> HashMap<String, ConceptInfo> {
+        let mut concepts = HashMap::new();
+        
+        concepts.insert("agent_engine_deployment".to_string(), ConceptInfo {
+            name: "Vertex AI Agent Engine Deployment".to_string(),
+            description: "Agent Engine is a fully managed Google Cloud service for running ADK agents. The agent is wrapped \
+                in an AdkApp and created with agent_engines.create, which packages the code and its requirements, \
+                provisions serving infrastructure, and scales automatically. Sessions and memory can be backed by the \
+                managed VertexAiSessionService and Vertex AI memory services, and tracing is exported to Cloud Trace.".to_string(),
+            examples: vec![
+                "app = reasoning_engines.AdkApp(agent=root_agent, enable_tracing=True)".to_string(),
+                "remote_app = agent_engines.create(agent_engine=app, requirements=[\"google-cloud-aiplatform[adk,agent_engines]\"])".to_string(),
+            ],
+            related_concepts: vec!["cloud_run_deployment".to_string(), "gke_deployment".to_string(), "session_service".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/deploy/agent-engine/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("cloud_run_deployment".to_string(), ConceptInfo {
+            name: "Cloud Run Deployment".to_string(),
+            description: "Cloud Run runs the agent as a containerized, autoscaling HTTP service. The adk deploy cloud_run \
+                command builds the container and deploys it, optionally with the development UI; alternatively a \
+                FastAPI app from get_fast_api_app can be deployed with a custom Dockerfile via gcloud run deploy. \
+                Instances are stateless, so sessions must use a persistent session service.".to_string(),
+            examples: vec![
+                "adk deploy cloud_run --project $GOOGLE_CLOUD_PROJECT --region $GOOGLE_CLOUD_LOCATION --service_name my-agent ./my_agent".to_string(),
+                "gcloud run deploy my-agent --source . --region $GOOGLE_CLOUD_LOCATION".to_string(),
+            ],
+            related_concepts: vec!["agent_engine_deployment".to_string(), "gke_deployment".to_string(), "session_service".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/deploy/cloud-run/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("gke_deployment".to_string(), ConceptInfo {
+            name: "Google Kubernetes Engine (GKE) Deployment".to_string(),
+            description: "GKE gives full control over how the agent container runs: build an image (for example with \
+                adk deploy gke or Cloud Build), push it to Artifact Registry, and apply a Deployment and Service manifest. \
+                Use Workload Identity for Google Cloud credentials and a HorizontalPodAutoscaler for scaling.".to_string(),
+            examples: vec![
+                "adk deploy gke --project $GOOGLE_CLOUD_PROJECT --cluster_name my-cluster --region $GOOGLE_CLOUD_LOCATION ./my_agent".to_string(),
+                "kubectl apply -f deployment.yaml".to_string(),
+            ],
+            related_concepts: vec!["cloud_run_deployment".to_string(), "agent_engine_deployment".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/deploy/gke/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts
+    }
+    
+    /// Best practices for deploying ADK agents
+    fn deployment_best_practices() -> Vec<BestPractice> {
+        vec![
+            BestPractice {
+                title: "Choose a Deployment Target by Operational Needs".to_string(),
+                description: "Use Agent Engine for a fully managed runtime with built-in sessions and tracing, Cloud Run for \
+                    a serverless container with minimal operations, and GKE when you need custom networking, sidecars, \
+                    GPUs, or fine-grained control over scaling.".to_string(),
+                category: "deployment".to_string(),
+                examples: vec![
+                    "Start on Agent Engine or Cloud Run and move to GKE only when a concrete requirement demands it".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/deploy/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Package Agents with Pinned Dependencies".to_string(),
+                description: "Declare every runtime dependency with a pinned version (requirements list for Agent Engine, \
+                    requirements.txt or the container image for Cloud Run and GKE) so the deployed agent matches what \
+                    was tested locally.".to_string(),
+                category: "deployment".to_string(),
+                examples: vec![
+                    "Pin google-adk in requirements.txt".to_string(),
+                    "Pass an explicit requirements list to agent_engines.create".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/deploy/agent-engine/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Configure Through Environment Variables and Secrets".to_string(),
+                description: "Set GOOGLE_CLOUD_PROJECT, GOOGLE_CLOUD_LOCATION, and GOOGLE_GENAI_USE_VERTEXAI as environment \
+                    variables on the deployment, and keep API keys and database credentials in Secret Manager (or \
+                    Kubernetes secrets) rather than in the image or source code.".to_string(),
+                category: "deployment".to_string(),
+                examples: vec![
+                    "gcloud run deploy --set-env-vars GOOGLE_GENAI_USE_VERTEXAI=TRUE".to_string(),
+                    "Mount the session database URL from Secret Manager".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/deploy/cloud-run/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Use Persistent Sessions on Scaled Deployments".to_string(),
+                description: "Cloud Run and GKE can run many instances and replace them at any time, so InMemorySessionService \
+                    loses conversations. Use DatabaseSessionService or VertexAiSessionService whenever more than one \
+                    instance may serve a user.".to_string(),
+                category: "deployment".to_string(),
+                examples: vec![
+                    "Configure --session_service_uri when deploying with the ADK CLI".to_string(),
+                    "Set Cloud Run min-instances and max-instances explicitly".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/sessions/session/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Enable Tracing and Structured Logging".to_string(),
+                description: "Turn on Cloud Trace export (enable_tracing on AdkApp, --trace_to_cloud with the ADK CLI) and write \
+                    structured logs to Cloud Logging so model calls, tool calls, and latency can be inspected in production.".to_string(),
+                category: "deployment".to_string(),
+                examples: vec![
+                    "AdkApp(agent=root_agent, enable_tracing=True)".to_string(),
+                    "adk deploy cloud_run --trace_to_cloud ...".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/deploy/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Scale GKE Agents with Workload Identity and Autoscaling".to_string(),
+                description: "On GKE, bind the Kubernetes service account to a Google service account with Workload Identity \
+                    instead of mounting key files, set resource requests and limits, and use a HorizontalPodAutoscaler \
+                    to follow request load.".to_string(),
+                category: "deployment".to_string(),
+                examples: vec![
+                    "kubectl autoscale deployment my-agent --cpu-percent=70 --min=2 --max=10".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/deploy/gke/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+        ]
+    }
+    
+    /// Implementation patterns for deploying ADK agents
+    fn deployment_patterns() -> HashMap<String, ImplementationPattern> {
+        let mut patterns = HashMap::new();
+        
+        patterns.insert("deploy_agent_engine".to_string(), ImplementationPattern {
+            name: "Deploy to Vertex AI Agent Engine".to_string(),
+            description: "Wrap the root agent in an AdkApp and create a managed Agent Engine instance.".to_string(),
+            use_cases: vec![
+                "Managed production hosting with minimal operations".to_string(),
+                "Agents that need managed sessions and memory".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Creating an Agent Engine deployment".to_string(),
+                    language: "python".to_string(),
+                    code: r#"import vertexai
+from vertexai import agent_engines
+from vertexai.preview import reasoning_engines
+
+vertexai.init(project=PROJECT_ID, location=LOCATION, staging_bucket=STAGING_BUCKET)
+
+app = reasoning_engines.AdkApp(agent=root_agent, enable_tracing=True)
+remote_app = agent_engines.create(
+    agent_engine=app,
+    requirements=["google-cloud-aiplatform[adk,agent_engines]"],
+)"#.to_string(),
+                    explanation: "Agent Engine scales the deployment automatically; enable_tracing exports spans to Cloud Trace.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Package Agents with Pinned Dependencies".to_string(),
+                "Enable Tracing and Structured Logging".to_string(),
+            ],
+        });
+        
+        patterns.insert("deploy_cloud_run".to_string(), ImplementationPattern {
+            name: "Deploy to Cloud Run".to_string(),
+            description: "Deploy the agent as an autoscaling container with the ADK CLI, backed by a persistent session service.".to_string(),
+            use_cases: vec![
+                "Serverless HTTP APIs for agents".to_string(),
+                "Sharing an agent with the development UI".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "ADK CLI deployment".to_string(),
+                    language: "bash".to_string(),
+                    code: r#"export GOOGLE_CLOUD_PROJECT=my-project
+export GOOGLE_CLOUD_LOCATION=us-central1
+export GOOGLE_GENAI_USE_VERTEXAI=True
+
+adk deploy cloud_run     --project=$GOOGLE_CLOUD_PROJECT     --region=$GOOGLE_CLOUD_LOCATION     --service_name=my-agent-service     --session_service_uri=$SESSION_DB_URL     --trace_to_cloud     ./my_agent"#.to_string(),
+                    explanation: "Add --with_ui to also serve the development UI. Tune concurrency and min/max instances on the service afterwards.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Configure Through Environment Variables and Secrets".to_string(),
+                "Use Persistent Sessions on Scaled Deployments".to_string(),
+            ],
+        });
+        
+        patterns.insert("deploy_gke".to_string(), ImplementationPattern {
+            name: "Deploy to GKE".to_string(),
+            description: "Run the agent container on GKE with Workload Identity, resource limits, and horizontal autoscaling.".to_string(),
+            use_cases: vec![
+                "Agents that need custom networking or sidecars".to_string(),
+                "Teams already operating Kubernetes platforms".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Deployment manifest".to_string(),
+                    language: "yaml".to_string(),
+                    code: r#"apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: adk-agent
+spec:
+  replicas: 2
+  selector:
+    matchLabels:
+      app: adk-agent
+  template:
+    metadata:
+      labels:
+        app: adk-agent
+    spec:
+      serviceAccount: adk-agent-sa
+      containers:
+        - name: adk-agent
+          image: us-central1-docker.pkg.dev/my-project/adk-repo/adk-agent:latest
+          ports:
+            - containerPort: 8080
+          env:
+            - name: GOOGLE_CLOUD_PROJECT
+              value: my-project
+            - name: GOOGLE_CLOUD_LOCATION
+              value: us-central1
+            - name: GOOGLE_GENAI_USE_VERTEXAI
+              value: "true"
+          resources:
+            requests:
+              cpu: 500m
+              memory: 1Gi
+            limits:
+              memory: 2Gi"#.to_string(),
+                    explanation: "Pair the Deployment with a Service and a HorizontalPodAutoscaler; adk-agent-sa is bound to a Google service account through Workload Identity.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Scale GKE Agents with Workload Identity and Autoscaling".to_string(),
+                "Configure Through Environment Variables and Secrets".to_string(),
+            ],
+        });
+        
+        patterns
+    }
+    
+    /// Concepts for configuring Gemini and other models
+    fn model_concepts() -> HashMap<String, ConceptInfo> {
+        let mut concepts = HashMap::new();
+        
+        concepts.insert("gemini_models".to_string(), ConceptInfo {
+            name: "Gemini Models".to_string(),
+            description: "An LlmAgent names its Gemini model with a plain string such as \"gemini-2.0-flash\". ADK calls the \
+                model through the google-genai SDK, either on Google AI Studio (GOOGLE_API_KEY) or on Vertex AI \
+                (GOOGLE_GENAI_USE_VERTEXAI=TRUE with GOOGLE_CLOUD_PROJECT and GOOGLE_CLOUD_LOCATION). Generation \
+                settings such as temperature, max_output_tokens, and safety settings are passed through \
+                generate_content_config.".to_string(),
+            examples: vec![
+                "LlmAgent(name=\"assistant\", model=\"gemini-2.0-flash\", instruction=\"...\")".to_string(),
+                "generate_content_config=types.GenerateContentConfig(temperature=0.2, max_output_tokens=1024)".to_string(),
+            ],
+            related_concepts: vec!["litellm_integration".to_string(), "model_selection".to_string(), "model_quotas".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/agents/models/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("litellm_integration".to_string(), ConceptInfo {
+            name: "LiteLLM Model Integration".to_string(),
+            description: "The LiteLlm wrapper lets an agent use models from other providers (OpenAI, Anthropic, Ollama, and \
+                more) through the LiteLLM library. Pass LiteLlm(model=\"provider/model-name\") as the agent's model and \
+                supply the provider's API key through its usual environment variable.".to_string(),
+            examples: vec![
+                "LlmAgent(model=LiteLlm(model=\"openai/gpt-4o\"), ...)".to_string(),
+                "LlmAgent(model=LiteLlm(model=\"ollama_chat/gemma3\"), ...) for a locally served model".to_string(),
+            ],
+            related_concepts: vec!["gemini_models".to_string(), "model_selection".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/agents/models/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("model_selection".to_string(), ConceptInfo {
+            name: "Model Selection Trade-offs".to_string(),
+            description: "Each agent can use a different model. Flash-class models give low latency and cost for routing, \
+                extraction, and tool-heavy steps, while Pro-class models handle complex reasoning and long context at \
+                higher cost and latency. Multi-agent systems often route with a fast model and delegate hard steps to \
+                a stronger one.".to_string(),
+            examples: vec![
+                "A gemini-2.0-flash coordinator delegating analysis to a gemini-2.5-pro sub-agent".to_string(),
+                "Switching models per environment through configuration rather than code".to_string(),
+            ],
+            related_concepts: vec!["gemini_models".to_string(), "litellm_integration".to_string(), "model_quotas".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/agents/models/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("model_quotas".to_string(), ConceptInfo {
+            name: "Model Quotas and Rate Limits".to_string(),
+            description: "Model endpoints enforce per-project quotas on requests and tokens. Exceeding them returns \
+                429 RESOURCE_EXHAUSTED errors, which agents should absorb with retries and exponential backoff, and \
+                which production deployments avoid by requesting quota increases or provisioned throughput on Vertex AI.".to_string(),
+            examples: vec![
+                "Gemini(model=\"gemini-2.0-flash\", retry_options=types.HttpRetryOptions(initial_delay=1, attempts=3))".to_string(),
+                "Capping max_output_tokens to reduce token consumption".to_string(),
+            ],
+            related_concepts: vec!["gemini_models".to_string(), "model_selection".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/agents/models/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts
+    }
+    
+    /// Best practices for configuring Gemini and other models
+    fn model_best_practices() -> Vec<BestPractice> {
+        vec![
+            BestPractice {
+                title: "Match the Model to the Agent's Task".to_string(),
+                description: "Choose models per agent: fast, inexpensive Flash-class models for routing, classification, and \
+                    tool orchestration, and Pro-class models only for steps that need deep reasoning or long context.".to_string(),
+                category: "models".to_string(),
+                examples: vec![
+                    "Use gemini-2.0-flash for a coordinator and a Pro model for a planning sub-agent".to_string(),
+                    "Measure quality with evaluations before upgrading to a larger model".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/agents/models/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Keep Model Names and Credentials in Configuration".to_string(),
+                description: "Read model names from environment variables or settings and provide credentials through \
+                    GOOGLE_API_KEY, Vertex AI application default credentials, or provider keys for LiteLLM, so models \
+                    and backends can change without code edits and keys never live in source.".to_string(),
+                category: "models".to_string(),
+                examples: vec![
+                    "model=os.getenv(\"AGENT_MODEL\", \"gemini-2.0-flash\")".to_string(),
+                    "Set GOOGLE_GENAI_USE_VERTEXAI=TRUE in production and FALSE for AI Studio development".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/agents/models/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Tune Generation Settings Explicitly".to_string(),
+                description: "Set temperature, max_output_tokens, and safety settings through generate_content_config instead \
+                    of relying on defaults, using low temperatures for deterministic tool use and extraction.".to_string(),
+                category: "models".to_string(),
+                examples: vec![
+                    "types.GenerateContentConfig(temperature=0.1, max_output_tokens=512) for a data extraction agent".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/agents/llm-agents/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Handle Quota Errors with Retries and Backoff".to_string(),
+                description: "Treat 429 RESOURCE_EXHAUSTED responses as expected under load: configure retries with \
+                    exponential backoff, bound output tokens, and request quota increases or provisioned throughput \
+                    before launch instead of letting user requests fail.".to_string(),
+                category: "models".to_string(),
+                examples: vec![
+                    "Gemini(model=..., retry_options=types.HttpRetryOptions(initial_delay=1, attempts=3))".to_string(),
+                    "Monitor quota usage in the Google Cloud console".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/agents/models/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Verify Tool Calling Support for Non-Gemini Models".to_string(),
+                description: "When using LiteLlm, confirm the chosen provider and model support function calling (and, for \
+                    Ollama, use the ollama_chat provider) before relying on tools, because unsupported models ignore or \
+                    garble tool calls.".to_string(),
+                category: "models".to_string(),
+                examples: vec![
+                    "LiteLlm(model=\"ollama_chat/gemma3\") instead of ollama/gemma3 for tool use".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/agents/models/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+        ]
+    }
+    
+    /// Implementation patterns for configuring Gemini and other models
+    fn model_patterns() -> HashMap<String, ImplementationPattern> {
+        let mut patterns = HashMap::new();
+        
+        patterns.insert("gemini_model_configuration".to_string(), ImplementationPattern {
+            name: "Configuring a Gemini Model".to_string(),
+            description: "Select a Gemini model by name and tune its generation settings on the agent.".to_string(),
+            use_cases: vec![
+                "Deterministic extraction or tool-calling agents".to_string(),
+                "Switching between AI Studio and Vertex AI backends".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Model name and generation config".to_string(),
+                    language: "python".to_string(),
+                    code: r#"import os
+from google.adk.agents import LlmAgent
+from google.genai import types
+
+agent = LlmAgent(
+    name="extractor",
+    model=os.getenv("AGENT_MODEL", "gemini-2.0-flash"),
+    instruction="Extract the order id and amount from the user's message.",
+    generate_content_config=types.GenerateContentConfig(
+        temperature=0.1,
+        max_output_tokens=512,
+    ),
+)"#.to_string(),
+                    explanation: "GOOGLE_GENAI_USE_VERTEXAI selects the backend; the agent code is the same for AI Studio and Vertex AI.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Keep Model Names and Credentials in Configuration".to_string(),
+                "Tune Generation Settings Explicitly".to_string(),
+            ],
+        });
+        
+        patterns.insert("litellm_model".to_string(), ImplementationPattern {
+            name: "Using Other Providers through LiteLLM".to_string(),
+            description: "Wrap a provider model in LiteLlm to run an ADK agent on OpenAI, Anthropic, or a local Ollama model.".to_string(),
+            use_cases: vec![
+                "Comparing providers for the same agent".to_string(),
+                "Running agents against local models during development".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "LiteLlm-backed agent".to_string(),
+                    language: "python".to_string(),
+                    code: r#"# pip install litellm; export OPENAI_API_KEY=...
+from google.adk.agents import LlmAgent
+from google.adk.models.lite_llm import LiteLlm
+
+agent = LlmAgent(
+    name="openai_agent",
+    model=LiteLlm(model="openai/gpt-4o"),
+    instruction="You are a helpful assistant.",
+)
+
+local_agent = LlmAgent(
+    name="local_agent",
+    model=LiteLlm(model="ollama_chat/gemma3"),
+    instruction="You are a helpful assistant.",
+)"#.to_string(),
+                    explanation: "The provider prefix selects the LiteLLM backend; credentials come from the provider's environment variables.".to_string(),
+                },
+            ],
+            related_practices: vec!["Verify Tool Calling Support for Non-Gemini Models".to_string()],
+        });
+        
+        patterns.insert("model_retry_on_quota".to_string(), ImplementationPattern {
+            name: "Retrying Model Calls on Quota Errors".to_string(),
+            description: "Configure HTTP retries with backoff on the Gemini model so transient 429 errors do not fail the invocation.".to_string(),
+            use_cases: vec![
+                "Agents serving bursty traffic".to_string(),
+                "Batch evaluations that hit per-minute quotas".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Gemini model with retry options".to_string(),
+                    language: "python".to_string(),
+                    code: r#"from google.adk.agents import LlmAgent
+from google.adk.models import Gemini
+from google.genai import types
+
+agent = LlmAgent(
+    name="resilient_agent",
+    model=Gemini(
+        model="gemini-2.0-flash",
+        retry_options=types.HttpRetryOptions(initial_delay=1, attempts=3),
+    ),
+    instruction="Answer the user's question.",
+)"#.to_string(),
+                    explanation: "Retries back off exponentially from initial_delay; persistent quota errors still need higher quota or provisioned throughput.".to_string(),
+                },
+            ],
+            related_practices: vec!["Handle Quota Errors with Retries and Backoff".to_string()],
+        });
+        
+        patterns
+    }
+    
+    /// Concepts for agent-to-agent (A2A) interoperability
+    fn a2a_concepts() -> HashMap<String, ConceptInfo> {
+        let mut concepts = HashMap::new();
+        
+        concepts.insert("a2a_protocol".to_string(), ConceptInfo {
+            name: "Agent2Agent (A2A) Protocol".to_string(),
+            description: "A2A is an open protocol for agents running as separate services to discover each other and \
+                exchange tasks and messages over HTTP. ADK agents can be exposed as A2A servers and can call remote A2A \
+                agents as if they were local sub-agents, regardless of the framework or language the remote agent uses. \
+                A2A support is installed with the google-adk[a2a] extra.".to_string(),
+            examples: vec![
+                "A support agent delegating billing questions to a billing team's separately deployed agent".to_string(),
+                "An ADK coordinator calling an agent built with another framework".to_string(),
+            ],
+            related_concepts: vec!["remote_a2a_agent".to_string(), "agent_card".to_string(), "a2a_vs_sub_agents".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/a2a/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("remote_a2a_agent".to_string(), ConceptInfo {
+            name: "Remote A2A Agents".to_string(),
+            description: "RemoteA2aAgent is a client-side proxy for an agent served over A2A. It is configured with the URL \
+                of the remote agent card and can be listed in sub_agents or wrapped as a tool; ADK sends the conversation \
+                to the remote agent and turns its replies back into events. On the serving side, to_a2a(root_agent) or \
+                adk api_server --a2a exposes an existing agent without code changes.".to_string(),
+            examples: vec![
+                "RemoteA2aAgent(name=\"billing\", agent_card=\"http://billing:8001/.well-known/agent.json\")".to_string(),
+                "a2a_app = to_a2a(root_agent, port=8001)".to_string(),
+            ],
+            related_concepts: vec!["a2a_protocol".to_string(), "agent_card".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/a2a/quickstart-consuming/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("agent_card".to_string(), ConceptInfo {
+            name: "Agent Cards".to_string(),
+            description: "An agent card is the JSON document an A2A server publishes at a well-known path describing the \
+                agent: its name, description, URL, capabilities, skills, and supported input and output modes. Clients \
+                read the card to discover what the agent can do and how to reach it.".to_string(),
+            examples: vec![
+                "GET http://localhost:8001/.well-known/agent.json".to_string(),
+                "Skills listing the tasks a remote agent accepts".to_string(),
+            ],
+            related_concepts: vec!["a2a_protocol".to_string(), "remote_a2a_agent".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/a2a/quickstart-exposing/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("a2a_vs_sub_agents".to_string(), ConceptInfo {
+            name: "A2A vs Local Sub-Agents".to_string(),
+            description: "Use local sub-agents when agents run in the same process, share session state, and are owned by \
+                one team, since calls are fast and state flows directly. Use A2A when an agent is deployed as its own \
+                service, is owned by another team, is written in another framework or language, or needs a formal, \
+                versioned contract; the network hop adds latency and state must be passed explicitly.".to_string(),
+            examples: vec![
+                "Sub-agents for steps of one workflow inside a single deployment".to_string(),
+                "A2A for calling an agent operated by a partner organization".to_string(),
+            ],
+            related_concepts: vec!["a2a_protocol".to_string(), "remote_a2a_agent".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/a2a/intro/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts
+    }
+    
+    /// Best practices for agent-to-agent (A2A) interoperability
+    fn a2a_best_practices() -> Vec<BestPractice> {
+        vec![
+            BestPractice {
+                title: "Prefer Sub-Agents Within One Deployment".to_string(),
+                description: "Keep agents that share a process, session state, and owner as local sub-agents, and introduce \
+                    A2A only at real service or team boundaries, because every remote call adds network latency and \
+                    failure modes.".to_string(),
+                category: "a2a".to_string(),
+                examples: vec![
+                    "Split a monolithic agent into sub-agents before splitting it into services".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/a2a/intro/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Publish Descriptive Agent Cards".to_string(),
+                description: "Give every exposed agent a clear name, description, and skill list in its agent card, since \
+                    client agents and their LLMs use the card to decide when to delegate to it.".to_string(),
+                category: "a2a".to_string(),
+                examples: vec![
+                    "Describe each skill with examples of requests it handles".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/a2a/quickstart-exposing/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Pass Context Explicitly Across A2A Boundaries".to_string(),
+                description: "Remote agents do not see the caller's session state, so include everything the remote agent \
+                    needs in the delegated message and return results in the response rather than expecting shared state.".to_string(),
+                category: "a2a".to_string(),
+                examples: vec![
+                    "Include the customer id and order number in the message sent to a remote billing agent".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/a2a/intro/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Secure and Monitor Remote Agent Endpoints".to_string(),
+                description: "Serve A2A endpoints over HTTPS with authentication, set timeouts on remote calls, and trace \
+                    requests across services so a slow or failing remote agent is visible and does not stall the caller.".to_string(),
+                category: "a2a".to_string(),
+                examples: vec![
+                    "Put the A2A server behind Cloud Run IAM or an API gateway".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/a2a/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+        ]
+    }
+    
+    /// Implementation patterns for agent-to-agent (A2A) interoperability
+    fn a2a_patterns() -> HashMap<String, ImplementationPattern> {
+        let mut patterns = HashMap::new();
+        
+        patterns.insert("expose_agent_a2a".to_string(), ImplementationPattern {
+            name: "Exposing an Agent over A2A".to_string(),
+            description: "Serve an existing ADK agent as an A2A server with an auto-generated agent card.".to_string(),
+            use_cases: vec![
+                "Sharing an agent with other teams or services".to_string(),
+                "Making an ADK agent callable from other frameworks".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "to_a2a server".to_string(),
+                    language: "python".to_string(),
+                    code: r#"# pip install google-adk[a2a]
+from google.adk.a2a.utils.agent_to_a2a import to_a2a
+from my_agent.agent import root_agent
+
+# Builds the agent card from the agent's name, description, and tools
+a2a_app = to_a2a(root_agent, port=8001)
+
+# Serve with: uvicorn my_agent.a2a_server:a2a_app --host 0.0.0.0 --port 8001"#.to_string(),
+                    explanation: "The agent card is served at /.well-known/agent.json; adk api_server --a2a is an alternative for agents with a hand-written agent.json.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Publish Descriptive Agent Cards".to_string(),
+                "Secure and Monitor Remote Agent Endpoints".to_string(),
+            ],
+        });
+        
+        patterns.insert("consume_remote_a2a_agent".to_string(), ImplementationPattern {
+            name: "Consuming a Remote A2A Agent".to_string(),
+            description: "Delegate to a remotely served agent by adding a RemoteA2aAgent as a sub-agent.".to_string(),
+            use_cases: vec![
+                "Coordinators delegating to agents owned by other teams".to_string(),
+                "Composing agents deployed as separate services".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "RemoteA2aAgent sub-agent".to_string(),
+                    language: "python".to_string(),
+                    code: r#"from google.adk.agents import LlmAgent
+from google.adk.agents.remote_a2a_agent import AGENT_CARD_WELL_KNOWN_PATH, RemoteA2aAgent
+
+billing_agent = RemoteA2aAgent(
+    name="billing_agent",
+    description="Answers questions about invoices and payments.",
+    agent_card=f"http://billing-service:8001{AGENT_CARD_WELL_KNOWN_PATH}",
+)
+
+root_agent = LlmAgent(
+    name="support_coordinator",
+    model="gemini-2.0-flash",
+    instruction="Delegate billing questions to billing_agent, including the customer id.",
+    sub_agents=[billing_agent],
+)"#.to_string(),
+                    explanation: "The coordinator transfers to the remote agent like a local sub-agent; the remote agent only sees the messages it is sent.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Pass Context Explicitly Across A2A Boundaries".to_string(),
+                "Prefer Sub-Agents Within One Deployment".to_string(),
+            ],
+        });
+        
+        patterns
+    }
+    
+    /// Concepts for evaluating agents
+    fn evaluation_concepts() -> HashMap<String, ConceptInfo> {
+        let mut concepts = HashMap::new();
+        
+        concepts.insert("agent_evaluation".to_string(), ConceptInfo {
+            name: "Agent Evaluation".to_string(),
+            description: "Because LLM output is non-deterministic, ADK replaces pass/fail unit tests with evaluation: \
+                recorded conversations are replayed against the agent and scored on the tools it called (trajectory) \
+                and the final responses it gave. Evaluations run from the adk eval CLI, from pytest through \
+                AgentEvaluator, or interactively in the Eval tab of adk web.".to_string(),
+            examples: vec![
+                "adk eval my_agent my_agent/tests/booking.evalset.json".to_string(),
+                "await AgentEvaluator.evaluate(agent_module=\"my_agent\", eval_dataset_file_path_or_dir=\"tests/fixtures\")".to_string(),
+            ],
+            related_concepts: vec!["eval_sets".to_string(), "trajectory_evaluation".to_string(), "response_evaluation".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/evaluate/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("eval_sets".to_string(), ConceptInfo {
+            name: "Eval Sets and Test Files".to_string(),
+            description: "A test file (.test.json) holds a single simple session for fast, unit-test-like checks during \
+                development. An eval set (.evalset.json) holds many eval cases, each a multi-turn conversation with the \
+                user messages, expected tool calls, and reference responses, for integration-style evaluation. \
+                Eval cases can be captured from real sessions in adk web.".to_string(),
+            examples: vec![
+                "tests/fixtures/weather.test.json for a single-turn tool call".to_string(),
+                "booking.evalset.json with one eval case per booking scenario".to_string(),
+            ],
+            related_concepts: vec!["agent_evaluation".to_string(), "trajectory_evaluation".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/evaluate/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("trajectory_evaluation".to_string(), ConceptInfo {
+            name: "Trajectory Evaluation".to_string(),
+            description: "Trajectory evaluation compares the sequence of tool calls the agent made with the expected \
+                calls in the eval case. The tool_trajectory_avg_score criterion is the average match across turns, \
+                and its default threshold of 1.0 requires an exact match.".to_string(),
+            examples: vec![
+                "Expect get_weather(city=\"London\") before the final answer".to_string(),
+            ],
+            related_concepts: vec!["agent_evaluation".to_string(), "response_evaluation".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/evaluate/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("response_evaluation".to_string(), ConceptInfo {
+            name: "Response Evaluation".to_string(),
+            description: "Response evaluation scores the agent's final response against the reference response. The \
+                response_match_score criterion uses ROUGE text similarity with a default threshold of 0.8; \
+                thresholds for both criteria are set in a test_config.json next to the eval files.".to_string(),
+            examples: vec![
+                "{\"criteria\": {\"tool_trajectory_avg_score\": 1.0, \"response_match_score\": 0.7}}".to_string(),
+            ],
+            related_concepts: vec!["agent_evaluation".to_string(), "trajectory_evaluation".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/evaluate/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts
+    }
+    
+    /// Best practices for evaluating agents
+    fn evaluation_best_practices() -> Vec<BestPractice> {
+        vec![
+            BestPractice {
+                title: "Evaluate Trajectories as well as Responses".to_string(),
+                description: "When testing agents, check which tools were called and with which arguments, not just the \
+                    final text: a correct-looking answer produced by the wrong tool path is a latent bug.".to_string(),
+                category: "evaluation".to_string(),
+                examples: vec![
+                    "Keep tool_trajectory_avg_score at 1.0 for deterministic tool flows".to_string(),
+                    "Record expected tool calls for every eval case".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/evaluate/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Use Test Files for Unit Checks and Eval Sets for Integration".to_string(),
+                description: "For testing agents during development, keep small .test.json files that run in seconds, and \
+                    maintain .evalset.json eval sets with multi-turn cases covering complete user journeys.".to_string(),
+                category: "evaluation".to_string(),
+                examples: vec![
+                    "One test file per tool behavior".to_string(),
+                    "An eval set per major user journey".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/evaluate/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Set Explicit Evaluation Criteria".to_string(),
+                description: "Choose pass thresholds deliberately in test_config.json: exact trajectory matching where \
+                    tool use is deterministic and a relaxed response_match_score where wording may legitimately vary.".to_string(),
+                category: "evaluation".to_string(),
+                examples: vec![
+                    "response_match_score of 0.7 for free-form answers".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/evaluate/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Run Evaluations in CI".to_string(),
+                description: "Treat evaluations as the regression suite for testing agents: invoke AgentEvaluator from \
+                    pytest in continuous integration on every change so prompt, model, and tool edits cannot silently \
+                    regress behavior.".to_string(),
+                category: "evaluation".to_string(),
+                examples: vec![
+                    "pytest tests/integration in the CI pipeline with model credentials from CI secrets".to_string(),
+                    "Fail the build when an eval case drops below its threshold".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/evaluate/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Grow Eval Sets from Real Sessions".to_string(),
+                description: "Capture conversations from adk web or production traces, especially failures, and add them \
+                    as eval cases so the suite used for testing agents covers the behavior users actually hit.".to_string(),
+                category: "evaluation".to_string(),
+                examples: vec![
+                    "Save a problematic session as an eval case from the adk web Eval tab".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/evaluate/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+        ]
+    }
+    
+    /// Implementation patterns for evaluating agents
+    fn evaluation_patterns() -> HashMap<String, ImplementationPattern> {
+        let mut patterns = HashMap::new();
+        
+        patterns.insert("pytest_agent_evaluation".to_string(), ImplementationPattern {
+            name: "Evaluating Agents with pytest".to_string(),
+            description: "Run test files and eval sets through AgentEvaluator inside pytest.".to_string(),
+            use_cases: vec![
+                "Regression testing agents during development".to_string(),
+                "Running evaluations in CI".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "AgentEvaluator test".to_string(),
+                    language: "python".to_string(),
+                    code: r#"import pytest
+from google.adk.evaluation.agent_evaluator import AgentEvaluator
+
+@pytest.mark.asyncio
+async def test_weather_agent():
+    await AgentEvaluator.evaluate(
+        agent_module="weather_agent",
+        eval_dataset_file_path_or_dir="tests/fixtures/weather.test.json",
+    )"#.to_string(),
+                    explanation: "The test fails when a criterion in the adjacent test_config.json is not met.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Use Test Files for Unit Checks and Eval Sets for Integration".to_string(),
+                "Run Evaluations in CI".to_string(),
+            ],
+        });
+        
+        patterns.insert("adk_eval_cli".to_string(), ImplementationPattern {
+            name: "Running Eval Sets with adk eval".to_string(),
+            description: "Evaluate an agent against an eval set from the command line with custom criteria.".to_string(),
+            use_cases: vec![
+                "Checking an agent before a release".to_string(),
+                "Comparing models or prompts on the same eval set".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "adk eval with criteria".to_string(),
+                    language: "bash".to_string(),
+                    code: r#"cat > my_agent/test_config.json <<'JSON'
+{
+  "criteria": {
+    "tool_trajectory_avg_score": 1.0,
+    "response_match_score": 0.7
+  }
+}
+JSON
+
+adk eval my_agent my_agent/booking.evalset.json \
+    --config_file_path=my_agent/test_config.json \
+    --print_detailed_results"#.to_string(),
+                    explanation: "Append :case_id to the eval set path to run selected eval cases only.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Set Explicit Evaluation Criteria".to_string(),
+                "Evaluate Trajectories as well as Responses".to_string(),
+            ],
+        });
+        
+        patterns.insert("evaluation_ci".to_string(), ImplementationPattern {
+            name: "Agent Evaluation in CI".to_string(),
+            description: "Run agent evaluations on every pull request in a CI workflow.".to_string(),
+            use_cases: vec![
+                "Blocking merges that regress agent behavior".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "GitHub Actions workflow".to_string(),
+                    language: "yaml".to_string(),
+                    code: r#"name: agent-evals
+on: [pull_request]
+jobs:
+  evaluate:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: actions/setup-python@v5
+        with:
+          python-version: "3.12"
+      - run: pip install google-adk pytest pytest-asyncio
+      - run: pytest tests/integration
+        env:
+          GOOGLE_API_KEY: ${{ secrets.GOOGLE_API_KEY }}"#.to_string(),
+                    explanation: "Evaluations call the model, so supply credentials from CI secrets and expect some run-to-run variance in response scores.".to_string(),
+                },
+            ],
+            related_practices: vec!["Run Evaluations in CI".to_string()],
+        });
+        
+        patterns
+    }
+    
+    /// Concepts for streaming and live (bidirectional) interaction
+    fn streaming_concepts() -> HashMap<String, ConceptInfo> {
+        let mut concepts = HashMap::new();
+        
+        concepts.insert("sse_streaming".to_string(), ConceptInfo {
+            name: "SSE Response Streaming".to_string(),
+            description: "With RunConfig(streaming_mode=StreamingMode.SSE), run_async yields partial events \
+                (event.partial is True) as the model generates text, followed by the complete event, so clients can \
+                render responses incrementally. The adk api_server exposes the same behavior through its /run_sse \
+                endpoint as server-sent events.".to_string(),
+            examples: vec![
+                "run_config=RunConfig(streaming_mode=StreamingMode.SSE)".to_string(),
+                "POST /run_sse with \"streaming\": true".to_string(),
+            ],
+            related_concepts: vec!["bidi_streaming".to_string(), "run_config".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/runtime/runconfig/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("bidi_streaming".to_string(), ConceptInfo {
+            name: "Bidi-streaming (Live)".to_string(),
+            description: "Bidirectional streaming runs an agent on the Gemini Live API for real-time voice and video \
+                conversations. run_live streams events out while the application streams audio, video frames, \
+                or text in through a LiveRequestQueue, and users can interrupt the agent mid-response. It requires a \
+                model that supports the Live API, such as gemini-2.0-flash-live-001.".to_string(),
+            examples: vec![
+                "run_live(session=session, live_request_queue=queue, run_config=RunConfig(response_modalities=[\"AUDIO\"]))".to_string(),
+                "Voice assistants and real-time video understanding".to_string(),
+            ],
+            related_concepts: vec!["live_request_queue".to_string(), "sse_streaming".to_string(), "streaming_tools".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/streaming/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("live_request_queue".to_string(), ConceptInfo {
+            name: "LiveRequestQueue".to_string(),
+            description: "LiveRequestQueue carries client input into a live session: send_content for complete turns of \
+                text, send_realtime for audio or image blobs such as 16 kHz PCM audio, and close to end the session. \
+                One queue belongs to one run_live call.".to_string(),
+            examples: vec![
+                "queue.send_realtime(types.Blob(data=pcm_chunk, mime_type=\"audio/pcm\"))".to_string(),
+                "queue.close() when the client disconnects".to_string(),
+            ],
+            related_concepts: vec!["bidi_streaming".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/streaming/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("streaming_tools".to_string(), ConceptInfo {
+            name: "Streaming Tools".to_string(),
+            description: "In live sessions a tool can be an async generator that yields intermediate results over time, \
+                letting the agent react to changing data (a price feed, a video stream) while the conversation \
+                continues.".to_string(),
+            examples: vec![
+                "async def monitor_stock_price(symbol: str): yields a message whenever the price changes".to_string(),
+            ],
+            related_concepts: vec!["bidi_streaming".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/streaming/streaming-tools/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("run_config".to_string(), ConceptInfo {
+            name: "RunConfig".to_string(),
+            description: "RunConfig sets runtime behavior for a single run: streaming_mode (NONE, SSE, or BIDI), \
+                response_modalities and speech_config for live audio, output_audio_transcription and \
+                input_audio_transcription, and max_llm_calls as a safety limit.".to_string(),
+            examples: vec![
+                "RunConfig(streaming_mode=StreamingMode.SSE, max_llm_calls=200)".to_string(),
+            ],
+            related_concepts: vec!["sse_streaming".to_string(), "bidi_streaming".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/runtime/runconfig/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts
+    }
+    
+    /// Best practices for streaming and live (bidirectional) interaction
+    fn streaming_best_practices() -> Vec<BestPractice> {
+        vec![
+            BestPractice {
+                title: "Pick the Streaming Mode by Interaction Type".to_string(),
+                description: "Use SSE streaming to show text responses as they are generated in chat UIs, and reserve \
+                    bidi-streaming for real-time voice or video, where interruptions and continuous input matter.".to_string(),
+                category: "streaming".to_string(),
+                examples: vec![
+                    "StreamingMode.SSE for a web chat".to_string(),
+                    "run_live for a phone or voice assistant".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/streaming/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Use Live API Models for Bidi-streaming".to_string(),
+                description: "Only models that support the Gemini Live API can run live sessions; pin a live-capable model \
+                    for streaming agents and check availability on your backend (AI Studio or Vertex AI).".to_string(),
+                category: "streaming".to_string(),
+                examples: vec![
+                    "model=\"gemini-2.0-flash-live-001\" for a voice agent".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/streaming/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Render Partial Events Without Persisting Them".to_string(),
+                description: "Display events with partial=True as they arrive but act on, store, or evaluate only the final \
+                    non-partial event, which carries the complete content.".to_string(),
+                category: "streaming".to_string(),
+                examples: vec![
+                    "Append partial text to the UI and replace it with the final event's text".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/runtime/runconfig/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Close Live Sessions Explicitly".to_string(),
+                description: "Call LiveRequestQueue.close() when the client disconnects or the conversation ends, and handle \
+                    turn_complete and interrupted events, so live connections and model sessions are not leaked.".to_string(),
+                category: "streaming".to_string(),
+                examples: vec![
+                    "Close the queue in a finally block of the websocket handler".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/streaming/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+        ]
+    }
+    
+    /// Implementation patterns for streaming and live (bidirectional) interaction
+    fn streaming_patterns() -> HashMap<String, ImplementationPattern> {
+        let mut patterns = HashMap::new();
+        
+        patterns.insert("sse_streaming_runner".to_string(), ImplementationPattern {
+            name: "Streaming Text Responses with SSE".to_string(),
+            description: "Run an agent in SSE mode and forward partial text to the client as it is generated.".to_string(),
+            use_cases: vec![
+                "Chat interfaces that render tokens as they arrive".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Partial events from run_async".to_string(),
+                    language: "python".to_string(),
+                    code: r#"from google.adk.agents.run_config import RunConfig, StreamingMode
+from google.genai import types
+
+run_config = RunConfig(streaming_mode=StreamingMode.SSE)
+message = types.Content(role="user", parts=[types.Part(text="Summarize today's news")])
+
+async for event in runner.run_async(
+    user_id=user_id, session_id=session_id, new_message=message, run_config=run_config
+):
+    if event.partial and event.content and event.content.parts:
+        send_to_client(event.content.parts[0].text)
+    elif event.is_final_response():
+        finish_client_message(event)"#.to_string(),
+                    explanation: "Partial events carry incremental text; the final response event carries the complete content.".to_string(),
+                },
+            ],
+            related_practices: vec!["Render Partial Events Without Persisting Them".to_string()],
+        });
+        
+        patterns.insert("bidi_live_streaming".to_string(), ImplementationPattern {
+            name: "Live Audio Agent with run_live".to_string(),
+            description: "Stream microphone audio into a live session and play the agent's audio responses.".to_string(),
+            use_cases: vec![
+                "Voice assistants".to_string(),
+                "Real-time customer support over audio".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "run_live with a LiveRequestQueue".to_string(),
+                    language: "python".to_string(),
+                    code: r#"from google.adk.agents import Agent, LiveRequestQueue
+from google.adk.agents.run_config import RunConfig
+from google.genai import types
+
+agent = Agent(name="voice_agent", model="gemini-2.0-flash-live-001", instruction="Be a concise voice assistant.")
+
+live_request_queue = LiveRequestQueue()
+run_config = RunConfig(response_modalities=["AUDIO"])
+
+live_events = runner.run_live(
+    session=session, live_request_queue=live_request_queue, run_config=run_config
+)
+
+# Client to agent: 16 kHz, 16-bit PCM audio chunks
+live_request_queue.send_realtime(types.Blob(data=pcm_chunk, mime_type="audio/pcm"))
+
+# Agent to client: audio parts, plus turn_complete and interrupted signals
+async for event in live_events:
+    if event.turn_complete or event.interrupted:
+        notify_client(event)
+        continue
+    part = event.content and event.content.parts and event.content.parts[0]
+    if part and part.inline_data:
+        play_audio(part.inline_data.data)
+
+live_request_queue.close()"#.to_string(),
+                    explanation: "Run the sending and receiving loops concurrently (for example over a websocket) and close the queue when the client leaves.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Use Live API Models for Bidi-streaming".to_string(),
+                "Close Live Sessions Explicitly".to_string(),
+            ],
+        });
+        
+        patterns.insert("streaming_tool".to_string(), ImplementationPattern {
+            name: "Streaming Tool".to_string(),
+            description: "Define an async generator tool that reports updates during a live session.".to_string(),
+            use_cases: vec![
+                "Monitoring prices or metrics while talking to the user".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Async generator tool".to_string(),
+                    language: "python".to_string(),
+                    code: r#"import asyncio
+from typing import AsyncGenerator
+
+async def monitor_stock_price(symbol: str) -> AsyncGenerator[str, None]:
+    """Reports price changes for a stock symbol."""
+    last_price = None
+    while True:
+        price = await fetch_price(symbol)
+        if price != last_price:
+            yield f"{symbol} is now {price}"
+            last_price = price
+        await asyncio.sleep(5)
+
+agent = Agent(name="market_agent", model="gemini-2.0-flash-live-001", tools=[monitor_stock_price])"#.to_string(),
+                    explanation: "Each yielded value is sent back to the model while the live conversation continues.".to_string(),
+                },
+            ],
+            related_practices: vec!["Pick the Streaming Mode by Interaction Type".to_string()],
+        });
+        
+        patterns
+    }
+    
+    /// Version-specific streaming features
+    fn streaming_features() -> Vec<VersionFeature> {
+        vec![
+            VersionFeature {
+                name: "SSE Streaming".to_string(),
+                description: "Partial response events through RunConfig(streaming_mode=StreamingMode.SSE) and the /run_sse endpoint.".to_string(),
+                introduced_in: "0.1.0".to_string(),
+                deprecated_in: None,
+                migration_notes: None,
+            },
+            VersionFeature {
+                name: "Bidi-streaming".to_string(),
+                description: "Live voice and video conversations through run_live and LiveRequestQueue on Gemini Live API models.".to_string(),
+                introduced_in: "0.1.0".to_string(),
+                deprecated_in: None,
+                migration_notes: None,
+            },
+        ]
+    }
+    
+    /// Concepts for using MCP servers as tools inside ADK agents
+    fn mcp_concepts() -> HashMap<String, ConceptInfo> {
+        let mut concepts = HashMap::new();
+        
+        concepts.insert("mcp_tools".to_string(), ConceptInfo {
+            name: "MCP Tools in ADK".to_string(),
+            description: "The Model Context Protocol (MCP) standardizes how applications expose tools, resources, and \
+                prompts to LLMs. ADK agents act as MCP clients: tools served by any MCP server, local or remote, can be \
+                added to an agent's tools list and are called like native ADK tools.".to_string(),
+            examples: vec![
+                "A filesystem MCP server giving an agent read access to a project directory".to_string(),
+                "A remote MCP server exposing an internal ticketing API".to_string(),
+            ],
+            related_concepts: vec!["mcp_toolset".to_string(), "mcp_connection_params".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/tools/mcp-tools/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("mcp_toolset".to_string(), ConceptInfo {
+            name: "MCPToolset".to_string(),
+            description: "MCPToolset connects to an MCP server, lists its tools, and adapts each one into an ADK tool \
+                the agent can call. It manages the connection for the agent's lifetime, and an optional tool_filter \
+                limits which server tools are exposed to the model.".to_string(),
+            examples: vec![
+                "tools=[MCPToolset(connection_params=..., tool_filter=[\"read_file\", \"list_directory\"])]".to_string(),
+            ],
+            related_concepts: vec!["mcp_connection_params".to_string(), "mcp_authentication".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/tools/mcp-tools/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("mcp_connection_params".to_string(), ConceptInfo {
+            name: "MCP Connection Parameters".to_string(),
+            description: "connection_params selects the MCP transport. StdioConnectionParams launches a local server \
+                process from a command and arguments (wrapping StdioServerParameters); SseConnectionParams and \
+                StreamableHTTPConnectionParams connect to a remote server by URL with optional headers and \
+                timeouts.".to_string(),
+            examples: vec![
+                "StdioConnectionParams(server_params=StdioServerParameters(command=\"npx\", args=[\"-y\", \"@modelcontextprotocol/server-filesystem\", path]))".to_string(),
+                "StreamableHTTPConnectionParams(url=\"https://mcp.example.com/mcp\")".to_string(),
+            ],
+            related_concepts: vec!["mcp_toolset".to_string(), "mcp_authentication".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/tools/mcp-tools/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("mcp_authentication".to_string(), ConceptInfo {
+            name: "MCP Authentication".to_string(),
+            description: "Remote MCP servers are authenticated either with static headers on the connection parameters \
+                (for example an API key or bearer token) or with auth_scheme and auth_credential on MCPToolset, which \
+                use ADK's tool authentication flow, including OAuth2, to obtain and attach credentials.".to_string(),
+            examples: vec![
+                "StreamableHTTPConnectionParams(url=..., headers={\"Authorization\": f\"Bearer {token}\"})".to_string(),
+                "MCPToolset(connection_params=..., auth_scheme=oauth2_scheme, auth_credential=oauth2_credential)".to_string(),
+            ],
+            related_concepts: vec!["mcp_connection_params".to_string(), "mcp_toolset".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/tools/authentication/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts
+    }
+    
+    /// Best practices for using MCP servers as tools inside ADK agents
+    fn mcp_best_practices() -> Vec<BestPractice> {
+        vec![
+            BestPractice {
+                title: "Filter MCP Tools to What the Agent Needs".to_string(),
+                description: "Pass tool_filter to MCPToolset so the model only sees the server tools the agent should use; \
+                    fewer tools improve tool selection and keep destructive operations out of reach.".to_string(),
+                category: "mcp".to_string(),
+                examples: vec![
+                    "tool_filter=[\"read_file\", \"list_directory\"] on a filesystem server".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/tools/mcp-tools/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Match the MCP Transport to the Deployment".to_string(),
+                description: "Use stdio connections for servers launched alongside the agent during development, and \
+                    Streamable HTTP or SSE connections for shared or deployed MCP servers, since deployed agents often \
+                    cannot spawn local server processes.".to_string(),
+                category: "mcp".to_string(),
+                examples: vec![
+                    "StdioConnectionParams with npx locally".to_string(),
+                    "StreamableHTTPConnectionParams to a Cloud Run hosted MCP server in production".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/tools/mcp-tools/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Keep MCP Credentials Out of Agent Code".to_string(),
+                description: "Read tokens and API keys for MCP servers from environment variables or a secret manager \
+                    when building connection headers or auth credentials, and never pass them to the model.".to_string(),
+                category: "mcp".to_string(),
+                examples: vec![
+                    "headers={\"Authorization\": f\"Bearer {os.environ['MCP_TOKEN']}\"}".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/tools/authentication/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Close MCP Connections on Shutdown".to_string(),
+                description: "MCP toolsets hold open server processes or network connections; call close() on toolsets \
+                    created outside adk web or the API server when the application exits.".to_string(),
+                category: "mcp".to_string(),
+                examples: vec![
+                    "await toolset.close() in the application's shutdown hook".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/tools/mcp-tools/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+        ]
+    }
+    
+    /// Implementation patterns for using MCP servers as tools inside ADK agents
+    fn mcp_patterns() -> HashMap<String, ImplementationPattern> {
+        let mut patterns = HashMap::new();
+        
+        patterns.insert("mcp_toolset_stdio".to_string(), ImplementationPattern {
+            name: "Local MCP Server over stdio".to_string(),
+            description: "Launch an MCP server as a subprocess and give its tools to an agent.".to_string(),
+            use_cases: vec![
+                "Using community MCP servers published on npm or PyPI".to_string(),
+                "Local development against an MCP server".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "MCPToolset with StdioConnectionParams".to_string(),
+                    language: "python".to_string(),
+                    code: r#"import os
+from google.adk.agents import LlmAgent
+from google.adk.tools.mcp_tool.mcp_toolset import MCPToolset
+from google.adk.tools.mcp_tool.mcp_session_manager import StdioConnectionParams
+from mcp import StdioServerParameters
+
+TARGET_FOLDER = os.path.abspath("./workspace")
+
+root_agent = LlmAgent(
+    name="filesystem_agent",
+    model="gemini-2.0-flash",
+    instruction="Help the user browse and read files in the workspace.",
+    tools=[
+        MCPToolset(
+            connection_params=StdioConnectionParams(
+                server_params=StdioServerParameters(
+                    command="npx",
+                    args=["-y", "@modelcontextprotocol/server-filesystem", TARGET_FOLDER],
+                ),
+            ),
+            tool_filter=["list_directory", "read_file"],
+        )
+    ],
+)"#.to_string(),
+                    explanation: "The server process is started on first use and its tools are adapted automatically; tool_filter limits what the model sees.".to_string(),
+                },
+            ],
+            related_practices: vec!["Filter MCP Tools to What the Agent Needs".to_string()],
+        });
+        
+        patterns.insert("mcp_toolset_remote".to_string(), ImplementationPattern {
+            name: "Remote MCP Server over HTTP".to_string(),
+            description: "Connect an agent to a deployed MCP server with header-based authentication.".to_string(),
+            use_cases: vec![
+                "Shared MCP servers used by several agents".to_string(),
+                "Agents deployed to Cloud Run, GKE, or Agent Engine".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "MCPToolset with StreamableHTTPConnectionParams".to_string(),
+                    language: "python".to_string(),
+                    code: r#"import os
+from google.adk.agents import LlmAgent
+from google.adk.tools.mcp_tool.mcp_toolset import MCPToolset
+from google.adk.tools.mcp_tool.mcp_session_manager import StreamableHTTPConnectionParams
+
+tickets = MCPToolset(
+    connection_params=StreamableHTTPConnectionParams(
+        url=os.environ["TICKETS_MCP_URL"],
+        headers={"Authorization": f"Bearer {os.environ['TICKETS_MCP_TOKEN']}"},
+        timeout=30,
+    ),
+)
+
+root_agent = LlmAgent(
+    name="support_agent",
+    model="gemini-2.0-flash",
+    instruction="Look up and update support tickets for the user.",
+    tools=[tickets],
+)"#.to_string(),
+                    explanation: "The URL and token come from the environment, so the same agent code works across environments without embedding secrets.".to_string(),
+                },
+            ],
+            related_practices: vec![
+                "Match the MCP Transport to the Deployment".to_string(),
+                "Keep MCP Credentials Out of Agent Code".to_string(),
+            ],
+        });
+        
+        patterns.insert("mcp_toolset_oauth".to_string(), ImplementationPattern {
+            name: "MCP Server with OAuth2".to_string(),
+            description: "Let ADK's tool authentication flow obtain OAuth2 credentials for an MCP server.".to_string(),
+            use_cases: vec![
+                "MCP servers that act on behalf of the signed-in user".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "auth_scheme and auth_credential on MCPToolset".to_string(),
+                    language: "python".to_string(),
+                    code: r#"import os
+from fastapi.openapi.models import OAuth2, OAuthFlowAuthorizationCode, OAuthFlows
+from google.adk.auth.auth_credential import AuthCredential, AuthCredentialTypes, OAuth2Auth
+from google.adk.tools.mcp_tool.mcp_toolset import MCPToolset
+from google.adk.tools.mcp_tool.mcp_session_manager import StreamableHTTPConnectionParams
+
+auth_scheme = OAuth2(
+    flows=OAuthFlows(
+        authorizationCode=OAuthFlowAuthorizationCode(
+            authorizationUrl="https://auth.example.com/authorize",
+            tokenUrl="https://auth.example.com/token",
+            scopes={"tickets.read": "Read tickets"},
+        )
+    )
+)
+auth_credential = AuthCredential(
+    auth_type=AuthCredentialTypes.OAUTH2,
+    oauth2=OAuth2Auth(client_id=os.environ["CLIENT_ID"], client_secret=os.environ["CLIENT_SECRET"]),
+)
+
+tickets = MCPToolset(
+    connection_params=StreamableHTTPConnectionParams(url=os.environ["TICKETS_MCP_URL"]),
+    auth_scheme=auth_scheme,
+    auth_credential=auth_credential,
+)"#.to_string(),
+                    explanation: "When a tool needs credentials, ADK requests user consent through the client and attaches the resulting token to MCP calls.".to_string(),
+                },
+            ],
+            related_practices: vec!["Keep MCP Credentials Out of Agent Code".to_string()],
+        });
+        
+        patterns
+    }
+    
+    /// Concepts for safety guardrails, filtering, tool confirmation, and grounding
+    fn safety_concepts() -> HashMap<String, ConceptInfo> {
+        let mut concepts = HashMap::new();
+        
+        concepts.insert("safety_guardrails".to_string(), ConceptInfo {
+            name: "Safety Guardrails".to_string(),
+            description: "ADK agents are protected in layers: callbacks act as in-agent guardrails that screen model \
+                requests (before_model_callback), model responses (after_model_callback), and tool calls \
+                (before_tool_callback); model safety settings filter harmful content; tool confirmation keeps a human \
+                in the loop for consequential actions; and grounding ties factual answers to sources. Tools should also \
+                run with the least privilege their task requires.".to_string(),
+            examples: vec![
+                "Blocking off-topic or policy-violating requests before they reach the model".to_string(),
+                "Rejecting tool calls whose arguments fall outside an allow-list".to_string(),
+            ],
+            related_concepts: vec![
+                "content_filtering".to_string(),
+                "tool_confirmation".to_string(),
+                "grounding".to_string(),
+                "model_callbacks".to_string(),
+                "tool_callbacks".to_string(),
+            ],
+            documentation_refs: vec!["https://google.github.io/adk-docs/safety/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("content_filtering".to_string(), ConceptInfo {
+            name: "Input and Output Filtering".to_string(),
+            description: "Content is filtered at two levels. Gemini safety settings, passed through \
+                generate_content_config, block harmful categories such as harassment or dangerous content at chosen \
+                thresholds. Application-specific policies, such as redacting PII or blocking competitor mentions, run \
+                in before_model_callback on inputs and after_model_callback on outputs, optionally using a fast model \
+                as a judge.".to_string(),
+            examples: vec![
+                "generate_content_config=types.GenerateContentConfig(safety_settings=[...])".to_string(),
+                "after_model_callback that redacts email addresses from responses".to_string(),
+            ],
+            related_concepts: vec!["safety_guardrails".to_string(), "model_callbacks".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/safety/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("tool_confirmation".to_string(), ConceptInfo {
+            name: "Tool Confirmation".to_string(),
+            description: "Tool confirmation pauses a tool call until a person approves it. FunctionTool(func, \
+                require_confirmation=True) asks for a yes/no approval before running, and a tool can call \
+                tool_context.request_confirmation(...) to ask for structured input; the client answers with a \
+                confirmation response and the invocation resumes.".to_string(),
+            examples: vec![
+                "FunctionTool(transfer_funds, require_confirmation=True)".to_string(),
+                "Requiring approval only when a refund exceeds a threshold".to_string(),
+            ],
+            related_concepts: vec!["safety_guardrails".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/tools/confirmation/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts.insert("grounding".to_string(), ConceptInfo {
+            name: "Grounding".to_string(),
+            description: "Grounding connects model answers to verifiable sources to reduce hallucination. The built-in \
+                google_search tool grounds answers in web results, VertexAiSearchTool grounds them in private data \
+                stores, and the response carries grounding metadata with the sources used, which applications should \
+                display.".to_string(),
+            examples: vec![
+                "tools=[google_search] on a research agent".to_string(),
+                "VertexAiSearchTool(data_store_id=...) for internal policy documents".to_string(),
+            ],
+            related_concepts: vec!["safety_guardrails".to_string()],
+            documentation_refs: vec!["https://google.github.io/adk-docs/grounding/google_search_grounding/".to_string()],
+            source_url: None,
+            last_updated: None,
+        });
+        
+        concepts
+    }
+    
+    /// Best practices for safety guardrails, filtering, tool confirmation, and grounding
+    fn safety_best_practices() -> Vec<BestPractice> {
+        vec![
+            BestPractice {
+                title: "Screen Inputs with Guardrail Callbacks".to_string(),
+                description: "Use before_model_callback to block or rewrite requests that violate policy, such as \
+                    prompt injection attempts or out-of-scope topics, and return a safe canned response instead of \
+                    calling the model.".to_string(),
+                category: "safety".to_string(),
+                examples: vec![
+                    "Checking user input against a denylist or a lightweight classifier model".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/safety/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Validate Tool Arguments Before Execution".to_string(),
+                description: "Use before_tool_callback to enforce allow-lists and invariants on tool arguments, for \
+                    example restricting queries to the signed-in user's records, since the model can be steered into \
+                    calling tools with unintended inputs.".to_string(),
+                category: "safety".to_string(),
+                examples: vec![
+                    "Rejecting a database query tool call whose customer_id differs from the session user".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/safety/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Require Confirmation for Consequential Tools".to_string(),
+                description: "Mark tools that spend money, delete data, or contact people with require_confirmation so a \
+                    person approves each call.".to_string(),
+                category: "safety".to_string(),
+                examples: vec![
+                    "FunctionTool(send_email, require_confirmation=True)".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/tools/confirmation/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Filter Model Outputs".to_string(),
+                description: "Configure Gemini safety settings for harmful content and use after_model_callback to redact \
+                    sensitive data or enforce brand and policy rules before responses reach users.".to_string(),
+                category: "safety".to_string(),
+                examples: vec![
+                    "Redacting PII from responses in after_model_callback".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/safety/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Ground Factual Answers".to_string(),
+                description: "Give agents that answer factual questions a grounding tool such as google_search or \
+                    VertexAiSearchTool, and surface the returned sources to users.".to_string(),
+                category: "safety".to_string(),
+                examples: vec![
+                    "A policy assistant grounded in a Vertex AI Search data store".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/grounding/google_search_grounding/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Run Tools with Least Privilege".to_string(),
+                description: "Give tools credentials scoped to what they need, using the agent's own service account or \
+                    the end user's OAuth identity, and sandbox generated code with a code executor rather than running it \
+                    directly.".to_string(),
+                category: "safety".to_string(),
+                examples: vec![
+                    "Read-only database credentials for a reporting agent".to_string(),
+                    "VertexAiCodeExecutor for model-generated code".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/safety/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+        ]
+    }
+    
+    /// Best practices for secrets, tool input validation, endpoint authentication, and prompt injection
+    fn security_best_practices() -> Vec<BestPractice> {
+        vec![
+            BestPractice {
+                title: "Keep Secrets Out of Code".to_string(),
+                description: "Load API keys, database passwords, and OAuth client secrets from environment variables or \
+                    Secret Manager at startup instead of writing them in source files, agent configuration, or \
+                    instructions, and rotate any secret that was ever committed.".to_string(),
+                category: "security".to_string(),
+                examples: vec![
+                    "GOOGLE_API_KEY read from the environment, mounted from Secret Manager on Cloud Run".to_string(),
+                ],
+                documentation_ref: "https://cloud.google.com/secret-manager/docs".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Treat Tool Arguments as Untrusted Input".to_string(),
+                description: "Tool arguments are chosen by the model and can be steered by users or retrieved content, so \
+                    validate them against allow-lists, use parameterized SQL, and never pass them to eval or a shell.".to_string(),
+                category: "security".to_string(),
+                examples: vec![
+                    "cursor.execute(\"SELECT * FROM orders WHERE id = ?\", (order_id,)) instead of an f-string query".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/safety/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Authenticate Exposed Agent Endpoints".to_string(),
+                description: "Require IAM or OAuth on every deployed agent endpoint, including A2A servers; an \
+                    unauthenticated endpoint lets anyone spend model quota and invoke the agent's tools.".to_string(),
+                category: "security".to_string(),
+                examples: vec![
+                    "Deploying to Cloud Run without --allow-unauthenticated and calling it with an identity token".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/deploy/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Keep Tool Descriptions Static".to_string(),
+                description: "Tool descriptions and instructions are read by the model as trusted guidance, so write them \
+                    as fixed, factual text; never build them from user input or fetched content, and pass untrusted text \
+                    as clearly delimited data instead.".to_string(),
+                category: "security".to_string(),
+                examples: vec![
+                    "Screening retrieved documents for injected instructions in before_model_callback".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/safety/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+        ]
+    }
+    
+    /// Best practices for parallelism, streaming, session state size, and tool round trips
+    fn performance_best_practices() -> Vec<BestPractice> {
         vec![
             BestPractice {
-                title: "Follow Official ADK Patterns".to_string(),
-                description: "Always use official Google ADK architectural patterns and conventions.".to_string(),
-                category: "architecture".to_string(),
+                title: "Run Independent Steps in Parallel".to_string(),
+                description: "Put sub-agents that do not depend on each other's output in a ParallelAgent, and await \
+                    independent model calls together, so a request takes as long as its slowest step rather than the sum \
+                    of all of them.".to_string(),
+                category: "performance".to_string(),
                 examples: vec![
-                    "Use recommended project structure".to_string(),
-                    "Follow naming conventions".to_string(),
+                    "A ParallelAgent running flight, hotel, and weather researchers before a SequentialAgent summarizer".to_string(),
                 ],
-                documentation_ref: "https://google.github.io/adk-docs/get-started/quickstart/".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/agents/workflow-agents/parallel-agents/".to_string(),
+                source_url: None,
+                last_updated: None,
             },
             BestPractice {
-                title: "Version-Aware Development".to_string(),
-                description: "Always specify and track ADK versions for consistent development.".to_string(),
-                category: "versioning".to_string(),
+                title: "Stream Responses to Interactive Users".to_string(),
+                description: "Enable SSE streaming in RunConfig for chat interfaces so users see partial text while the \
+                    model is still generating, instead of waiting for the complete response.".to_string(),
+                category: "performance".to_string(),
                 examples: vec![
-                    "Pin ADK version in configuration".to_string(),
-                    "Use version-specific features appropriately".to_string(),
+                    "RunConfig(streaming_mode=StreamingMode.SSE) in a web chat backend".to_string(),
                 ],
-                documentation_ref: "https://google.github.io/adk-docs/get-started/quickstart/".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/streaming/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Bound What Session State Accumulates".to_string(),
+                description: "Session state is sent with every turn, so store summaries and the keys the next step needs \
+                    rather than appending whole histories, documents, or tool results, and move long-term knowledge to a \
+                    MemoryService.".to_string(),
+                category: "performance".to_string(),
+                examples: vec![
+                    "Keeping the last five search results in state and a running summary of older ones".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/sessions/state/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Batch Work Inside Tools".to_string(),
+                description: "Give tools list parameters and do per-item work inside the tool, rather than having the \
+                    model call a tool once per item; every tool call is another model turn.".to_string(),
+                category: "performance".to_string(),
+                examples: vec![
+                    "get_order_statuses(order_ids: list[str]) instead of get_order_status(order_id: str) in a loop".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/tools/function-tools/".to_string(),
+                source_url: None,
+                last_updated: None,
             },
         ]
     }
     
-    /// Initialize default implementation patterns
-    fn initialize_default_patterns() -> HashMap<String, ImplementationPattern> {
+    /// Best practices for tool count, parameters, long-running operations, and error contracts
+    fn tool_design_best_practices() -> Vec<BestPractice> {
+        vec![
+            BestPractice {
+                title: "Keep Each Agent's Toolset Small".to_string(),
+                description: "Models choose tools less reliably as the list grows; give an agent at most about ten \
+                    related tools and move the rest to specialist sub-agents.".to_string(),
+                category: "tools".to_string(),
+                examples: vec![
+                    "A support root agent delegating to billing and shipping agents with four tools each".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/agents/multi-agents/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Give Tools Narrow, Typed Parameters".to_string(),
+                description: "ADK builds the schema the model sees from a tool's signature and docstring, so named, \
+                    typed parameters tell the model exactly what to pass; untyped dicts, `Any`, and `**kwargs` leave it \
+                    guessing and widen what a bad call can do.".to_string(),
+                category: "tools".to_string(),
+                examples: vec![
+                    "get_order(order_id: str) -> dict instead of run_query(params: dict)".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/tools/function-tools/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Use Long-Running Tools for Slow Operations".to_string(),
+                description: "Wrap operations that take minutes or wait for a person in a LongRunningFunctionTool that \
+                    returns a job id immediately, instead of sleeping inside the tool until the work finishes.".to_string(),
+                category: "tools".to_string(),
+                examples: vec![
+                    "LongRunningFunctionTool(func=request_approval) returning {\"status\": \"pending\", \"ticket_id\": ...}".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/tools/function-tools/#long-run-tool".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+            BestPractice {
+                title: "Return Errors From Tools as Results".to_string(),
+                description: "Have tools return a dict with a \"status\" of \"success\" or \"error\" and a message the \
+                    model can act on, so the agent can retry, ask the user, or explain the failure instead of the turn \
+                    ending with an exception.".to_string(),
+                category: "tools".to_string(),
+                examples: vec![
+                    "{\"status\": \"error\", \"error_message\": \"City 'Atlantis' not found\"}".to_string(),
+                ],
+                documentation_ref: "https://google.github.io/adk-docs/tools/function-tools/".to_string(),
+                source_url: None,
+                last_updated: None,
+            },
+        ]
+    }
+    
+    /// Implementation patterns for safety guardrails, filtering, tool confirmation, and grounding
+    fn safety_patterns() -> HashMap<String, ImplementationPattern> {
         let mut patterns = HashMap::new();
         
-        patterns.insert("basic_setup".to_string(), ImplementationPattern {
-            name: "Basic ADK Setup".to_string(),
-            description: "Standard pattern for setting up a new ADK project.".to_string(),
+        patterns.insert("tool_confirmation_flow".to_string(), ImplementationPattern {
+            name: "Human Confirmation for Tools".to_string(),
+            description: "Require approval before a tool runs, either always or only when a condition is met.".to_string(),
             use_cases: vec![
-                "New project initialization".to_string(),
-                "ADK integration into existing projects".to_string(),
+                "Payments, refunds, and other financial actions".to_string(),
+                "Deleting records or sending messages on a user's behalf".to_string(),
             ],
             code_examples: vec![
                 CodeExample {
-                    title: "Basic Configuration".to_string(),
-                    language: "rust".to_string(),
-                    code: "// ADK setup example - to be populated with actual patterns".to_string(),
-                    explanation: "Basic ADK project configuration following official guidelines.".to_string(),
+                    title: "require_confirmation on FunctionTool".to_string(),
+                    language: "python".to_string(),
+                    code: r#"from google.adk.agents import LlmAgent
+from google.adk.tools import FunctionTool
+
+def issue_refund(order_id: str, amount: float) -> dict:
+    """Issues a refund for an order."""
+    return {"status": "refunded", "order_id": order_id, "amount": amount}
+
+def needs_approval(order_id: str, amount: float) -> bool:
+    return amount > 100
+
+root_agent = LlmAgent(
+    name="support_agent",
+    model="gemini-2.0-flash",
+    instruction="Help customers with orders and refunds.",
+    tools=[FunctionTool(issue_refund, require_confirmation=needs_approval)],
+)"#.to_string(),
+                    explanation: "require_confirmation accepts True or a function of the tool arguments; the client receives a confirmation request and the call resumes once it is approved.".to_string(),
                 },
             ],
-            related_practices: vec!["Follow Official ADK Patterns".to_string()],
+            related_practices: vec!["Require Confirmation for Consequential Tools".to_string()],
+        });
+        
+        patterns.insert("output_filter_callback".to_string(), ImplementationPattern {
+            name: "Output Filtering with after_model_callback".to_string(),
+            description: "Combine Gemini safety settings with a callback that redacts sensitive data from responses.".to_string(),
+            use_cases: vec![
+                "Redacting PII before responses reach users".to_string(),
+                "Blocking harmful content categories".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "Safety settings and PII redaction".to_string(),
+                    language: "python".to_string(),
+                    code: r#"import re
+from typing import Optional
+from google.adk.agents import LlmAgent
+from google.adk.agents.callback_context import CallbackContext
+from google.adk.models import LlmResponse
+from google.genai import types
+
+EMAIL = re.compile(r"[\w.+-]+@[\w-]+\.[\w.]+")
+
+def redact_emails(callback_context: CallbackContext, llm_response: LlmResponse) -> Optional[LlmResponse]:
+    if not llm_response.content or not llm_response.content.parts:
+        return None
+    for part in llm_response.content.parts:
+        if part.text:
+            part.text = EMAIL.sub("[redacted]", part.text)
+    return llm_response
+
+root_agent = LlmAgent(
+    name="assistant",
+    model="gemini-2.0-flash",
+    generate_content_config=types.GenerateContentConfig(
+        safety_settings=[
+            types.SafetySetting(
+                category=types.HarmCategory.HARM_CATEGORY_DANGEROUS_CONTENT,
+                threshold=types.HarmBlockThreshold.BLOCK_LOW_AND_ABOVE,
+            )
+        ]
+    ),
+    after_model_callback=redact_emails,
+)"#.to_string(),
+                    explanation: "Safety settings filter harmful categories at the model; the callback applies application-specific rules to every response.".to_string(),
+                },
+            ],
+            related_practices: vec!["Filter Model Outputs".to_string()],
+        });
+        
+        patterns.insert("google_search_grounding".to_string(), ImplementationPattern {
+            name: "Grounding with Google Search".to_string(),
+            description: "Ground an agent's answers in web search results and show the sources.".to_string(),
+            use_cases: vec![
+                "Answering questions about recent events".to_string(),
+                "Research assistants that must cite sources".to_string(),
+            ],
+            code_examples: vec![
+                CodeExample {
+                    title: "google_search tool".to_string(),
+                    language: "python".to_string(),
+                    code: r#"from google.adk.agents import Agent
+from google.adk.tools import google_search
+
+root_agent = Agent(
+    name="research_agent",
+    model="gemini-2.0-flash",
+    instruction="Answer questions using Google Search and cite your sources.",
+    tools=[google_search],
+)
+
+# Sources used for an answer are attached to the final event
+# event.grounding_metadata.grounding_chunks -> web URIs and titles"#.to_string(),
+                    explanation: "Built-in grounding tools must be the only tool on their agent; wrap the grounded agent in an AgentTool to combine it with other tools.".to_string(),
+                },
+            ],
+            related_practices: vec!["Ground Factual Answers".to_string()],
         });
         
         patterns
     }
+    
+    /// Sections of the official documentation supporting default concepts and practices
+    ///
+    /// Anchors follow the documentation site's heading slugs; a stale anchor still lands
+    /// on the right page.
+    fn initialize_default_citations() -> HashMap<String, Citation> {
+        const SESSION_PAGE: &str = "https://google.github.io/adk-docs/sessions/session/";
+        const STATE_PAGE: &str = "https://google.github.io/adk-docs/sessions/state/";
+        const MEMORY_PAGE: &str = "https://google.github.io/adk-docs/sessions/memory/";
+        const CALLBACKS_PAGE: &str = "https://google.github.io/adk-docs/callbacks/";
+        const CALLBACK_TYPES_PAGE: &str = "https://google.github.io/adk-docs/callbacks/types-of-callbacks/";
+        const CALLBACK_PATTERNS_PAGE: &str = "https://google.github.io/adk-docs/callbacks/design-patterns-and-best-practices/";
+        const MODELS_PAGE: &str = "https://google.github.io/adk-docs/agents/models/";
+        const A2A_INTRO_PAGE: &str = "https://google.github.io/adk-docs/a2a/intro/";
+        const EVALUATE_PAGE: &str = "https://google.github.io/adk-docs/evaluate/";
+        const RUNCONFIG_PAGE: &str = "https://google.github.io/adk-docs/runtime/runconfig/";
+        const MCP_TOOLS_PAGE: &str = "https://google.github.io/adk-docs/tools/mcp-tools/";
+        const SAFETY_PAGE: &str = "https://google.github.io/adk-docs/safety/";
+        
+        let concepts = [
+            ("session", Citation::new(SESSION_PAGE, "the-session-object", &["Sessions", "Session", "The Session Object"])),
+            ("session_state", Citation::new(STATE_PAGE, "what-is-sessionstate", &["Sessions", "State", "What is session.state?"])),
+            ("session_service", Citation::new(SESSION_PAGE, "managing-sessions-with-a-sessionservice", &["Sessions", "Session", "Managing Sessions with a SessionService"])),
+            ("memory_service", Citation::new(MEMORY_PAGE, "the-memoryservice-role", &["Sessions", "Memory", "The MemoryService Role"])),
+            ("callbacks", Citation::new(CALLBACKS_PAGE, "the-callback-mechanism-interception-and-control", &["Callbacks", "The Callback Mechanism"])),
+            ("agent_callbacks", Citation::new(CALLBACK_TYPES_PAGE, "agent-lifecycle-callbacks", &["Callbacks", "Types of Callbacks", "Agent Lifecycle Callbacks"])),
+            ("model_callbacks", Citation::new(CALLBACK_TYPES_PAGE, "llm-interaction-callbacks", &["Callbacks", "Types of Callbacks", "LLM Interaction Callbacks"])),
+            ("tool_callbacks", Citation::new(CALLBACK_TYPES_PAGE, "tool-execution-callbacks", &["Callbacks", "Types of Callbacks", "Tool Execution Callbacks"])),
+            ("gemini_models", Citation::new(MODELS_PAGE, "using-google-gemini-models", &["Agents", "Models", "Using Google Gemini Models"])),
+            ("litellm_integration", Citation::new(MODELS_PAGE, "using-cloud-proprietary-models-via-litellm", &["Agents", "Models", "Using Cloud & Proprietary Models via LiteLLM"])),
+            ("trajectory_evaluation", Citation::new(EVALUATE_PAGE, "evaluating-trajectory-and-tool-use", &["Evaluate", "Evaluating Trajectory and Tool Use"])),
+            ("response_evaluation", Citation::new(EVALUATE_PAGE, "evaluation-criteria", &["Evaluate", "Evaluation Criteria"])),
+            ("sse_streaming", Citation::new(RUNCONFIG_PAGE, "streaming_mode", &["Runtime", "Runtime Configuration", "streaming_mode"])),
+            ("run_config", Citation::new(RUNCONFIG_PAGE, "runtime-parameters", &["Runtime", "Runtime Configuration", "Runtime Parameters"])),
+            ("mcp_tools", Citation::new(MCP_TOOLS_PAGE, "what-is-model-context-protocol-mcp", &["Tools", "MCP Tools", "What is Model Context Protocol (MCP)?"])),
+            ("mcp_toolset", Citation::new(MCP_TOOLS_PAGE, "1-using-mcp-servers-with-adk-agents-adk-as-an-mcp-client-in-adk-web", &["Tools", "MCP Tools", "Using MCP servers with ADK agents"])),
+            ("safety_guardrails", Citation::new(SAFETY_PAGE, "in-agent-callbacks", &["Safety and Security", "In-Agent Callbacks"])),
+            ("content_filtering", Citation::new(SAFETY_PAGE, "built-in-gemini-safety-features", &["Safety and Security", "Built-in Gemini Safety Features"])),
+            ("a2a_vs_sub_agents", Citation::new(A2A_INTRO_PAGE, "when-to-use-a2a-vs-local-sub-agents", &["A2A Protocol", "Introduction to A2A", "When to Use A2A vs. Local Sub-Agents"])),
+        ];
+        let practices = [
+            ("Choose the Session Service for the Environment", Citation::new(SESSION_PAGE, "managing-sessions-with-a-sessionservice", &["Sessions", "Session", "Managing Sessions with a SessionService"])),
+            ("Update State Through Events", Citation::new(STATE_PAGE, "how-state-is-updated-recommended-methods", &["Sessions", "State", "How State is Updated"])),
+            ("Use State Prefixes Deliberately", Citation::new(STATE_PAGE, "organizing-state-with-prefixes-scope-matters", &["Sessions", "State", "State Prefixes"])),
+            ("Separate Short-Term State from Long-Term Memory", Citation::new(MEMORY_PAGE, "the-memoryservice-role", &["Sessions", "Memory", "The MemoryService Role"])),
+            ("Keep Callbacks Focused and Fast", Citation::new(CALLBACK_PATTERNS_PAGE, "best-practices-for-callbacks", &["Callbacks", "Design Patterns and Best Practices", "Best Practices for Callbacks"])),
+            ("Handle Errors Inside Callbacks", Citation::new(CALLBACK_PATTERNS_PAGE, "best-practices-for-callbacks", &["Callbacks", "Design Patterns and Best Practices", "Best Practices for Callbacks"])),
+            ("Use Context State for Callback Coordination", Citation::new(CALLBACK_PATTERNS_PAGE, "best-practices-for-callbacks", &["Callbacks", "Design Patterns and Best Practices", "Best Practices for Callbacks"])),
+            ("Set Explicit Evaluation Criteria", Citation::new(EVALUATE_PAGE, "evaluation-criteria", &["Evaluate", "Evaluation Criteria"])),
+            ("Screen Inputs with Guardrail Callbacks", Citation::new(SAFETY_PAGE, "in-agent-callbacks", &["Safety and Security", "In-Agent Callbacks"])),
+            ("Run Tools with Least Privilege", Citation::new(SAFETY_PAGE, "identity-and-authorization", &["Safety and Security", "Identity and Authorization"])),
+            ("Prefer Sub-Agents Within One Deployment", Citation::new(A2A_INTRO_PAGE, "when-to-use-a2a-vs-local-sub-agents", &["A2A Protocol", "Introduction to A2A", "When to Use A2A vs. Local Sub-Agents"])),
+        ];
+        
+        concepts
+            .into_iter()
+            .map(|(key, citation)| (provenance_key("concept", key), citation))
+            .chain(practices.into_iter().map(|(title, citation)| (provenance_key("practice", title), citation)))
+            .collect()
+    }
+    
+    /// Project templates for bootstrapping ADK agents
+    fn initialize_default_templates() -> HashMap<String, ProjectTemplate> {
+        let file = |path: &str, content: &str| TemplateFile {
+            path: path.to_string(),
+            content: content.to_string(),
+        };
+        let env_file = r#"# Use Google AI Studio; set GOOGLE_GENAI_USE_VERTEXAI=TRUE to use Vertex AI instead
+GOOGLE_GENAI_USE_VERTEXAI=FALSE
+GOOGLE_API_KEY=your-api-key
+"#;
+        let setup_steps = |package: &str| vec![
+            "python -m venv .venv && source .venv/bin/activate".to_string(),
+            "pip install -r requirements.txt".to_string(),
+            format!("Fill in {}/.env with your credentials", package),
+            format!("adk run {} (terminal) or adk web (development UI) from the project root", package),
+        ];
+        
+        let mut templates = HashMap::new();
+        
+        templates.insert("single_agent".to_string(), ProjectTemplate {
+            name: "Single LlmAgent".to_string(),
+            description: "One LlmAgent with instructions, discoverable by the ADK CLI and development UI.".to_string(),
+            files: vec![
+                file("requirements.txt", "google-adk\n"),
+                file("my_agent/__init__.py", "from . import agent\n"),
+                file("my_agent/.env", env_file),
+                file("my_agent/agent.py", r#"from google.adk.agents import Agent
+
+root_agent = Agent(
+    name="assistant",
+    model="gemini-2.0-flash",
+    description="Answers general questions.",
+    instruction="You are a helpful assistant. Answer concisely and say when you are unsure.",
+)
+"#),
+            ],
+            setup_steps: setup_steps("my_agent"),
+        });
+        
+        templates.insert("multi_agent_team".to_string(), ProjectTemplate {
+            name: "Multi-Agent Team".to_string(),
+            description: "A coordinator LlmAgent that delegates to specialist sub-agents through agent transfer.".to_string(),
+            files: vec![
+                file("requirements.txt", "google-adk\n"),
+                file("agent_team/__init__.py", "from . import agent\n"),
+                file("agent_team/.env", env_file),
+                file("agent_team/agent.py", r#"from google.adk.agents import Agent
+
+MODEL = "gemini-2.0-flash"
+
+greeter = Agent(
+    name="greeter",
+    model=MODEL,
+    description="Handles greetings and small talk.",
+    instruction="Greet the user warmly and keep small talk brief.",
+)
+
+researcher = Agent(
+    name="researcher",
+    model=MODEL,
+    description="Answers factual questions in depth.",
+    instruction="Answer factual questions thoroughly and cite your reasoning.",
+)
+
+root_agent = Agent(
+    name="coordinator",
+    model=MODEL,
+    description="Routes each request to the best specialist.",
+    instruction=(
+        "Delegate greetings to greeter and factual questions to researcher. "
+        "Answer directly only when neither specialist applies."
+    ),
+    sub_agents=[greeter, researcher],
+)
+"#),
+            ],
+            setup_steps: setup_steps("agent_team"),
+        });
+        
+        templates.insert("agent_with_tools".to_string(), ProjectTemplate {
+            name: "Agent with Tools".to_string(),
+            description: "An LlmAgent calling Python function tools that return structured results.".to_string(),
+            files: vec![
+                file("requirements.txt", "google-adk\n"),
+                file("tool_agent/__init__.py", "from . import agent\n"),
+                file("tool_agent/.env", env_file),
+                file("tool_agent/tools.py", r#"def get_weather(city: str) -> dict:
+    """Retrieves the current weather report for a city.
+    
+    Args:
+        city: Name of the city.
+    
+    Returns:
+        dict with "status" and either "report" or "error_message".
+    """
+    if city.lower() == "london":
+        return {"status": "success", "report": "Cloudy, 15 degrees Celsius."}
+    return {"status": "error", "error_message": f"No weather data for {city}."}
+"#),
+                file("tool_agent/agent.py", r#"from google.adk.agents import Agent
+
+from .tools import get_weather
+
+root_agent = Agent(
+    name="weather_agent",
+    model="gemini-2.0-flash",
+    description="Reports the weather for a city.",
+    instruction=(
+        "Use get_weather to answer weather questions. "
+        "If the tool returns an error, explain it to the user."
+    ),
+    tools=[get_weather],
+)
+"#),
+            ],
+            setup_steps: setup_steps("tool_agent"),
+        });
+        
+        templates
+    }
+}
+
+/// Split a query into normalized search terms, dropping stop words
+///
+/// Terms are lowercased and a trailing plural 's' is stripped so that
+/// "callbacks" matches text mentioning "callback".
+pub fn query_terms(query: &str) -> Vec<String> {
+    const STOP_WORDS: &[&str] = &[
+        "a", "an", "and", "are", "can", "do", "does", "for", "how", "i", "in", "is", "it",
+        "my", "of", "on", "or", "the", "to", "use", "what", "when", "which", "with",
+        "adk", "google",
+    ];
+    
+    let mut terms = Vec::new();
+    for word in query.to_lowercase().split(|c: char| !c.is_alphanumeric()) {
+        if word.len() < 2 || STOP_WORDS.contains(&word) {
+            continue;
+        }
+        let term = if word.len() > 3 && word.ends_with('s') && !word.ends_with("ss") {
+            word[..word.len() - 1].to_string()
+        } else {
+            word.to_string()
+        };
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms
+}
+
+/// Order version strings numerically by dot-separated components
+///
+/// Non-numeric components compare as text, so "1.10.0" sorts after "1.9.0".
+pub fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    let mut left = a.trim_start_matches('v').split('.');
+    let mut right = b.trim_start_matches('v').split('.');
+    loop {
+        let ordering = match (left.next(), right.next()) {
+            (None, None) => return std::cmp::Ordering::Equal,
+            (Some(_), None) => std::cmp::Ordering::Greater,
+            (None, Some(_)) => std::cmp::Ordering::Less,
+            (Some(l), Some(r)) => match (l.parse::<u64>(), r.parse::<u64>()) {
+                (Ok(l), Ok(r)) => l.cmp(&r),
+                _ => l.cmp(r),
+            },
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+}
+
+/// Dice coefficient over character bigrams, tolerant of typos and inflections
+fn bigram_similarity(a: &str, b: &str) -> f32 {
+    let bigrams = |word: &str| {
+        let chars: Vec<char> = word.chars().collect();
+        chars.windows(2).map(|pair| (pair[0], pair[1])).collect::<Vec<_>>()
+    };
+    
+    let a_bigrams = bigrams(a);
+    let mut b_bigrams = bigrams(b);
+    if a_bigrams.is_empty() || b_bigrams.is_empty() {
+        return if a == b { 1.0 } else { 0.0 };
+    }
+    
+    let total = (a_bigrams.len() + b_bigrams.len()) as f32;
+    let mut shared = 0;
+    for bigram in a_bigrams {
+        if let Some(index) = b_bigrams.iter().position(|b| *b == bigram) {
+            b_bigrams.swap_remove(index);
+            shared += 1;
+        }
+    }
+    
+    2.0 * shared as f32 / total
+}
+
+/// Score how well query terms match an entry's name, description, and details
+///
+/// Each term contributes the weight of the most prominent field it appears in;
+/// the total is normalized against every term matching the name.
+fn relevance_score(terms: &[String], name: &str, description: &str, details: &str) -> f32 {
+    const NAME_WEIGHT: f32 = 3.0;
+    const DESCRIPTION_WEIGHT: f32 = 1.5;
+    const DETAIL_WEIGHT: f32 = 0.5;
+    
+    if terms.is_empty() {
+        return 0.0;
+    }
+    
+    let name = name.to_lowercase();
+    let description = description.to_lowercase();
+    let details = details.to_lowercase();
+    
+    let raw: f32 = terms
+        .iter()
+        .map(|term| {
+            if name.contains(term.as_str()) {
+                NAME_WEIGHT
+            } else if description.contains(term.as_str()) {
+                DESCRIPTION_WEIGHT
+            } else if details.contains(term.as_str()) {
+                DETAIL_WEIGHT
+            } else {
+                0.0
+            }
+        })
+        .sum();
+    
+    (raw / (NAME_WEIGHT * terms.len() as f32)).min(1.0)
+}
+
+/// Order scores from highest to lowest
+fn compare_scores(a: f32, b: f32) -> std::cmp::Ordering {
+    b.partial_cmp(&a).unwrap_or(std::cmp::Ordering::Equal)
+}
+
+impl VersionDocs {
+    /// Merge entries from another set of docs for the same version
+    ///
+    /// Entries from `other` replace existing entries with the same key
+    /// (concept/pattern/template key, practice title, glossary term, feature name, change title);
+    /// everything else is appended. Official URLs are kept from `self`.
+    /// Community-sourced entries never replace official ones.
+    pub fn merge_from(&mut self, other: VersionDocs) {
+        let VersionDocs {
+            concepts,
+            best_practices,
+            implementation_patterns,
+            version_features,
+            glossary,
+            templates,
+            changelog,
+            provenance,
+            citations,
+            ..
+        } = other;
+        
+        for (key, concept) in concepts {
+            let id = provenance_key("concept", &key);
+            if self.accept_entry(&id, self.concepts.contains_key(&key), provenance.get(&id)) {
+                self.adopt_citation(&id, &citations);
+                self.concepts.insert(key, concept);
+            }
+        }
+        
+        for (key, pattern) in implementation_patterns {
+            let id = provenance_key("pattern", &key);
+            if self.accept_entry(&id, self.implementation_patterns.contains_key(&key), provenance.get(&id)) {
+                self.implementation_patterns.insert(key, pattern);
+            }
+        }
+        
+        for practice in best_practices {
+            let id = provenance_key("practice", &practice.title);
+            let position = self.best_practices.iter().position(|p| p.title == practice.title);
+            if self.accept_entry(&id, position.is_some(), provenance.get(&id)) {
+                self.adopt_citation(&id, &citations);
+                match position {
+                    Some(index) => self.best_practices[index] = practice,
+                    None => self.best_practices.push(practice),
+                }
+            }
+        }
+        
+        for feature in version_features {
+            let id = provenance_key("feature", &feature.name);
+            let position = self.version_features.iter().position(|f| f.name == feature.name);
+            if self.accept_entry(&id, position.is_some(), provenance.get(&id)) {
+                match position {
+                    Some(index) => self.version_features[index] = feature,
+                    None => self.version_features.push(feature),
+                }
+            }
+        }
+        
+        for entry in glossary {
+            let id = provenance_key("glossary", &entry.term);
+            let position = self.glossary.iter().position(|e| e.term.eq_ignore_ascii_case(&entry.term));
+            if self.accept_entry(&id, position.is_some(), provenance.get(&id)) {
+                match position {
+                    Some(index) => self.glossary[index] = entry,
+                    None => self.glossary.push(entry),
+                }
+            }
+        }
+        
+        for (key, template) in templates {
+            let id = provenance_key("template", &key);
+            if self.accept_entry(&id, self.templates.contains_key(&key), provenance.get(&id)) {
+                self.templates.insert(key, template);
+            }
+        }
+        
+        for change in changelog {
+            let id = provenance_key("change", &change.title);
+            let position = self.changelog.iter().position(|c| c.title.eq_ignore_ascii_case(&change.title));
+            if self.accept_entry(&id, position.is_some(), provenance.get(&id)) {
+                match position {
+                    Some(index) => self.changelog[index] = change,
+                    None => self.changelog.push(change),
+                }
+            }
+        }
+    }
+    
+    /// Drop entries `existing` already holds, keeping new entries and official replacements of community entries
+    ///
+    /// Used when merging content older than what is loaded, so it only fills gaps.
+    pub fn retain_new_entries(&mut self, existing: &VersionDocs) {
+        let provenance = std::mem::take(&mut self.provenance);
+        let keep = |id: String, exists: bool| {
+            !exists || (provenance.get(&id).is_none_or(Provenance::is_official)
+                && existing.provenance.get(&id).is_some_and(|p| !p.is_official()))
+        };
+        
+        self.concepts.retain(|key, _| keep(provenance_key("concept", key), existing.concepts.contains_key(key)));
+        self.implementation_patterns.retain(|key, _| {
+            keep(provenance_key("pattern", key), existing.implementation_patterns.contains_key(key))
+        });
+        self.best_practices.retain(|practice| {
+            keep(provenance_key("practice", &practice.title), existing.best_practices.iter().any(|p| p.title == practice.title))
+        });
+        self.version_features.retain(|feature| {
+            keep(provenance_key("feature", &feature.name), existing.version_features.iter().any(|f| f.name == feature.name))
+        });
+        self.glossary.retain(|entry| {
+            keep(provenance_key("glossary", &entry.term), existing.glossary.iter().any(|e| e.term.eq_ignore_ascii_case(&entry.term)))
+        });
+        self.templates.retain(|key, _| keep(provenance_key("template", key), existing.templates.contains_key(key)));
+        self.changelog.retain(|change| {
+            keep(provenance_key("change", &change.title), existing.changelog.iter().any(|c| c.title.eq_ignore_ascii_case(&change.title)))
+        });
+        
+        self.provenance = provenance;
+    }
+    
+    /// Number of concepts, practices, patterns, features, glossary entries, templates, and changes
+    pub fn entry_count(&self) -> usize {
+        self.concepts.len()
+            + self.best_practices.len()
+            + self.implementation_patterns.len()
+            + self.version_features.len()
+            + self.glossary.len()
+            + self.templates.len()
+            + self.changelog.len()
+    }
+    
+    /// Attribute every entry in these docs to `provenance`
+    pub fn stamp_provenance(&mut self, provenance: &Provenance) {
+        let ids = self.concepts.keys().map(|key| provenance_key("concept", key))
+            .chain(self.implementation_patterns.keys().map(|key| provenance_key("pattern", key)))
+            .chain(self.best_practices.iter().map(|p| provenance_key("practice", &p.title)))
+            .chain(self.version_features.iter().map(|f| provenance_key("feature", &f.name)))
+            .chain(self.glossary.iter().map(|e| provenance_key("glossary", &e.term)))
+            .chain(self.templates.keys().map(|key| provenance_key("template", key)))
+            .chain(self.changelog.iter().map(|c| provenance_key("change", &c.title)))
+            .collect::<Vec<_>>();
+        
+        for id in ids {
+            self.provenance.insert(id, provenance.clone());
+        }
+    }
+    
+    /// Stamp concepts and best practices that carry no update time with `timestamp`
+    pub fn stamp_last_updated(&mut self, timestamp: i64) {
+        for concept in self.concepts.values_mut() {
+            concept.last_updated.get_or_insert(timestamp);
+        }
+        for practice in &mut self.best_practices {
+            practice.last_updated.get_or_insert(timestamp);
+        }
+    }
+    
+    /// Provenance of a concept, or `None` when it is official
+    pub fn concept_provenance(&self, key: &str) -> Option<&Provenance> {
+        self.provenance.get(&provenance_key("concept", key))
+    }
+    
+    /// Provenance of an implementation pattern, or `None` when it is official
+    pub fn pattern_provenance(&self, key: &str) -> Option<&Provenance> {
+        self.provenance.get(&provenance_key("pattern", key))
+    }
+    
+    /// Provenance of a project template, or `None` when it is official
+    pub fn template_provenance(&self, key: &str) -> Option<&Provenance> {
+        self.provenance.get(&provenance_key("template", key))
+    }
+    
+    /// Provenance of a best practice, or `None` when it is official
+    pub fn practice_provenance(&self, title: &str) -> Option<&Provenance> {
+        self.provenance.get(&provenance_key("practice", title))
+    }
+    
+    /// Documentation section supporting a concept, if known
+    pub fn concept_citation(&self, key: &str) -> Option<&Citation> {
+        self.citations.get(&provenance_key("concept", key))
+    }
+    
+    /// Documentation section supporting a best practice, if known
+    pub fn practice_citation(&self, title: &str) -> Option<&Citation> {
+        self.citations.get(&provenance_key("practice", title))
+    }
+    
+    /// Take the incoming citation for a merged entry, dropping a stale one when there is none
+    fn adopt_citation(&mut self, id: &str, citations: &HashMap<String, Citation>) {
+        match citations.get(id) {
+            Some(citation) => {
+                self.citations.insert(id.to_string(), citation.clone());
+            }
+            None => {
+                self.citations.remove(id);
+            }
+        }
+    }
+    
+    /// Decide whether an incoming entry may be merged, recording its provenance if so
+    fn accept_entry(&mut self, id: &str, exists: bool, incoming: Option<&Provenance>) -> bool {
+        let incoming_official = incoming.is_none_or(Provenance::is_official);
+        let existing_official = !exists || self.provenance.get(id).is_none_or(Provenance::is_official);
+        
+        // Third-party packs may add new entries or replace other community entries,
+        // but official guidance always wins over community guidance
+        if exists && existing_official && !incoming_official {
+            return false;
+        }
+        
+        match incoming {
+            Some(provenance) if !provenance.is_official() => {
+                self.provenance.insert(id.to_string(), provenance.clone());
+            }
+            _ => {
+                self.provenance.remove(id);
+            }
+        }
+        true
+    }
+    
+    /// Concept whose key or name matches a lowercase name, with spaces or dashes standing in for underscores
+    fn concept_named(&self, name: &str) -> Option<&ConceptInfo> {
+        let key = name.replace([' ', '-'], "_");
+        self.concepts.get(&key).or_else(|| {
+            self.concepts.values().find(|concept| concept.name.to_lowercase() == name)
+        })
+    }
+}
+
+/// Key identifying an entry in a `VersionDocs` provenance map
+fn provenance_key(kind: &str, id: &str) -> String {
+    format!("{}:{}", kind, id.to_lowercase())
+}
+
+impl Provenance {
+    /// Whether this entry comes from official Google ADK documentation
+    pub fn is_official(&self) -> bool {
+        self.trust_level == TrustLevel::Official
+    }
+}
+
+impl TrustLevel {
+    /// Human-readable label for display in responses
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Official => "Official",
+            Self::Verified => "Verified community",
+            Self::Community => "Community",
+        }
+    }
+}
+
+impl Availability {
+    /// Human-readable label for display in responses
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Available => "Available",
+            Self::Deprecated => "Deprecated",
+            Self::NotAvailable => "Not available",
+            Self::Unknown => "Unknown",
+        }
+    }
+}
+
+impl ChangeKind {
+    /// Section heading for changes of this kind in a release digest
+    pub fn heading(&self) -> &'static str {
+        match self {
+            Self::Breaking => "Breaking Changes",
+            Self::Feature => "New Features",
+            Self::Deprecation => "Deprecations",
+            Self::Fix => "Notable Fixes",
+        }
+    }
+}
+
+impl ReleaseChannel {
+    /// Every channel, from most to least mature
+    pub const ALL: [ReleaseChannel; 3] = [Self::Stable, Self::Preview, Self::Next];
+    
+    /// Parse a channel name, ignoring case and surrounding whitespace
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.trim();
+        Self::ALL.into_iter().find(|channel| channel.name().eq_ignore_ascii_case(name))
+    }
+    
+    /// Channel name, also used as its version alias
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Preview => "preview",
+            Self::Next => "next",
+        }
+    }
+}
+
+impl VersionStatus {
+    /// Human-readable label for display in responses
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Supported => "Supported",
+            Self::Unavailable => "Unavailable",
+        }
+    }
 }
 
 impl Default for AdkKnowledgeBase {
@@ -326,16 +4582,23 @@ impl VersionConfig {
     pub fn new() -> Self {
         let default_version = std::env::var("ADK_DOCS_VERSION")
             .unwrap_or_else(|_| "latest".to_string());
-            
+        
         let mut version_aliases = HashMap::new();
         version_aliases.insert("latest".to_string(), "1.0.0".to_string());
         version_aliases.insert("stable".to_string(), "1.0.0".to_string());
         
+        let mut channels = HashMap::new();
+        channels.insert(ReleaseChannel::Stable, ChannelConfig { version: "1.0.0".to_string(), feed_url: None });
+        
         Self {
             default_version: default_version.clone(),
             available_versions: vec!["1.0.0".to_string(), "latest".to_string()],
             version_aliases,
             auto_update_enabled: true,
+            strict_versions: std::env::var(STRICT_VERSIONS_ENV)
+                .map(|value| matches!(value.trim().to_lowercase().as_str(), "1" | "true" | "yes"))
+                .unwrap_or(false),
+            channels,
         }
     }
     
@@ -360,10 +4623,28 @@ impl VersionConfig {
         }
     }
     
-    /// Set version alias
+    /// Set version alias, moving the release channel of the same name along with it
     pub fn set_alias(&mut self, alias: String, target_version: String) {
+        if let Some(channel) = ReleaseChannel::parse(&alias).filter(|channel| channel.name() == alias) {
+            self.channels
+                .entry(channel)
+                .and_modify(|config| config.version = target_version.clone())
+                .or_insert_with(|| ChannelConfig { version: target_version.clone(), feed_url: None });
+        }
         self.version_aliases.insert(alias, target_version);
     }
+    
+    /// Point a release channel at a version, keeping the channel alias in sync
+    pub fn set_channel(&mut self, channel: ReleaseChannel, config: ChannelConfig) {
+        self.add_version(config.version.clone());
+        self.version_aliases.insert(channel.name().to_string(), config.version.clone());
+        self.channels.insert(channel, config);
+    }
+    
+    /// Version a release channel currently points to
+    pub fn channel_version(&self, channel: ReleaseChannel) -> Option<&str> {
+        self.channels.get(&channel).map(|config| config.version.as_str())
+    }
 }
 
 impl Default for VersionConfig {
@@ -375,19 +4656,39 @@ impl Default for VersionConfig {
 impl DocumentationUrls {
     /// Create new documentation URLs with Google ADK defaults
     pub fn new() -> Self {
+        Self::with_base(DOCS_BASE_URL)
+    }
+    
+    /// Documentation URLs for a specific version, served under `{DOCS_BASE_URL}/{version}/`
+    pub fn for_version(version: &str) -> Self {
+        Self::with_base(&format!("{}/{}", DOCS_BASE_URL, version))
+    }
+    
+    /// Rewrite a link into the unversioned documentation site so it points at `version`
+    ///
+    /// Links outside the documentation site are returned unchanged.
+    pub fn versioned_url(url: &str, version: &str) -> String {
+        match url.strip_prefix(DOCS_BASE_URL).and_then(|rest| rest.strip_prefix('/')) {
+            Some(path) => format!("{}/{}/{}", DOCS_BASE_URL, version, path),
+            None => url.to_string(),
+        }
+    }
+    
+    /// Documentation URLs rooted at `base`
+    fn with_base(base: &str) -> Self {
         Self {
-            quickstart: "https://google.github.io/adk-docs/get-started/quickstart/".to_string(),
+            quickstart: format!("{}/get-started/quickstart/", base),
             api_reference: vec![
-                "https://google.github.io/adk-docs/api/".to_string(),
+                format!("{}/api/", base),
             ],
             tutorials: vec![
-                "https://google.github.io/adk-docs/tutorials/".to_string(),
+                format!("{}/tutorials/", base),
             ],
             best_practices: vec![
-                "https://google.github.io/adk-docs/best-practices/".to_string(),
+                format!("{}/best-practices/", base),
             ],
             migration_guides: vec![
-                "https://google.github.io/adk-docs/migration/".to_string(),
+                format!("{}/migration/", base),
             ],
         }
     }
@@ -0,0 +1,190 @@
+//! Component graphs inferred from architecture descriptions
+//!
+//! `validate_architecture` reads the agents, tools, and services a description mentions
+//! and how they relate, and renders them as a Mermaid flowchart so a reviewer can check
+//! that the server understood the proposed design. Within a sentence, the first agent
+//! mentioned is taken to use or delegate to every other component in it; components in
+//! sentences without an agent are attached to the first agent of the description, and
+//! an unnamed "the agent" refers back to the last agent named.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use crate::expert::best_practices::is_negated;
+
+/// Words that do not name a component when they precede "agent", "tool", or "service"
+const STOPWORDS: &[&str] = &[
+    "a", "an", "the", "each", "every", "one", "two", "three", "multiple", "several", "many",
+    "other", "another", "single", "our", "their", "its", "this", "that", "these", "those",
+    "with", "and", "or", "to", "of", "for", "by", "from", "in", "on", "all", "some", "any",
+    "which", "no", "new", "more", "uses", "use", "calls", "call", "has", "have", "is", "are",
+];
+
+/// Identifier of an agent mentioned without a name, e.g. "the agent"
+const GENERIC_AGENT_ID: &str = "agent";
+
+/// Kind of component in an architecture
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentKind {
+    /// An agent
+    Agent,
+    /// A tool called by an agent
+    Tool,
+    /// An external service, API, or data store
+    Service,
+}
+
+/// Component named in an architecture description
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Component {
+    /// Mermaid node identifier
+    pub id: String,
+    /// Display label, e.g. "Billing Agent"
+    pub label: String,
+    /// Component kind
+    pub kind: ComponentKind,
+}
+
+/// Agents, tools, and services inferred from a description, with who uses whom
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ComponentGraph {
+    /// Components in order of first mention
+    pub components: Vec<Component>,
+    /// Edges as (from, to) component ids
+    pub edges: Vec<(String, String)>,
+}
+
+impl ComponentGraph {
+    /// Infer the component graph of a description
+    pub fn from_description(description: &str) -> Self {
+        let mut graph = ComponentGraph::default();
+        let mut root: Option<String> = None;
+        let mut last_named_agent: Option<Component> = None;
+        let mut detached = Vec::new();
+        
+        for sentence in description.split(['.', ';', '!', '?', '\n']) {
+            let mut mentioned = mentioned_components(sentence);
+            for component in mentioned.iter_mut().filter(|component| component.kind == ComponentKind::Agent) {
+                if component.id != GENERIC_AGENT_ID {
+                    last_named_agent = Some(component.clone());
+                } else if let Some(named) = &last_named_agent {
+                    *component = named.clone();
+                }
+            }
+            for component in &mentioned {
+                if !graph.components.iter().any(|existing| existing.id == component.id) {
+                    graph.components.push(component.clone());
+                }
+            }
+            
+            match mentioned.iter().find(|component| component.kind == ComponentKind::Agent) {
+                Some(subject) => {
+                    root.get_or_insert_with(|| subject.id.clone());
+                    for component in &mentioned {
+                        graph.add_edge(&subject.id, &component.id);
+                    }
+                }
+                None => detached.extend(mentioned.into_iter().map(|component| component.id)),
+            }
+        }
+        
+        if let Some(root) = root {
+            for id in detached {
+                graph.add_edge(&root, &id);
+            }
+        }
+        
+        graph
+    }
+    
+    /// Whether the graph is worth drawing: at least one agent connected to something
+    pub fn is_drawable(&self) -> bool {
+        !self.edges.is_empty()
+    }
+    
+    /// Render the graph as a Mermaid flowchart
+    ///
+    /// Agents are drawn as boxes, tools as subroutines, and services as cylinders.
+    pub fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("flowchart TD\n");
+        for component in &self.components {
+            let node = match component.kind {
+                ComponentKind::Agent => format!("[\"{}\"]", component.label),
+                ComponentKind::Tool => format!("[[\"{}\"]]", component.label),
+                ComponentKind::Service => format!("[(\"{}\")]", component.label),
+            };
+            mermaid.push_str(&format!("    {}{}\n", component.id, node));
+        }
+        for (from, to) in &self.edges {
+            mermaid.push_str(&format!("    {} --> {}\n", from, to));
+        }
+        mermaid
+    }
+    
+    /// Add an edge unless it is a self-loop or already present
+    fn add_edge(&mut self, from: &str, to: &str) {
+        let edge = (from.to_string(), to.to_string());
+        if from != to && !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+}
+
+/// Components mentioned in one sentence, in order of mention, skipping negated mentions
+fn mentioned_components(sentence: &str) -> Vec<Component> {
+    let patterns = [
+        (ComponentKind::Agent, r"(?i)\b([a-z][\w-]*)\s+(?:sub-?)?agents?\b", true),
+        (ComponentKind::Tool, r"(?i)\b([a-z][\w-]*)\s+tools?\b", false),
+        (ComponentKind::Service, r"(?i)\b([a-z][\w-]*)\s+(services?|apis?|databases?|db|queues?|stores?|caches?|buckets?)\b", true),
+    ];
+    
+    let mut mentions = Vec::new();
+    for (kind, pattern, allow_generic) in patterns {
+        let regex = Regex::new(pattern).expect("component pattern is valid");
+        for captures in regex.captures_iter(sentence) {
+            let whole = captures.get(0).expect("match has a whole capture");
+            if is_negated(sentence, whole.start()) {
+                continue;
+            }
+            let qualifier = &captures[1];
+            let noun = captures.get(2).map(|noun| noun.as_str()).unwrap_or(match kind {
+                ComponentKind::Agent => "agent",
+                ComponentKind::Tool => "tool",
+                ComponentKind::Service => "service",
+            });
+            let noun = singular(noun);
+            
+            let words = if STOPWORDS.contains(&qualifier.to_lowercase().as_str()) {
+                if !allow_generic {
+                    continue;
+                }
+                vec![noun]
+            } else {
+                vec![qualifier, noun]
+            };
+            let label = words.iter().map(|word| capitalize(word)).collect::<Vec<_>>().join(" ");
+            let id = words.iter().map(|word| word.to_lowercase().replace('-', "_")).collect::<Vec<_>>().join("_");
+            mentions.push((whole.start(), Component { id, label, kind }));
+        }
+    }
+    
+    mentions.sort_by_key(|(start, _)| *start);
+    mentions.into_iter().map(|(_, component)| component).collect()
+}
+
+/// Singular form of a component noun
+fn singular(noun: &str) -> &str {
+    match noun.to_lowercase().as_str() {
+        "services" | "apis" | "databases" | "queues" | "stores" | "caches" | "buckets" => &noun[..noun.len() - 1],
+        _ => noun,
+    }
+}
+
+/// Capitalize the first letter of a word, keeping the rest as written
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
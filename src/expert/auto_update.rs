@@ -0,0 +1,256 @@
+//! Scheduled background refresh of ADK documentation content
+//!
+//! When `VersionConfig::auto_update_enabled` is set and an update feed is configured,
+//! a tokio task periodically fetches fresh `VersionDocs` and merges them into the shared
+//! experts (and the persistent store, if one is open). Refreshes are spread out with
+//! random jitter and back off exponentially while the feed keeps failing.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use tokio::task::JoinHandle;
+use tracing::{debug, info};
+use crate::expert::SharedExperts;
+use crate::expert::adk_knowledge::VersionDocs;
+use crate::expert::knowledge_source::{KnowledgeSource, WEB_PRIORITY};
+use crate::utils::error::{ArkaftMcpError, ArkaftResult};
+use crate::utils::{log_error_with_severity, ServerMetrics};
+
+/// Environment variable naming the JSON feed of version documentation
+pub const UPDATE_FEED_ENV: &str = "ADK_UPDATE_FEED_URL";
+
+/// Environment variable overriding the refresh interval in seconds
+pub const UPDATE_INTERVAL_ENV: &str = "ADK_UPDATE_INTERVAL_SECS";
+
+/// Default time between refreshes
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Upper bound on the delay after repeated failures
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Timeout for a single feed request
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fetches version documentation from a JSON feed over HTTP
+///
+/// The feed must return a JSON array of `VersionDocs` objects.
+pub struct WebDocsFetcher {
+    /// URL of the documentation feed
+    feed_url: String,
+    /// Reusable HTTP client
+    client: reqwest::Client,
+    /// Merge priority when aggregated with other sources
+    priority: i32,
+}
+
+impl WebDocsFetcher {
+    /// Create a fetcher for the given feed URL
+    pub fn new<S: Into<String>>(feed_url: S) -> ArkaftResult<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(FETCH_TIMEOUT)
+            .user_agent(concat!("arkaft-mcp-google-adk/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| ArkaftMcpError::knowledge_update(format!("Failed to build HTTP client: {}", e)))?;
+        
+        Ok(Self {
+            feed_url: feed_url.into(),
+            client,
+            priority: WEB_PRIORITY,
+        })
+    }
+    
+    /// Override the merge priority
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+    
+    /// Create a fetcher from `ADK_UPDATE_FEED_URL`, if set
+    pub fn from_env() -> Option<ArkaftResult<Self>> {
+        std::env::var(UPDATE_FEED_ENV)
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+            .map(Self::new)
+    }
+    
+    /// URL of the documentation feed
+    pub fn feed_url(&self) -> &str {
+        &self.feed_url
+    }
+}
+
+#[async_trait]
+impl KnowledgeSource for WebDocsFetcher {
+    fn name(&self) -> &str {
+        "web-feed"
+    }
+    
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+    
+    async fn fetch_all(&self) -> ArkaftResult<Vec<VersionDocs>> {
+        let response = self.client
+            .get(&self.feed_url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| ArkaftMcpError::knowledge_update(format!("Failed to fetch {}: {}", self.feed_url, e)))?;
+        
+        response
+            .json::<Vec<VersionDocs>>()
+            .await
+            .map_err(|e| ArkaftMcpError::knowledge_update(format!("Invalid feed from {}: {}", self.feed_url, e)))
+    }
+}
+
+/// Timing configuration for scheduled refreshes
+#[derive(Clone, Debug)]
+pub struct AutoUpdateSettings {
+    /// Base time between successful refreshes
+    pub interval: Duration,
+    /// Maximum random delay added to each wait
+    pub jitter: Duration,
+    /// Upper bound on the delay after repeated failures
+    pub max_backoff: Duration,
+}
+
+impl AutoUpdateSettings {
+    /// Build settings from `ADK_UPDATE_INTERVAL_SECS`, falling back to defaults
+    pub fn from_env() -> Self {
+        let interval = std::env::var(UPDATE_INTERVAL_ENV)
+            .ok()
+            .and_then(|secs| secs.trim().parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_INTERVAL);
+        
+        Self::with_interval(interval)
+    }
+    
+    /// Build settings for an interval, with jitter of a tenth of the interval
+    pub fn with_interval(interval: Duration) -> Self {
+        Self {
+            interval,
+            jitter: interval / 10,
+            max_backoff: DEFAULT_MAX_BACKOFF.max(interval),
+        }
+    }
+    
+    /// Delay before the next refresh after `consecutive_failures` failures in a row
+    ///
+    /// The interval doubles with each failure up to `max_backoff`; jitter is added on top.
+    pub fn next_delay(&self, consecutive_failures: u32) -> Duration {
+        let factor = 1u32 << consecutive_failures.min(16);
+        let base = self.interval.saturating_mul(factor).min(self.max_backoff);
+        base + random_jitter(self.jitter)
+    }
+}
+
+impl Default for AutoUpdateSettings {
+    fn default() -> Self {
+        Self::with_interval(DEFAULT_INTERVAL)
+    }
+}
+
+/// Periodically refreshes the shared experts from a documentation source
+pub struct AutoUpdater {
+    /// Timing configuration
+    settings: AutoUpdateSettings,
+    /// Source of refreshed documentation
+    source: Arc<dyn KnowledgeSource>,
+    /// Experts updated in place
+    experts: SharedExperts,
+    /// Metrics recording refresh attempts
+    metrics: Arc<ServerMetrics>,
+}
+
+impl AutoUpdater {
+    /// Create an updater for the shared experts
+    pub fn new(
+        settings: AutoUpdateSettings,
+        source: Arc<dyn KnowledgeSource>,
+        experts: SharedExperts,
+        metrics: Arc<ServerMetrics>,
+    ) -> Self {
+        Self {
+            settings,
+            source,
+            experts,
+            metrics,
+        }
+    }
+    
+    /// Fetch once and merge the results, returning the number of versions refreshed
+    pub async fn refresh_once(&self) -> ArkaftResult<usize> {
+        self.metrics.record_refresh_attempt();
+        
+        let result = self.apply_updates().await;
+        if result.is_err() {
+            self.metrics.record_refresh_failure();
+        }
+        result
+    }
+    
+    /// Spawn the refresh loop if auto-update is enabled in the knowledge base's version config
+    pub async fn spawn_if_enabled(self) -> Option<JoinHandle<()>> {
+        if !self.experts.auto_update_enabled().await {
+            info!("Scheduled documentation updates are disabled");
+            return None;
+        }
+        
+        Some(self.spawn())
+    }
+    
+    /// Spawn the refresh loop on the tokio runtime
+    ///
+    /// The first refresh runs right away, so a freshly started server does not serve the
+    /// bundled documentation for a whole interval.
+    pub fn spawn(self) -> JoinHandle<()> {
+        info!("Scheduling documentation updates every {:?} (jitter up to {:?})", self.settings.interval, self.settings.jitter);
+        
+        tokio::spawn(async move {
+            let mut consecutive_failures = 0u32;
+            
+            loop {
+                match self.refresh_once().await {
+                    Ok(count) => {
+                        consecutive_failures = 0;
+                        info!("Refreshed documentation for {} version(s)", count);
+                    }
+                    Err(e) => {
+                        consecutive_failures = consecutive_failures.saturating_add(1);
+                        log_error_with_severity(&e, "scheduled_documentation_update");
+                    }
+                }
+                
+                let delay = self.settings.next_delay(consecutive_failures);
+                debug!("Next documentation refresh in {:?}", delay);
+                tokio::time::sleep(delay).await;
+            }
+        })
+    }
+    
+    /// Fetch updates and merge them into the shared experts
+    async fn apply_updates(&self) -> ArkaftResult<usize> {
+        let updates = self.source.fetch_all().await?;
+        let count = updates.len();
+        
+        self.experts.merge_version_docs(updates).await?;
+        
+        Ok(count)
+    }
+}
+
+/// Random delay between zero and `max`, used to spread out refreshes
+fn random_jitter(max: Duration) -> Duration {
+    let max_millis = max.as_millis() as u64;
+    if max_millis == 0 {
+        return Duration::ZERO;
+    }
+    
+    let random = RandomState::new().build_hasher().finish();
+    Duration::from_millis(random % (max_millis + 1))
+}
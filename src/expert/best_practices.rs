@@ -3,9 +3,199 @@
 //! Provides comprehensive validation and enforcement of Google ADK best practices,
 //! architectural patterns, and official guidelines.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use regex::{Match, Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 use crate::expert::adk_knowledge::{BestPractice, ImplementationPattern, AdkKnowledgeBase};
+use crate::expert::rule_sets::RuleSet;
+use crate::expert::architecture_diagram::ComponentGraph;
+use crate::expert::custom_rules::{CustomRule, RuleTarget};
+use crate::expert::fallback_practices::fallback_practices;
+use crate::expert::orchestration::AgentTopology;
+use crate::expert::relevance::RelevanceIndex;
+use crate::expert::suppressions::{SuppressedFinding, Suppressions};
+use crate::expert::snippets::{
+    PythonAnalysis, SnippetLanguage, injection_prone_descriptions, largest_tool_list, secret_lines, state_accumulation_lines,
+};
+use crate::review::analyzer::{CodeStructure, RustCodeAnalyzer};
+
+/// Weight of an entry's title against its body when ranking it for a scenario
+const TITLE_WEIGHT: f32 = 3.0;
+
+/// Tools one agent can hold before a snippet is reported as a god agent
+const GOD_AGENT_TOOL_LIMIT: usize = 10;
+
+/// Documentation referenced when a multi-agent orchestration rule fires
+const MULTI_AGENT_DOCS: &str = "https://google.github.io/adk-docs/agents/multi-agents/";
+
+/// ADK criteria of the validation checklist
+///
+/// A criterion fails when any finding comes from one of its rules. A criterion with an
+/// evidence pattern is not addressed when the description never affirms it.
+const CHECKLIST_CRITERIA: &[ChecklistCriterion] = &[
+    ChecklistCriterion {
+        name: "Project structure",
+        rule_ids: &[
+            "adk_structure",
+            "god_agent",
+            "god_agent_tools",
+            "broad_tool_parameters",
+            "python_broad_tool_params",
+            "delegation_depth",
+            "circular_sub_agents",
+            "missing_root_agent",
+            "official_patterns",
+        ],
+        evidence: None,
+        guidance: "Follow the official ADK project layout from the quickstart guide",
+    },
+    ChecklistCriterion {
+        name: "Error handling",
+        rule_ids: &[
+            "error_handling",
+            "unwrap_usage",
+            "panic_usage",
+            "python_bare_except",
+            "python_tool_error_contract",
+        ],
+        evidence: None,
+        guidance: "Return errors from agents and tools instead of unwrapping, panicking, or swallowing them",
+    },
+    ChecklistCriterion {
+        name: "Async usage",
+        rule_ids: &[
+            "async_patterns",
+            "python_blocking_call_in_async",
+            "sync_llm_chaining",
+            "python_sync_llm_chain",
+            "parallelizable_llm_calls",
+            "python_sequential_awaits",
+            "missing_streaming",
+            "chatty_tool_loop",
+            "python_chatty_loop",
+            "missing_long_running_tool",
+            "python_polling_tool",
+        ],
+        evidence: None,
+        guidance: "Use async I/O in agents and tools so one session cannot block the others",
+    },
+    ChecklistCriterion {
+        name: "Session handling",
+        rule_ids: &[
+            "shared_global_state",
+            "missing_session_persistence",
+            "static_mut_state",
+            "in_memory_sessions",
+            "python_global_state",
+            "unbounded_session_state",
+            "state_accumulation",
+            "parallel_state_contention",
+        ],
+        evidence: Some(r"\bsessions?\b|\bstate\b|\bmemory\b"),
+        guidance: "Describe how sessions and state are stored, e.g. a database-backed SessionService in production",
+    },
+    ChecklistCriterion {
+        name: "Safety",
+        rule_ids: &[
+            "safety_guardrails",
+            "tool_confirmation",
+            "hardcoded_secrets",
+            "unvalidated_tool_input",
+            "unauthenticated_endpoint",
+            "prompt_injection_exposure",
+            "secret_in_code",
+            "tool_description_injection",
+            "python_unvalidated_tool_input",
+        ],
+        evidence: None,
+        guidance: "Add guardrail callbacks, require confirmation for tools with side effects, and keep secrets, tool inputs, and endpoints locked down",
+    },
+    ChecklistCriterion {
+        name: "Deployment readiness",
+        rule_ids: &[],
+        evidence: Some(r"\bdeploy(?:s|ed|ing|ment)?\b|\bcloud\s+run\b|\bagent\s+engine\b|\bgke\b|\bkubernetes\b|\bdocker\b|\bcontaineri[sz]ed\b"),
+        guidance: "Describe the deployment target, e.g. Cloud Run or Vertex AI Agent Engine, with configuration from the environment",
+    },
+];
+
+/// Good patterns credited by validation
+///
+/// A signal is credited once, when the description affirms it or a snippet exhibits it,
+/// unless a finding from one of its contradicting rules was reported.
+const POSITIVE_SIGNALS: &[PositiveSignal] = &[
+    PositiveSignal {
+        id: "result_error_handling",
+        name: "Result-based error handling",
+        description_pattern: r"\bresult(?:\s*<|\s+types?\b|-based\b)|\berrors?\s+(?:are\s+)?(?:propagated|returned)\b|\breturns?\s+(?:an?\s+)?(?:result|error\s+status)\b|\bstructured\s+errors?\b",
+        snippet_pattern: r#"->\s*(?:[\w:]+::)?Result<|\)\?;|["']status["']\s*:\s*["']error["']"#,
+        contradicted_by: &["error_handling", "unwrap_usage", "panic_usage", "python_bare_except", "python_tool_error_contract"],
+    },
+    PositiveSignal {
+        id: "async_usage",
+        name: "Async I/O",
+        description_pattern: r"\basync(?:hronous(?:ly)?)?\b|\bawait\b|\bnon-?blocking\b",
+        snippet_pattern: r"\basync\s+(?:fn|def)\b|\.await\b|\bawait\s",
+        contradicted_by: &["async_patterns", "python_blocking_call_in_async", "sync_llm_chaining", "python_sync_llm_chain"],
+    },
+    PositiveSignal {
+        id: "session_persistence",
+        name: "Persistent sessions",
+        description_pattern: r"\bDatabaseSessionService\b|\bVertexAiSessionService\b|\b(?:persistent|durable|database-backed)\s+sessions?\b|\bsessions?\s+(?:are\s+)?persisted\b",
+        snippet_pattern: r"\bDatabaseSessionService\b|\bVertexAiSessionService\b",
+        contradicted_by: &["missing_session_persistence", "in_memory_sessions"],
+    },
+    PositiveSignal {
+        id: "evaluation_strategy",
+        name: "Evaluation strategy",
+        description_pattern: r"\beval(?:uation)?\s+(?:sets?|suites?|cases?)\b|\bevalsets?\b|\bAgentEvaluator\b|\badk\s+eval\b|\bgolden\s+(?:set|dataset)\b|\bregression\s+suite\b",
+        snippet_pattern: r"\bAgentEvaluator\b|\.evalset\.json\b|\.test\.json\b",
+        contradicted_by: &["missing_evaluation_strategy"],
+    },
+];
+
+/// Phrases in an architecture description indicating agents that talk across service or team boundaries
+const A2A_SIGNALS: &[&str] = &[
+    "a2a",
+    "agent-to-agent",
+    "agent to agent",
+    "remote agent",
+    "agent card",
+    "separate service",
+    "another service",
+    "microservice",
+    "another team",
+    "other team",
+    "external agent",
+    "third-party agent",
+];
+
+/// Words that negate an anti-pattern mentioned shortly after them in the same clause
+const NEGATION_CUES: &[&str] = &[
+    "avoid", "avoids", "avoiding", "avoided",
+    "not", "never", "no", "without",
+    "don't", "doesn't", "won't", "isn't", "aren't",
+    "prevent", "prevents", "eliminate", "eliminates",
+];
+
+/// Words contrasting an anti-pattern with its replacement ("unlike panics", "replaced panics")
+const CONTRAST_CUES: &[&str] = &["unlike", "replace", "replaces", "replaced", "replacing"];
+
+/// Words contrasting only when followed by the paired word ("Result types instead of panics")
+///
+/// Alone they often keep the anti-pattern, as in "we would rather panic".
+const PHRASE_CONTRAST_CUES: &[(&str, &str)] = &[("instead", "of"), ("rather", "than")];
+
+/// Words introducing the replacement after a contrast cue, which ends the cue's reach
+///
+/// In "we replaced panic! with Result types" the contrast negates `panic!` but not `Result`.
+const CONTRAST_BREAKS: &[&str] = &["with", "by"];
+
+/// Words starting a new clause, which ends the reach of an earlier negation
+const CLAUSE_BREAKS: &[&str] = &["but", "that", "which", "who", "while", "although", "however", "whereas", "because"];
+
+/// Number of words before a match searched for a negation cue
+const NEGATION_WINDOW: usize = 3;
 
 /// Best Practices Enforcement System for Google ADK
 #[derive(Clone, Debug)]
@@ -27,6 +217,61 @@ pub struct ValidationRules {
     pub code_pattern_rules: Vec<CodePatternRule>,
     /// Best practice enforcement rules
     pub best_practice_rules: Vec<BestPracticeRule>,
+    /// User-defined rules from rule sets
+    pub custom_rules: Vec<CustomRule>,
+    /// Disabled rules and severity overrides from rule sets
+    pub overrides: RuleOverrides,
+    /// How findings reduce the compliance score
+    pub scoring: ScoringModel,
+    /// Rule sets layered over the built-in rules, as "name" or "name@version"
+    pub rule_sets: Vec<String>,
+}
+
+/// Compliance scoring model
+///
+/// Validation starts at 100 and deducts points per finding by severity, with separate
+/// weights for findings in the architecture description and in code snippets. Positive
+/// findings credit points back, offsetting deductions without exceeding 100. A result
+/// is compliant when the score reaches the threshold and no finding is an error.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoringModel {
+    /// Points deducted for findings in the architecture description
+    pub description_weights: SeverityWeights,
+    /// Points deducted for findings in code snippets
+    pub snippet_weights: SeverityWeights,
+    /// Minimum score for a compliant result
+    pub compliance_threshold: u8,
+    /// Points credited back per positive finding, never raising the score above 100
+    pub positive_credit: u8,
+}
+
+/// Points deducted per finding of each severity
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SeverityWeights {
+    /// Points per error finding
+    pub error: u8,
+    /// Points per warning finding
+    pub warning: u8,
+    /// Points per info finding
+    pub info: u8,
+}
+
+/// Per-rule tuning of validation findings
+///
+/// Lets teams silence noisy rules or change how seriously a rule is taken without
+/// redefining it, e.g. treating `todo_usage` as an error on release branches.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuleOverrides {
+    /// Rule ids whose findings are dropped
+    #[serde(default)]
+    pub disabled_rules: Vec<String>,
+    /// Rule ids re-enabled after an earlier layer disabled them
+    #[serde(default)]
+    pub enabled_rules: Vec<String>,
+    /// Severity reported for a rule's findings, by rule id
+    #[serde(default)]
+    pub severity_overrides: HashMap<String, ValidationSeverity>,
 }
 
 /// Architecture validation rule
@@ -42,14 +287,192 @@ pub struct ArchitectureRule {
     pub category: String,
     /// Severity level (error, warning, info)
     pub severity: ValidationSeverity,
-    /// Pattern to match against
+    /// Case-insensitive regular expression matched against the description; use `\b` for word boundaries
     pub pattern: String,
+    /// Fire when the description does not mention the pattern, rather than when it does
+    #[serde(default)]
+    pub require: bool,
+    /// Case-insensitive regular expression a description must mention for the rule to apply
+    #[serde(default)]
+    pub when: Option<String>,
     /// Recommendation for compliance
     pub recommendation: String,
     /// Official documentation reference
     pub documentation_ref: String,
 }
 
+impl ArchitectureRule {
+    /// Compile the rule pattern as a case-insensitive regular expression
+    pub fn regex(&self) -> Result<Regex, regex::Error> {
+        RegexBuilder::new(&self.pattern).case_insensitive(true).build()
+    }
+    
+    /// Compile the `when` condition, if any, as a case-insensitive regular expression
+    pub fn condition(&self) -> Result<Option<Regex>, regex::Error> {
+        self.when
+            .as_deref()
+            .map(|when| RegexBuilder::new(when).case_insensitive(true).build())
+            .transpose()
+    }
+}
+
+/// Positive findings for the signals a description or its snippets exhibit and no finding contradicts
+fn positive_findings(description: &str, snippets: &[String], findings: &[ValidationFinding], credit: u8) -> Vec<PositiveFinding> {
+    POSITIVE_SIGNALS
+        .iter()
+        .filter(|signal| !findings.iter().any(|finding| signal.contradicted_by.contains(&finding.rule_id.as_str())))
+        .filter_map(|signal| {
+            let description_regex = RegexBuilder::new(signal.description_pattern).case_insensitive(true).build().ok()?;
+            let snippet_regex = Regex::new(signal.snippet_pattern).ok()?;
+            let location = if find_affirmative_match(&description_regex, description).is_some() {
+                "Architecture Description".to_string()
+            } else {
+                let index = snippets.iter().position(|snippet| snippet_regex.is_match(snippet))?;
+                format!("Code Snippet {}", index + 1)
+            };
+            
+            Some(PositiveFinding {
+                id: format!("positive_{}", signal.id),
+                signal_id: signal.id.to_string(),
+                description: signal.name.to_string(),
+                location: Some(location),
+                credit,
+            })
+        })
+        .collect()
+}
+
+/// Merge recommendations with the same description, rank them, and split off those beyond the cap
+///
+/// Recommendations are ordered by priority and then by estimated impact, renumbered in
+/// that order, and all but the first `MAX_RECOMMENDATIONS` returned separately.
+fn prioritize_recommendations(recommendations: Vec<Recommendation>) -> (Vec<Recommendation>, Vec<Recommendation>) {
+    let mut merged: Vec<Recommendation> = Vec::new();
+    for recommendation in recommendations {
+        let Some(existing) = merged.iter_mut().find(|existing| existing.description.eq_ignore_ascii_case(&recommendation.description)) else {
+            merged.push(recommendation);
+            continue;
+        };
+        existing.priority = existing.priority.min(recommendation.priority);
+        existing.estimated_impact = existing.estimated_impact.saturating_add(recommendation.estimated_impact);
+        for step in recommendation.implementation_steps {
+            if !existing.implementation_steps.contains(&step) {
+                existing.implementation_steps.push(step);
+            }
+        }
+        for benefit in recommendation.benefits {
+            if !existing.benefits.contains(&benefit) {
+                existing.benefits.push(benefit);
+            }
+        }
+    }
+    
+    merged.sort_by_key(|recommendation| (recommendation.priority, std::cmp::Reverse(recommendation.estimated_impact)));
+    for (index, recommendation) in merged.iter_mut().enumerate() {
+        recommendation.id = format!("rec_{}", index + 1);
+    }
+    let additional = merged.split_off(merged.len().min(MAX_RECOMMENDATIONS));
+    (merged, additional)
+}
+
+/// Reasons a validation fails its compliance policy, empty when it passes
+///
+/// A validation fails when its score is below the threshold or any finding is an error,
+/// and in strict mode also when any finding is a warning.
+fn non_compliant_reasons(score: u8, threshold: u8, findings: &[ValidationFinding], strict: bool) -> Vec<String> {
+    let mut reasons = Vec::new();
+    if score < threshold {
+        reasons.push(format!("Compliance score {} is below the minimum of {}", score, threshold));
+    }
+    
+    for finding in findings {
+        let location = finding.location.as_ref().map(|l| format!(" ({})", l)).unwrap_or_default();
+        match finding.severity {
+            ValidationSeverity::Error => reasons.push(format!("Error: {}{}", finding.description, location)),
+            ValidationSeverity::Warning if strict => reasons.push(format!("Warning in strict mode: {}{}", finding.description, location)),
+            _ => {}
+        }
+    }
+    reasons
+}
+
+/// Evaluate the checklist criteria against a description and its findings
+fn build_checklist(description: &str, findings: &[ValidationFinding]) -> Vec<ChecklistItem> {
+    CHECKLIST_CRITERIA
+        .iter()
+        .map(|criterion| {
+            let finding_ids: Vec<String> = findings
+                .iter()
+                .filter(|finding| criterion.rule_ids.contains(&finding.rule_id.as_str()))
+                .map(|finding| finding.id.clone())
+                .collect();
+            let addressed = criterion.evidence.is_none_or(|evidence| {
+                RegexBuilder::new(evidence)
+                    .case_insensitive(true)
+                    .build()
+                    .is_ok_and(|evidence| find_affirmative_match(&evidence, description).is_some())
+            });
+            let status = if !finding_ids.is_empty() {
+                ChecklistStatus::Fail
+            } else if addressed {
+                ChecklistStatus::Pass
+            } else {
+                ChecklistStatus::NotAddressed
+            };
+            
+            ChecklistItem {
+                criterion: criterion.name.to_string(),
+                status,
+                finding_ids,
+                guidance: criterion.guidance.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Describe 1-based line numbers for a finding location, e.g. "line 3" or "lines 3, 7"
+fn line_label(lines: &[usize]) -> String {
+    format!(
+        "{} {}",
+        if lines.len() == 1 { "line" } else { "lines" },
+        lines.iter().map(|line| line.to_string()).collect::<Vec<_>>().join(", ")
+    )
+}
+
+/// First match of `regex` in `text` that is not negated
+///
+/// A match is negated when a cue such as "avoid", "no", or "without" appears within a
+/// few words before it in the same clause ("we avoid blocking operations"), when it
+/// follows a contrast such as "instead of" or "replaced" ("we replaced panic! with Result
+/// types"), or when it is prefixed with "non-" ("non-blocking operations").
+pub fn find_affirmative_match<'t>(regex: &Regex, text: &'t str) -> Option<Match<'t>> {
+    regex.find_iter(text).find(|m| !is_negated(text, m.start()))
+}
+
+/// Whether the text before `start` negates what follows it
+pub(crate) fn is_negated(text: &str, start: usize) -> bool {
+    let before = &text[..start];
+    if before.to_lowercase().ends_with("non-") {
+        return true;
+    }
+    
+    let clause = before.rsplit(['.', ';', ':', '!', '?', '\n']).next().unwrap_or_default().to_lowercase();
+    let words: Vec<&str> = clause.split(|c: char| !(c.is_alphanumeric() || c == '\'')).filter(|word| !word.is_empty()).collect();
+    let mut contrast_ended = false;
+    words
+        .iter()
+        .enumerate()
+        .rev()
+        .take(NEGATION_WINDOW)
+        .take_while(|(_, word)| !CLAUSE_BREAKS.contains(word))
+        .any(|(index, word)| {
+            contrast_ended |= CONTRAST_BREAKS.contains(word);
+            let next = words.get(index + 1).copied();
+            let contrast = CONTRAST_CUES.contains(word) || PHRASE_CONTRAST_CUES.iter().any(|(cue, paired)| word == cue && next == Some(*paired));
+            NEGATION_CUES.contains(word) || (!contrast_ended && contrast)
+        })
+}
+
 /// Code pattern validation rule
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CodePatternRule {
@@ -67,6 +490,19 @@ pub struct CodePatternRule {
     pub category: String,
     /// Severity level
     pub severity: ValidationSeverity,
+    /// Snippet language the rule applies to, or any language when unset
+    #[serde(default)]
+    pub language: Option<SnippetLanguage>,
+}
+
+impl CodePatternRule {
+    /// Whether the rule applies to a snippet in `language`; snippets of unknown language get every rule
+    pub fn applies_to(&self, language: SnippetLanguage) -> bool {
+        match self.language {
+            Some(rule_language) => language == SnippetLanguage::Unknown || rule_language == language,
+            None => true,
+        }
+    }
 }
 
 /// Best practice enforcement rule
@@ -84,19 +520,38 @@ pub struct BestPracticeRule {
     pub failure_indicators: Vec<String>,
     /// Remediation steps
     pub remediation_steps: Vec<String>,
+    /// Regular expressions evidencing each success criterion or failure indicator, by its text
+    ///
+    /// Criteria and indicators without an entry are recognized by their text.
+    #[serde(default)]
+    pub criteria_patterns: HashMap<String, String>,
 }
 
 /// Validation severity levels
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum ValidationSeverity {
     /// Critical issues that must be fixed
+    #[serde(alias = "error")]
     Error,
     /// Important issues that should be addressed
+    #[serde(alias = "warning")]
     Warning,
     /// Suggestions for improvement
+    #[serde(alias = "info")]
     Info,
 }
 
+impl ValidationSeverity {
+    /// Recommendation priority of a finding of this severity, 1 being highest
+    pub fn priority(&self) -> u8 {
+        match self {
+            ValidationSeverity::Error => 1,
+            ValidationSeverity::Warning => 2,
+            ValidationSeverity::Info => 3,
+        }
+    }
+}
+
 /// Pattern matching engine for best practice enforcement
 #[derive(Clone, Debug)]
 pub struct PatternMatcher {
@@ -106,6 +561,47 @@ pub struct PatternMatcher {
     pub code_patterns: HashMap<String, CodePattern>,
 }
 
+/// Per-call options for architecture validation
+#[derive(Clone, Debug, Default)]
+pub struct ValidationOptions {
+    /// Rule overrides layered over the configured ones
+    pub overrides: Option<RuleOverrides>,
+    /// Architecture pattern whose required components are checked, e.g. "multi_agent_team"
+    pub pattern: Option<String>,
+    /// Minimum compliance score for a passing verdict, replacing the scoring model's threshold
+    pub min_score: Option<u8>,
+    /// Whether warning findings also fail the verdict
+    pub strict: bool,
+}
+
+/// Most designs accepted by one batch validation
+pub const MAX_BATCH_DESIGNS: usize = 20;
+
+/// Most recommendations listed in full; the rest are summarized as "and N more"
+pub const MAX_RECOMMENDATIONS: usize = 6;
+
+/// One design of a batch validation, e.g. one microservice of a system
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ArchitectureDesign {
+    /// Name shown in the comparison; designs without one are numbered
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Description of the design
+    pub description: String,
+    /// Code snippets validated with the description
+    #[serde(default)]
+    pub code_snippets: Option<Vec<String>>,
+}
+
+/// Validation result of one design of a batch
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DesignValidation {
+    /// Design name
+    pub name: String,
+    /// Validation result
+    pub result: ArchitectureValidationResult,
+}
+
 /// Architecture pattern definition
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ArchitecturePattern {
@@ -121,6 +617,11 @@ pub struct ArchitecturePattern {
     pub anti_patterns: Vec<String>,
     /// Validation criteria
     pub validation_criteria: Vec<String>,
+    /// Regular expressions evidencing each component in a description or snippet, by component name
+    ///
+    /// Components without an entry are recognized by their name.
+    #[serde(default)]
+    pub component_patterns: HashMap<String, String>,
 }
 
 /// Code pattern definition
@@ -143,14 +644,122 @@ pub struct CodePattern {
 pub struct ArchitectureValidationResult {
     /// Overall compliance status
     pub is_compliant: bool,
+    /// Pass/fail verdict for CI gates, agreeing with `is_compliant`
+    pub verdict: Verdict,
+    /// Why the verdict failed, empty when it passed
+    #[serde(default)]
+    pub non_compliant_reasons: Vec<String>,
     /// Validation score (0-100)
     pub compliance_score: u8,
     /// Detailed findings
     pub findings: Vec<ValidationFinding>,
-    /// Recommendations for improvement
+    /// Recommendations for improvement, most important first
     pub recommendations: Vec<Recommendation>,
+    /// Lower-ranked recommendations beyond the first `MAX_RECOMMENDATIONS`, summarized as "and N more"
+    #[serde(default)]
+    pub additional_recommendations: Vec<Recommendation>,
     /// Official documentation references
     pub documentation_refs: Vec<String>,
+    /// Scoring model the compliance score was computed with
+    pub scoring: ScoringModel,
+    /// Pass/fail status per ADK criterion
+    pub checklist: Vec<ChecklistItem>,
+    /// Mermaid flowchart of the agents, tools, and services inferred from the description
+    pub diagram: Option<String>,
+    /// Findings acknowledged by inline suppression markers, excluded from the score
+    #[serde(default)]
+    pub suppressed: Vec<SuppressedFinding>,
+    /// Good patterns credited back to the score
+    #[serde(default)]
+    pub positive_findings: Vec<PositiveFinding>,
+}
+
+/// Pass/fail verdict of an architecture validation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verdict {
+    /// The design meets the compliance policy
+    Pass,
+    /// The design violates the compliance policy
+    Fail,
+}
+
+/// One ADK criterion of the validation checklist
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    /// Criterion name
+    pub criterion: String,
+    /// Whether the design meets the criterion
+    pub status: ChecklistStatus,
+    /// Findings that failed the criterion
+    pub finding_ids: Vec<String>,
+    /// What meeting the criterion looks like
+    pub guidance: String,
+}
+
+/// Checklist status of an ADK criterion
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChecklistStatus {
+    /// No findings against the criterion
+    Pass,
+    /// At least one finding against the criterion
+    Fail,
+    /// The description does not cover the criterion
+    NotAddressed,
+}
+
+/// How validate_architecture presents its result
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidationView {
+    /// Findings and recommendations
+    #[default]
+    Report,
+    /// Pass/fail checklist per ADK criterion
+    Checklist,
+}
+
+impl ValidationView {
+    /// Parse a view name ("report" or "checklist"), ignoring case
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "report" => Some(Self::Report),
+            "checklist" => Some(Self::Checklist),
+            _ => None,
+        }
+    }
+}
+
+/// Positive signal definition
+struct PositiveSignal {
+    id: &'static str,
+    name: &'static str,
+    description_pattern: &'static str,
+    snippet_pattern: &'static str,
+    contradicted_by: &'static [&'static str],
+}
+
+/// Good pattern detected in a design, crediting points back to the compliance score
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PositiveFinding {
+    /// Finding identifier
+    pub id: String,
+    /// Signal that was detected
+    pub signal_id: String,
+    /// What was credited
+    pub description: String,
+    /// Where the signal was found
+    pub location: Option<String>,
+    /// Points credited to the compliance score
+    pub credit: u8,
+}
+
+/// Checklist criterion definition
+struct ChecklistCriterion {
+    name: &'static str,
+    rule_ids: &'static [&'static str],
+    evidence: Option<&'static str>,
+    guidance: &'static str,
 }
 
 /// Individual validation finding
@@ -187,6 +796,9 @@ pub struct Recommendation {
     pub benefits: Vec<String>,
     /// Official documentation reference
     pub documentation_ref: String,
+    /// Compliance points expected to be recovered by following it
+    #[serde(default)]
+    pub estimated_impact: u8,
 }
 
 /// Best practices retrieval result
@@ -196,6 +808,9 @@ pub struct BestPracticesResult {
     pub scenario: String,
     /// Applicable best practices
     pub practices: Vec<BestPractice>,
+    /// Whether the practices are curated general guidance because none matched the scenario
+    #[serde(default)]
+    pub general: bool,
     /// Implementation patterns
     pub patterns: Vec<ImplementationPattern>,
     /// Official documentation references
@@ -237,22 +852,89 @@ impl BestPracticesEnforcer {
         code_snippets: Option<&[String]>,
         version: Option<&str>,
     ) -> anyhow::Result<ArchitectureValidationResult> {
+        self.validate_architecture_with(description, code_snippets, version, &ValidationOptions::default()).await
+    }
+    
+    /// Validate architecture with per-call options
+    pub async fn validate_architecture_with(
+        &self,
+        description: &str,
+        code_snippets: Option<&[String]>,
+        version: Option<&str>,
+        options: &ValidationOptions,
+    ) -> anyhow::Result<ArchitectureValidationResult> {
+        let mut overrides = self.validation_rules.overrides.clone();
+        if let Some(call_overrides) = &options.overrides {
+            overrides.layer(call_overrides.clone());
+        }
+        
+        let pattern = match options.pattern.as_deref() {
+            Some(name) => Some(self.pattern_matcher.architecture_pattern(name)?),
+            None => None,
+        };
+        
         let resolved_version = version
             .map(|v| self.knowledge_base.resolve_version(v))
             .unwrap_or_else(|| self.knowledge_base.default_version.clone());
         
+        let scoring = &self.validation_rules.scoring;
+        let suppressions = Suppressions::new(description, code_snippets.unwrap_or_default());
+        let mut suppressed = Vec::new();
         let mut findings = Vec::new();
         let mut compliance_score = 100u8;
         
         // Validate against architecture rules
         for rule in &self.validation_rules.architecture_rules {
-            if let Some(finding) = self.check_architecture_rule(rule, description, &resolved_version) {
+            let finding = self.check_architecture_rule(rule, description, &resolved_version);
+            let finding = finding.and_then(|finding| overrides.apply(finding));
+            if let Some(finding) = finding.and_then(|finding| suppressions.admit(finding, None, &mut suppressed)) {
                 // Reduce compliance score based on severity
-                match finding.severity {
-                    ValidationSeverity::Error => compliance_score = compliance_score.saturating_sub(20),
-                    ValidationSeverity::Warning => compliance_score = compliance_score.saturating_sub(10),
-                    ValidationSeverity::Info => compliance_score = compliance_score.saturating_sub(5),
+                compliance_score = compliance_score.saturating_sub(scoring.description_weights.deduction(&finding.severity));
+                findings.push(finding);
+            }
+        }
+        
+        // Validate against user-defined rules for descriptions
+        for rule in self.validation_rules.custom_rules.iter().filter(|rule| rule.applies_to(RuleTarget::Description)) {
+            let id = format!("custom_{}", rule.id);
+            let finding = rule.evaluate(description, RuleTarget::Description, id, "Architecture Description".to_string());
+            let finding = finding.and_then(|finding| overrides.apply(finding));
+            if let Some(finding) = finding.and_then(|finding| suppressions.admit(finding, None, &mut suppressed)) {
+                compliance_score = compliance_score.saturating_sub(scoring.description_weights.deduction(&finding.severity));
+                findings.push(finding);
+            }
+        }
+        
+        // Validate against the requested architecture pattern's required components
+        if let Some((pattern_key, pattern)) = pattern {
+            let snippets_text = code_snippets.map(|snippets| snippets.join("\n")).unwrap_or_default();
+            for finding in pattern.missing_component_findings(pattern_key, description, &snippets_text) {
+                let finding = overrides.apply(finding);
+                if let Some(finding) = finding.and_then(|finding| suppressions.admit(finding, None, &mut suppressed)) {
+                    compliance_score = compliance_score.saturating_sub(scoring.description_weights.deduction(&finding.severity));
+                    findings.push(finding);
                 }
+            }
+        }
+        
+        // Validate the delegation topology of multi-agent designs
+        let topology = AgentTopology::new(description, code_snippets.unwrap_or_default());
+        for finding in topology.findings(description).into_iter().filter_map(|finding| overrides.apply(finding)) {
+            let Some(finding) = suppressions.admit(finding, None, &mut suppressed) else {
+                continue;
+            };
+            compliance_score = compliance_score.saturating_sub(scoring.description_weights.deduction(&finding.severity));
+            findings.push(finding);
+        }
+        
+        // Validate against the failure indicators of best practice rules
+        for rule in &self.validation_rules.best_practice_rules {
+            for (finding, snippet) in rule.failure_findings(description, code_snippets.unwrap_or_default()) {
+                let Some(finding) = overrides.apply(finding).and_then(|finding| suppressions.admit(finding, snippet, &mut suppressed)) else {
+                    continue;
+                };
+                let weights = if snippet.is_some() { &scoring.snippet_weights } else { &scoring.description_weights };
+                compliance_score = compliance_score.saturating_sub(weights.deduction(&finding.severity));
                 findings.push(finding);
             }
         }
@@ -261,31 +943,106 @@ impl BestPracticesEnforcer {
         if let Some(snippets) = code_snippets {
             for (index, snippet) in snippets.iter().enumerate() {
                 let snippet_findings = self.validate_code_snippet(snippet, index, &resolved_version);
-                for finding in snippet_findings {
-                    match finding.severity {
-                        ValidationSeverity::Error => compliance_score = compliance_score.saturating_sub(15),
-                        ValidationSeverity::Warning => compliance_score = compliance_score.saturating_sub(8),
-                        ValidationSeverity::Info => compliance_score = compliance_score.saturating_sub(3),
-                    }
+                for finding in snippet_findings.into_iter().filter_map(|finding| overrides.apply(finding)) {
+                    let Some(finding) = suppressions.admit(finding, Some(index), &mut suppressed) else {
+                        continue;
+                    };
+                    compliance_score = compliance_score.saturating_sub(scoring.snippet_weights.deduction(&finding.severity));
                     findings.push(finding);
                 }
             }
         }
         
         // Generate recommendations based on findings
-        let recommendations = self.generate_recommendations(&findings, &resolved_version);
+        let mut recommendations = self.generate_recommendations(&findings, &resolved_version, scoring);
         
-        // Get official documentation references
-        let documentation_refs = self.get_architecture_documentation_refs(&resolved_version);
+        // Spell out the requested pattern's validation criteria
+        if let Some((pattern_key, pattern)) = pattern {
+            let documentation_ref = self.knowledge_base
+                .get_official_urls(Some(&resolved_version))
+                .best_practices
+                .first()
+                .cloned()
+                .unwrap_or_default();
+            let rule_id = format!("pattern_{}", pattern_key);
+            recommendations.push(Recommendation {
+                estimated_impact: scoring.recovered_points(findings.iter().filter(|finding| finding.rule_id == rule_id)),
+                ..pattern.criteria_recommendation(recommendations.len() + 1, documentation_ref)
+            });
+        }
+        
+        // Walk through the remediation of each best practice rule that fired
+        for rule in &self.validation_rules.best_practice_rules {
+            if findings.iter().any(|finding| finding.rule_id == rule.id) {
+                let documentation_ref = self.knowledge_base
+                    .get_official_urls(Some(&resolved_version))
+                    .best_practices
+                    .first()
+                    .cloned()
+                    .unwrap_or_default();
+                recommendations.push(Recommendation {
+                    estimated_impact: scoring.recovered_points(findings.iter().filter(|finding| finding.rule_id == rule.id)),
+                    ..rule.remediation_recommendation(
+                        recommendations.len() + 1,
+                        description,
+                        code_snippets.unwrap_or_default(),
+                        documentation_ref,
+                    )
+                });
+            }
+        }
+        
+        // Point designs with agents across service or team boundaries at A2A guidance
+        if let Some(recommendation) = self.a2a_recommendation(description, recommendations.len() + 1, &resolved_version) {
+            recommendations.push(recommendation);
+        }
+        
+        // Merge duplicates, most important first, keeping the tail out of the main list
+        let (recommendations, additional_recommendations) = prioritize_recommendations(recommendations);
+        
+        // Get official documentation references, including those of the architecture rules that fired
+        let mut documentation_refs = self.get_architecture_documentation_refs(&resolved_version);
+        for rule in &self.validation_rules.architecture_rules {
+            let fired = findings.iter().any(|finding| finding.rule_id == rule.id);
+            if fired && !rule.documentation_ref.is_empty() && !documentation_refs.contains(&rule.documentation_ref) {
+                documentation_refs.push(rule.documentation_ref.clone());
+            }
+        }
+        if findings.iter().any(|finding| finding.id.starts_with("orchestration_")) && !documentation_refs.contains(&MULTI_AGENT_DOCS.to_string()) {
+            documentation_refs.push(MULTI_AGENT_DOCS.to_string());
+        }
+        
+        // Credit good patterns the findings do not contradict
+        let positive_findings = positive_findings(description, code_snippets.unwrap_or_default(), &findings, scoring.positive_credit);
+        let credit = positive_findings.iter().map(|finding| u32::from(finding.credit)).sum::<u32>();
+        compliance_score = (u32::from(compliance_score) + credit).min(100) as u8;
         
-        let is_compliant = compliance_score >= 80 && !findings.iter().any(|f| f.severity == ValidationSeverity::Error);
+        let checklist = build_checklist(description, &findings);
+        let graph = ComponentGraph::from_description(description);
+        let diagram = graph.is_drawable().then(|| graph.to_mermaid());
+        
+        // A per-call minimum score replaces the configured threshold, and is reported as such
+        let mut scoring = scoring.clone();
+        if let Some(min_score) = options.min_score {
+            scoring.compliance_threshold = min_score;
+        }
+        let non_compliant_reasons = non_compliant_reasons(compliance_score, scoring.compliance_threshold, &findings, options.strict);
+        let is_compliant = non_compliant_reasons.is_empty();
         
         Ok(ArchitectureValidationResult {
             is_compliant,
+            verdict: if is_compliant { Verdict::Pass } else { Verdict::Fail },
+            non_compliant_reasons,
             compliance_score,
             findings,
             recommendations,
+            additional_recommendations,
             documentation_refs,
+            scoring,
+            checklist,
+            diagram,
+            suppressed,
+            positive_findings,
         })
     }
     
@@ -314,9 +1071,27 @@ impl BestPracticesEnforcer {
         // Get official documentation references
         let documentation_refs = self.get_best_practices_documentation_refs(scenario, &resolved_version);
         
+        // Point each practice at the section supporting it when one is cited
+        let docs = self.knowledge_base.get_version_docs(&resolved_version);
+        let practices: Vec<BestPractice> = practices
+            .into_iter()
+            .map(|practice| {
+                let mut practice = practice.clone();
+                if let Some(citation) = docs.and_then(|docs| docs.practice_citation(&practice.title)) {
+                    practice.documentation_ref = citation.deep_link();
+                }
+                practice
+            })
+            .collect();
+        
+        // Fall back to curated general guidance rather than answering with nothing
+        let general = practices.is_empty();
+        let practices = if general { fallback_practices(scenario, category) } else { practices };
+        
         Ok(BestPracticesResult {
             scenario: scenario.to_string(),
-            practices: practices.into_iter().cloned().collect(),
+            practices,
+            general,
             patterns,
             documentation_refs,
             version: resolved_version,
@@ -330,32 +1105,50 @@ impl BestPracticesEnforcer {
         description: &str,
         _version: &str,
     ) -> Option<ValidationFinding> {
-        // Simple pattern matching - in a real implementation this would be more sophisticated
-        let description_lower = description.to_lowercase();
-        let pattern_lower = rule.pattern.to_lowercase();
-        
-        // Check if the pattern indicates a potential issue
-        if description_lower.contains(&pattern_lower) {
-            Some(ValidationFinding {
-                id: format!("arch_{}", rule.id),
-                rule_id: rule.id.clone(),
-                severity: rule.severity.clone(),
-                description: format!("{}: {}", rule.name, rule.description),
-                location: Some("Architecture Description".to_string()),
-                suggested_fix: Some(rule.recommendation.clone()),
-            })
-        } else {
-            None
+        let (regex, condition) = match (rule.regex(), rule.condition()) {
+            (Ok(regex), Ok(condition)) => (regex, condition),
+            (Err(e), _) | (_, Err(e)) => {
+                warn!("Skipping architecture rule '{}' with an invalid pattern: {}", rule.id, e);
+                return None;
+            }
+        };
+        
+        // Conditional rules only apply to descriptions affirming their condition
+        if condition.is_some_and(|condition| find_affirmative_match(&condition, description).is_none()) {
+            return None;
         }
+        
+        // Only affirmative mentions count; "we avoid blocking operations" is not a mention
+        let mentioned = find_affirmative_match(&regex, description).is_some();
+        if mentioned == rule.require {
+            return None;
+        }
+        
+        Some(ValidationFinding {
+            id: format!("arch_{}", rule.id),
+            rule_id: rule.id.clone(),
+            severity: rule.severity.clone(),
+            description: format!("{}: {}", rule.name, rule.description),
+            location: Some("Architecture Description".to_string()),
+            suggested_fix: Some(rule.recommendation.clone()),
+        })
     }
     
     /// Validate a code snippet against best practices
     fn validate_code_snippet(&self, snippet: &str, index: usize, _version: &str) -> Vec<ValidationFinding> {
         let mut findings = Vec::new();
         
-        // Check against code pattern rules
-        for rule in &self.validation_rules.code_pattern_rules {
-            if snippet.contains(&rule.pattern) {
+        let language = SnippetLanguage::detect(snippet);
+        
+        // Rust snippets are parsed so matches carry line and test context
+        let analyzer = RustCodeAnalyzer::new(snippet).ok().filter(|analyzer| analyzer.has_valid_syntax());
+        let structure = analyzer.as_ref().map(|analyzer| analyzer.structure());
+        
+        // Check against code pattern rules for the snippet's language
+        for rule in self.validation_rules.code_pattern_rules.iter().filter(|rule| rule.applies_to(language)) {
+            if let (Some(analyzer), Some(structure)) = (&analyzer, &structure) {
+                findings.extend(self.check_rust_code_pattern(rule, analyzer, structure, index));
+            } else if snippet.contains(&rule.pattern) {
                 findings.push(ValidationFinding {
                     id: format!("code_{}_{}", index, rule.id),
                     rule_id: rule.id.clone(),
@@ -367,118 +1160,420 @@ impl BestPracticesEnforcer {
             }
         }
         
+        // Check for agents holding too many tools
+        let tool_count = largest_tool_list(snippet);
+        if tool_count > GOD_AGENT_TOOL_LIMIT {
+            findings.push(ValidationFinding {
+                id: format!("code_{}_god_agent_tools", index),
+                rule_id: "god_agent_tools".to_string(),
+                severity: ValidationSeverity::Warning,
+                description: format!(
+                    "God agent: an agent is given {} tools; models choose poorly among more than {}",
+                    tool_count, GOD_AGENT_TOOL_LIMIT
+                ),
+                location: Some(format!("Code Snippet {}", index + 1)),
+                suggested_fix: Some("Split the tools across specialist sub-agents coordinated by a root agent".to_string()),
+            });
+        }
+        
+        // Check snippets in any language for security issues
+        let secrets = secret_lines(snippet);
+        if !secrets.is_empty() {
+            findings.push(ValidationFinding {
+                id: format!("code_{}_secret_in_code", index),
+                rule_id: "secret_in_code".to_string(),
+                severity: ValidationSeverity::Error,
+                description: "Hard-coded secret: credentials in source code leak through version control and logs".to_string(),
+                location: Some(format!("Code Snippet {}, {}", index + 1, line_label(&secrets))),
+                suggested_fix: Some("Read the secret from an environment variable or Secret Manager and rotate the exposed value".to_string()),
+            });
+        }
+        
+        let injection_prone = injection_prone_descriptions(snippet);
+        if !injection_prone.is_empty() {
+            findings.push(ValidationFinding {
+                id: format!("code_{}_tool_description_injection", index),
+                rule_id: "tool_description_injection".to_string(),
+                severity: ValidationSeverity::Warning,
+                description: "Tool description open to prompt injection: it is built at runtime or tells the model what to do instead of describing the tool".to_string(),
+                location: Some(format!("Code Snippet {}, {}", index + 1, line_label(&injection_prone))),
+                suggested_fix: Some("Keep tool descriptions static and factual; pass untrusted text as tool arguments or results, never as instructions".to_string()),
+            });
+        }
+        
+        let accumulating = state_accumulation_lines(snippet);
+        if !accumulating.is_empty() {
+            findings.push(ValidationFinding {
+                id: format!("code_{}_state_accumulation", index),
+                rule_id: "state_accumulation".to_string(),
+                severity: ValidationSeverity::Warning,
+                description: "Unbounded session state: appending to a state entry on every turn grows the context of every later request".to_string(),
+                location: Some(format!("Code Snippet {}, {}", index + 1, line_label(&accumulating))),
+                suggested_fix: Some("Cap the list, store a running summary instead, or move history to a MemoryService".to_string()),
+            });
+        }
+        
+        // Check Python snippets for Python-specific issues
+        if language == SnippetLanguage::Python {
+            findings.extend(self.validate_python_snippet(snippet, index));
+        }
+        
+        // Check against user-defined rules for snippets
+        for rule in self.validation_rules.custom_rules.iter().filter(|rule| rule.applies_to(RuleTarget::Snippet)) {
+            let id = format!("custom_{}_{}", index, rule.id);
+            let location = format!("Code Snippet {}", index + 1);
+            if let Some(finding) = rule.evaluate(snippet, RuleTarget::Snippet, id, location) {
+                findings.push(finding);
+            }
+        }
+        
         findings
     }
     
-    /// Generate recommendations based on validation findings
-    fn generate_recommendations(&self, findings: &[ValidationFinding], version: &str) -> Vec<Recommendation> {
-        let mut recommendations = Vec::new();
-        let mut rec_id = 1;
+    /// Check a code pattern rule against a parsed Rust snippet
+    ///
+    /// Matches in test code are skipped, since `unwrap()` and `panic!` are idiomatic in
+    /// tests. The finding names the matching lines and the enclosing function, and points
+    /// error-handling fixes at `?` when that function already returns `Result`.
+    fn check_rust_code_pattern(
+        &self,
+        rule: &CodePatternRule,
+        analyzer: &RustCodeAnalyzer,
+        structure: &CodeStructure,
+        index: usize,
+    ) -> Option<ValidationFinding> {
+        let lines: Vec<usize> = analyzer
+            .lines()
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.contains(&rule.pattern) && !line.trim_start().starts_with("//"))
+            .map(|(line_index, _)| line_index + 1)
+            .filter(|line| !structure.is_test_line(*line))
+            .collect();
+        let first_line = *lines.first()?;
         
-        // Group findings by category and generate recommendations
-        let error_count = findings.iter().filter(|f| f.severity == ValidationSeverity::Error).count();
-        let warning_count = findings.iter().filter(|f| f.severity == ValidationSeverity::Warning).count();
+        let line_list = line_label(&lines);
+        let function = structure.enclosing_function(first_line);
+        let location = match function {
+            Some(function) => format!("Code Snippet {}, {} in `{}`", index + 1, line_list, function.name),
+            None => format!("Code Snippet {}, {}", index + 1, line_list),
+        };
+        let suggested_fix = match function {
+            Some(function) if function.returns_result && rule.category == "error_handling" => format!(
+                "Consider using: {}; `{}` already returns Result, so errors can be propagated with ?",
+                rule.expected_pattern, function.name
+            ),
+            _ => format!("Consider using: {}", rule.expected_pattern),
+        };
         
-        if error_count > 0 {
-            recommendations.push(Recommendation {
-                id: format!("rec_{}", rec_id),
-                category: "Critical Issues".to_string(),
-                priority: 1,
-                description: format!("Address {} critical architecture issues that prevent ADK compliance", error_count),
-                implementation_steps: vec![
-                    "Review all error-level findings".to_string(),
-                    "Implement suggested fixes for critical issues".to_string(),
-                    "Validate changes against ADK guidelines".to_string(),
-                ],
-                benefits: vec![
-                    "Ensures ADK compliance".to_string(),
-                    "Prevents runtime issues".to_string(),
-                    "Follows official best practices".to_string(),
-                ],
-                documentation_ref: format!("https://google.github.io/adk-docs/best-practices/?version={}", version),
+        Some(ValidationFinding {
+            id: format!("code_{}_{}", index, rule.id),
+            rule_id: rule.id.clone(),
+            severity: rule.severity.clone(),
+            description: format!("{}: {}", rule.name, rule.rationale),
+            location: Some(location),
+            suggested_fix: Some(suggested_fix),
+        })
+    }
+    
+    /// Check a Python snippet for Python-specific error handling, async, state, security, and tool issues
+    fn validate_python_snippet(&self, snippet: &str, index: usize) -> Vec<ValidationFinding> {
+        let analysis = PythonAnalysis::new(snippet);
+        let mut findings = Vec::new();
+        
+        if !analysis.bare_excepts.is_empty() {
+            findings.push(ValidationFinding {
+                id: format!("code_{}_python_bare_except", index),
+                rule_id: "python_bare_except".to_string(),
+                severity: ValidationSeverity::Warning,
+                description: "Avoid bare except: it also catches KeyboardInterrupt and SystemExit and hides the real error".to_string(),
+                location: Some(format!("Code Snippet {}, {}", index + 1, line_label(&analysis.bare_excepts))),
+                suggested_fix: Some("Catch specific exceptions, e.g. `except ValueError as error:`".to_string()),
             });
-            rec_id += 1;
         }
         
-        if warning_count > 0 {
-            recommendations.push(Recommendation {
-                id: format!("rec_{}", rec_id),
-                category: "Improvements".to_string(),
-                priority: 2,
-                description: format!("Consider addressing {} warning-level improvements for better ADK alignment", warning_count),
-                implementation_steps: vec![
-                    "Review warning-level findings".to_string(),
-                    "Prioritize improvements based on impact".to_string(),
-                    "Implement changes incrementally".to_string(),
-                ],
-                benefits: vec![
-                    "Improves code quality".to_string(),
-                    "Better alignment with ADK patterns".to_string(),
-                    "Enhanced maintainability".to_string(),
-                ],
-                documentation_ref: format!("https://google.github.io/adk-docs/best-practices/?version={}", version),
+        if let Some((_, function)) = analysis.blocking_calls_in_async.first() {
+            let lines: Vec<usize> = analysis.blocking_calls_in_async.iter().map(|(line, _)| *line).collect();
+            findings.push(ValidationFinding {
+                id: format!("code_{}_python_blocking_call_in_async", index),
+                rule_id: "python_blocking_call_in_async".to_string(),
+                severity: ValidationSeverity::Error,
+                description: "Avoid blocking calls in async functions: they stall the event loop shared by every agent session".to_string(),
+                location: Some(format!("Code Snippet {}, {} in `{}`", index + 1, line_label(&lines), function)),
+                suggested_fix: Some("Use an async client such as httpx.AsyncClient or aiohttp, asyncio.sleep, or move the call to asyncio.to_thread".to_string()),
+            });
+        }
+        
+        if !analysis.global_statements.is_empty() {
+            findings.push(ValidationFinding {
+                id: format!("code_{}_python_global_state", index),
+                rule_id: "python_global_state".to_string(),
+                severity: ValidationSeverity::Warning,
+                description: "Avoid global state: module globals are shared by every agent and session".to_string(),
+                location: Some(format!("Code Snippet {}, {}", index + 1, line_label(&analysis.global_statements))),
+                suggested_fix: Some("Keep per-conversation data in tool_context.state and durable data in an external store".to_string()),
+            });
+        }
+        
+        for (function, lines) in &analysis.sync_model_call_chains {
+            findings.push(ValidationFinding {
+                id: format!("code_{}_python_sync_llm_chain_{}", index, function),
+                rule_id: "python_sync_llm_chain".to_string(),
+                severity: ValidationSeverity::Warning,
+                description: format!(
+                    "Synchronous LLM call chain: `{}` makes {} model calls one after another",
+                    function,
+                    lines.len()
+                ),
+                location: Some(format!("Code Snippet {}, {} in `{}`", index + 1, line_label(lines), function)),
+                suggested_fix: Some("Model each step as an LlmAgent inside a SequentialAgent or ParallelAgent, or await async model calls concurrently".to_string()),
+            });
+        }
+        
+        if let Some((_, tool)) = analysis.unvalidated_tool_inputs.first() {
+            let lines: Vec<usize> = analysis.unvalidated_tool_inputs.iter().map(|(line, _)| *line).collect();
+            findings.push(ValidationFinding {
+                id: format!("code_{}_python_unvalidated_tool_input", index),
+                rule_id: "python_unvalidated_tool_input".to_string(),
+                severity: ValidationSeverity::Error,
+                description: "Unvalidated tool input: a tool passes a model-supplied argument to eval, a shell, or SQL".to_string(),
+                location: Some(format!("Code Snippet {}, {} in `{}`", index + 1, line_label(&lines), tool)),
+                suggested_fix: Some("Validate the argument against an allow-list and use parameterized queries or argument lists instead of strings".to_string()),
+            });
+        }
+        
+        for (function, lines) in &analysis.sequential_independent_awaits {
+            findings.push(ValidationFinding {
+                id: format!("code_{}_python_sequential_awaits_{}", index, function),
+                rule_id: "python_sequential_awaits".to_string(),
+                severity: ValidationSeverity::Warning,
+                description: format!(
+                    "Independent model calls awaited in sequence: `{}` waits for {} calls that do not use each other's results",
+                    function,
+                    lines.len()
+                ),
+                location: Some(format!("Code Snippet {}, {} in `{}`", index + 1, line_label(lines), function)),
+                suggested_fix: Some("Await them together with asyncio.gather, or run the steps as sub-agents of a ParallelAgent".to_string()),
+            });
+        }
+        
+        if !analysis.calls_in_loops.is_empty() {
+            findings.push(ValidationFinding {
+                id: format!("code_{}_python_chatty_loop", index),
+                rule_id: "python_chatty_loop".to_string(),
+                severity: ValidationSeverity::Warning,
+                description: "Chatty loop: a model or tool is called once per loop iteration".to_string(),
+                location: Some(format!("Code Snippet {}, {}", index + 1, line_label(&analysis.calls_in_loops))),
+                suggested_fix: Some("Batch the items into one call, give the tool a list parameter, or gather the calls concurrently".to_string()),
+            });
+        }
+        
+        for tool in analysis.untyped_tools() {
+            let mut missing: Vec<String> = tool.untyped_params.iter().map(|param| format!("`{}`", param)).collect();
+            if !tool.has_return_annotation {
+                missing.push("the return value".to_string());
+            }
+            findings.push(ValidationFinding {
+                id: format!("code_{}_python_tool_type_hints_{}", index, tool.name),
+                rule_id: "python_tool_type_hints".to_string(),
+                severity: ValidationSeverity::Warning,
+                description: format!(
+                    "Add type hints to tool `{}`: ADK builds the tool schema the model sees from them (missing for {})",
+                    tool.name,
+                    missing.join(", ")
+                ),
+                location: Some(format!("Code Snippet {}, line {} in `{}`", index + 1, tool.line, tool.name)),
+                suggested_fix: Some("Annotate every parameter and the return type, e.g. `def get_weather(city: str) -> dict:`".to_string()),
+            });
+        }
+        
+        for tool in analysis.broad_tools() {
+            findings.push(ValidationFinding {
+                id: format!("code_{}_python_broad_tool_params_{}", index, tool.name),
+                rule_id: "python_broad_tool_params".to_string(),
+                severity: ValidationSeverity::Warning,
+                description: format!(
+                    "Broad tool parameters: `{}` takes {} without a schema, so the model has to guess what to pass",
+                    tool.name,
+                    tool.broad_params.iter().map(|param| format!("`{}`", param)).collect::<Vec<_>>().join(", ")
+                ),
+                location: Some(format!("Code Snippet {}, line {} in `{}`", index + 1, tool.line, tool.name)),
+                suggested_fix: Some("Replace the dict or keyword arguments with named, typed parameters".to_string()),
+            });
+        }
+        
+        for (line, tool) in &analysis.polling_tools {
+            findings.push(ValidationFinding {
+                id: format!("code_{}_python_polling_tool_{}", index, tool),
+                rule_id: "python_polling_tool".to_string(),
+                severity: ValidationSeverity::Warning,
+                description: format!("Long-running tool: `{}` sleeps in a loop waiting for an operation, blocking the agent's turn", tool),
+                location: Some(format!("Code Snippet {}, line {} in `{}`", index + 1, line, tool)),
+                suggested_fix: Some("Wrap the function in LongRunningFunctionTool and return a job id, reporting the result in a later turn".to_string()),
+            });
+        }
+        
+        for (line, tool) in &analysis.raising_tools {
+            findings.push(ValidationFinding {
+                id: format!("code_{}_python_tool_error_contract_{}", index, tool),
+                rule_id: "python_tool_error_contract".to_string(),
+                severity: ValidationSeverity::Warning,
+                description: format!("Tool error contract: `{}` raises instead of returning an error status the agent can act on", tool),
+                location: Some(format!("Code Snippet {}, line {} in `{}`", index + 1, line, tool)),
+                suggested_fix: Some("Catch the failure and return {\"status\": \"error\", \"error_message\": ...} instead of raising".to_string()),
             });
         }
         
-        recommendations
+        findings
     }
     
-    /// Get practices relevant to a specific scenario
-    fn get_scenario_relevant_practices(&self, scenario: &str, version: &str) -> Vec<&BestPractice> {
-        if let Some(docs) = self.knowledge_base.get_version_docs(version) {
-            docs.best_practices
-                .iter()
-                .filter(|practice| {
-                    let scenario_lower = scenario.to_lowercase();
-                    practice.title.to_lowercase().contains(&scenario_lower) ||
-                    practice.description.to_lowercase().contains(&scenario_lower) ||
-                    practice.category.to_lowercase().contains(&scenario_lower)
-                })
-                .collect()
-        } else {
-            Vec::new()
+    /// Generate recommendations based on validation findings
+    ///
+    /// Findings sharing a remediation are merged into one recommendation, ranked by their
+    /// most severe finding. Findings of best practice rules are left to the rule's own
+    /// remediation recommendation.
+    fn generate_recommendations(&self, findings: &[ValidationFinding], version: &str, scoring: &ScoringModel) -> Vec<Recommendation> {
+        let best_practices_ref = self.knowledge_base
+            .get_official_urls(Some(version))
+            .best_practices
+            .first()
+            .cloned()
+            .unwrap_or_default();
+        
+        // Group findings by remediation, in order of first appearance
+        let mut groups: Vec<(&str, Vec<&ValidationFinding>)> = Vec::new();
+        let practice_findings = |finding: &&ValidationFinding| {
+            self.validation_rules.best_practice_rules.iter().any(|rule| rule.id == finding.rule_id)
+        };
+        for finding in findings.iter().filter(|finding| !practice_findings(finding)) {
+            let remediation = finding.suggested_fix.as_deref().unwrap_or(&finding.description);
+            match groups.iter_mut().find(|(key, _)| key.eq_ignore_ascii_case(remediation)) {
+                Some((_, group)) => group.push(finding),
+                None => groups.push((remediation, vec![finding])),
+            }
         }
+        
+        groups
+            .into_iter()
+            .enumerate()
+            .map(|(index, (remediation, group))| {
+                let priority = group.iter().map(|finding| finding.severity.priority()).min().unwrap_or(3);
+                let (category, benefits): (&str, &[&str]) = match priority {
+                    1 => ("Critical Issues", &["Ensures ADK compliance", "Prevents runtime issues", "Follows official best practices"]),
+                    2 => ("Improvements", &["Improves code quality", "Better alignment with ADK patterns", "Enhanced maintainability"]),
+                    _ => ("Suggestions", &["Better alignment with ADK patterns", "Enhanced maintainability"]),
+                };
+                let description = match group.len() {
+                    1 => remediation.to_string(),
+                    count => format!("{} ({} findings)", remediation, count),
+                };
+                let mut implementation_steps: Vec<String> = Vec::new();
+                for finding in &group {
+                    let location = finding.location.as_ref().map(|l| format!(" ({})", l)).unwrap_or_default();
+                    let step = format!("Resolve: {}{}", finding.description, location);
+                    if !implementation_steps.contains(&step) {
+                        implementation_steps.push(step);
+                    }
+                }
+                implementation_steps.push("Validate changes against ADK guidelines".to_string());
+                
+                Recommendation {
+                    id: format!("rec_{}", index + 1),
+                    category: category.to_string(),
+                    priority,
+                    description,
+                    implementation_steps,
+                    benefits: benefits.iter().map(|benefit| benefit.to_string()).collect(),
+                    documentation_ref: best_practices_ref.clone(),
+                    estimated_impact: scoring.recovered_points(group.into_iter()),
+                }
+            })
+            .collect()
     }
     
-    /// Get implementation patterns relevant to a scenario
-    fn get_scenario_patterns(&self, scenario: &str, version: &str) -> Vec<ImplementationPattern> {
-        if let Some(docs) = self.knowledge_base.get_version_docs(version) {
-            docs.implementation_patterns
-                .values()
-                .filter(|pattern| {
-                    let scenario_lower = scenario.to_lowercase();
-                    pattern.name.to_lowercase().contains(&scenario_lower) ||
-                    pattern.description.to_lowercase().contains(&scenario_lower) ||
-                    pattern.use_cases.iter().any(|uc| uc.to_lowercase().contains(&scenario_lower))
-                })
-                .cloned()
-                .collect()
-        } else {
-            Vec::new()
+    /// Recommend A2A when the description has agents calling each other across service or team boundaries
+    fn a2a_recommendation(&self, description: &str, rec_id: usize, version: &str) -> Option<Recommendation> {
+        let description_lower = description.to_lowercase();
+        if !A2A_SIGNALS.iter().any(|signal| description_lower.contains(signal)) {
+            return None;
         }
+        
+        let docs = self.knowledge_base.get_version_docs(version)?;
+        let concept = docs.concepts.get("a2a_vs_sub_agents")?;
+        let documentation_ref = docs.concept_citation("a2a_vs_sub_agents")
+            .map(|citation| citation.deep_link())
+            .or_else(|| concept.documentation_refs.first().cloned())
+            .unwrap_or_default();
+        
+        Some(Recommendation {
+            id: format!("rec_{}", rec_id),
+            category: "Agent Interoperability".to_string(),
+            priority: 3,
+            description: "Connect agents deployed as separate services or owned by other teams through the A2A protocol, \
+                and keep agents inside one deployment as local sub-agents".to_string(),
+            implementation_steps: vec![
+                "Expose each remote agent with to_a2a or adk api_server --a2a and publish a descriptive agent card".to_string(),
+                "Call remote agents from the coordinator through RemoteA2aAgent, passing required context in the message".to_string(),
+                "Keep in-process agents as sub_agents so they share session state without network hops".to_string(),
+            ],
+            benefits: vec![
+                "Independent deployment and ownership of agents".to_string(),
+                "Interoperability with agents built on other frameworks".to_string(),
+                "No network overhead for agents that belong together".to_string(),
+            ],
+            documentation_ref,
+            estimated_impact: 0,
+        })
+    }
+    
+    /// Get practices relevant to a specific scenario, most relevant first
+    fn get_scenario_relevant_practices(&self, scenario: &str, version: &str) -> Vec<&BestPractice> {
+        let Some(docs) = self.knowledge_base.get_version_docs(version) else {
+            return Vec::new();
+        };
+        
+        let index = RelevanceIndex::new(docs.best_practices.iter().map(|practice| {
+            [
+                (practice.title.as_str(), TITLE_WEIGHT),
+                (practice.category.as_str(), TITLE_WEIGHT),
+                (practice.description.as_str(), 1.0),
+            ]
+        }));
+        index.rank(scenario).into_iter().map(|(position, _)| &docs.best_practices[position]).collect()
+    }
+    
+    /// Get implementation patterns relevant to a scenario, most relevant first
+    fn get_scenario_patterns(&self, scenario: &str, version: &str) -> Vec<ImplementationPattern> {
+        let Some(docs) = self.knowledge_base.get_version_docs(version) else {
+            return Vec::new();
+        };
+        
+        // Sort by key so ties rank the same way on every call
+        let mut patterns: Vec<(&String, &ImplementationPattern)> = docs.implementation_patterns.iter().collect();
+        patterns.sort_by_key(|(key, _)| *key);
+        let index = RelevanceIndex::new(patterns.iter().map(|(_, pattern)| {
+            std::iter::once((pattern.name.as_str(), TITLE_WEIGHT))
+                .chain(std::iter::once((pattern.description.as_str(), 1.0)))
+                .chain(pattern.use_cases.iter().map(|use_case| (use_case.as_str(), 1.0)))
+        }));
+        index.rank(scenario).into_iter().map(|(position, _)| patterns[position].1.clone()).collect()
     }
     
     /// Get architecture documentation references
     fn get_architecture_documentation_refs(&self, version: &str) -> Vec<String> {
-        if let Some(urls) = self.knowledge_base.get_official_urls(Some(version)) {
-            let mut refs = vec![urls.quickstart.clone()];
-            refs.extend(urls.best_practices.clone());
-            refs
-        } else {
-            vec!["https://google.github.io/adk-docs/get-started/quickstart/".to_string()]
-        }
+        let urls = self.knowledge_base.get_official_urls(Some(version));
+        let mut refs = vec![urls.quickstart];
+        refs.extend(urls.best_practices);
+        refs
     }
     
     /// Get best practices documentation references
     fn get_best_practices_documentation_refs(&self, _scenario: &str, version: &str) -> Vec<String> {
-        if let Some(urls) = self.knowledge_base.get_official_urls(Some(version)) {
-            let mut refs = vec![urls.quickstart.clone()];
-            refs.extend(urls.best_practices.clone());
-            refs.extend(urls.tutorials.clone());
-            refs
-        } else {
-            vec!["https://google.github.io/adk-docs/get-started/quickstart/".to_string()]
-        }
+        let urls = self.knowledge_base.get_official_urls(Some(version));
+        let mut refs = vec![urls.quickstart];
+        refs.extend(urls.best_practices);
+        refs.extend(urls.tutorials);
+        refs
     }
 }
 
@@ -489,9 +1584,72 @@ impl ValidationRules {
             architecture_rules: Self::create_default_architecture_rules(),
             code_pattern_rules: Self::create_default_code_pattern_rules(),
             best_practice_rules: Self::create_default_best_practice_rules(),
+            custom_rules: Vec::new(),
+            overrides: RuleOverrides::default(),
+            scoring: ScoringModel::default(),
+            rule_sets: Vec::new(),
         }
     }
     
+    /// Version of the effective rules: the server version and a fingerprint of every rule and setting
+    ///
+    /// Two validations report the same version only when they ran with identical rules,
+    /// so a compliance report can be traced to the rules that produced it.
+    pub fn version(&self) -> String {
+        let severity_overrides: BTreeMap<_, _> = self.overrides.severity_overrides.iter().collect();
+        let rules = serde_json::json!([
+            self.architecture_rules,
+            self.code_pattern_rules,
+            self.best_practice_rules,
+            self.custom_rules,
+            self.overrides.disabled_rules,
+            self.overrides.enabled_rules,
+            severity_overrides,
+            self.scoring,
+        ]);
+        
+        // FNV-1a, which unlike the standard library hasher is stable across builds
+        let fingerprint = rules.to_string().bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+        format!("{}+{:016x}", env!("CARGO_PKG_VERSION"), fingerprint)
+    }
+    
+    /// Layer a rule set over these rules, replacing rules with the same id and adding the rest
+    pub fn merge_rule_set(&mut self, rule_set: RuleSet) {
+        let label = rule_set.name.as_deref().unwrap_or("unnamed");
+        self.rule_sets.push(match &rule_set.version {
+            Some(version) => format!("{}@{}", label, version),
+            None => label.to_string(),
+        });
+        
+        fn merge<T>(rules: &mut Vec<T>, incoming: Vec<T>, id: impl Fn(&T) -> &str) {
+            for rule in incoming {
+                match rules.iter().position(|existing| id(existing) == id(&rule)) {
+                    Some(index) => rules[index] = rule,
+                    None => rules.push(rule),
+                }
+            }
+        }
+        
+        merge(&mut self.architecture_rules, rule_set.architecture_rules, |rule| &rule.id);
+        merge(&mut self.code_pattern_rules, rule_set.code_pattern_rules, |rule| &rule.id);
+        merge(&mut self.best_practice_rules, rule_set.best_practice_rules, |rule| &rule.id);
+        merge(&mut self.custom_rules, rule_set.custom_rules, |rule| &rule.id);
+        self.overrides.layer(rule_set.overrides);
+        if let Some(scoring) = rule_set.scoring {
+            self.scoring = scoring;
+        }
+    }
+    
+    /// Whether any rule has the id `rule_id`
+    pub fn has_rule(&self, rule_id: &str) -> bool {
+        self.architecture_rules.iter().any(|rule| rule.id == rule_id)
+            || self.code_pattern_rules.iter().any(|rule| rule.id == rule_id)
+            || self.best_practice_rules.iter().any(|rule| rule.id == rule_id)
+            || self.custom_rules.iter().any(|rule| rule.id == rule_id)
+    }
+    
     /// Create default architecture validation rules
     fn create_default_architecture_rules() -> Vec<ArchitectureRule> {
         vec![
@@ -501,7 +1659,9 @@ impl ValidationRules {
                 description: "Project should follow official ADK structure guidelines".to_string(),
                 category: "architecture".to_string(),
                 severity: ValidationSeverity::Warning,
-                pattern: "non-standard".to_string(),
+                pattern: r"\bnon-?standard\b".to_string(),
+                require: false,
+                when: None,
                 recommendation: "Follow the official ADK project structure as documented in the quickstart guide".to_string(),
                 documentation_ref: "https://google.github.io/adk-docs/get-started/quickstart/".to_string(),
             },
@@ -511,7 +1671,9 @@ impl ValidationRules {
                 description: "Should use proper async patterns as recommended by ADK".to_string(),
                 category: "architecture".to_string(),
                 severity: ValidationSeverity::Error,
-                pattern: "blocking operations".to_string(),
+                pattern: r"\bblocking\s+(?:operations?|calls?|i/o)\b".to_string(),
+                require: false,
+                when: None,
                 recommendation: "Use async/await patterns and non-blocking operations as specified in ADK guidelines".to_string(),
                 documentation_ref: "https://google.github.io/adk-docs/best-practices/".to_string(),
             },
@@ -521,10 +1683,252 @@ impl ValidationRules {
                 description: "Should implement proper error handling following ADK conventions".to_string(),
                 category: "error_handling".to_string(),
                 severity: ValidationSeverity::Error,
-                pattern: "panic".to_string(),
+                pattern: r"\bpanic(?:s|king)?\b".to_string(),
+                require: false,
+                when: None,
                 recommendation: "Use Result types and proper error propagation instead of panic! calls".to_string(),
                 documentation_ref: "https://google.github.io/adk-docs/best-practices/".to_string(),
             },
+            ArchitectureRule {
+                id: "safety_guardrails".to_string(),
+                name: "Safety Guardrails".to_string(),
+                description: "Agents should screen model inputs, outputs, and tool calls for policy violations".to_string(),
+                category: "safety".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\b(?:no|without(?:\s+any)?)\s+(?:safety\s+)?guardrails?\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Add before_model_callback and before_tool_callback guardrails and configure model safety settings; see the \"safety\" best practices category".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/safety/".to_string(),
+            },
+            ArchitectureRule {
+                id: "tool_confirmation".to_string(),
+                name: "Tool Confirmation".to_string(),
+                description: "Tools with consequential side effects should require human confirmation".to_string(),
+                category: "safety".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\bwithout\s+(?:any\s+|user\s+|human\s+)?confirm(?:ation|ing)\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Set require_confirmation on tools that spend money, delete data, or contact people; see the \"safety\" best practices category".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/tools/confirmation/".to_string(),
+            },
+            ArchitectureRule {
+                id: "god_agent".to_string(),
+                name: "God Agent".to_string(),
+                description: "A single agent owning dozens of tools struggles to pick the right one and is hard to test".to_string(),
+                category: "anti_pattern".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\b(?:dozens\s+of|(?:1[1-9]|[2-9]\d|\d{3,})\+?|(?:eleven|twelve|fifteen|twenty|thirty|forty|fifty)(?:\s+or\s+more)?)\s+tools\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Split the agent into specialist sub-agents with a handful of related tools each, coordinated by a root agent".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/agents/multi-agents/".to_string(),
+            },
+            ArchitectureRule {
+                id: "shared_global_state".to_string(),
+                name: "Shared Global State".to_string(),
+                description: "Agents sharing mutable global state race with each other and lose data across sessions and replicas".to_string(),
+                category: "anti_pattern".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\b(?:global|module-level|static)\s+(?:mutable\s+)?(?:state|variables?|dict(?:ionary)?|maps?|cache)\b|\bshared\s+mutable\s+state\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Keep agent data in session state (ToolContext.state or output_key) and durable data in an external store".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/sessions/state/".to_string(),
+            },
+            ArchitectureRule {
+                id: "sync_llm_chaining".to_string(),
+                name: "Synchronous LLM Call Chaining".to_string(),
+                description: "Chaining model calls one after another by hand adds their latencies and blocks the agent while each call runs".to_string(),
+                category: "anti_pattern".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\b(?:chain(?:s|ed|ing)?|sequential(?:ly)?|one\s+after\s+another|serial(?:ly)?|synchronous(?:ly)?)\s+(?:\w+\s+){0,2}(?:llm|model|gemini)\s+calls?\b|\b(?:llm|model|gemini)\s+calls?\s+(?:are\s+)?(?:chained|made\s+sequentially|made\s+synchronously)\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Use a ParallelAgent for independent steps and a SequentialAgent passing results through output_key for dependent ones, with async model calls".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/agents/workflow-agents/".to_string(),
+            },
+            ArchitectureRule {
+                id: "missing_session_persistence".to_string(),
+                name: "Missing Session Persistence".to_string(),
+                description: "In-memory sessions are lost on every restart and are not shared between replicas".to_string(),
+                category: "anti_pattern".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\bin-?memory\s*session(?:s|\s*service)?\b|\bsessions?\s+(?:are\s+)?(?:lost|reset|dropped|wiped)\s+(?:on|after|when|between)\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Use DatabaseSessionService or VertexAiSessionService outside local development".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/sessions/session/".to_string(),
+            },
+            ArchitectureRule {
+                id: "hardcoded_secrets".to_string(),
+                name: "Hard-coded Secrets".to_string(),
+                description: "API keys, passwords, and tokens kept in code or configuration files leak through source control and logs".to_string(),
+                category: "security".to_string(),
+                severity: ValidationSeverity::Error,
+                pattern: r"\b(?:hard-?coded|embedded|inline|checked[- ]in|committed)\s+(?:\w+\s+)?(?:api\s+keys?|secrets?|credentials?|passwords?|tokens?)\b|\b(?:api\s+keys?|secrets?|credentials?|passwords?|tokens?)\s+(?:are\s+|is\s+)?(?:hard-?coded|stored\s+in\s+(?:the\s+)?(?:code|source|repo(?:sitory)?))\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Load secrets from the environment or Secret Manager at startup and never commit them; see the \"security\" best practices category".to_string(),
+                documentation_ref: "https://cloud.google.com/secret-manager/docs".to_string(),
+            },
+            ArchitectureRule {
+                id: "unvalidated_tool_input".to_string(),
+                name: "Unvalidated Tool Input".to_string(),
+                description: "Tool arguments come from the model and can be steered by users, so they must be validated before use".to_string(),
+                category: "security".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\b(?:raw|unvalidated|unsanitized|unsanitised|unchecked|untrusted)\s+(?:user\s+|model\s+|tool\s+)?(?:input|arguments?|parameters?)\b|\bpass(?:es|ed)?\s+(?:\w+\s+){0,3}(?:directly|straight)\s+(?:in)?to\s+(?:sql|the\s+shell|a\s+shell|eval|the\s+database)\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Validate tool arguments against allow-lists in before_tool_callback and use parameterized queries; see the \"security\" best practices category".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/safety/".to_string(),
+            },
+            ArchitectureRule {
+                id: "unauthenticated_endpoint".to_string(),
+                name: "Unauthenticated Agent Endpoint".to_string(),
+                description: "An agent endpoint reachable without authentication lets anyone spend model quota and call its tools".to_string(),
+                category: "security".to_string(),
+                severity: ValidationSeverity::Error,
+                pattern: r"\bunauthenticated\b|\b(?:without|no)\s+(?:any\s+)?(?:auth(?:entication|orization)?|api\s+keys?|iam|login)\b|\b(?:publicly|openly)\s+(?:exposed|accessible|reachable)\b|\bopen\s+to\s+the\s+(?:internet|public)\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Require IAM or OAuth on deployed endpoints, e.g. deploy to Cloud Run without --allow-unauthenticated and front public apps with IAP; see the \"security\" best practices category".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/deploy/".to_string(),
+            },
+            ArchitectureRule {
+                id: "prompt_injection_exposure".to_string(),
+                name: "Prompt Injection Exposure".to_string(),
+                description: "Untrusted text placed in instructions or tool descriptions can override the agent's instructions".to_string(),
+                category: "security".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\b(?:tool\s+descriptions?|docstrings?|instructions?|system\s+prompts?)\s+(?:are\s+|is\s+)?(?:built|generated|populated|filled|templated)\s+(?:at\s+runtime\s+)?from\s+(?:user|untrusted|external|third-party|fetched|web|retrieved)\b|\b(?:user|untrusted|external|retrieved|fetched|web)\s+(?:input|content|text|data)\s+(?:is\s+)?(?:inserted|interpolated|injected|concatenated|pasted)\s+(?:directly\s+)?into\s+(?:the\s+)?(?:prompt|instructions?|tool\s+descriptions?)\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Keep instructions and tool descriptions static, pass untrusted content as clearly delimited data, and screen it in before_model_callback; see the \"security\" best practices category".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/safety/".to_string(),
+            },
+            ArchitectureRule {
+                id: "parallelizable_llm_calls".to_string(),
+                name: "Sequential Independent Steps".to_string(),
+                description: "Independent model calls or sub-agents run one after another take the sum of their latencies instead of the longest one".to_string(),
+                category: "performance".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\b(?:independent|unrelated|separate)\s+(?:\w+\s+){0,3}(?:run|runs|are\s+run|executed|are\s+executed|called|are\s+called|processed|are\s+processed)\s+(?:one\s+after\s+(?:another|the\s+other)|sequentially|in\s+sequence|serially)\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Run independent sub-agents in a ParallelAgent, or gather async model calls concurrently, and keep SequentialAgent for steps that depend on each other; see the \"performance\" best practices category".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/agents/workflow-agents/parallel-agents/".to_string(),
+            },
+            ArchitectureRule {
+                id: "missing_streaming".to_string(),
+                name: "Missing Response Streaming".to_string(),
+                description: "Users of interactive agents wait for the whole response when partial results are not streamed".to_string(),
+                category: "performance".to_string(),
+                severity: ValidationSeverity::Info,
+                pattern: r"\b(?:without|no)\s+(?:response\s+)?streaming\b|\bstreaming\s+(?:is\s+)?disabled\b|\bwaits?\s+for\s+the\s+(?:full|complete|entire|whole)\s+(?:response|answer|reply)\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Stream partial events to users with RunConfig(streaming_mode=StreamingMode.SSE), or bidirectional streaming for live audio and video; see the \"performance\" best practices category".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/streaming/".to_string(),
+            },
+            ArchitectureRule {
+                id: "unbounded_session_state".to_string(),
+                name: "Unbounded Session State".to_string(),
+                description: "Accumulating whole histories or documents in session state grows every request's context, latency, and cost".to_string(),
+                category: "performance".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\b(?:append|accumulat|stor|keep|sav)\w*\s+(?:the\s+)?(?:entire|full|whole|every|all)\s+(?:\w+\s+)?(?:conversations?|chats?|messages?|histor(?:y|ies)|transcripts?|tool\s+results?|documents?|responses?)\s+(?:in|into|to)\s+(?:the\s+)?(?:session\s+)?state\b|\bstate\s+grows\s+(?:without\s+(?:a\s+)?(?:limit|bound)|unbounded|indefinitely|forever)\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Keep only summaries and the keys the next step needs in session state, cap lists, and move long-term knowledge to a MemoryService; see the \"performance\" best practices category".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/sessions/state/".to_string(),
+            },
+            ArchitectureRule {
+                id: "chatty_tool_loop".to_string(),
+                name: "Chatty Tool Loop".to_string(),
+                description: "Calling a tool or the model once per item multiplies round trips and model turns".to_string(),
+                category: "performance".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\b(?:calls?|invokes?|queries|query)\s+(?:the\s+)?(?:\w+\s+)?(?:tool|model|llm|api)\s+(?:once\s+)?(?:per|for\s+each|for\s+every)\s+(?:\w+\s+)?(?:items?|records?|rows?|files?|pages?|results?|documents?|entry|entries|orders?|ids?|products?|customers?|tickets?)\b|\bone\s+(?:tool|model|llm)\s+call\s+per\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Give tools batch parameters (a list of ids instead of one id) and do per-item work inside the tool; see the \"performance\" best practices category".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/tools/function-tools/".to_string(),
+            },
+            ArchitectureRule {
+                id: "missing_evaluation_strategy".to_string(),
+                name: "Evaluation Strategy".to_string(),
+                description: "The design does not say how agent behavior is tested or evaluated".to_string(),
+                category: "testing".to_string(),
+                severity: ValidationSeverity::Info,
+                pattern: r"\beval(?:uat(?:e|es|ed|ion|ions|ing))?s?\b|\bevalsets?\b|\btest(?:s|ed|ing)?\b|\bgolden\s+(?:set|dataset|trajector(?:y|ies))\b|\bbenchmarks?\b|\bregression\s+suite\b".to_string(),
+                require: true,
+                when: None,
+                recommendation: "Add unit tests for tools and an ADK evaluation set of example conversations with expected tool trajectories and responses, run with `adk eval` in CI; see the \"evaluation\" best practices category".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/evaluate/".to_string(),
+            },
+            ArchitectureRule {
+                id: "missing_tracing".to_string(),
+                name: "Tracing".to_string(),
+                description: "The design does not say how model calls, tool calls, and agent transfers are traced".to_string(),
+                category: "observability".to_string(),
+                severity: ValidationSeverity::Info,
+                pattern: r"\btrac(?:e|es|ed|ing)\b|\bopen\s?telemetry\b|\botel\b|\bspans?\b|\barize\b|\bphoenix\b|\blangfuse\b|\bweave\b|\bagentops\b".to_string(),
+                require: true,
+                when: None,
+                recommendation: "Export ADK's OpenTelemetry spans to Cloud Trace or another tracing backend, e.g. `adk deploy cloud_run --trace_to_cloud`, to follow each request through model and tool calls".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/observability/cloud-trace/".to_string(),
+            },
+            ArchitectureRule {
+                id: "missing_structured_logging".to_string(),
+                name: "Structured Logging".to_string(),
+                description: "The design does not say how agents and tools emit structured logs".to_string(),
+                category: "observability".to_string(),
+                severity: ValidationSeverity::Info,
+                pattern: r"\bstructured\s+log(?:s|ging)?\b|\bjson\s+log(?:s|ging)?\b|\bcloud\s+logging\b|\blog\s+(?:fields|entries|records)\b".to_string(),
+                require: true,
+                when: None,
+                recommendation: "Emit JSON logs with the session id, invocation id, agent, and tool name as fields, e.g. through Cloud Logging, so failures can be filtered per conversation".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/observability/logging/".to_string(),
+            },
+            ArchitectureRule {
+                id: "broad_tool_parameters".to_string(),
+                name: "Broad Tool Parameters".to_string(),
+                description: "Tools taking arbitrary payloads, queries, or commands leave the model to guess their shape and widen what a bad call can do".to_string(),
+                category: "tools".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\b(?:generic|catch-all|do-anything|universal|general-purpose)\s+tools?\b|\btools?\b[^.;]*\b(?:takes?|accepts?|receives?)\s+(?:an?\s+)?(?:arbitrary|any|generic|free-form|raw|untyped|opaque)\s+(?:json|dict(?:ionary)?|payloads?|sql|quer(?:y|ies)|commands?|strings?|inputs?|arguments?|parameters?|objects?)\b".to_string(),
+                require: false,
+                when: None,
+                recommendation: "Give each tool a narrow purpose and typed, documented parameters, e.g. get_order(order_id: str) instead of run_query(sql: str)".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/tools/function-tools/".to_string(),
+            },
+            ArchitectureRule {
+                id: "missing_long_running_tool".to_string(),
+                name: "Long-Running Tool Handling".to_string(),
+                description: "Slow operations are described without saying how the agent waits for them, so a tool call blocks the turn until it finishes or times out".to_string(),
+                category: "tools".to_string(),
+                severity: ValidationSeverity::Warning,
+                pattern: r"\bLongRunningFunctionTool\b|\blong[- ]running\s+(?:function\s+)?tools?\b|\bpoll(?:s|ing)?\s+(?:for\s+)?(?:the\s+)?(?:status|job|progress)\b|\bjob\s+ids?\b|\bstatus\s+updates?\b".to_string(),
+                require: true,
+                when: Some(r"\btakes?\s+(?:several\s+|many\s+|a\s+few\s+)?(?:minutes|hours|days)\b|\blong-running\b|\bslow\s+(?:operations?|jobs?|apis?|tasks?|tools?)\b|\bbatch\s+jobs?\b|\b(?:human|manager)\s+approvals?\b|\bwaits?\s+for\s+(?:an?\s+)?approval\b|\brender(?:s|ing)?\s+(?:videos?|reports?)\b".to_string()),
+                recommendation: "Wrap slow operations in a LongRunningFunctionTool that returns a job id immediately, and report progress or the result in a later turn".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/tools/function-tools/#long-run-tool".to_string(),
+            },
+            ArchitectureRule {
+                id: "missing_error_contract".to_string(),
+                name: "Tool Error Contract".to_string(),
+                description: "The design does not say how tools report failures to the agent".to_string(),
+                category: "tools".to_string(),
+                severity: ValidationSeverity::Info,
+                pattern: r"\berror\s+(?:contracts?|responses?|results?|status(?:es)?|codes?|fields?|schemas?|messages?)\b|\breturns?\s+(?:an?\s+)?(?:error|status)\b|\bstatus\s+field\b|\bstructured\s+errors?\b".to_string(),
+                require: true,
+                when: Some(r"\btools?\b|\bfunction[- ]calling\b".to_string()),
+                recommendation: "Have every tool return a dict with a \"status\" of \"success\" or \"error\" and an error message the model can act on, instead of raising".to_string(),
+                documentation_ref: "https://google.github.io/adk-docs/tools/function-tools/".to_string(),
+            },
         ]
     }
     
@@ -539,6 +1943,7 @@ impl ValidationRules {
                 rationale: "unwrap() can cause panics; use proper error handling instead".to_string(),
                 category: "error_handling".to_string(),
                 severity: ValidationSeverity::Warning,
+                language: Some(SnippetLanguage::Rust),
             },
             CodePatternRule {
                 id: "panic_usage".to_string(),
@@ -548,6 +1953,7 @@ impl ValidationRules {
                 rationale: "panic! should be avoided in favor of recoverable error handling".to_string(),
                 category: "error_handling".to_string(),
                 severity: ValidationSeverity::Error,
+                language: Some(SnippetLanguage::Rust),
             },
             CodePatternRule {
                 id: "todo_usage".to_string(),
@@ -557,6 +1963,27 @@ impl ValidationRules {
                 rationale: "TODO markers indicate incomplete implementation".to_string(),
                 category: "completeness".to_string(),
                 severity: ValidationSeverity::Info,
+                language: Some(SnippetLanguage::Rust),
+            },
+            CodePatternRule {
+                id: "static_mut_state".to_string(),
+                name: "Avoid static mut state".to_string(),
+                pattern: "static mut ".to_string(),
+                expected_pattern: "session state or a synchronized store passed to the agent".to_string(),
+                rationale: "Mutable globals are shared by every agent and session and are unsound across threads".to_string(),
+                category: "anti_pattern".to_string(),
+                severity: ValidationSeverity::Warning,
+                language: Some(SnippetLanguage::Rust),
+            },
+            CodePatternRule {
+                id: "in_memory_sessions".to_string(),
+                name: "In-memory session service".to_string(),
+                pattern: "InMemorySessionService".to_string(),
+                expected_pattern: "DatabaseSessionService or VertexAiSessionService outside local development".to_string(),
+                rationale: "In-memory sessions are lost on restart and not shared between replicas".to_string(),
+                category: "anti_pattern".to_string(),
+                severity: ValidationSeverity::Info,
+                language: None,
             },
         ]
     }
@@ -583,6 +2010,18 @@ impl ValidationRules {
                     "Restructure project to match guidelines".to_string(),
                     "Update code to use recommended patterns".to_string(),
                 ],
+                criteria_patterns: HashMap::from([
+                    (
+                        "Uses recommended project structure".to_string(),
+                        r"\bagent\.py\b|\b(?:recommended|official|adk)\s+(?:project|directory)\s+(?:structure|layout)\b".to_string(),
+                    ),
+                    ("Follows naming conventions".to_string(), r"\bnaming\s+conventions?\b".to_string()),
+                    ("Implements proper async patterns".to_string(), r"\basync\b|\bawait\b".to_string()),
+                    (
+                        "Inconsistent naming".to_string(),
+                        r"\binconsistent(?:ly)?\s+nam(?:ing|ed)\b|\bmixed\s+naming\b|\bnaming\s+(?:is\s+)?inconsistent\b".to_string(),
+                    ),
+                ]),
             },
         ]
     }
@@ -597,6 +2036,19 @@ impl PatternMatcher {
         }
     }
     
+    /// Look up an architecture pattern by key, listing the available keys when it is unknown
+    pub fn architecture_pattern(&self, name: &str) -> anyhow::Result<(&str, &ArchitecturePattern)> {
+        let key = name.trim().to_lowercase();
+        self.architecture_patterns
+            .get_key_value(key.as_str())
+            .map(|(key, pattern)| (key.as_str(), pattern))
+            .ok_or_else(|| {
+                let mut available: Vec<&str> = self.architecture_patterns.keys().map(String::as_str).collect();
+                available.sort();
+                anyhow::anyhow!("Unknown architecture pattern '{}'. Available patterns: {}", name, available.join(", "))
+            })
+    }
+    
     /// Create default architecture patterns
     fn create_default_architecture_patterns() -> HashMap<String, ArchitecturePattern> {
         let mut patterns = HashMap::new();
@@ -616,12 +2068,72 @@ impl PatternMatcher {
             anti_patterns: vec![
                 "Blocking operations in async context".to_string(),
                 "Panic-based error handling".to_string(),
+                "God agents with dozens of tools".to_string(),
+                "Shared mutable global state between agents".to_string(),
+                "Synchronous chaining of LLM calls".to_string(),
+                "Sessions without persistence".to_string(),
             ],
             validation_criteria: vec![
                 "Follows ADK project structure".to_string(),
                 "Uses async/await patterns".to_string(),
                 "Implements proper error handling".to_string(),
             ],
+            component_patterns: HashMap::from([
+                (
+                    "Proper project structure".to_string(),
+                    r"\bproject\s+(?:structure|layout)\b|\bagent\.py\b|\b__init__\.py\b|\bcargo\.toml\b|\bmodules?\b|\bpackages?\b".to_string(),
+                ),
+                (
+                    "Configuration management".to_string(),
+                    r"\bconfig(?:uration|ured|urable)?\b|\benvironment\s+variables?\b|\benv\s+vars?\b|\.env\b|\bsettings\b|\bsecret\s+manager\b".to_string(),
+                ),
+                (
+                    "Error handling".to_string(),
+                    r"\berrors?\b|\bexceptions?\b|\bretr(?:y|ies)\b|\bfallbacks?\b|\bresult<".to_string(),
+                ),
+            ]),
+        });
+        
+        patterns.insert("multi_agent_team".to_string(), ArchitecturePattern {
+            name: "Multi-Agent Team".to_string(),
+            description: "A coordinator agent delegating to specialist sub-agents that share session state".to_string(),
+            required_components: vec![
+                "Coordinator agent".to_string(),
+                "Specialist sub-agents".to_string(),
+                "Agent descriptions for delegation".to_string(),
+                "Shared session state".to_string(),
+            ],
+            optional_components: vec![
+                "A2A for remote agents".to_string(),
+                "Agent evaluation".to_string(),
+            ],
+            anti_patterns: vec![
+                "One agent with every tool and instruction".to_string(),
+                "Sub-agents passing context through prompts instead of session state".to_string(),
+            ],
+            validation_criteria: vec![
+                "A root agent routes requests to sub-agents".to_string(),
+                "Each sub-agent has a focused instruction and a description used for delegation".to_string(),
+                "Agents exchange results through session state, e.g. output_key".to_string(),
+            ],
+            component_patterns: HashMap::from([
+                (
+                    "Coordinator agent".to_string(),
+                    r"\b(?:coordinator|root|orchestrator|router|routing|supervisor|manager|dispatcher|triage)\s+agent\b".to_string(),
+                ),
+                (
+                    "Specialist sub-agents".to_string(),
+                    r"\bsub-?agents?\b|\bspecialists?(?:\s+agents?)?\b|\bdelegat\w*\s+to\b".to_string(),
+                ),
+                (
+                    "Agent descriptions for delegation".to_string(),
+                    r"\bdescriptions?\b|\binstructions?\b".to_string(),
+                ),
+                (
+                    "Shared session state".to_string(),
+                    r"\b(?:session|shared)\s+state\b|\boutput_key\b|\bstate\b".to_string(),
+                ),
+            ]),
         });
         
         patterns
@@ -655,6 +2167,221 @@ impl Default for BestPracticesEnforcer {
     }
 }
 
+impl ArchitecturePattern {
+    /// Findings for required components the description and snippets never mention
+    pub fn missing_component_findings(&self, key: &str, description: &str, snippets: &str) -> Vec<ValidationFinding> {
+        self.required_components
+            .iter()
+            .enumerate()
+            .filter(|(_, component)| !self.mentions_component(component, description, snippets))
+            .map(|(index, component)| ValidationFinding {
+                id: format!("pattern_{}_{}", key, index),
+                rule_id: format!("pattern_{}", key),
+                severity: ValidationSeverity::Warning,
+                description: format!("{} is missing a required component: {}", self.name, component),
+                location: Some("Architecture Description".to_string()),
+                suggested_fix: Some(format!(
+                    "Describe the design's {} as the {} pattern requires",
+                    component.to_lowercase(),
+                    self.name
+                )),
+            })
+            .collect()
+    }
+    
+    /// Recommendation walking through the pattern's validation criteria
+    pub fn criteria_recommendation(&self, rec_id: usize, documentation_ref: String) -> Recommendation {
+        Recommendation {
+            id: format!("rec_{}", rec_id),
+            category: "Architecture Pattern".to_string(),
+            priority: 2,
+            description: format!("Check the design against the {} criteria: {}", self.name, self.description),
+            implementation_steps: self.validation_criteria.clone(),
+            benefits: self.optional_components.iter().map(|component| format!("Optional: {}", component)).collect(),
+            documentation_ref,
+            estimated_impact: 0,
+        }
+    }
+    
+    /// Whether the description affirms a component or a snippet mentions it
+    fn mentions_component(&self, component: &str, description: &str, snippets: &str) -> bool {
+        let pattern = self
+            .component_patterns
+            .get(component)
+            .cloned()
+            .unwrap_or_else(|| format!(r"\b{}\b", regex::escape(component)));
+        match RegexBuilder::new(&pattern).case_insensitive(true).build() {
+            Ok(regex) => find_affirmative_match(&regex, description).is_some() || regex.is_match(snippets),
+            Err(e) => {
+                warn!("Skipping component '{}' of pattern '{}' with an invalid pattern: {}", component, self.name, e);
+                true
+            }
+        }
+    }
+}
+
+impl BestPracticeRule {
+    /// Findings for failure indicators the description affirms or a snippet exhibits, each with the snippet it came from
+    ///
+    /// An indicator affirmed in the description is reported against the description,
+    /// otherwise against the first snippet exhibiting it.
+    pub fn failure_findings(&self, description: &str, snippets: &[String]) -> Vec<(ValidationFinding, Option<usize>)> {
+        self.failure_indicators
+            .iter()
+            .enumerate()
+            .filter_map(|(index, indicator)| {
+                let source = self.evidence(indicator, description, snippets)?;
+                let location = match source {
+                    Some(snippet) => format!("Code Snippet {}", snippet + 1),
+                    None => "Architecture Description".to_string(),
+                };
+                let finding = ValidationFinding {
+                    id: format!("practice_{}_{}", self.id, index),
+                    rule_id: self.id.clone(),
+                    severity: ValidationSeverity::Warning,
+                    description: format!("{}: {}", self.validation_logic, indicator),
+                    location: Some(location),
+                    suggested_fix: (!self.remediation_steps.is_empty()).then(|| self.remediation_steps.join("; ")),
+                };
+                Some((finding, source))
+            })
+            .collect()
+    }
+    
+    /// Success criteria the description affirms or a snippet exhibits
+    pub fn met_criteria(&self, description: &str, snippets: &[String]) -> Vec<&str> {
+        self.success_criteria
+            .iter()
+            .filter(|criterion| self.evidence(criterion, description, snippets).is_some())
+            .map(String::as_str)
+            .collect()
+    }
+    
+    /// Recommendation walking through the remediation steps and the success criteria still unmet
+    pub fn remediation_recommendation(&self, rec_id: usize, description: &str, snippets: &[String], documentation_ref: String) -> Recommendation {
+        let met = self.met_criteria(description, snippets);
+        let unmet = self.success_criteria.iter().filter(|criterion| !met.contains(&criterion.as_str()));
+        
+        Recommendation {
+            id: format!("rec_{}", rec_id),
+            category: "Best Practice".to_string(),
+            priority: 2,
+            description: format!(
+                "{} ({} of {} success criteria met)",
+                self.validation_logic,
+                met.len(),
+                self.success_criteria.len()
+            ),
+            implementation_steps: self
+                .remediation_steps
+                .iter()
+                .cloned()
+                .chain(unmet.map(|criterion| format!("Meet the success criterion: {}", criterion)))
+                .collect(),
+            benefits: self.success_criteria.clone(),
+            documentation_ref,
+            estimated_impact: 0,
+        }
+    }
+    
+    /// Where a criterion or indicator is evidenced: `Some(None)` for the description, `Some(Some(index))` for a snippet
+    fn evidence(&self, criterion: &str, description: &str, snippets: &[String]) -> Option<Option<usize>> {
+        let pattern = self
+            .criteria_patterns
+            .get(criterion)
+            .cloned()
+            .unwrap_or_else(|| format!(r"\b{}\b", regex::escape(criterion)));
+        let regex = match RegexBuilder::new(&pattern).case_insensitive(true).build() {
+            Ok(regex) => regex,
+            Err(e) => {
+                warn!("Skipping criterion '{}' of best practice rule '{}' with an invalid pattern: {}", criterion, self.id, e);
+                return None;
+            }
+        };
+        
+        if find_affirmative_match(&regex, description).is_some() {
+            return Some(None);
+        }
+        snippets.iter().position(|snippet| regex.is_match(snippet)).map(Some)
+    }
+}
+
+impl SeverityWeights {
+    /// Points deducted for one finding of `severity`
+    pub fn deduction(&self, severity: &ValidationSeverity) -> u8 {
+        match severity {
+            ValidationSeverity::Error => self.error,
+            ValidationSeverity::Warning => self.warning,
+            ValidationSeverity::Info => self.info,
+        }
+    }
+}
+
+impl ScoringModel {
+    /// Points the score would recover if `findings` were resolved
+    ///
+    /// Findings located in a code snippet are weighed with the snippet weights, all others
+    /// with the description weights.
+    pub fn recovered_points<'f>(&self, findings: impl Iterator<Item = &'f ValidationFinding>) -> u8 {
+        let points: u32 = findings
+            .map(|finding| {
+                let in_snippet = finding.location.as_deref().is_some_and(|location| location.starts_with("Code Snippet"));
+                let weights = if in_snippet { &self.snippet_weights } else { &self.description_weights };
+                u32::from(weights.deduction(&finding.severity))
+            })
+            .sum();
+        points.min(100) as u8
+    }
+}
+
+impl Default for ScoringModel {
+    fn default() -> Self {
+        Self {
+            description_weights: SeverityWeights { error: 20, warning: 10, info: 5 },
+            snippet_weights: SeverityWeights { error: 15, warning: 8, info: 3 },
+            compliance_threshold: 80,
+            positive_credit: 5,
+        }
+    }
+}
+
+impl RuleOverrides {
+    /// Whether there is nothing to override
+    pub fn is_empty(&self) -> bool {
+        self.disabled_rules.is_empty() && self.enabled_rules.is_empty() && self.severity_overrides.is_empty()
+    }
+    
+    /// Layer `other` over these overrides; its enabled rules undo earlier disables
+    pub fn layer(&mut self, other: RuleOverrides) {
+        self.disabled_rules.retain(|id| !other.enabled_rules.contains(id));
+        for id in other.disabled_rules {
+            if !self.disabled_rules.contains(&id) {
+                self.disabled_rules.push(id);
+            }
+        }
+        self.severity_overrides.extend(other.severity_overrides);
+    }
+    
+    /// Rule ids named by these overrides
+    pub fn rule_ids(&self) -> impl Iterator<Item = &String> {
+        self.disabled_rules
+            .iter()
+            .chain(&self.enabled_rules)
+            .chain(self.severity_overrides.keys())
+    }
+    
+    /// Apply the overrides to a finding, dropping it when its rule is disabled
+    pub fn apply(&self, mut finding: ValidationFinding) -> Option<ValidationFinding> {
+        if self.disabled_rules.contains(&finding.rule_id) {
+            return None;
+        }
+        if let Some(severity) = self.severity_overrides.get(&finding.rule_id) {
+            finding.severity = severity.clone();
+        }
+        Some(finding)
+    }
+}
+
 impl Default for ValidationRules {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,86 @@
+//! Standalone compliance report artifacts
+//!
+//! With `artifact` set, `validate_architecture` returns its result as a self-contained
+//! report in Markdown and JSON, ready to attach to a design doc or change-management
+//! ticket. Both forms carry metadata identifying what was validated and how: when, by
+//! which server, against which ADK version, and with which rules, so a report can be
+//! reproduced or audited later.
+
+use serde::{Deserialize, Serialize};
+use crate::expert::best_practices::ArchitectureValidationResult;
+use crate::expert::documentation::format_unix_time;
+
+/// Format version of the JSON artifact, raised when its fields change incompatibly
+pub const REPORT_FORMAT_VERSION: u32 = 1;
+
+/// What a compliance report covers and how it was produced
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReportMetadata {
+    /// Format version of the report
+    pub format_version: u32,
+    /// Project the design belongs to, if given
+    pub project_id: Option<String>,
+    /// When the validation ran, as seconds since the Unix epoch
+    pub generated_at: i64,
+    /// Name and version of the server that produced the report
+    pub generator: String,
+    /// ADK version the design was validated against
+    pub adk_version: String,
+    /// Version of the effective validation rules
+    pub rule_set_version: String,
+    /// Rule sets layered over the built-in rules
+    pub rule_sets: Vec<String>,
+    /// Architecture description that was validated
+    pub description: String,
+    /// Number of code snippets validated with the description
+    pub code_snippets: usize,
+}
+
+/// Validation result with the metadata needed to stand on its own
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ComplianceReport {
+    /// What the report covers and how it was produced
+    pub metadata: ReportMetadata,
+    /// The validation result
+    pub result: ArchitectureValidationResult,
+}
+
+impl ComplianceReport {
+    /// Render the report as a Markdown document around an already formatted validation `body`
+    pub fn to_markdown(&self, body: &str) -> String {
+        let metadata = &self.metadata;
+        let mut rows = vec![
+            ("Generated", format_unix_time(metadata.generated_at)),
+            ("Generator", metadata.generator.clone()),
+            ("ADK version", metadata.adk_version.clone()),
+            ("Rule-set version", format!("`{}`", metadata.rule_set_version)),
+            (
+                "Rule sets",
+                if metadata.rule_sets.is_empty() { "built-in only".to_string() } else { metadata.rule_sets.join(", ") },
+            ),
+            ("Code snippets", metadata.code_snippets.to_string()),
+        ];
+        if let Some(project_id) = &metadata.project_id {
+            rows.insert(0, ("Project", format!("`{}`", project_id)));
+        }
+        
+        let table = rows
+            .iter()
+            .map(|(field, value)| format!("| {} | {} |", field, value))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let description = metadata.description.lines().map(|line| format!("> {}", line)).collect::<Vec<_>>().join("\n");
+        
+        format!(
+            "# ADK Compliance Report\n\n| Field | Value |\n|-------|-------|\n{}\n\n## Design Under Review\n\n{}\n\n{}",
+            table,
+            description,
+            body.replacen("# Architecture Validation", "## Architecture Validation", 1)
+        )
+    }
+    
+    /// Render the report as pretty-printed JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
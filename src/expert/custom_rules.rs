@@ -0,0 +1,141 @@
+//! User-defined validation rules
+//!
+//! Custom rules are a small declarative format for organization-specific conventions,
+//! such as "all agents must use our telemetry wrapper". Each rule names what to look for,
+//! where to look (the architecture description, code snippets, or both), and the message,
+//! fix suggestion, and documentation link reported when it fires. Rules are declared in
+//! rule-set files and evaluated by `BestPracticesEnforcer` alongside the built-in rules.
+
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use crate::expert::best_practices::{ValidationFinding, ValidationSeverity, find_affirmative_match};
+
+/// Where a custom rule is evaluated
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleTarget {
+    /// The architecture description only
+    Description,
+    /// Code snippets only
+    Snippet,
+    /// Both the description and code snippets
+    #[default]
+    Both,
+}
+
+/// Declarative validation rule
+///
+/// A rule matches either literal text (`pattern`, case-insensitive) or a regular
+/// expression (`regex`). By default it fires when the text is found; with `require`
+/// set it fires when the text is missing instead. `when` limits the rule to texts
+/// matching another regular expression, e.g. snippets that define an agent.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CustomRule {
+    /// Rule identifier, also used to replace rules from earlier rule sets
+    pub id: String,
+    /// Literal text to look for, matched case-insensitively
+    #[serde(default)]
+    pub pattern: Option<String>,
+    /// Regular expression to look for, matched case-insensitively
+    #[serde(default)]
+    pub regex: Option<String>,
+    /// Fire when the text is missing rather than when it is present
+    #[serde(default)]
+    pub require: bool,
+    /// Regular expression a text must match for the rule to apply
+    #[serde(default)]
+    pub when: Option<String>,
+    /// Severity of the finding
+    #[serde(default = "default_severity")]
+    pub severity: ValidationSeverity,
+    /// Message reported when the rule fires
+    pub message: String,
+    /// Suggested fix
+    #[serde(default)]
+    pub fix: Option<String>,
+    /// Documentation explaining the convention
+    #[serde(default)]
+    pub docs: Option<String>,
+    /// Where the rule is evaluated
+    #[serde(default)]
+    pub applies_to: RuleTarget,
+}
+
+/// Severity for custom rules that do not declare one
+fn default_severity() -> ValidationSeverity {
+    ValidationSeverity::Warning
+}
+
+impl CustomRule {
+    /// Check that the rule names exactly one of `pattern` and `regex` and that its expressions compile
+    pub fn validate(&self) -> Result<(), String> {
+        match (&self.pattern, &self.regex) {
+            (Some(_), Some(_)) => return Err(format!("custom rule '{}' sets both 'pattern' and 'regex'", self.id)),
+            (None, None) => return Err(format!("custom rule '{}' needs a 'pattern' or a 'regex'", self.id)),
+            _ => {}
+        }
+        self.matcher().map_err(|e| format!("custom rule '{}' has an invalid regex: {}", self.id, e))?;
+        self.condition().map_err(|e| format!("custom rule '{}' has an invalid 'when' regex: {}", self.id, e))?;
+        Ok(())
+    }
+    
+    /// Whether the rule is evaluated against `target`
+    pub fn applies_to(&self, target: RuleTarget) -> bool {
+        self.applies_to == RuleTarget::Both || self.applies_to == target
+    }
+    
+    /// Evaluate the rule against `text`, reporting a finding with `id` at `location` when it fires
+    ///
+    /// Mentions in descriptions are negation-aware, so "we never call the raw client"
+    /// does not fire a rule forbidding the raw client.
+    pub fn evaluate(&self, text: &str, target: RuleTarget, id: String, location: String) -> Option<ValidationFinding> {
+        let matcher = self.matcher().ok()?;
+        if let Some(condition) = self.condition().ok()? {
+            if !condition.is_match(text) {
+                return None;
+            }
+        }
+        
+        let found = match target {
+            RuleTarget::Description => find_affirmative_match(&matcher, text).is_some(),
+            _ => matcher.is_match(text),
+        };
+        if found == self.require {
+            return None;
+        }
+        
+        let suggested_fix = match (&self.fix, &self.docs) {
+            (Some(fix), Some(docs)) => Some(format!("{} (see {})", fix, docs)),
+            (Some(fix), None) => Some(fix.clone()),
+            (None, Some(docs)) => Some(format!("See {}", docs)),
+            (None, None) => None,
+        };
+        
+        Some(ValidationFinding {
+            id,
+            rule_id: self.id.clone(),
+            severity: self.severity.clone(),
+            description: self.message.clone(),
+            location: Some(location),
+            suggested_fix,
+        })
+    }
+    
+    /// Compile the rule's pattern or regex
+    fn matcher(&self) -> Result<Regex, regex::Error> {
+        let pattern = match (&self.regex, &self.pattern) {
+            (Some(regex), _) => regex.clone(),
+            (None, Some(pattern)) => regex::escape(pattern),
+            (None, None) => return Err(regex::Error::Syntax("no pattern or regex".to_string())),
+        };
+        RegexBuilder::new(&pattern).case_insensitive(true).build()
+    }
+    
+    /// Compile the `when` condition, if any
+    fn condition(&self) -> Result<Option<Regex>, regex::Error> {
+        self.when
+            .as_deref()
+            .map(|when| RegexBuilder::new(when).case_insensitive(true).build())
+            .transpose()
+    }
+}
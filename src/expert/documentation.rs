@@ -1,6 +1,13 @@
 //! Documentation utilities and reference generation
 
-use crate::expert::adk_knowledge::{AdkKnowledgeBase, DocumentationUrls, ConceptInfo};
+use crate::expert::i18n::{Locale, localize};
+use crate::expert::query_analytics::{AnalyticsReport, TermStats};
+use crate::expert::federation::UpstreamSection;
+use crate::expert::validation_history::{ScoreTrend, TrendDirection};
+use crate::expert::adk_knowledge::{
+    AdkKnowledgeBase, ChangelogEntry, Citation, DocumentationUrls, ConceptInfo, ConceptMatch, BestPractice, CodeExampleMatch, ImplementationPattern,
+    FeatureAvailability, Passage, PatternMatch, PracticeMatch, ProjectTemplate, Provenance, VersionFeature, VersionStats, VersionSummary,
+};
 
 /// Documentation reference generator for version-aware official links
 pub struct DocumentationReferenceGenerator {
@@ -15,21 +22,12 @@ impl DocumentationReferenceGenerator {
     
     /// Generate official documentation references for specific version
     pub fn generate_official_references(&self, version: Option<&str>) -> Vec<String> {
-        if let Some(urls) = self.knowledge_base.get_official_urls(version) {
-            urls.get_all_urls()
-        } else {
-            // Fallback to default URLs
-            DocumentationUrls::default().get_all_urls()
-        }
+        self.knowledge_base.get_official_urls(version).get_all_urls()
     }
     
     /// Generate category-specific references
     pub fn generate_category_references(&self, category: &str, version: Option<&str>) -> Vec<String> {
-        if let Some(urls) = self.knowledge_base.get_official_urls(version) {
-            urls.get_urls_by_category(category)
-        } else {
-            DocumentationUrls::default().get_urls_by_category(category)
-        }
+        self.knowledge_base.get_official_urls(version).get_urls_by_category(category)
     }
     
     /// Generate references for specific concepts
@@ -38,7 +36,14 @@ impl DocumentationReferenceGenerator {
         
         if let Some(docs) = self.knowledge_base.get_version_docs(version_str) {
             if let Some(concept) = docs.concepts.get(concept_name) {
-                return concept.documentation_refs.clone();
+                // Concept links target the latest docs; point them at the requested version
+                return match self.knowledge_base.linked_version(version) {
+                    Some(linked) => concept.documentation_refs
+                        .iter()
+                        .map(|url| DocumentationUrls::versioned_url(url, &linked))
+                        .collect(),
+                    None => concept.documentation_refs.clone(),
+                };
             }
         }
         
@@ -47,6 +52,106 @@ impl DocumentationReferenceGenerator {
     }
 }
 
+/// How strongly an answer is backed by knowledge base content
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Confidence {
+    /// The query closely matches knowledge base entries
+    High,
+    /// The query partially matches knowledge base entries
+    Medium,
+    /// Only weak matches were found
+    Low,
+    /// Nothing in the knowledge base matches the query
+    NotFound,
+}
+
+impl Confidence {
+    /// Map a 0.0-1.0 relevance score to a confidence level
+    pub fn from_score(score: f32) -> Self {
+        if score >= 0.6 {
+            Self::High
+        } else if score >= 0.3 {
+            Self::Medium
+        } else if score > 0.0 {
+            Self::Low
+        } else {
+            Self::NotFound
+        }
+    }
+    
+    /// Human-readable label for display in responses
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::High => "High",
+            Self::Medium => "Medium",
+            Self::Low => "Low",
+            Self::NotFound => "None",
+        }
+    }
+}
+
+/// Format the confidence line for an answer whose best match has `score`
+pub fn format_confidence(score: f32) -> String {
+    let confidence = Confidence::from_score(score);
+    match confidence {
+        Confidence::NotFound => "**Confidence:** None - no knowledge base content supports this answer".to_string(),
+        Confidence::Low => format!(
+            "**Confidence:** {} ({}% match) - only loosely related content was found, verify against the official documentation",
+            confidence.label(),
+            relevance_percent(score)
+        ),
+        _ => format!("**Confidence:** {} ({}% match)", confidence.label(), relevance_percent(score)),
+    }
+}
+
+/// Format an explicit "not found" response with the closest related topics
+///
+/// Used instead of generic filler so downstream clients can tell that the
+/// knowledge base has nothing to say about the query.
+pub fn format_not_found_response(
+    query: &str,
+    version: &str,
+    suggestions: &[String],
+    references: &[String],
+) -> String {
+    let suggestions_text = if suggestions.is_empty() {
+        "No closely related topics were found.".to_string()
+    } else {
+        suggestions
+            .iter()
+            .map(|topic| format!("- {}", topic))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    
+    format!(
+        "## Google ADK Documentation Query: {}\n\n\
+        **Version:** {}\n\n\
+        {}\n\n\
+        No relevant content was found in the ADK knowledge base for this query. \
+        Rather than guess, please rephrase the question or consult the official documentation.\n\n\
+        ### Closest Related Topics:\n{}\n\n\
+        ### Official References:\n{}",
+        query,
+        version,
+        format_confidence(0.0),
+        suggestions_text,
+        format_reference_links(references)
+    )
+}
+
+/// Format suggested follow-up questions appended to a query response
+pub fn format_follow_up_questions(questions: &[String]) -> String {
+    format!(
+        "### Suggested Follow-up Questions:\n{}",
+        questions
+            .iter()
+            .map(|question| format!("- {}", question))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
+}
+
 /// Format documentation response with proper references and version information
 pub fn format_documentation_response(
     query: &str,
@@ -63,11 +168,7 @@ pub fn format_documentation_response(
         query,
         version,
         content,
-        references
-            .iter()
-            .map(|url| format!("- [{}]({})", extract_url_title(url), url))
-            .collect::<Vec<_>>()
-            .join("\n"),
+        format_reference_links(references),
         version
     )
 }
@@ -77,6 +178,598 @@ pub fn format_concept_response(
     concept: &ConceptInfo,
     version: &str,
 ) -> String {
+    format!(
+        "## {}\n\n\
+        **Version:** {}\n\n\
+        {}\n\n\
+        {}\n\n\
+        ### Official References:\n{}\n\n\
+        *Information based on official Google ADK documentation (version: {})*",
+        concept.name,
+        version,
+        format_freshness_note(concept.source_url.as_deref(), concept.last_updated),
+        format_concept_details(concept),
+        format_reference_links(&concept.documentation_refs),
+        version
+    )
+}
+
+/// Format ranked concept matches, expanding the most relevant one
+///
+/// The top match is rendered in full; remaining matches are listed with their
+/// relevance score and a one-sentence summary so ambiguous queries stay visible.
+pub fn format_ranked_concept_response(
+    query: &str,
+    matches: &[ConceptMatch<'_>],
+    version: &str,
+) -> String {
+    let Some(top) = matches.first() else {
+        return String::new();
+    };
+    
+    let others_text = if matches.len() > 1 {
+        format!(
+            "\n\n### Other Matches:\n{}",
+            matches[1..]
+                .iter()
+                .enumerate()
+                .map(|(index, m)| format!(
+                    "{}. **{}** (`{}`, relevance: {}%){} - {}",
+                    index + 1,
+                    m.concept.name,
+                    m.key,
+                    relevance_percent(m.score),
+                    format_source_tag(m.provenance),
+                    summarize(&m.concept.description)
+                ))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    } else {
+        String::new()
+    };
+    
+    format!(
+        "## Google ADK Documentation Query: {}\n\n\
+        **Version:** {}\n\n\
+        {}\n\n\
+        ### Most Relevant: {} (relevance: {}%)\n\n\
+        {}\n\n\
+        {}\n\n\
+        {}{}\n\n\
+        ### Official References:\n{}\n\n\
+        *Information based on official Google ADK documentation (version: {})*",
+        query,
+        version,
+        format_confidence(top.score),
+        top.concept.name,
+        relevance_percent(top.score),
+        format_source_note(top.provenance),
+        format_freshness_note(top.concept.source_url.as_deref(), top.concept.last_updated),
+        format_concept_details(top.concept),
+        others_text,
+        format_cited_reference_links(top.citation, &top.concept.documentation_refs),
+        version
+    )
+}
+
+/// Format a best practice as a markdown section, linking the supporting section when cited
+pub fn format_practice_content(practice: &BestPractice, provenance: Option<&Provenance>, citation: Option<&Citation>) -> String {
+    let reference = match citation {
+        Some(citation) => format_citation_link(citation),
+        None => format!("[{}]({})", practice.documentation_ref, practice.documentation_ref),
+    };
+    
+    format!(
+        "### {}\n\n**Category:** {}\n\n{}\n\n{}\n\n{}\n\n**Examples:**\n{}\n\n**Reference:** {}",
+        practice.title,
+        practice.category,
+        format_source_note(provenance),
+        format_freshness_note(practice.source_url.as_deref(), practice.last_updated),
+        practice.description,
+        practice.examples
+            .iter()
+            .map(|ex| format!("- {}", ex))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        reference
+    )
+}
+
+/// Format an implementation pattern with its use cases and code examples
+pub fn format_pattern_content(pattern: &ImplementationPattern, provenance: Option<&Provenance>) -> String {
+    let examples_text = pattern.code_examples
+        .iter()
+        .map(|example| {
+            format!(
+                "#### {}\n\n```{}\n{}\n```\n\n{}",
+                example.title,
+                example.language,
+                example.code,
+                example.explanation
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    
+    format!(
+        "### {}\n\n{}\n\n{}\n\n**Use Cases:**\n{}\n\n**Examples:**\n\n{}\n\n**Related Practices:**\n{}",
+        pattern.name,
+        format_source_note(provenance),
+        pattern.description,
+        pattern.use_cases
+            .iter()
+            .map(|uc| format!("- {}", uc))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        examples_text,
+        pattern.related_practices
+            .iter()
+            .map(|rp| format!("- {}", rp))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
+}
+
+/// Format ranked pattern matches, expanding the most relevant one
+pub fn format_ranked_patterns(matches: &[PatternMatch<'_>]) -> String {
+    let Some(top) = matches.first() else {
+        return String::new();
+    };
+    
+    let mut content = format_pattern_content(top.pattern, top.provenance);
+    if matches.len() > 1 {
+        content.push_str("\n\n### Other Patterns:\n");
+        content.push_str(
+            &matches[1..]
+                .iter()
+                .map(|m| format!(
+                    "- **{}** (`{}`, relevance: {}%){} - {}",
+                    m.pattern.name,
+                    m.key,
+                    relevance_percent(m.score),
+                    format_source_tag(m.provenance),
+                    summarize(&m.pattern.description)
+                ))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+    content
+}
+
+/// Format ranked code example matches as snippets with their explanations
+pub fn format_code_examples(matches: &[CodeExampleMatch<'_>]) -> String {
+    matches
+        .iter()
+        .map(|m| format!(
+            "### {}\n\n**Pattern:** {} (`{}`, relevance: {}%){}\n\n```{}\n{}\n```\n\n{}",
+            m.example.title,
+            m.pattern.name,
+            m.pattern_key,
+            relevance_percent(m.score),
+            format_source_tag(m.provenance),
+            m.example.language,
+            m.example.code,
+            m.example.explanation
+        ))
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n")
+}
+
+/// Format a project template as its setup steps followed by every file's contents
+pub fn format_template_content(template: &ProjectTemplate, provenance: Option<&Provenance>) -> String {
+    let layout = template.files
+        .iter()
+        .map(|file| format!("- `{}`", file.path))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let files = template.files
+        .iter()
+        .map(|file| format!(
+            "#### File: `{}`\n\n```{}\n{}\n```",
+            file.path,
+            code_fence_language(&file.path),
+            file.content.trim_end()
+        ))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let steps = template.setup_steps
+        .iter()
+        .enumerate()
+        .map(|(i, step)| format!("{}. {}", i + 1, step))
+        .collect::<Vec<_>>()
+        .join("\n");
+    
+    format!(
+        "### {}\n\n{}\n\n{}\n\n**Project Layout:**\n{}\n\n{}\n\n**Setup Steps:**\n{}",
+        template.name,
+        format_source_note(provenance),
+        template.description,
+        layout,
+        files,
+        steps
+    )
+}
+
+/// Code fence language for a template file, based on its extension
+fn code_fence_language(path: &str) -> &'static str {
+    match path.rsplit_once('.').map(|(_, ext)| ext) {
+        Some("py") => "python",
+        Some("toml") => "toml",
+        Some("json") => "json",
+        Some("yaml" | "yml") => "yaml",
+        Some("env") => "bash",
+        _ => "text",
+    }
+}
+
+/// Format best practice categories and their practice counts as a table
+pub fn format_category_list(categories: &[(String, usize)], version: &str) -> String {
+    let rows = categories
+        .iter()
+        .map(|(category, count)| format!("| `{}` | {} |", category, count))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let total: usize = categories.iter().map(|(_, count)| count).sum();
+    
+    format!(
+        "## Best Practice Categories\n\n**Version:** {}\n\n| Category | Practices |\n|----------|-----------|\n{}\n\n\
+        {} practices in {} categories. Pass a category above as `category` to `get_best_practices` to list its practices.",
+        version,
+        rows,
+        total,
+        categories.len()
+    )
+}
+
+/// Format query analytics as most-asked and most-missed term tables
+pub fn format_analytics_report(report: &AnalyticsReport) -> String {
+    let mut content = format!(
+        "## Query Analytics\n\n**Total queries:** {}\n**Unanswered queries:** {} ({:.1}%)\n**Tracked terms:** {}",
+        report.total_queries,
+        report.unanswered_queries,
+        report.miss_rate(),
+        report.tracked_terms
+    );
+    if report.total_queries == 0 {
+        content.push_str("\n\nNo queries have been recorded since the server started or the analytics were reset.");
+        return content;
+    }
+    
+    let rows = |terms: &[(String, TermStats)]| {
+        terms
+            .iter()
+            .map(|(term, stats)| format!("| `{}` | {} | {} |", term, stats.asked, stats.missed))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    content.push_str(&format!(
+        "\n\n### Most Asked Topics\n\n| Term | Asked | Missed |\n|------|-------|--------|\n{}",
+        rows(&report.most_asked)
+    ));
+    if report.most_missed.is_empty() {
+        content.push_str("\n\n### Most Missed Topics\n\nEvery recorded query was answered.");
+    } else {
+        content.push_str(&format!(
+            "\n\n### Most Missed Topics\n\n| Term | Asked | Missed |\n|------|-------|--------|\n{}\n\n\
+            Frequently missed terms are candidates for new concepts, practices, or patterns.",
+            rows(&report.most_missed)
+        ));
+    }
+    content
+}
+
+/// Format a project's compliance scores over time as a table
+pub fn format_score_trend(trend: &ScoreTrend) -> String {
+    let direction = match trend.direction {
+        TrendDirection::Improving => "improving",
+        TrendDirection::Declining => "declining",
+        TrendDirection::Steady => "steady",
+    };
+    let rows = trend
+        .records
+        .iter()
+        .map(|record| {
+            format!(
+                "| {} | {}/100 | {} | {} | {} | {} |",
+                format_unix_time(record.recorded_at),
+                record.compliance_score,
+                if record.is_compliant { "yes" } else { "no" },
+                record.errors,
+                record.warnings,
+                record.infos
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    
+    format!(
+        "## Validation Score Trend\n\n**Project:** {}\n**Validations:** {}\n**Latest Score:** {}/100\n**Best Score:** {}/100\n**Change:** {:+} ({})\n\n\
+        | Validated | Score | Compliant | Errors | Warnings | Info |\n|-----------|-------|-----------|--------|----------|------|\n{}",
+        trend.project_id,
+        trend.records.len(),
+        trend.latest_score(),
+        trend.best_score,
+        trend.change,
+        direction,
+        rows
+    )
+}
+
+/// Format per-version knowledge base sizes, refresh times, and sources as a table
+pub fn format_knowledge_stats(stats: &[VersionStats], default_version: &str) -> String {
+    if stats.is_empty() {
+        return "## Knowledge Base Statistics\n\nNo documentation is loaded for any version.".to_string();
+    }
+    
+    let rows = stats
+        .iter()
+        .map(|s| {
+            let version = if s.version == default_version {
+                format!("`{}` (default)", s.version)
+            } else {
+                format!("`{}`", s.version)
+            };
+            let refreshed = if s.updated_at > 0 { format_unix_time(s.updated_at) } else { "never".to_string() };
+            let sources = if s.sources.is_empty() { "unknown".to_string() } else { s.sources.join(", ") };
+            format!("| {} | {} | {} | {} | {} | {} |", version, s.concepts, s.best_practices, s.patterns, refreshed, sources)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    
+    format!(
+        "## Knowledge Base Statistics\n\n**Loaded versions:** {}\n\n\
+        | Version | Concepts | Best Practices | Patterns | Last Refresh | Sources |\n\
+        |---------|----------|----------------|----------|--------------|---------|\n{}\n\n\
+        A last refresh of \"never\" means the version only holds bundled or undated content.",
+        stats.len(),
+        rows
+    )
+}
+
+/// Format seconds since the Unix epoch as a UTC date and time
+pub(crate) fn format_unix_time(timestamp: i64) -> String {
+    let days = timestamp.div_euclid(86_400);
+    let seconds = timestamp.rem_euclid(86_400);
+    
+    // Civil date from days since 1970-01-01 (proleptic Gregorian calendar)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    
+    format!("{:04}-{:02}-{:02} {:02}:{:02} UTC", year, month, day, seconds / 3600, seconds % 3600 / 60)
+}
+
+/// Format ranked best practice matches in relevance order
+pub fn format_ranked_practices(matches: &[PracticeMatch<'_>]) -> String {
+    matches
+        .iter()
+        .map(|m| format_practice_content(m.practice, m.provenance, m.citation))
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n")
+}
+
+/// Synthesize an answer from retrieved passages, citing each one
+///
+/// Every claim in the summary carries a numbered citation pointing at the
+/// supporting passage, so the answer stays grounded in knowledge base content.
+pub fn format_grounded_answer(passages: &[Passage], version: &str) -> String {
+    let summary = passages
+        .iter()
+        .enumerate()
+        .map(|(index, p)| format!(
+            "- **{}** ({}){}: {} [{}]",
+            p.title,
+            p.kind.label(),
+            format_source_tag(p.provenance.as_ref()),
+            summarize(&p.text),
+            index + 1
+        ))
+        .collect::<Vec<_>>()
+        .join("\n");
+    
+    let supporting = passages
+        .iter()
+        .enumerate()
+        .map(|(index, p)| {
+            let reference = match (&p.citation, &p.reference) {
+                (Some(citation), _) => format!("\n\nReference: {}", format_citation_link(citation)),
+                (None, Some(url)) => format!("\n\nReference: [{}]({})", extract_url_title(url), url),
+                (None, None) => String::new(),
+            };
+            format!(
+                "**[{}] {}** - {}, relevance: {}%{}\n\n> {}{}",
+                index + 1,
+                p.title,
+                p.kind.label(),
+                relevance_percent(p.score),
+                format_source_tag(p.provenance.as_ref()),
+                p.text,
+                reference
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    
+    format!(
+        "{}\n\nBased on {} matching {} in the ADK knowledge base (version {}):\n\n{}\n\n### Supporting Passages\n\n{}",
+        format_confidence(passages.first().map_or(0.0, |p| p.score)),
+        passages.len(),
+        if passages.len() == 1 { "entry" } else { "entries" },
+        version,
+        summary,
+        supporting
+    )
+}
+
+/// Format a version feature with deprecation and migration details
+pub fn format_version_feature(feature: &VersionFeature) -> String {
+    let mut content = format!(
+        "### {}\n\n{}\n\n**Introduced in:** {}",
+        feature.name,
+        feature.description,
+        feature.introduced_in
+    );
+    if let Some(deprecated_in) = &feature.deprecated_in {
+        content.push_str(&format!("\n**Deprecated in:** {}", deprecated_in));
+    }
+    if let Some(notes) = &feature.migration_notes {
+        content.push_str(&format!("\n\n**Migration Notes:** {}", notes));
+    }
+    content
+}
+
+/// Format the version picker listing: default version, per-version status, and aliases
+pub fn format_version_list(summaries: &[VersionSummary], default_version: &str) -> String {
+    let rows = summaries
+        .iter()
+        .map(|summary| {
+            let aliases = if summary.aliases.is_empty() {
+                "-".to_string()
+            } else {
+                summary.aliases.iter().map(|alias| format!("`{}`", alias)).collect::<Vec<_>>().join(", ")
+            };
+            format!("| `{}` | {} | {} |", summary.version, summary.status.label(), aliases)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    
+    format!(
+        "## Google ADK Versions\n\n\
+        **Default version:** {}\n\n\
+        | Version | Status | Aliases |\n|---------|--------|---------|\n{}\n\n\
+        Pass a version, alias, or release channel (`stable`, `preview`, `next`) as the `version` \
+        parameter of any documentation tool.",
+        default_version,
+        rows
+    )
+}
+
+/// Format the cross-version availability matrix of a concept or feature
+pub fn format_availability(availability: &FeatureAvailability) -> String {
+    let mut content = format!(
+        "## Version Availability: {}\n\n\
+        **Introduced in:** {}\n\
+        **Minimum version:** {}",
+        availability.name,
+        availability.introduced_in.as_deref().unwrap_or("Not recorded"),
+        availability.minimum_version().unwrap_or("None of the known versions")
+    );
+    if let Some(deprecated_in) = &availability.deprecated_in {
+        content.push_str(&format!("\n**Deprecated in:** {}", deprecated_in));
+    }
+    
+    let rows = availability.versions
+        .iter()
+        .map(|v| format!("| `{}` | {} |", v.version, v.availability.label()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    content.push_str(&format!("\n\n| Version | Status |\n|---------|--------|\n{}", rows));
+    
+    if let Some(notes) = &availability.migration_notes {
+        content.push_str(&format!("\n\n**Migration Notes:** {}", notes));
+    }
+    content
+}
+
+/// Format a release digest grouping changes by kind, with links for each change
+pub fn format_release_digest(version: &str, changes: &[ChangelogEntry], release_notes_url: &str) -> String {
+    let mut content = format!(
+        "## What's New in Google ADK: {}\n\n**Release notes:** [{}]({})",
+        version,
+        release_notes_url,
+        release_notes_url
+    );
+    if changes.is_empty() {
+        content.push_str(&format!(
+            "\n\nNo changelog data has been ingested for version {}. Check the release notes above before upgrading.",
+            version
+        ));
+        return content;
+    }
+    
+    let mut current_kind = None;
+    for change in changes {
+        if current_kind != Some(change.kind) {
+            current_kind = Some(change.kind);
+            content.push_str(&format!("\n\n### {}\n", change.kind.heading()));
+        }
+        let description = if change.description.is_empty() {
+            String::new()
+        } else {
+            format!(" - {}", change.description)
+        };
+        let link = change.link
+            .as_ref()
+            .map(|url| format!(" ([details]({}))", url))
+            .unwrap_or_default();
+        content.push_str(&format!("\n- **{}**{}{}", change.title, description, link));
+    }
+    content
+}
+
+/// Format a line stating whether guidance is official or community-sourced
+pub fn format_source_note(provenance: Option<&Provenance>) -> String {
+    match provenance.filter(|p| !p.is_official()) {
+        None => "**Source:** Official Google ADK documentation".to_string(),
+        Some(provenance) => format!(
+            "**Source:** {} knowledge pack '{}'{} - not part of the official Google ADK documentation, verify before relying on it",
+            provenance.trust_level.label(),
+            provenance.source_name,
+            provenance.url.as_ref().map(|url| format!(" ({})", url)).unwrap_or_default()
+        ),
+    }
+}
+
+/// Format a line giving an entry's source page, when known, and when it was last updated
+pub fn format_freshness_note(source_url: Option<&str>, last_updated: Option<i64>) -> String {
+    let updated = match last_updated {
+        Some(timestamp) => format_unix_time(timestamp),
+        None => "not recorded (bundled with the server)".to_string(),
+    };
+    match source_url {
+        Some(url) => format!("**Source URL:** [{}]({}) · **Last updated:** {}", url, url, updated),
+        None => format!("**Last updated:** {}", updated),
+    }
+}
+
+/// Format upstream server answers as a section below the local response, attributing each one
+pub fn format_upstream_sections(sections: &[UpstreamSection]) -> String {
+    let sections_text = sections
+        .iter()
+        .map(|section| {
+            let title = section.title.clone().unwrap_or_else(|| format!("Result from {}", section.source));
+            format!(
+                "### {}\n\n**Source:** upstream MCP server '{}'{} - not part of this server's ADK knowledge base\n\n{}",
+                title,
+                section.source,
+                section.url.as_ref().map(|url| format!(" ([{}]({}))", url, url)).unwrap_or_default(),
+                section.text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n");
+    
+    format!("## Upstream Documentation\n\n{}", sections_text)
+}
+
+/// Short inline tag marking community-sourced entries in compact listings
+pub fn format_source_tag(provenance: Option<&Provenance>) -> String {
+    match provenance.filter(|p| !p.is_official()) {
+        None => String::new(),
+        Some(provenance) => format!(" [{}: {}]", provenance.trust_level.label(), provenance.source_name),
+    }
+}
+
+/// Format a concept's description, examples, and related concepts
+fn format_concept_details(concept: &ConceptInfo) -> String {
     let examples_text = if concept.examples.is_empty() {
         String::new()
     } else {
@@ -103,24 +796,386 @@ pub fn format_concept_response(
         )
     };
     
-    format!(
-        "## {}\n\n\
-        **Version:** {}\n\n\
-        {}{}{}\n\n\
-        ### Official References:\n{}\n\n\
-        *Information based on official Google ADK documentation (version: {})*",
-        concept.name,
-        version,
-        concept.description,
-        examples_text,
-        related_text,
-        concept.documentation_refs
-            .iter()
-            .map(|url| format!("- [{}]({})", extract_url_title(url), url))
-            .collect::<Vec<_>>()
-            .join("\n"),
-        version
-    )
+    format!("{}{}{}", concept.description, examples_text, related_text)
+}
+
+/// Format URLs as a markdown link list
+fn format_reference_links(urls: &[String]) -> String {
+    urls.iter()
+        .map(|url| format!("- [{}]({})", extract_url_title(url), url))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format a link to a cited documentation section, titled with its heading path
+pub fn format_citation_link(citation: &Citation) -> String {
+    format!("[{}]({})", citation.title(), citation.deep_link())
+}
+
+/// Format reference links, leading with the cited section and skipping its page
+fn format_cited_reference_links(citation: Option<&Citation>, urls: &[String]) -> String {
+    let Some(citation) = citation else {
+        return format_reference_links(urls);
+    };
+    
+    let page_links = urls
+        .iter()
+        .filter(|url| url.as_str() != citation.url)
+        .cloned()
+        .collect::<Vec<_>>();
+    let mut links = format!("- {}", format_citation_link(citation));
+    if !page_links.is_empty() {
+        links.push('\n');
+        links.push_str(&format_reference_links(&page_links));
+    }
+    links
+}
+
+/// Convert a 0.0-1.0 relevance score to a whole percentage
+fn relevance_percent(score: f32) -> u8 {
+    (score.clamp(0.0, 1.0) * 100.0).round() as u8
+}
+
+/// Shorten text to its first sentence for compact listings
+pub fn summarize(text: &str) -> String {
+    match text.find(". ") {
+        Some(end) => text[..=end].to_string(),
+        None => text.to_string(),
+    }
+}
+
+/// Output format of expert tool responses
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Markdown text (the native response format)
+    #[default]
+    Markdown,
+    /// Plain text without markdown syntax
+    Plain,
+    /// JSON document with the title, fields, sections, and references
+    Json,
+}
+
+impl OutputFormat {
+    /// Parse a format name ("markdown", "plain", or "json"), ignoring case
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "markdown" | "md" => Some(Self::Markdown),
+            "plain" | "text" => Some(Self::Plain),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Caller choices for rendering an expert tool response
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Language of headings and labels
+    pub locale: Locale,
+    /// How much of the response to keep
+    pub detail: ResponseDetail,
+    /// Maximum length in characters of markdown or plain output
+    pub max_length: Option<usize>,
+    /// Output format
+    pub format: OutputFormat,
+}
+
+impl RenderOptions {
+    /// Render an English markdown response for the caller
+    ///
+    /// The response is condensed to the requested detail and length, localized, then
+    /// converted to the output format. JSON output carries `data`, the structured result
+    /// behind the response, unless the response was condensed; it is never truncated.
+    pub fn render(&self, markdown: &str, data: Option<serde_json::Value>) -> String {
+        let condensed = self.detail == ResponseDetail::Summary
+            || self.max_length.is_some_and(|max| markdown.chars().count() > max);
+        let localized = localize(&fit_response(markdown, self.detail, self.max_length), self.locale);
+        
+        let rendered = match self.format {
+            OutputFormat::Markdown => localized,
+            OutputFormat::Plain => markdown_to_plain(&localized),
+            OutputFormat::Json => {
+                let data = data.filter(|_| !condensed);
+                return serde_json::to_string_pretty(&markdown_to_json(&localized, data)).unwrap_or(localized);
+            }
+        };
+        match self.max_length {
+            Some(max) => truncate_chars(&rendered, max),
+            None => rendered,
+        }
+    }
+}
+
+/// Strip markdown syntax, keeping headings, list items, links, and code as readable text
+pub fn markdown_to_plain(markdown: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut in_code = false;
+    
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            lines.push(format!("    {}", line));
+            continue;
+        }
+        
+        let trimmed = line.trim();
+        if trimmed.chars().all(|c| c == '-') && trimmed.len() >= 3 {
+            lines.push(String::new());
+            continue;
+        }
+        
+        let text = trimmed.trim_start_matches('#').trim_start();
+        let text = if text.len() > 2 && text.starts_with('*') && text.ends_with('*') && !text.starts_with("**") {
+            &text[1..text.len() - 1]
+        } else {
+            text
+        };
+        lines.push(plain_links(&text.replace("**", "").replace('`', "")));
+    }
+    
+    lines.join("\n")
+}
+
+/// Structure a markdown response as a JSON document
+///
+/// Leading `**Label:** value` lines become fields, each later heading starts a section
+/// holding its markdown content, and every distinct web link is listed as a reference.
+pub fn markdown_to_json(markdown: &str, data: Option<serde_json::Value>) -> serde_json::Value {
+    let mut title = String::new();
+    let mut fields = serde_json::Map::new();
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    let mut in_code = false;
+    
+    for line in markdown.lines() {
+        let is_fence = line.trim_start().starts_with("```");
+        let is_heading = !in_code && line.starts_with('#');
+        if is_fence {
+            in_code = !in_code;
+        }
+        
+        if is_heading {
+            let heading = line.trim_start_matches('#').trim().trim_end_matches(':').to_string();
+            if title.is_empty() && sections.is_empty() {
+                title = heading;
+            } else {
+                sections.push((heading, Vec::new()));
+            }
+            continue;
+        }
+        
+        match sections.last_mut() {
+            Some((_, content)) => content.push(line),
+            None => {
+                if let Some((label, value)) = line.strip_prefix("**").and_then(|rest| rest.split_once(":**")) {
+                    fields.insert(label.to_string(), serde_json::Value::String(value.trim().to_string()));
+                }
+            }
+        }
+    }
+    
+    let sections: Vec<serde_json::Value> = sections
+        .into_iter()
+        .map(|(heading, content)| serde_json::json!({
+            "heading": heading,
+            "content": section_content(&content),
+        }))
+        .collect();
+    let references: Vec<serde_json::Value> = markdown_links(markdown)
+        .into_iter()
+        .map(|(title, url)| serde_json::json!({ "title": title, "url": url }))
+        .collect();
+    
+    let mut document = serde_json::json!({
+        "title": title,
+        "fields": fields,
+        "sections": sections,
+        "references": references,
+    });
+    if let Some(data) = data {
+        document["result"] = data;
+    }
+    document
+}
+
+/// Join a section's lines, dropping surrounding blank lines and trailing `---` separators
+fn section_content(lines: &[&str]) -> String {
+    let mut content = lines.join("\n");
+    while let Some(stripped) = content.trim_end().strip_suffix("---") {
+        content = stripped.to_string();
+    }
+    content.trim().to_string()
+}
+
+/// Distinct `[title](url)` web links in document order
+fn markdown_links(markdown: &str) -> Vec<(String, String)> {
+    let mut links: Vec<(String, String)> = Vec::new();
+    let mut rest = markdown;
+    
+    while let Some(start) = rest.find("](http") {
+        let url_rest = &rest[start + 2..];
+        let Some(end) = url_rest.find(')') else { break };
+        let url = &url_rest[..end];
+        if let Some(title_start) = rest[..start].rfind('[') {
+            if !links.iter().any(|(_, existing)| existing == url) {
+                links.push((rest[title_start + 1..start].to_string(), url.to_string()));
+            }
+        }
+        rest = &url_rest[end..];
+    }
+    
+    links
+}
+
+/// Rewrite `[title](url)` links as "title (url)", or just the URL when the title repeats it
+fn plain_links(line: &str) -> String {
+    let mut plain = String::new();
+    let mut rest = line;
+    
+    while let Some(start) = rest.find("](") {
+        let Some(title_start) = rest[..start].rfind('[') else { break };
+        let Some(end) = rest[start + 2..].find(')') else { break };
+        let title = &rest[title_start + 1..start];
+        let url = &rest[start + 2..start + 2 + end];
+        
+        plain.push_str(&rest[..title_start]);
+        if title == url {
+            plain.push_str(url);
+        } else {
+            plain.push_str(&format!("{} ({})", title, url));
+        }
+        rest = &rest[start + 3 + end..];
+    }
+    
+    plain.push_str(rest);
+    plain
+}
+
+/// How much of a response to render
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResponseDetail {
+    /// The complete markdown response
+    #[default]
+    Full,
+    /// Headline answer and top references only
+    Summary,
+}
+
+impl ResponseDetail {
+    /// Parse a detail level name ("full" or "summary"), ignoring case
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "full" => Some(Self::Full),
+            "summary" => Some(Self::Summary),
+            _ => None,
+        }
+    }
+}
+
+/// Number of reference links kept in summarized responses
+pub const SUMMARY_REFERENCE_LIMIT: usize = 3;
+
+/// Render a response at the requested detail, staying within `max_length` characters
+///
+/// A full response longer than `max_length` falls back to the summary. A summary that is
+/// still too long first drops its leading fields, shortens the headline to its first
+/// sentence, and drops references, and is truncated only as a last resort.
+pub fn fit_response(markdown: &str, detail: ResponseDetail, max_length: Option<usize>) -> String {
+    let fits = |text: &str| max_length.is_none_or(|max| text.chars().count() <= max);
+    if detail == ResponseDetail::Full && fits(markdown) {
+        return markdown.to_string();
+    }
+    
+    let summary = condense_response(markdown, SUMMARY_REFERENCE_LIMIT, false);
+    let Some(max) = max_length.filter(|_| !fits(&summary)) else {
+        return summary;
+    };
+    
+    let shorter = [(SUMMARY_REFERENCE_LIMIT, true), (1, true), (0, true)];
+    shorter
+        .iter()
+        .map(|&(references, brief)| condense_response(markdown, references, brief))
+        .find(|candidate| fits(candidate))
+        .unwrap_or_else(|| truncate_chars(&condense_response(markdown, 0, true), max))
+}
+
+/// Condense a markdown response to its title, leading fields, headline paragraph, and top references
+pub fn summarize_response(markdown: &str) -> String {
+    condense_response(markdown, SUMMARY_REFERENCE_LIMIT, false)
+}
+
+/// Condense a response keeping `references` links; `brief` drops leading fields and keeps the headline's first sentence
+fn condense_response(markdown: &str, references: usize, brief: bool) -> String {
+    let mut title: Option<&str> = None;
+    let mut fields: Vec<&str> = Vec::new();
+    let mut headline: Option<&str> = None;
+    let mut in_code = false;
+    
+    for paragraph in markdown.split("\n\n").map(str::trim) {
+        let starts_in_code = in_code;
+        if paragraph.matches("```").count() % 2 == 1 {
+            in_code = !in_code;
+        }
+        if starts_in_code || paragraph.starts_with("```") || paragraph.is_empty() {
+            continue;
+        }
+        
+        if paragraph.starts_with('#') {
+            title = title.or(paragraph.lines().next());
+        } else if paragraph.lines().all(|line| line.starts_with("**") && line.contains(":**")) {
+            if headline.is_none() {
+                fields.extend(paragraph.lines());
+            }
+        } else if headline.is_none() && !is_markdown_scaffolding(paragraph) {
+            headline = Some(paragraph);
+        }
+    }
+    
+    let mut sections: Vec<String> = Vec::new();
+    sections.extend(title.map(str::to_string));
+    if !fields.is_empty() && !brief {
+        sections.push(fields.join("\n"));
+    }
+    sections.extend(headline.map(|text| if brief { summarize(text) } else { text.to_string() }));
+    
+    let references = extract_reference_links(markdown, references);
+    if !references.is_empty() {
+        sections.push(format!("### Official References:\n{}", references.iter().map(|link| format!("- {}", link)).collect::<Vec<_>>().join("\n")));
+    }
+    sections.push("*Summarized response; request full detail for the complete answer.*".to_string());
+    
+    sections.join("\n\n")
+}
+
+/// Whether a paragraph is a list, table, rule, or italic note rather than prose
+fn is_markdown_scaffolding(paragraph: &str) -> bool {
+    paragraph.starts_with("- ")
+        || paragraph.starts_with('|')
+        || paragraph.starts_with("---")
+        || (paragraph.starts_with('*') && !paragraph.starts_with("**"))
+        || (paragraph.chars().next().is_some_and(|c| c.is_ascii_digit()) && paragraph.contains(". "))
+}
+
+/// Collect up to `limit` distinct markdown links to web pages, in document order
+fn extract_reference_links(markdown: &str, limit: usize) -> Vec<String> {
+    markdown_links(markdown)
+        .into_iter()
+        .take(limit)
+        .map(|(title, url)| format!("[{}]({})", title, url))
+        .collect()
+}
+
+/// Cut text to at most `max` characters, ending with an ellipsis when shortened
+pub fn truncate_chars(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
 }
 
 /// Extract a readable title from URL for display
@@ -149,19 +1204,18 @@ pub fn generate_comprehensive_links(
     let mut links = Vec::new();
     
     // Always include quickstart
-    if let Some(urls) = knowledge_base.get_official_urls(Some(version)) {
-        links.push(urls.quickstart.clone());
-        
-        // Add relevant category links based on query content
-        if query.to_lowercase().contains("api") {
-            links.extend(urls.api_reference.clone());
-        }
-        if query.to_lowercase().contains("tutorial") || query.to_lowercase().contains("guide") {
-            links.extend(urls.tutorials.clone());
-        }
-        if query.to_lowercase().contains("best") || query.to_lowercase().contains("practice") {
-            links.extend(urls.best_practices.clone());
-        }
+    let urls = knowledge_base.get_official_urls(Some(version));
+    links.push(urls.quickstart.clone());
+    
+    // Add relevant category links based on query content
+    if query.to_lowercase().contains("api") {
+        links.extend(urls.api_reference.clone());
+    }
+    if query.to_lowercase().contains("tutorial") || query.to_lowercase().contains("guide") {
+        links.extend(urls.tutorials.clone());
+    }
+    if query.to_lowercase().contains("best") || query.to_lowercase().contains("practice") {
+        links.extend(urls.best_practices.clone());
     }
     
     // Remove duplicates while preserving order
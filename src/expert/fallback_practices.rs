@@ -0,0 +1,218 @@
+//! Curated fallback guidance for best-practice requests
+//!
+//! A scenario the knowledge base has nothing on, or a version whose documentation is
+//! sparse, would otherwise get an empty answer. Instead `get_best_practices` falls back
+//! to a small curated set of general ADK practices: those of the scenario families the
+//! scenario belongs to (sessions, deployment, testing, ...), or a core set covering
+//! architecture, tools, sessions, deployment, and evaluation when it belongs to none.
+//! Results drawn from this set are marked as general guidance.
+
+use crate::expert::adk_knowledge::BestPractice;
+use crate::expert::relevance::tokenize;
+
+/// Categories of the practices returned when a scenario belongs to no family
+const CORE_CATEGORIES: &[&str] = &["architecture", "tools", "sessions", "deployment", "evaluation"];
+
+/// Scenario families: the fallback category answering each and the words identifying it
+const SCENARIO_FAMILIES: &[(&str, &[&str])] = &[
+    ("architecture", &["architecture", "design", "structure", "layout", "project", "orchestration", "coordinator", "workflow", "pipeline", "multi"]),
+    ("tools", &["tool", "function", "api", "integration", "mcp", "plugin"]),
+    ("sessions", &["session", "state", "memory", "conversation", "context", "history"]),
+    ("reliability", &["error", "failure", "exception", "retry", "timeout", "recovery", "resilience", "reliability", "quota"]),
+    ("deployment", &["deploy", "deployment", "production", "cloud", "gke", "container", "hosting", "scale", "scaling"]),
+    ("security", &["security", "secret", "credential", "auth", "authentication", "permission", "iam", "oauth"]),
+    ("safety", &["safety", "guardrail", "moderation", "harmful", "injection", "policy"]),
+    ("performance", &["performance", "latency", "speed", "fast", "slow", "cost", "throughput", "parallel", "streaming"]),
+    ("evaluation", &["test", "testing", "evaluation", "eval", "quality", "regression", "ci", "debug", "observability", "tracing", "logging"]),
+];
+
+/// General practices for a scenario that nothing in the knowledge base matched
+///
+/// A `category` with curated practices selects them; otherwise the scenario's families
+/// do, and a scenario outside every family gets the core set.
+pub fn fallback_practices(scenario: &str, category: Option<&str>) -> Vec<BestPractice> {
+    let practices = curated_practices();
+    if let Some(category) = category {
+        let matching: Vec<BestPractice> = practices
+            .iter()
+            .filter(|practice| practice.category.eq_ignore_ascii_case(category.trim()))
+            .cloned()
+            .collect();
+        if !matching.is_empty() {
+            return matching;
+        }
+    }
+    
+    let terms = tokenize(scenario);
+    let families: Vec<&str> = SCENARIO_FAMILIES
+        .iter()
+        .filter(|(_, keywords)| keywords.iter().flat_map(|keyword| tokenize(keyword)).any(|keyword| terms.contains(&keyword)))
+        .map(|(category, _)| *category)
+        .collect();
+    let categories = if families.is_empty() { CORE_CATEGORIES } else { families.as_slice() };
+    
+    practices
+        .into_iter()
+        .filter(|practice| categories.contains(&practice.category.as_str()))
+        .collect()
+}
+
+/// The curated fallback practices, two per category
+fn curated_practices() -> Vec<BestPractice> {
+    let practice = |title: &str, description: &str, category: &str, example: &str, documentation_ref: &str| BestPractice {
+        title: title.to_string(),
+        description: description.to_string(),
+        category: category.to_string(),
+        examples: vec![example.to_string()],
+        documentation_ref: documentation_ref.to_string(),
+        source_url: None,
+        last_updated: None,
+    };
+    
+    vec![
+        practice(
+            "Start from a Single Root Agent",
+            "Expose one root_agent per app from agent.py and add sub-agents only when a task clearly splits into \
+                specialties; each agent should have a focused instruction and a description the root uses to delegate.",
+            "architecture",
+            "A root agent delegating to billing and shipping sub-agents by their descriptions",
+            "https://google.github.io/adk-docs/agents/multi-agents/",
+        ),
+        practice(
+            "Use Workflow Agents for Fixed Control Flow",
+            "When the order of steps is known in advance, compose agents with SequentialAgent, ParallelAgent, or \
+                LoopAgent instead of asking a model to decide what runs next.",
+            "architecture",
+            "SequentialAgent(sub_agents=[drafter, reviewer]) passing the draft through output_key",
+            "https://google.github.io/adk-docs/agents/workflow-agents/",
+        ),
+        practice(
+            "Write Tools as Small, Documented Functions",
+            "Give each tool one job, typed parameters, and a docstring explaining when to use it; ADK builds the schema \
+                the model sees from the signature and docstring.",
+            "tools",
+            "def get_order_status(order_id: str) -> dict with a docstring naming its inputs and result",
+            "https://google.github.io/adk-docs/tools/function-tools/",
+        ),
+        practice(
+            "Return Structured Results from Tools",
+            "Return a dict with a status and either the result or an error message, so the model can recover from \
+                failures instead of the turn ending with an exception.",
+            "tools",
+            "{\"status\": \"error\", \"error_message\": \"Order not found\"}",
+            "https://google.github.io/adk-docs/tools/function-tools/",
+        ),
+        practice(
+            "Persist Sessions Outside Local Development",
+            "InMemorySessionService loses every conversation on restart; use DatabaseSessionService or \
+                VertexAiSessionService wherever sessions must survive a deployment.",
+            "sessions",
+            "Runner(agent=root_agent, app_name=\"support\", session_service=DatabaseSessionService(db_url=...))",
+            "https://google.github.io/adk-docs/sessions/session/",
+        ),
+        practice(
+            "Keep Session State Small and Scoped",
+            "Store only what later turns need, use the user:, app:, and temp: prefixes to set each key's lifetime, \
+                and move long-term recall to a MemoryService.",
+            "sessions",
+            "tool_context.state[\"user:preferred_language\"] = \"en\"",
+            "https://google.github.io/adk-docs/sessions/state/",
+        ),
+        practice(
+            "Handle Failures Where They Happen",
+            "Catch errors inside tools and callbacks and turn them into results the agent can act on; an uncaught \
+                exception aborts the whole invocation.",
+            "reliability",
+            "A tool returning {\"status\": \"error\"} when a downstream API times out",
+            "https://google.github.io/adk-docs/tools/function-tools/",
+        ),
+        practice(
+            "Retry Transient Model and API Errors with Backoff",
+            "Quota and availability errors are usually transient; retry them with exponential backoff and a bounded \
+                number of attempts rather than failing the request or retrying in a tight loop.",
+            "reliability",
+            "Retrying a 429 RESOURCE_EXHAUSTED response after 1, 2, and 4 seconds",
+            "https://google.github.io/adk-docs/agents/models/",
+        ),
+        practice(
+            "Deploy to a Managed Runtime",
+            "Use Vertex AI Agent Engine for a fully managed runtime, or Cloud Run for a container with minimal \
+                operations, and pin dependency versions so production matches what was tested.",
+            "deployment",
+            "adk deploy cloud_run --project $PROJECT --region us-central1 ./support_agent",
+            "https://google.github.io/adk-docs/deploy/",
+        ),
+        practice(
+            "Configure Agents Through the Environment",
+            "Read model names, project ids, and credentials from environment variables or Secret Manager, so the \
+                same code runs locally and in every deployment.",
+            "deployment",
+            "model=os.environ.get(\"AGENT_MODEL\", \"gemini-2.0-flash\")",
+            "https://google.github.io/adk-docs/deploy/",
+        ),
+        practice(
+            "Keep Secrets Out of Agent Code",
+            "Never write API keys or passwords in source, instructions, or tool descriptions; load them at startup \
+                and rotate any secret that was committed.",
+            "security",
+            "api_key = os.environ[\"WEATHER_API_KEY\"]",
+            "https://google.github.io/adk-docs/safety/",
+        ),
+        practice(
+            "Give Agents Least-Privilege Credentials",
+            "Run tools with a service account or the end user's OAuth identity scoped to exactly what they need, and \
+                require authentication on every exposed agent endpoint.",
+            "security",
+            "A Cloud Run service account granted read-only access to one BigQuery dataset",
+            "https://google.github.io/adk-docs/safety/",
+        ),
+        practice(
+            "Screen Inputs and Tool Calls with Callbacks",
+            "Use before_model_callback and before_tool_callback to block unsafe requests and validate tool arguments \
+                before anything runs.",
+            "safety",
+            "A before_tool_callback rejecting refunds above a policy limit",
+            "https://google.github.io/adk-docs/safety/",
+        ),
+        practice(
+            "Treat Retrieved Content as Untrusted",
+            "Text from users, documents, and tool results can carry injected instructions; pass it as data, keep \
+                instructions static, and confirm consequential actions.",
+            "safety",
+            "Quoting a retrieved web page as a tool result instead of appending it to the instruction",
+            "https://google.github.io/adk-docs/safety/",
+        ),
+        practice(
+            "Stream Responses to Interactive Users",
+            "Run interactive agents with streaming so users see partial output immediately instead of waiting for \
+                the whole response.",
+            "performance",
+            "RunConfig(streaming_mode=StreamingMode.SSE)",
+            "https://google.github.io/adk-docs/runtime/runconfig/",
+        ),
+        practice(
+            "Run Independent Work in Parallel",
+            "Put steps that do not depend on each other in a ParallelAgent, and use fast models for routing and \
+                simple steps, so latency follows the slowest step rather than the sum of all of them.",
+            "performance",
+            "ParallelAgent(sub_agents=[flight_search, hotel_search])",
+            "https://google.github.io/adk-docs/agents/workflow-agents/parallel-agents/",
+        ),
+        practice(
+            "Evaluate Agents Against Eval Sets",
+            "Capture representative conversations as eval cases and check both the final responses and the tools \
+                the agent called, so changes to prompts, models, or tools cannot silently regress behavior.",
+            "evaluation",
+            "adk eval support_agent support_agent/evals/checkout.evalset.json",
+            "https://google.github.io/adk-docs/evaluate/",
+        ),
+        practice(
+            "Trace and Log Every Invocation",
+            "Enable tracing and structured logging so each model call and tool call can be inspected when an agent \
+                misbehaves in development or production.",
+            "evaluation",
+            "adk web --trace_to_cloud while reproducing a failing conversation",
+            "https://google.github.io/adk-docs/evaluate/",
+        ),
+    ]
+}
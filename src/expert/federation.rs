@@ -0,0 +1,394 @@
+//! Federated queries to upstream MCP documentation servers
+//!
+//! Operators can list other MCP servers, such as a general documentation server, in the
+//! JSON file named by `ADK_UPSTREAM_SERVERS_FILE`. `adk_query` then calls a search tool on
+//! every upstream concurrently and appends their answers below the local knowledge base
+//! response. Sections repeating the local answer or another upstream are dropped, and each
+//! remaining section names the server it came from. A slow or failing upstream is logged
+//! and skipped, so local answers are never held back by federation.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use rmcp::ServiceExt;
+use rmcp::model::{CallToolRequestParam, CallToolResult};
+use rmcp::service::{RoleClient, RunningService};
+use rmcp::transport::{StreamableHttpClientTransport, TokioChildProcess};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tokio::task::JoinSet;
+use tracing::{debug, warn};
+use crate::expert::adk_knowledge::query_terms;
+use crate::expert::documentation::truncate_chars;
+use crate::utils::error::{ArkaftMcpError, ArkaftResult};
+
+/// Environment variable naming the JSON file of upstream documentation servers
+pub const UPSTREAM_SERVERS_FILE_ENV: &str = "ADK_UPSTREAM_SERVERS_FILE";
+
+/// Time allowed for an upstream to answer, including connecting to it
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Longest upstream section kept, in characters
+const MAX_SECTION_CHARS: usize = 4000;
+
+/// Share of terms two sections must have in common to count as duplicates
+const DUPLICATE_SIMILARITY: f32 = 0.8;
+
+/// Upstream servers listed in the federation config file
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FederationConfig {
+    /// Servers queried alongside the local knowledge base, in display order
+    #[serde(default)]
+    pub servers: Vec<UpstreamServerConfig>,
+}
+
+impl FederationConfig {
+    /// Read a federation config file
+    pub async fn read_from(path: &Path) -> ArkaftResult<Self> {
+        let content = tokio::fs::read_to_string(path).await?;
+        serde_json::from_str(&content).map_err(|e| {
+            ArkaftMcpError::Configuration(format!("Invalid upstream servers file {}: {}", path.display(), e))
+        })
+    }
+}
+
+/// Upstream MCP server and the tool that searches its documentation
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpstreamServerConfig {
+    /// Name shown when attributing sections to the server
+    pub name: String,
+    /// How to reach the server
+    #[serde(flatten)]
+    pub transport: UpstreamTransport,
+    /// Name of the search tool to call
+    pub tool: String,
+    /// Tool argument receiving the query text
+    #[serde(default = "default_query_argument")]
+    pub query_argument: String,
+    /// Extra arguments passed on every call, e.g. a library identifier
+    #[serde(default)]
+    pub arguments: serde_json::Map<String, serde_json::Value>,
+    /// Seconds to wait for an answer (defaults to 10)
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+}
+
+/// Transport used to reach an upstream server
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum UpstreamTransport {
+    /// Local server launched as a child process speaking MCP over stdio
+    Stdio {
+        /// Program to run
+        command: String,
+        /// Program arguments
+        #[serde(default)]
+        args: Vec<String>,
+    },
+    /// Remote server speaking streamable HTTP
+    Http {
+        /// Endpoint URL
+        url: String,
+    },
+}
+
+/// Default tool argument receiving the query text
+fn default_query_argument() -> String {
+    "query".to_string()
+}
+
+/// Piece of an upstream answer, attributed to the server it came from
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpstreamSection {
+    /// Name of the upstream server
+    pub source: String,
+    /// Leading markdown heading of the section, if any
+    pub title: Option<String>,
+    /// Section text
+    pub text: String,
+    /// First web link in the section, used to attribute it
+    pub url: Option<String>,
+}
+
+impl UpstreamSection {
+    /// Build a section from one block of tool output, or `None` when it is blank
+    pub fn from_text(source: &str, text: &str) -> Option<Self> {
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+        
+        let first_line = text.lines().next().unwrap_or_default();
+        let (title, body) = match first_line.strip_prefix('#') {
+            Some(heading) => (
+                Some(heading.trim_start_matches('#').trim().to_string()),
+                text[first_line.len()..].trim(),
+            ),
+            None => (None, text),
+        };
+        
+        Some(Self {
+            source: source.to_string(),
+            title,
+            text: truncate_chars(body, MAX_SECTION_CHARS),
+            url: first_web_link(body),
+        })
+    }
+}
+
+/// Documentation server queried alongside the local knowledge base
+#[async_trait]
+pub trait UpstreamDocs: Send + Sync {
+    /// Name used to attribute sections and in logs
+    fn name(&self) -> &str;
+    
+    /// Time allowed for a search before the upstream is skipped
+    fn timeout(&self) -> Duration {
+        DEFAULT_TIMEOUT
+    }
+    
+    /// Search the upstream's documentation
+    async fn search(&self, query: &str) -> ArkaftResult<Vec<UpstreamSection>>;
+}
+
+/// Upstream reached through an MCP client connection
+///
+/// The connection is opened on first use and reopened after a failed call.
+pub struct McpUpstream {
+    /// Server configuration
+    config: UpstreamServerConfig,
+    /// Open client connection, if any
+    client: Mutex<Option<RunningService<RoleClient, ()>>>,
+}
+
+impl McpUpstream {
+    /// Create an upstream for a configured server without connecting yet
+    pub fn new(config: UpstreamServerConfig) -> Self {
+        Self {
+            config,
+            client: Mutex::new(None),
+        }
+    }
+    
+    /// Open a client connection to the server
+    async fn connect(&self) -> ArkaftResult<RunningService<RoleClient, ()>> {
+        let connection_error = |e: &dyn std::fmt::Display| {
+            ArkaftMcpError::McpProtocol(format!("Failed to connect to upstream '{}': {}", self.config.name, e))
+        };
+        
+        match &self.config.transport {
+            UpstreamTransport::Stdio { command, args } => {
+                let mut command = tokio::process::Command::new(command);
+                command.args(args);
+                let (transport, _) = TokioChildProcess::builder(command)
+                    .stderr(Stdio::null())
+                    .spawn()
+                    .map_err(|e| connection_error(&e))?;
+                ().serve(transport).await.map_err(|e| connection_error(&e))
+            }
+            UpstreamTransport::Http { url } => {
+                let transport = StreamableHttpClientTransport::from_uri(url.as_str());
+                ().serve(transport).await.map_err(|e| connection_error(&e))
+            }
+        }
+    }
+    
+    /// Arguments for a search tool call
+    fn arguments(&self, query: &str) -> serde_json::Map<String, serde_json::Value> {
+        let mut arguments = self.config.arguments.clone();
+        arguments.insert(self.config.query_argument.clone(), serde_json::Value::String(query.to_string()));
+        arguments
+    }
+}
+
+#[async_trait]
+impl UpstreamDocs for McpUpstream {
+    fn name(&self) -> &str {
+        &self.config.name
+    }
+    
+    fn timeout(&self) -> Duration {
+        self.config.timeout_secs.map(Duration::from_secs).unwrap_or(DEFAULT_TIMEOUT)
+    }
+    
+    async fn search(&self, query: &str) -> ArkaftResult<Vec<UpstreamSection>> {
+        let mut client = self.client.lock().await;
+        if client.is_none() {
+            *client = Some(self.connect().await?);
+        }
+        let Some(service) = client.as_ref() else {
+            return Ok(Vec::new());
+        };
+        
+        let request = CallToolRequestParam {
+            name: self.config.tool.clone().into(),
+            arguments: Some(self.arguments(query)),
+        };
+        let result = match service.call_tool(request).await {
+            Ok(result) => result,
+            Err(e) => {
+                // Drop the connection so the next search reconnects
+                *client = None;
+                return Err(ArkaftMcpError::McpProtocol(format!(
+                    "Upstream '{}' failed to run '{}': {}",
+                    self.config.name, self.config.tool, e
+                )));
+            }
+        };
+        
+        sections_from_result(&self.config.name, &self.config.tool, result)
+    }
+}
+
+/// Turn a tool result into sections, one per text block
+fn sections_from_result(source: &str, tool: &str, result: CallToolResult) -> ArkaftResult<Vec<UpstreamSection>> {
+    let texts: Vec<&str> = result.content
+        .iter()
+        .filter_map(|content| content.as_text().map(|text| text.text.as_str()))
+        .collect();
+    
+    if result.is_error == Some(true) {
+        return Err(ArkaftMcpError::McpProtocol(format!(
+            "Upstream '{}' reported an error from '{}': {}",
+            source,
+            tool,
+            texts.join(" ")
+        )));
+    }
+    
+    Ok(texts.into_iter().filter_map(|text| UpstreamSection::from_text(source, text)).collect())
+}
+
+/// Upstream documentation servers queried alongside the local knowledge base
+#[derive(Clone, Default)]
+pub struct Federation {
+    /// Upstreams in display order
+    upstreams: Vec<Arc<dyn UpstreamDocs>>,
+}
+
+impl Federation {
+    /// Federate over the given upstreams
+    pub fn new(upstreams: Vec<Arc<dyn UpstreamDocs>>) -> Self {
+        Self { upstreams }
+    }
+    
+    /// Federate over the servers listed in a config file
+    pub fn from_config(config: FederationConfig) -> Self {
+        Self::new(
+            config.servers
+                .into_iter()
+                .map(|server| Arc::new(McpUpstream::new(server)) as Arc<dyn UpstreamDocs>)
+                .collect(),
+        )
+    }
+    
+    /// Whether no upstream is configured
+    pub fn is_empty(&self) -> bool {
+        self.upstreams.is_empty()
+    }
+    
+    /// Names of the configured upstreams
+    pub fn upstream_names(&self) -> Vec<&str> {
+        self.upstreams.iter().map(|upstream| upstream.name()).collect()
+    }
+    
+    /// Search every upstream concurrently, keeping sections in upstream order
+    ///
+    /// Upstreams that fail or exceed their timeout are logged and contribute nothing.
+    pub async fn search(&self, query: &str) -> Vec<UpstreamSection> {
+        let mut searches = JoinSet::new();
+        for (index, upstream) in self.upstreams.iter().enumerate() {
+            let upstream = Arc::clone(upstream);
+            let query = query.to_string();
+            searches.spawn(async move {
+                let result = tokio::time::timeout(upstream.timeout(), upstream.search(&query)).await;
+                (index, upstream.name().to_string(), result)
+            });
+        }
+        
+        let mut answers: Vec<(usize, Vec<UpstreamSection>)> = Vec::new();
+        while let Some(joined) = searches.join_next().await {
+            match joined {
+                Ok((index, name, Ok(Ok(sections)))) => {
+                    debug!("Upstream '{}' returned {} section(s)", name, sections.len());
+                    answers.push((index, sections));
+                }
+                Ok((_, name, Ok(Err(e)))) => warn!("Skipping upstream '{}': {}", name, e),
+                Ok((_, name, Err(_))) => warn!("Skipping upstream '{}': timed out", name),
+                Err(e) => warn!("Upstream search task failed: {}", e),
+            }
+        }
+        
+        answers.sort_by_key(|(index, _)| *index);
+        answers.into_iter().flat_map(|(_, sections)| sections).collect()
+    }
+    
+    /// Search every upstream and keep only sections that add to the local response
+    pub async fn federate(&self, query: &str, local_response: &str) -> Vec<UpstreamSection> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        dedupe_sections(local_response, self.search(query).await)
+    }
+}
+
+/// Drop sections repeating the local response or an earlier section
+///
+/// A section is a duplicate when its text appears in the local response, or when it
+/// shares most of its terms with a local paragraph or a section already kept.
+pub fn dedupe_sections(local_response: &str, sections: Vec<UpstreamSection>) -> Vec<UpstreamSection> {
+    let local_text = normalize_text(local_response);
+    let mut seen: Vec<HashSet<String>> = local_response
+        .split("\n\n")
+        .map(|paragraph| query_terms(paragraph).into_iter().collect::<HashSet<_>>())
+        .filter(|terms| !terms.is_empty())
+        .collect();
+    
+    let mut kept = Vec::new();
+    for section in sections {
+        let text = normalize_text(&section.text);
+        if text.is_empty() || local_text.contains(&text) {
+            continue;
+        }
+        
+        let terms: HashSet<String> = query_terms(&section.text).into_iter().collect();
+        if seen.iter().any(|other| term_similarity(&terms, other) >= DUPLICATE_SIMILARITY) {
+            continue;
+        }
+        
+        seen.push(terms);
+        kept.push(section);
+    }
+    kept
+}
+
+/// Lowercase words separated by single spaces, ignoring punctuation and markdown
+fn normalize_text(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Jaccard similarity of two term sets
+fn term_similarity(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
+/// First `http(s)` URL in markdown text, from a link or bare
+fn first_web_link(text: &str) -> Option<String> {
+    let start = text.find("https://").or_else(|| text.find("http://"))?;
+    let url: String = text[start..]
+        .chars()
+        .take_while(|c| !c.is_whitespace() && !matches!(c, ')' | ']' | '>' | '"' | '\''))
+        .collect();
+    Some(url.trim_end_matches(['.', ',', ';']).to_string())
+}
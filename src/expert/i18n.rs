@@ -0,0 +1,158 @@
+//! Localized response scaffolding
+//!
+//! Responses are rendered in English and then localized: headings and field labels
+//! produced by the formatters are swapped for their translations. Knowledge base
+//! content (descriptions, examples, code) is left untouched, and unsupported
+//! languages fall back to English.
+
+/// Supported response languages
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Locale {
+    /// English (source language of all responses)
+    #[default]
+    English,
+    /// Brazilian Portuguese
+    PortugueseBrazil,
+    /// Spanish
+    Spanish,
+    /// Japanese
+    Japanese,
+}
+
+impl Locale {
+    /// Parse a BCP 47 language tag such as "pt-BR" or "ja", ignoring case and region where possible
+    pub fn parse(tag: &str) -> Option<Self> {
+        let tag = tag.trim().to_lowercase().replace('_', "-");
+        let language = tag.split('-').next().unwrap_or_default();
+        match language {
+            "en" => Some(Self::English),
+            "pt" => Some(Self::PortugueseBrazil),
+            "es" => Some(Self::Spanish),
+            "ja" => Some(Self::Japanese),
+            _ => None,
+        }
+    }
+    
+    /// Locale for an optional tag, falling back to English when absent or unsupported
+    pub fn from_tag(tag: Option<&str>) -> Self {
+        tag.and_then(Self::parse).unwrap_or_default()
+    }
+    
+    /// Canonical language tag
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::PortugueseBrazil => "pt-BR",
+            Self::Spanish => "es",
+            Self::Japanese => "ja",
+        }
+    }
+}
+
+/// Translation of one piece of scaffolding: English, Portuguese (Brazil), Spanish, Japanese
+type Translation = (&'static str, &'static str, &'static str, &'static str);
+
+/// Markdown headings, matched at the start of a line up to an optional trailing colon
+const HEADINGS: &[Translation] = &[
+    ("# Google ADK Best Practices", "# Boas Práticas do Google ADK", "# Buenas Prácticas de Google ADK", "# Google ADK ベストプラクティス"),
+    ("# Architecture Validation Result", "# Resultado da Validação de Arquitetura", "# Resultado de la Validación de Arquitectura", "# アーキテクチャ検証結果"),
+    ("# Architecture Validation Checklist", "# Checklist de Validação de Arquitetura", "# Lista de Verificación de Arquitectura", "# アーキテクチャ検証チェックリスト"),
+    ("## Inferred Architecture", "## Arquitetura Inferida", "## Arquitectura Inferida", "## 推定アーキテクチャ"),
+    ("## Google ADK Documentation Query", "## Consulta à Documentação do Google ADK", "## Consulta de Documentación de Google ADK", "## Google ADK ドキュメント検索"),
+    ("## What's New in Google ADK", "## Novidades do Google ADK", "## Novedades de Google ADK", "## Google ADK の新機能"),
+    ("## Google ADK Versions", "## Versões do Google ADK", "## Versiones de Google ADK", "## Google ADK バージョン"),
+    ("## Version Availability", "## Disponibilidade por Versão", "## Disponibilidad por Versión", "## バージョン別の対応状況"),
+    ("## Best Practice Categories", "## Categorias de Boas Práticas", "## Categorías de Buenas Prácticas", "## ベストプラクティスのカテゴリ"),
+    ("## Best Practices", "## Boas Práticas", "## Buenas Prácticas", "## ベストプラクティス"),
+    ("## Implementation Patterns", "## Padrões de Implementação", "## Patrones de Implementación", "## 実装パターン"),
+    ("## Upstream Documentation", "## Documentação de Outros Servidores", "## Documentación de Otros Servidores", "## 外部サーバーのドキュメント"),
+    ("## Official Documentation References", "## Referências da Documentação Oficial", "## Referencias de la Documentación Oficial", "## 公式ドキュメント参照"),
+    ("## Validation Findings", "## Resultados da Validação", "## Hallazgos de la Validación", "## 検証結果の指摘事項"),
+    ("## Non-Compliant Reasons", "## Motivos de Não Conformidade", "## Motivos de Incumplimiento", "## 非準拠の理由"),
+    ("## Positive Findings", "## Pontos Positivos", "## Hallazgos Positivos", "## 評価できる点"),
+    ("## Recommendations", "## Recomendações", "## Recomendaciones", "## 推奨事項"),
+    ("### Official References", "### Referências Oficiais", "### Referencias Oficiales", "### 公式リファレンス"),
+    ("### Suggested Follow-up Questions", "### Perguntas de Acompanhamento Sugeridas", "### Preguntas de Seguimiento Sugeridas", "### 次に尋ねるとよい質問"),
+    ("### Closest Related Topics", "### Tópicos Relacionados Mais Próximos", "### Temas Relacionados Más Cercanos", "### 関連性の高いトピック"),
+    ("### Supporting Passages", "### Trechos de Apoio", "### Pasajes de Apoyo", "### 根拠となる記述"),
+    ("### Related Concepts", "### Conceitos Relacionados", "### Conceptos Relacionados", "### 関連する概念"),
+    ("### Other Patterns", "### Outros Padrões", "### Otros Patrones", "### その他のパターン"),
+    ("### Other Matches", "### Outros Resultados", "### Otros Resultados", "### その他の一致"),
+    ("### Most Relevant", "### Mais Relevante", "### Más Relevante", "### 最も関連性の高い結果"),
+    ("### Breaking Changes", "### Mudanças Incompatíveis", "### Cambios Incompatibles", "### 破壊的変更"),
+    ("### New Features", "### Novos Recursos", "### Nuevas Funciones", "### 新機能"),
+    ("### Deprecations", "### Descontinuações", "### Obsolescencias", "### 非推奨"),
+    ("### Notable Fixes", "### Correções Relevantes", "### Correcciones Destacadas", "### 主な修正"),
+    ("### Examples", "### Exemplos", "### Ejemplos", "### 例"),
+];
+
+/// Bold field labels, replaced wherever they appear
+const LABELS: &[Translation] = &[
+    ("**Version:**", "**Versão:**", "**Versión:**", "**バージョン:**"),
+    ("**Query Type:**", "**Tipo de Consulta:**", "**Tipo de Consulta:**", "**クエリの種類:**"),
+    ("**Confidence:**", "**Confiança:**", "**Confianza:**", "**信頼度:**"),
+    ("**Source:**", "**Fonte:**", "**Fuente:**", "**出典:**"),
+    ("**Source URL:**", "**URL de Origem:**", "**URL de Origen:**", "**出典 URL:**"),
+    ("**Last updated:**", "**Última atualização:**", "**Última actualización:**", "**最終更新:**"),
+    ("**Migration Notes:**", "**Notas de Migração:**", "**Notas de Migración:**", "**移行メモ:**"),
+    ("**Introduced in:**", "**Introduzido em:**", "**Introducido en:**", "**導入バージョン:**"),
+    ("**Deprecated in:**", "**Descontinuado em:**", "**Obsoleto en:**", "**非推奨バージョン:**"),
+    ("**Minimum version:**", "**Versão mínima:**", "**Versión mínima:**", "**最小バージョン:**"),
+    ("**Release notes:**", "**Notas de versão:**", "**Notas de la versión:**", "**リリースノート:**"),
+    ("**Default version:**", "**Versão padrão:**", "**Versión predeterminada:**", "**デフォルトバージョン:**"),
+    ("**Examples:**", "**Exemplos:**", "**Ejemplos:**", "**例:**"),
+    ("**Code Examples:**", "**Exemplos de Código:**", "**Ejemplos de Código:**", "**コード例:**"),
+    ("**Use Cases:**", "**Casos de Uso:**", "**Casos de Uso:**", "**ユースケース:**"),
+    ("**Related Practices:**", "**Práticas Relacionadas:**", "**Prácticas Relacionadas:**", "**関連するプラクティス:**"),
+    ("**Reference:**", "**Referência:**", "**Referencia:**", "**参照:**"),
+    ("**Pattern:**", "**Padrão:**", "**Patrón:**", "**パターン:**"),
+    ("**Category:**", "**Categoria:**", "**Categoría:**", "**カテゴリ:**"),
+    ("**Scenario:**", "**Cenário:**", "**Escenario:**", "**シナリオ:**"),
+    ("**General guidance:**", "**Orientação geral:**", "**Orientación general:**", "**一般的なガイダンス:**"),
+    ("**Compliance Status:**", "**Status de Conformidade:**", "**Estado de Cumplimiento:**", "**準拠状況:**"),
+    ("**Compliance Score:**", "**Pontuação de Conformidade:**", "**Puntuación de Cumplimiento:**", "**準拠スコア:**"),
+    ("**Compliance Threshold:**", "**Limite de Conformidade:**", "**Umbral de Cumplimiento:**", "**準拠しきい値:**"),
+    ("**Location:**", "**Localização:**", "**Ubicación:**", "**場所:**"),
+    ("**Suggested Fix:**", "**Correção Sugerida:**", "**Corrección Sugerida:**", "**修正案:**"),
+    ("**Not addressed:**", "**Não abordado:**", "**No abordado:**", "**未対応:**"),
+    ("**Implementation Steps:**", "**Etapas de Implementação:**", "**Pasos de Implementación:**", "**実装手順:**"),
+    ("**More Recommendations:**", "**Mais Recomendações:**", "**Más Recomendaciones:**", "**その他の推奨事項:**"),
+    ("**Benefits:**", "**Benefícios:**", "**Beneficios:**", "**メリット:**"),
+    ("**Project Layout:**", "**Estrutura do Projeto:**", "**Estructura del Proyecto:**", "**プロジェクト構成:**"),
+    ("**Setup Steps:**", "**Etapas de Configuração:**", "**Pasos de Configuración:**", "**セットアップ手順:**"),
+];
+
+/// Render English response scaffolding in the requested locale
+pub fn localize(text: &str, locale: Locale) -> String {
+    if locale == Locale::English {
+        return text.to_string();
+    }
+    
+    let localized_lines = text
+        .split('\n')
+        .map(|line| localize_heading(line, locale).unwrap_or_else(|| line.to_string()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    
+    LABELS.iter().fold(localized_lines, |text, entry| {
+        text.replace(entry.0, translation(entry, locale))
+    })
+}
+
+/// Translate a heading line, keeping anything after the heading text
+fn localize_heading(line: &str, locale: Locale) -> Option<String> {
+    HEADINGS.iter().find_map(|entry| {
+        let rest = line.strip_prefix(entry.0)?;
+        (rest.is_empty() || rest.starts_with(':')).then(|| format!("{}{}", translation(entry, locale), rest))
+    })
+}
+
+/// Pick the translation for a locale
+fn translation(entry: &Translation, locale: Locale) -> &'static str {
+    match locale {
+        Locale::English => entry.0,
+        Locale::PortugueseBrazil => entry.1,
+        Locale::Spanish => entry.2,
+        Locale::Japanese => entry.3,
+    }
+}
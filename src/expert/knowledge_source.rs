@@ -0,0 +1,236 @@
+//! Pluggable sources of ADK knowledge
+//!
+//! A `KnowledgeSource` supplies concepts, best practices, and patterns per ADK version.
+//! `AdkKnowledgeBase::from_sources` merges several sources in priority order, so a local
+//! pack can override the web feed, which in turn overrides the bundled defaults.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use crate::expert::adk_knowledge::{AdkKnowledgeBase, Provenance, TrustLevel, VersionConfig, VersionDocs};
+use crate::utils::error::{ArkaftMcpError, ArkaftResult};
+
+/// Environment variable naming a knowledge pack file or directory
+pub const PACKS_PATH_ENV: &str = "ADK_KNOWLEDGE_PACKS";
+
+/// Priority of the compiled-in defaults
+pub const BUNDLED_PRIORITY: i32 = 0;
+
+/// Priority of the web documentation feed
+pub const WEB_PRIORITY: i32 = 50;
+
+/// Priority of local knowledge packs
+pub const LOCAL_PACK_PRIORITY: i32 = 100;
+
+/// Source of version-specific ADK knowledge
+#[async_trait]
+pub trait KnowledgeSource: Send + Sync {
+    /// Short name used in logs
+    fn name(&self) -> &str;
+    
+    /// Merge priority; entries from higher-priority sources win conflicts
+    fn priority(&self) -> i32;
+    
+    /// Fetch concepts, practices, and patterns for every version this source covers
+    async fn fetch_all(&self) -> ArkaftResult<Vec<VersionDocs>>;
+    
+    /// Fetch concepts, practices, and patterns for a single version
+    async fn fetch_version(&self, version: &str) -> ArkaftResult<Option<VersionDocs>> {
+        let mut result: Option<VersionDocs> = None;
+        for docs in self.fetch_all().await? {
+            if docs.version != version {
+                continue;
+            }
+            match &mut result {
+                Some(existing) => existing.merge_from(docs),
+                None => result = Some(docs),
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Knowledge compiled into the server binary
+pub struct BundledSource {
+    /// Version the bundled docs are published under
+    version: String,
+}
+
+impl BundledSource {
+    /// Bundled defaults published under the configured latest version
+    pub fn new() -> Self {
+        let version = VersionConfig::new().resolve_version("latest");
+        Self { version }
+    }
+}
+
+impl Default for BundledSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl KnowledgeSource for BundledSource {
+    fn name(&self) -> &str {
+        "bundled"
+    }
+    
+    fn priority(&self) -> i32 {
+        BUNDLED_PRIORITY
+    }
+    
+    async fn fetch_all(&self) -> ArkaftResult<Vec<VersionDocs>> {
+        Ok(vec![AdkKnowledgeBase::bundled_version_docs(self.version.clone())])
+    }
+}
+
+/// Third-party knowledge pack with provenance metadata
+///
+/// Every entry in `docs` is attributed to the pack when merged, so responses can
+/// flag it as community-sourced. Pack entries never replace official entries.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct KnowledgePack {
+    /// Pack name shown in responses
+    pub name: String,
+    /// Homepage or repository of the pack
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Trust level of the pack (defaults to unreviewed community content)
+    #[serde(default = "default_pack_trust")]
+    pub trust_level: TrustLevel,
+    /// Documentation contributed by the pack
+    pub docs: Vec<VersionDocs>,
+}
+
+impl KnowledgePack {
+    /// Provenance attached to every entry of this pack
+    pub fn provenance(&self) -> Provenance {
+        Provenance {
+            source_name: self.name.clone(),
+            url: self.url.clone(),
+            trust_level: self.trust_level,
+        }
+    }
+    
+    /// Pack documentation with every entry attributed to the pack
+    pub fn into_version_docs(self) -> Vec<VersionDocs> {
+        let provenance = self.provenance();
+        self.docs
+            .into_iter()
+            .map(|mut docs| {
+                docs.stamp_provenance(&provenance);
+                docs
+            })
+            .collect()
+    }
+}
+
+/// Trust level for packs that do not declare one
+fn default_pack_trust() -> TrustLevel {
+    TrustLevel::Community
+}
+
+/// Knowledge pack file holding one or more versions
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PackFile {
+    /// A third-party pack with provenance metadata
+    Community(KnowledgePack),
+    /// A single version's documentation
+    Single(Box<VersionDocs>),
+    /// Documentation for several versions
+    Multiple(Vec<VersionDocs>),
+}
+
+/// Knowledge packs stored as JSON files on the local filesystem
+///
+/// The path may name a single `.json` file or a directory of them. Each file holds a
+/// `KnowledgePack`, a `VersionDocs` object, or an array of them; files in a directory
+/// are read in name order.
+pub struct LocalPackSource {
+    /// Pack file or directory
+    path: PathBuf,
+    /// Merge priority
+    priority: i32,
+}
+
+impl LocalPackSource {
+    /// Create a source reading packs from `path`
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            priority: LOCAL_PACK_PRIORITY,
+        }
+    }
+    
+    /// Override the merge priority
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+    
+    /// Pack file or directory
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    
+    /// List the pack files to read, in name order
+    async fn pack_files(&self) -> ArkaftResult<Vec<PathBuf>> {
+        if !tokio::fs::metadata(&self.path).await?.is_dir() {
+            return Ok(vec![self.path.clone()]);
+        }
+        
+        let mut files = Vec::new();
+        let mut entries = tokio::fs::read_dir(&self.path).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                files.push(path);
+            }
+        }
+        files.sort();
+        
+        Ok(files)
+    }
+}
+
+#[async_trait]
+impl KnowledgeSource for LocalPackSource {
+    fn name(&self) -> &str {
+        "local-pack"
+    }
+    
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+    
+    async fn fetch_all(&self) -> ArkaftResult<Vec<VersionDocs>> {
+        let mut all_docs = Vec::new();
+        
+        for file in self.pack_files().await? {
+            let content = tokio::fs::read_to_string(&file).await?;
+            let pack: PackFile = serde_json::from_str(&content).map_err(|e| {
+                ArkaftMcpError::knowledge_update(format!("Invalid knowledge pack {}: {}", file.display(), e))
+            })?;
+            
+            match pack {
+                PackFile::Community(pack) => all_docs.extend(pack.into_version_docs()),
+                PackFile::Single(docs) => all_docs.push(*docs),
+                PackFile::Multiple(docs) => all_docs.extend(docs),
+            }
+        }
+        
+        Ok(all_docs)
+    }
+}
+
+/// Bundled defaults plus local packs at `packs_path`, if given
+pub fn default_sources(packs_path: Option<&str>) -> Vec<Arc<dyn KnowledgeSource>> {
+    let mut sources: Vec<Arc<dyn KnowledgeSource>> = vec![Arc::new(BundledSource::new())];
+    if let Some(path) = packs_path.filter(|path| !path.trim().is_empty()) {
+        sources.push(Arc::new(LocalPackSource::new(path)));
+    }
+    sources
+}
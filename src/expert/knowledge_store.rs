@@ -0,0 +1,202 @@
+//! Persistent on-disk storage for the ADK knowledge base
+//!
+//! Knowledge is stored in a SQLite database under a configurable data directory,
+//! one row per ADK version holding the serialized `VersionDocs`. Stored entries are
+//! merged over the compiled-in defaults on startup so enrichments survive restarts.
+//! The same database holds the per-project history of architecture validations.
+
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use rusqlite::{params, Connection, OptionalExtension};
+use crate::expert::adk_knowledge::{AdkKnowledgeBase, VersionDocs};
+use crate::expert::validation_history::ValidationRecord;
+use crate::utils::error::{ArkaftMcpError, ArkaftResult};
+
+/// Environment variable naming the data directory for persistent storage
+pub const DATA_DIR_ENV: &str = "ADK_DATA_DIR";
+
+/// Database file name inside the data directory
+const DATABASE_FILE: &str = "knowledge.db";
+
+/// SQLite-backed store for knowledge base content
+#[derive(Clone, Debug)]
+pub struct KnowledgeStore {
+    /// Path to the SQLite database file
+    db_path: PathBuf,
+}
+
+impl KnowledgeStore {
+    /// Open (or create) the store inside `data_dir`
+    pub fn open<P: AsRef<Path>>(data_dir: P) -> ArkaftResult<Self> {
+        std::fs::create_dir_all(data_dir.as_ref())?;
+        
+        let store = Self {
+            db_path: data_dir.as_ref().join(DATABASE_FILE),
+        };
+        
+        store.connect()?.execute_batch(
+            "CREATE TABLE IF NOT EXISTS version_docs (
+                version TEXT PRIMARY KEY,
+                docs TEXT NOT NULL,
+                updated_at INTEGER NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS validation_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                project_id TEXT NOT NULL,
+                recorded_at INTEGER NOT NULL,
+                record TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS validation_history_project ON validation_history (project_id, recorded_at);",
+        ).map_err(storage_error)?;
+        
+        Ok(store)
+    }
+    
+    /// Open the store from the `ADK_DATA_DIR` environment variable, if set
+    pub fn from_env() -> Option<ArkaftResult<Self>> {
+        std::env::var(DATA_DIR_ENV)
+            .ok()
+            .filter(|dir| !dir.trim().is_empty())
+            .map(Self::open)
+    }
+    
+    /// Path to the underlying database file
+    pub fn db_path(&self) -> &Path {
+        &self.db_path
+    }
+    
+    /// Save documentation for a single version, replacing any stored copy
+    pub fn save_version_docs(&self, docs: &VersionDocs) -> ArkaftResult<()> {
+        let json = serde_json::to_string(docs)?;
+        
+        self.connect()?.execute(
+            "INSERT INTO version_docs (version, docs, updated_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(version) DO UPDATE SET docs = excluded.docs, updated_at = excluded.updated_at",
+            params![docs.version, json, unix_timestamp()],
+        ).map_err(storage_error)?;
+        
+        Ok(())
+    }
+    
+    /// Merge documentation into the stored copy for its version
+    ///
+    /// Only the merged-in entries are kept on disk, so compiled-in defaults are
+    /// never frozen into the store and newer releases can still update them.
+    pub fn merge_version_docs(&self, docs: VersionDocs) -> ArkaftResult<()> {
+        let merged = match self.load_version(&docs.version)? {
+            Some(mut stored) => {
+                stored.merge_from(docs);
+                stored
+            }
+            None => docs,
+        };
+        self.save_version_docs(&merged)
+    }
+    
+    /// Load stored documentation for a single version
+    pub fn load_version(&self, version: &str) -> ArkaftResult<Option<VersionDocs>> {
+        let conn = self.connect()?;
+        let json: Option<String> = conn
+            .query_row(
+                "SELECT docs FROM version_docs WHERE version = ?1",
+                params![version],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(storage_error)?;
+        
+        json.map(|json| serde_json::from_str(&json).map_err(ArkaftMcpError::from))
+            .transpose()
+    }
+    
+    /// Save every version held by the knowledge base
+    pub fn save_knowledge_base(&self, knowledge_base: &AdkKnowledgeBase) -> ArkaftResult<()> {
+        for docs in knowledge_base.version_docs.values() {
+            self.save_version_docs(docs)?;
+        }
+        Ok(())
+    }
+    
+    /// Load all stored version documentation
+    pub fn load_version_docs(&self) -> ArkaftResult<Vec<VersionDocs>> {
+        let conn = self.connect()?;
+        let mut statement = conn
+            .prepare("SELECT docs FROM version_docs ORDER BY version")
+            .map_err(storage_error)?;
+        
+        let rows = statement
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(storage_error)?;
+        
+        let mut all_docs = Vec::new();
+        for row in rows {
+            let json = row.map_err(storage_error)?;
+            all_docs.push(serde_json::from_str(&json)?);
+        }
+        
+        Ok(all_docs)
+    }
+    
+    /// Merge stored documentation into the knowledge base, returning the number of versions loaded
+    pub fn load_into(&self, knowledge_base: &mut AdkKnowledgeBase) -> ArkaftResult<usize> {
+        let stored = self.load_version_docs()?;
+        let count = stored.len();
+        
+        for docs in stored {
+            knowledge_base.record_source(&docs.version, "knowledge-store");
+            knowledge_base.merge_version_docs(docs);
+        }
+        
+        Ok(count)
+    }
+    
+    /// Append a validation record to the project's history
+    pub fn record_validation(&self, record: &ValidationRecord) -> ArkaftResult<()> {
+        let json = serde_json::to_string(record)?;
+        
+        self.connect()?.execute(
+            "INSERT INTO validation_history (project_id, recorded_at, record) VALUES (?1, ?2, ?3)",
+            params![record.project_id, record.recorded_at, json],
+        ).map_err(storage_error)?;
+        
+        Ok(())
+    }
+    
+    /// Load a project's validation records in the order they were recorded
+    pub fn load_validation_history(&self, project_id: &str) -> ArkaftResult<Vec<ValidationRecord>> {
+        let conn = self.connect()?;
+        let mut statement = conn
+            .prepare("SELECT record FROM validation_history WHERE project_id = ?1 ORDER BY recorded_at, id")
+            .map_err(storage_error)?;
+        
+        let rows = statement
+            .query_map(params![project_id], |row| row.get::<_, String>(0))
+            .map_err(storage_error)?;
+        
+        let mut records = Vec::new();
+        for row in rows {
+            let json = row.map_err(storage_error)?;
+            records.push(serde_json::from_str(&json)?);
+        }
+        
+        Ok(records)
+    }
+    
+    /// Open a connection to the database
+    fn connect(&self) -> ArkaftResult<Connection> {
+        Connection::open(&self.db_path).map_err(storage_error)
+    }
+}
+
+/// Convert a SQLite error into a storage error
+fn storage_error(error: rusqlite::Error) -> ArkaftMcpError {
+    ArkaftMcpError::storage(error.to_string())
+}
+
+/// Current time as seconds since the Unix epoch
+pub(crate) fn unix_timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
@@ -5,13 +5,59 @@
 
 pub mod adk_knowledge;
 pub mod documentation;
+pub mod fallback_practices;
 pub mod best_practices;
+pub mod query_intent;
+pub mod knowledge_store;
+pub mod auto_update;
+pub mod knowledge_source;
+pub mod query_cache;
+pub mod query_analytics;
+pub mod relevance;
+pub mod i18n;
+pub mod snapshot;
+pub mod federation;
+pub mod rule_sets;
+pub mod custom_rules;
+pub mod snippets;
+pub mod architecture_diagram;
+pub mod orchestration;
+pub mod suppressions;
+pub mod compliance_report;
+pub mod validation_history;
 
 #[cfg(test)]
 mod best_practices_tests;
 
-use adk_knowledge::{AdkKnowledgeBase, VersionConfig};
-use documentation::{DocumentationReferenceGenerator, format_documentation_response, format_concept_response, generate_comprehensive_links};
+#[cfg(test)]
+mod tests;
+
+use adk_knowledge::{AdkKnowledgeBase, VersionConfig, VersionDocs, VersionOverrides, query_terms};
+use knowledge_store::{KnowledgeStore, unix_timestamp};
+use query_cache::{CacheStats, QueryCache};
+use query_analytics::{AnalyticsReport, QueryAnalytics};
+use snapshot::{ImportReport, KnowledgeSnapshot};
+use federation::Federation;
+use validation_history::ValidationHistory;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+use best_practices::{ArchitectureDesign, BestPracticesEnforcer, DesignValidation, ValidationOptions, ValidationRules};
+use tracing::{info, warn};
+use documentation::{
+    DocumentationReferenceGenerator, format_documentation_response, format_ranked_concept_response,
+    format_ranked_patterns, format_ranked_practices, format_practice_content, format_pattern_content,
+    format_version_feature, format_source_tag, format_grounded_answer, format_confidence, format_not_found_response,
+    format_version_list, format_code_examples, format_availability, format_template_content, format_category_list,
+    format_follow_up_questions, format_release_digest, format_knowledge_stats, generate_comprehensive_links, summarize,
+};
+use query_intent::{QueryIntent, classify_query};
+
+/// Maximum number of ranked concept matches returned for a query
+const MAX_RANKED_RESULTS: usize = 5;
+
+/// Maximum number of follow-up questions suggested after an answer
+const MAX_FOLLOW_UP_QUESTIONS: usize = 3;
 
 /// Documentation Expert System for Google ADK with comprehensive knowledge base
 pub struct DocumentationExpert {
@@ -19,56 +65,399 @@ pub struct DocumentationExpert {
     pub knowledge_base: AdkKnowledgeBase,
     /// Documentation reference generator
     pub reference_generator: DocumentationReferenceGenerator,
+    /// Persistent storage for enriched knowledge, if configured
+    pub store: Option<KnowledgeStore>,
+    /// LRU cache of rendered query responses and whether each one answered its query
+    cache: Mutex<QueryCache<(String, bool)>>,
+    /// Anonymized per-term counts of asked and unanswered queries
+    analytics: Mutex<QueryAnalytics>,
 }
 
 impl DocumentationExpert {
     /// Create a new Documentation Expert instance with default configuration
+    ///
+    /// When `ADK_DATA_DIR` is set, previously stored knowledge is merged over the
+    /// compiled-in defaults and later updates are written back to disk.
     pub fn new() -> Self {
-        let knowledge_base = AdkKnowledgeBase::new();
+        Self::with_knowledge_base(AdkKnowledgeBase::new())
+    }
+    
+    /// Create Documentation Expert with custom version configuration
+    pub fn with_version_config(config: VersionConfig) -> Self {
+        Self::with_knowledge_base(AdkKnowledgeBase::with_version_config(config))
+    }
+    
+    /// Create Documentation Expert over an existing knowledge base, e.g. one aggregated from sources
+    pub fn with_knowledge_base(mut knowledge_base: AdkKnowledgeBase) -> Self {
+        let store = Self::load_store_from_env(&mut knowledge_base);
         let reference_generator = DocumentationReferenceGenerator::new(knowledge_base.clone());
         
         Self {
             knowledge_base,
             reference_generator,
+            store,
+            cache: Mutex::new(QueryCache::default()),
+            analytics: Mutex::new(QueryAnalytics::default()),
         }
     }
     
-    /// Create Documentation Expert with custom version configuration
-    pub fn with_version_config(config: VersionConfig) -> Self {
-        let knowledge_base = AdkKnowledgeBase::with_version_config(config);
+    /// Create Documentation Expert backed by an explicit knowledge store
+    pub fn with_store(store: KnowledgeStore) -> anyhow::Result<Self> {
+        let mut knowledge_base = AdkKnowledgeBase::new();
+        store.load_into(&mut knowledge_base)?;
         let reference_generator = DocumentationReferenceGenerator::new(knowledge_base.clone());
         
-        Self {
+        Ok(Self {
             knowledge_base,
             reference_generator,
+            store: Some(store),
+            cache: Mutex::new(QueryCache::default()),
+            analytics: Mutex::new(QueryAnalytics::default()),
+        })
+    }
+    
+    /// Directory holding the knowledge store, which bounds the snapshot files admin tools may touch
+    pub fn data_dir(&self) -> Option<&Path> {
+        self.store.as_ref().and_then(|store| store.db_path().parent())
+    }
+    
+    /// Replace the query cache with one holding at most `capacity` responses (zero disables caching)
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache = Mutex::new(QueryCache::new(capacity));
+        self
+    }
+    
+    /// Merge new documentation into the knowledge base and persist it when a store is configured
+    pub fn persist_version_docs(&mut self, docs: VersionDocs) -> anyhow::Result<()> {
+        self.persist_version_docs_at(docs, unix_timestamp(), "refresh")
+    }
+    
+    /// Merge and persist documentation from `source` whose content dates from `timestamp`
+    ///
+    /// Entries that carry no update time of their own are stamped with `timestamp`.
+    fn persist_version_docs_at(&mut self, mut docs: VersionDocs, timestamp: i64, source: &str) -> anyhow::Result<()> {
+        docs.stamp_last_updated(timestamp);
+        if let Some(store) = &self.store {
+            store.merge_version_docs(docs.clone())?;
         }
+        
+        self.knowledge_base.mark_updated(&docs.version, timestamp);
+        self.knowledge_base.record_source(&docs.version, source);
+        self.knowledge_base.merge_version_docs(docs);
+        self.refresh_derived_state();
+        
+        Ok(())
+    }
+    
+    /// Add versions and repoint aliases in the knowledge base
+    pub fn apply_version_overrides(&mut self, overrides: VersionOverrides) -> anyhow::Result<()> {
+        self.knowledge_base.apply_version_overrides(overrides)?;
+        self.refresh_derived_state();
+        Ok(())
+    }
+    
+    /// Rebuild the reference generator from the knowledge base and drop cached responses,
+    /// which both reflect the knowledge base as it was before a change
+    fn refresh_derived_state(&mut self) {
+        self.reference_generator = DocumentationReferenceGenerator::new(self.knowledge_base.clone());
+        self.clear_cache();
+    }
+    
+    /// Open the store named by `ADK_DATA_DIR` and merge its contents into the knowledge base
+    fn load_store_from_env(knowledge_base: &mut AdkKnowledgeBase) -> Option<KnowledgeStore> {
+        let store = match KnowledgeStore::from_env()? {
+            Ok(store) => store,
+            Err(e) => {
+                warn!("Failed to open knowledge store, using compiled-in defaults: {}", e);
+                return None;
+            }
+        };
+        
+        match store.load_into(knowledge_base) {
+            Ok(count) => info!("Loaded {} stored knowledge version(s) from {}", count, store.db_path().display()),
+            Err(e) => warn!("Failed to load stored knowledge from {}: {}", store.db_path().display(), e),
+        }
+        
+        Some(store)
     }
     
     /// Query ADK documentation and concepts with comprehensive knowledge base lookup
+    ///
+    /// Responses are cached per (normalized query, version) so agent loops that repeat
+    /// a question skip search and rendering. Every query, cached or not, is counted in
+    /// the query analytics. Answered queries end with suggested follow-up questions.
     pub async fn query_documentation(&self, query: &str, version: Option<&str>) -> anyhow::Result<String> {
         let resolved_version = version
             .map(|v| self.knowledge_base.resolve_version(v))
             .unwrap_or_else(|| self.knowledge_base.default_version.clone());
         
-        // Search for matching concepts first
-        let matching_concepts = self.knowledge_base.search_concepts(query, Some(&resolved_version));
+        let cached = self.lock_cache().get(query, &resolved_version);
+        let (response, answered) = match cached {
+            Some(cached) => cached,
+            None => {
+                let (mut response, answered) = self.answer_query(query, &resolved_version);
+                if answered {
+                    let follow_ups = self.knowledge_base.suggest_follow_up_questions(query, Some(&resolved_version), MAX_FOLLOW_UP_QUESTIONS);
+                    if !follow_ups.is_empty() {
+                        response.push_str("\n\n");
+                        response.push_str(&format_follow_up_questions(&follow_ups));
+                    }
+                }
+                self.lock_cache().insert(query, &resolved_version, (response.clone(), answered));
+                (response, answered)
+            }
+        };
+        self.lock_analytics().record(query, answered);
+        
+        Ok(response)
+    }
+    
+    /// Query cache usage statistics
+    pub fn cache_stats(&self) -> CacheStats {
+        self.lock_cache().stats()
+    }
+    
+    /// Drop cached responses, e.g. after changing the knowledge base directly
+    pub fn clear_cache(&self) {
+        self.lock_cache().clear();
+    }
+    
+    /// Most-asked and most-missed query terms, `limit` of each
+    pub fn query_analytics(&self, limit: usize) -> AnalyticsReport {
+        self.lock_analytics().report(limit)
+    }
+    
+    /// Forget all recorded query analytics
+    pub fn reset_query_analytics(&self) {
+        self.lock_analytics().reset();
+    }
+    
+    /// Capture the full knowledge base, all versions included, as a portable snapshot
+    pub fn export_snapshot(&self) -> KnowledgeSnapshot {
+        KnowledgeSnapshot::from_knowledge_base(&self.knowledge_base)
+    }
+    
+    /// Merge a snapshot into the knowledge base, persisting it when a store is configured
+    ///
+    /// Versions last refreshed after the snapshot was exported keep their entries and only
+    /// gain missing ones. Aliases already defined locally are never changed.
+    pub fn import_snapshot(&mut self, snapshot: KnowledgeSnapshot) -> anyhow::Result<ImportReport> {
+        let mut report = ImportReport::default();
         
-        if !matching_concepts.is_empty() {
-            // Return detailed concept information
-            let concept = matching_concepts[0];
-            return Ok(format_concept_response(concept, &resolved_version));
+        for mut docs in snapshot.versions {
+            let version = docs.version.clone();
+            if snapshot.exported_at >= self.knowledge_base.content_updated_at(&version) {
+                report.updated_versions.push(version);
+            } else {
+                if let Some(existing) = self.knowledge_base.version_docs.get(&version) {
+                    docs.retain_new_entries(existing);
+                }
+                report.gap_filled_versions.push(version);
+            }
+            
+            report.entries_merged += docs.entry_count();
+            self.persist_version_docs_at(docs, snapshot.exported_at, "snapshot")?;
         }
         
-        // Generate comprehensive response with official references
-        let content = self.generate_query_response(query, &resolved_version).await?;
-        let references = generate_comprehensive_links(query, &resolved_version, &self.knowledge_base);
+        let mut aliases: Vec<(String, String)> = snapshot.version_aliases.into_iter().collect();
+        aliases.sort();
+        for (alias, target) in aliases {
+            if !self.knowledge_base.version_config.version_aliases.contains_key(&alias) {
+                self.knowledge_base.version_config.set_alias(alias.clone(), target);
+                report.aliases_added.push(alias);
+            }
+        }
+        if !report.aliases_added.is_empty() {
+            self.refresh_derived_state();
+        }
         
-        Ok(format_documentation_response(
+        Ok(report)
+    }
+    
+    /// Lock the query cache, recovering from a poisoned lock
+    fn lock_cache(&self) -> std::sync::MutexGuard<'_, QueryCache<(String, bool)>> {
+        self.cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+    
+    /// Lock the query analytics, recovering from a poisoned lock
+    fn lock_analytics(&self) -> std::sync::MutexGuard<'_, QueryAnalytics> {
+        self.analytics.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+    
+    /// Build the response for a query against a resolved version, and whether it answered the query
+    fn answer_query(&self, query: &str, resolved_version: &str) -> (String, bool) {
+        // Route the query to the sub-system that matches its intent
+        let intent = classify_query(query);
+        let routed = match intent {
+            QueryIntent::HowTo => self.answer_how_to(query, resolved_version),
+            QueryIntent::BestPractice => self.answer_best_practice(query, resolved_version),
+            QueryIntent::Troubleshooting => self.answer_troubleshooting(query, resolved_version),
+            QueryIntent::Migration => self.answer_migration(query, resolved_version),
+            QueryIntent::ConceptLookup => None,
+        };
+        
+        if let Some(response) = routed {
+            return (response, true);
+        }
+        
+        // Fall back to ranked concepts when the routed sub-system has no answer
+        let ranked_concepts = self.knowledge_base.rank_concepts(query, Some(resolved_version), MAX_RANKED_RESULTS);
+        
+        if !ranked_concepts.is_empty() {
+            // Expand the most relevant concept and list the other candidates
+            return (format_ranked_concept_response(query, &ranked_concepts, resolved_version), true);
+        }
+        
+        // Synthesize an answer from passages across the whole knowledge base
+        let passages = self.knowledge_base.retrieve_passages(query, Some(resolved_version), MAX_RANKED_RESULTS);
+        
+        if passages.is_empty() {
+            // Say so explicitly instead of returning confident-sounding filler
+            let suggestions = self.knowledge_base.closest_topics(query, Some(resolved_version), MAX_RANKED_RESULTS);
+            let references = self.reference_generator.generate_category_references("quickstart", Some(resolved_version));
+            return (format_not_found_response(query, resolved_version, &suggestions, &references), false);
+        }
+        
+        let content = format_grounded_answer(&passages, resolved_version);
+        
+        let mut references: Vec<String> = passages.iter().filter_map(|p| p.reference.clone()).collect();
+        for link in generate_comprehensive_links(query, resolved_version, &self.knowledge_base) {
+            if !references.contains(&link) {
+                references.push(link);
+            }
+        }
+        
+        let response = format_documentation_response(
             query,
             &content,
-            &resolved_version,
+            resolved_version,
             &references,
-        ))
+        );
+        (response, true)
+    }
+    
+    /// Answer how-to queries from implementation patterns
+    fn answer_how_to(&self, query: &str, version: &str) -> Option<String> {
+        let patterns = self.knowledge_base.rank_patterns(query, Some(version), MAX_RANKED_RESULTS);
+        if patterns.is_empty() {
+            return None;
+        }
+        
+        let content = format!(
+            "**Query Type:** {}\n\n{}\n\n{}",
+            QueryIntent::HowTo.label(),
+            format_confidence(patterns[0].score),
+            format_ranked_patterns(&patterns)
+        );
+        let references = self.reference_generator.generate_category_references("tutorials", Some(version));
+        
+        Some(format_documentation_response(query, &content, version, &references))
+    }
+    
+    /// Answer best-practice requests from the best practices catalog
+    fn answer_best_practice(&self, query: &str, version: &str) -> Option<String> {
+        let practices = self.knowledge_base.rank_best_practices(query, Some(version), MAX_RANKED_RESULTS);
+        if practices.is_empty() {
+            return None;
+        }
+        
+        let content = format!(
+            "**Query Type:** {}\n\n{}\n\n{}",
+            QueryIntent::BestPractice.label(),
+            format_confidence(practices[0].score),
+            format_ranked_practices(&practices)
+        );
+        let references = self.reference_generator.generate_category_references("best_practices", Some(version));
+        
+        Some(format_documentation_response(query, &content, version, &references))
+    }
+    
+    /// Answer troubleshooting queries from related practices and concepts
+    fn answer_troubleshooting(&self, query: &str, version: &str) -> Option<String> {
+        let practices = self.knowledge_base.rank_best_practices(query, Some(version), 3);
+        let concepts = self.knowledge_base.rank_concepts(query, Some(version), 3);
+        if practices.is_empty() && concepts.is_empty() {
+            return None;
+        }
+        
+        let top_score = practices.first().map_or(0.0, |m| m.score)
+            .max(concepts.first().map_or(0.0, |m| m.score));
+        let mut content = format!(
+            "**Query Type:** {}\n\n{}\n\n",
+            QueryIntent::Troubleshooting.label(),
+            format_confidence(top_score)
+        );
+        if !practices.is_empty() {
+            content.push_str("## Practices That Commonly Resolve This\n\n");
+            content.push_str(&format_ranked_practices(&practices));
+            content.push_str("\n\n");
+        }
+        if !concepts.is_empty() {
+            content.push_str("## Concepts to Check\n\n");
+            content.push_str(
+                &concepts
+                    .iter()
+                    .map(|m| format!(
+                        "- **{}** (`{}`){} - {}",
+                        m.concept.name,
+                        m.key,
+                        format_source_tag(m.provenance),
+                        summarize(&m.concept.description)
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+        }
+        
+        let references = generate_comprehensive_links(query, version, &self.knowledge_base);
+        
+        Some(format_documentation_response(query, content.trim_end(), version, &references))
+    }
+    
+    /// Answer migration queries from version features and migration guides
+    fn answer_migration(&self, query: &str, version: &str) -> Option<String> {
+        let docs = self.knowledge_base.get_version_docs(version)?;
+        let terms = query_terms(query);
+        
+        // Prefer features named in the query, otherwise list every deprecation or migration note
+        let matched_terms = |feature: &adk_knowledge::VersionFeature| {
+            let text = format!("{} {}", feature.name, feature.description).to_lowercase();
+            terms.iter().filter(|term| text.contains(term.as_str())).count()
+        };
+        let mut features: Vec<_> = docs.version_features
+            .iter()
+            .filter(|feature| matched_terms(feature) > 0)
+            .collect();
+        
+        // Share of query terms found in the best feature; listing every note is a weak answer
+        let mut score = features
+            .iter()
+            .map(|feature| matched_terms(feature) as f32 / terms.len().max(1) as f32)
+            .fold(0.0, f32::max);
+        if features.is_empty() {
+            score = 0.2;
+            features = docs.version_features
+                .iter()
+                .filter(|feature| feature.deprecated_in.is_some() || feature.migration_notes.is_some())
+                .collect();
+        }
+        if features.is_empty() {
+            return None;
+        }
+        
+        let content = format!(
+            "**Query Type:** {}\n\n{}\n\n{}",
+            QueryIntent::Migration.label(),
+            format_confidence(score),
+            features
+                .iter()
+                .map(|feature| format_version_feature(feature))
+                .collect::<Vec<_>>()
+                .join("\n\n---\n\n")
+        );
+        let references = self.reference_generator.generate_category_references("migration", Some(version));
+        
+        Some(format_documentation_response(query, &content, version, &references))
     }
     
     /// Get available ADK versions
@@ -76,6 +465,56 @@ impl DocumentationExpert {
         self.knowledge_base.get_available_versions()
     }
     
+    /// List known versions with their aliases and support status
+    pub fn list_versions(&self) -> String {
+        format_version_list(&self.knowledge_base.version_summaries(), &self.knowledge_base.default_version)
+    }
+    
+    /// Report entry counts, last refresh times, and sources for every loaded version
+    pub fn knowledge_base_stats(&self) -> String {
+        format_knowledge_stats(&self.knowledge_base.version_stats(), &self.knowledge_base.default_version)
+    }
+    
+    /// List best practice categories with practice counts for a version
+    pub fn list_best_practice_categories(&self, version: Option<&str>) -> String {
+        let resolved_version = version
+            .map(|v| self.knowledge_base.resolve_version(v))
+            .unwrap_or_else(|| self.knowledge_base.default_version.clone());
+        
+        format_category_list(&self.knowledge_base.best_practice_categories(Some(&resolved_version)), &resolved_version)
+    }
+    
+    /// Summarize the features, deprecations, and fixes shipped in a release
+    pub fn whats_new_in(&self, version: &str) -> String {
+        let resolved_version = self.knowledge_base.resolve_version(version);
+        format_release_digest(
+            &resolved_version,
+            &self.knowledge_base.changes_in(&resolved_version),
+            &self.knowledge_base.release_notes_url(&resolved_version),
+        )
+    }
+    
+    /// Report which versions support a concept or feature, for targeting a minimum version
+    pub fn get_feature_availability(&self, name: &str) -> String {
+        match self.knowledge_base.feature_availability(name) {
+            Some(availability) => format_availability(&availability),
+            None => {
+                let suggestions = self.knowledge_base.closest_topics(name, None, MAX_RANKED_RESULTS);
+                let mut response = format!(
+                    "No concept or version feature named '{}' was found in the ADK knowledge base.",
+                    name
+                );
+                if !suggestions.is_empty() {
+                    response.push_str(&format!(
+                        "\n\n### Closest Related Topics:\n{}",
+                        suggestions.iter().map(|topic| format!("- {}", topic)).collect::<Vec<_>>().join("\n")
+                    ));
+                }
+                response
+            }
+        }
+    }
+    
     /// Get best practices for specific category
     pub async fn get_best_practices(&self, category: Option<&str>, version: Option<&str>) -> anyhow::Result<String> {
         let resolved_version = version
@@ -93,35 +532,32 @@ impl DocumentationExpert {
             }
         };
         
-        if practices.is_empty() {
-            return Ok(format!(
-                "No best practices found for category '{}' in version {}. \
-                Please refer to the official documentation for the latest guidelines.",
-                category.unwrap_or("all"),
-                resolved_version
-            ));
-        }
-        
-        let content = practices
+        let docs = self.knowledge_base.get_version_docs(&resolved_version);
+        let mut content = practices
             .iter()
             .map(|practice| {
-                format!(
-                    "### {}\n\n**Category:** {}\n\n{}\n\n**Examples:**\n{}\n\n**Reference:** [{}]({})",
-                    practice.title,
-                    practice.category,
-                    practice.description,
-                    practice.examples
-                        .iter()
-                        .map(|ex| format!("- {}", ex))
-                        .collect::<Vec<_>>()
-                        .join("\n"),
-                    practice.documentation_ref,
-                    practice.documentation_ref
-                )
+                let provenance = docs.and_then(|docs| docs.practice_provenance(&practice.title));
+                let citation = docs.and_then(|docs| docs.practice_citation(&practice.title));
+                format_practice_content(practice, provenance, citation)
             })
             .collect::<Vec<_>>()
             .join("\n\n---\n\n");
         
+        // Fall back to curated general guidance rather than answering with nothing
+        if practices.is_empty() {
+            let fallback = fallback_practices::fallback_practices("", category)
+                .iter()
+                .map(|practice| format_practice_content(practice, None, None))
+                .collect::<Vec<_>>()
+                .join("\n\n---\n\n");
+            content = format!(
+                "**General guidance:** No best practices found for category '{}' in version {}, so these general ADK practices apply instead.\n\n{}",
+                category.unwrap_or("all"),
+                resolved_version,
+                fallback
+            );
+        }
+        
         let references = self.reference_generator.generate_category_references("best_practices", Some(&resolved_version));
         
         Ok(format_documentation_response(
@@ -139,36 +575,10 @@ impl DocumentationExpert {
             .unwrap_or_else(|| self.knowledge_base.default_version.clone());
         
         if let Some(pattern) = self.knowledge_base.get_implementation_pattern(pattern_name, Some(&resolved_version)) {
-            let examples_text = pattern.code_examples
-                .iter()
-                .map(|example| {
-                    format!(
-                        "#### {}\n\n```{}\n{}\n```\n\n{}",
-                        example.title,
-                        example.language,
-                        example.code,
-                        example.explanation
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join("\n\n");
-            
-            let content = format!(
-                "### {}\n\n{}\n\n**Use Cases:**\n{}\n\n**Examples:**\n\n{}\n\n**Related Practices:**\n{}",
-                pattern.name,
-                pattern.description,
-                pattern.use_cases
-                    .iter()
-                    .map(|uc| format!("- {}", uc))
-                    .collect::<Vec<_>>()
-                    .join("\n"),
-                examples_text,
-                pattern.related_practices
-                    .iter()
-                    .map(|rp| format!("- {}", rp))
-                    .collect::<Vec<_>>()
-                    .join("\n")
-            );
+            let provenance = self.knowledge_base
+                .get_version_docs(&resolved_version)
+                .and_then(|docs| docs.pattern_provenance(pattern_name));
+            let content = format_pattern_content(pattern, provenance);
             
             let references = self.reference_generator.generate_official_references(Some(&resolved_version));
             
@@ -179,31 +589,362 @@ impl DocumentationExpert {
                 &references,
             ))
         } else {
+            let available = self.pattern_keys(&resolved_version)
+                .iter()
+                .map(|key| format!("`{}`", key))
+                .collect::<Vec<_>>()
+                .join(", ");
             Ok(format!(
-                "Implementation pattern '{}' not found for version {}. \
-                Please refer to the official documentation for available patterns.",
+                "Implementation pattern '{}' not found for version {}. Available patterns: {}",
                 pattern_name,
-                resolved_version
+                resolved_version,
+                available
             ))
         }
     }
     
-    /// Generate response content for general queries
-    async fn generate_query_response(&self, query: &str, version: &str) -> anyhow::Result<String> {
-        // This is a placeholder for more sophisticated query processing
-        // In a real implementation, this would analyze the query and generate
-        // appropriate responses based on the knowledge base
+    /// Search code examples across implementation patterns by keyword and optional language
+    pub async fn search_code_examples(&self, query: &str, language: Option<&str>, version: Option<&str>) -> anyhow::Result<String> {
+        let resolved_version = version
+            .map(|v| self.knowledge_base.resolve_version(v))
+            .unwrap_or_else(|| self.knowledge_base.default_version.clone());
+        
+        let matches = self.knowledge_base.search_code_examples(query, language, Some(&resolved_version), MAX_RANKED_RESULTS);
+        let content = if matches.is_empty() {
+            let filter = language
+                .map(|lang| format!(" in language '{}'", lang.trim()))
+                .unwrap_or_default();
+            format!(
+                "No code examples matched '{}'{}. Call `adk_pattern` without arguments to browse all patterns.",
+                query,
+                filter
+            )
+        } else {
+            format_code_examples(&matches)
+        };
+        let references = self.reference_generator.generate_category_references("tutorials", Some(&resolved_version));
+        
+        Ok(format_documentation_response(
+            &format!("Code Examples: {}", query),
+            &content,
+            &resolved_version,
+            &references,
+        ))
+    }
+    
+    /// List implementation patterns available for a version
+    pub async fn list_implementation_patterns(&self, version: Option<&str>) -> anyhow::Result<String> {
+        let resolved_version = version
+            .map(|v| self.knowledge_base.resolve_version(v))
+            .unwrap_or_else(|| self.knowledge_base.default_version.clone());
+        
+        let docs = self.knowledge_base.get_version_docs(&resolved_version);
+        let rows = self.pattern_keys(&resolved_version)
+            .iter()
+            .filter_map(|key| {
+                let docs = docs?;
+                let pattern = docs.implementation_patterns.get(key)?;
+                Some(format!(
+                    "| `{}` | {}{} | {} |",
+                    key,
+                    pattern.name,
+                    format_source_tag(docs.pattern_provenance(key)),
+                    summarize(&pattern.description)
+                ))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        
+        let content = format!(
+            "| Pattern | Name | Summary |\n|---------|------|---------|\n{}\n\n\
+            Call `adk_pattern` with `pattern_name` set to a key above for code examples.",
+            rows
+        );
+        let references = self.reference_generator.generate_category_references("tutorials", Some(&resolved_version));
+        
+        Ok(format_documentation_response(
+            "Implementation Patterns",
+            &content,
+            &resolved_version,
+            &references,
+        ))
+    }
+    
+    /// Sorted implementation pattern keys for a version
+    fn pattern_keys(&self, version: &str) -> Vec<String> {
+        let mut keys: Vec<String> = self.knowledge_base
+            .get_version_docs(version)
+            .map(|docs| docs.implementation_patterns.keys().cloned().collect())
+            .unwrap_or_default();
+        keys.sort();
+        keys
+    }
+    
+    /// Get an ADK project template, or list the available templates when none is named
+    pub async fn get_project_template(&self, template_name: Option<&str>, version: Option<&str>) -> anyhow::Result<String> {
+        let resolved_version = version
+            .map(|v| self.knowledge_base.resolve_version(v))
+            .unwrap_or_else(|| self.knowledge_base.default_version.clone());
+        let docs = self.knowledge_base.get_version_docs(&resolved_version);
+        let references = self.reference_generator.generate_category_references("quickstart", Some(&resolved_version));
+        
+        if let Some(template_name) = template_name {
+            let template_name = template_name.trim();
+            if let Some(template) = self.knowledge_base.get_template(template_name, Some(&resolved_version)) {
+                let provenance = docs.and_then(|docs| docs.template_provenance(template_name));
+                
+                return Ok(format_documentation_response(
+                    &format!("Project Template: {}", template_name),
+                    &format_template_content(template, provenance),
+                    &resolved_version,
+                    &references,
+                ));
+            }
+            
+            let available = self.template_keys(&resolved_version)
+                .iter()
+                .map(|key| format!("`{}`", key))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Ok(format!(
+                "Project template '{}' not found for version {}. Available templates: {}",
+                template_name,
+                resolved_version,
+                available
+            ));
+        }
+        
+        let rows = self.template_keys(&resolved_version)
+            .iter()
+            .filter_map(|key| {
+                let docs = docs?;
+                let template = docs.templates.get(key)?;
+                Some(format!(
+                    "| `{}` | {}{} | {} |",
+                    key,
+                    template.name,
+                    format_source_tag(docs.template_provenance(key)),
+                    summarize(&template.description)
+                ))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        
+        let content = format!(
+            "| Template | Name | Summary |\n|----------|------|---------|\n{}\n\n\
+            Call `get_adk_template` with `template_name` set to a key above for the full file set.",
+            rows
+        );
+        
+        Ok(format_documentation_response(
+            "Project Templates",
+            &content,
+            &resolved_version,
+            &references,
+        ))
+    }
+    
+    /// Sorted project template keys for a version
+    fn template_keys(&self, version: &str) -> Vec<String> {
+        let mut keys: Vec<String> = self.knowledge_base
+            .get_version_docs(version)
+            .map(|docs| docs.templates.keys().cloned().collect())
+            .unwrap_or_default();
+        keys.sort();
+        keys
+    }
+    
+    /// Get ADK glossary definitions, either a single term or the full table
+    pub async fn get_glossary(&self, term: Option<&str>, version: Option<&str>) -> anyhow::Result<String> {
+        let resolved_version = version
+            .map(|v| self.knowledge_base.resolve_version(v))
+            .unwrap_or_else(|| self.knowledge_base.default_version.clone());
         
-        Ok(format!(
-            "Based on the official Google ADK documentation (version {}), here's information about '{}':\n\n\
-            This query is processed using the comprehensive ADK knowledge base with version-aware \
-            information retrieval. The system provides accurate information with official documentation \
-            references and implementation guidance following Google ADK best practices.\n\n\
-            For detailed information, please refer to the official documentation links provided below.",
-            version,
-            query
+        let entries = match term {
+            Some(term) => match self.knowledge_base.get_glossary_entry(term, Some(&resolved_version)) {
+                Some(entry) => vec![entry],
+                None => {
+                    let available = self.knowledge_base.get_glossary(Some(&resolved_version))
+                        .iter()
+                        .map(|entry| entry.term.clone())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    return Ok(format!(
+                        "Term '{}' is not in the ADK glossary for version {}. Available terms: {}",
+                        term,
+                        resolved_version,
+                        available
+                    ));
+                }
+            },
+            None => self.knowledge_base.get_glossary(Some(&resolved_version)),
+        };
+        
+        let rows = entries
+            .iter()
+            .map(|entry| {
+                let see_also = entry.concept_ref
+                    .as_ref()
+                    .map(|c| format!("`{}`", c))
+                    .unwrap_or_default();
+                format!("| **{}** | {} | {} |", entry.term, entry.definition, see_also)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        
+        let content = format!("| Term | Definition | Concept |\n|------|------------|---------|\n{}", rows);
+        let references = self.reference_generator.generate_category_references("quickstart", Some(&resolved_version));
+        
+        Ok(format_documentation_response(
+            &format!("Glossary{}", term.map(|t| format!(" - {}", t)).unwrap_or_default()),
+            &content,
+            &resolved_version,
+            &references,
         ))
     }
+
+}
+
+/// Documentation expert and best practices enforcer shared across tool calls
+///
+/// Built once per server so each request reuses the same knowledge base instead
+/// of rebuilding it. Both experts are updated together when new docs arrive.
+#[derive(Clone)]
+pub struct SharedExperts {
+    /// Shared documentation expert
+    pub documentation: Arc<RwLock<DocumentationExpert>>,
+    /// Shared best practices enforcer
+    pub enforcer: Arc<RwLock<BestPracticesEnforcer>>,
+    /// Upstream documentation servers queried alongside the knowledge base
+    pub federation: Arc<Federation>,
+    /// Compliance scores recorded per project
+    pub history: Arc<ValidationHistory>,
+}
+
+impl SharedExperts {
+    /// Create shared experts over the default knowledge base
+    pub fn new() -> Self {
+        Self::with_knowledge_base(AdkKnowledgeBase::new())
+    }
+    
+    /// Create shared experts over an existing knowledge base
+    pub fn with_knowledge_base(knowledge_base: AdkKnowledgeBase) -> Self {
+        Self::with_documentation(DocumentationExpert::with_knowledge_base(knowledge_base))
+    }
+    
+    /// Create shared experts around an existing documentation expert, e.g. one backed by a store
+    pub fn with_documentation(documentation: DocumentationExpert) -> Self {
+        let enforcer = BestPracticesEnforcer::with_knowledge_base(documentation.knowledge_base.clone());
+        let history = ValidationHistory::new(documentation.store.clone());
+        
+        Self {
+            documentation: Arc::new(RwLock::new(documentation)),
+            enforcer: Arc::new(RwLock::new(enforcer)),
+            federation: Arc::new(Federation::default()),
+            history: Arc::new(history),
+        }
+    }
+    
+    /// Query upstream documentation servers alongside the knowledge base
+    pub fn with_federation(mut self, federation: Federation) -> Self {
+        self.federation = Arc::new(federation);
+        self
+    }
+    
+    /// Record validation history in `history` instead of the default one
+    pub fn with_validation_history(mut self, history: ValidationHistory) -> Self {
+        self.history = Arc::new(history);
+        self
+    }
+    
+    /// Whether scheduled updates are enabled in the shared version configuration
+    pub async fn auto_update_enabled(&self) -> bool {
+        self.documentation.read().await.knowledge_base.version_config.auto_update_enabled
+    }
+    
+    /// Reject an unknown requested version when strict version validation is enabled
+    pub async fn validate_version(&self, version: Option<&str>) -> anyhow::Result<()> {
+        if let Some(version) = version {
+            self.documentation.read().await.knowledge_base.validate_version(version)?;
+        }
+        Ok(())
+    }
+    
+    /// Validate several designs concurrently, returning their results in input order
+    ///
+    /// Fails when any design fails to validate, e.g. because the requested pattern is unknown.
+    pub async fn validate_architectures(
+        &self,
+        designs: Vec<ArchitectureDesign>,
+        version: Option<String>,
+        options: ValidationOptions,
+    ) -> anyhow::Result<Vec<DesignValidation>> {
+        let mut validations = tokio::task::JoinSet::new();
+        for (index, design) in designs.into_iter().enumerate() {
+            let enforcer = Arc::clone(&self.enforcer);
+            let version = version.clone();
+            let options = options.clone();
+            validations.spawn(async move {
+                let result = enforcer
+                    .read()
+                    .await
+                    .validate_architecture_with(&design.description, design.code_snippets.as_deref(), version.as_deref(), &options)
+                    .await;
+                let name = design.name.unwrap_or_else(|| format!("Design {}", index + 1));
+                (index, name, result)
+            });
+        }
+        
+        let mut results = Vec::new();
+        while let Some(joined) = validations.join_next().await {
+            let (index, name, result) = joined?;
+            let result = result.map_err(|e| anyhow::anyhow!("Design '{}': {}", name, e))?;
+            results.push((index, DesignValidation { name, result }));
+        }
+        
+        results.sort_by_key(|(index, _)| *index);
+        Ok(results.into_iter().map(|(_, validation)| validation).collect())
+    }
+    
+    /// Replace the rules the best practices enforcer validates architectures against
+    pub async fn set_validation_rules(&self, rules: ValidationRules) {
+        self.enforcer.write().await.validation_rules = rules;
+    }
+    
+    /// Add versions and repoint aliases in both experts
+    pub async fn apply_version_overrides(&self, overrides: VersionOverrides) -> anyhow::Result<()> {
+        let mut documentation = self.documentation.write().await;
+        documentation.apply_version_overrides(overrides)?;
+        
+        self.enforcer.write().await.knowledge_base = documentation.knowledge_base.clone();
+        Ok(())
+    }
+    
+    /// Merge new documentation into both experts, persisting it when a store is configured
+    ///
+    /// When a version fails to merge, the versions merged before it still reach the enforcer.
+    pub async fn merge_version_docs(&self, updates: Vec<VersionDocs>) -> anyhow::Result<()> {
+        let mut documentation = self.documentation.write().await;
+        let merged = updates.into_iter().try_for_each(|docs| documentation.persist_version_docs(docs));
+        
+        self.enforcer.write().await.knowledge_base = documentation.knowledge_base.clone();
+        merged
+    }
+    
+    /// Merge a snapshot into both experts
+    pub async fn import_snapshot(&self, snapshot: KnowledgeSnapshot) -> anyhow::Result<ImportReport> {
+        let mut documentation = self.documentation.write().await;
+        let report = documentation.import_snapshot(snapshot)?;
+        
+        self.enforcer.write().await.knowledge_base = documentation.knowledge_base.clone();
+        Ok(report)
+    }
+}
+
+impl Default for SharedExperts {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Default for DocumentationExpert {
@@ -0,0 +1,330 @@
+//! Multi-agent orchestration checks
+//!
+//! When a description or its snippets indicate several agents, `validate_architecture`
+//! builds the delegation topology between them: from the component graph inferred from
+//! the description, and from agent definitions in snippets (`sub_agents=[...]`). Deep
+//! delegation chains, circular sub-agent references, teams without a coordinator, and
+//! parallel agents writing the same state key are reported as findings.
+
+use std::collections::HashMap;
+use regex::{Regex, RegexBuilder};
+use crate::expert::architecture_diagram::{ComponentGraph, ComponentKind};
+use crate::expert::best_practices::{ValidationFinding, ValidationSeverity, find_affirmative_match};
+
+/// Longest chain of agents delegating to one another before routing becomes unreliable
+pub const MAX_DELEGATION_DEPTH: usize = 3;
+
+/// Words naming an agent that coordinates others, or describing delegation between agents
+const COORDINATOR_PATTERN: &str = r"\b(?:root|coordinat\w*|orchestrat\w*|router|routes|routing|supervis\w*|manager|dispatch\w*|triage|parent|delegat\w*|transfers?|hands?\s+off)\b";
+
+/// Phrases indicating a multi-agent design even when fewer than two agents are named
+const MULTI_AGENT_PATTERN: &str = r"\bmulti-?agent\b|\bsub-?agents?\b|\b(?:sequential|parallel|loop)\s?agents?\b|\bagent\s+team\b";
+
+/// Parallel agents described as writing to one shared state entry
+const SHARED_STATE_PATTERN: &str = r"\bparallel(?:\s?agents?)?\b[^.;]*\b(?:same|shared|single|common)\s+(?:session\s+)?(?:state\s+)?(?:keys?|output_keys?|state(?:\s+entry)?|variables?)\b";
+
+/// Python agent constructions, e.g. `billing = LlmAgent(`
+const AGENT_DEFINITION_PATTERN: &str = r"(?m)^\s*(\w+)\s*=\s*(LlmAgent|Agent|SequentialAgent|ParallelAgent|LoopAgent)\s*\(";
+
+/// Agent defined in a code snippet
+#[derive(Clone, Debug, PartialEq)]
+pub struct AgentDefinition {
+    /// Variable the agent is assigned to
+    pub variable: String,
+    /// Agent class, e.g. "ParallelAgent"
+    pub class: String,
+    /// Variables of the agent's sub-agents
+    pub sub_agents: Vec<String>,
+    /// State key the agent writes its output to
+    pub output_key: Option<String>,
+}
+
+/// Agents of a design and who delegates to whom
+#[derive(Clone, Debug, Default)]
+pub struct AgentTopology {
+    /// Agent identifiers in order of first mention
+    pub agents: Vec<String>,
+    /// Delegation edges as (parent, child) agent identifiers
+    pub edges: Vec<(String, String)>,
+    /// Agents defined in code snippets
+    pub definitions: Vec<AgentDefinition>,
+    /// Whether the design names a coordinating agent or delegation between agents
+    pub has_coordinator: bool,
+    /// Whether the design explicitly calls itself multi-agent
+    pub declared_multi_agent: bool,
+}
+
+impl AgentTopology {
+    /// Build the topology of a description and its code snippets
+    pub fn new(description: &str, code_snippets: &[String]) -> Self {
+        let graph = ComponentGraph::from_description(description);
+        let mut topology = AgentTopology::default();
+        
+        for component in graph.components.iter().filter(|component| component.kind == ComponentKind::Agent) {
+            topology.add_agent(&component.id);
+        }
+        for (from, to) in &graph.edges {
+            if topology.agents.contains(from) && topology.agents.contains(to) {
+                topology.add_edge(from, to);
+            }
+        }
+        
+        let definitions: Vec<AgentDefinition> = code_snippets.iter().flat_map(|snippet| agent_definitions(snippet)).collect();
+        for definition in &definitions {
+            topology.add_agent(&definition.variable);
+            for sub_agent in &definition.sub_agents {
+                topology.add_agent(sub_agent);
+                topology.add_edge(&definition.variable, sub_agent);
+            }
+        }
+        
+        let coordinator = case_insensitive(COORDINATOR_PATTERN);
+        let multi_agent = case_insensitive(MULTI_AGENT_PATTERN);
+        topology.has_coordinator = find_affirmative_match(&coordinator, description).is_some()
+            || definitions.iter().any(|definition| !definition.sub_agents.is_empty() || definition.variable == "root_agent");
+        topology.declared_multi_agent = find_affirmative_match(&multi_agent, description).is_some();
+        topology.definitions = definitions;
+        topology
+    }
+    
+    /// Whether the design has several agents
+    pub fn is_multi_agent(&self) -> bool {
+        self.agents.len() > 1 || self.declared_multi_agent
+    }
+    
+    /// Longest delegation chain without repeating an agent, e.g. ["root", "billing", "refunds"]
+    pub fn longest_delegation_chain(&self) -> Vec<String> {
+        let mut longest = Vec::new();
+        for agent in &self.agents {
+            let mut path = vec![agent.clone()];
+            self.extend_chain(&mut path, &mut longest);
+        }
+        longest
+    }
+    
+    /// First circular delegation found, starting and ending with the same agent
+    pub fn delegation_cycle(&self) -> Option<Vec<String>> {
+        self.agents.iter().find_map(|agent| {
+            let mut path = vec![agent.clone()];
+            self.find_cycle(&mut path)
+        })
+    }
+    
+    /// State keys written by more than one sub-agent of the same parallel agent, with those sub-agents
+    pub fn contended_state_keys(&self) -> Vec<(String, Vec<String>)> {
+        let output_keys: HashMap<&str, &str> = self
+            .definitions
+            .iter()
+            .filter_map(|definition| Some((definition.variable.as_str(), definition.output_key.as_deref()?)))
+            .collect();
+        
+        let mut contended: Vec<(String, Vec<String>)> = Vec::new();
+        for parallel in self.definitions.iter().filter(|definition| definition.class == "ParallelAgent") {
+            let mut writers: HashMap<&str, Vec<String>> = HashMap::new();
+            for sub_agent in &parallel.sub_agents {
+                if let Some(key) = output_keys.get(sub_agent.as_str()) {
+                    writers.entry(key).or_default().push(sub_agent.clone());
+                }
+            }
+            let mut shared: Vec<(String, Vec<String>)> = writers
+                .into_iter()
+                .filter(|(_, writers)| writers.len() > 1)
+                .map(|(key, writers)| (key.to_string(), writers))
+                .collect();
+            shared.sort();
+            contended.extend(shared);
+        }
+        contended
+    }
+    
+    /// Findings for the orchestration rules, or none when the design has a single agent
+    pub fn findings(&self, description: &str) -> Vec<ValidationFinding> {
+        if !self.is_multi_agent() {
+            return Vec::new();
+        }
+        let mut findings = Vec::new();
+        
+        let chain = self.longest_delegation_chain();
+        if chain.len() > MAX_DELEGATION_DEPTH + 1 {
+            findings.push(orchestration_finding(
+                "delegation_depth",
+                ValidationSeverity::Warning,
+                format!(
+                    "Delegation depth: requests pass through {} levels of agents ({}); each hop adds a model turn and another chance to route wrongly",
+                    chain.len() - 1,
+                    chain.join(" → ")
+                ),
+                format!("Keep delegation to at most {} levels by flattening specialists under one coordinator or using workflow agents", MAX_DELEGATION_DEPTH),
+            ));
+        }
+        
+        if let Some(cycle) = self.delegation_cycle() {
+            findings.push(orchestration_finding(
+                "circular_sub_agents",
+                ValidationSeverity::Error,
+                format!("Circular sub-agent reference: {}; an agent can only have one parent", cycle.join(" → ")),
+                "Make delegation a tree: return control with transfer_to_agent or an AgentTool instead of listing the parent as a sub-agent".to_string(),
+            ));
+        }
+        
+        if !self.has_coordinator && self.agents.len() > 1 {
+            findings.push(orchestration_finding(
+                "missing_root_agent",
+                ValidationSeverity::Warning,
+                format!(
+                    "Missing coordinator: {} agents are named but none routes requests between them",
+                    self.agents.len()
+                ),
+                "Add a root agent whose sub_agents are the specialists, with instructions on when to transfer to each".to_string(),
+            ));
+        }
+        
+        let shared_state = case_insensitive(SHARED_STATE_PATTERN);
+        if find_affirmative_match(&shared_state, description).is_some() {
+            findings.push(orchestration_finding(
+                "parallel_state_contention",
+                ValidationSeverity::Warning,
+                "Shared-state contention: parallel agents write the same state entry, so the last one to finish overwrites the others".to_string(),
+                "Give each parallel sub-agent its own output_key and merge the results in a following agent".to_string(),
+            ));
+        }
+        for (key, writers) in self.contended_state_keys() {
+            findings.push(ValidationFinding {
+                id: format!("orchestration_parallel_state_contention_{}", key),
+                rule_id: "parallel_state_contention".to_string(),
+                severity: ValidationSeverity::Error,
+                description: format!(
+                    "Shared-state contention: parallel sub-agents {} all write output_key \"{}\"",
+                    writers.iter().map(|writer| format!("`{}`", writer)).collect::<Vec<_>>().join(", "),
+                    key
+                ),
+                location: Some("Code Snippets".to_string()),
+                suggested_fix: Some("Give each parallel sub-agent its own output_key and merge the results in a following agent".to_string()),
+            });
+        }
+        
+        findings
+    }
+    
+    /// Add an agent unless already present
+    fn add_agent(&mut self, agent: &str) {
+        if !self.agents.iter().any(|existing| existing == agent) {
+            self.agents.push(agent.to_string());
+        }
+    }
+    
+    /// Add a delegation edge unless already present
+    fn add_edge(&mut self, from: &str, to: &str) {
+        let edge = (from.to_string(), to.to_string());
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+    
+    /// Agents `agent` delegates to
+    fn children<'a>(&'a self, agent: &'a str) -> impl Iterator<Item = &'a String> + 'a {
+        self.edges.iter().filter(move |(from, _)| from == agent).map(|(_, to)| to)
+    }
+    
+    /// Extend `path` depth-first, keeping the longest chain seen in `longest`
+    fn extend_chain(&self, path: &mut Vec<String>, longest: &mut Vec<String>) {
+        if path.len() > longest.len() {
+            *longest = path.clone();
+        }
+        let last = path.last().cloned().unwrap_or_default();
+        for child in self.children(&last) {
+            if !path.contains(child) {
+                path.push(child.clone());
+                self.extend_chain(path, longest);
+                path.pop();
+            }
+        }
+    }
+    
+    /// Search depth-first for a path returning to its first agent
+    fn find_cycle(&self, path: &mut Vec<String>) -> Option<Vec<String>> {
+        let last = path.last().cloned().unwrap_or_default();
+        for child in self.children(&last) {
+            if *child == path[0] {
+                let mut cycle = path.clone();
+                cycle.push(child.clone());
+                return Some(cycle);
+            }
+            if !path.contains(child) {
+                path.push(child.clone());
+                if let Some(cycle) = self.find_cycle(path) {
+                    return Some(cycle);
+                }
+                path.pop();
+            }
+        }
+        None
+    }
+}
+
+/// Agents defined in a Python snippet with their sub-agents and output keys
+pub fn agent_definitions(snippet: &str) -> Vec<AgentDefinition> {
+    let definition = Regex::new(AGENT_DEFINITION_PATTERN).expect("agent definition pattern is valid");
+    let sub_agents = Regex::new(r"\bsub_agents\s*=\s*\[([^\]]*)\]").expect("sub-agents pattern is valid");
+    let output_key = Regex::new(r#"\boutput_key\s*=\s*["']([^"']+)["']"#).expect("output key pattern is valid");
+    let identifier = Regex::new(r"^[A-Za-z_]\w*$").expect("identifier pattern is valid");
+    
+    definition
+        .captures_iter(snippet)
+        .map(|captures| {
+            let whole = captures.get(0).expect("match has a whole capture");
+            let arguments = call_arguments(&snippet[whole.end()..]);
+            AgentDefinition {
+                variable: captures[1].to_string(),
+                class: captures[2].to_string(),
+                sub_agents: sub_agents
+                    .captures(arguments)
+                    .map(|list| {
+                        list[1]
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|entry| identifier.is_match(entry))
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                output_key: output_key.captures(arguments).map(|key| key[1].to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Arguments of a call whose opening parenthesis precedes `text`
+fn call_arguments(text: &str) -> &str {
+    let mut depth = 1;
+    for (offset, ch) in text.char_indices() {
+        match ch {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return &text[..offset];
+                }
+            }
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Finding for an orchestration rule about the description as a whole
+fn orchestration_finding(rule_id: &str, severity: ValidationSeverity, description: String, suggested_fix: String) -> ValidationFinding {
+    ValidationFinding {
+        id: format!("orchestration_{}", rule_id),
+        rule_id: rule_id.to_string(),
+        severity,
+        description,
+        location: Some("Agent Topology".to_string()),
+        suggested_fix: Some(suggested_fix),
+    }
+}
+
+/// Compile a built-in pattern case-insensitively
+fn case_insensitive(pattern: &str) -> Regex {
+    RegexBuilder::new(pattern).case_insensitive(true).build().expect("orchestration pattern is valid")
+}
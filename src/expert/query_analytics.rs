@@ -0,0 +1,148 @@
+//! Anonymized analytics over documentation queries
+//!
+//! Only normalized keywords are recorded, never the raw query text, together with
+//! whether the knowledge base could answer the query. The most-asked and most-missed
+//! terms show maintainers which knowledge areas to expand.
+
+use std::collections::HashMap;
+use crate::expert::adk_knowledge::query_terms;
+
+/// Default number of distinct terms tracked before the least-asked ones are evicted
+pub const DEFAULT_MAX_TRACKED_TERMS: usize = 1000;
+
+/// Counters for a single query term
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TermStats {
+    /// Number of queries containing the term
+    pub asked: u64,
+    /// Number of those queries the knowledge base could not answer
+    pub missed: u64,
+}
+
+/// Most-asked and most-missed terms with overall query totals
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnalyticsReport {
+    /// Number of queries recorded
+    pub total_queries: u64,
+    /// Number of queries the knowledge base could not answer
+    pub unanswered_queries: u64,
+    /// Number of distinct terms currently tracked
+    pub tracked_terms: usize,
+    /// Terms ordered by how often they were asked
+    pub most_asked: Vec<(String, TermStats)>,
+    /// Terms ordered by how often they went unanswered
+    pub most_missed: Vec<(String, TermStats)>,
+}
+
+impl AnalyticsReport {
+    /// Share of queries the knowledge base could not answer, as a percentage
+    pub fn miss_rate(&self) -> f64 {
+        if self.total_queries == 0 {
+            return 0.0;
+        }
+        (self.unanswered_queries as f64 / self.total_queries as f64) * 100.0
+    }
+}
+
+/// Per-term counters of asked and unanswered queries
+#[derive(Debug)]
+pub struct QueryAnalytics {
+    /// Maximum number of distinct terms tracked
+    max_terms: usize,
+    /// Counters keyed by normalized term
+    terms: HashMap<String, TermStats>,
+    /// Number of queries recorded
+    total_queries: u64,
+    /// Number of queries the knowledge base could not answer
+    unanswered_queries: u64,
+}
+
+impl QueryAnalytics {
+    /// Create analytics tracking at most `max_terms` distinct terms
+    pub fn new(max_terms: usize) -> Self {
+        Self {
+            max_terms,
+            terms: HashMap::new(),
+            total_queries: 0,
+            unanswered_queries: 0,
+        }
+    }
+    
+    /// Record a query's terms and whether the knowledge base answered it
+    pub fn record(&mut self, query: &str, answered: bool) {
+        self.total_queries += 1;
+        if !answered {
+            self.unanswered_queries += 1;
+        }
+        
+        for term in query_terms(query) {
+            if !self.terms.contains_key(&term) && !self.make_room() {
+                continue;
+            }
+            let stats = self.terms.entry(term).or_default();
+            stats.asked += 1;
+            if !answered {
+                stats.missed += 1;
+            }
+        }
+    }
+    
+    /// Report the `limit` most-asked and most-missed terms
+    pub fn report(&self, limit: usize) -> AnalyticsReport {
+        let mut most_asked: Vec<(String, TermStats)> = self.terms
+            .iter()
+            .map(|(term, stats)| (term.clone(), *stats))
+            .collect();
+        most_asked.sort_by(|a, b| b.1.asked.cmp(&a.1.asked).then_with(|| a.0.cmp(&b.0)));
+        
+        let mut most_missed: Vec<(String, TermStats)> = most_asked
+            .iter()
+            .filter(|(_, stats)| stats.missed > 0)
+            .cloned()
+            .collect();
+        most_missed.sort_by(|a, b| b.1.missed.cmp(&a.1.missed).then_with(|| a.0.cmp(&b.0)));
+        
+        most_asked.truncate(limit);
+        most_missed.truncate(limit);
+        
+        AnalyticsReport {
+            total_queries: self.total_queries,
+            unanswered_queries: self.unanswered_queries,
+            tracked_terms: self.terms.len(),
+            most_asked,
+            most_missed,
+        }
+    }
+    
+    /// Drop every recorded term and counter
+    pub fn reset(&mut self) {
+        self.terms.clear();
+        self.total_queries = 0;
+        self.unanswered_queries = 0;
+    }
+    
+    /// Evict the least-asked term when full, returning whether a new term fits
+    fn make_room(&mut self) -> bool {
+        if self.max_terms == 0 {
+            return false;
+        }
+        if self.terms.len() < self.max_terms {
+            return true;
+        }
+        
+        let least_asked = self.terms
+            .iter()
+            .min_by(|a, b| a.1.asked.cmp(&b.1.asked).then_with(|| b.0.cmp(a.0)))
+            .map(|(term, _)| term.clone());
+        if let Some(term) = least_asked {
+            self.terms.remove(&term);
+        }
+        true
+    }
+}
+
+impl Default for QueryAnalytics {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_TRACKED_TERMS)
+    }
+}
@@ -0,0 +1,142 @@
+//! Bounded LRU cache for rendered documentation query responses
+
+use std::collections::{HashMap, VecDeque};
+
+/// Default number of cached responses
+pub const DEFAULT_CACHE_CAPACITY: usize = 256;
+
+/// Cache key: normalized query text and resolved version
+type CacheKey = (String, String);
+
+/// Least-recently-used cache of query responses keyed on (normalized query, version)
+#[derive(Debug)]
+pub struct QueryCache<V = String> {
+    /// Maximum number of cached responses
+    capacity: usize,
+    /// Cached responses
+    entries: HashMap<CacheKey, V>,
+    /// Keys from least to most recently used
+    order: VecDeque<CacheKey>,
+    /// Number of lookups answered from the cache
+    hits: u64,
+    /// Number of lookups that missed the cache
+    misses: u64,
+}
+
+/// Snapshot of cache usage for monitoring
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of lookups answered from the cache
+    pub hits: u64,
+    /// Number of lookups that missed the cache
+    pub misses: u64,
+    /// Number of responses currently cached
+    pub entries: usize,
+    /// Maximum number of cached responses
+    pub capacity: usize,
+}
+
+impl CacheStats {
+    /// Share of lookups answered from the cache, as a percentage
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            return 0.0;
+        }
+        (self.hits as f64 / total as f64) * 100.0
+    }
+}
+
+impl<V: Clone> QueryCache<V> {
+    /// Create a cache holding at most `capacity` responses (zero disables caching)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+    
+    /// Look up a cached response, marking it most recently used
+    pub fn get(&mut self, query: &str, version: &str) -> Option<V> {
+        let key = cache_key(query, version);
+        match self.entries.get(&key) {
+            Some(response) => {
+                let response = response.clone();
+                self.hits += 1;
+                self.touch(&key);
+                Some(response)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+    
+    /// Cache a response, evicting the least recently used entry when full
+    pub fn insert(&mut self, query: &str, version: &str, response: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        
+        let key = cache_key(query, version);
+        if self.entries.insert(key.clone(), response).is_some() {
+            self.touch(&key);
+            return;
+        }
+        
+        self.order.push_back(key);
+        while self.entries.len() > self.capacity {
+            match self.order.pop_front() {
+                Some(oldest) => {
+                    self.entries.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+    }
+    
+    /// Drop every cached response, keeping the hit counters
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+    
+    /// Current usage statistics
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.entries.len(),
+            capacity: self.capacity,
+        }
+    }
+    
+    /// Move a key to the most recently used position
+    fn touch(&mut self, key: &CacheKey) {
+        if let Some(index) = self.order.iter().position(|k| k == key) {
+            if let Some(key) = self.order.remove(index) {
+                self.order.push_back(key);
+            }
+        }
+    }
+}
+
+impl<V: Clone> Default for QueryCache<V> {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_CAPACITY)
+    }
+}
+
+/// Normalize a query so trivially different phrasings share a cache entry
+fn cache_key(query: &str, version: &str) -> CacheKey {
+    let normalized = query
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ");
+    (normalized, version.to_string())
+}
@@ -0,0 +1,74 @@
+//! Query intent classification for routing documentation queries
+
+use serde::{Deserialize, Serialize};
+
+/// Intent behind a documentation query, used to pick the answering sub-system
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum QueryIntent {
+    /// "What is X?" style lookups answered from concepts
+    ConceptLookup,
+    /// "How do I X?" requests answered from implementation patterns
+    HowTo,
+    /// Error and failure reports answered from practices and concepts
+    Troubleshooting,
+    /// Requests for recommendations answered from best practices
+    BestPractice,
+    /// Upgrade and deprecation questions answered from version features
+    Migration,
+}
+
+impl QueryIntent {
+    /// Human-readable label for display in responses
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::ConceptLookup => "Concept Lookup",
+            Self::HowTo => "How-To",
+            Self::Troubleshooting => "Troubleshooting",
+            Self::BestPractice => "Best Practice",
+            Self::Migration => "Migration",
+        }
+    }
+}
+
+/// Phrases indicating that something is broken
+const TROUBLESHOOTING_MARKERS: &[&str] = &[
+    "error", "fail", "not working", "doesn't work", "does not work", "broken",
+    "debug", "troubleshoot", "exception", "crash", "why does", "why is", "issue",
+];
+
+/// Phrases indicating an upgrade or deprecation question
+const MIGRATION_MARKERS: &[&str] = &[
+    "migrate", "migration", "upgrade", "deprecat", "breaking change", "changelog",
+];
+
+/// Phrases indicating a request for recommendations
+const BEST_PRACTICE_MARKERS: &[&str] = &[
+    "best practice", "should i", "recommend", "guideline", "avoid", "pitfall",
+];
+
+/// Phrases indicating a request for implementation steps
+const HOW_TO_MARKERS: &[&str] = &[
+    "how to", "how do", "how can", "example", "implement", "set up", "setup",
+    "configure", "create", "build", "write",
+];
+
+/// Classify a query by the first matching intent, checked from most to least specific
+///
+/// Troubleshooting and migration take precedence because phrases like
+/// "how do I fix this error" are really failure reports, not how-to requests.
+pub fn classify_query(query: &str) -> QueryIntent {
+    let query_lower = query.to_lowercase();
+    let has_marker = |markers: &[&str]| markers.iter().any(|marker| query_lower.contains(marker));
+    
+    if has_marker(TROUBLESHOOTING_MARKERS) {
+        QueryIntent::Troubleshooting
+    } else if has_marker(MIGRATION_MARKERS) {
+        QueryIntent::Migration
+    } else if has_marker(BEST_PRACTICE_MARKERS) {
+        QueryIntent::BestPractice
+    } else if has_marker(HOW_TO_MARKERS) {
+        QueryIntent::HowTo
+    } else {
+        QueryIntent::ConceptLookup
+    }
+}
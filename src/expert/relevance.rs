@@ -0,0 +1,162 @@
+//! BM25 relevance ranking for scenario matching
+//!
+//! `get_best_practices` ranks practices and implementation patterns against a free-text
+//! scenario. Entries are indexed by stemmed terms with their title counting more than
+//! their body, and query terms are expanded with related vocabulary so "error handling"
+//! also finds "Robust failure recovery". Terms found in most entries, like "agent", do
+//! not count toward relevance, and results scoring well below the best match are dropped.
+
+use std::collections::HashMap;
+
+/// Term frequency saturation
+const K1: f32 = 1.2;
+
+/// Document length normalization
+const B: f32 = 0.75;
+
+/// Weight of a related term relative to the query term it expands
+const RELATED_TERM_WEIGHT: f32 = 0.5;
+
+/// Fraction of the best score a result needs to be returned
+const MIN_RELATIVE_SCORE: f32 = 0.35;
+
+/// Words carrying no meaning for ranking
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "can", "do", "does", "for", "from", "how", "i", "in", "into",
+    "is", "it", "its", "my", "of", "on", "or", "our", "so", "that", "the", "their", "them", "this", "to", "use",
+    "we", "what", "when", "which", "with", "you", "your", "adk", "google",
+];
+
+/// Groups of terms close enough in meaning to stand in for each other in a scenario
+const RELATED_TERMS: &[&[&str]] = &[
+    &["error", "failure", "fail", "exception", "fault", "recovery", "retry", "backoff"],
+    &["test", "testing", "evaluation", "eval"],
+    &["deploy", "deployment", "hosting", "production", "release"],
+    &["secret", "credential", "password"],
+    &["latency", "performance", "speed", "fast", "slow", "throughput"],
+    &["session", "state", "memory", "persistence"],
+    &["guardrail", "safety", "policy", "moderation"],
+    &["stream", "streaming", "live", "realtime"],
+    &["tool", "function"],
+    &["orchestration", "coordinator", "delegation", "delegate"],
+    &["observability", "tracing", "logging", "monitoring"],
+];
+
+/// BM25 index over entries made of weighted text fields
+#[derive(Clone, Debug, Default)]
+pub struct RelevanceIndex {
+    /// Weighted term frequencies of each entry
+    entries: Vec<HashMap<String, f32>>,
+    /// Weighted length of each entry
+    lengths: Vec<f32>,
+    /// Number of entries containing each term
+    entry_frequency: HashMap<String, usize>,
+}
+
+impl RelevanceIndex {
+    /// Index entries given as `(text, weight)` fields, such as a title weighted above a description
+    pub fn new<'a, E>(entries: impl IntoIterator<Item = E>) -> Self
+    where
+        E: IntoIterator<Item = (&'a str, f32)>,
+    {
+        let mut index = Self::default();
+        for fields in entries {
+            let mut frequencies: HashMap<String, f32> = HashMap::new();
+            for (text, weight) in fields {
+                for term in tokenize(text) {
+                    *frequencies.entry(term).or_default() += weight;
+                }
+            }
+            for term in frequencies.keys() {
+                *index.entry_frequency.entry(term.clone()).or_default() += 1;
+            }
+            index.lengths.push(frequencies.values().sum());
+            index.entries.push(frequencies);
+        }
+        index
+    }
+    
+    /// Indices of the entries relevant to `query` with their scores, best first
+    ///
+    /// A query without meaningful terms matches nothing.
+    pub fn rank(&self, query: &str) -> Vec<(usize, f32)> {
+        let query_terms = expand_query(query);
+        if query_terms.is_empty() || self.entries.is_empty() {
+            return Vec::new();
+        }
+        
+        let count = self.entries.len() as f32;
+        let average_length = (self.lengths.iter().sum::<f32>() / count).max(1.0);
+        let mut ranked: Vec<(usize, f32)> = self
+            .entries
+            .iter()
+            .zip(&self.lengths)
+            .enumerate()
+            .map(|(index, (frequencies, length))| {
+                let score = query_terms
+                    .iter()
+                    .flatten()
+                    .filter_map(|(term, weight)| {
+                        let frequency = *frequencies.get(term)?;
+                        let containing = self.entry_frequency[term] as f32;
+                        let idf = ((count - containing + 0.5) / (containing + 0.5)).ln().max(0.0);
+                        let saturation = frequency * (K1 + 1.0) / (frequency + K1 * (1.0 - B + B * length / average_length));
+                        Some(weight * idf * saturation)
+                    })
+                    .sum::<f32>();
+                (index, score)
+            })
+            .filter(|(_, score)| *score > 0.0)
+            .collect();
+        
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal).then(a.0.cmp(&b.0)));
+        let cutoff = ranked.first().map_or(0.0, |(_, best)| best * MIN_RELATIVE_SCORE);
+        ranked.retain(|(_, score)| *score >= cutoff);
+        ranked
+    }
+}
+
+/// Split text into stemmed terms, dropping stop words
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.len() > 1 && !STOP_WORDS.contains(word))
+        .map(stem)
+        .collect()
+}
+
+/// Reduce a lowercase word to a crude stem so "handling", "handled", and "handles" agree
+fn stem(word: &str) -> String {
+    let strip = |word: &str, suffix: &str| word.strip_suffix(suffix).filter(|rest| rest.len() > 2).map(str::to_string);
+    
+    // Plurals first, then verb and noun endings, so "evaluations" and "evaluated" agree
+    let mut stem = match strip(word, "ies") {
+        Some(rest) => format!("{}y", rest),
+        None if word.ends_with("ss") => word.to_string(),
+        None => strip(word, "es").or_else(|| strip(word, "s")).unwrap_or_else(|| word.to_string()),
+    };
+    if let Some(rest) = ["ing", "ed", "ion"].iter().find_map(|suffix| strip(&stem, suffix)) {
+        stem = rest;
+    }
+    strip(&stem, "e").unwrap_or(stem)
+}
+
+/// Each distinct query term with its weight, followed by its related terms at a reduced weight
+fn expand_query(query: &str) -> Vec<Vec<(String, f32)>> {
+    let mut query_terms: Vec<Vec<(String, f32)>> = Vec::new();
+    for term in tokenize(query) {
+        if query_terms.iter().any(|terms| terms[0].0 == term) {
+            continue;
+        }
+        
+        let mut terms = vec![(term.clone(), 1.0)];
+        for group in RELATED_TERMS {
+            let group_terms: Vec<String> = group.iter().map(|related| stem(related)).collect();
+            if group_terms.contains(&term) {
+                terms.extend(group_terms.into_iter().filter(|related| *related != term).map(|related| (related, RELATED_TERM_WEIGHT)));
+            }
+        }
+        query_terms.push(terms);
+    }
+    query_terms
+}
@@ -0,0 +1,149 @@
+//! Validation rule sets loaded from files
+//!
+//! Teams can keep their own ADK compliance rules in version control as JSON or YAML
+//! rule-set files instead of recompiling the server. Rule sets are layered over the
+//! built-in rules in file name order: a rule whose id matches an existing rule replaces
+//! it, and any other rule is added.
+
+use std::path::{Path, PathBuf};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use crate::expert::best_practices::{ArchitectureRule, BestPracticeRule, CodePatternRule, RuleOverrides, ScoringModel, ValidationRules};
+use crate::expert::custom_rules::CustomRule;
+use crate::utils::error::{ArkaftMcpError, ArkaftResult};
+
+/// Environment variable naming a rule-set file or directory
+pub const RULE_SETS_PATH_ENV: &str = "ADK_RULE_SETS";
+
+/// File extensions read as rule sets
+const RULE_SET_EXTENSIONS: &[&str] = &["json", "yaml", "yml"];
+
+/// Validation rules contributed by one rule-set file
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RuleSet {
+    /// Rule set name shown in logs
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Rule set version recorded in compliance reports, e.g. "2024.3"
+    #[serde(default)]
+    pub version: Option<String>,
+    /// Architecture rules to add or replace by id
+    #[serde(default)]
+    pub architecture_rules: Vec<ArchitectureRule>,
+    /// Code pattern rules to add or replace by id
+    #[serde(default)]
+    pub code_pattern_rules: Vec<CodePatternRule>,
+    /// Best practice rules to add or replace by id
+    #[serde(default)]
+    pub best_practice_rules: Vec<BestPracticeRule>,
+    /// User-defined rules to add or replace by id
+    #[serde(default)]
+    pub custom_rules: Vec<CustomRule>,
+    /// Rules to disable or re-enable and severity overrides
+    #[serde(flatten)]
+    pub overrides: RuleOverrides,
+    /// Scoring model replacing the current one
+    #[serde(default)]
+    pub scoring: Option<ScoringModel>,
+}
+
+impl RuleSet {
+    /// Parse a rule set, as YAML when `path` ends in `.yaml` or `.yml` and as JSON otherwise
+    pub fn parse(path: &Path, content: &str) -> ArkaftResult<Self> {
+        let is_yaml = path.extension().is_some_and(|ext| ext == "yaml" || ext == "yml");
+        let rule_set: Self = if is_yaml {
+            serde_yaml::from_str(content).map_err(|e| invalid_rule_set(path, e))?
+        } else {
+            serde_json::from_str(content).map_err(|e| invalid_rule_set(path, e))?
+        };
+        
+        for rule in &rule_set.architecture_rules {
+            rule.regex().map_err(|e| {
+                invalid_rule_set(path, format!("architecture rule '{}' has an invalid pattern: {}", rule.id, e))
+            })?;
+            rule.condition().map_err(|e| {
+                invalid_rule_set(path, format!("architecture rule '{}' has an invalid 'when' regex: {}", rule.id, e))
+            })?;
+        }
+        for rule in &rule_set.best_practice_rules {
+            for (criterion, pattern) in &rule.criteria_patterns {
+                Regex::new(pattern).map_err(|e| {
+                    invalid_rule_set(path, format!("best practice rule '{}' has an invalid pattern for '{}': {}", rule.id, criterion, e))
+                })?;
+            }
+        }
+        for rule in &rule_set.custom_rules {
+            rule.validate().map_err(|e| invalid_rule_set(path, e))?;
+        }
+        
+        Ok(rule_set)
+    }
+    
+    /// Read and parse a rule-set file
+    pub async fn read_from(path: &Path) -> ArkaftResult<Self> {
+        let content = tokio::fs::read_to_string(path).await?;
+        Self::parse(path, &content)
+    }
+    
+    /// Number of rules of every kind in the set
+    pub fn rule_count(&self) -> usize {
+        self.architecture_rules.len() + self.code_pattern_rules.len() + self.best_practice_rules.len() + self.custom_rules.len()
+    }
+}
+
+/// Read every rule set at `path`, a single file or a directory of `.json`, `.yaml`, and `.yml` files in name order
+pub async fn read_rule_sets(path: &Path) -> ArkaftResult<Vec<RuleSet>> {
+    let mut rule_sets = Vec::new();
+    for file in rule_set_files(path).await? {
+        rule_sets.push(RuleSet::read_from(&file).await?);
+    }
+    Ok(rule_sets)
+}
+
+/// Built-in validation rules with the rule sets at `path` layered over them
+///
+/// Fails without applying anything when any rule set is unreadable or invalid.
+pub async fn load_validation_rules(path: &Path) -> ArkaftResult<ValidationRules> {
+    let mut rules = ValidationRules::new();
+    for rule_set in read_rule_sets(path).await? {
+        info!(
+            "Applying rule set '{}' with {} rule(s)",
+            rule_set.name.as_deref().unwrap_or("unnamed"),
+            rule_set.rule_count()
+        );
+        rules.merge_rule_set(rule_set);
+    }
+    for rule_id in rules.overrides.rule_ids().filter(|id| !rules.has_rule(id)) {
+        warn!("Rule overrides name unknown rule '{}'", rule_id);
+    }
+    Ok(rules)
+}
+
+/// List the rule-set files to read, in name order
+async fn rule_set_files(path: &Path) -> ArkaftResult<Vec<PathBuf>> {
+    if !tokio::fs::metadata(path).await?.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    
+    let mut files = Vec::new();
+    let mut entries = tokio::fs::read_dir(path).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let file = entry.path();
+        let is_rule_set = file
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| RULE_SET_EXTENSIONS.contains(&ext));
+        if is_rule_set {
+            files.push(file);
+        }
+    }
+    files.sort();
+    
+    Ok(files)
+}
+
+/// Configuration error for an unusable rule-set file
+fn invalid_rule_set(path: &Path, error: impl std::fmt::Display) -> ArkaftMcpError {
+    ArkaftMcpError::Configuration(format!("Invalid rule set {}: {}", path.display(), error))
+}
@@ -0,0 +1,159 @@
+//! Portable snapshots of the whole knowledge base
+//!
+//! A snapshot captures every loaded version plus the version aliases in a single JSON
+//! file, so a machine with internet access can export the refreshed knowledge base and
+//! an air-gapped deployment can be provisioned from the file.
+//!
+//! Importing merges a snapshot into the running knowledge base. Official entries always
+//! win over community entries; otherwise the newer side wins: a snapshot exported after a
+//! version was last refreshed replaces its entries, while an older snapshot only fills gaps.
+
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::expert::adk_knowledge::{AdkKnowledgeBase, VersionDocs, compare_versions};
+use crate::expert::knowledge_store::unix_timestamp;
+use crate::utils::error::{ArkaftMcpError, ArkaftResult};
+
+/// Snapshot file format version, bumped on incompatible changes
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// Serialized copy of the full knowledge base
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct KnowledgeSnapshot {
+    /// Snapshot file format version
+    pub format_version: u32,
+    /// Server version that produced the snapshot
+    pub generator: String,
+    /// Export time as seconds since the Unix epoch
+    pub exported_at: i64,
+    /// Version used when a request names none
+    pub default_version: String,
+    /// Version aliases (e.g., "stable" -> "1.0.0")
+    pub version_aliases: HashMap<String, String>,
+    /// Documentation for every loaded version, oldest first
+    pub versions: Vec<VersionDocs>,
+}
+
+/// Resolve a snapshot path requested by a client inside the server's data directory
+///
+/// Relative paths are taken from the data directory. Paths with `..` components, and paths
+/// whose nearest existing ancestor resolves outside the directory, e.g. through a symlink,
+/// are rejected so clients cannot read or write arbitrary files.
+pub fn resolve_snapshot_path(data_dir: &Path, requested: &str) -> ArkaftResult<PathBuf> {
+    let root = std::fs::canonicalize(data_dir)?;
+    let requested = Path::new(requested);
+    if requested.components().any(|component| matches!(component, Component::ParentDir)) {
+        return Err(ArkaftMcpError::parameter_validation(format!(
+            "Snapshot path {} must not contain '..'",
+            requested.display()
+        )));
+    }
+    
+    let path = root.join(requested);
+    let existing = path.ancestors().find(|ancestor| ancestor.exists()).unwrap_or(&root);
+    if !std::fs::canonicalize(existing)?.starts_with(&root) {
+        return Err(ArkaftMcpError::parameter_validation(format!(
+            "Snapshot path {} is outside the data directory {}",
+            requested.display(),
+            root.display()
+        )));
+    }
+    
+    Ok(path)
+}
+
+/// Outcome of merging a snapshot into the knowledge base
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    /// Versions whose entries the snapshot replaced because it was newer
+    pub updated_versions: Vec<String>,
+    /// Versions where the older snapshot only filled in missing entries
+    pub gap_filled_versions: Vec<String>,
+    /// Number of entries merged across all versions
+    pub entries_merged: usize,
+    /// Aliases added because they were not defined locally
+    pub aliases_added: Vec<String>,
+}
+
+/// Size summary of a snapshot
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SnapshotSummary {
+    /// Number of versions
+    pub versions: usize,
+    /// Number of concepts across all versions
+    pub concepts: usize,
+    /// Number of best practices across all versions
+    pub best_practices: usize,
+    /// Number of implementation patterns across all versions
+    pub patterns: usize,
+}
+
+impl KnowledgeSnapshot {
+    /// Capture every version and alias held by the knowledge base
+    pub fn from_knowledge_base(knowledge_base: &AdkKnowledgeBase) -> Self {
+        let mut versions: Vec<VersionDocs> = knowledge_base.version_docs.values().cloned().collect();
+        versions.sort_by(|a, b| compare_versions(&a.version, &b.version));
+        
+        Self {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            generator: concat!("arkaft-mcp-google-adk/", env!("CARGO_PKG_VERSION")).to_string(),
+            exported_at: unix_timestamp(),
+            default_version: knowledge_base.default_version.clone(),
+            version_aliases: knowledge_base.version_config.version_aliases.clone(),
+            versions,
+        }
+    }
+    
+    /// Count the entries held by the snapshot
+    pub fn summary(&self) -> SnapshotSummary {
+        self.versions.iter().fold(
+            SnapshotSummary { versions: self.versions.len(), ..SnapshotSummary::default() },
+            |mut summary, docs| {
+                summary.concepts += docs.concepts.len();
+                summary.best_practices += docs.best_practices.len();
+                summary.patterns += docs.implementation_patterns.len();
+                summary
+            },
+        )
+    }
+    
+    /// Read a snapshot file, rejecting formats newer than this server understands
+    pub async fn read_from(path: &Path) -> ArkaftResult<Self> {
+        let content = tokio::fs::read_to_string(path).await?;
+        let snapshot: Self = serde_json::from_str(&content).map_err(|e| {
+            ArkaftMcpError::knowledge_update(format!("Invalid knowledge snapshot {}: {}", path.display(), e))
+        })?;
+        
+        if snapshot.format_version > SNAPSHOT_FORMAT_VERSION {
+            return Err(ArkaftMcpError::knowledge_update(format!(
+                "Snapshot {} uses format version {}, but this server supports up to {}",
+                path.display(),
+                snapshot.format_version,
+                SNAPSHOT_FORMAT_VERSION
+            )));
+        }
+        
+        Ok(snapshot)
+    }
+    
+    /// Write the snapshot as pretty-printed JSON, returning the number of bytes written
+    ///
+    /// Refuses to replace an existing file unless `overwrite` is set.
+    pub async fn write_to(&self, path: &Path, overwrite: bool) -> ArkaftResult<usize> {
+        if !overwrite && tokio::fs::try_exists(path).await? {
+            return Err(ArkaftMcpError::storage(format!(
+                "Snapshot file {} already exists; set overwrite to replace it",
+                path.display()
+            )));
+        }
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        
+        let json = serde_json::to_string_pretty(self)?;
+        tokio::fs::write(path, &json).await?;
+        
+        Ok(json.len())
+    }
+}
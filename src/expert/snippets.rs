@@ -0,0 +1,572 @@
+//! Code snippet language detection and Python analysis
+//!
+//! ADK snippets are often Python rather than Rust. `validate_architecture` detects each
+//! snippet's language so Rust-only patterns are not applied to Python, and runs the
+//! Python checks here: bare `except:` clauses, blocking calls inside `async def`
+//! functions, tool functions without type hints (ADK builds tool schemas from them),
+//! `global` state, model calls chained synchronously, and tool arguments passed
+//! unchecked to `eval`, the shell, or SQL. Performance checks find independent model
+//! calls awaited one after another and model or tool calls made once per loop iteration.
+//! Tool design checks find tools taking untyped dicts or `**kwargs`, tools that poll
+//! with `sleep` instead of being long-running tools, and tools that raise errors
+//! rather than returning them to the agent.
+//! Hard-coded secrets, tool descriptions open to prompt injection, and session state
+//! that grows without bound are detected in snippets of any language.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use syn::File;
+
+/// Programming language of a code snippet
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SnippetLanguage {
+    /// Rust source
+    Rust,
+    /// Python source
+    Python,
+    /// Language could not be determined
+    Unknown,
+}
+
+/// Source markers typical of Rust
+const RUST_MARKERS: &[&str] = &[
+    r"\bfn\s+\w+",
+    r"\blet\s+(?:mut\s+)?\w+",
+    r"\bimpl\b",
+    r"\bpub\s+",
+    r"\w+::\w+",
+    r"\buse\s+\w+::",
+    r"\w+!\(",
+    r";\s*$",
+    r"&(?:mut\s+)?\w+",
+];
+
+/// Source markers typical of Python
+const PYTHON_MARKERS: &[&str] = &[
+    r"^\s*(?:async\s+)?def\s+\w+\s*\(",
+    r"^\s*(?:from\s+[\w.]+\s+)?import\s+\w+",
+    r"^\s*(?:if|elif|else|for|while|try|except|finally|with|class)\b.*:\s*$",
+    r"\bself\.",
+    r"\b(?:None|True|False)\b",
+    r"^\s*#",
+    r#"""""#,
+    r"^\s*@\w+",
+];
+
+/// Synchronous model calls; their async forms are awaited or use `generate_content_async`
+const SYNC_MODEL_CALL_PATTERN: &str = r"\.generate_content\s*\(|\.completions\.create\s*\(";
+
+/// Model and agent calls, synchronous or async
+const MODEL_CALL_PATTERN: &str = r"\.generate_content(?:_async)?\s*\(|\.completions\.create\s*\(|\.run_async\s*\(|\.ainvoke\s*\(";
+
+/// Annotations too broad for the model to know what a tool expects, e.g. `dict` or `Any`
+const BROAD_ANNOTATION_PATTERN: &str = r"^(?:typing\.)?(?:dict|Dict|Mapping|Any|object)(?:\[\s*str\s*,\s*(?:typing\.)?Any\s*\])?$";
+
+/// Appends to a session state entry, e.g. `tool_context.state["history"].append(...)`
+const STATE_ACCUMULATION_PATTERN: &str = r#"\bstate\s*\[\s*["'][^"']+["']\s*\]\s*(?:\.(?:append|extend)\s*\(|\+=)"#;
+
+/// Tool lists in Python keyword arguments, dicts, or Rust `vec!` builders
+const TOOL_LIST_PATTERN: &str = r"\btools\s*(?:[=:]\s*|\(\s*)(?:vec!\s*)?\[([^\]]*)\]";
+
+/// Assignments of string literals to secret-looking names, e.g. `API_KEY = "..."` or `"password": "..."`
+const SECRET_ASSIGNMENT_PATTERN: &str =
+    r#"(?i)\b\w*(?:api[_-]?key|secret|password|passwd|token|access[_-]?key|private[_-]?key)\w*["']?\s*(?::[^=\n]*)?[=:]\s*("[^"\s]{8,}"|'[^'\s]{8,}')"#;
+
+/// Credential formats recognizable on their own: Google API keys, OpenAI-style keys,
+/// AWS access key ids, GitHub tokens, and PEM private keys
+const SECRET_TOKEN_PATTERN: &str =
+    r"\bAIza[0-9A-Za-z_\-]{35}|\bsk-[A-Za-z0-9_\-]{20,}|\bAKIA[0-9A-Z]{16}\b|\bgh[pousr]_[A-Za-z0-9]{36}\b|-----BEGIN [A-Z ]*PRIVATE KEY-----";
+
+/// Literal values that are placeholders rather than real secrets
+const SECRET_PLACEHOLDERS: &[&str] = &["your", "xxx", "<", "example", "changeme", "placeholder", "...", "${", "redacted", "dummy"];
+
+/// Phrases in tool descriptions that try to steer the model rather than describe the tool
+const INJECTION_PHRASE_PATTERN: &str = r"(?i)\b(?:ignore|disregard|forget)\s+(?:all\s+|any\s+)?(?:previous|prior|earlier|above|other)\s+(?:instructions|rules|prompts?)\b|\bsystem\s+prompt\b|\byou\s+must\s+always\s+(?:call|use)\b|\bdo\s+not\s+(?:tell|inform)\s+the\s+user\b|\bwithout\s+asking\s+the\s+user\b";
+
+/// Tool descriptions built at runtime, which can carry untrusted text into the prompt
+const DYNAMIC_DESCRIPTION_PATTERN: &str = r#"\bdescription\s*=\s*(?:f["']|[^\n]*\.format\(|[^\n]*["']\s*\+)|\.description\s*\(\s*&?format!"#;
+
+/// Calls that execute their argument as code, a shell command, or SQL
+const DANGEROUS_SINK_PATTERN: &str = r#"\b(?:eval|exec)\s*\(|\bos\.(?:system|popen)\s*\(|\bsubprocess\.\w+\s*\([^\n]*shell\s*=\s*True|\.execute\s*\(\s*f["']|\.execute\s*\([^\n]*(?:%\s*\(?\w|\.format\(|["']\s*\+)"#;
+
+/// Calls that block the event loop when made from an `async def`
+const BLOCKING_CALL_PATTERN: &str =
+    r"\b(?:requests\.(?:get|post|put|patch|delete|head|options|request)|time\.sleep|urllib\.request\.urlopen|subprocess\.(?:run|call|check_output))\s*\(";
+
+impl SnippetLanguage {
+    /// Detect a snippet's language, preferring Rust when the snippet parses as a Rust file
+    pub fn detect(snippet: &str) -> Self {
+        if syn::parse_str::<File>(snippet).is_ok() && !snippet.trim().is_empty() {
+            return SnippetLanguage::Rust;
+        }
+        
+        let rust = marker_count(RUST_MARKERS, snippet);
+        let python = marker_count(PYTHON_MARKERS, snippet);
+        if rust == 0 && python == 0 {
+            SnippetLanguage::Unknown
+        } else if python > rust {
+            SnippetLanguage::Python
+        } else if rust > python {
+            SnippetLanguage::Rust
+        } else {
+            SnippetLanguage::Unknown
+        }
+    }
+}
+
+/// Python function definition located in a snippet
+#[derive(Clone, Debug, PartialEq)]
+pub struct PythonFunction {
+    /// Function name
+    pub name: String,
+    /// Line of the `def` (1-based)
+    pub line: usize,
+    /// Last line of the body (1-based)
+    pub end_line: usize,
+    /// Whether the function is an `async def`
+    pub is_async: bool,
+    /// Parameter names, excluding `self` and `cls`
+    pub params: Vec<String>,
+    /// Parameters without a type annotation, excluding `self` and `cls`
+    pub untyped_params: Vec<String>,
+    /// Parameters annotated with an untyped dict, `Any`, or `object`, and `*args`/`**kwargs`
+    pub broad_params: Vec<String>,
+    /// Whether the signature has a `->` return annotation
+    pub has_return_annotation: bool,
+}
+
+/// Python patterns found in a snippet
+#[derive(Clone, Debug, Default)]
+pub struct PythonAnalysis {
+    /// Function definitions in source order
+    pub functions: Vec<PythonFunction>,
+    /// Lines with a bare `except:` clause
+    pub bare_excepts: Vec<usize>,
+    /// Lines making a blocking call inside an `async def`, with the function name
+    pub blocking_calls_in_async: Vec<(usize, String)>,
+    /// Functions registered as agent tools
+    pub tool_names: Vec<String>,
+    /// Lines with a `global` statement
+    pub global_statements: Vec<usize>,
+    /// Functions making more than one synchronous model call, with the call lines
+    pub sync_model_call_chains: Vec<(String, Vec<usize>)>,
+    /// Lines where a tool passes one of its arguments to `eval`, the shell, or SQL, with the tool name
+    pub unvalidated_tool_inputs: Vec<(usize, String)>,
+    /// Async functions awaiting several model calls that do not use each other's results, with the call lines
+    pub sequential_independent_awaits: Vec<(String, Vec<usize>)>,
+    /// Lines calling a model or a registered tool inside a `for` or `while` loop
+    pub calls_in_loops: Vec<usize>,
+    /// Lines where a registered tool sleeps inside a loop while waiting for an operation, with the tool name
+    pub polling_tools: Vec<(usize, String)>,
+    /// First line where a registered tool raises instead of returning an error status, with the tool name
+    pub raising_tools: Vec<(usize, String)>,
+}
+
+impl PythonAnalysis {
+    /// Analyze a Python snippet
+    pub fn new(snippet: &str) -> Self {
+        let lines: Vec<&str> = snippet.lines().collect();
+        let functions = python_functions(&lines);
+        
+        let bare_except = Regex::new(r"^\s*except\s*:").expect("bare except pattern is valid");
+        let bare_excepts = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| bare_except.is_match(line))
+            .map(|(index, _)| index + 1)
+            .collect();
+        
+        let blocking_call = Regex::new(BLOCKING_CALL_PATTERN).expect("blocking call pattern is valid");
+        let mut blocking_calls_in_async = Vec::new();
+        for (index, line) in lines.iter().enumerate() {
+            let line_number = index + 1;
+            if line.trim_start().starts_with('#') || !blocking_call.is_match(line) {
+                continue;
+            }
+            let enclosing = functions
+                .iter()
+                .filter(|func| func.line < line_number && line_number <= func.end_line)
+                .min_by_key(|func| func.end_line - func.line);
+            if let Some(func) = enclosing.filter(|func| func.is_async) {
+                blocking_calls_in_async.push((line_number, func.name.clone()));
+            }
+        }
+        
+        let global_statement = Regex::new(r"^\s+global\s+\w+").expect("global statement pattern is valid");
+        let global_statements = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| global_statement.is_match(line))
+            .map(|(index, _)| index + 1)
+            .collect();
+        
+        let sync_model_call = Regex::new(SYNC_MODEL_CALL_PATTERN).expect("sync model call pattern is valid");
+        let sync_model_call_chains = functions
+            .iter()
+            .filter_map(|func| {
+                let calls: Vec<usize> = (func.line + 1..=func.end_line)
+                    .filter(|line_number| {
+                        let line = lines[line_number - 1];
+                        sync_model_call.is_match(line) && !line.contains("await ") && !line.trim_start().starts_with('#')
+                    })
+                    .collect();
+                (calls.len() > 1).then(|| (func.name.clone(), calls))
+            })
+            .collect();
+        
+        let tool_names = tool_names(snippet);
+        let sink = Regex::new(DANGEROUS_SINK_PATTERN).expect("dangerous sink pattern is valid");
+        let mut unvalidated_tool_inputs = Vec::new();
+        for func in functions.iter().filter(|func| tool_names.contains(&func.name)) {
+            for line_number in func.line + 1..=func.end_line {
+                let line = lines[line_number - 1];
+                if line.trim_start().starts_with('#') || !sink.is_match(line) {
+                    continue;
+                }
+                let uses_param = func.params.iter().any(|param| {
+                    Regex::new(&format!(r"\b{}\b", regex::escape(param))).is_ok_and(|param| param.is_match(line))
+                });
+                if uses_param {
+                    unvalidated_tool_inputs.push((line_number, func.name.clone()));
+                }
+            }
+        }
+        
+        let model_call = Regex::new(MODEL_CALL_PATTERN).expect("model call pattern is valid");
+        let awaited_assignment = Regex::new(r"^\s*(?:(\w+)\s*=\s*)?await\s").expect("awaited assignment pattern is valid");
+        let sequential_independent_awaits = functions
+            .iter()
+            .filter(|func| func.is_async)
+            .filter_map(|func| {
+                let mut results: Vec<String> = Vec::new();
+                let mut calls = Vec::new();
+                for line_number in func.line + 1..=func.end_line {
+                    let line = lines[line_number - 1];
+                    let Some(captures) = awaited_assignment.captures(line).filter(|_| model_call.is_match(line)) else {
+                        continue;
+                    };
+                    let uses_result = results.iter().any(|result| {
+                        Regex::new(&format!(r"\b{}\b", regex::escape(result))).is_ok_and(|result| result.is_match(line))
+                    });
+                    if uses_result {
+                        return None;
+                    }
+                    results.extend(captures.get(1).map(|result| result.as_str().to_string()));
+                    calls.push(line_number);
+                }
+                (calls.len() > 1).then(|| (func.name.clone(), calls))
+            })
+            .collect();
+        
+        let tool_call = tool_names
+            .iter()
+            .map(|name| regex::escape(name))
+            .reduce(|pattern, name| format!("{}|{}", pattern, name))
+            .and_then(|names| Regex::new(&format!(r"\b(?:{})\s*\(", names)).ok());
+        let calls_in_loops = (1..=lines.len())
+            .filter(|line_number| {
+                let line = lines[line_number - 1];
+                let is_call = model_call.is_match(line) || tool_call.as_ref().is_some_and(|tool_call| tool_call.is_match(line));
+                is_call && !line.trim_start().starts_with('#') && in_loop(&lines, *line_number)
+            })
+            .collect();
+        
+        let sleep = Regex::new(r"\bsleep\s*\(").expect("sleep pattern is valid");
+        let raise = Regex::new(r"^\s*raise\b").expect("raise pattern is valid");
+        let status = Regex::new(r#"["']status["']"#).expect("status pattern is valid");
+        let mut polling_tools = Vec::new();
+        let mut raising_tools = Vec::new();
+        for func in functions.iter().filter(|func| tool_names.contains(&func.name)) {
+            let body = func.line + 1..=func.end_line;
+            if let Some(line_number) = body.clone().find(|line_number| {
+                let line = lines[line_number - 1];
+                sleep.is_match(line) && !line.trim_start().starts_with('#') && in_loop(&lines, *line_number)
+            }) {
+                polling_tools.push((line_number, func.name.clone()));
+            }
+            let returns_status = body.clone().any(|line_number| status.is_match(lines[line_number - 1]));
+            if let Some(line_number) = body.clone().find(|line_number| raise.is_match(lines[line_number - 1])) {
+                if !returns_status {
+                    raising_tools.push((line_number, func.name.clone()));
+                }
+            }
+        }
+        
+        Self {
+            tool_names,
+            functions,
+            bare_excepts,
+            blocking_calls_in_async,
+            global_statements,
+            sync_model_call_chains,
+            unvalidated_tool_inputs,
+            sequential_independent_awaits,
+            calls_in_loops,
+            polling_tools,
+            raising_tools,
+        }
+    }
+    
+    /// Tool functions defined in the snippet that lack parameter or return type hints
+    pub fn untyped_tools(&self) -> Vec<&PythonFunction> {
+        self.functions
+            .iter()
+            .filter(|func| self.tool_names.contains(&func.name))
+            .filter(|func| !func.untyped_params.is_empty() || !func.has_return_annotation)
+            .collect()
+    }
+    
+    /// Tool functions defined in the snippet that take untyped dicts, `Any`, or `*args`/`**kwargs`
+    pub fn broad_tools(&self) -> Vec<&PythonFunction> {
+        self.functions
+            .iter()
+            .filter(|func| self.tool_names.contains(&func.name) && !func.broad_params.is_empty())
+            .collect()
+    }
+}
+
+/// Number of entries in the longest tool list of a snippet, in any language
+pub fn largest_tool_list(snippet: &str) -> usize {
+    let tool_list = Regex::new(TOOL_LIST_PATTERN).expect("tool list pattern is valid");
+    tool_list
+        .captures_iter(snippet)
+        .map(|captures| split_top_level(&captures[1]).into_iter().filter(|entry| !entry.trim().is_empty()).count())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Lines of a snippet, in any language, that hard-code a secret
+pub fn secret_lines(snippet: &str) -> Vec<usize> {
+    let assignment = Regex::new(SECRET_ASSIGNMENT_PATTERN).expect("secret assignment pattern is valid");
+    let token = Regex::new(SECRET_TOKEN_PATTERN).expect("secret token pattern is valid");
+    let is_placeholder = |value: &str| {
+        let value = value.to_lowercase();
+        SECRET_PLACEHOLDERS.iter().any(|placeholder| value.contains(placeholder))
+    };
+    
+    snippet
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            token.find(line).is_some_and(|secret| !is_placeholder(secret.as_str()))
+                || assignment.captures(line).is_some_and(|captures| !is_placeholder(&captures[1]))
+        })
+        .map(|(index, _)| index + 1)
+        .collect()
+}
+
+/// Lines of a snippet, in any language, that append to a session state entry
+pub fn state_accumulation_lines(snippet: &str) -> Vec<usize> {
+    let accumulation = Regex::new(STATE_ACCUMULATION_PATTERN).expect("state accumulation pattern is valid");
+    snippet
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| accumulation.is_match(line) && !line.trim_start().starts_with('#'))
+        .map(|(index, _)| index + 1)
+        .collect()
+}
+
+/// Lines of tool descriptions or docstrings, in any language, open to prompt injection
+///
+/// A description is flagged when it is built at runtime, so untrusted text can reach
+/// the model through it, or when it contains instructions aimed at the model.
+pub fn injection_prone_descriptions(snippet: &str) -> Vec<usize> {
+    let phrase = Regex::new(INJECTION_PHRASE_PATTERN).expect("injection phrase pattern is valid");
+    let dynamic = Regex::new(DYNAMIC_DESCRIPTION_PATTERN).expect("dynamic description pattern is valid");
+    let mut in_docstring = false;
+    let mut flagged = Vec::new();
+    
+    for (index, line) in snippet.lines().enumerate() {
+        let quotes = line.matches(r#"""""#).count() + line.matches("'''").count();
+        let descriptive = in_docstring || quotes > 0 || line.contains("description");
+        if dynamic.is_match(line) || (descriptive && phrase.is_match(line)) {
+            flagged.push(index + 1);
+        }
+        if quotes % 2 == 1 {
+            in_docstring = !in_docstring;
+        }
+    }
+    flagged
+}
+
+/// Number of marker patterns matching any line of the snippet
+fn marker_count(markers: &[&str], snippet: &str) -> usize {
+    markers
+        .iter()
+        .filter_map(|marker| Regex::new(&format!("(?m){}", marker)).ok())
+        .filter(|marker| marker.is_match(snippet))
+        .count()
+}
+
+/// Locate `def` statements, joining signatures split across lines
+fn python_functions(lines: &[&str]) -> Vec<PythonFunction> {
+    let def = Regex::new(r"^(\s*)(async\s+)?def\s+(\w+)\s*\(").expect("def pattern is valid");
+    let mut functions = Vec::new();
+    
+    for (index, line) in lines.iter().enumerate() {
+        let Some(captures) = def.captures(line) else {
+            continue;
+        };
+        let indent = captures[1].len();
+        
+        // Join the signature until its parentheses balance
+        let mut signature = String::new();
+        let mut header_end = index;
+        for (offset, part) in lines[index..].iter().enumerate() {
+            signature.push_str(part);
+            signature.push(' ');
+            header_end = index + offset;
+            if signature.matches('(').count() <= signature.matches(')').count() {
+                break;
+            }
+        }
+        
+        // The body runs until the next line indented no deeper than the def
+        let end_line = lines[header_end + 1..]
+            .iter()
+            .position(|line| !line.trim().is_empty() && indentation(line) <= indent)
+            .map(|offset| header_end + offset + 1)
+            .unwrap_or(lines.len());
+        
+        let params_start = signature.find('(').map(|start| start + 1).unwrap_or(0);
+        let params_end = matching_paren(&signature, params_start);
+        let params = &signature[params_start..params_end];
+        let after_params = &signature[(params_end + 1).min(signature.len())..];
+        
+        functions.push(PythonFunction {
+            name: captures[3].to_string(),
+            line: index + 1,
+            end_line,
+            is_async: captures.get(2).is_some(),
+            params: param_names(params),
+            untyped_params: untyped_params(params),
+            broad_params: broad_params(params),
+            has_return_annotation: after_params.trim_start().starts_with("->"),
+        });
+    }
+    
+    functions
+}
+
+/// Index of the parenthesis closing the one opened just before `start`
+fn matching_paren(text: &str, start: usize) -> usize {
+    let mut depth = 1;
+    for (offset, ch) in text[start..].char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return start + offset;
+                }
+            }
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+/// Parameter names in a parameter list, excluding `self` and `cls`
+fn param_names(params: &str) -> Vec<String> {
+    split_top_level(params)
+        .into_iter()
+        .map(|param| param.trim().trim_start_matches('*').split([':', '=']).next().unwrap_or_default().trim().to_string())
+        .filter(|param| !param.is_empty() && param != "/" && param != "self" && param != "cls")
+        .collect()
+}
+
+/// Parameter names without annotations in a parameter list
+fn untyped_params(params: &str) -> Vec<String> {
+    split_top_level(params)
+        .into_iter()
+        .map(|param| param.trim().trim_start_matches('*').trim().to_string())
+        .filter(|param| !param.is_empty() && param != "/" && param != "self" && param != "cls")
+        .filter(|param| !param.split('=').next().unwrap_or_default().contains(':'))
+        .map(|param| param.split('=').next().unwrap_or_default().trim().to_string())
+        .collect()
+}
+
+/// Parameters annotated too broadly, and `*args`/`**kwargs`, in a parameter list
+fn broad_params(params: &str) -> Vec<String> {
+    let broad_annotation = Regex::new(BROAD_ANNOTATION_PATTERN).expect("broad annotation pattern is valid");
+    split_top_level(params)
+        .into_iter()
+        .map(str::trim)
+        .filter(|param| {
+            let declaration = param.split('=').next().unwrap_or_default();
+            let annotation = declaration.split_once(':').map(|(_, annotation)| annotation.trim());
+            (param.starts_with('*') && param.len() > 1) || annotation.is_some_and(|annotation| broad_annotation.is_match(annotation))
+        })
+        .map(|param| param.trim_start_matches('*').split([':', '=']).next().unwrap_or_default().trim().to_string())
+        .collect()
+}
+
+/// Split on commas outside brackets, so `Dict[str, int]` stays whole
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '[' | '(' | '{' => depth += 1,
+            ']' | ')' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&text[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Whether a line is nested in a `for` or `while` loop of its function
+fn in_loop(lines: &[&str], line_number: usize) -> bool {
+    let loop_header = Regex::new(r"^\s*(?:async\s+)?(?:for|while)\b").expect("loop header pattern is valid");
+    let scope_header = Regex::new(r"^\s*(?:async\s+)?(?:def|class)\b").expect("scope header pattern is valid");
+    let mut indent = indentation(lines[line_number - 1]);
+    
+    for line in lines[..line_number - 1].iter().rev() {
+        if line.trim().is_empty() || indentation(line) >= indent {
+            continue;
+        }
+        if loop_header.is_match(line) {
+            return true;
+        }
+        if scope_header.is_match(line) {
+            return false;
+        }
+        indent = indentation(line);
+    }
+    false
+}
+
+/// Width of a line's leading whitespace
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Names registered as tools via `tools=[...]` or `FunctionTool(...)`
+fn tool_names(snippet: &str) -> Vec<String> {
+    let tool_list = Regex::new(r"\btools\s*=\s*\[([^\]]*)\]").expect("tool list pattern is valid");
+    let function_tool = Regex::new(r"\bFunctionTool\s*\(\s*(?:func\s*=\s*)?(\w+)").expect("function tool pattern is valid");
+    let identifier = Regex::new(r"^[A-Za-z_]\w*$").expect("identifier pattern is valid");
+    
+    let mut names = Vec::new();
+    for captures in tool_list.captures_iter(snippet) {
+        for entry in captures[1].split(',') {
+            let entry = entry.trim();
+            if identifier.is_match(entry) && !names.iter().any(|name| name == entry) {
+                names.push(entry.to_string());
+            }
+        }
+    }
+    for captures in function_tool.captures_iter(snippet) {
+        if !names.iter().any(|name| name == &captures[1]) {
+            names.push(captures[1].to_string());
+        }
+    }
+    names
+}
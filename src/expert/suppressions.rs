@@ -0,0 +1,106 @@
+//! Inline suppression markers
+//!
+//! Teams sometimes deviate from a rule on purpose, such as keeping in-memory sessions in
+//! a prototype. A `// arkaft-allow: <rule_id>` comment (`# arkaft-allow:` in Python)
+//! acknowledges the deviation for the snippet it appears in, and `arkaft-allow:
+//! <rule_id>` or "allow rule <rule_id>" in a description acknowledges it for the whole
+//! validation. Several rule ids can be listed, separated by commas. Suppressed findings
+//! are reported separately and do not lower the compliance score.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use crate::expert::best_practices::ValidationFinding;
+
+/// Suppression comment in a code snippet, e.g. `// arkaft-allow: unwrap_usage, todo_usage`
+const SNIPPET_MARKER_PATTERN: &str = r"(?i)(?://|#)\s*arkaft-allow:\s*([\w-]+(?:\s*,\s*[\w-]+)*)";
+
+/// Suppression in a description, e.g. "arkaft-allow: in_memory_sessions" or "allow rule `god_agent`"
+const DESCRIPTION_MARKER_PATTERN: &str = r"(?i)\b(?:arkaft-allow:|allow\s+rules?)\s*(`?[\w-]+`?(?:\s*,\s*`?[\w-]+`?)*)";
+
+/// Finding excluded from the results by an inline suppression marker
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SuppressedFinding {
+    /// The finding that would have been reported
+    pub finding: ValidationFinding,
+    /// Where the marker acknowledging it was found, e.g. "Code Snippet 1, line 4"
+    pub marker: String,
+}
+
+/// Suppression markers found in a description and its code snippets
+#[derive(Clone, Debug, Default)]
+pub struct Suppressions {
+    /// Rule ids acknowledged in the description
+    description: Vec<String>,
+    /// Rule ids acknowledged in each snippet, with the marker's line
+    snippets: Vec<Vec<(String, usize)>>,
+}
+
+impl Suppressions {
+    /// Collect the markers of a description and its code snippets
+    pub fn new(description: &str, code_snippets: &[String]) -> Self {
+        let description_marker = Regex::new(DESCRIPTION_MARKER_PATTERN).expect("description marker pattern is valid");
+        let snippet_marker = Regex::new(SNIPPET_MARKER_PATTERN).expect("snippet marker pattern is valid");
+        
+        let description = description_marker
+            .captures_iter(description)
+            .flat_map(|captures| rule_ids(&captures[1]))
+            .collect();
+        let snippets = code_snippets
+            .iter()
+            .map(|snippet| {
+                snippet
+                    .lines()
+                    .enumerate()
+                    .flat_map(|(index, line)| {
+                        snippet_marker
+                            .captures_iter(line)
+                            .flat_map(|captures| rule_ids(&captures[1]))
+                            .map(move |rule_id| (rule_id, index + 1))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect()
+            })
+            .collect();
+        
+        Self { description, snippets }
+    }
+    
+    /// Pass a finding through, or record it in `suppressed` when a marker acknowledges its rule
+    ///
+    /// `snippet` is the index of the snippet the finding comes from, if any; markers in
+    /// a snippet only acknowledge that snippet's findings.
+    pub fn admit(
+        &self,
+        finding: ValidationFinding,
+        snippet: Option<usize>,
+        suppressed: &mut Vec<SuppressedFinding>,
+    ) -> Option<ValidationFinding> {
+        let marker = if self.description.contains(&finding.rule_id) {
+            Some("Architecture Description".to_string())
+        } else {
+            snippet.and_then(|index| {
+                self.snippets
+                    .get(index)?
+                    .iter()
+                    .find(|(rule_id, _)| *rule_id == finding.rule_id)
+                    .map(|(_, line)| format!("Code Snippet {}, line {}", index + 1, line))
+            })
+        };
+        
+        match marker {
+            Some(marker) => {
+                suppressed.push(SuppressedFinding { finding, marker });
+                None
+            }
+            None => Some(finding),
+        }
+    }
+}
+
+/// Rule ids in a marker's comma-separated list
+fn rule_ids(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(|rule_id| rule_id.trim().trim_matches('`').to_string())
+        .filter(|rule_id| !rule_id.is_empty())
+        .collect()
+}
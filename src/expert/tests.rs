@@ -0,0 +1,1525 @@
+//! Unit tests for the ADK knowledge base and Documentation Expert
+
+use super::*;
+use crate::expert::adk_knowledge::{AdkKnowledgeBase, DocumentationUrls, VersionStatus, DOCS_BASE_URL};
+
+#[test]
+fn test_session_concepts_available() {
+    let kb = AdkKnowledgeBase::new();
+    let docs = kb.get_version_docs(&kb.default_version).unwrap();
+    
+    for key in ["session", "session_state", "session_service", "memory_service"] {
+        assert!(docs.concepts.contains_key(key), "Missing concept: {}", key);
+    }
+    
+    // State prefixes must be documented
+    let state = &docs.concepts["session_state"];
+    for prefix in ["user:", "app:", "temp:"] {
+        assert!(state.description.contains(prefix), "Missing prefix: {}", prefix);
+    }
+}
+
+#[test]
+fn test_session_best_practices_by_category() {
+    let kb = AdkKnowledgeBase::new();
+    let practices = kb.get_best_practices_by_category("sessions", None);
+    
+    assert!(!practices.is_empty());
+    assert!(practices.iter().any(|p| p.description.contains("InMemorySessionService")
+        && p.description.contains("DatabaseSessionService")));
+}
+
+#[tokio::test]
+async fn test_session_implementation_patterns() {
+    let expert = DocumentationExpert::new();
+    
+    for pattern in ["session_management", "state_management", "long_term_memory"] {
+        let response = expert.get_implementation_pattern(pattern, None).await.unwrap();
+        assert!(!response.contains("not found"), "Missing pattern: {}", pattern);
+    }
+}
+
+#[test]
+fn test_project_templates_are_runnable_layouts() {
+    let kb = AdkKnowledgeBase::new();
+    
+    for key in ["single_agent", "multi_agent_team", "agent_with_tools"] {
+        let template = kb.get_template(key, None).unwrap_or_else(|| panic!("Missing template: {}", key));
+        let paths: Vec<&str> = template.files.iter().map(|file| file.path.as_str()).collect();
+        assert!(paths.contains(&"requirements.txt"), "{} lacks requirements.txt", key);
+        assert!(paths.iter().any(|path| path.ends_with("/__init__.py")), "{} lacks __init__.py", key);
+        assert!(
+            template.files.iter().any(|file| file.path.ends_with("/agent.py") && file.content.contains("root_agent = Agent(")),
+            "{} does not define root_agent",
+            key
+        );
+        assert!(!template.setup_steps.is_empty());
+    }
+    assert!(kb.get_template("missing", None).is_none());
+}
+
+#[test]
+fn test_best_practice_categories_merge_case_variants() {
+    let mut kb = AdkKnowledgeBase::new();
+    let deployment_count = kb.get_best_practices_by_category("deployment", None).len();
+    let version = kb.default_version.clone();
+    let docs = kb.version_docs.get_mut(&version).unwrap();
+    let mut practice = docs.best_practices[0].clone();
+    practice.title = "Upper-case category".to_string();
+    practice.category = "Deployment".to_string();
+    docs.best_practices.push(practice);
+    
+    let categories = kb.best_practice_categories(None);
+    assert!(categories.contains(&("deployment".to_string(), deployment_count + 1)));
+    assert!(categories.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn test_query_analytics_evicts_least_asked_terms() {
+    use crate::expert::query_analytics::QueryAnalytics;
+    
+    let mut analytics = QueryAnalytics::new(2);
+    analytics.record("session state", true);
+    analytics.record("sessions", false);
+    analytics.record("deployment", false);
+    
+    let report = analytics.report(10);
+    assert_eq!(report.total_queries, 3);
+    assert_eq!(report.unanswered_queries, 2);
+    assert_eq!(report.tracked_terms, 2);
+    assert_eq!(report.most_asked[0].0, "session");
+    assert_eq!(report.most_asked[0].1.asked, 2);
+    assert_eq!(report.most_asked[0].1.missed, 1);
+    assert!(report.most_asked.iter().all(|(term, _)| term != "state"));
+    assert_eq!(report.most_missed.len(), 2);
+    
+    analytics.reset();
+    assert_eq!(analytics.report(10), Default::default());
+}
+
+#[test]
+fn test_follow_up_questions_skip_covered_subjects() {
+    let kb = AdkKnowledgeBase::new();
+    
+    let questions = kb.suggest_follow_up_questions("What is a session?", None, 3);
+    assert_eq!(questions.len(), 3);
+    assert!(questions.contains(&"What is Session State?".to_string()));
+    assert!(questions.iter().any(|q| q.starts_with("How do I implement")));
+    assert!(questions.iter().all(|q| q != "What is Session?"));
+    
+    let questions = kb.suggest_follow_up_questions("how to deploy to cloud run", None, 3);
+    assert!(questions.iter().all(|q| !q.contains("deploy to cloud run")));
+    assert!(questions.contains(&"What are the best practices for deployment?".to_string()));
+    
+    assert!(kb.suggest_follow_up_questions("quantum teleportation flux", None, 3).is_empty());
+}
+
+#[test]
+fn test_citations_deep_link_to_sections() {
+    use crate::expert::adk_knowledge::Citation;
+    
+    let citation = Citation::new("https://example.com/docs/state/#old", "#state-prefixes", &["Sessions", "State", "Prefixes"]);
+    assert_eq!(citation.deep_link(), "https://example.com/docs/state/#state-prefixes");
+    assert_eq!(citation.title(), "Sessions → State → Prefixes");
+    
+    let kb = AdkKnowledgeBase::new();
+    let top = &kb.rank_concepts("session state", None, 1)[0];
+    assert_eq!(top.key, "session_state");
+    assert_eq!(top.citation.unwrap().deep_link(), "https://google.github.io/adk-docs/sessions/state/#what-is-sessionstate");
+    
+    let docs = kb.get_version_docs(&kb.default_version).unwrap();
+    for (id, citation) in &docs.citations {
+        let (kind, _) = id.split_once(':').unwrap();
+        assert!(kind == "concept" || kind == "practice", "Unexpected citation kind: {}", id);
+        assert!(citation.url.starts_with(DOCS_BASE_URL), "Citation {} is not official", id);
+    }
+    assert!(docs.best_practices.iter().any(|p| docs.practice_citation(&p.title).is_some()));
+}
+
+#[test]
+fn test_merge_replaces_or_drops_citations_with_entries() {
+    use crate::expert::adk_knowledge::Citation;
+    
+    let mut docs = AdkKnowledgeBase::bundled_version_docs("1.0.0".to_string());
+    let original = docs.concept_citation("session_state").cloned().unwrap();
+    
+    // A replacement without a citation drops the stale one
+    let mut update = docs_with_concept("1.0.0", "session_state", "Rewritten state docs.");
+    docs.merge_from(update.clone());
+    assert!(docs.concept_citation("session_state").is_none());
+    
+    // A replacement with a citation brings its own
+    let moved = Citation::new("https://google.github.io/adk-docs/sessions/state/", "state-basics", &["Sessions", "State", "Basics"]);
+    update.citations.insert("concept:session_state".to_string(), moved.clone());
+    docs.merge_from(update);
+    assert_eq!(docs.concept_citation("session_state"), Some(&moved));
+    assert_ne!(original, moved);
+}
+
+#[test]
+fn test_changes_in_combines_changelog_and_features() {
+    use crate::expert::adk_knowledge::{ChangeKind, ChangelogEntry, VersionFeature};
+    
+    let mut kb = AdkKnowledgeBase::new();
+    let mut docs = docs_with_concept("1.1.0", "live_api", "Live API.");
+    docs.changelog = vec![
+        ChangelogEntry {
+            kind: ChangeKind::Fix,
+            title: "Session state race".to_string(),
+            description: "Concurrent state deltas no longer overwrite each other.".to_string(),
+            link: Some("https://github.com/google/adk-python/pull/1".to_string()),
+        },
+        ChangelogEntry {
+            kind: ChangeKind::Feature,
+            title: "Live Streaming".to_string(),
+            description: "Changelog wording wins.".to_string(),
+            link: None,
+        },
+    ];
+    docs.version_features = vec![
+        VersionFeature {
+            name: "Live Streaming".to_string(),
+            description: "Bidirectional audio streaming.".to_string(),
+            introduced_in: "1.1.0".to_string(),
+            deprecated_in: None,
+            migration_notes: None,
+        },
+        VersionFeature {
+            name: "Legacy Runner".to_string(),
+            description: "Old runner.".to_string(),
+            introduced_in: "1.0.0".to_string(),
+            deprecated_in: Some("v1.1.0".to_string()),
+            migration_notes: Some("Use Runner instead.".to_string()),
+        },
+    ];
+    kb.merge_version_docs(docs);
+    
+    let changes = kb.changes_in("1.1.0");
+    let summary: Vec<(ChangeKind, &str, &str)> = changes
+        .iter()
+        .map(|c| (c.kind, c.title.as_str(), c.description.as_str()))
+        .collect();
+    assert_eq!(summary, vec![
+        (ChangeKind::Feature, "Live Streaming", "Changelog wording wins."),
+        (ChangeKind::Deprecation, "Legacy Runner", "Use Runner instead."),
+        (ChangeKind::Fix, "Session state race", "Concurrent state deltas no longer overwrite each other."),
+    ]);
+    
+    assert!(kb.changes_in("9.9.9").is_empty());
+    assert_eq!(kb.release_notes_url("1.1.0"), "https://github.com/google/adk-python/releases/tag/v1.1.0");
+}
+
+#[test]
+fn test_strict_version_validation_suggests_closest_version() {
+    let mut kb = AdkKnowledgeBase::new();
+    kb.version_config.strict_versions = false;
+    assert_eq!(kb.validate_version("9.9.9").unwrap(), "9.9.9");
+    
+    kb.version_config.strict_versions = true;
+    assert_eq!(kb.validate_version("stable").unwrap(), "1.0.0");
+    assert_eq!(kb.validate_version("1.0.0").unwrap(), "1.0.0");
+    
+    let error = kb.validate_version("v1.0").unwrap_err();
+    assert!(matches!(error, crate::utils::error::ArkaftMcpError::ParameterValidation(_)));
+    let message = error.to_string();
+    assert!(message.contains("Unknown ADK version 'v1.0'. Did you mean '1.0.0'?"));
+    assert!(message.contains("Available versions: 1.0.0, latest, stable"));
+    
+    assert_eq!(kb.closest_version("lastest").as_deref(), Some("latest"));
+    assert_eq!(kb.closest_version("zzz"), None);
+    assert!(!kb.validate_version("zzz").unwrap_err().to_string().contains("Did you mean"));
+}
+
+#[test]
+fn test_version_overrides_repoint_aliases() {
+    use std::collections::HashMap;
+    use crate::expert::adk_knowledge::VersionOverrides;
+    
+    let mut kb = AdkKnowledgeBase::new();
+    kb.merge_version_docs(AdkKnowledgeBase::bundled_version_docs("1.2.0".to_string()));
+    
+    let overrides = VersionOverrides {
+        versions: vec!["1.3.0".to_string()],
+        aliases: HashMap::from([
+            ("stable".to_string(), "1.2.0".to_string()),
+            ("preview".to_string(), "1.3.0".to_string()),
+        ]),
+        ..VersionOverrides::default()
+    };
+    kb.apply_version_overrides(overrides).unwrap();
+    assert_eq!(kb.resolve_version("stable"), "1.2.0");
+    assert_eq!(kb.resolve_version("preview"), "1.3.0");
+    assert!(kb.is_known_version("1.3.0"));
+    assert_eq!(kb.default_version, "1.0.0");
+    
+    let latest = VersionOverrides {
+        aliases: HashMap::from([("latest".to_string(), "1.2.0".to_string())]),
+        ..VersionOverrides::default()
+    };
+    kb.apply_version_overrides(latest).unwrap();
+    assert_eq!(kb.default_version, "1.2.0");
+    
+    let unknown_target = VersionOverrides {
+        aliases: HashMap::from([("stable".to_string(), "9.9.9".to_string())]),
+        ..VersionOverrides::default()
+    };
+    assert!(kb.apply_version_overrides(unknown_target).unwrap_err().to_string().contains("not a known version"));
+    assert_eq!(kb.resolve_version("stable"), "1.2.0");
+    
+    let shadowing = VersionOverrides {
+        versions: vec!["2.0.0".to_string()],
+        aliases: HashMap::from([("1.0.0".to_string(), "1.2.0".to_string())]),
+        ..VersionOverrides::default()
+    };
+    assert!(kb.apply_version_overrides(shadowing).is_err());
+    assert!(!kb.is_known_version("2.0.0"));
+}
+
+#[tokio::test]
+async fn test_version_overrides_refresh_references_and_cache() {
+    use crate::expert::adk_knowledge::VersionOverrides;
+    
+    let experts = SharedExperts::new();
+    experts.documentation.read().await.query_documentation("session", Some("9.9.9")).await.unwrap();
+    
+    let overrides = VersionOverrides { versions: vec!["9.9.9".to_string()], ..VersionOverrides::default() };
+    experts.apply_version_overrides(overrides).await.unwrap();
+    
+    let documentation = experts.documentation.read().await;
+    let references = documentation.reference_generator.generate_category_references("tutorials", Some("9.9.9"));
+    assert_eq!(references, vec![format!("{}/9.9.9/tutorials/", DOCS_BASE_URL)]);
+    
+    // Responses cached before the override are not served after it
+    assert_eq!(documentation.cache_stats().entries, 0);
+    documentation.query_documentation("session", Some("9.9.9")).await.unwrap();
+    assert_eq!(documentation.cache_stats().hits, 0);
+}
+
+#[test]
+fn test_release_channels_resolve_as_aliases() {
+    use crate::expert::adk_knowledge::{ChannelConfig, ReleaseChannel, VersionOverrides};
+    
+    assert_eq!(ReleaseChannel::parse(" Preview "), Some(ReleaseChannel::Preview));
+    assert_eq!(ReleaseChannel::parse("beta"), None);
+    
+    let overrides: VersionOverrides = serde_json::from_str(r#"{
+        "channels": {
+            "preview": { "version": "1.1.0rc1", "feed_url": "https://example.com/preview.json" },
+            "next": { "version": "1.2.0.dev0" }
+        }
+    }"#).unwrap();
+    assert_eq!(overrides.channel_feeds(), vec![
+        (ReleaseChannel::Preview, "https://example.com/preview.json".to_string()),
+    ]);
+    
+    let mut kb = AdkKnowledgeBase::new();
+    kb.merge_version_docs(AdkKnowledgeBase::bundled_version_docs("1.1.0rc1".to_string()));
+    kb.apply_version_overrides(overrides).unwrap();
+    
+    assert_eq!(kb.resolve_version("preview"), "1.1.0rc1");
+    assert_eq!(kb.resolve_version("next"), "1.2.0.dev0");
+    assert!(kb.is_known_version("next"));
+    assert_eq!(kb.default_version, "1.0.0");
+    assert_eq!(kb.version_config.channel_version(ReleaseChannel::Stable), Some("1.0.0"));
+    
+    let preview = kb.version_summaries().into_iter().find(|summary| summary.version == "1.1.0rc1").unwrap();
+    assert_eq!(preview.status, VersionStatus::Supported);
+    assert_eq!(preview.aliases, vec!["preview".to_string()]);
+    
+    kb.version_config.set_alias("preview".to_string(), "1.0.0".to_string());
+    assert_eq!(kb.version_config.channel_version(ReleaseChannel::Preview), Some("1.0.0"));
+    
+    kb.version_config.set_channel(ReleaseChannel::Next, ChannelConfig { version: "1.3.0".to_string(), feed_url: None });
+    assert_eq!(kb.resolve_version("next"), "1.3.0");
+    assert!(kb.version_config.is_version_available("1.3.0"));
+}
+
+#[tokio::test]
+async fn test_version_stats_track_sources_and_refreshes() {
+    use crate::expert::knowledge_source::{BundledSource, KnowledgeSource};
+    use std::sync::Arc;
+    
+    let sources: Vec<Arc<dyn KnowledgeSource>> = vec![
+        Arc::new(BundledSource::new()),
+        Arc::new(FixedSource { name: "pack", priority: 100, docs: Some(vec![docs_with_concept("1.1.0", "agent", "Pack agent.")]) }),
+    ];
+    let mut kb = AdkKnowledgeBase::from_sources(&sources).await;
+    kb.mark_updated("1.1.0", 1_700_000_000);
+    
+    let stats = kb.version_stats();
+    assert_eq!(stats.iter().map(|s| s.version.as_str()).collect::<Vec<_>>(), vec!["1.0.0", "1.1.0"]);
+    assert_eq!(stats[0].sources, vec!["bundled"]);
+    assert_eq!(stats[0].updated_at, 0);
+    assert!(stats[0].concepts > 0 && stats[0].best_practices > 0 && stats[0].patterns > 0);
+    assert_eq!((stats[1].concepts, stats[1].best_practices, stats[1].patterns), (1, 0, 0));
+    assert_eq!(stats[1].sources, vec!["pack"]);
+    
+    let report = format_knowledge_stats(&stats, &kb.default_version);
+    assert!(report.contains("| `1.1.0` | 1 | 0 | 0 | 2023-11-14 22:13 UTC | pack |"));
+}
+
+#[test]
+fn test_callback_concepts_available() {
+    let kb = AdkKnowledgeBase::new();
+    let docs = kb.get_version_docs(&kb.default_version).unwrap();
+    
+    for key in ["callbacks", "agent_callbacks", "model_callbacks", "tool_callbacks"] {
+        assert!(docs.concepts.contains_key(key), "Missing concept: {}", key);
+    }
+    assert!(!kb.get_best_practices_by_category("callbacks", None).is_empty());
+}
+
+#[tokio::test]
+async fn test_callback_implementation_patterns() {
+    let expert = DocumentationExpert::new();
+    
+    let response = expert.get_implementation_pattern("input_guardrail_callback", None).await.unwrap();
+    assert!(response.contains("before_model_callback"));
+    
+    for pattern in ["tool_argument_validation", "callback_logging"] {
+        let response = expert.get_implementation_pattern(pattern, None).await.unwrap();
+        assert!(!response.contains("not found"), "Missing pattern: {}", pattern);
+    }
+}
+
+#[tokio::test]
+async fn test_deployment_best_practices_category() {
+    let enforcer = crate::expert::best_practices::BestPracticesEnforcer::new();
+    let result = enforcer.get_best_practices("deploy my agent", Some("deployment"), None).await.unwrap();
+    
+    assert!(!result.practices.is_empty());
+    let all_text: String = result.practices.iter()
+        .map(|p| format!("{} {}", p.title, p.description))
+        .collect();
+    for target in ["Agent Engine", "Cloud Run", "GKE"] {
+        assert!(all_text.contains(target), "Missing deployment target: {}", target);
+    }
+}
+
+#[tokio::test]
+async fn test_model_best_practices_category() {
+    let kb = AdkKnowledgeBase::new();
+    let docs = kb.get_version_docs(&kb.default_version).unwrap();
+    for key in ["gemini_models", "litellm_integration", "model_selection", "model_quotas"] {
+        assert!(docs.concepts.contains_key(key), "Missing concept: {}", key);
+    }
+    
+    let enforcer = crate::expert::best_practices::BestPracticesEnforcer::new();
+    let result = enforcer.get_best_practices("choose a model", Some("models"), None).await.unwrap();
+    let all_text: String = result.practices.iter()
+        .map(|p| format!("{} {}", p.title, p.description))
+        .collect();
+    for topic in ["Flash", "LiteLlm", "429 RESOURCE_EXHAUSTED"] {
+        assert!(all_text.contains(topic), "Missing model guidance: {}", topic);
+    }
+    
+    let expert = DocumentationExpert::new();
+    let response = expert.get_implementation_pattern("litellm_model", None).await.unwrap();
+    assert!(response.contains("LiteLlm(model=\"openai/gpt-4o\")"));
+}
+
+#[tokio::test]
+async fn test_a2a_knowledge_available() {
+    let kb = AdkKnowledgeBase::new();
+    let docs = kb.get_version_docs(&kb.default_version).unwrap();
+    for key in ["a2a_protocol", "remote_a2a_agent", "agent_card", "a2a_vs_sub_agents"] {
+        assert!(docs.concepts.contains_key(key), "Missing concept: {}", key);
+    }
+    assert!(!kb.get_best_practices_by_category("a2a", None).is_empty());
+    
+    let expert = DocumentationExpert::new();
+    let response = expert.query_documentation("when should I use A2A instead of sub-agents", None).await.unwrap();
+    assert!(response.contains("Prefer Sub-Agents Within One Deployment"));
+    
+    let response = expert.query_documentation("what is an agent card", None).await.unwrap();
+    assert!(response.contains("Agent Cards"));
+    
+    let response = expert.get_implementation_pattern("consume_remote_a2a_agent", None).await.unwrap();
+    assert!(response.contains("RemoteA2aAgent"));
+}
+
+#[tokio::test]
+async fn test_evaluation_best_practices_for_testing_agents() {
+    let kb = AdkKnowledgeBase::new();
+    let docs = kb.get_version_docs(&kb.default_version).unwrap();
+    for key in ["agent_evaluation", "eval_sets", "trajectory_evaluation", "response_evaluation"] {
+        assert!(docs.concepts.contains_key(key), "Missing concept: {}", key);
+    }
+    
+    let enforcer = crate::expert::best_practices::BestPracticesEnforcer::new();
+    let result = enforcer.get_best_practices("testing agents", None, None).await.unwrap();
+    assert!(result.practices.len() >= 3);
+    assert!(result.practices.iter().all(|p| p.category == "evaluation"));
+    let all_text: String = result.practices.iter()
+        .map(|p| format!("{} {}", p.title, p.description))
+        .collect();
+    for topic in ["Trajectories", "eval sets", "AgentEvaluator"] {
+        assert!(all_text.contains(topic), "Missing evaluation guidance: {}", topic);
+    }
+    
+    let result = enforcer.get_best_practices("ci", Some("evaluation"), None).await.unwrap();
+    assert!(result.practices.iter().any(|p| p.title == "Run Evaluations in CI"));
+}
+
+#[tokio::test]
+async fn test_streaming_knowledge_available() {
+    let kb = AdkKnowledgeBase::new();
+    let docs = kb.get_version_docs(&kb.default_version).unwrap();
+    for key in ["sse_streaming", "bidi_streaming", "live_request_queue", "streaming_tools", "run_config"] {
+        assert!(docs.concepts.contains_key(key), "Missing concept: {}", key);
+    }
+    assert!(docs.implementation_patterns["bidi_live_streaming"].code_examples[0].code.contains("run_live"));
+    
+    let enforcer = crate::expert::best_practices::BestPracticesEnforcer::new();
+    let result = enforcer.get_best_practices("live", Some("streaming"), None).await.unwrap();
+    assert!(result.practices.iter().any(|p| p.title == "Use Live API Models for Bidi-streaming"));
+    
+    let availability = kb.feature_availability("SSE Streaming").unwrap();
+    assert_eq!(availability.introduced_in.as_deref(), Some("0.1.0"));
+    assert!(availability.versions.iter()
+        .filter(|v| v.version == "1.0.0")
+        .all(|v| v.availability == crate::expert::adk_knowledge::Availability::Available));
+}
+
+#[tokio::test]
+async fn test_mcp_integration_knowledge_available() {
+    let kb = AdkKnowledgeBase::new();
+    let docs = kb.get_version_docs(&kb.default_version).unwrap();
+    for key in ["mcp_tools", "mcp_toolset", "mcp_connection_params", "mcp_authentication"] {
+        assert!(docs.concepts.contains_key(key), "Missing concept: {}", key);
+    }
+    for key in ["mcp_toolset_stdio", "mcp_toolset_remote", "mcp_toolset_oauth"] {
+        assert!(docs.implementation_patterns[key].code_examples[0].code.contains("MCPToolset("), "Missing MCPToolset usage: {}", key);
+    }
+    
+    let enforcer = crate::expert::best_practices::BestPracticesEnforcer::new();
+    let result = enforcer.get_best_practices("mcp", None, None).await.unwrap();
+    assert!(result.practices.len() >= 3);
+    assert!(result.practices.iter().all(|p| p.category == "mcp"));
+    
+    let expert = DocumentationExpert::new();
+    let response = expert.query_documentation("what is MCPToolset", None).await.unwrap();
+    assert!(response.contains("MCPToolset"));
+    assert!(response.contains("tool_filter"));
+}
+
+#[tokio::test]
+async fn test_safety_best_practices_category() {
+    let kb = AdkKnowledgeBase::new();
+    let docs = kb.get_version_docs(&kb.default_version).unwrap();
+    for key in ["safety_guardrails", "content_filtering", "tool_confirmation", "grounding"] {
+        assert!(docs.concepts.contains_key(key), "Missing concept: {}", key);
+    }
+    assert!(docs.implementation_patterns["tool_confirmation_flow"].code_examples[0].code.contains("require_confirmation"));
+    
+    assert!(kb.best_practice_categories(None).iter().any(|(category, count)| category == "safety" && *count == 6));
+    
+    let enforcer = crate::expert::best_practices::BestPracticesEnforcer::new();
+    let result = enforcer.get_best_practices("safety", None, None).await.unwrap();
+    assert_eq!(result.practices.iter().filter(|p| p.category == "safety").count(), 6);
+    let guardrails = result.practices.iter().find(|p| p.title == "Screen Inputs with Guardrail Callbacks").unwrap();
+    assert!(guardrails.documentation_ref.ends_with("#in-agent-callbacks"));
+}
+
+#[test]
+fn test_fit_response_summarizes_past_code_blocks() {
+    use crate::expert::documentation::{ResponseDetail, fit_response};
+    
+    let markdown = "## Pattern: Callbacks\n\n**Version:** 1.0.0\n\n```python\ndef guard():\n\n    return None\n```\n\n\
+        Guardrails run before the model. They can block requests.\n\n\
+        - [Callbacks](https://google.github.io/adk-docs/callbacks/)\n- [Safety](https://google.github.io/adk-docs/safety/)";
+    
+    assert_eq!(fit_response(markdown, ResponseDetail::Full, None), markdown);
+    
+    let summary = fit_response(markdown, ResponseDetail::Summary, None);
+    assert!(summary.starts_with("## Pattern: Callbacks\n\n**Version:** 1.0.0\n\nGuardrails run before the model. They can block requests."));
+    assert!(summary.contains("- [Callbacks](https://google.github.io/adk-docs/callbacks/)\n- [Safety](https://google.github.io/adk-docs/safety/)"));
+    assert!(!summary.contains("def guard"));
+    
+    let brief = fit_response(markdown, ResponseDetail::Full, Some(150));
+    assert!(brief.chars().count() <= 150);
+    assert!(brief.contains("Guardrails run before the model."));
+    assert!(!brief.contains("They can block requests."));
+}
+
+#[test]
+fn test_markdown_output_conversions() {
+    use crate::expert::documentation::{markdown_to_json, markdown_to_plain};
+    
+    let markdown = "# Google ADK Best Practices\n\n**Scenario:** safety\n**Version:** 1.0.0\n\n\
+        ## Best Practices\n\n- **Guardrails**: Use `before_model_callback`.\n\n---\n\n\
+        ### Official References:\n- [Safety](https://google.github.io/adk-docs/safety/)\n\n*Generated from the knowledge base*";
+    
+    let plain = markdown_to_plain(markdown);
+    assert!(plain.starts_with("Google ADK Best Practices\n\nScenario: safety"));
+    assert!(plain.contains("- Guardrails: Use before_model_callback."));
+    assert!(plain.contains("- Safety (https://google.github.io/adk-docs/safety/)"));
+    assert!(plain.ends_with("Generated from the knowledge base"));
+    assert!(!plain.contains("**") && !plain.contains('`') && !plain.contains('#'));
+    
+    let document = markdown_to_json(markdown, Some(serde_json::json!({ "practices": [] })));
+    assert_eq!(document["title"], "Google ADK Best Practices");
+    assert_eq!(document["fields"]["Scenario"], "safety");
+    assert_eq!(document["fields"]["Version"], "1.0.0");
+    assert_eq!(document["sections"][0]["heading"], "Best Practices");
+    assert_eq!(document["sections"][0]["content"], "- **Guardrails**: Use `before_model_callback`.");
+    assert_eq!(document["sections"][1]["heading"], "Official References");
+    assert_eq!(document["references"][0]["url"], "https://google.github.io/adk-docs/safety/");
+    assert!(document["result"]["practices"].is_array());
+    assert!(markdown_to_json(markdown, None).get("result").is_none());
+}
+
+#[test]
+fn test_rank_concepts_orders_by_relevance() {
+    let kb = AdkKnowledgeBase::new();
+    let matches = kb.rank_concepts("session state prefixes", None, 5);
+    
+    assert!(matches.len() > 1);
+    assert_eq!(matches[0].key, "session_state");
+    assert!(matches.windows(2).all(|w| w[0].score >= w[1].score));
+    assert!(matches.iter().all(|m| m.score > 0.0 && m.score <= 1.0));
+    
+    // Limit is respected and stop words alone match nothing
+    assert_eq!(kb.rank_concepts("session", None, 2).len(), 2);
+    assert!(kb.rank_concepts("what is the", None, 5).is_empty());
+}
+
+#[tokio::test]
+async fn test_query_documentation_returns_ranked_matches() {
+    let expert = DocumentationExpert::new();
+    let response = expert.query_documentation("model callbacks", None).await.unwrap();
+    
+    assert!(response.contains("### Most Relevant: Model Callbacks"));
+    assert!(response.contains("### Other Matches:"));
+    assert!(response.contains("relevance:"));
+}
+
+#[test]
+fn test_classify_query_intents() {
+    use crate::expert::query_intent::{classify_query, QueryIntent};
+    
+    assert_eq!(classify_query("What is a session?"), QueryIntent::ConceptLookup);
+    assert_eq!(classify_query("How do I deploy to Cloud Run?"), QueryIntent::HowTo);
+    assert_eq!(classify_query("My tool callback fails with an error"), QueryIntent::Troubleshooting);
+    assert_eq!(classify_query("Best practices for state prefixes"), QueryIntent::BestPractice);
+    assert_eq!(classify_query("How do I migrate to the new runner API?"), QueryIntent::Migration);
+}
+
+#[tokio::test]
+async fn test_query_documentation_routes_by_intent() {
+    let expert = DocumentationExpert::new();
+    
+    let how_to = expert.query_documentation("How do I deploy to GKE?", None).await.unwrap();
+    assert!(how_to.contains("**Query Type:** How-To"));
+    assert!(how_to.contains("Deploy to GKE"));
+    
+    let practice = expert.query_documentation("best practice for callbacks", None).await.unwrap();
+    assert!(practice.contains("**Query Type:** Best Practice"));
+    assert!(practice.contains("**Category:** callbacks"));
+    
+    let troubleshooting = expert.query_documentation("session state error after restart", None).await.unwrap();
+    assert!(troubleshooting.contains("**Query Type:** Troubleshooting"));
+    
+    // Migration queries fall back to concepts when no version features are recorded
+    let migration = expert.query_documentation("migrate session service", None).await.unwrap();
+    assert!(migration.contains("### Most Relevant:"));
+}
+
+#[tokio::test]
+async fn test_knowledge_store_survives_restart() {
+    use crate::expert::adk_knowledge::{ConceptInfo, DocumentationUrls, VersionDocs};
+    use crate::expert::knowledge_store::KnowledgeStore;
+    use std::collections::HashMap;
+    
+    let data_dir = std::env::temp_dir().join(format!("arkaft-kb-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&data_dir);
+    
+    let mut concepts = HashMap::new();
+    concepts.insert("artifact_service".to_string(), ConceptInfo {
+        name: "ArtifactService".to_string(),
+        description: "Stores versioned binary artifacts for sessions.".to_string(),
+        examples: Vec::new(),
+        related_concepts: Vec::new(),
+        documentation_refs: vec!["https://google.github.io/adk-docs/artifacts/".to_string()],
+        source_url: None,
+        last_updated: None,
+    });
+    let enrichment = VersionDocs {
+        version: "1.0.0".to_string(),
+        official_urls: DocumentationUrls::default(),
+        concepts,
+        best_practices: Vec::new(),
+        implementation_patterns: HashMap::new(),
+        version_features: Vec::new(),
+        glossary: Vec::new(),
+        templates: HashMap::new(),
+        changelog: Vec::new(),
+        provenance: HashMap::new(),
+        citations: HashMap::new(),
+    };
+    
+    {
+        let mut expert = DocumentationExpert::with_store(KnowledgeStore::open(&data_dir).unwrap()).unwrap();
+        expert.persist_version_docs(enrichment).unwrap();
+    }
+    
+    // A fresh expert sees both the stored enrichment and the compiled-in defaults
+    let expert = DocumentationExpert::with_store(KnowledgeStore::open(&data_dir).unwrap()).unwrap();
+    let docs = expert.knowledge_base.get_version_docs("1.0.0").unwrap();
+    assert!(docs.concepts.contains_key("artifact_service"));
+    assert!(docs.concepts.contains_key("session"));
+    
+    // Only the enrichment is written to disk
+    let stored = expert.store.as_ref().unwrap().load_version("1.0.0").unwrap().unwrap();
+    assert_eq!(stored.concepts.len(), 1);
+    
+    let _ = std::fs::remove_dir_all(&data_dir);
+}
+
+#[test]
+fn test_validation_history_survives_restart() {
+    use crate::expert::knowledge_store::KnowledgeStore;
+    use crate::expert::validation_history::{TrendDirection, ValidationHistory, ValidationRecord};
+    
+    let data_dir = std::env::temp_dir().join(format!("arkaft-history-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&data_dir);
+    let record = |project_id: &str, recorded_at: i64, compliance_score: u8| ValidationRecord {
+        project_id: project_id.to_string(),
+        recorded_at,
+        compliance_score,
+        is_compliant: compliance_score >= 80,
+        errors: 0,
+        warnings: 0,
+        infos: 0,
+    };
+    
+    {
+        let history = ValidationHistory::new(Some(KnowledgeStore::open(&data_dir).unwrap()));
+        history.record(record("checkout", 200, 70)).unwrap();
+        history.record(record("checkout", 100, 55)).unwrap();
+        history.record(record("search", 150, 90)).unwrap();
+        history.record(record("checkout", 300, 85)).unwrap();
+    }
+    
+    // Trends are per project, chronological, and limited to the most recent validations
+    let history = ValidationHistory::new(Some(KnowledgeStore::open(&data_dir).unwrap()));
+    let trend = history.trend("checkout", 10).unwrap().unwrap();
+    let scores: Vec<u8> = trend.records.iter().map(|record| record.compliance_score).collect();
+    assert_eq!(scores, vec![55, 70, 85]);
+    assert_eq!(trend.change, 30);
+    assert_eq!(trend.best_score, 85);
+    assert_eq!(trend.direction, TrendDirection::Improving);
+    
+    let recent = history.trend("checkout", 2).unwrap().unwrap();
+    assert_eq!(recent.records.len(), 2);
+    assert_eq!(recent.change, 15);
+    assert!(history.trend("billing", 10).unwrap().is_none());
+    
+    let _ = std::fs::remove_dir_all(&data_dir);
+}
+
+/// Fetcher returning canned results for auto-update tests
+struct StubFetcher {
+    result: Option<Vec<crate::expert::adk_knowledge::VersionDocs>>,
+}
+
+#[async_trait::async_trait]
+impl crate::expert::knowledge_source::KnowledgeSource for StubFetcher {
+    fn name(&self) -> &str {
+        "stub"
+    }
+    
+    fn priority(&self) -> i32 {
+        0
+    }
+    
+    async fn fetch_all(&self) -> crate::utils::error::ArkaftResult<Vec<crate::expert::adk_knowledge::VersionDocs>> {
+        self.result
+            .clone()
+            .ok_or_else(|| crate::utils::error::ArkaftMcpError::knowledge_update("feed unavailable"))
+    }
+}
+
+fn auto_updater(
+    result: Option<Vec<crate::expert::adk_knowledge::VersionDocs>>,
+    knowledge_base: AdkKnowledgeBase,
+) -> (crate::expert::auto_update::AutoUpdater, SharedExperts, std::sync::Arc<crate::utils::ServerMetrics>) {
+    use crate::expert::auto_update::{AutoUpdateSettings, AutoUpdater};
+    use std::sync::Arc;
+    
+    let experts = SharedExperts::with_knowledge_base(knowledge_base);
+    let metrics = Arc::new(crate::utils::ServerMetrics::new());
+    let updater = AutoUpdater::new(
+        AutoUpdateSettings::default(),
+        Arc::new(StubFetcher { result }),
+        experts.clone(),
+        Arc::clone(&metrics),
+    );
+    (updater, experts, metrics)
+}
+
+#[tokio::test]
+async fn test_auto_update_merges_new_versions() {
+    use crate::expert::adk_knowledge::{DocumentationUrls, VersionDocs};
+    use std::collections::HashMap;
+    use std::sync::atomic::Ordering;
+    
+    let update = VersionDocs {
+        version: "1.1.0".to_string(),
+        official_urls: DocumentationUrls::default(),
+        concepts: HashMap::new(),
+        best_practices: Vec::new(),
+        implementation_patterns: HashMap::new(),
+        version_features: Vec::new(),
+        glossary: Vec::new(),
+        templates: HashMap::new(),
+        changelog: Vec::new(),
+        provenance: HashMap::new(),
+        citations: HashMap::new(),
+    };
+    let (updater, shared, metrics) = auto_updater(Some(vec![update]), AdkKnowledgeBase::new());
+    
+    assert_eq!(updater.refresh_once().await.unwrap(), 1);
+    assert!(shared.documentation.read().await.get_available_versions().contains(&"1.1.0".to_string()));
+    assert!(shared.enforcer.read().await.knowledge_base.get_available_versions().contains(&"1.1.0".to_string()));
+    assert_eq!(metrics.refresh_attempts.load(Ordering::Relaxed), 1);
+    assert_eq!(metrics.refresh_failures.load(Ordering::Relaxed), 0);
+}
+
+#[tokio::test]
+async fn test_auto_update_counts_failures() {
+    use std::sync::atomic::Ordering;
+    
+    let (updater, _shared, metrics) = auto_updater(None, AdkKnowledgeBase::new());
+    
+    assert!(updater.refresh_once().await.is_err());
+    assert_eq!(metrics.refresh_attempts.load(Ordering::Relaxed), 1);
+    assert_eq!(metrics.refresh_failures.load(Ordering::Relaxed), 1);
+}
+
+#[tokio::test]
+async fn test_auto_update_refreshes_at_startup() {
+    let update = AdkKnowledgeBase::bundled_version_docs("1.1.0".to_string());
+    let (updater, shared, _metrics) = auto_updater(Some(vec![update]), AdkKnowledgeBase::new());
+    
+    // The default interval is hours long, so only a startup refresh can merge the update
+    let task = updater.spawn();
+    let mut merged = false;
+    for _ in 0..100 {
+        if shared.enforcer.read().await.knowledge_base.get_available_versions().contains(&"1.1.0".to_string()) {
+            merged = true;
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    task.abort();
+    assert!(merged);
+}
+
+#[tokio::test]
+async fn test_auto_update_respects_disabled_flag() {
+    let mut knowledge_base = AdkKnowledgeBase::new();
+    knowledge_base.version_config.auto_update_enabled = false;
+    let (updater, _shared, _metrics) = auto_updater(Some(Vec::new()), knowledge_base);
+    
+    assert!(updater.spawn_if_enabled().await.is_none());
+}
+
+#[test]
+fn test_auto_update_backoff_and_jitter() {
+    use crate::expert::auto_update::AutoUpdateSettings;
+    use std::time::Duration;
+    
+    let settings = AutoUpdateSettings {
+        interval: Duration::from_secs(60),
+        jitter: Duration::from_secs(5),
+        max_backoff: Duration::from_secs(300),
+    };
+    
+    let first = settings.next_delay(0);
+    assert!(first >= Duration::from_secs(60) && first <= Duration::from_secs(65));
+    
+    // Each failure doubles the wait until the cap is reached
+    let after_two = settings.next_delay(2);
+    assert!(after_two >= Duration::from_secs(240) && after_two <= Duration::from_secs(245));
+    
+    let capped = settings.next_delay(10);
+    assert!(capped >= Duration::from_secs(300) && capped <= Duration::from_secs(305));
+}
+
+/// Knowledge source returning fixed docs at a fixed priority
+struct FixedSource {
+    name: &'static str,
+    priority: i32,
+    docs: Option<Vec<crate::expert::adk_knowledge::VersionDocs>>,
+}
+
+#[async_trait::async_trait]
+impl crate::expert::knowledge_source::KnowledgeSource for FixedSource {
+    fn name(&self) -> &str {
+        self.name
+    }
+    
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+    
+    async fn fetch_all(&self) -> crate::utils::error::ArkaftResult<Vec<crate::expert::adk_knowledge::VersionDocs>> {
+        self.docs
+            .clone()
+            .ok_or_else(|| crate::utils::error::ArkaftMcpError::knowledge_update("source unavailable"))
+    }
+}
+
+/// Upstream documentation server answering with fixed text after an optional delay
+struct FixedUpstream {
+    name: &'static str,
+    delay: std::time::Duration,
+    answer: Option<Vec<&'static str>>,
+}
+
+#[async_trait::async_trait]
+impl crate::expert::federation::UpstreamDocs for FixedUpstream {
+    fn name(&self) -> &str {
+        self.name
+    }
+    
+    fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(200)
+    }
+    
+    async fn search(&self, _query: &str) -> crate::utils::error::ArkaftResult<Vec<crate::expert::federation::UpstreamSection>> {
+        tokio::time::sleep(self.delay).await;
+        let answer = self.answer
+            .clone()
+            .ok_or_else(|| crate::utils::error::ArkaftMcpError::McpProtocol("upstream unavailable".to_string()))?;
+        Ok(answer.into_iter().filter_map(|text| crate::expert::federation::UpstreamSection::from_text(self.name, text)).collect())
+    }
+}
+
+fn docs_with_concept(version: &str, key: &str, description: &str) -> crate::expert::adk_knowledge::VersionDocs {
+    use crate::expert::adk_knowledge::{ConceptInfo, DocumentationUrls, VersionDocs};
+    use std::collections::HashMap;
+    
+    let mut concepts = HashMap::new();
+    concepts.insert(key.to_string(), ConceptInfo {
+        name: key.to_string(),
+        description: description.to_string(),
+        examples: Vec::new(),
+        related_concepts: Vec::new(),
+        documentation_refs: Vec::new(),
+        source_url: None,
+        last_updated: None,
+    });
+    VersionDocs {
+        version: version.to_string(),
+        official_urls: DocumentationUrls::default(),
+        concepts,
+        best_practices: Vec::new(),
+        implementation_patterns: HashMap::new(),
+        version_features: Vec::new(),
+        glossary: Vec::new(),
+        templates: HashMap::new(),
+        changelog: Vec::new(),
+        provenance: HashMap::new(),
+        citations: HashMap::new(),
+    }
+}
+
+#[test]
+fn test_federation_config_accepts_stdio_and_http_servers() {
+    use crate::expert::federation::{FederationConfig, UpstreamTransport};
+    
+    let config: FederationConfig = serde_json::from_str(r#"{"servers": [
+        {"name": "general-docs", "command": "npx", "args": ["-y", "docs-mcp"], "tool": "search_docs"},
+        {"name": "remote", "url": "https://docs.example.com/mcp", "tool": "search", "query_argument": "q", "arguments": {"library": "adk"}, "timeout_secs": 3}
+    ]}"#).unwrap();
+    
+    assert_eq!(config.servers[0].transport, UpstreamTransport::Stdio {
+        command: "npx".to_string(),
+        args: vec!["-y".to_string(), "docs-mcp".to_string()],
+    });
+    assert_eq!(config.servers[0].query_argument, "query");
+    assert_eq!(config.servers[1].transport, UpstreamTransport::Http { url: "https://docs.example.com/mcp".to_string() });
+    assert_eq!((config.servers[1].query_argument.as_str(), config.servers[1].timeout_secs), ("q", Some(3)));
+    assert_eq!(config.servers[1].arguments["library"], "adk");
+}
+
+#[tokio::test]
+async fn test_federation_merges_upstreams_in_order_and_dedupes() {
+    use crate::expert::federation::{Federation, UpstreamDocs};
+    use std::sync::Arc;
+    use std::time::Duration;
+    
+    let upstreams: Vec<Arc<dyn UpstreamDocs>> = vec![
+        Arc::new(FixedUpstream { name: "slow-docs", delay: Duration::from_millis(50), answer: Some(vec![
+            "## Deploying to Cloud Run\n\nUse `adk deploy cloud_run` to ship an agent, see https://google.github.io/adk-docs/deploy/cloud-run/.",
+        ]) }),
+        Arc::new(FixedUpstream { name: "general-docs", delay: Duration::ZERO, answer: Some(vec![
+            "A Session tracks a single conversation thread.",
+            "Use adk deploy cloud_run to ship an agent. See https://google.github.io/adk-docs/deploy/cloud-run/",
+            "Vertex AI Agent Engine hosts agents as a managed service.",
+            "   ",
+        ]) }),
+        Arc::new(FixedUpstream { name: "broken", delay: Duration::ZERO, answer: None }),
+        Arc::new(FixedUpstream { name: "hung", delay: Duration::from_secs(5), answer: Some(vec!["Never arrives."]) }),
+    ];
+    let federation = Federation::new(upstreams);
+    assert_eq!(federation.upstream_names(), vec!["slow-docs", "general-docs", "broken", "hung"]);
+    
+    // Sections keep upstream order even though the first upstream answers last
+    let sections = federation.search("deploy").await;
+    assert_eq!(sections.iter().map(|s| s.source.as_str()).collect::<Vec<_>>(), vec!["slow-docs", "general-docs", "general-docs", "general-docs"]);
+    assert_eq!(sections[0].title.as_deref(), Some("Deploying to Cloud Run"));
+    assert_eq!(sections[0].url.as_deref(), Some("https://google.github.io/adk-docs/deploy/cloud-run/"));
+    
+    // Local content and repeated upstream answers are dropped
+    let local = "## Session\n\nA session tracks a single conversation thread!";
+    let kept = federation.federate("deploy", local).await;
+    assert_eq!(kept.len(), 2);
+    assert_eq!(kept[0].source, "slow-docs");
+    assert_eq!(kept[1].text, "Vertex AI Agent Engine hosts agents as a managed service.");
+    
+    assert!(Federation::default().federate("deploy", local).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_sources_merge_in_priority_order() {
+    use crate::expert::knowledge_source::{BundledSource, KnowledgeSource};
+    use std::sync::Arc;
+    
+    // Listed high-priority first to show ordering comes from priority, not position
+    let sources: Vec<Arc<dyn KnowledgeSource>> = vec![
+        Arc::new(FixedSource { name: "pack", priority: 100, docs: Some(vec![docs_with_concept("1.0.0", "agent", "Overridden by pack.")]) }),
+        Arc::new(FixedSource { name: "broken", priority: 50, docs: None }),
+        Arc::new(BundledSource::new()),
+    ];
+    
+    let kb = AdkKnowledgeBase::from_sources(&sources).await;
+    let docs = kb.get_version_docs("1.0.0").unwrap();
+    
+    assert_eq!(docs.concepts["agent"].description, "Overridden by pack.");
+    assert!(docs.concepts.contains_key("session"), "bundled content should remain");
+}
+
+#[tokio::test]
+async fn test_local_pack_source_reads_directory() {
+    use crate::expert::knowledge_source::{KnowledgeSource, LocalPackSource};
+    
+    let pack_dir = std::env::temp_dir().join(format!("arkaft-packs-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&pack_dir);
+    std::fs::create_dir_all(&pack_dir).unwrap();
+    
+    let single = docs_with_concept("1.0.0", "planner", "Plans multi-step work.");
+    let multiple = vec![docs_with_concept("2.0.0", "flow", "Controls LLM interaction.")];
+    std::fs::write(pack_dir.join("a.json"), serde_json::to_string(&single).unwrap()).unwrap();
+    std::fs::write(pack_dir.join("b.json"), serde_json::to_string(&multiple).unwrap()).unwrap();
+    std::fs::write(pack_dir.join("notes.txt"), "ignored").unwrap();
+    
+    let source = LocalPackSource::new(&pack_dir);
+    assert_eq!(source.fetch_all().await.unwrap().len(), 2);
+    
+    let v2 = source.fetch_version("2.0.0").await.unwrap().unwrap();
+    assert!(v2.concepts.contains_key("flow"));
+    assert!(source.fetch_version("3.0.0").await.unwrap().is_none());
+    
+    let _ = std::fs::remove_dir_all(&pack_dir);
+}
+
+fn community_pack(trust_level: crate::expert::adk_knowledge::TrustLevel) -> crate::expert::knowledge_source::KnowledgePack {
+    let mut docs = docs_with_concept("1.0.0", "session", "Community take on sessions.");
+    docs.merge_from(docs_with_concept("1.0.0", "vector_memory", "Community memory backed by a vector store."));
+    
+    crate::expert::knowledge_source::KnowledgePack {
+        name: "adk-community-extras".to_string(),
+        url: Some("https://example.com/adk-extras".to_string()),
+        trust_level,
+        docs: vec![docs],
+    }
+}
+
+#[test]
+fn test_community_pack_never_overrides_official_entries() {
+    use crate::expert::adk_knowledge::TrustLevel;
+    
+    let mut kb = AdkKnowledgeBase::new();
+    let official_session = kb.get_version_docs("1.0.0").unwrap().concepts["session"].description.clone();
+    
+    kb.merge_knowledge_pack(community_pack(TrustLevel::Community));
+    let docs = kb.get_version_docs("1.0.0").unwrap();
+    
+    assert_eq!(docs.concepts["session"].description, official_session);
+    assert!(docs.concept_provenance("session").is_none());
+    
+    let provenance = docs.concept_provenance("vector_memory").unwrap();
+    assert_eq!(provenance.source_name, "adk-community-extras");
+    assert_eq!(provenance.trust_level, TrustLevel::Community);
+}
+
+#[test]
+fn test_official_docs_replace_community_entries() {
+    use crate::expert::adk_knowledge::TrustLevel;
+    
+    let mut kb = AdkKnowledgeBase::new();
+    kb.merge_knowledge_pack(community_pack(TrustLevel::Verified));
+    kb.merge_version_docs(docs_with_concept("1.0.0", "vector_memory", "Official vector memory."));
+    
+    let docs = kb.get_version_docs("1.0.0").unwrap();
+    assert_eq!(docs.concepts["vector_memory"].description, "Official vector memory.");
+    assert!(docs.concept_provenance("vector_memory").is_none());
+}
+
+#[tokio::test]
+async fn test_responses_flag_community_guidance() {
+    use crate::expert::adk_knowledge::TrustLevel;
+    
+    let mut expert = DocumentationExpert::new();
+    expert.knowledge_base.merge_knowledge_pack(community_pack(TrustLevel::Community));
+    
+    let community = expert.query_documentation("vector memory", None).await.unwrap();
+    assert!(community.contains("Community knowledge pack 'adk-community-extras'"));
+    assert!(community.contains("not part of the official Google ADK documentation"));
+    
+    let official = expert.query_documentation("What is a session?", None).await.unwrap();
+    assert!(official.contains("**Source:** Official Google ADK documentation"));
+}
+
+#[tokio::test]
+async fn test_local_pack_source_reads_community_pack() {
+    use crate::expert::adk_knowledge::TrustLevel;
+    use crate::expert::knowledge_source::{KnowledgeSource, LocalPackSource};
+    
+    let pack_path = std::env::temp_dir().join(format!("arkaft-community-pack-{}.json", std::process::id()));
+    std::fs::write(&pack_path, serde_json::to_string(&community_pack(TrustLevel::Community)).unwrap()).unwrap();
+    
+    let docs = LocalPackSource::new(&pack_path).fetch_version("1.0.0").await.unwrap().unwrap();
+    assert_eq!(
+        docs.concept_provenance("vector_memory").map(|p| p.source_name.as_str()),
+        Some("adk-community-extras")
+    );
+    
+    let _ = std::fs::remove_file(&pack_path);
+}
+
+#[test]
+fn test_retrieve_passages_spans_entry_kinds() {
+    use crate::expert::adk_knowledge::PassageKind;
+    
+    let kb = AdkKnowledgeBase::new();
+    let passages = kb.retrieve_passages("runner", None, 5);
+    
+    assert_eq!(passages[0].kind, PassageKind::Glossary);
+    assert!(passages.iter().any(|p| p.kind == PassageKind::Pattern));
+    assert!(passages.windows(2).all(|w| w[0].score >= w[1].score));
+    assert!(kb.retrieve_passages("xyzzy", None, 5).is_empty());
+}
+
+#[tokio::test]
+async fn test_query_without_concept_match_is_grounded_in_passages() {
+    let expert = DocumentationExpert::new();
+    let response = expert.query_documentation("runner", None).await.unwrap();
+    
+    assert!(response.contains("matching entries in the ADK knowledge base"));
+    assert!(response.contains("### Supporting Passages"));
+    assert!(response.contains("**Runner** (Glossary)"));
+    assert!(response.contains("[1]"));
+    assert!(!response.contains("This query is processed using the comprehensive ADK knowledge base"));
+}
+
+#[tokio::test]
+async fn test_query_with_no_matches_says_not_found() {
+    let expert = DocumentationExpert::new();
+    let response = expert.query_documentation("xyzzy", None).await.unwrap();
+    
+    assert!(response.contains("No relevant content was found"));
+    assert!(response.contains("**Confidence:** None"));
+    assert!(!response.contains("comprehensive ADK knowledge base"));
+}
+
+#[tokio::test]
+async fn test_not_found_suggests_closest_topics() {
+    let expert = DocumentationExpert::new();
+    let response = expert.query_documentation("sesion", None).await.unwrap();
+    
+    assert!(response.contains("### Closest Related Topics:"));
+    assert!(response.contains("Session (`session`)"));
+}
+
+#[tokio::test]
+async fn test_answers_include_confidence() {
+    let expert = DocumentationExpert::new();
+    
+    let strong = expert.query_documentation("What is a session?", None).await.unwrap();
+    assert!(strong.contains("**Confidence:** High"));
+    
+    let how_to = expert.query_documentation("How do I deploy to GKE?", None).await.unwrap();
+    assert!(how_to.contains("**Confidence:**"));
+}
+
+#[test]
+fn test_confidence_levels() {
+    use crate::expert::documentation::Confidence;
+    
+    assert_eq!(Confidence::from_score(0.9), Confidence::High);
+    assert_eq!(Confidence::from_score(0.4), Confidence::Medium);
+    assert_eq!(Confidence::from_score(0.1), Confidence::Low);
+    assert_eq!(Confidence::from_score(0.0), Confidence::NotFound);
+}
+
+#[tokio::test]
+async fn test_repeated_queries_hit_the_cache() {
+    let expert = DocumentationExpert::new();
+    
+    let first = expert.query_documentation("What is a session?", None).await.unwrap();
+    let second = expert.query_documentation("  what IS a   session? ", None).await.unwrap();
+    assert_eq!(first, second);
+    
+    let stats = expert.cache_stats();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+    assert_eq!(stats.hit_rate(), 50.0);
+    
+    // A different version is a different cache entry
+    expert.query_documentation("What is a session?", Some("1.0.0")).await.unwrap();
+    expert.query_documentation("What is a session?", Some("2.0.0")).await.unwrap();
+    assert_eq!(expert.cache_stats().entries, 2);
+}
+
+#[test]
+fn test_query_cache_evicts_least_recently_used() {
+    use crate::expert::query_cache::QueryCache;
+    
+    let mut cache = QueryCache::new(2);
+    cache.insert("first", "1.0.0", "one".to_string());
+    cache.insert("second", "1.0.0", "two".to_string());
+    
+    // Touch "first" so "second" becomes the eviction candidate
+    assert!(cache.get("first", "1.0.0").is_some());
+    cache.insert("third", "1.0.0", "three".to_string());
+    
+    assert!(cache.get("second", "1.0.0").is_none());
+    assert_eq!(cache.get("first", "1.0.0").as_deref(), Some("one"));
+    assert_eq!(cache.get("third", "1.0.0").as_deref(), Some("three"));
+    assert_eq!(cache.stats().entries, 2);
+}
+
+#[tokio::test]
+async fn test_knowledge_updates_invalidate_the_cache() {
+    let mut expert = DocumentationExpert::new();
+    let before = expert.query_documentation("vector memory", None).await.unwrap();
+    
+    expert.persist_version_docs(docs_with_concept("1.0.0", "vector_memory", "Long-term memory backed by a vector store.")).unwrap();
+    let after = expert.query_documentation("vector memory", None).await.unwrap();
+    
+    assert_ne!(before, after);
+    assert!(after.contains("Long-term memory backed by a vector store."));
+}
+
+#[tokio::test]
+async fn test_refreshed_entries_carry_source_and_update_time() {
+    use crate::expert::documentation::format_freshness_note;
+    
+    let mut expert = DocumentationExpert::new();
+    let bundled = expert.query_documentation("What is a session?", None).await.unwrap();
+    assert!(bundled.contains("**Last updated:** not recorded (bundled with the server)"));
+    
+    let mut docs = docs_with_concept("1.0.0", "vector_memory", "Long-term memory backed by a vector store.");
+    docs.concepts.get_mut("vector_memory").unwrap().source_url = Some("https://google.github.io/adk-docs/sessions/memory/".to_string());
+    expert.persist_version_docs(docs).unwrap();
+    
+    let concept = &expert.knowledge_base.get_version_docs("1.0.0").unwrap().concepts["vector_memory"];
+    assert!(concept.last_updated.is_some_and(|timestamp| timestamp > 0));
+    let response = expert.query_documentation("vector memory", None).await.unwrap();
+    assert!(response.contains("**Source URL:** [https://google.github.io/adk-docs/sessions/memory/](https://google.github.io/adk-docs/sessions/memory/) · **Last updated:** "));
+    
+    // Entries that already know when they were updated keep their own time
+    let mut docs = docs_with_concept("1.0.0", "vector_memory", "Dated elsewhere.");
+    docs.concepts.get_mut("vector_memory").unwrap().last_updated = Some(1_700_000_000);
+    docs.stamp_last_updated(1_800_000_000);
+    assert_eq!(docs.concepts["vector_memory"].last_updated, Some(1_700_000_000));
+    assert!(format_freshness_note(None, Some(1_700_000_000)).ends_with("2023-11-14 22:13 UTC"));
+}
+
+#[tokio::test]
+async fn test_zero_capacity_disables_caching() {
+    let expert = DocumentationExpert::new().with_cache_capacity(0);
+    expert.query_documentation("What is a session?", None).await.unwrap();
+    expert.query_documentation("What is a session?", None).await.unwrap();
+    
+    let stats = expert.cache_stats();
+    assert_eq!((stats.hits, stats.entries), (0, 0));
+}
+
+#[test]
+fn test_version_summaries_fold_aliases_and_report_status() {
+    let mut kb = AdkKnowledgeBase::new();
+    kb.version_config.add_version("0.9.0".to_string());
+    kb.merge_version_docs(docs_with_concept("1.1.0", "session", "Newer sessions."));
+    
+    let summaries = kb.version_summaries();
+    let versions: Vec<&str> = summaries.iter().map(|s| s.version.as_str()).collect();
+    assert_eq!(versions, vec!["1.0.0", "0.9.0", "1.1.0"]);
+    
+    assert_eq!(summaries[0].status, VersionStatus::Default);
+    assert_eq!(summaries[0].aliases, vec!["latest".to_string(), "stable".to_string()]);
+    assert_eq!(summaries[1].status, VersionStatus::Unavailable);
+    assert_eq!(summaries[2].status, VersionStatus::Supported);
+    assert!(summaries[2].aliases.is_empty());
+}
+
+#[test]
+fn test_search_code_examples_ranks_and_filters_by_language() {
+    let kb = AdkKnowledgeBase::new();
+    
+    let matches = kb.search_code_examples("guardrail", None, None, 5);
+    assert!(!matches.is_empty());
+    assert_eq!(matches[0].example.title, "Keyword guardrail");
+    
+    let yaml = kb.search_code_examples("deployment", Some("YAML"), None, 5);
+    assert!(!yaml.is_empty());
+    assert!(yaml.iter().all(|m| m.example.language == "yaml"));
+    
+    assert!(kb.search_code_examples("guardrail", Some("rust"), None, 5).is_empty());
+    assert!(kb.search_code_examples("the of", None, None, 5).is_empty());
+}
+
+#[test]
+fn test_official_urls_follow_requested_version() {
+    let mut kb = AdkKnowledgeBase::new();
+    kb.version_config.add_version("0.9.0".to_string());
+    kb.merge_version_docs(docs_with_concept("1.1.0", "session", "Newer sessions."));
+    
+    // The default version and its aliases link to the unversioned site
+    assert_eq!(kb.get_official_urls(Some("stable")), DocumentationUrls::default());
+    assert_eq!(kb.get_official_urls(None), DocumentationUrls::default());
+    
+    // Other known versions get version-specific links
+    let urls = kb.get_official_urls(Some("1.1.0"));
+    assert_eq!(urls.quickstart, "https://google.github.io/adk-docs/1.1.0/get-started/quickstart/");
+    assert!(urls.get_all_urls().iter().all(|url| url.contains("/adk-docs/1.1.0/")));
+    assert!(kb.get_official_urls(Some("0.9.0")).quickstart.contains("/adk-docs/0.9.0/"));
+    
+    // Unknown versions fall back to the default documentation
+    assert_eq!(kb.get_official_urls(Some("9.9.9")), DocumentationUrls::default());
+}
+
+#[test]
+fn test_concept_references_follow_requested_version() {
+    let mut kb = AdkKnowledgeBase::new();
+    kb.version_config.add_version("0.9.0".to_string());
+    let generator = documentation::DocumentationReferenceGenerator::new(kb);
+    
+    let latest = generator.generate_concept_references("session", Some("latest"));
+    assert!(latest.iter().all(|url| !url.contains("/0.9.0/")));
+    
+    let older = generator.generate_concept_references("session", Some("0.9.0"));
+    assert!(!older.is_empty());
+    assert!(older.iter().all(|url| url.starts_with("https://google.github.io/adk-docs/0.9.0/")));
+    
+    assert_eq!(
+        DocumentationUrls::versioned_url("https://example.com/guide/", "0.9.0"),
+        "https://example.com/guide/"
+    );
+}
+
+#[test]
+fn test_feature_availability_from_version_features() {
+    use crate::expert::adk_knowledge::{Availability, VersionFeature};
+    
+    let mut kb = AdkKnowledgeBase::new();
+    kb.version_config.add_version("0.9.0".to_string());
+    let mut docs = docs_with_concept("1.1.0", "session", "Newer sessions.");
+    docs.version_features.push(VersionFeature {
+        name: "Live Streaming".to_string(),
+        description: "Bidirectional audio streaming.".to_string(),
+        introduced_in: "1.0.0".to_string(),
+        deprecated_in: Some("1.1.0".to_string()),
+        migration_notes: Some("Use the run_live API instead.".to_string()),
+    });
+    kb.merge_version_docs(docs);
+    
+    let availability = kb.feature_availability("live streaming").unwrap();
+    assert_eq!(availability.name, "Live Streaming");
+    assert_eq!(availability.minimum_version(), Some("1.0.0"));
+    let statuses: Vec<(&str, Availability)> = availability.versions
+        .iter()
+        .map(|v| (v.version.as_str(), v.availability))
+        .collect();
+    assert_eq!(statuses, vec![
+        ("0.9.0", Availability::NotAvailable),
+        ("1.0.0", Availability::Available),
+        ("1.1.0", Availability::Deprecated),
+    ]);
+    
+    // Concepts without feature data follow the loaded documentation
+    let session = kb.feature_availability("Session").unwrap();
+    assert_eq!(session.introduced_in, None);
+    assert_eq!(session.versions[0].availability, Availability::Unknown);
+    assert_eq!(session.versions[1].availability, Availability::Available);
+    
+    assert!(kb.feature_availability("time travel").is_none());
+}
+
+#[test]
+fn test_compare_versions_orders_numerically() {
+    use crate::expert::adk_knowledge::compare_versions;
+    use std::cmp::Ordering;
+    
+    assert_eq!(compare_versions("1.10.0", "1.9.0"), Ordering::Greater);
+    assert_eq!(compare_versions("v1.0", "1.0.0"), Ordering::Less);
+    assert_eq!(compare_versions("2.0.0", "2.0.0"), Ordering::Equal);
+}
+
+#[test]
+fn test_locale_parsing_falls_back_to_english() {
+    use crate::expert::i18n::Locale;
+    
+    assert_eq!(Locale::parse("pt-BR"), Some(Locale::PortugueseBrazil));
+    assert_eq!(Locale::parse("pt_br"), Some(Locale::PortugueseBrazil));
+    assert_eq!(Locale::parse("JA-jp"), Some(Locale::Japanese));
+    assert_eq!(Locale::parse("es"), Some(Locale::Spanish));
+    assert_eq!(Locale::parse("klingon"), None);
+    assert_eq!(Locale::from_tag(Some("klingon")), Locale::English);
+    assert_eq!(Locale::from_tag(None), Locale::English);
+}
+
+#[test]
+fn test_localize_translates_scaffolding_only() {
+    use crate::expert::i18n::{Locale, localize};
+    
+    let response = "## Google ADK Documentation Query: sessions\n\n**Version:** 1.0.0\n\n\
+        A session tracks a conversation.\n\n### Official References:\n- https://google.github.io/adk-docs/";
+    
+    let japanese = localize(response, Locale::Japanese);
+    assert!(japanese.starts_with("## Google ADK ドキュメント検索: sessions"));
+    assert!(japanese.contains("**バージョン:** 1.0.0"));
+    assert!(japanese.contains("### 公式リファレンス:"));
+    assert!(japanese.contains("A session tracks a conversation."));
+    
+    assert_eq!(localize(response, Locale::English), response);
+    assert!(localize("### Examples of sessions", Locale::Spanish).contains("### Examples of sessions"));
+}
+
+#[tokio::test]
+async fn test_snapshot_export_covers_all_versions() {
+    use crate::expert::snapshot::{KnowledgeSnapshot, SNAPSHOT_FORMAT_VERSION};
+    
+    let mut kb = AdkKnowledgeBase::new();
+    kb.merge_version_docs(docs_with_concept("1.1.0", "session", "Newer sessions."));
+    
+    let snapshot = KnowledgeSnapshot::from_knowledge_base(&kb);
+    let versions: Vec<&str> = snapshot.versions.iter().map(|docs| docs.version.as_str()).collect();
+    assert_eq!(versions, vec!["1.0.0", "1.1.0"]);
+    assert_eq!(snapshot.version_aliases.get("stable").map(String::as_str), Some("1.0.0"));
+    assert_eq!(snapshot.summary().versions, 2);
+    assert!(snapshot.summary().patterns > 0);
+    
+    let path = std::env::temp_dir().join(format!("arkaft-snapshot-test-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    
+    let bytes = snapshot.write_to(&path, false).await.unwrap();
+    assert_eq!(bytes as u64, std::fs::metadata(&path).unwrap().len());
+    
+    let written: KnowledgeSnapshot = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(written.format_version, SNAPSHOT_FORMAT_VERSION);
+    assert_eq!(written.versions.len(), 2);
+    
+    // Existing files are only replaced on request
+    assert!(snapshot.write_to(&path, false).await.is_err());
+    assert!(snapshot.write_to(&path, true).await.is_ok());
+    
+    let _ = std::fs::remove_file(&path);
+}
+
+fn snapshot_with(exported_at: i64, docs: Vec<crate::expert::adk_knowledge::VersionDocs>) -> crate::expert::snapshot::KnowledgeSnapshot {
+    let mut snapshot = crate::expert::snapshot::KnowledgeSnapshot::from_knowledge_base(&AdkKnowledgeBase::empty());
+    snapshot.exported_at = exported_at;
+    snapshot.versions = docs;
+    snapshot.version_aliases.insert("lts".to_string(), "1.0.0".to_string());
+    snapshot.version_aliases.insert("stable".to_string(), "0.9.0".to_string());
+    snapshot
+}
+
+#[test]
+fn test_newer_snapshot_replaces_entries() {
+    let mut expert = DocumentationExpert::new();
+    
+    let report = expert.import_snapshot(snapshot_with(i64::MAX, vec![
+        docs_with_concept("1.0.0", "session", "Snapshot sessions."),
+        docs_with_concept("1.2.0", "session", "Future sessions."),
+    ])).unwrap();
+    
+    assert_eq!(report.updated_versions, vec!["1.0.0".to_string(), "1.2.0".to_string()]);
+    assert!(report.gap_filled_versions.is_empty());
+    assert_eq!(report.entries_merged, 2);
+    assert_eq!(expert.knowledge_base.version_docs["1.0.0"].concepts["session"].description, "Snapshot sessions.");
+    assert!(expert.knowledge_base.version_docs.contains_key("1.2.0"));
+    
+    // Local aliases are kept; missing ones are added
+    assert_eq!(report.aliases_added, vec!["lts".to_string()]);
+    assert_eq!(expert.knowledge_base.resolve_version("stable"), "1.0.0");
+}
+
+#[tokio::test]
+async fn test_snapshot_aliases_refresh_references_and_cache() {
+    use crate::expert::adk_knowledge::VersionOverrides;
+    
+    let mut expert = DocumentationExpert::new();
+    let overrides = VersionOverrides { versions: vec!["1.2.0".to_string()], ..VersionOverrides::default() };
+    expert.apply_version_overrides(overrides).unwrap();
+    expert.query_documentation("session", Some("lts")).await.unwrap();
+    
+    let mut snapshot = snapshot_with(i64::MAX, Vec::new());
+    snapshot.version_aliases.insert("lts".to_string(), "1.2.0".to_string());
+    let report = expert.import_snapshot(snapshot).unwrap();
+    assert_eq!(report.aliases_added, vec!["lts".to_string()]);
+    
+    let references = expert.reference_generator.generate_category_references("tutorials", Some("lts"));
+    assert_eq!(references, vec![format!("{}/1.2.0/tutorials/", DOCS_BASE_URL)]);
+    assert_eq!(expert.cache_stats().entries, 0);
+}
+
+#[test]
+fn test_older_snapshot_only_fills_gaps() {
+    use crate::expert::adk_knowledge::TrustLevel;
+    
+    let mut expert = DocumentationExpert::new();
+    expert.persist_version_docs(docs_with_concept("1.0.0", "session", "Fresh sessions.")).unwrap();
+    expert.knowledge_base.merge_knowledge_pack(community_pack(TrustLevel::Community));
+    
+    let mut stale = docs_with_concept("1.0.0", "session", "Stale sessions.");
+    stale.merge_from(docs_with_concept("1.0.0", "gap_concept", "Only in the snapshot."));
+    stale.merge_from(docs_with_concept("1.0.0", "vector_memory", "Official vector memory."));
+    
+    let report = expert.import_snapshot(snapshot_with(1, vec![stale])).unwrap();
+    
+    assert_eq!(report.gap_filled_versions, vec!["1.0.0".to_string()]);
+    let docs = &expert.knowledge_base.version_docs["1.0.0"];
+    assert_eq!(docs.concepts["session"].description, "Fresh sessions.");
+    assert_eq!(docs.concepts["gap_concept"].description, "Only in the snapshot.");
+    
+    // Official entries still replace community ones from an older snapshot
+    assert_eq!(docs.concepts["vector_memory"].description, "Official vector memory.");
+    assert!(docs.concept_provenance("vector_memory").is_none());
+    assert_eq!(report.entries_merged, 2);
+}
+
+#[tokio::test]
+async fn test_scenario_matching_ranks_related_vocabulary() {
+    use crate::expert::relevance::RelevanceIndex;
+    
+    let entries = [
+        ("Robust failure recovery", "Retry transient tool failures with backoff"),
+        ("Keep callbacks fast", "Callbacks run on every model call"),
+        ("Handle errors inside callbacks", "Catch exceptions so the agent keeps running"),
+    ];
+    let index = RelevanceIndex::new(entries.iter().map(|(title, body)| [(*title, 3.0), (*body, 1.0)]));
+    let ranked: Vec<usize> = index.rank("error handling").into_iter().map(|(position, _)| position).collect();
+    assert_eq!(ranked, vec![2, 0]);
+    assert!(index.rank("the and of").is_empty());
+    
+    // Scenarios no longer need to appear verbatim in a practice
+    let enforcer = crate::expert::best_practices::BestPracticesEnforcer::new();
+    let result = enforcer.get_best_practices("recovering from errors", None, None).await.unwrap();
+    assert!(result.practices.iter().any(|p| p.title == "Handle Quota Errors with Retries and Backoff"));
+    assert!(result.practices.iter().any(|p| p.title == "Handle Errors Inside Callbacks"));
+}
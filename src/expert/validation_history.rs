@@ -0,0 +1,155 @@
+//! Per-project history of architecture validation scores
+//!
+//! When `validate_architecture` is called with a `project_id`, a summary of the result
+//! is recorded here. Records are written to the knowledge store when one is configured,
+//! so trends survive restarts, and are otherwise kept in memory for the life of the
+//! server. Trends show whether a team's ADK compliance is improving across sprints.
+
+use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
+use crate::expert::best_practices::{ArchitectureValidationResult, ValidationSeverity};
+use crate::expert::knowledge_store::KnowledgeStore;
+use crate::utils::error::ArkaftResult;
+
+/// Default number of most recent validations included in a trend
+pub const DEFAULT_TREND_LIMIT: usize = 20;
+
+/// Summary of one validation of a project
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationRecord {
+    /// Project the validation belongs to
+    pub project_id: String,
+    /// When the validation ran, as seconds since the Unix epoch
+    pub recorded_at: i64,
+    /// Compliance score (0-100)
+    pub compliance_score: u8,
+    /// Whether the design was compliant
+    pub is_compliant: bool,
+    /// Number of error findings
+    pub errors: usize,
+    /// Number of warning findings
+    pub warnings: usize,
+    /// Number of info findings
+    pub infos: usize,
+}
+
+impl ValidationRecord {
+    /// Summarize a validation result for `project_id`
+    pub fn from_result(project_id: &str, result: &ArchitectureValidationResult, recorded_at: i64) -> Self {
+        let count = |severity: ValidationSeverity| result.findings.iter().filter(|f| f.severity == severity).count();
+        Self {
+            project_id: project_id.to_string(),
+            recorded_at,
+            compliance_score: result.compliance_score,
+            is_compliant: result.is_compliant,
+            errors: count(ValidationSeverity::Error),
+            warnings: count(ValidationSeverity::Warning),
+            infos: count(ValidationSeverity::Info),
+        }
+    }
+}
+
+/// Direction of a project's compliance score over its recorded validations
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrendDirection {
+    /// The latest score is higher than the first
+    Improving,
+    /// The latest score is lower than the first
+    Declining,
+    /// The latest score equals the first
+    Steady,
+}
+
+/// Compliance scores of a project over time
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreTrend {
+    /// Project the trend belongs to
+    pub project_id: String,
+    /// Validations in chronological order
+    pub records: Vec<ValidationRecord>,
+    /// Latest score minus the first score
+    pub change: i16,
+    /// Highest score recorded
+    pub best_score: u8,
+    /// Whether the score is improving, declining, or steady
+    pub direction: TrendDirection,
+}
+
+impl ScoreTrend {
+    /// Build a trend from chronologically ordered records, or `None` when there are none
+    pub fn from_records(project_id: &str, records: Vec<ValidationRecord>) -> Option<Self> {
+        let first = records.first()?.compliance_score;
+        let latest = records.last()?.compliance_score;
+        let change = i16::from(latest) - i16::from(first);
+        
+        Some(Self {
+            project_id: project_id.to_string(),
+            best_score: records.iter().map(|record| record.compliance_score).max().unwrap_or(latest),
+            direction: match change {
+                change if change > 0 => TrendDirection::Improving,
+                change if change < 0 => TrendDirection::Declining,
+                _ => TrendDirection::Steady,
+            },
+            change,
+            records,
+        })
+    }
+    
+    /// Score of the most recent validation
+    pub fn latest_score(&self) -> u8 {
+        self.records.last().map(|record| record.compliance_score).unwrap_or(0)
+    }
+}
+
+/// Validation records, persisted to the knowledge store when one is configured
+#[derive(Debug, Default)]
+pub struct ValidationHistory {
+    /// Store the records are written to, if any
+    store: Option<KnowledgeStore>,
+    /// Records kept in memory when no store is configured
+    records: Mutex<Vec<ValidationRecord>>,
+}
+
+impl ValidationHistory {
+    /// Create a history backed by `store`, or kept in memory when `None`
+    pub fn new(store: Option<KnowledgeStore>) -> Self {
+        Self {
+            store,
+            records: Mutex::new(Vec::new()),
+        }
+    }
+    
+    /// Record a validation
+    pub fn record(&self, record: ValidationRecord) -> ArkaftResult<()> {
+        match &self.store {
+            Some(store) => store.record_validation(&record),
+            None => {
+                self.lock_records().push(record);
+                Ok(())
+            }
+        }
+    }
+    
+    /// Trend over the `limit` most recent validations of a project, or `None` if it has none
+    pub fn trend(&self, project_id: &str, limit: usize) -> ArkaftResult<Option<ScoreTrend>> {
+        let mut records = match &self.store {
+            Some(store) => store.load_validation_history(project_id)?,
+            None => self
+                .lock_records()
+                .iter()
+                .filter(|record| record.project_id == project_id)
+                .cloned()
+                .collect(),
+        };
+        records.sort_by_key(|record| record.recorded_at);
+        let skip = records.len().saturating_sub(limit);
+        
+        Ok(ScoreTrend::from_records(project_id, records.split_off(skip)))
+    }
+    
+    /// Lock the in-memory records, recovering from a poisoned lock
+    fn lock_records(&self) -> std::sync::MutexGuard<'_, Vec<ValidationRecord>> {
+        self.records.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -0,0 +1,481 @@
+//! ADK agent configuration review for YAML, TOML, and JSON files
+//!
+//! An agent config declares an agent's `name`, `model`, `instruction`, `tools`, and
+//! `sub_agents`, given inline or by `config_path`. Configs are checked against that schema,
+//! and every value for hard-coded credentials and malformed environment variable references.
+
+use super::Severity;
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::sync::OnceLock;
+
+/// Agent classes that orchestrate sub-agents instead of calling a model
+const WORKFLOW_AGENT_CLASSES: &[&str] = &["SequentialAgent", "ParallelAgent", "LoopAgent"];
+
+/// Keys an agent config may declare
+const AGENT_KEYS: &[&str] = &[
+    "agent_class",
+    "name",
+    "description",
+    "model",
+    "instruction",
+    "global_instruction",
+    "static_instruction",
+    "tools",
+    "sub_agents",
+    "generate_content_config",
+    "output_key",
+    "input_schema",
+    "output_schema",
+    "include_contents",
+    "disallow_transfer_to_parent",
+    "disallow_transfer_to_peers",
+    "max_iterations",
+    "planner",
+    "code_executor",
+    "before_agent_callbacks",
+    "after_agent_callbacks",
+    "before_model_callbacks",
+    "after_model_callbacks",
+    "before_tool_callbacks",
+    "after_tool_callbacks",
+];
+
+/// Gemini models served by the Gemini API and Vertex AI; dated and preview releases extend them with a `-` suffix
+const KNOWN_MODELS: &[&str] = &[
+    "gemini-2.5-pro",
+    "gemini-2.5-flash",
+    "gemini-2.5-flash-lite",
+    "gemini-2.0-flash",
+    "gemini-2.0-flash-lite",
+    "gemini-2.0-flash-live",
+    "gemini-live-2.5-flash",
+    "gemini-1.5-pro",
+    "gemini-1.5-flash",
+];
+
+/// Key name fragments that mark a credential, matched ignoring case, `-`, and `_`
+const SECRET_KEY_FRAGMENTS: &[&str] = &["apikey", "secret", "password", "token", "credential", "privatekey"];
+
+/// Shortest string value treated as a credential under a credential-like key
+const MIN_SECRET_LENGTH: usize = 8;
+
+/// Agent config file format
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigFormat {
+    /// `.yaml` or `.yml`
+    Yaml,
+    /// `.toml`
+    Toml,
+    /// `.json`
+    Json,
+}
+
+impl ConfigFormat {
+    /// Format for a path's extension, ignoring case
+    pub fn from_path(path: &str) -> Option<Self> {
+        match path.rsplit_once('.')?.1.to_lowercase().as_str() {
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            "toml" => Some(ConfigFormat::Toml),
+            "json" => Some(ConfigFormat::Json),
+            _ => None,
+        }
+    }
+    
+    /// Name used for the format in review output
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConfigFormat::Yaml => "YAML",
+            ConfigFormat::Toml => "TOML",
+            ConfigFormat::Json => "JSON",
+        }
+    }
+}
+
+/// Agent config issue
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigIssue {
+    /// Type of issue, e.g. "Hardcoded Secret"
+    pub issue_type: String,
+    /// Dotted path of the offending value, e.g. "tools[0].args.api_key"
+    pub key: String,
+    /// Line of the value, when it can be located (1-based)
+    pub line: Option<usize>,
+    /// Description of the issue
+    pub description: String,
+    /// Suggested fix
+    pub fix_suggestion: String,
+    /// Name of the rule that produced the issue, e.g. "hardcoded-secret"
+    pub rule: &'static str,
+    /// How urgently the issue should be addressed
+    pub severity: Severity,
+}
+
+/// Results of an agent config review
+#[derive(Debug, Serialize)]
+pub struct ConfigReview {
+    /// Format the file was parsed as
+    pub format: ConfigFormat,
+    /// Names of the agents declared in the file, root agent first
+    pub agents: Vec<String>,
+    /// Environment variables the config references, sorted
+    pub env_vars: Vec<String>,
+    /// Issues found, most severe first
+    pub issues: Vec<ConfigIssue>,
+}
+
+/// Review an ADK agent config, choosing the format from the path's extension
+pub fn review_agent_config(path: &str, content: &str) -> Result<ConfigReview> {
+    let format = ConfigFormat::from_path(path)
+        .ok_or_else(|| anyhow!("Unsupported agent config file {}. Expected a .yaml, .yml, .toml, or .json file", path))?;
+    let mut review = ConfigReview { format, agents: Vec::new(), env_vars: Vec::new(), issues: Vec::new() };
+    
+    let parsed: std::result::Result<Value, (String, Option<usize>)> = match format {
+        ConfigFormat::Yaml => serde_yaml::from_str(content).map_err(|e| (e.to_string(), e.location().map(|location| location.line()))),
+        ConfigFormat::Toml => toml::from_str(content).map_err(|e| (e.message().to_string(), e.span().map(|span| line_of_offset(content, span.start)))),
+        ConfigFormat::Json => serde_json::from_str(content).map_err(|e| (e.to_string(), Some(e.line()))),
+    };
+    let config = match parsed {
+        Ok(config) => config,
+        Err((message, line)) => {
+            review.issues.push(ConfigIssue {
+                issue_type: "Invalid Config".to_string(),
+                key: String::new(),
+                line,
+                description: format!("The {} could not be parsed: {}", format.label(), message),
+                fix_suggestion: "Fix the syntax so ADK can load the agent".to_string(),
+                rule: "config-syntax",
+                severity: Severity::Error,
+            });
+            return Ok(review);
+        }
+    };
+    
+    let mut checker = ConfigChecker { content, config: &config, issues: Vec::new(), agents: Vec::new(), env_vars: BTreeSet::new() };
+    match &config {
+        Value::Object(agent) => checker.check_agent(agent, ""),
+        _ => checker.issue(
+            "Schema Violation",
+            "",
+            "The config is not a mapping of agent fields".to_string(),
+            "Declare the agent's name, model, instruction, and tools as top-level keys",
+            "config-schema",
+            Severity::Error,
+        ),
+    }
+    checker.check_values(&config, "");
+    
+    review.agents = checker.agents;
+    review.env_vars = checker.env_vars.into_iter().collect();
+    review.issues = checker.issues;
+    review.issues.sort_by_key(|issue| issue.severity);
+    Ok(review)
+}
+
+/// Issues and facts collected while walking a parsed config
+struct ConfigChecker<'a> {
+    content: &'a str,
+    config: &'a Value,
+    issues: Vec<ConfigIssue>,
+    agents: Vec<String>,
+    env_vars: BTreeSet<String>,
+}
+
+impl ConfigChecker<'_> {
+    /// Record an issue about the value at `key`
+    fn issue(&mut self, issue_type: &str, key: &str, description: String, fix_suggestion: &str, rule: &'static str, severity: Severity) {
+        let line = self.locate(key);
+        self.issues.push(ConfigIssue {
+            issue_type: issue_type.to_string(),
+            key: key.to_string(),
+            line,
+            description,
+            fix_suggestion: fix_suggestion.to_string(),
+            rule,
+            severity,
+        });
+    }
+    
+    /// Line of the value at `key`, found by its field name and, for strings and named
+    /// entries, the value itself, since field names such as `name` repeat across agents
+    fn locate(&self, key: &str) -> Option<usize> {
+        let field = key.rsplit('.').next().unwrap_or_default().split('[').next().unwrap_or_default();
+        let located = match resolve(self.config, key) {
+            Some(Value::String(text)) => key_line(self.content, field, Some(text)),
+            Some(Value::Object(entry)) => entry.get("name").and_then(Value::as_str).and_then(|name| key_line(self.content, "name", Some(name))),
+            _ => None,
+        };
+        located.or_else(|| if field.is_empty() { None } else { key_line(self.content, field, None) })
+    }
+    
+    /// Check one agent's fields, then its inline sub-agents
+    fn check_agent(&mut self, agent: &Map<String, Value>, path: &str) {
+        let key = |field: &str| if path.is_empty() { field.to_string() } else { format!("{}.{}", path, field) };
+        
+        match agent.get("name") {
+            Some(Value::String(name)) => {
+                self.agents.push(name.clone());
+                if !is_identifier(name) {
+                    self.issue(
+                        "Schema Violation",
+                        &key("name"),
+                        format!("Agent name \"{}\" is not a valid identifier", name),
+                        "Use letters, digits, and underscores, starting with a letter or underscore, so other agents can transfer to it",
+                        "config-schema",
+                        Severity::Warning,
+                    );
+                }
+            }
+            Some(_) => self.issue("Schema Violation", &key("name"), "Agent name is not a string".to_string(), "Set name to a string identifier", "config-schema", Severity::Error),
+            None => self.issue(
+                "Schema Violation",
+                path,
+                "Agent does not declare a name".to_string(),
+                "Add a unique name, which ADK uses to route transfers and label events",
+                "config-schema",
+                Severity::Error,
+            ),
+        }
+        
+        let workflow = agent.get("agent_class").and_then(Value::as_str).is_some_and(|class| WORKFLOW_AGENT_CLASSES.contains(&class));
+        match agent.get("model") {
+            Some(Value::String(model)) => self.check_model(model, &key("model")),
+            Some(_) => self.issue("Schema Violation", &key("model"), "Model is not a string".to_string(), "Set model to a model identifier such as gemini-2.5-flash", "config-schema", Severity::Error),
+            None if !workflow => self.issue(
+                "Schema Violation",
+                path,
+                "LLM agent does not declare a model".to_string(),
+                "Set model explicitly so the agent does not depend on an inherited or default model",
+                "config-schema",
+                Severity::Warning,
+            ),
+            None => {}
+        }
+        
+        for field in ["description", "instruction", "global_instruction"] {
+            if agent.get(field).is_some_and(|value| !value.is_string()) {
+                self.issue("Schema Violation", &key(field), format!("{} is not a string", field), "Provide the text as a single string", "config-schema", Severity::Error);
+            }
+        }
+        
+        if let Some(tools) = agent.get("tools") {
+            self.check_tools(tools, &key("tools"));
+        }
+        
+        match agent.get("sub_agents") {
+            Some(Value::Array(sub_agents)) => {
+                for (index, sub_agent) in sub_agents.iter().enumerate() {
+                    let sub_key = format!("{}[{}]", key("sub_agents"), index);
+                    match sub_agent.as_object() {
+                        Some(sub_agent) if sub_agent.contains_key("config_path") => {}
+                        Some(sub_agent) => self.check_agent(sub_agent, &sub_key),
+                        None => self.issue(
+                            "Schema Violation",
+                            &sub_key,
+                            "Sub-agent is not a mapping".to_string(),
+                            "Declare each sub-agent inline or reference its file with config_path",
+                            "config-schema",
+                            Severity::Error,
+                        ),
+                    }
+                }
+            }
+            Some(_) => self.issue("Schema Violation", &key("sub_agents"), "sub_agents is not a list".to_string(), "List the sub-agents under sub_agents", "config-schema", Severity::Error),
+            None => {}
+        }
+        
+        for field in agent.keys().filter(|field| !AGENT_KEYS.contains(&field.as_str())) {
+            self.issue(
+                "Unknown Key",
+                &key(field),
+                format!("\"{}\" is not an agent config field", field),
+                "Check the key for typos; ADK rejects or ignores fields outside the agent schema",
+                "config-schema",
+                Severity::Info,
+            );
+        }
+    }
+    
+    /// Report model identifiers that no known provider serves
+    fn check_model(&mut self, model: &str, key: &str) {
+        // Environment references, LiteLLM provider prefixes, and Vertex AI endpoints cannot be checked offline
+        if model.contains('$') || model.contains('/') {
+            return;
+        }
+        let known = KNOWN_MODELS.iter().any(|known| model == *known || model.strip_prefix(known).is_some_and(|suffix| suffix.starts_with('-')));
+        if !known {
+            self.issue(
+                "Unknown Model",
+                key,
+                format!("Model \"{}\" is not a known Gemini model", model),
+                "Use a current model identifier such as gemini-2.5-flash, or a provider-qualified name such as openai/gpt-4o for LiteLLM",
+                "unknown-model",
+                Severity::Warning,
+            );
+        }
+    }
+    
+    /// Check that tools are listed by name, without duplicates
+    fn check_tools(&mut self, tools: &Value, key: &str) {
+        let Some(tools) = tools.as_array() else {
+            self.issue("Schema Violation", key, "tools is not a list".to_string(), "List the agent's tools under tools", "config-schema", Severity::Error);
+            return;
+        };
+        let mut names = BTreeSet::new();
+        for (index, tool) in tools.iter().enumerate() {
+            let tool_key = format!("{}[{}]", key, index);
+            let name = match tool {
+                Value::String(name) => Some(name.as_str()),
+                Value::Object(tool) => tool.get("name").and_then(Value::as_str),
+                _ => None,
+            };
+            let Some(name) = name else {
+                self.issue(
+                    "Schema Violation",
+                    &tool_key,
+                    "Tool does not declare a name".to_string(),
+                    "Reference each tool by name, e.g. google_search or my_package.tools.lookup, with optional args",
+                    "config-schema",
+                    Severity::Error,
+                );
+                continue;
+            };
+            if !names.insert(name) {
+                self.issue(
+                    "Duplicate Tool",
+                    &tool_key,
+                    format!("Tool \"{}\" is listed more than once", name),
+                    "Remove the duplicate; the model sees one declaration per tool name",
+                    "config-schema",
+                    Severity::Warning,
+                );
+            }
+        }
+    }
+    
+    /// Check every string value for hard-coded credentials and environment references
+    fn check_values(&mut self, value: &Value, path: &str) {
+        match value {
+            Value::Object(map) => {
+                for (field, child) in map {
+                    let key = if path.is_empty() { field.clone() } else { format!("{}.{}", path, field) };
+                    if let Value::String(text) = child {
+                        self.check_secret(field, text, &key);
+                    }
+                    self.check_values(child, &key);
+                }
+            }
+            Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    self.check_values(item, &format!("{}[{}]", path, index));
+                }
+            }
+            Value::String(text) => self.check_env_references(text, path),
+            _ => {}
+        }
+    }
+    
+    /// Report a credential written into the config instead of read from the environment
+    fn check_secret(&mut self, field: &str, value: &str, key: &str) {
+        if value.contains('$') {
+            return;
+        }
+        let normalized: String = field.to_lowercase().chars().filter(|c| *c != '_' && *c != '-').collect();
+        let secret_key = SECRET_KEY_FRAGMENTS.iter().any(|fragment| normalized.contains(fragment)) && value.len() >= MIN_SECRET_LENGTH;
+        if !secret_key && !secret_value_pattern().is_match(value) {
+            return;
+        }
+        self.issues.push(ConfigIssue {
+            issue_type: "Hardcoded Secret".to_string(),
+            key: key.to_string(),
+            line: value_line(self.content, value),
+            description: format!("{} holds a credential in plain text ({}...)", key, value.chars().take(4).collect::<String>()),
+            fix_suggestion: "Read the credential from the environment, e.g. ${GOOGLE_API_KEY}, or a secret manager, and rotate the exposed value".to_string(),
+            rule: "hardcoded-secret",
+            severity: Severity::Error,
+        });
+    }
+    
+    /// Collect `${NAME}` references and report malformed ones
+    fn check_env_references(&mut self, text: &str, key: &str) {
+        let mut rest = text;
+        while let Some(start) = rest.find("${") {
+            let after = &rest[start + 2..];
+            let Some(end) = after.find('}') else {
+                self.issue(
+                    "Environment Reference",
+                    key,
+                    format!("Unclosed environment variable reference in \"{}\"", text),
+                    "Close the reference with }, e.g. ${GOOGLE_API_KEY}",
+                    "env-reference",
+                    Severity::Warning,
+                );
+                return;
+            };
+            // Shell-style defaults such as ${MODEL:-gemini-2.5-flash} are allowed
+            let name = after[..end].split(":-").next().unwrap_or_default();
+            if is_identifier(name) {
+                self.env_vars.insert(name.to_string());
+            } else {
+                self.issue(
+                    "Environment Reference",
+                    key,
+                    format!("\"{}\" is not a valid environment variable name", name),
+                    "Use letters, digits, and underscores, e.g. ${GOOGLE_API_KEY}",
+                    "env-reference",
+                    Severity::Warning,
+                );
+            }
+            rest = &after[end + 1..];
+        }
+    }
+}
+
+/// Credential formats recognizable from the value alone: Google, OpenAI, GitHub, and AWS keys
+fn secret_value_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"AIza[0-9A-Za-z_\-]{35}|\bsk-[A-Za-z0-9_\-]{20,}|\bgh[pousr]_[A-Za-z0-9]{36}|\bAKIA[0-9A-Z]{16}\b")
+            .expect("secret patterns are valid regexes")
+    })
+}
+
+/// Whether a name is a valid agent identifier or environment variable name
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|first| first.is_ascii_alphabetic() || first == '_') && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Value at a dotted key path such as "sub_agents[0].tools[1]"
+fn resolve<'v>(config: &'v Value, key: &str) -> Option<&'v Value> {
+    let mut value = config;
+    for segment in key.split('.').filter(|segment| !segment.is_empty()) {
+        let mut parts = segment.split('[');
+        value = value.get(parts.next()?)?;
+        for index in parts {
+            value = value.get(index.trim_end_matches(']').parse::<usize>().ok()?)?;
+        }
+    }
+    Some(value)
+}
+
+/// First line (1-based) declaring `key` in YAML, TOML, or JSON syntax, with `value` when given
+fn key_line(content: &str, key: &str, value: Option<&str>) -> Option<usize> {
+    let value = value.map(|value| format!(r#"\s*["']?{}"#, regex::escape(value))).unwrap_or_default();
+    let regex = Regex::new(&format!(r#"(?m)^\s*(?:-\s*)?["']?{}["']?\s*[:=]{}"#, regex::escape(key), value)).ok()?;
+    regex.find(content).map(|found| line_of_offset(content, found.start()))
+}
+
+/// First line (1-based) containing `value`
+fn value_line(content: &str, value: &str) -> Option<usize> {
+    content.find(value).map(|offset| line_of_offset(content, offset))
+}
+
+/// Line (1-based) of a byte offset
+fn line_of_offset(content: &str, offset: usize) -> usize {
+    content[..offset.min(content.len())].matches('\n').count() + 1
+}
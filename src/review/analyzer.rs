@@ -1,13 +1,21 @@
 //! Rust code analysis for ADK compliance and improvements
 
-use super::{TranslationOpportunity, ArchitecturalImprovement, ComplianceIssue, OrganizationSuggestion};
+use super::{TranslationOpportunity, ArchitecturalImprovement, ComplianceIssue, FunctionMetrics, OrganizationSuggestion, ReviewConfig, Severity, SourceSpan};
+use super::language::{find_foreign_text, TextKind};
 use anyhow::Result;
-use syn::{File, Item, ItemFn, ItemStruct, ItemEnum, ItemImpl, Visibility, parse_str};
+use std::collections::HashMap;
+use syn::{Attribute, BinOp, Block, Expr, ExprAssign, ExprAsync, ExprAwait, ExprBinary, ExprBreak, ExprCall, ExprForLoop, ExprIf, ExprLit, ExprLoop, ExprClosure, ExprMatch, ExprMethodCall, ExprPath, ExprReturn, ExprUnsafe, ExprWhile, Fields, File, FnArg, GenericArgument, ImplItemFn, Item, ItemFn, ItemMod, ItemStruct, ItemEnum, ItemImpl, Lit, Local, Macro, Pat, PatIdent, PathArguments, ReturnType, Signature, Token, Type, Visibility, parse_str};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
 
 /// Rust code parser and analyzer
 pub struct RustCodeAnalyzer {
     /// Parsed AST of the Rust file
     ast: Option<File>,
+    /// Location of the first syntax error, when parsing failed
+    syntax_error: Option<SourceSpan>,
     /// Original source code
     #[allow(dead_code)]
     source: String,
@@ -18,15 +26,17 @@ pub struct RustCodeAnalyzer {
 impl RustCodeAnalyzer {
     /// Create a new analyzer for the given Rust code
     pub fn new(content: &str) -> Result<Self> {
-        let ast = match parse_str::<File>(content) {
-            Ok(ast) => Some(ast),
-            Err(_) => None, // Continue analysis even if parsing fails
+        // Continue analysis even if parsing fails
+        let (ast, syntax_error) = match parse_str::<File>(content) {
+            Ok(ast) => (Some(ast), None),
+            Err(e) => (None, Some(SourceSpan::from_span(e.span()))),
         };
         
         let lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
         
         Ok(Self {
             ast,
+            syntax_error,
             source: content.to_string(),
             lines,
         })
@@ -47,6 +57,11 @@ impl RustCodeAnalyzer {
         self.ast.is_some()
     }
     
+    /// Location of the first syntax error, if the code does not parse
+    pub fn syntax_error(&self) -> Option<SourceSpan> {
+        self.syntax_error
+    }
+    
     /// Extract all functions from the AST
     pub fn extract_functions(&self) -> Vec<&ItemFn> {
         if let Some(ast) = &self.ast {
@@ -75,81 +90,1909 @@ impl RustCodeAnalyzer {
         }
     }
     
-    /// Extract all enums from the AST
-    pub fn extract_enums(&self) -> Vec<&ItemEnum> {
-        if let Some(ast) = &self.ast {
-            ast.items.iter()
-                .filter_map(|item| match item {
-                    Item::Enum(e) => Some(e),
-                    _ => None,
-                })
-                .collect()
-        } else {
-            Vec::new()
-        }
+    /// Extract all enums from the AST
+    pub fn extract_enums(&self) -> Vec<&ItemEnum> {
+        if let Some(ast) = &self.ast {
+            ast.items.iter()
+                .filter_map(|item| match item {
+                    Item::Enum(e) => Some(e),
+                    _ => None,
+                })
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+    
+    /// Extract all impl blocks from the AST
+    pub fn extract_impls(&self) -> Vec<&ItemImpl> {
+        if let Some(ast) = &self.ast {
+            ast.items.iter()
+                .filter_map(|item| match item {
+                    Item::Impl(i) => Some(i),
+                    _ => None,
+                })
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+    
+    /// Analyze code complexity and patterns
+    pub fn analyze_patterns(&self) -> CodePatterns {
+        let mut patterns = CodePatterns::default();
+        
+        // Analyze function patterns
+        for func in self.extract_functions() {
+            patterns.function_count += 1;
+            
+            // Check for async functions
+            if func.sig.asyncness.is_some() {
+                patterns.async_functions += 1;
+            }
+            
+            // Check for public functions
+            if matches!(func.vis, Visibility::Public(_)) {
+                patterns.public_functions += 1;
+            }
+            
+            // Check for error handling patterns
+            if returns_result(&func.sig) {
+                patterns.result_returning_functions += 1;
+            }
+        }
+        
+        // Analyze struct patterns
+        patterns.struct_count = self.extract_structs().len();
+        patterns.enum_count = self.extract_enums().len();
+        patterns.impl_count = self.extract_impls().len();
+        
+        // Locate calls and macros in the AST, so comments and strings do not count
+        if let Some(ast) = &self.ast {
+            let mut collector = UsageCollector::default();
+            collector.visit_file(ast);
+            patterns.unwrap_usage = collector.unwrap_usage;
+            patterns.panic_usage = collector.panic_usage;
+            patterns.todo_usage = collector.todo_usage;
+            patterns.sync_io_usage = collector.sync_io_usage;
+            patterns.unbounded_channels = collector.unbounded_channels;
+            patterns.oversized_channels = collector.oversized_channels;
+            patterns.unbounded_queues = collector.unbounded_queues;
+            patterns.unwrap_in_result_fn = collector.unwrap_in_result_fn;
+            patterns.expect_usage = collector.expect_usage;
+            patterns.print_usage = collector.print_usage;
+            let (types_without_debug, config_types_without_clone) = collector.missing_derives
+                .into_iter()
+                .filter(|missing| !collector.manual_impls.contains(&(missing.trait_name, missing.name.clone())))
+                .partition(|missing| missing.trait_name == "Debug");
+            patterns.types_without_debug = types_without_debug;
+            patterns.config_types_without_clone = config_types_without_clone;
+            
+            let mut loops = SequentialAwaitCollector::default();
+            loops.visit_file(ast);
+            patterns.sequential_await_loops = loops.loops;
+            
+            let mut unsafe_code = UnsafeCollector::default();
+            unsafe_code.visit_file(ast);
+            patterns.undocumented_unsafe = unsafe_code.sites
+                .into_iter()
+                .filter(|site| !has_safety_comment(&self.lines, site.span.start_line))
+                .collect();
+            
+            let mut types = TypeCollector::default();
+            types.visit_file(ast);
+            let layouts = types.layouts();
+            patterns.large_enum_variants = types.enums.iter().filter_map(|item| large_enum_variant(item, &layouts)).collect();
+            patterns.large_structs = layouts
+                .values()
+                .filter(|layout| layout.fields > LARGE_STRUCT_FIELDS)
+                .map(|layout| LargeStruct { name: layout.name.clone(), fields: layout.fields, span: layout.span })
+                .collect();
+            patterns.large_structs.sort_by_key(|large| large.span.start_line);
+            
+            let mut methods = MethodCollector::default();
+            methods.visit_file(ast);
+            patterns.god_types = methods.types
+                .into_iter()
+                .filter(|god| god.methods.len() > GOD_TYPE_METHODS)
+                .map(|god| match layouts.get(&god.name).filter(|layout| layout.fields > 0) {
+                    Some(layout) => GodType { fields: Some(layout.fields), span: layout.span, ..god },
+                    None => god,
+                })
+                .collect();
+            
+            let mut clones = HotCloneScanner {
+                large_types: layouts.into_values().filter(|layout| layout.derives_clone && layout.is_large()).collect(),
+                ..HotCloneScanner::default()
+            };
+            clones.visit_file(ast);
+            patterns.hot_clones = clones.clones;
+            
+            let mut public_items = PublicItemCollector::default();
+            public_items.visit_file(ast);
+            patterns.unused_public_items = public_items.items
+                .into_iter()
+                .filter(|item| !public_items.referenced.contains(&item.name) && !public_items.reexported.contains(&item.name))
+                .collect();
+            
+            let mut untyped_errors = UntypedErrorCollector::new(ast);
+            untyped_errors.visit_file(ast);
+            patterns.untyped_errors = untyped_errors.functions;
+            
+            let mut drop_hazards = DropHazardCollector::default();
+            drop_hazards.visit_file(ast);
+            patterns.drop_hazards = drop_hazards.hazards;
+            
+            let mut guards = GuardAwaitCollector::default();
+            guards.visit_file(ast);
+            patterns.guards_across_await = guards.guards;
+        }
+        
+        patterns
+    }
+    
+    /// Locate functions, methods, and test code in the AST
+    pub fn structure(&self) -> CodeStructure {
+        let mut collector = StructureCollector::default();
+        if let Some(ast) = &self.ast {
+            collector.visit_file(ast);
+        }
+        collector.structure
+    }
+}
+
+/// Function or method located in the source
+#[derive(Clone, Debug, PartialEq)]
+pub struct FunctionSpan {
+    /// Function name
+    pub name: String,
+    /// First line of the function, including attributes (1-based)
+    pub start_line: usize,
+    /// Last line of the function (1-based)
+    pub end_line: usize,
+    /// Whether the signature returns a `Result`
+    pub returns_result: bool,
+    /// Whether the function is a test or sits in a `#[cfg(test)]` module
+    pub is_test: bool,
+}
+
+/// Functions and test code located in parsed Rust source
+#[derive(Clone, Debug, Default)]
+pub struct CodeStructure {
+    /// Functions and methods, in source order
+    pub functions: Vec<FunctionSpan>,
+    /// Line ranges of `#[cfg(test)]` modules (1-based, inclusive)
+    pub test_modules: Vec<(usize, usize)>,
+}
+
+impl CodeStructure {
+    /// Innermost function or method containing `line`
+    pub fn enclosing_function(&self, line: usize) -> Option<&FunctionSpan> {
+        self.functions
+            .iter()
+            .filter(|func| func.start_line <= line && line <= func.end_line)
+            .min_by_key(|func| func.end_line - func.start_line)
+    }
+    
+    /// Whether `line` is test code rather than a production path
+    pub fn is_test_line(&self, line: usize) -> bool {
+        self.test_modules.iter().any(|(start, end)| *start <= line && line <= *end)
+            || self.enclosing_function(line).is_some_and(|func| func.is_test)
+    }
+}
+
+/// AST visitor collecting a `CodeStructure`
+#[derive(Default)]
+struct StructureCollector {
+    structure: CodeStructure,
+    in_test_module: bool,
+}
+
+impl StructureCollector {
+    /// Record a function or method
+    fn record(&mut self, attrs: &[Attribute], sig: &Signature, span: proc_macro2::Span) {
+        self.structure.functions.push(FunctionSpan {
+            name: sig.ident.to_string(),
+            start_line: span.start().line,
+            end_line: span.end().line,
+            returns_result: returns_result(sig),
+            is_test: self.in_test_module || attrs.iter().any(is_test_attribute),
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for StructureCollector {
+    fn visit_item_fn(&mut self, func: &'ast ItemFn) {
+        self.record(&func.attrs, &func.sig, func.span());
+        visit::visit_item_fn(self, func);
+    }
+    
+    fn visit_impl_item_fn(&mut self, func: &'ast ImplItemFn) {
+        self.record(&func.attrs, &func.sig, func.span());
+        visit::visit_impl_item_fn(self, func);
+    }
+    
+    fn visit_item_mod(&mut self, module: &'ast ItemMod) {
+        let is_test_module = module.attrs.iter().any(is_cfg_test);
+        if is_test_module {
+            let span = module.span();
+            self.structure.test_modules.push((span.start().line, span.end().line));
+        }
+        
+        let outer = self.in_test_module;
+        self.in_test_module |= is_test_module;
+        visit::visit_item_mod(self, module);
+        self.in_test_module = outer;
+    }
+}
+
+/// Console printing macros and the `tracing` macros replacing them by default
+const PRINT_MACROS: &[(&str, &str)] = &[
+    ("println", "tracing::info"),
+    ("print", "tracing::info"),
+    ("eprintln", "tracing::error"),
+    ("eprint", "tracing::error"),
+];
+
+/// Message keywords selecting a `tracing` level other than the default, checked in order
+const TRACING_LEVEL_KEYWORDS: &[(&str, &str)] = &[
+    ("error", "tracing::error"),
+    ("fail", "tracing::error"),
+    ("warn", "tracing::warn"),
+    ("debug", "tracing::debug"),
+    ("trace", "tracing::trace"),
+];
+
+/// `tracing` macro for a printed message: the level its wording suggests, else `default`
+fn tracing_macro_for(message: Option<&str>, default: &'static str) -> &'static str {
+    let message = message.unwrap_or_default().to_lowercase();
+    TRACING_LEVEL_KEYWORDS
+        .iter()
+        .find(|(keyword, _)| message.contains(keyword))
+        .map_or(default, |(_, replacement)| replacement)
+}
+
+/// AST visitor locating `unwrap()` and `expect()` calls, panicking, placeholder, and printing macros,
+/// synchronous I/O, unbounded channels and queues, and public types without `Debug`
+#[derive(Default)]
+struct UsageCollector {
+    unwrap_usage: Vec<SourceSpan>,
+    unwrap_in_result_fn: Vec<SourceSpan>,
+    expect_usage: Vec<ExpectUsage>,
+    panic_usage: Vec<SourceSpan>,
+    todo_usage: Vec<SourceSpan>,
+    sync_io_usage: Vec<SourceSpan>,
+    unbounded_channels: Vec<SourceSpan>,
+    oversized_channels: Vec<SourceSpan>,
+    unbounded_queues: Vec<SourceSpan>,
+    print_usage: Vec<PrintUsage>,
+    /// Public types missing `Debug`, and config-like types missing `Clone`
+    missing_derives: Vec<MissingDerive>,
+    /// Types with a hand-written `Debug` or `Clone` impl, as (trait, type)
+    manual_impls: Vec<(&'static str, String)>,
+    /// Whether each enclosing function returns `Result`; closures and async blocks count as not
+    result_scopes: Vec<bool>,
+    /// Whether the visitor is inside a `#[cfg(test)]` module or a test function
+    in_test: bool,
+}
+
+impl UsageCollector {
+    /// Record a public type unless it derives `Debug`, and a config-like one unless it derives `Clone`
+    fn check_derives(&mut self, vis: &Visibility, attrs: &[Attribute], ident: &syn::Ident) {
+        if !matches!(vis, Visibility::Public(_)) {
+            return;
+        }
+        let derives: Vec<&Attribute> = attrs.iter().filter(|attr| attr.path().is_ident("derive")).collect();
+        let name = ident.to_string();
+        let config_like = CONFIG_TYPE_SUFFIXES.iter().any(|suffix| name.ends_with(suffix));
+        
+        for (trait_name, expected) in [("Debug", true), ("Clone", config_like)] {
+            if expected && !derives_trait(attrs, trait_name) {
+                self.missing_derives.push(MissingDerive {
+                    name: name.clone(),
+                    trait_name,
+                    span: SourceSpan::from_span(ident.span()),
+                    item_line: vis.span().start().line,
+                    derive: derives.first().map(|attr| SourceSpan::from_span(attr.span())),
+                });
+            }
+        }
+    }
+    
+    /// Visit a function body with its `Result` context
+    fn in_scope(&mut self, returns_result: bool, visit: impl FnOnce(&mut Self)) {
+        self.result_scopes.push(returns_result);
+        visit(self);
+        self.result_scopes.pop();
+    }
+    
+    /// Record collections a long-lived loop grows without ever draining
+    fn check_queue_growth(&mut self, body: &Block) {
+        if self.in_test {
+            return;
+        }
+        let mut scanner = QueueGrowthScanner::default();
+        scanner.visit_block(body);
+        for (name, span) in scanner.pushes {
+            let recreated = scanner.locals.contains(&name);
+            let drained = scanner.drains.contains(&name);
+            if !recreated && !drained && !self.unbounded_queues.contains(&span) {
+                self.unbounded_queues.push(span);
+            }
+        }
+    }
+    
+    /// Visit an item, treating it as test code when `is_test` is set
+    fn in_test_context(&mut self, is_test: bool, visit: impl FnOnce(&mut Self)) {
+        let outer = self.in_test;
+        self.in_test |= is_test;
+        visit(self);
+        self.in_test = outer;
+    }
+}
+
+impl<'ast> Visit<'ast> for UsageCollector {
+    fn visit_item_fn(&mut self, func: &'ast ItemFn) {
+        self.in_test_context(func.attrs.iter().any(is_test_attribute), |collector| {
+            collector.in_scope(returns_result(&func.sig), |collector| visit::visit_item_fn(collector, func));
+        });
+    }
+    
+    fn visit_impl_item_fn(&mut self, func: &'ast ImplItemFn) {
+        self.in_test_context(func.attrs.iter().any(is_test_attribute), |collector| {
+            collector.in_scope(returns_result(&func.sig), |collector| visit::visit_impl_item_fn(collector, func));
+        });
+    }
+    
+    fn visit_item_mod(&mut self, module: &'ast ItemMod) {
+        self.in_test_context(module.attrs.iter().any(is_cfg_test), |collector| visit::visit_item_mod(collector, module));
+    }
+    
+    fn visit_expr_closure(&mut self, closure: &'ast ExprClosure) {
+        self.in_scope(false, |collector| visit::visit_expr_closure(collector, closure));
+    }
+    
+    fn visit_expr_async(&mut self, block: &'ast ExprAsync) {
+        self.in_scope(false, |collector| visit::visit_expr_async(collector, block));
+    }
+    
+    fn visit_item_struct(&mut self, item: &'ast ItemStruct) {
+        self.check_derives(&item.vis, &item.attrs, &item.ident);
+        visit::visit_item_struct(self, item);
+    }
+    
+    fn visit_item_enum(&mut self, item: &'ast ItemEnum) {
+        self.check_derives(&item.vis, &item.attrs, &item.ident);
+        visit::visit_item_enum(self, item);
+    }
+    
+    fn visit_item_impl(&mut self, item: &'ast ItemImpl) {
+        let implemented = item.trait_.as_ref()
+            .and_then(|(_, path, _)| path.segments.last())
+            .and_then(|segment| ["Debug", "Clone"].into_iter().find(|trait_name| segment.ident == trait_name));
+        if let (Some(trait_name), Type::Path(self_type)) = (implemented, item.self_ty.as_ref()) {
+            if let Some(segment) = self_type.path.segments.last() {
+                self.manual_impls.push((trait_name, segment.ident.to_string()));
+            }
+        }
+        visit::visit_item_impl(self, item);
+    }
+    
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        // Failing fast with unwrap() and expect() is accepted in tests
+        if call.method == "unwrap" && call.args.is_empty() && !self.in_test {
+            // From the method name through its parentheses, e.g. `unwrap()`
+            let span = SourceSpan::between(call.method.span(), call.paren_token.span.close());
+            self.unwrap_usage.push(span);
+            if self.result_scopes.last() == Some(&true) {
+                self.unwrap_in_result_fn.push(span);
+            }
+        } else if call.method == "expect" && call.args.len() == 1 && !self.in_test {
+            let message = match &call.args[0] {
+                Expr::Lit(ExprLit { lit: Lit::Str(message), .. }) => Some(message.value()),
+                _ => None,
+            };
+            self.expect_usage.push(ExpectUsage {
+                span: SourceSpan::between(call.method.span(), call.paren_token.span.close()),
+                message,
+                in_result_fn: self.result_scopes.last() == Some(&true),
+            });
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+    
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        if let (Expr::Path(path), false) = (call.func.as_ref(), self.in_test) {
+            let segments: Vec<String> = path.path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+            let qualified = segments.len() > 1;
+            let span = SourceSpan::from_span(call.span());
+            match (segments.last().map(String::as_str), call.args.first()) {
+                // tokio's unbounded_channel, crossbeam/flume/async-channel unbounded, and std's mpsc::channel
+                (Some("unbounded_channel"), None) => self.unbounded_channels.push(span),
+                (Some("unbounded"), None) if qualified => self.unbounded_channels.push(span),
+                (Some("channel"), None) if segments.iter().any(|segment| segment == "mpsc") => self.unbounded_channels.push(span),
+                (Some("channel" | "bounded" | "sync_channel"), Some(capacity)) if qualified && is_oversized_capacity(capacity) => {
+                    self.oversized_channels.push(span);
+                }
+                _ => {}
+            }
+        }
+        visit::visit_expr_call(self, call);
+    }
+    
+    fn visit_expr_loop(&mut self, loop_expr: &'ast ExprLoop) {
+        self.check_queue_growth(&loop_expr.body);
+        visit::visit_expr_loop(self, loop_expr);
+    }
+    
+    fn visit_expr_while(&mut self, while_expr: &'ast ExprWhile) {
+        self.check_queue_growth(&while_expr.body);
+        visit::visit_expr_while(self, while_expr);
+    }
+    
+    fn visit_expr_path(&mut self, path: &'ast ExprPath) {
+        let segments: Vec<String> = path.path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+        let std_fs = segments.windows(2).any(|pair| pair[0] == "std" && pair[1] == "fs");
+        let file_call = segments.len() > 1 && segments[segments.len() - 2] == "File";
+        if std_fs || file_call {
+            self.sync_io_usage.push(SourceSpan::from_span(path.span()));
+        }
+        visit::visit_expr_path(self, path);
+    }
+    
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        let span = SourceSpan::between(mac.path.span(), mac.delimiter.span().close());
+        if mac.path.is_ident("panic") {
+            self.panic_usage.push(span);
+        } else if mac.path.is_ident("todo") || mac.path.is_ident("unimplemented") {
+            self.todo_usage.push(span);
+        } else if let (Some((name, default)), false) = (PRINT_MACROS.iter().find(|(name, _)| mac.path.is_ident(name)), self.in_test) {
+            let message = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated).ok().and_then(|args| match args.first() {
+                Some(Expr::Lit(ExprLit { lit: Lit::Str(message), .. })) => Some(message.value()),
+                _ => None,
+            });
+            let replacement = tracing_macro_for(message.as_deref(), default);
+            self.print_usage.push(PrintUsage { span, name, replacement });
+        }
+        
+        // Arguments of expression-like macros such as `println!` and `format!` are code too
+        if let Ok(args) = Punctuated::<Expr, Token![,]>::parse_terminated.parse2(mac.tokens.clone()) {
+            for arg in &args {
+                self.visit_expr(arg);
+            }
+        }
+        visit::visit_macro(self, mac);
+    }
+}
+
+/// `unsafe` block or impl in the source
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnsafeSite {
+    /// What is unsafe, e.g. "unsafe block" or "unsafe impl Send for Buffer"
+    pub kind: String,
+    /// Innermost function containing the site, if any
+    pub function: Option<String>,
+    /// Range of the `unsafe` keyword
+    pub span: SourceSpan,
+}
+
+/// AST visitor locating `unsafe` blocks and impls with their enclosing function
+#[derive(Default)]
+struct UnsafeCollector {
+    sites: Vec<UnsafeSite>,
+    functions: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for UnsafeCollector {
+    fn visit_item_fn(&mut self, func: &'ast ItemFn) {
+        self.functions.push(func.sig.ident.to_string());
+        visit::visit_item_fn(self, func);
+        self.functions.pop();
+    }
+    
+    fn visit_impl_item_fn(&mut self, func: &'ast ImplItemFn) {
+        self.functions.push(func.sig.ident.to_string());
+        visit::visit_impl_item_fn(self, func);
+        self.functions.pop();
+    }
+    
+    fn visit_expr_unsafe(&mut self, block: &'ast ExprUnsafe) {
+        self.sites.push(UnsafeSite {
+            kind: "unsafe block".to_string(),
+            function: self.functions.last().cloned(),
+            span: SourceSpan::from_span(block.unsafe_token.span),
+        });
+        visit::visit_expr_unsafe(self, block);
+    }
+    
+    fn visit_item_impl(&mut self, item: &'ast ItemImpl) {
+        if let Some(unsafe_token) = &item.unsafety {
+            let trait_name = item.trait_.as_ref()
+                .and_then(|(_, path, _)| path.segments.last())
+                .map(|segment| segment.ident.to_string())
+                .unwrap_or_default();
+            let type_name = match item.self_ty.as_ref() {
+                Type::Path(path) => path.path.segments.last().map(|segment| segment.ident.to_string()).unwrap_or_default(),
+                _ => String::new(),
+            };
+            self.sites.push(UnsafeSite {
+                kind: format!("unsafe impl {} for {}", trait_name, type_name),
+                function: self.functions.last().cloned(),
+                span: SourceSpan::from_span(unsafe_token.span),
+            });
+        }
+        visit::visit_item_impl(self, item);
+    }
+}
+
+/// Whether the code at `line` (1-based) is documented by a `// SAFETY:` comment, either on
+/// the same line or in the comments directly above it, skipping attributes
+fn has_safety_comment(lines: &[String], line: usize) -> bool {
+    let is_safety = |text: &str| text.contains("SAFETY:");
+    if lines.get(line.wrapping_sub(1)).is_some_and(|text| text.contains("//") && is_safety(text)) {
+        return true;
+    }
+    lines[..line.saturating_sub(1).min(lines.len())]
+        .iter()
+        .rev()
+        .map(|text| text.trim())
+        .take_while(|text| text.starts_with("//") || text.starts_with("#["))
+        .any(is_safety)
+}
+
+/// Macros that panic when reached or when their condition fails
+const PANICKING_MACROS: &[&str] = &["panic", "unreachable", "todo", "unimplemented", "assert", "assert_eq", "assert_ne"];
+
+/// Path segments of calls doing file, network, or process I/O
+const IO_PATH_SEGMENTS: &[&str] = &["fs", "File", "OpenOptions", "net", "TcpStream", "UdpSocket", "Command"];
+
+/// Methods doing I/O on files, sockets, and writers
+const IO_METHODS: &[&str] = &["write_all", "flush", "sync_all", "sync_data", "read_to_string", "read_to_end", "shutdown"];
+
+/// Panic or I/O inside a `Drop::drop` implementation
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropHazard {
+    /// Type whose `Drop` impl contains the hazard
+    pub type_name: String,
+    /// What the hazard is, e.g. "unwrap()", "panic!", or "std::fs::remove_file"
+    pub kind: String,
+    /// Whether the hazard is I/O rather than a panic
+    pub io: bool,
+    /// Range of the call or macro
+    pub span: SourceSpan,
+}
+
+/// AST visitor collecting panics and I/O in the `drop` methods of `Drop` impls
+#[derive(Default)]
+struct DropHazardCollector {
+    hazards: Vec<DropHazard>,
+    /// Type whose `drop` method is being visited
+    dropping: Option<String>,
+}
+
+impl DropHazardCollector {
+    fn record(&mut self, kind: String, io: bool, span: SourceSpan) {
+        if let Some(type_name) = &self.dropping {
+            self.hazards.push(DropHazard { type_name: type_name.clone(), kind, io, span });
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for DropHazardCollector {
+    fn visit_item_impl(&mut self, item: &'ast ItemImpl) {
+        let is_drop = item.trait_.as_ref().and_then(|(_, path, _)| path.segments.last()).is_some_and(|segment| segment.ident == "Drop");
+        if !is_drop {
+            visit::visit_item_impl(self, item);
+            return;
+        }
+        for impl_item in &item.items {
+            if let syn::ImplItem::Fn(method) = impl_item {
+                if method.sig.ident == "drop" {
+                    self.dropping = type_name(&item.self_ty);
+                    self.visit_block(&method.block);
+                    self.dropping = None;
+                }
+            }
+        }
+    }
+    
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        let method = call.method.to_string();
+        if method == "unwrap" || method == "expect" {
+            self.record(format!("{}()", method), false, SourceSpan::from_span(call.method.span()));
+        } else if IO_METHODS.contains(&method.as_str()) {
+            self.record(format!(".{}()", method), true, SourceSpan::from_span(call.method.span()));
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+    
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        if let Expr::Path(path) = call.func.as_ref() {
+            if path.path.segments.iter().any(|segment| IO_PATH_SEGMENTS.iter().any(|name| segment.ident == name)) {
+                let name: Vec<String> = path.path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+                self.record(name.join("::"), true, SourceSpan::from_span(path.span()));
+            }
+        }
+        visit::visit_expr_call(self, call);
+    }
+    
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        if let Some(name) = PANICKING_MACROS.iter().find(|name| mac.path.is_ident(name)) {
+            self.record(format!("{}!", name), false, SourceSpan::between(mac.path.span(), mac.delimiter.span().close()));
+        }
+        // Macro arguments such as `assert!(file.flush().is_ok())` are code too
+        if let Ok(args) = Punctuated::<Expr, Token![,]>::parse_terminated.parse2(mac.tokens.clone()) {
+            for arg in &args {
+                self.visit_expr(arg);
+            }
+        }
+    }
+}
+
+/// Size gap in bytes between an enum's largest and next largest variant from which boxing pays off
+const LARGE_VARIANT_BYTES: usize = 200;
+
+/// Field count above which a struct should be split into sub-structs
+const LARGE_STRUCT_FIELDS: usize = 20;
+
+/// Inherent method count above which a type likely mixes unrelated concerns
+pub const GOD_TYPE_METHODS: usize = 24;
+
+/// Top-level item count above which a module likely mixes unrelated concerns
+pub const GOD_MODULE_ITEMS: usize = 40;
+
+/// Estimated size in bytes from which cloning a type on every loop iteration is costly
+const LARGE_TYPE_BYTES: usize = 256;
+
+/// Estimated sizes in bytes of common types; other unknown types count as a pointer
+const TYPE_SIZES: &[(&str, usize)] = &[
+    ("u8", 1), ("i8", 1), ("bool", 1),
+    ("u16", 2), ("i16", 2),
+    ("u32", 4), ("i32", 4), ("f32", 4), ("char", 4),
+    ("u128", 16), ("i128", 16),
+    ("String", 24), ("PathBuf", 24), ("Vec", 24), ("VecDeque", 24), ("BTreeMap", 24), ("BTreeSet", 24), ("BinaryHeap", 24),
+    ("HashMap", 48), ("HashSet", 48),
+];
+
+/// Enum whose largest variant dwarfs the others
+#[derive(Clone, Debug, PartialEq)]
+pub struct LargeEnumVariant {
+    /// Enum name
+    pub enum_name: String,
+    /// Largest variant
+    pub variant: String,
+    /// Estimated size of the largest variant's fields, in bytes
+    pub size: usize,
+    /// Estimated size of the next largest variant's fields, in bytes
+    pub next_size: usize,
+    /// Range of the variant name
+    pub span: SourceSpan,
+}
+
+/// Struct with more than `LARGE_STRUCT_FIELDS` fields
+#[derive(Clone, Debug, PartialEq)]
+pub struct LargeStruct {
+    /// Struct name
+    pub name: String,
+    /// Number of fields
+    pub fields: usize,
+    /// Range of the struct name
+    pub span: SourceSpan,
+}
+
+/// Type whose inherent methods outnumber `GOD_TYPE_METHODS`
+#[derive(Clone, Debug, PartialEq)]
+pub struct GodType {
+    /// Type name
+    pub name: String,
+    /// Names of its inherent methods, in declaration order
+    pub methods: Vec<String>,
+    /// Number of inherent impl blocks declaring them
+    pub impl_blocks: usize,
+    /// Number of fields, when the type is a struct declared in the file
+    pub fields: Option<usize>,
+    /// Range of the struct name, or of the first impl's type
+    pub span: SourceSpan,
+}
+
+/// `.clone()` of a large type deriving `Clone`, inside a loop
+#[derive(Clone, Debug, PartialEq)]
+pub struct HotClone {
+    /// Type of the cloned value
+    pub type_name: String,
+    /// Range of the `clone()` call
+    pub span: SourceSpan,
+}
+
+/// Estimated layout of a struct defined in the file
+#[derive(Clone, Debug)]
+struct TypeLayout {
+    name: String,
+    fields: usize,
+    size: usize,
+    derives_clone: bool,
+    span: SourceSpan,
+}
+
+impl TypeLayout {
+    /// Whether the type is costly to clone
+    fn is_large(&self) -> bool {
+        self.size >= LARGE_TYPE_BYTES || self.fields > LARGE_STRUCT_FIELDS
+    }
+}
+
+/// AST visitor collecting the methods of inherent impl blocks outside test code, per type
+#[derive(Default)]
+struct MethodCollector {
+    types: Vec<GodType>,
+}
+
+impl<'ast> Visit<'ast> for MethodCollector {
+    fn visit_item_mod(&mut self, module: &'ast ItemMod) {
+        if !module.attrs.iter().any(is_cfg_test) {
+            visit::visit_item_mod(self, module);
+        }
+    }
+    
+    fn visit_item_impl(&mut self, item: &'ast ItemImpl) {
+        let Some(name) = type_name(&item.self_ty).filter(|_| item.trait_.is_none()) else {
+            return;
+        };
+        let methods = item.items.iter().filter_map(|impl_item| match impl_item {
+            syn::ImplItem::Fn(method) => Some(method.sig.ident.to_string()),
+            _ => None,
+        });
+        match self.types.iter_mut().find(|existing| existing.name == name) {
+            Some(existing) => {
+                existing.methods.extend(methods);
+                existing.impl_blocks += 1;
+            }
+            None => self.types.push(GodType {
+                name,
+                methods: methods.collect(),
+                impl_blocks: 1,
+                fields: None,
+                span: SourceSpan::from_span(item.self_ty.span()),
+            }),
+        }
+    }
+}
+
+/// Verbs that lead function names without naming a concern, e.g. `get` in `get_session`
+const GENERIC_VERBS: &[&str] = &[
+    "add", "apply", "as", "build", "call", "check", "clear", "close", "compute", "create", "delete", "execute", "fetch", "find",
+    "format", "from", "get", "handle", "has", "init", "insert", "into", "invoke", "is", "list", "load", "make", "new", "on",
+    "open", "parse", "process", "read", "register", "remove", "render", "reset", "run", "save", "send", "set", "start", "stop",
+    "to", "try", "update", "validate", "with", "write",
+];
+
+/// Concern groups listed per god type or module
+const MAX_CONCERN_GROUPS: usize = 6;
+
+/// Function names listed per concern group
+const MAX_GROUP_NAMES: usize = 5;
+
+/// Candidate concern groups of function names, largest first, e.g.
+/// "session (create_session, get_session)"
+///
+/// Names are grouped by their first word, or by the word after a leading generic verb,
+/// and only groups of at least two names are listed.
+fn concern_groups(names: &[String]) -> Vec<String> {
+    let mut groups: Vec<(String, Vec<&String>)> = Vec::new();
+    for name in names {
+        let words: Vec<&str> = name.split('_').filter(|word| !word.is_empty()).collect();
+        let key = match words.as_slice() {
+            [verb, noun, ..] if GENERIC_VERBS.contains(verb) => *noun,
+            [first, ..] => *first,
+            [] => continue,
+        };
+        match groups.iter_mut().find(|(existing, _)| existing == key) {
+            Some((_, members)) => members.push(name),
+            None => groups.push((key.to_string(), vec![name])),
+        }
+    }
+    groups.retain(|(_, members)| members.len() > 1);
+    groups.sort_by_key(|(_, members)| std::cmp::Reverse(members.len()));
+    groups
+        .into_iter()
+        .take(MAX_CONCERN_GROUPS)
+        .map(|(key, members)| {
+            let mut listed: Vec<&str> = members.iter().take(MAX_GROUP_NAMES).map(|name| name.as_str()).collect();
+            if members.len() > MAX_GROUP_NAMES {
+                listed.push("...");
+            }
+            format!("{} ({})", key, listed.join(", "))
+        })
+        .collect()
+}
+
+/// AST visitor collecting the structs and enums of a file
+#[derive(Default)]
+struct TypeCollector<'ast> {
+    structs: Vec<&'ast ItemStruct>,
+    enums: Vec<&'ast ItemEnum>,
+}
+
+impl<'ast> TypeCollector<'ast> {
+    /// Estimated layouts of the structs and enums, by name
+    ///
+    /// Structs are measured first, so enum variants holding them count their fields.
+    fn layouts(&self) -> HashMap<String, TypeLayout> {
+        let mut layouts: HashMap<String, TypeLayout> = HashMap::new();
+        for item in &self.structs {
+            let size = fields_size(&item.fields, &layouts);
+            layouts.insert(item.ident.to_string(), TypeLayout {
+                name: item.ident.to_string(),
+                fields: item.fields.len(),
+                size,
+                derives_clone: derives_trait(&item.attrs, "Clone"),
+                span: SourceSpan::from_span(item.ident.span()),
+            });
+        }
+        for item in &self.enums {
+            let size = item.variants.iter().map(|variant| fields_size(&variant.fields, &layouts)).max().unwrap_or_default();
+            layouts.insert(item.ident.to_string(), TypeLayout {
+                name: item.ident.to_string(),
+                fields: 0,
+                size,
+                derives_clone: derives_trait(&item.attrs, "Clone"),
+                span: SourceSpan::from_span(item.ident.span()),
+            });
+        }
+        layouts
+    }
+}
+
+impl<'ast> Visit<'ast> for TypeCollector<'ast> {
+    fn visit_item_struct(&mut self, item: &'ast ItemStruct) {
+        self.structs.push(item);
+        visit::visit_item_struct(self, item);
+    }
+    
+    fn visit_item_enum(&mut self, item: &'ast ItemEnum) {
+        self.enums.push(item);
+        visit::visit_item_enum(self, item);
+    }
+}
+
+/// Largest variant of an enum, when it is `LARGE_VARIANT_BYTES` larger than the next one
+fn large_enum_variant(item: &ItemEnum, layouts: &HashMap<String, TypeLayout>) -> Option<LargeEnumVariant> {
+    let mut sizes: Vec<(usize, &syn::Variant)> = item.variants.iter().map(|variant| (fields_size(&variant.fields, layouts), variant)).collect();
+    sizes.sort_by_key(|(size, _)| std::cmp::Reverse(*size));
+    let (size, variant) = *sizes.first()?;
+    let next_size = sizes.get(1)?.0;
+    (size - next_size >= LARGE_VARIANT_BYTES).then(|| LargeEnumVariant {
+        enum_name: item.ident.to_string(),
+        variant: variant.ident.to_string(),
+        size,
+        next_size,
+        span: SourceSpan::from_span(variant.ident.span()),
+    })
+}
+
+/// Estimated size of a list of fields, in bytes, ignoring padding
+fn fields_size(fields: &Fields, layouts: &HashMap<String, TypeLayout>) -> usize {
+    fields.iter().map(|field| estimated_size(&field.ty, layouts)).sum()
+}
+
+/// Estimated size of a type, in bytes, using the layouts of types defined in the file
+fn estimated_size(ty: &Type, layouts: &HashMap<String, TypeLayout>) -> usize {
+    match ty {
+        Type::Array(array) => {
+            let len = match &array.len {
+                Expr::Lit(ExprLit { lit: Lit::Int(len), .. }) => len.base10_parse().unwrap_or(1),
+                _ => 1,
+            };
+            estimated_size(&array.elem, layouts).saturating_mul(len)
+        }
+        Type::Tuple(tuple) => tuple.elems.iter().map(|elem| estimated_size(elem, layouts)).sum(),
+        Type::Paren(paren) => estimated_size(&paren.elem, layouts),
+        Type::Path(path) => {
+            let Some(segment) = path.path.segments.last() else {
+                return 8;
+            };
+            let name = segment.ident.to_string();
+            // Option uses the niche of its payload for most payload types
+            if name == "Option" {
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    if let Some(GenericArgument::Type(inner)) = args.args.first() {
+                        return estimated_size(inner, layouts);
+                    }
+                }
+            }
+            TYPE_SIZES
+                .iter()
+                .find(|(known, _)| *known == name)
+                .map(|(_, size)| *size)
+                .or_else(|| layouts.get(&name).map(|layout| layout.size))
+                .unwrap_or(8)
+        }
+        _ => 8,
+    }
+}
+
+/// AST visitor locating `.clone()` calls inside loops on bindings of a large `Clone` type
+///
+/// Bindings are typed from function parameters, annotated `let` statements, and `self`.
+#[derive(Default)]
+struct HotCloneScanner {
+    large_types: Vec<TypeLayout>,
+    /// Typed bindings in scope, innermost last, as (binding, type name)
+    bindings: Vec<(String, String)>,
+    /// Type of `self` in the enclosing impl block
+    self_type: Option<String>,
+    loop_depth: usize,
+    clones: Vec<HotClone>,
+}
+
+impl HotCloneScanner {
+    /// Visit a function with its typed parameters in scope
+    fn in_function(&mut self, sig: &Signature, visit: impl FnOnce(&mut Self)) {
+        let scope = self.bindings.len();
+        let outer_depth = std::mem::take(&mut self.loop_depth);
+        for input in &sig.inputs {
+            if let FnArg::Typed(param) = input {
+                self.bind(&param.pat, &param.ty);
+            }
+        }
+        visit(self);
+        self.bindings.truncate(scope);
+        self.loop_depth = outer_depth;
+    }
+    
+    /// Record the type of an identifier pattern
+    fn bind(&mut self, pat: &Pat, ty: &Type) {
+        if let (Pat::Ident(pat), Some(type_name)) = (pat, type_name(ty)) {
+            self.bindings.push((pat.ident.to_string(), type_name));
+        }
+    }
+    
+    /// Visit a loop body
+    fn in_loop(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.loop_depth += 1;
+        visit(self);
+        self.loop_depth -= 1;
+    }
+}
+
+impl<'ast> Visit<'ast> for HotCloneScanner {
+    fn visit_item_impl(&mut self, item: &'ast ItemImpl) {
+        let outer = std::mem::replace(&mut self.self_type, type_name(&item.self_ty));
+        visit::visit_item_impl(self, item);
+        self.self_type = outer;
+    }
+    
+    fn visit_item_fn(&mut self, func: &'ast ItemFn) {
+        self.in_function(&func.sig, |scanner| visit::visit_item_fn(scanner, func));
+    }
+    
+    fn visit_impl_item_fn(&mut self, func: &'ast ImplItemFn) {
+        self.in_function(&func.sig, |scanner| visit::visit_impl_item_fn(scanner, func));
+    }
+    
+    fn visit_local(&mut self, local: &'ast Local) {
+        visit::visit_local(self, local);
+        if let Pat::Type(typed) = &local.pat {
+            self.bind(&typed.pat, &typed.ty);
+        }
+    }
+    
+    fn visit_expr_for_loop(&mut self, for_loop: &'ast ExprForLoop) {
+        self.visit_expr(&for_loop.expr);
+        self.in_loop(|scanner| scanner.visit_block(&for_loop.body));
+    }
+    
+    fn visit_expr_while(&mut self, while_expr: &'ast ExprWhile) {
+        self.in_loop(|scanner| visit::visit_expr_while(scanner, while_expr));
+    }
+    
+    fn visit_expr_loop(&mut self, loop_expr: &'ast ExprLoop) {
+        self.in_loop(|scanner| visit::visit_expr_loop(scanner, loop_expr));
+    }
+    
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        if call.method == "clone" && call.args.is_empty() && self.loop_depth > 0 {
+            let receiver = match call.receiver.as_ref() {
+                Expr::Path(path) => path.path.get_ident().map(|ident| ident.to_string()),
+                _ => None,
+            };
+            let type_name = match receiver.as_deref() {
+                Some("self") => self.self_type.clone(),
+                Some(receiver) => self.bindings.iter().rev().find(|(binding, _)| binding == receiver).map(|(_, type_name)| type_name.clone()),
+                None => None,
+            };
+            if let Some(type_name) = type_name.filter(|type_name| self.large_types.iter().any(|layout| layout.name == *type_name)) {
+                self.clones.push(HotClone {
+                    type_name,
+                    span: SourceSpan::between(call.method.span(), call.paren_token.span.close()),
+                });
+            }
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+}
+
+/// Public item declared in the file
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicItem {
+    /// Item name
+    pub name: String,
+    /// Item kind, e.g. "fn" or "struct"
+    pub kind: &'static str,
+    /// Range of the item name
+    pub span: SourceSpan,
+}
+
+/// Public function returning `anyhow::Result` or `Box<dyn Error>`
+#[derive(Clone, Debug, PartialEq)]
+pub struct UntypedErrorFunction {
+    /// Function or method name
+    pub name: String,
+    /// Untyped error the function returns, e.g. "anyhow::Result"
+    pub error_type: &'static str,
+    /// Range of the function name
+    pub span: SourceSpan,
+    /// Variants for a typed error enum, one per distinct error site in the body
+    pub variants: Vec<ErrorVariant>,
+}
+
+/// Variant of a suggested thiserror enum
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorVariant {
+    /// Variant name, e.g. "Io"
+    pub name: String,
+    /// Display message for `#[error(...)]`, e.g. "I/O error: {0}"
+    pub message: String,
+    /// Tuple fields, e.g. "#[from] std::io::Error" or "String"
+    pub fields: Vec<String>,
+}
+
+impl ErrorVariant {
+    /// Variant declaration with its `#[error]` attribute, indented for an enum body
+    pub fn declaration(&self) -> String {
+        let fields = if self.fields.is_empty() { String::new() } else { format!("({})", self.fields.join(", ")) };
+        format!("    #[error(\"{}\")]\n    {}{},\n", self.message.replace('"', "\\\""), self.name, fields)
+    }
+}
+
+/// AST visitor collecting `pub` items outside test code, the names the file refers to,
+/// and the names it re-exports with `pub use`
+///
+/// The types of impl blocks do not count as references, since every type with methods
+/// would otherwise look used.
+#[derive(Default)]
+struct PublicItemCollector {
+    items: Vec<PublicItem>,
+    referenced: Vec<String>,
+    reexported: Vec<String>,
+}
+
+impl PublicItemCollector {
+    /// Record an item declared with plain `pub`
+    fn record(&mut self, vis: &Visibility, kind: &'static str, ident: &syn::Ident) {
+        if matches!(vis, Visibility::Public(_)) && ident != "main" {
+            self.items.push(PublicItem { name: ident.to_string(), kind, span: SourceSpan::from_span(ident.span()) });
+        }
+    }
+    
+    /// Record the identifiers of macro tokens, which syn does not parse
+    fn reference_tokens(&mut self, tokens: proc_macro2::TokenStream) {
+        for token in tokens {
+            match token {
+                proc_macro2::TokenTree::Ident(ident) => self.referenced.push(ident.to_string()),
+                proc_macro2::TokenTree::Group(group) => self.reference_tokens(group.stream()),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for PublicItemCollector {
+    fn visit_item(&mut self, item: &'ast Item) {
+        match item {
+            Item::Fn(func) => self.record(&func.vis, "fn", &func.sig.ident),
+            Item::Struct(item) => self.record(&item.vis, "struct", &item.ident),
+            Item::Enum(item) => self.record(&item.vis, "enum", &item.ident),
+            Item::Trait(item) => self.record(&item.vis, "trait", &item.ident),
+            Item::Const(item) => self.record(&item.vis, "const", &item.ident),
+            Item::Static(item) => self.record(&item.vis, "static", &item.ident),
+            Item::Type(item) => self.record(&item.vis, "type", &item.ident),
+            Item::Use(item) if matches!(item.vis, Visibility::Public(_)) => {
+                let mut names = UseNameCollector::default();
+                names.visit_use_tree(&item.tree);
+                self.reexported.extend(names.names);
+            }
+            _ => {}
+        }
+        visit::visit_item(self, item);
+    }
+    
+    fn visit_item_mod(&mut self, module: &'ast ItemMod) {
+        if !module.attrs.iter().any(is_cfg_test) {
+            visit::visit_item_mod(self, module);
+        }
+    }
+    
+    fn visit_item_impl(&mut self, item: &'ast ItemImpl) {
+        for impl_item in &item.items {
+            self.visit_impl_item(impl_item);
+        }
+    }
+    
+    fn visit_path(&mut self, path: &'ast syn::Path) {
+        self.referenced.extend(path.segments.iter().map(|segment| segment.ident.to_string()));
+        visit::visit_path(self, path);
+    }
+    
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        self.reference_tokens(mac.tokens.clone());
+        visit::visit_macro(self, mac);
+    }
+}
+
+/// AST visitor collecting the names a `use` tree brings into scope under their original name
+#[derive(Default)]
+struct UseNameCollector {
+    names: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for UseNameCollector {
+    fn visit_use_name(&mut self, name: &'ast syn::UseName) {
+        self.names.push(name.ident.to_string());
+    }
+    
+    fn visit_use_rename(&mut self, rename: &'ast syn::UseRename) {
+        self.names.push(rename.ident.to_string());
+    }
+}
+
+/// Error type of a well-known fallible call, as a `#[from]` variant of a typed error
+struct ErrorSource {
+    variant: &'static str,
+    message: &'static str,
+    error_type: &'static str,
+}
+
+/// Error types of common std and ecosystem calls
+const ERROR_SOURCES: &[ErrorSource] = &[
+    ErrorSource { variant: "Io", message: "I/O error: {0}", error_type: "std::io::Error" },
+    ErrorSource { variant: "Json", message: "invalid JSON: {0}", error_type: "serde_json::Error" },
+    ErrorSource { variant: "Yaml", message: "invalid YAML: {0}", error_type: "serde_yaml::Error" },
+    ErrorSource { variant: "Toml", message: "invalid TOML: {0}", error_type: "toml::de::Error" },
+    ErrorSource { variant: "Http", message: "HTTP request failed: {0}", error_type: "reqwest::Error" },
+    ErrorSource { variant: "Env", message: "environment variable not set: {0}", error_type: "std::env::VarError" },
+    ErrorSource { variant: "ParseInt", message: "invalid integer: {0}", error_type: "std::num::ParseIntError" },
+    ErrorSource { variant: "ParseFloat", message: "invalid number: {0}", error_type: "std::num::ParseFloatError" },
+];
+
+/// Words left out of variant names derived from error messages
+const VARIANT_STOP_WORDS: &[&str] = &["a", "an", "and", "at", "for", "from", "in", "is", "of", "on", "or", "the", "to", "was", "with"];
+
+/// AST visitor collecting public functions and inherent methods outside test code that
+/// return `anyhow::Result` or `Box<dyn Error>`, with the error sites of their bodies
+#[derive(Default)]
+struct UntypedErrorCollector {
+    /// Whether the file imports `anyhow::Result`, so a bare `Result<T>` is anyhow's
+    anyhow_result: bool,
+    /// Whether the file imports `anyhow::Error`
+    anyhow_error: bool,
+    functions: Vec<UntypedErrorFunction>,
+}
+
+impl UntypedErrorCollector {
+    /// Note the names the file imports from anyhow
+    fn new(file: &File) -> Self {
+        let mut names = UseNameCollector::default();
+        for item in &file.items {
+            if let Item::Use(item) = item {
+                if matches!(&item.tree, syn::UseTree::Path(path) if path.ident == "anyhow") {
+                    names.visit_use_tree(&item.tree);
+                }
+            }
+        }
+        Self {
+            anyhow_result: names.names.iter().any(|name| name == "Result"),
+            anyhow_error: names.names.iter().any(|name| name == "Error"),
+            functions: Vec::new(),
+        }
+    }
+    
+    /// Record a public function returning an untyped error
+    fn record(&mut self, vis: &Visibility, sig: &Signature, block: &Block) {
+        if !matches!(vis, Visibility::Public(_)) {
+            return;
+        }
+        if let Some(error_type) = self.untyped_error(sig) {
+            let mut sites = ErrorSiteCollector::default();
+            sites.visit_block(block);
+            self.functions.push(UntypedErrorFunction {
+                name: sig.ident.to_string(),
+                error_type,
+                span: SourceSpan::from_span(sig.ident.span()),
+                variants: sites.variants,
+            });
+        }
+    }
+    
+    /// Untyped error a signature returns, if any
+    fn untyped_error(&self, sig: &Signature) -> Option<&'static str> {
+        let ReturnType::Type(_, ty) = &sig.output else {
+            return None;
+        };
+        let Type::Path(path) = ty.as_ref() else {
+            return None;
+        };
+        let segments = &path.path.segments;
+        let last = segments.last()?;
+        let PathArguments::AngleBracketed(args) = &last.arguments else {
+            return None;
+        };
+        if last.ident != "Result" {
+            return None;
+        }
+        let types: Vec<&Type> = args.args.iter().filter_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }).collect();
+        let anyhow_path = segments.len() == 2 && segments[0].ident == "anyhow";
+        match types.as_slice() {
+            [_] if anyhow_path || (segments.len() == 1 && self.anyhow_result) => Some("anyhow::Result"),
+            [_, error] if self.is_anyhow_error(error) => Some("anyhow::Result"),
+            [_, error] if is_boxed_dyn_error(error) => Some("Box<dyn Error>"),
+            _ => None,
+        }
+    }
+    
+    /// Whether a type is `anyhow::Error`
+    fn is_anyhow_error(&self, ty: &Type) -> bool {
+        let Type::Path(path) = ty else {
+            return false;
+        };
+        let segments: Vec<String> = path.path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+        segments == ["anyhow", "Error"] || (segments == ["Error"] && self.anyhow_error)
+    }
+}
+
+impl<'ast> Visit<'ast> for UntypedErrorCollector {
+    fn visit_item_fn(&mut self, func: &'ast ItemFn) {
+        self.record(&func.vis, &func.sig, &func.block);
+    }
+    
+    fn visit_item_mod(&mut self, module: &'ast ItemMod) {
+        if !module.attrs.iter().any(is_cfg_test) {
+            visit::visit_item_mod(self, module);
+        }
+    }
+    
+    fn visit_item_impl(&mut self, item: &'ast ItemImpl) {
+        // Trait methods keep the trait's signature
+        if item.trait_.is_some() {
+            return;
+        }
+        for impl_item in &item.items {
+            if let syn::ImplItem::Fn(method) = impl_item {
+                self.record(&method.vis, &method.sig, &method.block);
+            }
+        }
+    }
+}
+
+/// Whether a type is `Box<dyn Error>`, with any auto-trait bounds
+fn is_boxed_dyn_error(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    let Some(segment) = path.path.segments.last().filter(|segment| segment.ident == "Box") else {
+        return false;
+    };
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return false;
+    };
+    args.args.iter().any(|arg| matches!(
+        arg,
+        GenericArgument::Type(Type::TraitObject(object)) if object.bounds.iter().any(|bound| matches!(
+            bound,
+            syn::TypeParamBound::Trait(bound) if bound.path.segments.last().is_some_and(|segment| segment.ident == "Error")
+        ))
+    ))
+}
+
+/// AST visitor deriving typed error variants from the error sites of a function body:
+/// `?` on well-known fallible calls, `.context(...)` messages, `bail!`, `anyhow!`, and
+/// `ensure!` messages, and `Err("...".into())`
+#[derive(Default)]
+struct ErrorSiteCollector {
+    variants: Vec<ErrorVariant>,
+}
+
+impl ErrorSiteCollector {
+    fn add(&mut self, variant: ErrorVariant) {
+        if !self.variants.iter().any(|existing| existing.name == variant.name) {
+            self.variants.push(variant);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for ErrorSiteCollector {
+    fn visit_expr_try(&mut self, expr: &'ast syn::ExprTry) {
+        match context_call(&expr.expr) {
+            Some(call) => {
+                if let Some(message) = call.args.first().and_then(message_literal) {
+                    self.add(message_variant(&message, error_source(&call.receiver).map(|source| source.error_type)));
+                }
+            }
+            None => {
+                if let Some(source) = error_source(&expr.expr) {
+                    self.add(ErrorVariant {
+                        name: source.variant.to_string(),
+                        message: source.message.to_string(),
+                        fields: vec![format!("#[from] {}", source.error_type)],
+                    });
+                }
+            }
+        }
+        visit::visit_expr_try(self, expr);
+    }
+    
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        let is_err = matches!(call.func.as_ref(), Expr::Path(path) if path.path.is_ident("Err"));
+        if let (true, Some(arg)) = (is_err, call.args.first()) {
+            let mut arg = arg;
+            while let Expr::MethodCall(method) = arg {
+                if !["into", "to_string", "to_owned"].iter().any(|name| method.method == name) {
+                    break;
+                }
+                arg = &method.receiver;
+            }
+            if let Some(message) = message_literal(arg) {
+                self.add(message_variant(&message, None));
+            }
+        }
+        visit::visit_expr_call(self, call);
+    }
+    
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        let name = mac.path.segments.last().map(|segment| segment.ident.to_string()).unwrap_or_default();
+        let message_index = match name.as_str() {
+            "bail" | "anyhow" => 0,
+            "ensure" => 1,
+            _ => return,
+        };
+        let message = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated).ok().and_then(|args| match args.iter().nth(message_index) {
+            Some(Expr::Lit(ExprLit { lit: Lit::Str(message), .. })) => Some(message.value()),
+            _ => None,
+        });
+        if let Some(message) = message {
+            self.add(message_variant(&message, None));
+        }
+    }
+    
+    // Nested functions have error sites of their own
+    fn visit_item_fn(&mut self, _: &'ast ItemFn) {}
+}
+
+/// The `.context(...)` or `.with_context(...)` call an expression ends with, if any
+fn context_call(expr: &Expr) -> Option<&ExprMethodCall> {
+    match expr {
+        Expr::Await(expr) => context_call(&expr.base),
+        Expr::Paren(expr) => context_call(&expr.expr),
+        Expr::MethodCall(call) if call.method == "context" || call.method == "with_context" => Some(call),
+        _ => None,
+    }
+}
+
+/// Message of a string literal, a `format!` call, or a closure returning either
+fn message_literal(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Str(message), .. }) => Some(message.value()),
+        Expr::Closure(closure) => message_literal(&closure.body),
+        Expr::Macro(mac) if mac.mac.path.is_ident("format") => {
+            let args = mac.mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated).ok()?;
+            args.first().and_then(message_literal)
+        }
+        _ => None,
+    }
+}
+
+/// Error type of a well-known fallible call, looking through `.await` and method chains
+fn error_source(expr: &Expr) -> Option<&'static ErrorSource> {
+    let variant = |name: &str| ERROR_SOURCES.iter().find(|source| source.variant == name);
+    match expr {
+        Expr::Await(expr) => error_source(&expr.base),
+        Expr::Paren(expr) => error_source(&expr.expr),
+        Expr::MethodCall(call) if call.method == "parse" => {
+            let target = call.turbofish.as_ref().and_then(|turbofish| match turbofish.args.first() {
+                Some(GenericArgument::Type(ty)) => type_name(ty),
+                _ => None,
+            })?;
+            match target.as_str() {
+                "f32" | "f64" => variant("ParseFloat"),
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => variant("ParseInt"),
+                _ => None,
+            }
+        }
+        Expr::MethodCall(call) => error_source(&call.receiver),
+        Expr::Call(call) => {
+            let Expr::Path(path) = call.func.as_ref() else {
+                return None;
+            };
+            let segments: Vec<String> = path.path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+            let has = |name: &str| segments.iter().any(|segment| segment == name);
+            if has("serde_json") {
+                variant("Json")
+            } else if has("serde_yaml") {
+                variant("Yaml")
+            } else if has("toml") && has("from_str") {
+                variant("Toml")
+            } else if has("reqwest") {
+                variant("Http")
+            } else if segments.ends_with(&["env".to_string(), "var".to_string()]) {
+                variant("Env")
+            } else if ["fs", "io", "net", "File", "OpenOptions", "TcpStream", "TcpListener", "UdpSocket"].iter().any(|name| has(name)) {
+                variant("Io")
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Variant for an error message, with a `String` field per placeholder and the source error
+fn message_variant(message: &str, source: Option<&str>) -> ErrorVariant {
+    let mut template = String::new();
+    let mut placeholders = 0;
+    let mut chars = message.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                template.push_str("{{");
+            }
+            '{' => {
+                let inner: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                let spec = inner.split_once(':').map(|(_, spec)| format!(":{}", spec)).unwrap_or_default();
+                template.push_str(&format!("{{{}{}}}", placeholders, spec));
+                placeholders += 1;
+            }
+            c => template.push(c),
+        }
+    }
+    
+    let name: String = template
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .filter(|word| !word.is_empty() && !VARIANT_STOP_WORDS.contains(&word.to_lowercase().as_str()))
+        .take(3)
+        .map(|word| word[..1].to_uppercase() + &word[1..].to_lowercase())
+        .collect();
+    let mut fields = vec!["String".to_string(); placeholders];
+    fields.extend(source.map(|source| format!("#[source] {}", source)));
+    ErrorVariant { name: if name.is_empty() { "Other".to_string() } else { name }, message: template, fields }
+}
+
+/// Name of a type behind any references, e.g. "AgentState" for `&mut AgentState`
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Reference(reference) => type_name(&reference.elem),
+        Type::Path(path) => path.path.segments.last().map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
+
+/// Channel capacity from which a bounded channel is effectively unbounded
+const OVERSIZED_CHANNEL_CAPACITY: u128 = 65_536;
+
+/// Whether a channel capacity is a literal of at least `OVERSIZED_CHANNEL_CAPACITY` or a `MAX` constant
+fn is_oversized_capacity(capacity: &Expr) -> bool {
+    match capacity {
+        Expr::Lit(ExprLit { lit: Lit::Int(value), .. }) => value.base10_parse::<u128>().is_ok_and(|value| value >= OVERSIZED_CHANNEL_CAPACITY),
+        Expr::Path(path) => path.path.segments.last().is_some_and(|segment| segment.ident == "MAX"),
+        _ => false,
+    }
+}
+
+/// Methods growing a queue-like collection
+const QUEUE_PUSH_METHODS: &[&str] = &["push", "push_back", "push_front"];
+
+/// Methods removing items from a queue-like collection
+const QUEUE_DRAIN_METHODS: &[&str] = &["pop", "pop_back", "pop_front", "drain", "clear", "truncate", "remove", "retain", "split_off"];
+
+/// AST visitor collecting the collections a loop body pushes to and drains, and the
+/// variables it declares
+#[derive(Default)]
+struct QueueGrowthScanner {
+    /// Collections pushed to, with the span of the first push
+    pushes: Vec<(String, SourceSpan)>,
+    /// Collections drained
+    drains: Vec<String>,
+    /// Variables declared in the body, recreated on every iteration
+    locals: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for QueueGrowthScanner {
+    fn visit_local(&mut self, local: &'ast Local) {
+        let mut bindings = NameCollector::default();
+        bindings.visit_pat(&local.pat);
+        self.locals.extend(bindings.names);
+        visit::visit_local(self, local);
+    }
+    
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        if let Expr::Path(receiver) = call.receiver.as_ref() {
+            if let Some(name) = receiver.path.get_ident().map(|ident| ident.to_string()) {
+                let method = call.method.to_string();
+                if QUEUE_PUSH_METHODS.contains(&method.as_str()) && !self.pushes.iter().any(|(pushed, _)| *pushed == name) {
+                    self.pushes.push((name, SourceSpan::from_span(call.span())));
+                } else if QUEUE_DRAIN_METHODS.contains(&method.as_str()) {
+                    self.drains.push(name);
+                }
+            }
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+    
+    fn visit_expr_closure(&mut self, _: &'ast ExprClosure) {}
+}
+
+/// AST visitor locating `for` loops that await a future built from the loop item on each
+/// iteration, so the iterations run one after another
+#[derive(Default)]
+struct SequentialAwaitCollector {
+    loops: Vec<SourceSpan>,
+}
+
+impl<'ast> Visit<'ast> for SequentialAwaitCollector {
+    fn visit_expr_for_loop(&mut self, for_loop: &'ast ExprForLoop) {
+        let mut bindings = NameCollector::default();
+        bindings.visit_pat(&for_loop.pat);
+        let mut body = LoopBodyScanner::default();
+        body.visit_block(&for_loop.body);
+        
+        // Awaits on state the loop updates, or loops that stop early, depend on earlier iterations
+        let independent = body.awaited.iter().any(|names| {
+            names.iter().any(|name| bindings.names.contains(name)) && !names.iter().any(|name| body.assigned.contains(name))
+        });
+        if independent && !body.breaks {
+            self.loops.push(SourceSpan::between(for_loop.for_token.span, for_loop.expr.span()));
+        }
+        visit::visit_expr_for_loop(self, for_loop);
+    }
+}
+
+/// AST visitor collecting the identifiers a pattern binds or an expression mentions
+#[derive(Default)]
+struct NameCollector {
+    names: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for NameCollector {
+    fn visit_pat_ident(&mut self, pat: &'ast PatIdent) {
+        self.names.push(pat.ident.to_string());
+        visit::visit_pat_ident(self, pat);
+    }
+    
+    fn visit_expr_path(&mut self, path: &'ast ExprPath) {
+        if let Some(ident) = path.path.get_ident() {
+            self.names.push(ident.to_string());
+        }
+        visit::visit_expr_path(self, path);
+    }
+}
+
+/// AST visitor collecting what a loop body awaits and assigns, and whether it exits early
+///
+/// Nested loops, closures, and async blocks are skipped, since their awaits do not run
+/// directly in the loop.
+#[derive(Default)]
+struct LoopBodyScanner {
+    /// Identifiers mentioned by each awaited expression
+    awaited: Vec<Vec<String>>,
+    /// Variables assigned in the body
+    assigned: Vec<String>,
+    /// Whether the body contains `break` or `return`
+    breaks: bool,
+}
+
+impl<'ast> Visit<'ast> for LoopBodyScanner {
+    fn visit_expr_await(&mut self, await_expr: &'ast ExprAwait) {
+        let mut mentioned = NameCollector::default();
+        mentioned.visit_expr(&await_expr.base);
+        self.awaited.push(mentioned.names);
+        visit::visit_expr_await(self, await_expr);
+    }
+    
+    fn visit_expr_assign(&mut self, assign: &'ast ExprAssign) {
+        let mut target = NameCollector::default();
+        target.visit_expr(&assign.left);
+        self.assigned.extend(target.names);
+        visit::visit_expr_assign(self, assign);
+    }
+    
+    fn visit_expr_binary(&mut self, binary: &'ast ExprBinary) {
+        // Compound assignments such as `total += ...`
+        if matches!(
+            binary.op,
+            BinOp::AddAssign(_) | BinOp::SubAssign(_) | BinOp::MulAssign(_) | BinOp::DivAssign(_) | BinOp::RemAssign(_)
+        ) {
+            let mut target = NameCollector::default();
+            target.visit_expr(&binary.left);
+            self.assigned.extend(target.names);
+        }
+        visit::visit_expr_binary(self, binary);
+    }
+    
+    fn visit_expr_break(&mut self, break_expr: &'ast ExprBreak) {
+        self.breaks = true;
+        visit::visit_expr_break(self, break_expr);
+    }
+    
+    fn visit_expr_return(&mut self, return_expr: &'ast ExprReturn) {
+        self.breaks = true;
+        visit::visit_expr_return(self, return_expr);
+    }
+    
+    fn visit_expr_for_loop(&mut self, _: &'ast ExprForLoop) {}
+    
+    fn visit_expr_closure(&mut self, _: &'ast ExprClosure) {}
+    
+    fn visit_expr_async(&mut self, _: &'ast ExprAsync) {}
+}
+
+/// Methods returning a lock guard or `RefCell` borrow that lives as long as its binding
+const GUARD_METHODS: &[&str] = &["lock", "read", "write", "try_lock", "borrow", "borrow_mut"];
+
+/// Lock guard or `RefCell` borrow bound in async code and still alive at an `.await`
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuardAcrossAwait {
+    /// Name the guard is bound to
+    pub guard: String,
+    /// Method that produced the guard, e.g. "lock()"
+    pub method: String,
+    /// Async function containing the binding, if any
+    pub function: Option<String>,
+    /// Line (1-based) of the first `.await` reached while the guard is alive
+    pub await_line: usize,
+    /// Range of the guard's binding
+    pub span: SourceSpan,
+}
+
+/// AST visitor finding guards from `GUARD_METHODS` bound with `let` in async functions and
+/// blocks and still alive at a later `.await` of the same block
+///
+/// A guard stays alive until the end of its block or an explicit `drop(guard)`. Guards
+/// produced by an awaited call, such as `tokio::sync::Mutex::lock().await`, are held safely.
+#[derive(Default)]
+struct GuardAwaitCollector {
+    guards: Vec<GuardAcrossAwait>,
+    functions: Vec<String>,
+    in_async: bool,
+}
+
+impl GuardAwaitCollector {
+    /// Visit a body with `in_async` set for its duration
+    fn visit_body(&mut self, is_async: bool, visit_body: impl FnOnce(&mut Self)) {
+        let outer = std::mem::replace(&mut self.in_async, is_async);
+        visit_body(self);
+        self.in_async = outer;
+    }
+}
+
+impl<'ast> Visit<'ast> for GuardAwaitCollector {
+    fn visit_item_fn(&mut self, func: &'ast ItemFn) {
+        self.functions.push(func.sig.ident.to_string());
+        self.visit_body(func.sig.asyncness.is_some(), |collector| visit::visit_item_fn(collector, func));
+        self.functions.pop();
+    }
+    
+    fn visit_impl_item_fn(&mut self, func: &'ast ImplItemFn) {
+        self.functions.push(func.sig.ident.to_string());
+        self.visit_body(func.sig.asyncness.is_some(), |collector| visit::visit_impl_item_fn(collector, func));
+        self.functions.pop();
     }
     
-    /// Extract all impl blocks from the AST
-    pub fn extract_impls(&self) -> Vec<&ItemImpl> {
-        if let Some(ast) = &self.ast {
-            ast.items.iter()
-                .filter_map(|item| match item {
-                    Item::Impl(i) => Some(i),
-                    _ => None,
-                })
-                .collect()
-        } else {
-            Vec::new()
-        }
+    fn visit_expr_async(&mut self, block: &'ast ExprAsync) {
+        self.visit_body(true, |collector| visit::visit_expr_async(collector, block));
     }
     
-    /// Analyze code complexity and patterns
-    pub fn analyze_patterns(&self) -> CodePatterns {
-        let mut patterns = CodePatterns::default();
-        
-        // Analyze function patterns
-        for func in self.extract_functions() {
-            patterns.function_count += 1;
-            
-            // Check for async functions
-            if func.sig.asyncness.is_some() {
-                patterns.async_functions += 1;
-            }
-            
-            // Check for public functions
-            if matches!(func.vis, Visibility::Public(_)) {
-                patterns.public_functions += 1;
-            }
-            
-            // Check for error handling patterns
-            let func_str = quote::ToTokens::to_token_stream(func).to_string();
-            if func_str.contains("Result<") {
-                patterns.result_returning_functions += 1;
-            }
-        }
-        
-        // Analyze struct patterns
-        patterns.struct_count = self.extract_structs().len();
-        patterns.enum_count = self.extract_enums().len();
-        patterns.impl_count = self.extract_impls().len();
-        
-        // Analyze source patterns
-        for (line_num, line) in self.lines.iter().enumerate() {
-            if line.contains("unwrap()") {
-                patterns.unwrap_usage.push(line_num + 1);
-            }
-            if line.contains("panic!") {
-                patterns.panic_usage.push(line_num + 1);
-            }
-            if line.contains("todo!") || line.contains("unimplemented!") {
-                patterns.todo_usage.push(line_num + 1);
+    fn visit_expr_closure(&mut self, closure: &'ast ExprClosure) {
+        self.visit_body(closure.asyncness.is_some(), |collector| visit::visit_expr_closure(collector, closure));
+    }
+    
+    fn visit_block(&mut self, block: &'ast Block) {
+        if self.in_async {
+            let mut live: Vec<(String, String, SourceSpan)> = Vec::new();
+            for stmt in &block.stmts {
+                if let Some(dropped) = dropped_name(stmt) {
+                    live.retain(|(guard, _, _)| *guard != dropped);
+                    continue;
+                }
+                let mut awaits = AwaitFinder::default();
+                awaits.visit_stmt(stmt);
+                if let Some(await_line) = awaits.first_line {
+                    for (guard, method, span) in live.drain(..) {
+                        self.guards.push(GuardAcrossAwait { guard, method, function: self.functions.last().cloned(), await_line, span });
+                    }
+                }
+                if let syn::Stmt::Local(local) = stmt {
+                    if let (Pat::Ident(binding), Some(init)) = (&local.pat, &local.init) {
+                        if let Some(method) = guard_method(&init.expr) {
+                            live.push((binding.ident.to_string(), method, SourceSpan::from_span(binding.ident.span())));
+                        }
+                    }
+                }
             }
         }
-        
-        patterns
+        visit::visit_block(self, block);
+    }
+}
+
+/// Method producing the guard an expression evaluates to, looking through `unwrap()`,
+/// `expect()`, and `?`, or `None` when the guard comes from an awaited call
+fn guard_method(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Try(expr) => guard_method(&expr.expr),
+        Expr::MethodCall(call) if call.method == "unwrap" || call.method == "expect" => guard_method(&call.receiver),
+        Expr::MethodCall(call) if call.args.is_empty() && GUARD_METHODS.iter().any(|method| call.method == method) => Some(format!("{}()", call.method)),
+        _ => None,
+    }
+}
+
+/// Name of the variable a `drop(name);` statement drops
+fn dropped_name(stmt: &syn::Stmt) -> Option<String> {
+    let syn::Stmt::Expr(Expr::Call(call), _) = stmt else {
+        return None;
+    };
+    match call.func.as_ref() {
+        Expr::Path(func) if func.path.segments.last().is_some_and(|segment| segment.ident == "drop") => {}
+        _ => return None,
+    }
+    match call.args.first() {
+        Some(Expr::Path(arg)) => arg.path.get_ident().map(|ident| ident.to_string()),
+        _ => None,
+    }
+}
+
+/// AST visitor finding the first `.await` a statement runs directly, skipping closures,
+/// async blocks, and nested functions, which run separately
+#[derive(Default)]
+struct AwaitFinder {
+    first_line: Option<usize>,
+}
+
+impl<'ast> Visit<'ast> for AwaitFinder {
+    fn visit_expr_await(&mut self, await_expr: &'ast ExprAwait) {
+        visit::visit_expr_await(self, await_expr);
+        let line = await_expr.await_token.span.start().line;
+        self.first_line = Some(self.first_line.map_or(line, |first| first.min(line)));
+    }
+    
+    fn visit_expr_closure(&mut self, _: &'ast ExprClosure) {}
+    
+    fn visit_expr_async(&mut self, _: &'ast ExprAsync) {}
+    
+    fn visit_item_fn(&mut self, _: &'ast ItemFn) {}
+}
+
+/// Rules for failing fast with `unwrap()` and `expect()`, not reported outside library and binary code
+pub const FAIL_FAST_RULES: &[&str] = &["unwrap", "excessive-unwrap", "expect", "expect-message"];
+
+/// Directories holding example, integration test, and benchmark targets
+const NON_PRODUCTION_DIRECTORIES: &[&str] = &["examples", "tests", "benches"];
+
+/// Whether a path is a binary entry point or non-production code, where printing to the
+/// console is expected
+pub fn allows_console_output(file_path: &str) -> bool {
+    let file_name = file_path.rsplit(['/', '\\']).next().unwrap_or_default();
+    file_name == "main.rs" || file_path.contains("src/bin/") || is_non_production_path(file_path)
+}
+
+/// Whether a path is an example, integration test, benchmark, or build script rather than
+/// library or binary code, so failing fast with `unwrap()` and `expect()` is accepted
+pub fn is_non_production_path(file_path: &str) -> bool {
+    let components: Vec<&str> = file_path.split(['/', '\\']).collect();
+    let build_script = components.last() == Some(&"build.rs") && !components.contains(&"src");
+    build_script || components.iter().any(|component| NON_PRODUCTION_DIRECTORIES.contains(component))
+}
+
+/// Whether `#[derive(...)]` attributes list a trait, e.g. "Clone"
+fn derives_trait(attrs: &[Attribute], trait_name: &str) -> bool {
+    attrs.iter().filter(|attr| attr.path().is_ident("derive")).any(|attr| {
+        attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+            .is_ok_and(|paths| paths.iter().any(|path| path.segments.last().is_some_and(|segment| segment.ident == trait_name)))
+    })
+}
+
+/// Whether a signature returns `Result`, including aliases such as `anyhow::Result<T>`
+fn returns_result(sig: &Signature) -> bool {
+    match &sig.output {
+        ReturnType::Type(_, ty) => matches!(
+            ty.as_ref(),
+            Type::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "Result")
+        ),
+        ReturnType::Default => false,
+    }
+}
+
+/// Whether an attribute marks a test function, e.g. `#[test]` or `#[tokio::test]`
+fn is_test_attribute(attr: &Attribute) -> bool {
+    attr.path().segments.last().is_some_and(|segment| segment.ident == "test")
+}
+
+/// Whether an attribute is `#[cfg(test)]`
+pub(crate) fn is_cfg_test(attr: &Attribute) -> bool {
+    attr.path().is_ident("cfg") && attr.parse_args::<syn::Ident>().is_ok_and(|ident| ident == "test")
+}
+
+/// Messages that name the failure rather than the invariant expected to hold
+const UNHELPFUL_EXPECT_MESSAGES: &[&str] = &[
+    "bad",
+    "err",
+    "error",
+    "fail",
+    "failed",
+    "failure",
+    "oops",
+    "panic",
+    "should not fail",
+    "should not happen",
+    "should never happen",
+    "something went wrong",
+    "unreachable",
+];
+
+/// `expect()` call outside test code
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpectUsage {
+    /// Range from the method name through its parentheses, e.g. `expect("...")`
+    pub span: SourceSpan,
+    /// Message, when it is a string literal
+    pub message: Option<String>,
+    /// Whether the enclosing function returns `Result`, so the error could be propagated
+    pub in_result_fn: bool,
+}
+
+impl ExpectUsage {
+    /// Whether the message is empty, a single word, or restates the failure instead of
+    /// explaining why the value is expected to be present
+    ///
+    /// Messages that are not string literals, e.g. `format!` calls, are assumed helpful.
+    pub fn has_unhelpful_message(&self) -> bool {
+        self.message.as_deref().is_some_and(|message| {
+            let normalized = message.trim().trim_end_matches(['.', '!']).to_lowercase();
+            normalized.split_whitespace().count() < 2 || UNHELPFUL_EXPECT_MESSAGES.contains(&normalized.as_str())
+        })
     }
 }
 
+/// Console printing macro call
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PrintUsage {
+    /// Range of the macro call, from its name through its closing delimiter
+    pub span: SourceSpan,
+    /// Macro name, e.g. "println"
+    pub name: &'static str,
+    /// `tracing` macro replacing it, e.g. "tracing::info"
+    pub replacement: &'static str,
+}
+
+/// Name endings of configuration types, which are expected to implement `Clone`
+const CONFIG_TYPE_SUFFIXES: &[&str] = &["Config", "Configuration", "Settings", "Options"];
+
+/// Public struct or enum that does not implement a trait it is expected to derive
+#[derive(Clone, Debug, PartialEq)]
+pub struct MissingDerive {
+    /// Type name
+    pub name: String,
+    /// Missing trait, "Debug" or "Clone"
+    pub trait_name: &'static str,
+    /// Range of the type name
+    pub span: SourceSpan,
+    /// Line of the item after its attributes, where a derive can be inserted
+    pub item_line: usize,
+    /// Range of the type's first `#[derive(...)]` attribute, if it has one
+    pub derive: Option<SourceSpan>,
+}
+
 /// Code patterns detected in the analysis
 #[derive(Debug, Default)]
 pub struct CodePatterns {
@@ -160,9 +2003,28 @@ pub struct CodePatterns {
     pub struct_count: usize,
     pub enum_count: usize,
     pub impl_count: usize,
-    pub unwrap_usage: Vec<usize>,
-    pub panic_usage: Vec<usize>,
-    pub todo_usage: Vec<usize>,
+    pub unwrap_usage: Vec<SourceSpan>,
+    pub panic_usage: Vec<SourceSpan>,
+    pub todo_usage: Vec<SourceSpan>,
+    pub sync_io_usage: Vec<SourceSpan>,
+    pub unbounded_channels: Vec<SourceSpan>,
+    pub oversized_channels: Vec<SourceSpan>,
+    pub unbounded_queues: Vec<SourceSpan>,
+    pub unwrap_in_result_fn: Vec<SourceSpan>,
+    pub expect_usage: Vec<ExpectUsage>,
+    pub print_usage: Vec<PrintUsage>,
+    pub types_without_debug: Vec<MissingDerive>,
+    pub config_types_without_clone: Vec<MissingDerive>,
+    pub sequential_await_loops: Vec<SourceSpan>,
+    pub undocumented_unsafe: Vec<UnsafeSite>,
+    pub large_enum_variants: Vec<LargeEnumVariant>,
+    pub large_structs: Vec<LargeStruct>,
+    pub hot_clones: Vec<HotClone>,
+    pub unused_public_items: Vec<PublicItem>,
+    pub untyped_errors: Vec<UntypedErrorFunction>,
+    pub drop_hazards: Vec<DropHazard>,
+    pub guards_across_await: Vec<GuardAcrossAwait>,
+    pub god_types: Vec<GodType>,
 }
 
 /// Analyze Rust code for translation opportunities
@@ -170,11 +2032,15 @@ pub fn analyze_translation_opportunities(content: &str) -> Result<Vec<Translatio
     let analyzer = RustCodeAnalyzer::new(content)?;
     let mut opportunities = Vec::new();
     
-    if !analyzer.has_valid_syntax() {
+    if let Some(span) = analyzer.syntax_error() {
         opportunities.push(TranslationOpportunity {
-            line: 1,
+            line: span.start_line,
+            span,
             description: "Syntax errors detected in Rust code".to_string(),
             suggestion: "Fix syntax errors to enable proper analysis and ADK compliance checking".to_string(),
+            fix: None,
+            rule: "syntax-error",
+            severity: Severity::Error,
         });
         return Ok(opportunities);
     }
@@ -182,45 +2048,144 @@ pub fn analyze_translation_opportunities(content: &str) -> Result<Vec<Translatio
     let patterns = analyzer.analyze_patterns();
     
     // Check for unwrap() usage - translation opportunity to proper error handling
-    for line_num in &patterns.unwrap_usage {
+    for span in &patterns.unwrap_usage {
         opportunities.push(TranslationOpportunity {
-            line: *line_num,
+            line: span.start_line,
+            span: *span,
             description: "Direct unwrap() usage detected".to_string(),
             suggestion: "Replace unwrap() with proper error handling using match, if let, or ? operator for better ADK compliance".to_string(),
+            fix: None,
+            rule: "unwrap",
+            severity: Severity::Warning,
         });
     }
     
+    // Check for expect() usage - propagate errors where possible, otherwise explain the invariant
+    for usage in &patterns.expect_usage {
+        let message = usage.message.as_deref().unwrap_or("...");
+        if usage.in_result_fn {
+            opportunities.push(TranslationOpportunity {
+                line: usage.span.start_line,
+                span: usage.span,
+                description: "expect() in a function returning Result".to_string(),
+                suggestion: format!(
+                    "Propagate the error instead of panicking, keeping the message as context: .context(\"{}\")? with anyhow::Context",
+                    message
+                ),
+                fix: None,
+                rule: "expect",
+                severity: Severity::Warning,
+            });
+        } else if usage.has_unhelpful_message() {
+            opportunities.push(TranslationOpportunity {
+                line: usage.span.start_line,
+                span: usage.span,
+                description: format!("expect() message \"{}\" does not explain the invariant", message),
+                suggestion: "State why the value is always present, e.g. expect(\"config is validated at startup\"), so a panic points at the broken assumption".to_string(),
+                fix: None,
+                rule: "expect-message",
+                severity: Severity::Warning,
+            });
+        }
+    }
+    
     // Check for panic! usage - translation opportunity to Result-based error handling
-    for line_num in &patterns.panic_usage {
+    for span in &patterns.panic_usage {
         opportunities.push(TranslationOpportunity {
-            line: *line_num,
+            line: span.start_line,
+            span: *span,
             description: "Panic usage detected".to_string(),
             suggestion: "Replace panic! with Result-based error handling to follow ADK error handling patterns".to_string(),
+            fix: None,
+            rule: "panic",
+            severity: Severity::Error,
         });
     }
     
     // Check for TODO/unimplemented - translation opportunities
-    for line_num in &patterns.todo_usage {
+    for span in &patterns.todo_usage {
         opportunities.push(TranslationOpportunity {
-            line: *line_num,
+            line: span.start_line,
+            span: *span,
             description: "Incomplete implementation detected".to_string(),
             suggestion: "Complete the implementation following Google ADK patterns and best practices".to_string(),
+            fix: None,
+            rule: "todo",
+            severity: Severity::Warning,
+        });
+    }
+    
+    // Check for console output - translation opportunity to structured logging
+    for usage in &patterns.print_usage {
+        // MCP stdio servers speak the protocol over stdout, so stray prints corrupt it
+        let stdout = !usage.name.starts_with('e');
+        opportunities.push(TranslationOpportunity {
+            line: usage.span.start_line,
+            span: usage.span,
+            description: format!("Console output via {}! detected", usage.name),
+            suggestion: format!(
+                "Use {}! so output is structured, leveled, and visible in ADK traces{}",
+                usage.replacement,
+                if stdout { "; stdout carries the protocol for MCP stdio servers, so stray prints corrupt it" } else { "" }
+            ),
+            fix: None,
+            rule: "print",
+            severity: if stdout { Severity::Warning } else { Severity::Info },
+        });
+    }
+    
+    // Check for public types that cannot be logged or inspected with {:?}
+    for missing in &patterns.types_without_debug {
+        opportunities.push(TranslationOpportunity {
+            line: missing.span.start_line,
+            span: missing.span,
+            description: format!("Public type {} does not implement Debug", missing.name),
+            suggestion: "Derive Debug so the type can be logged and inspected when debugging agents".to_string(),
+            fix: None,
+            rule: "missing-debug",
+            severity: Severity::Info,
         });
     }
     
     // Check for missing async patterns in functions that could benefit
     if patterns.function_count > 0 && patterns.async_functions == 0 {
-        // Look for I/O operations that should be async
-        for (line_num, line) in analyzer.lines().iter().enumerate() {
-            if line.contains("std::fs::") || line.contains("File::") {
-                opportunities.push(TranslationOpportunity {
-                    line: line_num + 1,
-                    description: "Synchronous I/O operation detected".to_string(),
-                    suggestion: "Consider using async I/O operations (tokio::fs) for better performance in ADK applications".to_string(),
-                });
-                break; // Only suggest once per file
-            }
+        // Only suggest once per file, at the first I/O operation that should be async
+        if let Some(span) = patterns.sync_io_usage.first() {
+            opportunities.push(TranslationOpportunity {
+                line: span.start_line,
+                span: *span,
+                description: "Synchronous I/O operation detected".to_string(),
+                suggestion: "Consider using async I/O operations (tokio::fs) for better performance in ADK applications".to_string(),
+                fix: None,
+                rule: "sync-io",
+                severity: Severity::Info,
+            });
+        }
+    }
+    
+    // Check for comments and strings written in a language other than English
+    let structure = analyzer.structure();
+    for text in find_foreign_text(content) {
+        if structure.is_test_line(text.span.start_line) {
+            continue;
         }
+        let suggestion = match text.kind {
+            TextKind::Comment => "Translate the comment to English so every contributor can follow it".to_string(),
+            TextKind::DocComment => "Translate the documentation to English, the language of the rest of the API docs and of the Google ADK references".to_string(),
+            TextKind::StringLiteral => format!(
+                "Translate the text to English, or, if it is meant for {} speakers, load it from a per-locale message catalog (e.g. fluent or rust-i18n) instead of hardcoding it",
+                text.language.eng_name()
+            ),
+        };
+        opportunities.push(TranslationOpportunity {
+            line: text.span.start_line,
+            span: text.span,
+            description: format!("{} in {}: \"{}\"", text.kind.label(), text.language.eng_name(), text.excerpt),
+            suggestion,
+            fix: None,
+            rule: "foreign-language",
+            severity: Severity::Info,
+        });
     }
     
     Ok(opportunities)
@@ -244,6 +2209,8 @@ pub fn analyze_architectural_patterns(content: &str) -> Result<Vec<Architectural
             current_pattern: "Functions without Result return types".to_string(),
             recommended_pattern: "Use Result<T, E> return types for fallible operations".to_string(),
             rationale: "Google ADK emphasizes robust error handling. Functions that can fail should return Result types".to_string(),
+            rule: "result-returns",
+            severity: Severity::Warning,
         });
     }
     
@@ -260,10 +2227,110 @@ pub fn analyze_architectural_patterns(content: &str) -> Result<Vec<Architectural
                 current_pattern: "Synchronous I/O operations".to_string(),
                 recommended_pattern: "Async/await pattern with tokio runtime".to_string(),
                 rationale: "ADK applications benefit from async architecture for better concurrency and performance".to_string(),
+                rule: "async-io",
+                severity: Severity::Info,
             });
         }
     }
     
+    // Check for loops awaiting independent futures one at a time
+    if !patterns.sequential_await_loops.is_empty() {
+        let lines: Vec<String> = patterns.sequential_await_loops.iter().map(|span| span.start_line.to_string()).collect();
+        improvements.push(ArchitecturalImprovement {
+            area: "Concurrency".to_string(),
+            current_pattern: format!("for loops awaiting a future per item, one at a time (line {})", lines.join(", ")),
+            recommended_pattern: "Run the futures concurrently with join_all, FuturesUnordered, or buffer_unordered to bound concurrency:\n\
+                ```rust\n\
+                use futures::stream::{self, StreamExt};\n\n\
+                let results: Vec<_> = stream::iter(items)\n    .map(|item| async move { process(item).await })\n    .buffer_unordered(8)\n    .collect()\n    .await;\n\
+                ```".to_string(),
+            rationale: "Each iteration waits for the previous one to finish, so latency adds up; ADK services handling many requests or tool calls gain throughput from overlapping independent I/O".to_string(),
+            rule: "sequential-await",
+            severity: Severity::Info,
+        });
+    }
+    
+    // Check for enums whose largest variant makes every value large
+    if !patterns.large_enum_variants.is_empty() {
+        let variants: Vec<String> = patterns.large_enum_variants
+            .iter()
+            .map(|large| format!(
+                "{}::{} is about {} bytes while the next largest variant is about {} (line {})",
+                large.enum_name, large.variant, large.size, large.next_size, large.span.start_line
+            ))
+            .collect();
+        improvements.push(ArchitecturalImprovement {
+            area: "Enum Layout".to_string(),
+            current_pattern: variants.join("; "),
+            recommended_pattern: "Box the large variant's payload, e.g. Snapshot(Box<SnapshotData>), so the other variants stay small".to_string(),
+            rationale: "An enum is as large as its largest variant, so every event or message moved through channels, queues, and Vecs pays for the rare large one".to_string(),
+            rule: "large-enum-variant",
+            severity: Severity::Info,
+        });
+    }
+    
+    // Check for structs with dozens of fields
+    if !patterns.large_structs.is_empty() {
+        let structs: Vec<String> = patterns.large_structs
+            .iter()
+            .map(|large| format!("{} has {} fields (line {})", large.name, large.fields, large.span.start_line))
+            .collect();
+        improvements.push(ArchitecturalImprovement {
+            area: "Struct Size".to_string(),
+            current_pattern: structs.join("; "),
+            recommended_pattern: "Group related fields into sub-structs, e.g. model, tool, and runtime settings, each with its own defaults and impl".to_string(),
+            rationale: "Types with dozens of fields are hard to construct, test, and evolve; sub-structs name related settings and let functions take only the part they need".to_string(),
+            rule: "large-struct",
+            severity: Severity::Info,
+        });
+    }
+    
+    // Check for types whose methods span many concerns
+    for god in &patterns.god_types {
+        let fields = god.fields.map(|fields| format!(" and {} fields", fields)).unwrap_or_default();
+        let groups = concern_groups(&god.methods);
+        let groupings = if groups.is_empty() {
+            "Group the methods by the fields they use to find the seams".to_string()
+        } else {
+            format!("Candidate groupings from method names: {}", groups.join("; "))
+        };
+        improvements.push(ArchitecturalImprovement {
+            area: "Separation of Concerns".to_string(),
+            current_pattern: format!(
+                "{} has {} methods across {} impl block{}{} (line {})",
+                god.name,
+                god.methods.len(),
+                god.impl_blocks,
+                if god.impl_blocks == 1 { "" } else { "s" },
+                fields,
+                god.span.start_line
+            ),
+            recommended_pattern: format!(
+                "Split {} into smaller types that each own one concern and the fields it needs, keeping {} as a thin facade that delegates to them. {}",
+                god.name, god.name, groupings
+            ),
+            rationale: "ADK separates agents, tools, sessions, and services into focused components; a type with dozens of methods couples unrelated state, so each change risks the others and every test needs the whole object".to_string(),
+            rule: "god-object",
+            severity: Severity::Warning,
+        });
+    }
+    
+    // Check for large types deep-copied on every loop iteration
+    if !patterns.hot_clones.is_empty() {
+        let clones: Vec<String> = patterns.hot_clones
+            .iter()
+            .map(|clone| format!("{} (line {})", clone.type_name, clone.span.start_line))
+            .collect();
+        improvements.push(ArchitecturalImprovement {
+            area: "Cloning".to_string(),
+            current_pattern: format!("Large types deriving Clone cloned inside loops: {}", clones.join(", ")),
+            recommended_pattern: "Borrow the value instead, clone it once before the loop, or share it as Arc<T> so each clone is a reference count increment".to_string(),
+            rationale: "#[derive(Clone)] deep-copies every field, including heap data, so cloning a large type on each iteration can dominate the loop's cost".to_string(),
+            rule: "large-clone",
+            severity: Severity::Info,
+        });
+    }
+    
     // Check for proper module organization
     if patterns.struct_count > 3 && patterns.impl_count == 0 {
         improvements.push(ArchitecturalImprovement {
@@ -271,32 +2338,254 @@ pub fn analyze_architectural_patterns(content: &str) -> Result<Vec<Architectural
             current_pattern: "Structs without associated implementations".to_string(),
             recommended_pattern: "Group related functionality in impl blocks".to_string(),
             rationale: "ADK promotes clear code organization with methods grouped in impl blocks".to_string(),
+            rule: "impl-blocks",
+            severity: Severity::Info,
         });
     }
     
-    // Check for proper visibility patterns
-    if patterns.public_functions > patterns.function_count / 2 {
+    // Check for public items nothing in the file uses or re-exports
+    if !patterns.unused_public_items.is_empty() {
+        let items: Vec<String> = patterns.unused_public_items
+            .iter()
+            .map(|item| format!("{} {} (line {})", item.kind, item.name, item.span.start_line))
+            .collect();
         improvements.push(ArchitecturalImprovement {
             area: "API Design".to_string(),
-            current_pattern: "Many public functions without clear API boundaries".to_string(),
-            recommended_pattern: "Minimize public API surface, use pub(crate) for internal functions".to_string(),
+            current_pattern: format!("Public items neither used in this file nor re-exported: {}", items.join(", ")),
+            recommended_pattern: "Minimize public API surface: use pub(crate) for items only other modules of the crate need, keeping pub for the crate's intended API".to_string(),
             rationale: "ADK emphasizes clean API design with minimal public interfaces".to_string(),
+            rule: "api-surface",
+            severity: Severity::Info,
+        });
+    }
+    
+    // Check for public functions returning errors callers cannot match on
+    if !patterns.untyped_errors.is_empty() {
+        let functions: Vec<String> = patterns.untyped_errors
+            .iter()
+            .map(|function| format!("{} returns {} (line {})", function.name, function.error_type, function.span.start_line))
+            .collect();
+        let mut variants: Vec<&ErrorVariant> = Vec::new();
+        for variant in patterns.untyped_errors.iter().flat_map(|function| &function.variants) {
+            if !variants.iter().any(|existing| existing.name == variant.name) {
+                variants.push(variant);
+            }
+        }
+        let body: String = if variants.is_empty() {
+            "    // One variant per way these functions can fail\n".to_string()
+        } else {
+            variants.iter().map(|variant| variant.declaration()).collect()
+        };
+        improvements.push(ArchitecturalImprovement {
+            area: "Error Types".to_string(),
+            current_pattern: format!("Public functions returning untyped errors: {}", functions.join(", ")),
+            recommended_pattern: format!(
+                "Return a typed error enum built with thiserror, with a variant per failure found in these functions:\n\
+                ```rust\n\
+                #[derive(Debug, thiserror::Error)]\npub enum Error {{\n{}}}\n\n\
+                pub type Result<T> = std::result::Result<T, Error>;\n\
+                ```",
+                body
+            ),
+            rationale: "Callers of a library cannot match on anyhow::Error or Box<dyn Error> without downcasting; a typed enum documents each failure in the API, and applications can still convert it into anyhow::Error with ?".to_string(),
+            rule: "typed-errors",
+            severity: Severity::Info,
         });
     }
     
     Ok(improvements)
 }
 
+/// Compute the size, cyclomatic complexity, and nesting depth of every function and method
+pub fn analyze_function_metrics(content: &str) -> Result<Vec<FunctionMetrics>> {
+    let analyzer = RustCodeAnalyzer::new(content)?;
+    let mut collector = MetricsCollector::default();
+    if let Some(ast) = analyzer.ast() {
+        collector.visit_file(ast);
+    }
+    Ok(collector.metrics)
+}
+
+/// Refactoring suggestions for functions above the complexity thresholds of `config`
+pub fn complexity_improvements(metrics: &[FunctionMetrics], config: &ReviewConfig) -> Vec<ArchitecturalImprovement> {
+    let mut improvements = Vec::new();
+    for function in metrics {
+        if function.cyclomatic_complexity > config.max_cyclomatic_complexity {
+            improvements.push(ArchitecturalImprovement {
+                area: "Complexity".to_string(),
+                current_pattern: format!(
+                    "{} (line {}) has cyclomatic complexity {}, above the threshold of {}",
+                    function.name, function.span.start_line, function.cyclomatic_complexity, config.max_cyclomatic_complexity
+                ),
+                recommended_pattern: "Extract branches into well-named helper functions, match on an enum instead of chains of flag checks, and return early with ? or guard clauses".to_string(),
+                rationale: "Every independent path through a function needs its own test; ADK agents and tools with fewer paths are easier to test and reason about".to_string(),
+                rule: "cyclomatic-complexity",
+                severity: Severity::Warning,
+            });
+        }
+        if function.nesting_depth > config.max_nesting_depth {
+            improvements.push(ArchitecturalImprovement {
+                area: "Nesting Depth".to_string(),
+                current_pattern: format!(
+                    "{} (line {}) nests control flow {} levels deep, above the threshold of {}",
+                    function.name, function.span.start_line, function.nesting_depth, config.max_nesting_depth
+                ),
+                recommended_pattern: "Flatten the function with guard clauses, let-else, and ?, and move inner loops or match arms into helper functions".to_string(),
+                rationale: "Deeply nested code hides which conditions hold at each point, making agent and tool logic harder to follow and review".to_string(),
+                rule: "nesting-depth",
+                severity: Severity::Warning,
+            });
+        }
+    }
+    improvements
+}
+
+/// Organization suggestions for functions above the length and parameter thresholds of `config`
+pub fn function_size_suggestions(metrics: &[FunctionMetrics], config: &ReviewConfig) -> Vec<OrganizationSuggestion> {
+    let mut suggestions = Vec::new();
+    for function in metrics {
+        if function.lines > config.max_function_lines {
+            suggestions.push(OrganizationSuggestion {
+                suggestion_type: "Function Length".to_string(),
+                description: format!(
+                    "{} (line {}) spans {} lines, above the threshold of {}",
+                    function.name, function.span.start_line, function.lines, config.max_function_lines
+                ),
+                action: "Extract cohesive steps into helper functions named after what they do, keeping the function at one level of abstraction".to_string(),
+                rule: "function-length",
+                severity: Severity::Info,
+            });
+        }
+        if function.parameters > config.max_parameters {
+            suggestions.push(OrganizationSuggestion {
+                suggestion_type: "Parameter Count".to_string(),
+                description: format!(
+                    "{} (line {}) takes {} parameters, above the threshold of {}",
+                    function.name, function.span.start_line, function.parameters, config.max_parameters
+                ),
+                action: "Group related parameters into a struct, with a builder for optional settings, so call sites stay readable and new options do not change the signature".to_string(),
+                rule: "parameter-count",
+                severity: Severity::Info,
+            });
+        }
+    }
+    suggestions
+}
+
+/// AST visitor computing `FunctionMetrics` for every function and method
+#[derive(Default)]
+struct MetricsCollector {
+    metrics: Vec<FunctionMetrics>,
+}
+
+impl MetricsCollector {
+    /// Measure one function body
+    fn record(&mut self, sig: &Signature, block: &Block) {
+        let mut scanner = ComplexityScanner { complexity: 1, ..ComplexityScanner::default() };
+        scanner.visit_block(block);
+        let span = SourceSpan::between(sig.fn_token.span, block.brace_token.span.close());
+        self.metrics.push(FunctionMetrics {
+            name: sig.ident.to_string(),
+            span,
+            cyclomatic_complexity: scanner.complexity,
+            nesting_depth: scanner.max_depth,
+            lines: span.end_line - span.start_line + 1,
+            parameters: sig.inputs.iter().filter(|input| matches!(input, FnArg::Typed(_))).count(),
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for MetricsCollector {
+    fn visit_item_fn(&mut self, func: &'ast ItemFn) {
+        self.record(&func.sig, &func.block);
+        visit::visit_item_fn(self, func);
+    }
+    
+    fn visit_impl_item_fn(&mut self, func: &'ast ImplItemFn) {
+        self.record(&func.sig, &func.block);
+        visit::visit_impl_item_fn(self, func);
+    }
+}
+
+/// AST visitor counting the decision points and nesting of one function body
+///
+/// Items nested in the body, such as inner functions, are measured separately.
+#[derive(Default)]
+struct ComplexityScanner {
+    complexity: usize,
+    depth: usize,
+    max_depth: usize,
+}
+
+impl ComplexityScanner {
+    /// Visit a body one nesting level deeper
+    fn nested(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.depth += 1;
+        self.max_depth = self.max_depth.max(self.depth);
+        visit(self);
+        self.depth -= 1;
+    }
+}
+
+impl<'ast> Visit<'ast> for ComplexityScanner {
+    fn visit_item(&mut self, _item: &'ast Item) {}
+    
+    fn visit_expr_if(&mut self, expr: &'ast ExprIf) {
+        self.complexity += 1;
+        self.visit_expr(&expr.cond);
+        self.nested(|scanner| scanner.visit_block(&expr.then_branch));
+        match expr.else_branch.as_ref().map(|(_, branch)| branch.as_ref()) {
+            Some(branch @ Expr::If(_)) => self.visit_expr(branch),
+            Some(branch) => self.nested(|scanner| scanner.visit_expr(branch)),
+            None => {}
+        }
+    }
+    
+    fn visit_expr_match(&mut self, expr: &'ast ExprMatch) {
+        self.complexity += expr.arms.len().saturating_sub(1) + expr.arms.iter().filter(|arm| arm.guard.is_some()).count();
+        self.visit_expr(&expr.expr);
+        self.nested(|scanner| expr.arms.iter().for_each(|arm| scanner.visit_arm(arm)));
+    }
+    
+    fn visit_expr_for_loop(&mut self, expr: &'ast ExprForLoop) {
+        self.complexity += 1;
+        self.visit_expr(&expr.expr);
+        self.nested(|scanner| scanner.visit_block(&expr.body));
+    }
+    
+    fn visit_expr_while(&mut self, expr: &'ast ExprWhile) {
+        self.complexity += 1;
+        self.visit_expr(&expr.cond);
+        self.nested(|scanner| scanner.visit_block(&expr.body));
+    }
+    
+    fn visit_expr_loop(&mut self, expr: &'ast ExprLoop) {
+        self.complexity += 1;
+        self.nested(|scanner| scanner.visit_block(&expr.body));
+    }
+    
+    fn visit_expr_binary(&mut self, expr: &'ast ExprBinary) {
+        if matches!(expr.op, BinOp::And(_) | BinOp::Or(_)) {
+            self.complexity += 1;
+        }
+        visit::visit_expr_binary(self, expr);
+    }
+}
+
 /// Analyze code for ADK compliance issues
 pub fn analyze_adk_compliance(content: &str) -> Result<Vec<ComplianceIssue>> {
     let analyzer = RustCodeAnalyzer::new(content)?;
     let mut issues = Vec::new();
     
-    if !analyzer.has_valid_syntax() {
+    if let Some(span) = analyzer.syntax_error() {
         issues.push(ComplianceIssue {
             issue_type: "Syntax Error".to_string(),
             description: "Code contains syntax errors that prevent proper analysis".to_string(),
             fix_suggestion: "Fix all syntax errors to ensure code compiles and follows Rust standards".to_string(),
+            locations: vec![span],
+            fixes: Vec::new(),
+            rule: "syntax-error",
+            severity: Severity::Error,
         });
         return Ok(issues);
     }
@@ -309,6 +2598,10 @@ pub fn analyze_adk_compliance(content: &str) -> Result<Vec<ComplianceIssue>> {
             issue_type: "Error Handling Compliance".to_string(),
             description: format!("Found {} panic! usage(s) which violate ADK error handling guidelines", patterns.panic_usage.len()),
             fix_suggestion: "Replace panic! with proper Result-based error handling or graceful error recovery".to_string(),
+            locations: patterns.panic_usage.clone(),
+            fixes: Vec::new(),
+            rule: "panic",
+            severity: Severity::Error,
         });
     }
     
@@ -318,16 +2611,163 @@ pub fn analyze_adk_compliance(content: &str) -> Result<Vec<ComplianceIssue>> {
             issue_type: "Error Handling Compliance".to_string(),
             description: format!("Excessive unwrap() usage ({} instances) may indicate poor error handling", patterns.unwrap_usage.len()),
             fix_suggestion: "Replace unwrap() calls with proper error handling using ?, match, or if let patterns".to_string(),
+            locations: patterns.unwrap_usage.clone(),
+            fixes: Vec::new(),
+            rule: "excessive-unwrap",
+            severity: Severity::Warning,
+        });
+    }
+    
+    // Check that unsafe code documents the invariants it relies on
+    if !patterns.undocumented_unsafe.is_empty() {
+        let sites: Vec<String> = patterns.undocumented_unsafe
+            .iter()
+            .map(|site| match &site.function {
+                Some(function) => format!("{} in {} (line {})", site.kind, function, site.span.start_line),
+                None => format!("{} (line {})", site.kind, site.span.start_line),
+            })
+            .collect();
+        issues.push(ComplianceIssue {
+            issue_type: "Unsafe Code Documentation".to_string(),
+            description: format!("Unsafe code without a // SAFETY: comment: {}", sites.join(", ")),
+            fix_suggestion: "Precede each unsafe block or impl with a // SAFETY: comment stating the invariants that make it sound, such as pointer validity, aliasing, and thread safety, so reviewers can verify them and later changes keep them".to_string(),
+            locations: patterns.undocumented_unsafe.iter().map(|site| site.span).collect(),
+            fixes: Vec::new(),
+            rule: "undocumented-unsafe",
+            severity: Severity::Error,
+        });
+    }
+    
+    // Check for Drop impls that can panic or block on I/O, where failures cannot be reported
+    let (drop_io, drop_panics): (Vec<&DropHazard>, Vec<&DropHazard>) = patterns.drop_hazards.iter().partition(|hazard| hazard.io);
+    let drop_checks = [
+        (
+            drop_panics,
+            "drop-panic",
+            "Drop impls that can panic",
+            "Never panic in drop: a panic while already unwinding aborts the process, and otherwise replaces the original error. Handle each failure in place, ignoring it or logging it with tracing::warn!, and offer an explicit fn close(self) -> Result<()> for callers that need to see cleanup errors",
+        ),
+        (
+            drop_io,
+            "drop-io",
+            "Drop impls doing I/O",
+            "Drop cannot return errors or be async, so I/O there fails silently and blocks the thread, stalling async runtimes. Move the cleanup into an explicit close, flush, or shutdown method returning Result (async when the I/O is), call it on the normal path, and keep Drop as a best-effort fallback that logs failures",
+        ),
+    ];
+    for (hazards, rule, summary, fix_suggestion) in drop_checks {
+        if hazards.is_empty() {
+            continue;
+        }
+        let sites: Vec<String> = hazards
+            .iter()
+            .map(|hazard| format!("{} in Drop for {} (line {})", hazard.kind, hazard.type_name, hazard.span.start_line))
+            .collect();
+        issues.push(ComplianceIssue {
+            issue_type: "Drop Safety".to_string(),
+            description: format!("{}: {}", summary, sites.join(", ")),
+            fix_suggestion: fix_suggestion.to_string(),
+            locations: hazards.iter().map(|hazard| hazard.span).collect(),
+            fixes: Vec::new(),
+            rule,
+            severity: Severity::Error,
+        });
+    }
+    
+    // Check for lock guards and RefCell borrows held while the task is suspended
+    if !patterns.guards_across_await.is_empty() {
+        let guards: Vec<String> = patterns.guards_across_await
+            .iter()
+            .map(|guard| {
+                let function = guard.function.as_ref().map(|function| format!(" in {}", function)).unwrap_or_default();
+                format!("`{}` from {}{} (line {}, held across the .await on line {})", guard.guard, guard.method, function, guard.span.start_line, guard.await_line)
+            })
+            .collect();
+        issues.push(ComplianceIssue {
+            issue_type: "Async Safety".to_string(),
+            description: format!("Lock guards or RefCell borrows held across .await: {}", guards.join(", ")),
+            fix_suggestion: "A std::sync::MutexGuard held across .await makes the future !Send, so it cannot be spawned on a multi-threaded runtime, and blocks every task waiting for the lock, deadlocking when they share a thread; a RefCell borrow panics if another task borrows it meanwhile. Narrow the scope so the guard is dropped before awaiting, e.g. let value = { let state = self.state.lock().unwrap(); state.value.clone() }; or call drop(guard). If the lock must be held across the await, use tokio::sync::Mutex, whose guard is Send and yields to other tasks while waiting".to_string(),
+            locations: patterns.guards_across_await.iter().map(|guard| guard.span).collect(),
+            fixes: Vec::new(),
+            rule: "guard-across-await",
+            severity: Severity::Warning,
+        });
+    }
+    
+    // Check for channels and queues that grow without limit - resource safety issue
+    if !patterns.unbounded_channels.is_empty() {
+        issues.push(ComplianceIssue {
+            issue_type: "Resource Safety".to_string(),
+            description: format!("Found {} unbounded channel(s), which buffer without limit when receivers fall behind", patterns.unbounded_channels.len()),
+            fix_suggestion: "Use a bounded channel such as tokio::sync::mpsc::channel(capacity), so senders wait for capacity (backpressure) instead of growing memory".to_string(),
+            locations: patterns.unbounded_channels.clone(),
+            fixes: Vec::new(),
+            rule: "unbounded-channel",
+            severity: Severity::Warning,
+        });
+    }
+    if !patterns.oversized_channels.is_empty() {
+        issues.push(ComplianceIssue {
+            issue_type: "Resource Safety".to_string(),
+            description: format!(
+                "Found {} channel(s) with a capacity of {} or more, which is effectively unbounded",
+                patterns.oversized_channels.len(),
+                OVERSIZED_CHANNEL_CAPACITY
+            ),
+            fix_suggestion: "Size the capacity to the bursts the receivers must absorb; broadcast channels keep that many messages for lagging receivers".to_string(),
+            locations: patterns.oversized_channels.clone(),
+            fixes: Vec::new(),
+            rule: "oversized-channel",
+            severity: Severity::Warning,
+        });
+    }
+    if !patterns.unbounded_queues.is_empty() {
+        issues.push(ComplianceIssue {
+            issue_type: "Resource Safety".to_string(),
+            description: format!("Found {} collection(s) grown in a long-lived loop without ever being drained", patterns.unbounded_queues.len()),
+            fix_suggestion: "Cap the queue, e.g. a VecDeque that drops or rejects items past a maximum length, or hand items to a bounded channel".to_string(),
+            locations: patterns.unbounded_queues.clone(),
+            fixes: Vec::new(),
+            rule: "unbounded-queue",
+            severity: Severity::Info,
+        });
+    }
+    
+    // Check that public types can be logged, and configuration shared, as ADK services expect
+    let hygiene_checks = [
+        (&patterns.types_without_debug, "missing-debug", "Public types without Debug", "Derive Debug so the types can be logged with {:?} and inspected when debugging agents"),
+        (&patterns.config_types_without_clone, "missing-clone", "Config-like public types without Clone", "Derive Clone so configuration can be handed to each agent, task, and tool that needs it"),
+    ];
+    for (missing, rule, summary, fix_suggestion) in hygiene_checks {
+        if missing.is_empty() {
+            continue;
+        }
+        let types: Vec<String> = missing.iter().map(|missing| format!("{} (line {})", missing.name, missing.span.start_line)).collect();
+        issues.push(ComplianceIssue {
+            issue_type: "Service Hygiene".to_string(),
+            description: format!("{}: {}", summary, types.join(", ")),
+            fix_suggestion: fix_suggestion.to_string(),
+            locations: missing.iter().map(|missing| missing.span).collect(),
+            fixes: Vec::new(),
+            rule,
+            severity: Severity::Info,
         });
     }
     
     // Check for missing documentation on public items
     let public_items_without_docs = check_missing_documentation(&analyzer);
     if !public_items_without_docs.is_empty() {
+        let items: Vec<String> = public_items_without_docs
+            .iter()
+            .map(|(item, span)| format!("{} (line {})", item, span.start_line))
+            .collect();
         issues.push(ComplianceIssue {
             issue_type: "Documentation Compliance".to_string(),
-            description: "Public items missing documentation comments".to_string(),
+            description: format!("Public items missing documentation comments: {}", items.join(", ")),
             fix_suggestion: "Add /// documentation comments to all public functions, structs, and modules following ADK documentation standards".to_string(),
+            locations: public_items_without_docs.iter().map(|(_, span)| *span).collect(),
+            fixes: Vec::new(),
+            rule: "missing-docs",
+            severity: Severity::Info,
         });
     }
     
@@ -337,35 +2777,63 @@ pub fn analyze_adk_compliance(content: &str) -> Result<Vec<ComplianceIssue>> {
             issue_type: "Implementation Completeness".to_string(),
             description: format!("Found {} incomplete implementation(s) (todo!/unimplemented!)", patterns.todo_usage.len()),
             fix_suggestion: "Complete all implementations or use proper feature flags for incomplete functionality".to_string(),
+            locations: patterns.todo_usage.clone(),
+            fixes: Vec::new(),
+            rule: "todo",
+            severity: Severity::Warning,
         });
     }
     
     Ok(issues)
 }
 
-/// Check for missing documentation on public items
-fn check_missing_documentation(analyzer: &RustCodeAnalyzer) -> Vec<String> {
-    let mut missing_docs = Vec::new();
-    
-    // Check public functions
-    for func in analyzer.extract_functions() {
-        if matches!(func.vis, Visibility::Public(_)) {
-            let func_name = func.sig.ident.to_string();
-            // Simple heuristic: check if there's a doc comment before the function
-            // In a real implementation, we'd need more sophisticated AST analysis
-            missing_docs.push(format!("Function: {}", func_name));
+/// Check for missing documentation on public items, with the span of each item's name
+///
+/// Public functions, structs, enums, and impl methods count as documented when they carry
+/// a `#[doc]` attribute, which `///` and `/** */` comments desugar to.
+fn check_missing_documentation(analyzer: &RustCodeAnalyzer) -> Vec<(String, SourceSpan)> {
+    let mut collector = DocumentationCollector::default();
+    if let Some(ast) = analyzer.ast() {
+        collector.visit_file(ast);
+    }
+    collector.missing_docs
+}
+
+/// AST visitor collecting public items without documentation
+#[derive(Default)]
+struct DocumentationCollector {
+    missing_docs: Vec<(String, SourceSpan)>,
+}
+
+impl DocumentationCollector {
+    /// Record a public item unless it is documented
+    fn check(&mut self, kind: &str, vis: &Visibility, attrs: &[Attribute], ident: &syn::Ident) {
+        if matches!(vis, Visibility::Public(_)) && !attrs.iter().any(|attr| attr.path().is_ident("doc")) {
+            self.missing_docs.push((format!("{}: {}", kind, ident), SourceSpan::from_span(ident.span())));
         }
     }
+}
+
+impl<'ast> Visit<'ast> for DocumentationCollector {
+    fn visit_item_fn(&mut self, func: &'ast ItemFn) {
+        self.check("Function", &func.vis, &func.attrs, &func.sig.ident);
+        visit::visit_item_fn(self, func);
+    }
     
-    // Check public structs
-    for struct_item in analyzer.extract_structs() {
-        if matches!(struct_item.vis, Visibility::Public(_)) {
-            let struct_name = struct_item.ident.to_string();
-            missing_docs.push(format!("Struct: {}", struct_name));
-        }
+    fn visit_item_struct(&mut self, item: &'ast ItemStruct) {
+        self.check("Struct", &item.vis, &item.attrs, &item.ident);
+        visit::visit_item_struct(self, item);
+    }
+    
+    fn visit_item_enum(&mut self, item: &'ast ItemEnum) {
+        self.check("Enum", &item.vis, &item.attrs, &item.ident);
+        visit::visit_item_enum(self, item);
     }
     
-    missing_docs
+    fn visit_impl_item_fn(&mut self, func: &'ast ImplItemFn) {
+        self.check("Method", &func.vis, &func.attrs, &func.sig.ident);
+        visit::visit_impl_item_fn(self, func);
+    }
 }
 
 /// Analyze file organization and structure
@@ -382,6 +2850,8 @@ pub fn analyze_file_organization(file_path: &str, content: &str) -> Result<Vec<O
             suggestion_type: "File Size".to_string(),
             description: format!("File is quite large ({} lines) which may impact maintainability", line_count),
             action: "Consider splitting into smaller, focused modules following ADK organization patterns".to_string(),
+            rule: "file-size",
+            severity: Severity::Warning,
         });
     }
     
@@ -391,6 +2861,35 @@ pub fn analyze_file_organization(file_path: &str, content: &str) -> Result<Vec<O
             suggestion_type: "Module Organization".to_string(),
             description: "Many types defined in a single file".to_string(),
             action: "Consider organizing related types into separate modules with a mod.rs file".to_string(),
+            rule: "module-organization",
+            severity: Severity::Info,
+        });
+    }
+    
+    // Check for modules declaring many unrelated items
+    let type_count = patterns.struct_count + patterns.enum_count + analyzer.ast().map_or(0, |ast| {
+        ast.items.iter().filter(|item| matches!(item, Item::Trait(_))).count()
+    });
+    if patterns.function_count + type_count > GOD_MODULE_ITEMS {
+        let names: Vec<String> = analyzer.extract_functions().iter().map(|func| func.sig.ident.to_string()).collect();
+        let groups = concern_groups(&names);
+        let groupings = if groups.is_empty() {
+            String::new()
+        } else {
+            format!(". Candidate submodules from function names: {}", groups.join("; "))
+        };
+        suggestions.push(OrganizationSuggestion {
+            suggestion_type: "God Module".to_string(),
+            description: format!(
+                "The module declares {} top-level items ({} functions, {} types), more than the {} of a focused module",
+                patterns.function_count + type_count,
+                patterns.function_count,
+                type_count,
+                GOD_MODULE_ITEMS
+            ),
+            action: format!("Split it into submodules by concern, re-exporting the public API from this module{}", groupings),
+            rule: "god-module",
+            severity: Severity::Info,
         });
     }
     
@@ -400,6 +2899,8 @@ pub fn analyze_file_organization(file_path: &str, content: &str) -> Result<Vec<O
             suggestion_type: "Code Organization".to_string(),
             description: "Many standalone functions without clear grouping".to_string(),
             action: "Group related functions into structs with impl blocks or separate modules".to_string(),
+            rule: "function-grouping",
+            severity: Severity::Info,
         });
     }
     
@@ -414,6 +2915,8 @@ pub fn analyze_file_organization(file_path: &str, content: &str) -> Result<Vec<O
             suggestion_type: "Naming Convention".to_string(),
             description: "File name uses underscores".to_string(),
             action: "Consider using kebab-case for file names following Rust conventions".to_string(),
+            rule: "file-naming",
+            severity: Severity::Info,
         });
     }
     
@@ -429,6 +2932,8 @@ pub fn analyze_file_organization(file_path: &str, content: &str) -> Result<Vec<O
             suggestion_type: "Import Organization".to_string(),
             description: "Many import statements may indicate complex dependencies".to_string(),
             action: "Group imports by source (std, external crates, local modules) and consider reducing dependencies".to_string(),
+            rule: "import-count",
+            severity: Severity::Info,
         });
     }
     
@@ -0,0 +1,121 @@
+//! Unified-diff autofixes for mechanical review findings
+//!
+//! Each fix rewrites or inserts a single line, so the patch applies with `git apply`
+//! on the reviewed file as long as the surrounding lines are unchanged.
+
+use super::analyzer::RustCodeAnalyzer;
+use super::{AutoFix, SourceSpan};
+
+/// Unchanged lines shown around each change
+const CONTEXT_LINES: usize = 3;
+
+/// Autofixes for a file's findings, with the rule of the finding each one resolves
+///
+/// Covered findings are `unwrap()` calls directly in functions returning `Result`,
+/// `println!`/`eprintln!` calls, public types without `Debug`, and config-like types
+/// without `Clone`.
+pub fn suggest_fixes(file_path: &str, content: &str) -> Vec<(&'static str, AutoFix)> {
+    let Ok(analyzer) = RustCodeAnalyzer::new(content) else {
+        return Vec::new();
+    };
+    if !analyzer.has_valid_syntax() {
+        return Vec::new();
+    }
+    let patterns = analyzer.analyze_patterns();
+    let lines: Vec<&str> = content.lines().collect();
+    let mut fixes = Vec::new();
+    
+    for span in &patterns.unwrap_in_result_fn {
+        let replaced = single_line(span).and_then(|index| {
+            let line = lines.get(index)?;
+            let start = byte_index(line, span.start_column - 1)?;
+            let end = byte_index(line, span.end_column)?;
+            let receiver = line[..start].trim_end().strip_suffix('.')?;
+            Some(format!("{}?{}", receiver, &line[end..]))
+        });
+        if let Some(replaced) = replaced {
+            fixes.push(("unwrap", AutoFix {
+                description: "Propagate the error with ? instead of unwrap()".to_string(),
+                span: *span,
+                patch: line_patch(file_path, &lines, span.start_line - 1, &[replaced]),
+            }));
+        }
+    }
+    
+    for usage in &patterns.print_usage {
+        let replaced = lines.get(usage.span.start_line - 1).and_then(|line| {
+            let start = byte_index(line, usage.span.start_column - 1)?;
+            let rest = line[start..].strip_prefix(usage.name)?;
+            Some(format!("{}{}{}", &line[..start], usage.replacement, rest))
+        });
+        if let Some(replaced) = replaced {
+            fixes.push(("print", AutoFix {
+                description: format!("Replace {}! with {}!", usage.name, usage.replacement),
+                span: usage.span,
+                patch: line_patch(file_path, &lines, usage.span.start_line - 1, &[replaced]),
+            }));
+        }
+    }
+    
+    let missing_derives = patterns.types_without_debug.iter().map(|missing| ("missing-debug", missing))
+        .chain(patterns.config_types_without_clone.iter().map(|missing| ("missing-clone", missing)));
+    for (rule, missing) in missing_derives {
+        let trait_name = missing.trait_name;
+        let fix = match missing.derive {
+            // Extend the existing derive list
+            Some(derive) => single_line(&derive).and_then(|index| {
+                let line = lines.get(index)?;
+                let (before, after) = line.split_once("derive(")?;
+                let separator = if after.trim_start().starts_with(')') { "" } else { ", " };
+                Some((index, vec![format!("{}derive({}{}{}", before, trait_name, separator, after)]))
+            }),
+            None => lines.get(missing.item_line - 1).map(|line| {
+                let indent = &line[..line.len() - line.trim_start().len()];
+                (missing.item_line - 1, vec![format!("{}#[derive({})]", indent, trait_name), line.to_string()])
+            }),
+        };
+        if let Some((index, replacement)) = fix {
+            fixes.push((rule, AutoFix {
+                description: format!("Derive {} for {}", trait_name, missing.name),
+                span: missing.span,
+                patch: line_patch(file_path, &lines, index, &replacement),
+            }));
+        }
+    }
+    
+    fixes
+}
+
+/// Zero-based line index of a span on a single line
+fn single_line(span: &SourceSpan) -> Option<usize> {
+    (span.start_line == span.end_line).then(|| span.start_line - 1)
+}
+
+/// Byte offset of a zero-based character column, allowing the end of the line
+fn byte_index(line: &str, column: usize) -> Option<usize> {
+    line.char_indices().map(|(index, _)| index).chain(std::iter::once(line.len())).nth(column)
+}
+
+/// Unified diff replacing line `index` (zero-based) of `lines` with `replacement`
+fn line_patch(file_path: &str, lines: &[&str], index: usize, replacement: &[String]) -> String {
+    let start = index.saturating_sub(CONTEXT_LINES);
+    let end = (index + 1 + CONTEXT_LINES).min(lines.len());
+    let old_count = end - start;
+    let new_count = old_count - 1 + replacement.len();
+    
+    let mut patch = format!(
+        "--- a/{}\n+++ b/{}\n@@ -{},{} +{},{} @@\n",
+        file_path, file_path, start + 1, old_count, start + 1, new_count
+    );
+    for (offset, line) in lines[start..end].iter().enumerate() {
+        if start + offset == index {
+            patch.push_str(&format!("-{}\n", line));
+            for new_line in replacement {
+                patch.push_str(&format!("+{}\n", new_line));
+            }
+        } else {
+            patch.push_str(&format!(" {}\n", line));
+        }
+    }
+    patch
+}
@@ -0,0 +1,377 @@
+//! Dependency audit against RustSec advisories and yanked crates.io releases
+//!
+//! Locked versions from `Cargo.lock` are checked against a curated snapshot of RustSec
+//! advisories for crates common in ADK agents, or against a local checkout of the
+//! RustSec advisory database when `ADK_ADVISORY_DB` is set. Yanked releases are looked
+//! up in the crates.io sparse index on request, since that needs network access.
+
+use anyhow::{anyhow, Result};
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::Duration;
+use toml::{Table, Value};
+use tracing::{info, warn};
+
+/// Environment variable naming a local checkout of the RustSec advisory database
+pub const ADVISORY_DB_PATH_ENV: &str = "ADK_ADVISORY_DB";
+
+/// Most locked packages looked up in the crates.io index per review
+pub const MAX_YANKED_CHECKS: usize = 100;
+
+/// Base URL of the crates.io sparse index
+const CRATES_IO_INDEX: &str = "https://index.crates.io";
+
+/// Timeout for a single index request
+const INDEX_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Kind of advisory, following RustSec's `informational` field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdvisoryKind {
+    /// Security vulnerability
+    Vulnerability,
+    /// Crate is no longer maintained
+    Unmaintained,
+    /// Crate exposes unsound APIs
+    Unsound,
+    /// Other informational notice
+    Notice,
+}
+
+impl AdvisoryKind {
+    /// Kind for RustSec's `informational` value, a vulnerability when absent
+    fn from_informational(informational: Option<&str>) -> Self {
+        match informational {
+            None => AdvisoryKind::Vulnerability,
+            Some("unmaintained") => AdvisoryKind::Unmaintained,
+            Some("unsound") => AdvisoryKind::Unsound,
+            Some(_) => AdvisoryKind::Notice,
+        }
+    }
+    
+    /// Heading used for the kind in review output
+    pub fn label(&self) -> &'static str {
+        match self {
+            AdvisoryKind::Vulnerability => "Vulnerability",
+            AdvisoryKind::Unmaintained => "Unmaintained",
+            AdvisoryKind::Unsound => "Unsound",
+            AdvisoryKind::Notice => "Notice",
+        }
+    }
+}
+
+/// RustSec advisory for one crate
+#[derive(Debug, Clone)]
+pub struct Advisory {
+    /// Advisory id, e.g. `RUSTSEC-2021-0124`
+    pub id: String,
+    /// Affected crate
+    pub package: String,
+    /// One-line summary
+    pub title: String,
+    /// Vulnerability or informational kind
+    pub kind: AdvisoryKind,
+    /// Version requirements of patched releases
+    pub patched: Vec<VersionReq>,
+    /// Version requirements of releases never affected
+    pub unaffected: Vec<VersionReq>,
+}
+
+impl Advisory {
+    /// Whether a version is affected, i.e. neither patched nor unaffected
+    pub fn affects(&self, version: &Version) -> bool {
+        !self.patched.iter().chain(&self.unaffected).any(|requirement| requirement.matches(version))
+    }
+    
+    /// Lowest patched release newer than a version, if any
+    pub fn first_patched_after(&self, version: &Version) -> Option<Version> {
+        self.patched
+            .iter()
+            .flat_map(|requirement| &requirement.comparators)
+            .filter(|comparator| matches!(comparator.op, semver::Op::GreaterEq | semver::Op::Caret | semver::Op::Tilde))
+            .map(|comparator| Version::new(comparator.major, comparator.minor.unwrap_or(0), comparator.patch.unwrap_or(0)))
+            .filter(|patched| patched > version)
+            .min()
+    }
+}
+
+/// Package pinned in a `Cargo.lock`
+#[derive(Debug, Clone, PartialEq)]
+pub struct LockedPackage {
+    /// Crate name
+    pub name: String,
+    /// Locked version
+    pub version: Version,
+    /// Whether the package comes from crates.io
+    pub from_crates_io: bool,
+}
+
+/// Finding about one locked dependency
+#[derive(Debug, Clone, PartialEq)]
+pub struct DependencyFinding {
+    /// Crate name
+    pub package: String,
+    /// Locked version
+    pub version: String,
+    /// "Vulnerability", "Unmaintained", "Unsound", "Notice", or "Yanked"
+    pub kind: String,
+    /// RustSec advisory id, for advisory findings
+    pub advisory_id: Option<String>,
+    /// What is wrong with the version
+    pub title: String,
+    /// How to resolve the finding
+    pub suggestion: String,
+}
+
+/// Curated snapshot of RustSec advisories for crates common in ADK agents
+pub fn bundled_advisories() -> Vec<Advisory> {
+    let advisory = |id: &str, package: &str, title: &str, informational: Option<&str>, patched: &[&str]| Advisory {
+        id: id.to_string(),
+        package: package.to_string(),
+        title: title.to_string(),
+        kind: AdvisoryKind::from_informational(informational),
+        patched: patched.iter().filter_map(|requirement| VersionReq::parse(requirement).ok()).collect(),
+        unaffected: Vec::new(),
+    };
+    
+    vec![
+        advisory("RUSTSEC-2021-0124", "tokio", "Data race when sending and receiving after closing a `oneshot` channel", None, &[">= 1.8.4, < 1.9.0", ">= 1.13.1"]),
+        advisory("RUSTSEC-2023-0005", "tokio", "`tokio::io::ReadHalf<T>::unsplit` is unsound", Some("unsound"), &[">= 1.18.4, < 1.19.0", ">= 1.20.3, < 1.21.0", ">= 1.23.1"]),
+        advisory("RUSTSEC-2024-0003", "h2", "Resource exhaustion vulnerability in h2 may lead to Denial of Service", None, &[">= 0.3.24, < 0.4.0", ">= 0.4.2"]),
+        advisory("RUSTSEC-2024-0336", "rustls", "`rustls::ConnectionCommon::complete_io` could fall into an infinite loop based on network input", None, &[">= 0.21.11, < 0.22.0", ">= 0.22.4, < 0.23.0", ">= 0.23.5"]),
+        advisory("RUSTSEC-2025-0009", "ring", "Some AES functions may panic when overflow checking is enabled", None, &[">= 0.17.12"]),
+        advisory("RUSTSEC-2024-0421", "idna", "`idna` accepts Punycode labels that do not produce any non-ASCII when decoded", None, &[">= 1.0.0"]),
+        advisory("RUSTSEC-2022-0013", "regex", "Regexes with large repetitions on empty sub-expressions take a very long time to parse", None, &[">= 1.5.5"]),
+        advisory("RUSTSEC-2020-0071", "time", "Potential segfault in the time crate", None, &[">= 0.2.23"]),
+        advisory("RUSTSEC-2023-0071", "rsa", "Marvin Attack: potential key recovery through timing sidechannels", None, &[]),
+        advisory("RUSTSEC-2021-0145", "atty", "Potential unaligned read", Some("unsound"), &[]),
+        advisory("RUSTSEC-2021-0139", "ansi_term", "ansi_term is unmaintained", Some("unmaintained"), &[]),
+        advisory("RUSTSEC-2024-0320", "yaml-rust", "yaml-rust is unmaintained", Some("unmaintained"), &[]),
+        advisory("RUSTSEC-2024-0384", "instant", "`instant` is unmaintained", Some("unmaintained"), &[]),
+        advisory("RUSTSEC-2024-0370", "proc-macro-error", "proc-macro-error is unmaintained", Some("unmaintained"), &[]),
+        advisory("RUSTSEC-2024-0436", "paste", "paste is no longer maintained", Some("unmaintained"), &[]),
+    ]
+}
+
+/// Advisories used by project reviews: the RustSec checkout at `ADK_ADVISORY_DB` when it
+/// loads, and the bundled snapshot otherwise
+///
+/// The checkout is read once per process.
+pub fn configured_advisories() -> &'static [Advisory] {
+    static ADVISORIES: OnceLock<Vec<Advisory>> = OnceLock::new();
+    ADVISORIES.get_or_init(|| {
+        let path = std::env::var(ADVISORY_DB_PATH_ENV).ok().filter(|path| !path.trim().is_empty());
+        match path.map(|path| load_advisory_db(Path::new(path.trim()))) {
+            Some(Ok(advisories)) => {
+                info!("Loaded {} RustSec advisories", advisories.len());
+                advisories
+            }
+            Some(Err(e)) => {
+                warn!("Failed to load the RustSec advisory database, using the bundled advisories: {}", e);
+                bundled_advisories()
+            }
+            None => bundled_advisories(),
+        }
+    })
+}
+
+/// Load the advisories of a RustSec advisory database checkout (`crates/<name>/*.md`)
+///
+/// Withdrawn advisories and files that do not parse are skipped.
+pub fn load_advisory_db(root: &Path) -> Result<Vec<Advisory>> {
+    let crates = root.join("crates");
+    if !crates.is_dir() {
+        return Err(anyhow!("{} has no crates directory", root.display()));
+    }
+    
+    let mut advisories = Vec::new();
+    for crate_dir in std::fs::read_dir(&crates)? {
+        let crate_dir = crate_dir?.path();
+        if !crate_dir.is_dir() {
+            continue;
+        }
+        for file in std::fs::read_dir(&crate_dir)? {
+            let file = file?.path();
+            if file.extension().is_some_and(|extension| extension == "md") {
+                advisories.extend(parse_advisory(&std::fs::read_to_string(&file)?));
+            }
+        }
+    }
+    advisories.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(advisories)
+}
+
+/// Parse a RustSec advisory file: a TOML front matter block followed by a Markdown body
+/// whose first heading is the title
+pub fn parse_advisory(content: &str) -> Option<Advisory> {
+    let front_matter = content.trim_start().strip_prefix("```toml")?;
+    let (front_matter, body) = front_matter.split_once("```")?;
+    let table: Table = front_matter.parse().ok()?;
+    let advisory = table.get("advisory")?.as_table()?;
+    if advisory.contains_key("withdrawn") {
+        return None;
+    }
+    
+    let text = |key: &str| advisory.get(key).and_then(Value::as_str);
+    let requirements = |key: &str| -> Vec<VersionReq> {
+        table
+            .get("versions")
+            .and_then(|versions| versions.get(key))
+            .and_then(Value::as_array)
+            .map(|list| list.iter().filter_map(Value::as_str).filter_map(|requirement| VersionReq::parse(requirement).ok()).collect())
+            .unwrap_or_default()
+    };
+    let title = text("title")
+        .map(str::to_string)
+        .or_else(|| body.lines().find_map(|line| line.strip_prefix("# ")).map(|title| title.trim().to_string()))?;
+    
+    Some(Advisory {
+        id: text("id")?.to_string(),
+        package: text("package")?.to_string(),
+        title,
+        kind: AdvisoryKind::from_informational(text("informational")),
+        patched: requirements("patched"),
+        unaffected: requirements("unaffected"),
+    })
+}
+
+/// Packages pinned in a `Cargo.lock`, skipping entries with unparseable versions
+pub fn parse_lockfile(content: &str) -> Result<Vec<LockedPackage>> {
+    let lockfile: Table = content.parse().map_err(|e| anyhow!("Cargo.lock could not be parsed: {}", e))?;
+    let packages = lockfile.get("package").and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+    
+    Ok(packages
+        .iter()
+        .filter_map(|package| {
+            let name = package.get("name")?.as_str()?;
+            let version = Version::parse(package.get("version")?.as_str()?).ok()?;
+            let from_crates_io = package
+                .get("source")
+                .and_then(Value::as_str)
+                .is_some_and(|source| source.contains("github.com/rust-lang/crates.io-index") || source.contains("index.crates.io"));
+            Some(LockedPackage { name: name.to_string(), version, from_crates_io })
+        })
+        .collect())
+}
+
+/// Findings for locked packages affected by advisories, vulnerabilities first
+pub fn audit_packages(packages: &[LockedPackage], advisories: &[Advisory]) -> Vec<DependencyFinding> {
+    let mut findings: Vec<(AdvisoryKind, DependencyFinding)> = Vec::new();
+    for package in packages {
+        for advisory in advisories.iter().filter(|advisory| advisory.package == package.name && advisory.affects(&package.version)) {
+            let suggestion = match (advisory.first_patched_after(&package.version), advisory.kind) {
+                (Some(patched), _) => format!("Upgrade {} to {} or later", package.name, patched),
+                (None, AdvisoryKind::Unmaintained) => format!("Replace {} with a maintained alternative", package.name),
+                (None, _) => format!("No patched release of {} exists; replace it or isolate its use", package.name),
+            };
+            findings.push((advisory.kind, DependencyFinding {
+                package: package.name.clone(),
+                version: package.version.to_string(),
+                kind: advisory.kind.label().to_string(),
+                advisory_id: Some(advisory.id.clone()),
+                title: advisory.title.clone(),
+                suggestion,
+            }));
+        }
+    }
+    
+    findings.sort_by(|(a_kind, a), (b_kind, b)| {
+        (*a_kind != AdvisoryKind::Vulnerability, &a.package, &a.advisory_id).cmp(&(*b_kind != AdvisoryKind::Vulnerability, &b.package, &b.advisory_id))
+    });
+    findings.dedup_by(|a, b| a.1 == b.1);
+    findings.into_iter().map(|(_, finding)| finding).collect()
+}
+
+/// Path of a crate's file in the crates.io sparse index
+///
+/// `None` for names crates.io cannot hold, i.e. empty or non-ASCII ones from a corrupt lockfile.
+pub fn index_path(name: &str) -> Option<String> {
+    if name.is_empty() || !name.is_ascii() {
+        return None;
+    }
+    let name = name.to_ascii_lowercase();
+    Some(match name.len() {
+        1 => format!("1/{}", name),
+        2 => format!("2/{}", name),
+        3 => format!("3/{}/{}", &name[..1], name),
+        _ => format!("{}/{}/{}", &name[..2], &name[2..4], name),
+    })
+}
+
+/// Finding for a locked version the sparse index lists as yanked, if it is
+///
+/// The suggested upgrade is the newest unyanked semver-compatible release, or the newest
+/// unyanked release when no compatible one exists.
+pub fn yanked_finding(package: &LockedPackage, index_body: &str) -> Option<DependencyFinding> {
+    #[derive(Deserialize)]
+    struct IndexEntry {
+        vers: String,
+        #[serde(default)]
+        yanked: bool,
+    }
+    
+    let entries: Vec<(Version, bool)> = index_body
+        .lines()
+        .filter_map(|line| serde_json::from_str::<IndexEntry>(line).ok())
+        .filter_map(|entry| Version::parse(&entry.vers).ok().map(|version| (version, entry.yanked)))
+        .collect();
+    if !entries.iter().any(|(version, yanked)| *yanked && *version == package.version) {
+        return None;
+    }
+    
+    let compatible = VersionReq::parse(&format!("^{}", package.version)).ok();
+    let available = || entries.iter().filter(|(version, yanked)| !yanked && version.pre.is_empty()).map(|(version, _)| version);
+    let replacement = available()
+        .filter(|version| compatible.as_ref().is_some_and(|requirement| requirement.matches(version)))
+        .max()
+        .or_else(|| available().max());
+    
+    Some(DependencyFinding {
+        package: package.name.clone(),
+        version: package.version.to_string(),
+        kind: "Yanked".to_string(),
+        advisory_id: None,
+        title: format!("{} {} was yanked from crates.io", package.name, package.version),
+        suggestion: match replacement {
+            Some(version) => format!("Update {} to {}", package.name, version),
+            None => format!("Every release of {} is yanked; replace it", package.name),
+        },
+    })
+}
+
+/// Look up locked crates.io packages in the sparse index and report yanked versions
+///
+/// At most `MAX_YANKED_CHECKS` packages are looked up; lookups that fail are logged and
+/// skipped so an unreachable index never fails the review.
+pub async fn fetch_yanked_findings(packages: &[LockedPackage]) -> Vec<DependencyFinding> {
+    let client = match reqwest::Client::builder()
+        .timeout(INDEX_TIMEOUT)
+        .user_agent(concat!("arkaft-mcp-google-adk/", env!("CARGO_PKG_VERSION")))
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("Failed to build HTTP client for the crates.io index: {}", e);
+            return Vec::new();
+        }
+    };
+    
+    let mut findings = Vec::new();
+    for package in packages.iter().filter(|package| package.from_crates_io).take(MAX_YANKED_CHECKS) {
+        let Some(path) = index_path(&package.name) else {
+            warn!("Skipping {:?}, which is not a valid crates.io package name", package.name);
+            continue;
+        };
+        let url = format!("{}/{}", CRATES_IO_INDEX, path);
+        let body = match client.get(&url).send().await.and_then(|response| response.error_for_status()) {
+            Ok(response) => response.text().await,
+            Err(e) => Err(e),
+        };
+        match body {
+            Ok(body) => findings.extend(yanked_finding(package, &body)),
+            Err(e) => warn!("Failed to look up {} in the crates.io index: {}", package.name, e),
+        }
+    }
+    findings
+}
@@ -0,0 +1,254 @@
+//! Pull-request style review scoped to the lines a unified diff adds
+//!
+//! The diff is parsed per file and, for modified files, applied to the base content to
+//! recover the new file. The new file is reviewed whole, then findings are kept only
+//! where they touch an added line, so pre-existing issues are not reported again.
+
+use super::{ComplianceIssue, ReviewResult, SourceSpan};
+use anyhow::{anyhow, Result};
+use std::collections::BTreeSet;
+
+/// Line of a diff hunk
+#[derive(Debug, Clone, PartialEq)]
+pub enum HunkLine {
+    /// Line present in both versions
+    Context(String),
+    /// Line only in the new version
+    Added(String),
+    /// Line only in the base version
+    Removed(String),
+}
+
+/// Hunk of a unified diff
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hunk {
+    /// First base line of the hunk (1-based), or the line it inserts after when it removes nothing
+    pub old_start: usize,
+    /// Base lines the hunk covers
+    pub old_count: usize,
+    /// First new line of the hunk (1-based; 0 for an empty new file)
+    pub new_start: usize,
+    /// Lines of the hunk in order
+    pub lines: Vec<HunkLine>,
+}
+
+/// Changes to one file in a unified diff
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileDiff {
+    /// Base path, `None` for added files
+    pub old_path: Option<String>,
+    /// New path, `None` for deleted files
+    pub new_path: Option<String>,
+    /// Hunks in file order
+    pub hunks: Vec<Hunk>,
+}
+
+impl FileDiff {
+    /// Whether the diff creates the file
+    pub fn is_new_file(&self) -> bool {
+        self.old_path.is_none()
+    }
+    
+    /// New-file line numbers (1-based) of added lines
+    pub fn added_lines(&self) -> BTreeSet<usize> {
+        let mut added = BTreeSet::new();
+        for hunk in &self.hunks {
+            let mut line = hunk.new_start;
+            for hunk_line in &hunk.lines {
+                match hunk_line {
+                    HunkLine::Context(_) => line += 1,
+                    HunkLine::Added(_) => {
+                        added.insert(line);
+                        line += 1;
+                    }
+                    HunkLine::Removed(_) => {}
+                }
+            }
+        }
+        added
+    }
+    
+    /// New file content: the added lines of a new file, or the hunks applied to the base
+    ///
+    /// Fails when a context or removed line does not match the base.
+    pub fn new_content(&self, base: Option<&str>) -> Result<String> {
+        let path = self.new_path.as_deref().unwrap_or_default();
+        let base_lines: Vec<&str> = match (self.is_new_file(), base) {
+            (true, _) => Vec::new(),
+            (false, Some(base)) => base.lines().collect(),
+            (false, None) => return Err(anyhow!("{} is modified but its base content was not provided", path)),
+        };
+        
+        let mut lines: Vec<&str> = Vec::new();
+        let mut next = 0;
+        for hunk in &self.hunks {
+            let start = if hunk.old_count == 0 { hunk.old_start } else { hunk.old_start.saturating_sub(1) };
+            if start < next || start > base_lines.len() {
+                return Err(anyhow!("The diff does not apply to the base content of {}", path));
+            }
+            lines.extend(&base_lines[next..start]);
+            next = start;
+            
+            for hunk_line in &hunk.lines {
+                match hunk_line {
+                    HunkLine::Context(text) | HunkLine::Removed(text) => {
+                        if base_lines.get(next).map(|line| line.trim_end()) != Some(text.trim_end()) {
+                            return Err(anyhow!("The diff does not apply to the base content of {} at line {}", path, next + 1));
+                        }
+                        if matches!(hunk_line, HunkLine::Context(_)) {
+                            lines.push(base_lines[next]);
+                        }
+                        next += 1;
+                    }
+                    HunkLine::Added(text) => lines.push(text),
+                }
+            }
+        }
+        lines.extend(&base_lines[next..]);
+        
+        let mut content = lines.join("\n");
+        content.push('\n');
+        Ok(content)
+    }
+}
+
+/// Parse a unified diff, as produced by `git diff` or `diff -u`, into per-file changes
+pub fn parse_unified_diff(diff: &str) -> Result<Vec<FileDiff>> {
+    let mut files: Vec<FileDiff> = Vec::new();
+    let mut lines = diff.lines();
+    
+    while let Some(line) = lines.next() {
+        if let Some(old) = line.strip_prefix("--- ") {
+            let new = lines
+                .next()
+                .and_then(|line| line.strip_prefix("+++ "))
+                .ok_or_else(|| anyhow!("Expected a +++ line after '{}'", line))?;
+            files.push(FileDiff { old_path: diff_path(old), new_path: diff_path(new), hunks: Vec::new() });
+        } else if let Some(header) = line.strip_prefix("@@ ") {
+            let file = files.last_mut().ok_or_else(|| anyhow!("Hunk '{}' comes before any file header", line))?;
+            let (old_start, old_count, new_start, new_count) = parse_hunk_header(header)
+                .ok_or_else(|| anyhow!("Invalid hunk header '{}'", line))?;
+            
+            let mut hunk = Hunk { old_start, old_count, new_start, lines: Vec::new() };
+            let (mut old_remaining, mut new_remaining) = (old_count, new_count);
+            while old_remaining > 0 || new_remaining > 0 {
+                let Some(line) = lines.next() else {
+                    return Err(anyhow!("Hunk '@@ {}' ends early", header));
+                };
+                let hunk_line = match line.chars().next() {
+                    Some('+') => HunkLine::Added(line[1..].to_string()),
+                    Some('-') => HunkLine::Removed(line[1..].to_string()),
+                    Some(' ') => HunkLine::Context(line[1..].to_string()),
+                    None => HunkLine::Context(String::new()),
+                    Some('\\') => continue,
+                    Some(_) => return Err(anyhow!("Unexpected line '{}' in hunk '@@ {}'", line, header)),
+                };
+                if !matches!(hunk_line, HunkLine::Added(_)) {
+                    old_remaining = old_remaining.saturating_sub(1);
+                }
+                if !matches!(hunk_line, HunkLine::Removed(_)) {
+                    new_remaining = new_remaining.saturating_sub(1);
+                }
+                hunk.lines.push(hunk_line);
+            }
+            file.hunks.push(hunk);
+        }
+    }
+    
+    if files.is_empty() {
+        return Err(anyhow!("The diff has no file headers (--- and +++ lines)"));
+    }
+    Ok(files)
+}
+
+/// Path of a `---` or `+++` header, without the `a/` or `b/` prefix or a trailing timestamp
+fn diff_path(header: &str) -> Option<String> {
+    let path = header.split('\t').next().unwrap_or_default().trim();
+    if path == "/dev/null" {
+        return None;
+    }
+    let path = path.strip_prefix("a/").or_else(|| path.strip_prefix("b/")).unwrap_or(path);
+    Some(path.to_string())
+}
+
+/// Starts and counts of a hunk header after its leading `@@ `
+fn parse_hunk_header(header: &str) -> Option<(usize, usize, usize, usize)> {
+    let (ranges, _) = header.split_once(" @@")?;
+    let (old, new) = ranges.split_once(' ')?;
+    let range = |range: &str| -> Option<(usize, usize)> {
+        match range.split_once(',') {
+            Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+            None => Some((range.parse().ok()?, 1)),
+        }
+    };
+    let (old_start, old_count) = range(old.strip_prefix('-')?)?;
+    let (new_start, new_count) = range(new.strip_prefix('+')?)?;
+    Some((old_start, old_count, new_start, new_count))
+}
+
+/// Keep only the findings touching an added line
+///
+/// Findings without a location, such as architectural improvements, describe the whole
+/// file and are kept only when every line of the file is new.
+pub fn scope_to_lines(result: ReviewResult, added: &BTreeSet<usize>, whole_file: bool) -> ReviewResult {
+    let touches = |span: &SourceSpan| added.range(span.start_line..=span.end_line).next().is_some();
+    
+    ReviewResult {
+        translation_opportunities: result.translation_opportunities.into_iter().filter(|opportunity| touches(&opportunity.span)).collect(),
+        architectural_improvements: if whole_file { result.architectural_improvements } else { Vec::new() },
+        compliance_issues: result
+            .compliance_issues
+            .into_iter()
+            .filter_map(|issue| {
+                let total = issue.locations.len();
+                let locations: Vec<SourceSpan> = issue.locations.into_iter().filter(|span| touches(span)).collect();
+                if locations.is_empty() {
+                    return None;
+                }
+                let description = if locations.len() < total {
+                    format!("{} ({} on changed lines)", issue.description, locations.len())
+                } else {
+                    issue.description
+                };
+                let fixes = issue.fixes.into_iter().filter(|fix| locations.contains(&fix.span)).collect();
+                Some(ComplianceIssue { description, locations, fixes, ..issue })
+            })
+            .collect(),
+        organization_suggestions: if whole_file { result.organization_suggestions } else { Vec::new() },
+        manifest_issues: result
+            .manifest_issues
+            .into_iter()
+            .filter(|issue| whole_file || issue.line.is_some_and(|line| added.contains(&line)))
+            .collect(),
+        suppressed: result.suppressed,
+        function_metrics: result.function_metrics.into_iter().filter(|metrics| whole_file || touches(&metrics.span)).collect(),
+        formatted: result.formatted,
+    }
+}
+
+/// Review of one file of a diff
+#[derive(Debug)]
+pub struct DiffFileReview {
+    /// New path of the file
+    pub path: String,
+    /// Lines the diff adds to the file
+    pub added_lines: usize,
+    /// Findings touching added lines
+    pub result: ReviewResult,
+}
+
+/// Results of a diff review
+#[derive(Debug, Default)]
+pub struct DiffReview {
+    /// Reviewed files in diff order
+    pub files: Vec<DiffFileReview>,
+    /// Reviewable files that could not be reviewed, with the reason
+    pub skipped: Vec<(String, String)>,
+}
+
+impl DiffReview {
+    /// Findings touching added lines across all files
+    pub fn finding_count(&self) -> usize {
+        self.files.iter().map(|file| file.result.finding_count()).sum()
+    }
+}
@@ -0,0 +1,122 @@
+//! rustfmt conformance check for reviewed Rust files
+//!
+//! The file content is piped through `rustfmt --check`, so the check needs a `rustfmt`
+//! binary on the server's `PATH`. It runs only on request, and a missing or failing
+//! rustfmt never fails the review.
+
+use super::{OrganizationSuggestion, Severity};
+use anyhow::{anyhow, Result};
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// Edition rustfmt formats reviewed files for
+const RUSTFMT_EDITION: &str = "2021";
+
+/// Longest rustfmt may take to check one file
+const RUSTFMT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Changed regions listed by line in the summary
+const MAX_LISTED_HUNKS: usize = 5;
+
+/// Changes rustfmt would make to a file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FormattingDiff {
+    /// First line (1-based) of each changed region, including its leading context
+    pub hunk_lines: Vec<usize>,
+    /// Lines rustfmt would remove
+    pub removed: usize,
+    /// Lines rustfmt would add
+    pub added: usize,
+}
+
+impl FormattingDiff {
+    /// Parse the uncolored output of `rustfmt --check`, made of `Diff in <file>:<line>:`
+    /// headers followed by context, `-` and `+` lines
+    pub fn parse(output: &str) -> Self {
+        let mut diff = Self::default();
+        let mut in_hunk = false;
+        for line in output.lines() {
+            if let Some(header) = line.strip_prefix("Diff in ") {
+                in_hunk = true;
+                let number = header.trim_end_matches(':').rsplit(':').next().and_then(|number| number.parse().ok());
+                diff.hunk_lines.push(number.unwrap_or_default());
+            } else if in_hunk && line.starts_with('-') {
+                diff.removed += 1;
+            } else if in_hunk && line.starts_with('+') {
+                diff.added += 1;
+            }
+        }
+        diff
+    }
+    
+    /// Whether the file is already formatted
+    pub fn is_empty(&self) -> bool {
+        self.hunk_lines.is_empty()
+    }
+    
+    /// One-line summary, e.g. "2 regions (around lines 1, 6): 3 lines removed, 3 added"
+    pub fn summary(&self) -> String {
+        let mut lines: Vec<String> = self.hunk_lines.iter().take(MAX_LISTED_HUNKS).map(|line| line.to_string()).collect();
+        if self.hunk_lines.len() > MAX_LISTED_HUNKS {
+            lines.push("...".to_string());
+        }
+        format!(
+            "{} region{} (around line{} {}): {} line{} removed, {} added",
+            self.hunk_lines.len(),
+            if self.hunk_lines.len() == 1 { "" } else { "s" },
+            if self.hunk_lines.len() == 1 { "" } else { "s" },
+            lines.join(", "),
+            self.removed,
+            if self.removed == 1 { "" } else { "s" },
+            self.added
+        )
+    }
+}
+
+/// Check Rust source against rustfmt's default style
+///
+/// Fails when rustfmt is not installed, times out, or cannot parse the source.
+pub async fn check_formatting(content: &str) -> Result<FormattingDiff> {
+    let mut child = Command::new("rustfmt")
+        .args(["--check", "--color", "never", "--edition", RUSTFMT_EDITION])
+        // Keep rustfmt.toml files around the server's working directory out of the check
+        .current_dir(std::env::temp_dir())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| anyhow!("Failed to run rustfmt: {}", e))?;
+    
+    let mut stdin = child.stdin.take().ok_or_else(|| anyhow!("rustfmt stdin is not piped"))?;
+    stdin.write_all(content.as_bytes()).await?;
+    drop(stdin);
+    
+    let output = tokio::time::timeout(RUSTFMT_TIMEOUT, child.wait_with_output())
+        .await
+        .map_err(|_| anyhow!("rustfmt did not finish within {} seconds", RUSTFMT_TIMEOUT.as_secs()))??;
+    
+    // Depending on the version, rustfmt exits with 0 or 1 when it prints a diff
+    let diff = FormattingDiff::parse(&String::from_utf8_lossy(&output.stdout));
+    if diff.is_empty() && !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("rustfmt failed: {}", stderr.lines().next().unwrap_or_default()));
+    }
+    Ok(diff)
+}
+
+/// Organization suggestion for a file rustfmt would change, if any
+pub fn formatting_suggestion(diff: &FormattingDiff) -> Option<OrganizationSuggestion> {
+    if diff.is_empty() {
+        return None;
+    }
+    Some(OrganizationSuggestion {
+        suggestion_type: "Formatting".to_string(),
+        description: format!("The file is not formatted with rustfmt, which would change {}", diff.summary()),
+        action: "Run cargo fmt, and check formatting in CI with cargo fmt --check so reviews stay focused on behavior".to_string(),
+        rule: "rustfmt",
+        severity: Severity::Info,
+    })
+}
@@ -0,0 +1,348 @@
+//! Detection of non-English comments, doc comments, and string literals
+//!
+//! syn drops regular comments, so the text is extracted with a small lexer of its own and
+//! passed to whatlang once code spans, URLs, placeholders, and identifiers are stripped.
+//! Detections must be reliable and long enough, and Latin-script text using common English
+//! words is taken as English, so short labels and code-like text are not reported.
+
+use super::SourceSpan;
+use std::collections::BTreeSet;
+use whatlang::{Lang, Script};
+
+/// Letters of prose a text needs before its language is detected
+const MIN_LETTERS: usize = 12;
+
+/// Words of prose Latin-script text needs before its language is detected
+const MIN_LATIN_WORDS: usize = 3;
+
+/// Common English words; Latin-script text using two of them is taken as English
+const ENGLISH_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "be", "by", "for", "from", "if", "in", "is", "it", "not", "of", "on", "or", "the", "this",
+    "that", "to", "when", "with",
+];
+
+/// Function words of common Latin-script languages
+///
+/// whatlang confidently reads short technical English such as "Validate query parameter" as
+/// Portuguese, so a Latin-script detection needs two of these words, or a non-ASCII letter
+/// when the detected language is not listed or the text has no function words.
+const FUNCTION_WORDS: &[(Lang, &[&str])] = &[
+    (Lang::Spa, &["al", "con", "de", "del", "el", "en", "es", "la", "las", "lo", "los", "no", "para", "por", "que", "se", "una"]),
+    (Lang::Por, &["ao", "as", "com", "da", "das", "de", "do", "dos", "em", "na", "no", "não", "os", "para", "que", "um", "uma", "é"]),
+    (Lang::Fra, &["au", "aux", "avec", "dans", "de", "des", "du", "est", "et", "la", "le", "les", "pas", "pour", "que", "sur", "un", "une"]),
+    (Lang::Deu, &["auf", "aus", "das", "dem", "den", "der", "die", "ein", "eine", "für", "ist", "mit", "nicht", "sie", "und", "von", "zu"]),
+    (Lang::Ita, &["che", "con", "del", "della", "di", "gli", "il", "la", "le", "lo", "nel", "non", "per", "sono", "un", "una", "è"]),
+    (Lang::Nld, &["de", "een", "en", "het", "met", "naar", "niet", "op", "te", "van", "voor", "zijn"]),
+];
+
+/// Characters of a text quoted in findings
+const EXCERPT_LENGTH: usize = 60;
+
+/// Kind of text extracted from source
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextKind {
+    /// `//` or `/* */` comment
+    Comment,
+    /// `///`, `//!`, `/** */`, or `/*! */` documentation
+    DocComment,
+    /// String literal, raw or not
+    StringLiteral,
+}
+
+impl TextKind {
+    /// Label used in findings, e.g. "Doc comment"
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Comment => "Comment",
+            Self::DocComment => "Doc comment",
+            Self::StringLiteral => "String literal",
+        }
+    }
+}
+
+/// Comment or string literal of a source file
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSegment {
+    /// Kind of text
+    pub kind: TextKind,
+    /// Text without comment markers or quotes; consecutive line comments are joined by newlines
+    pub text: String,
+    /// Range of the comment or literal in the source
+    pub span: SourceSpan,
+}
+
+/// Text written in a language other than English
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForeignText {
+    /// Kind of text
+    pub kind: TextKind,
+    /// Detected language
+    pub language: Lang,
+    /// Start of the text, for quoting in findings
+    pub excerpt: String,
+    /// Range of the comment or literal in the source
+    pub span: SourceSpan,
+}
+
+/// Comments, doc comments, and string literals of Rust source, in source order
+///
+/// Byte strings and C strings are skipped, since they rarely hold prose.
+pub fn extract_text(content: &str) -> Vec<TextSegment> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut positions = Vec::with_capacity(chars.len());
+    let (mut line, mut column) = (1, 1);
+    for c in &chars {
+        positions.push((line, column));
+        if *c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    let span = |start: usize, end: usize| SourceSpan {
+        start_line: positions[start].0,
+        start_column: positions[start].1,
+        end_line: positions[end].0,
+        end_column: positions[end].1,
+    };
+    
+    let mut segments: Vec<TextSegment> = Vec::new();
+    // Index of the last segment made of line comments, which the next line comment may extend
+    let mut open_line_comment: Option<usize> = None;
+    let mut i = 0;
+    while i < chars.len() {
+        if open_line_comment.is_some_and(|index| index + 1 != segments.len()) {
+            open_line_comment = None;
+        }
+        let next = chars.get(i + 1).copied();
+        if chars[i] == '/' && next == Some('/') {
+            let end = chars[i..].iter().position(|c| *c == '\n').map_or(chars.len(), |offset| i + offset);
+            let raw: String = chars[i..end].iter().collect();
+            let (kind, text) = match raw.strip_prefix("///").or_else(|| raw.strip_prefix("//!")) {
+                Some(text) if !raw.starts_with("////") => (TextKind::DocComment, text),
+                _ => (TextKind::Comment, raw.trim_start_matches('/')),
+            };
+            let text = text.trim();
+            let comment_span = span(i, end - 1);
+            match open_line_comment.map(|index| &mut segments[index]) {
+                Some(last) if last.kind == kind && last.span.end_line + 1 == comment_span.start_line => {
+                    last.text.push('\n');
+                    last.text.push_str(text);
+                    last.span.end_line = comment_span.end_line;
+                    last.span.end_column = comment_span.end_column;
+                }
+                _ => {
+                    open_line_comment = Some(segments.len());
+                    segments.push(TextSegment { kind, text: text.to_string(), span: comment_span });
+                }
+            }
+            i = end;
+        } else if chars[i] == '/' && next == Some('*') {
+            let end = block_comment_end(&chars, i);
+            let raw: String = chars[i..end].iter().collect();
+            let is_doc = (raw.starts_with("/**") && !raw.starts_with("/***") && raw != "/**/") || raw.starts_with("/*!");
+            let body = raw.get(3..raw.len().saturating_sub(2)).unwrap_or_default();
+            let text = body
+                .lines()
+                .map(|line| line.trim().trim_start_matches('*').trim())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let kind = if is_doc { TextKind::DocComment } else { TextKind::Comment };
+            segments.push(TextSegment { kind, text: text.trim().to_string(), span: span(i, end - 1) });
+            i = end;
+        } else if chars[i] == '"' {
+            let end = string_end(&chars, i + 1);
+            let text: String = chars[i + 1..end.min(chars.len())].iter().collect();
+            segments.push(TextSegment {
+                kind: TextKind::StringLiteral,
+                text: unescape(&text),
+                span: span(i, end.min(chars.len() - 1)),
+            });
+            i = end + 1;
+        } else if chars[i] == '\'' {
+            // Skip char literals, leaving lifetimes to the identifier branch
+            i = match (next, chars.get(i + 2)) {
+                (Some('\\'), _) => chars
+                    .get(i + 3..)
+                    .and_then(|rest| rest.iter().position(|c| *c == '\''))
+                    .map_or(chars.len(), |offset| i + offset + 4),
+                (Some(_), Some('\'')) => i + 3,
+                _ => i + 1,
+            };
+        } else if chars[i].is_alphanumeric() || chars[i] == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let quoted = matches!(chars.get(i), Some('"' | '#'));
+            if quoted && matches!(word.as_str(), "r" | "br" | "cr") {
+                let hashes = chars[i..].iter().take_while(|c| **c == '#').count();
+                if chars.get(i + hashes) != Some(&'"') {
+                    continue;
+                }
+                let body = i + hashes + 1;
+                let end = raw_string_end(&chars, body, hashes);
+                if word == "r" {
+                    let text: String = chars[body..end.min(chars.len())].iter().collect();
+                    segments.push(TextSegment {
+                        kind: TextKind::StringLiteral,
+                        text,
+                        span: span(start, (end + hashes).min(chars.len() - 1)),
+                    });
+                }
+                i = end + 1 + hashes;
+            } else if quoted && matches!(word.as_str(), "b" | "c") && chars[i] == '"' {
+                i = string_end(&chars, i + 1) + 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    segments
+}
+
+/// Index just past the end of the block comment starting at `start`, which may nest
+fn block_comment_end(chars: &[char], start: usize) -> usize {
+    let mut depth = 0;
+    let mut i = start;
+    while i < chars.len() {
+        match (chars[i], chars.get(i + 1)) {
+            ('/', Some('*')) => {
+                depth += 1;
+                i += 2;
+            }
+            ('*', Some('/')) => {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return i;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+/// Index of the closing quote of a string whose content starts at `start`
+fn string_end(chars: &[char], start: usize) -> usize {
+    let mut i = start;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '"' => return i,
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+/// Index of the closing quote of a raw string with `hashes` hashes whose content starts at `start`
+fn raw_string_end(chars: &[char], start: usize, hashes: usize) -> usize {
+    (start..chars.len())
+        .find(|&i| chars[i] == '"' && chars[i + 1..].iter().take(hashes).filter(|c| **c == '#').count() == hashes)
+        .unwrap_or(chars.len())
+}
+
+/// String literal content with escapes replaced by the characters they stand for, or by a
+/// space for those that do not matter to language detection
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some(escaped @ ('"' | '\'' | '\\')) => unescaped.push(escaped),
+            _ => unescaped.push(' '),
+        }
+    }
+    unescaped
+}
+
+/// Words of a text that read as prose, without fenced code blocks, `code` spans, URLs,
+/// format placeholders, paths, and identifiers
+///
+/// `None` when most of the words are code-like, as in code snippets held in strings.
+fn prose(text: &str) -> Option<String> {
+    let mut in_fence = false;
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        } else if !in_fence {
+            lines.push(line);
+        }
+    }
+    let joined = lines.join(" ");
+    let without_code = joined.split('`').step_by(2).collect::<Vec<_>>().join(" ");
+    let words: Vec<&str> = without_code.split_whitespace().collect();
+    let prose: Vec<&str> = words
+        .iter()
+        .copied()
+        .filter(|word| {
+            !word.contains("://")
+                && !word.contains("::")
+                && !word.contains(['_', '{', '}', '/', '\\', '=', '<', '>', '(', ')', '[', ']', ';', '@', '#', '$', '%', '&', '|'])
+                && !word.chars().any(|c| c.is_ascii_digit())
+        })
+        .collect();
+    (prose.len() * 2 >= words.len()).then(|| prose.join(" "))
+}
+
+/// Language of a text, when it is detected as something other than English
+///
+/// Text in a script other than Latin is never English, so only Latin-script detections are
+/// checked further.
+pub fn detect_foreign_language(text: &str) -> Option<Lang> {
+    let prose = prose(text)?;
+    if prose.chars().filter(|c| c.is_alphabetic()).count() < MIN_LETTERS {
+        return None;
+    }
+    let info = whatlang::detect(&prose)?;
+    if info.lang() == Lang::Eng {
+        return None;
+    }
+    if info.script() == Script::Latin {
+        let lowercase = prose.to_lowercase();
+        let words: BTreeSet<&str> = lowercase.split(|c: char| !c.is_alphabetic()).filter(|word| !word.is_empty()).collect();
+        let english = ENGLISH_WORDS.iter().filter(|word| words.contains(*word)).count();
+        if words.len() < MIN_LATIN_WORDS || english >= 2 {
+            return None;
+        }
+        let function_words = FUNCTION_WORDS
+            .iter()
+            .find(|(lang, _)| *lang == info.lang())
+            .map_or(0, |(_, function_words)| function_words.iter().filter(|word| words.contains(*word)).count());
+        let accented = prose.chars().any(|c| c.is_alphabetic() && !c.is_ascii());
+        if function_words < 2 && !(info.is_reliable() && accented) {
+            return None;
+        }
+    }
+    Some(info.lang())
+}
+
+/// Comments, doc comments, and string literals of Rust source not written in English
+pub fn find_foreign_text(content: &str) -> Vec<ForeignText> {
+    extract_text(content)
+        .into_iter()
+        .filter_map(|segment| {
+            let language = detect_foreign_language(&segment.text)?;
+            Some(ForeignText { kind: segment.kind, language, excerpt: excerpt(&segment.text), span: segment.span })
+        })
+        .collect()
+}
+
+/// First line of a text, shortened to `EXCERPT_LENGTH` characters
+fn excerpt(text: &str) -> String {
+    let line = text.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or_default();
+    if line.chars().count() <= EXCERPT_LENGTH {
+        return line.to_string();
+    }
+    let shortened: String = line.chars().take(EXCERPT_LENGTH).collect();
+    format!("{}...", shortened.trim_end())
+}
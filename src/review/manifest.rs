@@ -0,0 +1,216 @@
+//! Cargo.toml manifest analysis for metadata, dependency, and feature hygiene
+
+use super::{ManifestIssue, Severity};
+use regex::Regex;
+use toml::{Table, Value};
+
+/// Package fields a published crate should declare
+const REQUIRED_METADATA: &[&str] = &["description", "license", "repository", "edition"];
+
+/// Dependency tables checked for wildcard and git requirements
+const DEPENDENCY_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Dependency features that enable nearly everything the crate offers
+const CATCH_ALL_FEATURES: &[&str] = &["full", "all"];
+
+/// Analyze a Cargo manifest, checking features against the crate's Rust sources
+///
+/// Declared features are only reported as unused when `sources` is not empty, since a
+/// manifest reviewed alone gives no evidence of how its features are used.
+pub fn analyze_manifest(content: &str, sources: &[&str]) -> Vec<ManifestIssue> {
+    let manifest: Table = match content.parse() {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            return vec![ManifestIssue {
+                issue_type: "Invalid Manifest".to_string(),
+                key: String::new(),
+                line: e.span().map(|span| line_of_offset(content, span.start)),
+                description: format!("Cargo.toml could not be parsed: {}", e.message()),
+                fix_suggestion: "Fix the TOML syntax so Cargo and reviewers can read the manifest".to_string(),
+                severity: Severity::Error,
+            }];
+        }
+    };
+    
+    let mut issues = Vec::new();
+    if let Some(package) = manifest.get("package").and_then(Value::as_table) {
+        check_metadata(package, content, &mut issues);
+    }
+    
+    for (table_key, dependencies) in dependency_tables(&manifest) {
+        for (name, requirement) in dependencies {
+            check_dependency(&table_key, name, requirement, content, &mut issues);
+        }
+    }
+    
+    if let Some(features) = manifest.get("features").and_then(Value::as_table) {
+        check_features(features, &manifest, content, sources, &mut issues);
+    }
+    
+    issues
+}
+
+/// Report missing package metadata and `rust-version`
+fn check_metadata(package: &Table, content: &str, issues: &mut Vec<ManifestIssue>) {
+    let line = find_line(content, r"^\s*\[package\]");
+    let declared = |field: &str| package.contains_key(field) || (field == "license" && package.contains_key("license-file"));
+    
+    let missing: Vec<&str> = REQUIRED_METADATA.iter().copied().filter(|field| !declared(field)).collect();
+    if !missing.is_empty() {
+        issues.push(ManifestIssue {
+            issue_type: "Missing Metadata".to_string(),
+            key: "package".to_string(),
+            line,
+            description: format!("[package] does not declare {}", missing.join(", ")),
+            fix_suggestion: "Declare the crate's description, license, repository, and edition so it can be published and audited".to_string(),
+            severity: Severity::Info,
+        });
+    }
+    
+    if !package.contains_key("rust-version") {
+        issues.push(ManifestIssue {
+            issue_type: "Missing rust-version".to_string(),
+            key: "package.rust-version".to_string(),
+            line,
+            description: "[package] does not declare the minimum supported Rust version".to_string(),
+            fix_suggestion: "Set rust-version to the oldest toolchain the crate builds with, so older toolchains fail with a clear error".to_string(),
+            severity: Severity::Info,
+        });
+    }
+}
+
+/// Report wildcard and git requirements of one dependency
+fn check_dependency(table_key: &str, name: &str, requirement: &Value, content: &str, issues: &mut Vec<ManifestIssue>) {
+    let key = format!("{}.{}", table_key, name);
+    let line = find_line(content, &format!(r#"^\s*(?:{}|"{}")\s*=|^\s*\[(?:.*\.)?{}\.{}\]"#, name, name, table_key, name));
+    
+    let (version, table) = match requirement {
+        Value::String(version) => (Some(version.as_str()), None),
+        Value::Table(table) => (table.get("version").and_then(Value::as_str), Some(table)),
+        _ => return,
+    };
+    let has = |field: &str| table.is_some_and(|table| table.contains_key(field));
+    
+    if has("git") {
+        let pinned = has("rev") || has("tag");
+        issues.push(ManifestIssue {
+            issue_type: "Git Dependency".to_string(),
+            key: key.clone(),
+            line,
+            description: format!(
+                "{} is fetched from git{}",
+                name,
+                if pinned { "" } else { " without a pinned rev or tag" }
+            ),
+            fix_suggestion: "Depend on a crates.io release, or pin the git dependency to a rev or tag so builds are reproducible".to_string(),
+            severity: if pinned { Severity::Info } else { Severity::Warning },
+        });
+    } else if version.is_some_and(|version| version.trim() == "*") || (version.is_none() && !has("path") && !has("workspace")) {
+        issues.push(ManifestIssue {
+            issue_type: "Wildcard Dependency".to_string(),
+            key: key.clone(),
+            line,
+            description: format!("{} accepts any version", name),
+            fix_suggestion: "Require a specific version range, e.g. the current major release, so breaking releases are not picked up".to_string(),
+            severity: Severity::Warning,
+        });
+    }
+    
+    let catch_all = table
+        .and_then(|table| table.get("features"))
+        .and_then(Value::as_array)
+        .and_then(|features| features.iter().filter_map(Value::as_str).find(|feature| CATCH_ALL_FEATURES.contains(feature)));
+    if let Some(feature) = catch_all {
+        issues.push(ManifestIssue {
+            issue_type: "Broad Features".to_string(),
+            key,
+            line,
+            description: format!("{} enables its \"{}\" feature", name, feature),
+            fix_suggestion: "Enable only the features the crate uses, to cut compile time and dependency surface".to_string(),
+            severity: Severity::Info,
+        });
+    }
+}
+
+/// Report unused declared features and default features enabling everything
+fn check_features(features: &Table, manifest: &Table, content: &str, sources: &[&str], issues: &mut Vec<ManifestIssue>) {
+    let optional_dependencies: Vec<&String> = dependency_tables(manifest)
+        .into_iter()
+        .flat_map(|(_, dependencies)| dependencies.iter())
+        .filter(|(_, requirement)| requirement.get("optional").and_then(Value::as_bool) == Some(true))
+        .map(|(name, _)| name)
+        .collect();
+    let enabled_by = |name: &str| -> Vec<&str> {
+        features.get(name).and_then(Value::as_array).map(|list| list.iter().filter_map(Value::as_str).collect()).unwrap_or_default()
+    };
+    let declared: Vec<&String> = features.keys().filter(|name| name.as_str() != "default").collect();
+    
+    if !sources.is_empty() {
+        for name in &declared {
+            let referenced_in_code = Regex::new(&format!(r#"feature\s*=\s*"{}""#, regex::escape(name)))
+                .is_ok_and(|regex| sources.iter().any(|source| regex.is_match(source)));
+            let referenced_by_feature = features.keys().any(|other| other != *name && enabled_by(other).contains(&name.as_str()));
+            let enables_dependency = enabled_by(name).iter().any(|entry| {
+                entry.starts_with("dep:") || entry.contains('/') || optional_dependencies.iter().any(|dependency| dependency == entry)
+            });
+            if referenced_in_code || referenced_by_feature || enables_dependency {
+                continue;
+            }
+            
+            issues.push(ManifestIssue {
+                issue_type: "Unused Feature".to_string(),
+                key: format!("features.{}", name),
+                line: find_line(content, &format!(r#"^\s*(?:{}|"{}")\s*="#, regex::escape(name), regex::escape(name))),
+                description: format!("Feature \"{}\" is declared but no code or other feature uses it", name),
+                fix_suggestion: "Remove the feature, or gate the code it is meant to toggle with #[cfg(feature = \"...\")]".to_string(),
+                severity: Severity::Info,
+            });
+        }
+    }
+    
+    let default = enabled_by("default");
+    if declared.len() >= 2 && declared.iter().all(|name| default.contains(&name.as_str())) {
+        issues.push(ManifestIssue {
+            issue_type: "Broad Default Features".to_string(),
+            key: "features.default".to_string(),
+            line: find_line(content, r"^\s*default\s*="),
+            description: "The default feature set enables every declared feature".to_string(),
+            fix_suggestion: "Keep default features minimal so dependents can opt in to what they need".to_string(),
+            severity: Severity::Info,
+        });
+    }
+}
+
+/// Dependency tables of a manifest with their dotted keys, including target-specific and workspace tables
+fn dependency_tables(manifest: &Table) -> Vec<(String, &Table)> {
+    let mut tables: Vec<(String, &Table)> = DEPENDENCY_TABLES
+        .iter()
+        .filter_map(|key| manifest.get(*key).and_then(Value::as_table).map(|table| (key.to_string(), table)))
+        .collect();
+    
+    if let Some(targets) = manifest.get("target").and_then(Value::as_table) {
+        for (target, target_table) in targets {
+            for key in DEPENDENCY_TABLES {
+                if let Some(table) = target_table.get(*key).and_then(Value::as_table) {
+                    tables.push((format!("target.{}.{}", target, key), table));
+                }
+            }
+        }
+    }
+    
+    if let Some(table) = manifest.get("workspace").and_then(|workspace| workspace.get("dependencies")).and_then(Value::as_table) {
+        tables.push(("workspace.dependencies".to_string(), table));
+    }
+    tables
+}
+
+/// First line (1-based) matching a multi-line regex, if any
+fn find_line(content: &str, pattern: &str) -> Option<usize> {
+    let regex = Regex::new(&format!("(?m){}", pattern)).ok()?;
+    regex.find(content).map(|found| line_of_offset(content, found.start()))
+}
+
+/// Line (1-based) of a byte offset
+fn line_of_offset(content: &str, offset: usize) -> usize {
+    content[..offset.min(content.len())].matches('\n').count() + 1
+}
@@ -3,22 +3,55 @@
 //! Analyzes .rs files for translation needs, ADK compliance, and architectural improvements.
 //! Provides specific suggestions following Google ADK best practices.
 
+pub mod agent_config;
 pub mod analyzer;
+pub mod autofix;
+pub mod dependencies;
+pub mod diff;
+pub mod formatting;
+pub mod language;
+pub mod manifest;
+pub mod module_tree;
+pub mod project;
 pub mod suggestions;
+pub mod suppress;
 
 #[cfg(test)]
 mod tests;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use dependencies::Advisory;
+use diff::{DiffFileReview, DiffReview};
+use project::{FileReview, ProjectReview, ProjectSummary};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
 
 /// Code Review Engine for analyzing Rust files
 pub struct CodeReviewEngine {
     /// Configuration for review analysis
     pub config: ReviewConfig,
+    /// RustSec advisories locked dependencies are audited against
+    pub advisories: Vec<Advisory>,
 }
 
+/// Check names accepted by `ReviewConfig::from_checks`
+pub const REVIEW_CHECKS: &[&str] = &["translations", "architecture", "compliance", "organization"];
+
+/// Cyclomatic complexity above which a function is reported, by default
+pub const DEFAULT_MAX_CYCLOMATIC_COMPLEXITY: usize = 10;
+
+/// Control-flow nesting depth above which a function is reported, by default
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 4;
+
+/// Function length in lines above which a function is reported, by default
+pub const DEFAULT_MAX_FUNCTION_LINES: usize = 50;
+
+/// Parameter count above which a function is reported, by default
+pub const DEFAULT_MAX_PARAMETERS: usize = 5;
+
 /// Configuration for code review analysis
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct ReviewConfig {
     /// Enable translation opportunity detection
     pub detect_translations: bool,
@@ -26,10 +59,83 @@ pub struct ReviewConfig {
     pub check_architecture: bool,
     /// Enable ADK compliance validation
     pub validate_adk_compliance: bool,
+    /// Enable file organization suggestions
+    pub check_organization: bool,
+    /// Highest cyclomatic complexity accepted for a function
+    pub max_cyclomatic_complexity: usize,
+    /// Deepest control-flow nesting accepted in a function
+    pub max_nesting_depth: usize,
+    /// Most lines accepted in a function, from the `fn` keyword to the closing brace
+    pub max_function_lines: usize,
+    /// Most parameters accepted in a function, not counting `self`
+    pub max_parameters: usize,
+    /// Check the file against rustfmt, which needs a `rustfmt` binary
+    pub check_formatting: bool,
+}
+
+impl Default for ReviewConfig {
+    fn default() -> Self {
+        Self {
+            detect_translations: true,
+            check_architecture: true,
+            validate_adk_compliance: true,
+            check_organization: true,
+            max_cyclomatic_complexity: DEFAULT_MAX_CYCLOMATIC_COMPLEXITY,
+            max_nesting_depth: DEFAULT_MAX_NESTING_DEPTH,
+            max_function_lines: DEFAULT_MAX_FUNCTION_LINES,
+            max_parameters: DEFAULT_MAX_PARAMETERS,
+            check_formatting: false,
+        }
+    }
+}
+
+impl ReviewConfig {
+    /// Configuration running only the named checks (see `REVIEW_CHECKS`), ignoring case
+    pub fn from_checks<S: AsRef<str>>(checks: &[S]) -> Result<Self> {
+        let mut names = Vec::new();
+        for check in checks {
+            let name = check.as_ref().trim().to_lowercase();
+            if !REVIEW_CHECKS.contains(&name.as_str()) {
+                return Err(anyhow!("Unknown review check '{}'. Expected one of: {}", check.as_ref(), REVIEW_CHECKS.join(", ")));
+            }
+            names.push(name);
+        }
+        
+        let enabled = |check: &str| names.iter().any(|name| name == check);
+        Ok(Self {
+            detect_translations: enabled("translations"),
+            check_architecture: enabled("architecture"),
+            validate_adk_compliance: enabled("compliance"),
+            check_organization: enabled("organization"),
+            ..Self::default()
+        })
+    }
+}
+
+/// How urgently a review finding should be addressed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Breaks builds or risks failures on production paths, e.g. `panic!`
+    Error,
+    /// Should be addressed, e.g. `unwrap()` or unfinished code
+    Warning,
+    /// Style, documentation, and organization suggestions
+    Info,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        })
+    }
 }
 
 /// Results of a code review analysis
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ReviewResult {
     /// Translation opportunities found
     pub translation_opportunities: Vec<TranslationOpportunity>,
@@ -39,21 +145,132 @@ pub struct ReviewResult {
     pub compliance_issues: Vec<ComplianceIssue>,
     /// File organization suggestions
     pub organization_suggestions: Vec<OrganizationSuggestion>,
+    /// Cargo manifest issues, for `Cargo.toml` files
+    pub manifest_issues: Vec<ManifestIssue>,
+    /// Findings silenced by `arkaft-ignore` comments, counted per rule
+    pub suppressed: BTreeMap<&'static str, usize>,
+    /// Size and complexity metrics per function, computed with the architecture and organization checks
+    pub function_metrics: Vec<FunctionMetrics>,
+    /// Whether the file matches rustfmt's style, when the formatting check ran
+    pub formatted: Option<bool>,
+}
+
+/// Size and complexity metrics of one function or method
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FunctionMetrics {
+    /// Function name
+    pub name: String,
+    /// Range from the `fn` keyword to the closing brace
+    pub span: SourceSpan,
+    /// Decision points plus one: branches, loops, match arms, and `&&`/`||` operators
+    pub cyclomatic_complexity: usize,
+    /// Deepest nesting of `if`, `match`, and loop bodies, with `else if` chains counting once
+    pub nesting_depth: usize,
+    /// Lines spanned, from the `fn` keyword to the closing brace
+    pub lines: usize,
+    /// Parameters, not counting `self`
+    pub parameters: usize,
+}
+
+/// Source range of a finding, with 1-based lines and columns and an inclusive end
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct SourceSpan {
+    /// First line
+    pub start_line: usize,
+    /// Column of the first character on the first line
+    pub start_column: usize,
+    /// Last line
+    pub end_line: usize,
+    /// Column of the last character on the last line
+    pub end_column: usize,
+}
+
+impl SourceSpan {
+    /// Range covered by a parsed token span
+    ///
+    /// Spans without a location, such as those of some lexer errors, fall back to the
+    /// start of the file.
+    pub fn from_span(span: proc_macro2::Span) -> Self {
+        Self::between(span, span)
+    }
+    
+    /// Range from the start of `start` to the end of `end`
+    pub fn between(start: proc_macro2::Span, end: proc_macro2::Span) -> Self {
+        let (start, end) = (start.start(), end.end());
+        if start.line == 0 {
+            return Self { start_line: 1, start_column: 1, end_line: 1, end_column: 1 };
+        }
+        
+        // proc-macro2 columns are 0-based and its ends exclusive; only a single-line span
+        // needs its end kept at or after its start
+        let end_column = if end.line == start.line { end.column.max(start.column + 1) } else { end.column };
+        Self {
+            start_line: start.line,
+            start_column: start.column + 1,
+            end_line: end.line,
+            end_column,
+        }
+    }
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}-{}:{}", self.start_line, self.start_column, self.end_line, self.end_column)
+    }
+}
+
+impl ReviewResult {
+    /// Order the findings of each category by severity, most severe first, keeping source order otherwise
+    pub fn sort_by_severity(&mut self) {
+        self.translation_opportunities.sort_by_key(|opportunity| opportunity.severity);
+        self.architectural_improvements.sort_by_key(|improvement| improvement.severity);
+        self.compliance_issues.sort_by_key(|issue| issue.severity);
+        self.organization_suggestions.sort_by_key(|suggestion| suggestion.severity);
+        self.manifest_issues.sort_by_key(|issue| issue.severity);
+    }
+    
+    /// Findings of every category
+    pub fn finding_count(&self) -> usize {
+        self.translation_opportunities.len()
+            + self.architectural_improvements.len()
+            + self.compliance_issues.len()
+            + self.organization_suggestions.len()
+            + self.manifest_issues.len()
+    }
 }
 
 /// A translation opportunity in the code
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct TranslationOpportunity {
     /// Line number where opportunity exists
     pub line: usize,
+    /// Exact range of the code the opportunity refers to
+    pub span: SourceSpan,
     /// Description of the translation opportunity
     pub description: String,
     /// Suggested translation or improvement
     pub suggestion: String,
+    /// Patch resolving the finding, for mechanical fixes
+    pub fix: Option<AutoFix>,
+    /// Rule identifier, for suppressing the finding with `// arkaft-ignore: <rule>`
+    pub rule: &'static str,
+    /// How urgently the finding should be addressed
+    pub severity: Severity,
+}
+
+/// Patch that resolves a finding, for clients to offer as a one-click fix
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AutoFix {
+    /// What the patch changes
+    pub description: String,
+    /// Range of the finding the patch resolves
+    pub span: SourceSpan,
+    /// Unified diff against the reviewed file
+    pub patch: String,
 }
 
 /// An architectural improvement suggestion
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ArchitecturalImprovement {
     /// Area of improvement
     pub area: String,
@@ -63,10 +280,14 @@ pub struct ArchitecturalImprovement {
     pub recommended_pattern: String,
     /// Rationale for the improvement
     pub rationale: String,
+    /// Rule identifier, for suppressing the finding with `// arkaft-ignore: <rule>`
+    pub rule: &'static str,
+    /// How urgently the finding should be addressed
+    pub severity: Severity,
 }
 
 /// An ADK compliance issue
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ComplianceIssue {
     /// Type of compliance issue
     pub issue_type: String,
@@ -74,10 +295,35 @@ pub struct ComplianceIssue {
     pub description: String,
     /// How to fix the issue
     pub fix_suggestion: String,
+    /// Ranges of each occurrence of the issue, in source order
+    pub locations: Vec<SourceSpan>,
+    /// Patches resolving individual occurrences, for mechanical fixes
+    pub fixes: Vec<AutoFix>,
+    /// Rule identifier, for suppressing the finding with `// arkaft-ignore: <rule>`
+    pub rule: &'static str,
+    /// How urgently the finding should be addressed
+    pub severity: Severity,
+}
+
+/// A Cargo manifest issue
+#[derive(Debug, Serialize)]
+pub struct ManifestIssue {
+    /// Type of manifest issue
+    pub issue_type: String,
+    /// Dotted manifest key the issue refers to, e.g. "dependencies.serde"
+    pub key: String,
+    /// Line of the key in the manifest (1-based), when it can be located
+    pub line: Option<usize>,
+    /// Description of the issue
+    pub description: String,
+    /// How to fix the issue
+    pub fix_suggestion: String,
+    /// How urgently the finding should be addressed
+    pub severity: Severity,
 }
 
 /// A file organization suggestion
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct OrganizationSuggestion {
     /// Type of organization improvement
     pub suggestion_type: String,
@@ -85,18 +331,31 @@ pub struct OrganizationSuggestion {
     pub description: String,
     /// Recommended action
     pub action: String,
+    /// Rule identifier, for suppressing the finding with `// arkaft-ignore: <rule>`
+    pub rule: &'static str,
+    /// How urgently the finding should be addressed
+    pub severity: Severity,
 }
 
 impl CodeReviewEngine {
     /// Create a new Code Review Engine
     pub fn new() -> Self {
-        let config = ReviewConfig {
-            detect_translations: true,
-            check_architecture: true,
-            validate_adk_compliance: true,
-        };
-        
-        Self { config }
+        Self {
+            config: ReviewConfig::default(),
+            advisories: dependencies::bundled_advisories(),
+        }
+    }
+    
+    /// Run only the analyses enabled in `config`
+    pub fn with_config(mut self, config: ReviewConfig) -> Self {
+        self.config = config;
+        self
+    }
+    
+    /// Audit dependencies against the given advisories instead of the bundled snapshot
+    pub fn with_advisories(mut self, advisories: Vec<Advisory>) -> Self {
+        self.advisories = advisories;
+        self
     }
     
     /// Review a Rust file for improvements
@@ -104,6 +363,7 @@ impl CodeReviewEngine {
         use crate::review::analyzer::{
             analyze_translation_opportunities,
             analyze_architectural_patterns,
+            analyze_function_metrics,
             analyze_adk_compliance,
             analyze_file_organization,
         };
@@ -113,28 +373,222 @@ impl CodeReviewEngine {
             architectural_improvements: Vec::new(),
             compliance_issues: Vec::new(),
             organization_suggestions: Vec::new(),
+            manifest_issues: Vec::new(),
+            suppressed: BTreeMap::new(),
+            function_metrics: Vec::new(),
+            formatted: None,
         };
         
+        let fixes = autofix::suggest_fixes(file_path, file_content);
+        if self.config.check_architecture || self.config.check_organization {
+            result.function_metrics = analyze_function_metrics(file_content)?;
+        }
+        
         // Analyze translation opportunities if enabled
         if self.config.detect_translations {
             result.translation_opportunities = analyze_translation_opportunities(file_content)?;
+            
+            for opportunity in &mut result.translation_opportunities {
+                opportunity.fix = fixes
+                    .iter()
+                    .find(|(rule, fix)| *rule == opportunity.rule && fix.span == opportunity.span)
+                    .map(|(_, fix)| fix.clone());
+            }
         }
         
         // Analyze architectural patterns if enabled
         if self.config.check_architecture {
             result.architectural_improvements = analyze_architectural_patterns(file_content)?;
+            result.architectural_improvements.extend(analyzer::complexity_improvements(&result.function_metrics, &self.config));
         }
         
         // Analyze ADK compliance if enabled
         if self.config.validate_adk_compliance {
             result.compliance_issues = analyze_adk_compliance(file_content)?;
+            
+            for issue in &mut result.compliance_issues {
+                issue.fixes = fixes
+                    .iter()
+                    .filter(|(rule, fix)| *rule == issue.rule && issue.locations.contains(&fix.span))
+                    .map(|(_, fix)| fix.clone())
+                    .collect();
+            }
+        }
+        
+        // Analyze file organization if enabled
+        if self.config.check_organization {
+            result.organization_suggestions = analyze_file_organization(file_path, file_content)?;
+            result.organization_suggestions.extend(analyzer::function_size_suggestions(&result.function_metrics, &self.config));
+        }
+        
+        // Check formatting on request, since it runs rustfmt
+        if self.config.check_formatting {
+            match formatting::check_formatting(file_content).await {
+                Ok(diff) => {
+                    result.formatted = Some(diff.is_empty());
+                    result.organization_suggestions.extend(formatting::formatting_suggestion(&diff));
+                }
+                Err(e) => tracing::warn!("Skipping the formatting check of {}: {}", file_path, e),
+            }
         }
         
-        // Always analyze file organization
-        result.organization_suggestions = analyze_file_organization(file_path, file_content)?;
+        // Failing fast with unwrap() and expect() is accepted outside library and binary code
+        if analyzer::is_non_production_path(file_path) {
+            result.translation_opportunities.retain(|opportunity| !analyzer::FAIL_FAST_RULES.contains(&opportunity.rule));
+            result.compliance_issues.retain(|issue| !analyzer::FAIL_FAST_RULES.contains(&issue.rule));
+        }
+        // Binaries own their console and their errors, unlike library and server code
+        if analyzer::allows_console_output(file_path) {
+            result.translation_opportunities.retain(|opportunity| opportunity.rule != "print");
+            result.architectural_improvements.retain(|improvement| improvement.rule != "typed-errors");
+        }
         
+        suppress::apply(&mut result, file_content);
+        result.sort_by_severity();
         Ok(result)
     }
+    
+    /// Review a Cargo manifest, checking its features against the crate's Rust sources
+    pub fn review_manifest(&self, manifest_content: &str, sources: &[&str]) -> ReviewResult {
+        let mut result = ReviewResult {
+            translation_opportunities: Vec::new(),
+            architectural_improvements: Vec::new(),
+            compliance_issues: Vec::new(),
+            organization_suggestions: Vec::new(),
+            manifest_issues: manifest::analyze_manifest(manifest_content, sources),
+            suppressed: BTreeMap::new(),
+            function_metrics: Vec::new(),
+            formatted: None,
+        };
+        result.sort_by_severity();
+        result
+    }
+    
+    /// Review an ADK agent config in YAML, TOML, or JSON, chosen by the path's extension
+    pub fn review_agent_config(&self, config_path: &str, config_content: &str) -> Result<agent_config::ConfigReview> {
+        agent_config::review_agent_config(config_path, config_content)
+    }
+    
+    /// Review every `.rs` file and `Cargo.toml` of a project, given as a map of relative path to content
+    ///
+    /// Other files are ignored. Findings are aggregated per file, and the files with the
+    /// most compliance issues, then the most findings, are ranked as hotspots.
+    pub async fn review_project(&self, files: &BTreeMap<String, String>) -> Result<ProjectReview> {
+        let mut reviews = Vec::new();
+        let mut summary = ProjectSummary::default();
+        
+        let crate_roots: Vec<&str> = files.keys().filter(|path| project::is_manifest(path)).map(|path| project::manifest_crate_root(path)).collect();
+        for (path, content) in files.iter().filter(|(path, _)| path.ends_with(".rs")) {
+            let result = self.review_file(path, content).await?;
+            let crate_root = project::owning_crate_root(path, &crate_roots).to_string();
+            reviews.push(FileReview { path: path.clone(), crate_root, result });
+        }
+        if reviews.is_empty() {
+            return Err(anyhow!("The project has no .rs files to review"));
+        }
+        summary.files_reviewed = reviews.len();
+        
+        // Manifests are checked against the sources of their own crate
+        for (path, content) in files.iter().filter(|(path, _)| project::is_manifest(path)) {
+            let crate_root = project::manifest_crate_root(path);
+            let sources: Vec<&str> = reviews
+                .iter()
+                .filter(|review| review.path.ends_with(".rs") && review.crate_root == crate_root)
+                .map(|review| files[&review.path].as_str())
+                .collect();
+            summary.manifests.push(path.clone());
+            let result = self.review_manifest(content, &sources);
+            reviews.push(FileReview { path: path.clone(), crate_root: crate_root.to_string(), result });
+        }
+        reviews.sort_by(|a, b| a.path.cmp(&b.path));
+        
+        // Locked dependencies are audited once across every lockfile
+        let mut locked_packages = Vec::new();
+        let mut project_suggestions = Vec::new();
+        for (path, content) in files.iter().filter(|(path, _)| project::is_lockfile(path)) {
+            summary.lockfiles.push(path.clone());
+            match dependencies::parse_lockfile(content) {
+                Ok(packages) => locked_packages.extend(packages),
+                Err(e) => project_suggestions.push(OrganizationSuggestion {
+                    suggestion_type: "Invalid Lockfile".to_string(),
+                    description: format!("{}: {}", path, e),
+                    action: "Regenerate the lockfile with `cargo generate-lockfile` so dependencies can be audited".to_string(),
+                    rule: "invalid-lockfile",
+                    severity: Severity::Warning,
+                }),
+            }
+        }
+        let dependencies = dependencies::audit_packages(&locked_packages, &self.advisories);
+        summary.dependency_findings = dependencies.len();
+        
+        for review in &reviews {
+            summary.translation_opportunities += review.result.translation_opportunities.len();
+            summary.architectural_improvements += review.result.architectural_improvements.len();
+            summary.compliance_issues += review.result.compliance_issues.len();
+            summary.organization_suggestions += review.result.organization_suggestions.len();
+            summary.manifest_issues += review.result.manifest_issues.len();
+            summary.suppressed_findings += review.result.suppressed.values().sum::<usize>();
+        }
+        summary.hotspots = project::hotspots(&reviews);
+        
+        project_suggestions.extend(project::project_suggestions(&summary));
+        let module_tree = module_tree::analyze_module_tree(files);
+        project_suggestions.extend(module_tree.suggestions);
+        
+        Ok(ProjectReview {
+            project_suggestions,
+            module_trees: module_tree.trees,
+            files: reviews,
+            dependencies,
+            locked_packages,
+            summary,
+        })
+    }
+    
+    /// Review the `.rs` files and `Cargo.toml` manifests a unified diff adds or modifies
+    ///
+    /// Modified files are rebuilt by applying the diff to their content in `base_files`,
+    /// keyed by base path; files missing there, or that the diff does not apply to, are
+    /// skipped with the reason. Only findings touching added lines are kept.
+    pub async fn review_diff(&self, diff: &str, base_files: &BTreeMap<String, String>) -> Result<DiffReview> {
+        let mut review = DiffReview::default();
+        let file_diffs = diff::parse_unified_diff(diff)?;
+        
+        for file_diff in &file_diffs {
+            let Some(path) = file_diff.new_path.as_deref() else {
+                continue;
+            };
+            if !path.ends_with(".rs") && !project::is_manifest(path) {
+                continue;
+            }
+            
+            let base = file_diff.old_path.as_ref().and_then(|old_path| base_files.get(old_path));
+            let content = match file_diff.new_content(base.map(String::as_str)) {
+                Ok(content) => content,
+                Err(e) => {
+                    review.skipped.push((path.to_string(), e.to_string()));
+                    continue;
+                }
+            };
+            let result = if path.ends_with(".rs") {
+                self.review_file(path, &content).await?
+            } else {
+                self.review_manifest(&content, &[])
+            };
+            
+            let added = file_diff.added_lines();
+            review.files.push(DiffFileReview {
+                path: path.to_string(),
+                added_lines: added.len(),
+                result: diff::scope_to_lines(result, &added, file_diff.is_new_file()),
+            });
+        }
+        
+        if review.files.is_empty() && review.skipped.is_empty() {
+            return Err(anyhow!("The diff changes no .rs files or Cargo.toml manifests"));
+        }
+        Ok(review)
+    }
 }
 
 impl Default for CodeReviewEngine {
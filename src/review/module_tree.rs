@@ -0,0 +1,446 @@
+//! Module tree analysis across the Rust files of a project
+//!
+//! Starting from each crate root (`src/lib.rs`, `src/main.rs`, binaries, integration tests,
+//! examples, benchmarks, and build scripts), `mod` declarations are resolved to files the
+//! way rustc does. This finds declarations without a file, source files no crate root
+//! reaches, deeply nested modules, and sibling modules whose `use` declarations import
+//! from each other in a cycle.
+
+use super::analyzer::is_cfg_test;
+use super::{OrganizationSuggestion, Severity};
+use std::collections::{BTreeMap, BTreeSet};
+use syn::ext::IdentExt;
+
+/// Deepest module nesting below a crate root that is not reported
+pub const MAX_MODULE_DEPTH: usize = 4;
+
+/// Directories whose top-level files are crate roots of their own
+const TARGET_DIRECTORIES: &[&str] = &["tests", "examples", "benches"];
+
+/// Where a module's items are defined
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModuleSource {
+    /// Its own file, relative to the project root
+    File(String),
+    /// A `mod name { ... }` block in its parent's file
+    Inline,
+    /// A `mod name;` declaration whose file was not found
+    Missing,
+}
+
+/// Module of a crate, with its submodules in declaration order
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleNode {
+    /// Module name, or `crate` for a crate root
+    pub name: String,
+    /// Where the module is defined
+    pub source: ModuleSource,
+    /// Declared submodules
+    pub children: Vec<ModuleNode>,
+}
+
+impl ModuleNode {
+    /// Render the tree with box-drawing branches, one module per line
+    pub fn render(&self) -> String {
+        let mut output = format!("{}{}\n", self.name, self.label());
+        self.render_children("", &mut output);
+        output
+    }
+    
+    fn render_children(&self, indent: &str, output: &mut String) {
+        for (index, child) in self.children.iter().enumerate() {
+            let last = index + 1 == self.children.len();
+            output.push_str(&format!("{}{}{}{}\n", indent, if last { "└── " } else { "├── " }, child.name, child.label()));
+            child.render_children(&format!("{}{}", indent, if last { "    " } else { "│   " }), output);
+        }
+    }
+    
+    fn label(&self) -> String {
+        match &self.source {
+            ModuleSource::File(path) => format!(" ({})", path),
+            ModuleSource::Inline => " (inline)".to_string(),
+            ModuleSource::Missing => " (missing)".to_string(),
+        }
+    }
+}
+
+/// Module trees of a project and the suggestions found in them
+#[derive(Debug, Default)]
+pub struct ModuleTreeAnalysis {
+    /// One tree per crate root, in path order
+    pub trees: Vec<ModuleNode>,
+    /// Missing and unreachable files, deep nesting, and circular module dependencies
+    pub suggestions: Vec<OrganizationSuggestion>,
+}
+
+/// Module reached while walking a crate
+struct ModuleInfo {
+    /// Path from the crate root, starting with `crate`
+    path: Vec<String>,
+    /// File holding the module's items
+    file: String,
+    /// Whether the module is compiled only for tests
+    test_only: bool,
+    /// Paths of the module's `use` declarations, with their lines
+    uses: Vec<(Vec<String>, usize)>,
+}
+
+/// Walks the `mod` declarations of one crate root at a time
+struct TreeWalker<'a> {
+    files: &'a BTreeMap<String, String>,
+    reached: BTreeSet<String>,
+    modules: Vec<ModuleInfo>,
+    suggestions: Vec<OrganizationSuggestion>,
+}
+
+impl TreeWalker<'_> {
+    /// Walk the items of the module at `self.modules[module]`, returning its submodules
+    ///
+    /// `directory` is where its `mod name;` files live, and `stack` holds the files being
+    /// walked so `#[path]` attributes cannot loop.
+    fn walk_items(&mut self, items: &[syn::Item], module: usize, directory: &str, stack: &mut Vec<String>) -> Vec<ModuleNode> {
+        let mut children = Vec::new();
+        let file = self.modules[module].file.clone();
+        let test_only = self.modules[module].test_only;
+        for item in items {
+            match item {
+                syn::Item::Use(item_use) if !test_only && item_use.leading_colon.is_none() => {
+                    let line = item_use.use_token.span.start().line;
+                    let mut paths = Vec::new();
+                    use_paths(&item_use.tree, &mut Vec::new(), &mut paths);
+                    self.modules[module].uses.extend(paths.into_iter().map(|path| (path, line)));
+                }
+                syn::Item::Mod(item_mod) => {
+                    let name = item_mod.ident.unraw().to_string();
+                    let test_only = test_only || item_mod.attrs.iter().any(is_cfg_test);
+                    let mut path = self.modules[module].path.clone();
+                    path.push(name.clone());
+                    
+                    if let Some((_, items)) = &item_mod.content {
+                        // Inline test modules are part of the file they test
+                        if test_only && !self.modules[module].test_only {
+                            continue;
+                        }
+                        self.modules.push(ModuleInfo { path, file: file.clone(), test_only, uses: Vec::new() });
+                        let index = self.modules.len() - 1;
+                        let children_of = self.walk_items(items, index, &format!("{}{}/", directory, name), stack);
+                        children.push(ModuleNode { name, source: ModuleSource::Inline, children: children_of });
+                        continue;
+                    }
+                    
+                    let line = item_mod.ident.span().start().line;
+                    let Some(module_file) = self.resolve_file(item_mod, &name, &file, directory, line) else {
+                        children.push(ModuleNode { name, source: ModuleSource::Missing, children: Vec::new() });
+                        continue;
+                    };
+                    self.reached.insert(module_file.clone());
+                    if stack.contains(&module_file) {
+                        children.push(ModuleNode { name, source: ModuleSource::File(module_file), children: Vec::new() });
+                        continue;
+                    }
+                    
+                    let items = syn::parse_file(&self.files[&module_file]).map(|parsed| parsed.items).unwrap_or_default();
+                    let module_directory = if module_file.ends_with("/mod.rs") || module_file == "mod.rs" || path_attribute(item_mod).is_some() {
+                        parent_directory(&module_file)
+                    } else {
+                        format!("{}/", module_file.trim_end_matches(".rs"))
+                    };
+                    self.modules.push(ModuleInfo { path, file: module_file.clone(), test_only, uses: Vec::new() });
+                    let index = self.modules.len() - 1;
+                    stack.push(module_file.clone());
+                    let children_of = self.walk_items(&items, index, &module_directory, stack);
+                    stack.pop();
+                    children.push(ModuleNode { name, source: ModuleSource::File(module_file), children: children_of });
+                }
+                _ => {}
+            }
+        }
+        children
+    }
+    
+    /// File of a `mod name;` declaration, reporting it when none or both candidates exist
+    fn resolve_file(&mut self, item_mod: &syn::ItemMod, name: &str, file: &str, directory: &str, line: usize) -> Option<String> {
+        let candidates = match path_attribute(item_mod) {
+            Some(path) => vec![normalize(&format!("{}{}", parent_directory(file), path))],
+            None => vec![format!("{}{}.rs", directory, name), format!("{}{}/mod.rs", directory, name)],
+        };
+        let found: Vec<&String> = candidates.iter().filter(|candidate| self.files.contains_key(*candidate)).collect();
+        match found.as_slice() {
+            [] => {
+                self.suggestions.push(OrganizationSuggestion {
+                    suggestion_type: "Missing Module File".to_string(),
+                    description: format!("`mod {};` in {} (line {}) has no file: expected {}", name, file, line, candidates.join(" or ")),
+                    action: "Add the module's file, or remove the declaration if the module was deleted".to_string(),
+                    rule: "missing-module-file",
+                    severity: Severity::Warning,
+                });
+                None
+            }
+            [single] => Some((*single).clone()),
+            [first, second, ..] => {
+                self.suggestions.push(OrganizationSuggestion {
+                    suggestion_type: "Ambiguous Module File".to_string(),
+                    description: format!("`mod {};` in {} (line {}) matches both {} and {}, which rustc rejects", name, file, line, first, second),
+                    action: format!("Keep only one of {} and {}", first, second),
+                    rule: "ambiguous-module-file",
+                    severity: Severity::Warning,
+                });
+                self.reached.insert((*second).clone());
+                Some((*first).clone())
+            }
+        }
+    }
+}
+
+/// Build the module tree of every crate root in a project and report problems in it
+///
+/// Returns no trees when the files include no crate root, e.g. a handful of loose modules.
+pub fn analyze_module_tree(files: &BTreeMap<String, String>) -> ModuleTreeAnalysis {
+    let mut walker = TreeWalker { files, reached: BTreeSet::new(), modules: Vec::new(), suggestions: Vec::new() };
+    let mut trees = Vec::new();
+    
+    for root in files.keys().filter(|path| is_crate_root(path)) {
+        let items = syn::parse_file(&files[root]).map(|parsed| parsed.items).unwrap_or_default();
+        walker.reached.insert(root.clone());
+        walker.modules = vec![ModuleInfo { path: vec!["crate".to_string()], file: root.clone(), test_only: false, uses: Vec::new() }];
+        let children = walker.walk_items(&items, 0, &parent_directory(root), &mut vec![root.clone()]);
+        
+        let suggestions = depth_suggestions(&walker.modules, root).into_iter().chain(circular_dependencies(&walker.modules));
+        walker.suggestions.extend(suggestions);
+        if root.contains("src/") || !children.is_empty() {
+            trees.push(ModuleNode { name: "crate".to_string(), source: ModuleSource::File(root.clone()), children });
+        }
+    }
+    
+    for path in files.keys().filter(|path| path.ends_with(".rs") && !walker.reached.contains(*path)) {
+        let Some(crate_prefix) = source_crate_prefix(path) else {
+            continue;
+        };
+        let roots: Vec<String> = ["src/lib.rs", "src/main.rs"]
+            .iter()
+            .map(|root| format!("{}{}", crate_prefix, root))
+            .filter(|root| files.contains_key(root))
+            .collect();
+        if roots.is_empty() {
+            continue;
+        }
+        walker.suggestions.push(OrganizationSuggestion {
+            suggestion_type: "Unreachable File".to_string(),
+            description: format!("{} is not reached by any `mod` declaration from {}, so it is never compiled", path, roots.join(" or ")),
+            action: "Declare it with `mod` in its parent module, or delete it if it is dead code".to_string(),
+            rule: "unreachable-file",
+            severity: Severity::Warning,
+        });
+    }
+    
+    // Modules shared by several roots, such as a library and binary declaring the same
+    // `mod`, would otherwise be reported once per root
+    let mut seen = BTreeSet::new();
+    walker.suggestions.retain(|suggestion| seen.insert(suggestion.description.clone()));
+    ModuleTreeAnalysis { trees, suggestions: walker.suggestions }
+}
+
+/// Modules nested deeper than `MAX_MODULE_DEPTH` below their crate root, reported where
+/// the nesting first exceeds it
+fn depth_suggestions(modules: &[ModuleInfo], root: &str) -> Vec<OrganizationSuggestion> {
+    modules
+        .iter()
+        .filter(|module| !module.test_only && module.path.len() == MAX_MODULE_DEPTH + 2)
+        .map(|module| OrganizationSuggestion {
+            suggestion_type: "Deep Module Nesting".to_string(),
+            description: format!(
+                "`{}` ({}) is nested {} levels below the crate root ({}), past the {} that keep paths short and the layout easy to navigate",
+                module.path.join("::"),
+                module.file,
+                module.path.len() - 1,
+                root,
+                MAX_MODULE_DEPTH
+            ),
+            action: "Flatten the hierarchy by merging small leaf modules into their parents, or move the subtree into its own crate".to_string(),
+            rule: "module-depth",
+            severity: Severity::Info,
+        })
+        .collect()
+}
+
+/// Groups of sibling modules whose `use` declarations import from each other in a cycle
+///
+/// Each import is lifted to the children of the closest module containing both ends, so
+/// `crate::a::x` importing `crate::b::Item` counts as `crate::a` depending on `crate::b`.
+/// Imports between a module and its ancestors are ignored, as are test-only modules.
+fn circular_dependencies(modules: &[ModuleInfo]) -> Vec<OrganizationSuggestion> {
+    let known: BTreeSet<&[String]> = modules.iter().filter(|module| !module.test_only).map(|module| module.path.as_slice()).collect();
+    let mut edges: BTreeMap<(Vec<String>, Vec<String>), (String, usize)> = BTreeMap::new();
+    for module in modules.iter().filter(|module| !module.test_only) {
+        for (path, line) in &module.uses {
+            let Some(target) = resolve_use(&module.path, path, &known) else {
+                continue;
+            };
+            let common = module.path.iter().zip(&target).take_while(|(a, b)| a == b).count();
+            if common == module.path.len() || common == target.len() {
+                continue;
+            }
+            let edge = (module.path[..=common].to_vec(), target[..=common].to_vec());
+            edges.entry(edge).or_insert_with(|| (module.file.clone(), *line));
+        }
+    }
+    
+    let mut adjacency: BTreeMap<&Vec<String>, Vec<&Vec<String>>> = BTreeMap::new();
+    for (from, to) in edges.keys() {
+        adjacency.entry(from).or_default().push(to);
+    }
+    let reachable = |start: &Vec<String>| {
+        let mut seen = BTreeSet::new();
+        let mut pending = vec![start];
+        while let Some(node) = pending.pop() {
+            for next in adjacency.get(node).into_iter().flatten() {
+                if seen.insert(*next) {
+                    pending.push(*next);
+                }
+            }
+        }
+        seen
+    };
+    let reach: BTreeMap<&Vec<String>, BTreeSet<&Vec<String>>> = adjacency.keys().map(|node| (*node, reachable(node))).collect();
+    
+    let mut grouped = BTreeSet::new();
+    let mut suggestions = Vec::new();
+    for node in reach.keys() {
+        if grouped.contains(node) {
+            continue;
+        }
+        let group: Vec<&Vec<String>> = reach
+            .keys()
+            .filter(|other| reach[node].contains(*other) && reach[*other].contains(*node))
+            .copied()
+            .collect();
+        grouped.extend(group.iter().copied());
+        if group.len() < 2 {
+            continue;
+        }
+        let imports: Vec<String> = edges
+            .iter()
+            .filter(|((from, to), _)| group.contains(&from) && group.contains(&to))
+            .map(|((from, to), (file, line))| format!("`{}` → `{}` ({} line {})", from.join("::"), to.join("::"), file, line))
+            .collect();
+        let names: Vec<String> = group.iter().map(|path| format!("`{}`", path.join("::"))).collect();
+        suggestions.push(OrganizationSuggestion {
+            suggestion_type: "Circular Module Dependency".to_string(),
+            description: format!("Modules {} import from each other: {}", names.join(", "), imports.join(", ")),
+            action: "Move the items the modules share into a module they can all import, so dependencies point one way".to_string(),
+            rule: "circular-modules",
+            severity: Severity::Warning,
+        });
+    }
+    suggestions
+}
+
+/// Absolute module path of a `use` path written in `current`, or `None` for external crates
+fn resolve_use(current: &[String], path: &[String], known: &BTreeSet<&[String]>) -> Option<Vec<String>> {
+    let (mut absolute, rest) = match path.first()?.as_str() {
+        "crate" => (vec!["crate".to_string()], &path[1..]),
+        "self" => (current.to_vec(), &path[1..]),
+        "super" => {
+            let supers = path.iter().take_while(|segment| *segment == "super").count();
+            (current[..current.len().checked_sub(supers).filter(|len| *len > 0)?].to_vec(), &path[supers..])
+        }
+        first => {
+            let mut child = current.to_vec();
+            child.push(first.to_string());
+            if !known.contains(child.as_slice()) {
+                return None;
+            }
+            (current.to_vec(), path)
+        }
+    };
+    absolute.extend(rest.iter().cloned());
+    
+    // Keep the longest prefix naming a module, dropping the imported item
+    let len = (1..=absolute.len()).rev().find(|len| known.contains(&absolute[..*len]))?;
+    absolute.truncate(len);
+    Some(absolute)
+}
+
+/// Paths named by a `use` tree, with globs and groups expanded
+fn use_paths(tree: &syn::UseTree, prefix: &mut Vec<String>, paths: &mut Vec<Vec<String>>) {
+    match tree {
+        syn::UseTree::Path(path) => {
+            prefix.push(path.ident.to_string());
+            use_paths(&path.tree, prefix, paths);
+            prefix.pop();
+        }
+        syn::UseTree::Name(name) => paths.push(extend(prefix, &name.ident)),
+        syn::UseTree::Rename(rename) => paths.push(extend(prefix, &rename.ident)),
+        syn::UseTree::Glob(_) => paths.push(prefix.clone()),
+        syn::UseTree::Group(group) => {
+            for tree in &group.items {
+                use_paths(tree, prefix, paths);
+            }
+        }
+    }
+}
+
+/// `prefix` followed by `ident`, where `self` names the prefix itself
+fn extend(prefix: &[String], ident: &syn::Ident) -> Vec<String> {
+    let mut path = prefix.to_vec();
+    if ident != "self" {
+        path.push(ident.to_string());
+    }
+    path
+}
+
+/// Value of a `#[path = "..."]` attribute
+fn path_attribute(item_mod: &syn::ItemMod) -> Option<String> {
+    item_mod.attrs.iter().find(|attr| attr.path().is_ident("path")).and_then(|attr| match &attr.meta {
+        syn::Meta::NameValue(syn::MetaNameValue { value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(path), .. }), .. }) => Some(path.value()),
+        _ => None,
+    })
+}
+
+/// Whether a path names a file rustc compiles as a crate root
+fn is_crate_root(path: &str) -> bool {
+    let segments: Vec<&str> = path.split('/').collect();
+    let parents = &segments[..segments.len() - 1];
+    let parent = |depth: usize| parents.len().checked_sub(depth).map(|index| parents[index]);
+    let outside_src = !parents.contains(&"src");
+    match segments[segments.len() - 1] {
+        "build.rs" => outside_src,
+        "lib.rs" => parent(1) == Some("src"),
+        "main.rs" if parent(1) == Some("src") => true,
+        // Multi-file binaries, examples, tests, and benchmarks
+        "main.rs" if parent(3) == Some("src") && parent(2) == Some("bin") => true,
+        "main.rs" if parent(2).is_some_and(|dir| TARGET_DIRECTORIES.contains(&dir)) && !parents[..parents.len() - 2].contains(&"src") => true,
+        name if name.ends_with(".rs") => {
+            (parent(2) == Some("src") && parent(1) == Some("bin")) || (parent(1).is_some_and(|dir| TARGET_DIRECTORIES.contains(&dir)) && outside_src)
+        }
+        _ => false,
+    }
+}
+
+/// Path prefix of the crate whose `src/` directory holds `path`, e.g. "" or "crates/core/"
+fn source_crate_prefix(path: &str) -> Option<&str> {
+    if path.starts_with("src/") {
+        return Some("");
+    }
+    path.find("/src/").map(|index| &path[..=index])
+}
+
+/// Directory of a file, with a trailing `/` unless it is the project root
+fn parent_directory(file: &str) -> String {
+    file.rfind('/').map(|index| file[..=index].to_string()).unwrap_or_default()
+}
+
+/// Resolve `.` and `..` segments of a relative path
+fn normalize(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
@@ -0,0 +1,237 @@
+//! Whole-project review across Rust files, Cargo manifests, and lockfiles
+
+use super::dependencies::{DependencyFinding, LockedPackage};
+use super::module_tree::ModuleNode;
+use super::{OrganizationSuggestion, ReviewResult, Severity};
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tracing::warn;
+
+/// Most files reviewed in one project
+pub const MAX_PROJECT_FILES: usize = 500;
+
+/// Largest file read from a workspace, in bytes
+pub const MAX_PROJECT_FILE_BYTES: u64 = 1024 * 1024;
+
+/// Files ranked as hotspots in the project summary
+pub const MAX_HOTSPOTS: usize = 5;
+
+/// Directories skipped when walking a workspace
+const SKIPPED_DIRECTORIES: &[&str] = &["target", "node_modules"];
+
+/// Review of one file of a project
+#[derive(Debug)]
+pub struct FileReview {
+    /// Path relative to the project root, with `/` separators
+    pub path: String,
+    /// Directory of the crate the file belongs to, relative to the project root ("." for the root crate)
+    pub crate_root: String,
+    /// Findings for the file
+    pub result: ReviewResult,
+}
+
+/// File with the most findings in a project
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hotspot {
+    /// Path relative to the project root
+    pub path: String,
+    /// ADK compliance issues in the file
+    pub compliance_issues: usize,
+    /// Findings of every category in the file
+    pub total_findings: usize,
+}
+
+/// Project-level totals and hotspots
+#[derive(Debug, Default)]
+pub struct ProjectSummary {
+    /// Rust files reviewed, not counting manifests
+    pub files_reviewed: usize,
+    /// Cargo manifests found, relative to the project root
+    pub manifests: Vec<String>,
+    /// Translation opportunities across all files
+    pub translation_opportunities: usize,
+    /// Architectural improvements across all files
+    pub architectural_improvements: usize,
+    /// ADK compliance issues across all files
+    pub compliance_issues: usize,
+    /// File organization suggestions across all files
+    pub organization_suggestions: usize,
+    /// Cargo manifest issues across all manifests
+    pub manifest_issues: usize,
+    /// Lockfiles found, relative to the project root
+    pub lockfiles: Vec<String>,
+    /// Advisory and yanked-release findings for locked dependencies
+    pub dependency_findings: usize,
+    /// Findings silenced by `arkaft-ignore` comments across all files
+    pub suppressed_findings: usize,
+    /// Files with the most findings, worst first
+    pub hotspots: Vec<Hotspot>,
+}
+
+/// Results of a whole-project review
+#[derive(Debug)]
+pub struct ProjectReview {
+    /// Per-file results for Rust files and manifests, in path order
+    pub files: Vec<FileReview>,
+    /// Suggestions about the project as a whole, including its module trees
+    pub project_suggestions: Vec<OrganizationSuggestion>,
+    /// Module tree of each crate root, in path order
+    pub module_trees: Vec<ModuleNode>,
+    /// Findings for locked dependencies, vulnerabilities first
+    pub dependencies: Vec<DependencyFinding>,
+    /// Packages pinned by the project's lockfiles
+    pub locked_packages: Vec<LockedPackage>,
+    /// Totals and hotspots
+    pub summary: ProjectSummary,
+}
+
+impl ProjectReview {
+    /// Add dependency findings found after the review, such as yanked releases
+    pub fn add_dependency_findings(&mut self, findings: Vec<DependencyFinding>) {
+        self.summary.dependency_findings += findings.len();
+        self.dependencies.extend(findings);
+    }
+}
+
+/// Files with findings, ranked by compliance issues and then by total findings
+pub(crate) fn hotspots(reviews: &[FileReview]) -> Vec<Hotspot> {
+    let mut hotspots: Vec<Hotspot> = reviews
+        .iter()
+        .map(|review| Hotspot {
+            path: review.path.clone(),
+            compliance_issues: review.result.compliance_issues.len(),
+            total_findings: review.result.finding_count(),
+        })
+        .filter(|hotspot| hotspot.total_findings > 0)
+        .collect();
+    hotspots.sort_by(|a, b| {
+        (b.compliance_issues, b.total_findings).cmp(&(a.compliance_issues, a.total_findings)).then_with(|| a.path.cmp(&b.path))
+    });
+    hotspots.truncate(MAX_HOTSPOTS);
+    hotspots
+}
+
+/// Suggestions about a project as a whole
+pub(crate) fn project_suggestions(summary: &ProjectSummary) -> Vec<OrganizationSuggestion> {
+    let mut suggestions = Vec::new();
+    if summary.manifests.is_empty() {
+        suggestions.push(OrganizationSuggestion {
+            suggestion_type: "Project Manifest".to_string(),
+            description: "No Cargo.toml was found in the project".to_string(),
+            action: "Include the project's Cargo.toml so dependencies, features, and metadata can be reviewed with the code".to_string(),
+            rule: "project-manifest",
+            severity: Severity::Info,
+        });
+    }
+    if !summary.manifests.is_empty() && summary.lockfiles.is_empty() {
+        suggestions.push(OrganizationSuggestion {
+            suggestion_type: "Dependency Lockfile".to_string(),
+            description: "No Cargo.lock was found, so dependencies could not be audited for advisories".to_string(),
+            action: "Include the project's Cargo.lock so locked dependency versions can be checked".to_string(),
+            rule: "dependency-lockfile",
+            severity: Severity::Info,
+        });
+    }
+    suggestions
+}
+
+/// Whether a path names a Cargo manifest
+pub(crate) fn is_manifest(path: &str) -> bool {
+    path == "Cargo.toml" || path.ends_with("/Cargo.toml")
+}
+
+/// Directory of the crate a manifest describes, relative to the project root ("." for the root manifest)
+pub(crate) fn manifest_crate_root(manifest: &str) -> &str {
+    match manifest.trim_end_matches("Cargo.toml").trim_end_matches('/') {
+        "" => ".",
+        directory => directory,
+    }
+}
+
+/// Innermost of `crate_roots` containing `path`, falling back to the root crate
+pub(crate) fn owning_crate_root<'a>(path: &str, crate_roots: &[&'a str]) -> &'a str {
+    crate_roots
+        .iter()
+        .copied()
+        .filter(|root| *root != "." && path.strip_prefix(*root).is_some_and(|rest| rest.starts_with('/')))
+        .max_by_key(|root| root.len())
+        .unwrap_or(".")
+}
+
+/// Whether a path names a Cargo lockfile
+pub(crate) fn is_lockfile(path: &str) -> bool {
+    path == "Cargo.lock" || path.ends_with("/Cargo.lock")
+}
+
+/// Read the `.rs` files, `Cargo.toml` manifests, and `Cargo.lock` files under a workspace root
+///
+/// Hidden directories, `target`, and `node_modules` are skipped, as are files larger than
+/// `MAX_PROJECT_FILE_BYTES`. Subdirectories and files that cannot be read, including files
+/// that are not UTF-8, are skipped with a warning. Fails when the root is not a readable
+/// directory or holds more than `MAX_PROJECT_FILES` reviewable files.
+pub fn collect_project_files(root: &Path) -> Result<BTreeMap<String, String>> {
+    if !root.is_dir() {
+        return Err(anyhow!("Workspace root {} is not a directory", root.display()));
+    }
+    
+    let mut files = BTreeMap::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(directory) = pending.pop() {
+        let entries = match std::fs::read_dir(&directory) {
+            Ok(entries) => entries,
+            Err(e) if directory != root => {
+                warn!("Skipping unreadable directory {}: {}", directory.display(), e);
+                continue;
+            }
+            Err(e) => return Err(anyhow!("Failed to read workspace root {}: {}", root.display(), e)),
+        };
+        for entry in entries {
+            let (entry, file_type) = match entry.and_then(|entry| entry.file_type().map(|file_type| (entry, file_type))) {
+                Ok(entry) => entry,
+                Err(e) => {
+                    warn!("Skipping unreadable entry in {}: {}", directory.display(), e);
+                    continue;
+                }
+            };
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            
+            if file_type.is_dir() {
+                if !name.starts_with('.') && !SKIPPED_DIRECTORIES.contains(&name.as_str()) {
+                    pending.push(path);
+                }
+                continue;
+            }
+            
+            let reviewable = name == "Cargo.toml" || name == "Cargo.lock" || name.ends_with(".rs");
+            if !file_type.is_file() || !reviewable {
+                continue;
+            }
+            match entry.metadata() {
+                Ok(metadata) if metadata.len() > MAX_PROJECT_FILE_BYTES => continue,
+                Ok(_) => {}
+                Err(e) => {
+                    warn!("Skipping {}: {}", path.display(), e);
+                    continue;
+                }
+            }
+            if files.len() == MAX_PROJECT_FILES {
+                return Err(anyhow!("Workspace root {} has more than {} reviewable files", root.display(), MAX_PROJECT_FILES));
+            }
+            
+            let relative = path.strip_prefix(root)?.components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            match std::fs::read_to_string(&path) {
+                Ok(content) => {
+                    files.insert(relative, content);
+                }
+                Err(e) => warn!("Skipping {}: {}", path.display(), e),
+            }
+        }
+    }
+    
+    Ok(files)
+}
@@ -1,22 +1,55 @@
 //! Improvement suggestions generation for code review
 
 use super::ReviewResult;
+use super::agent_config::ConfigReview;
+use super::diff::DiffReview;
+use super::project::ProjectReview;
 
-/// Generate formatted suggestions from review results
+/// Generate formatted suggestions from review results, followed by the function metrics table
 pub fn format_review_suggestions(result: &ReviewResult) -> String {
-    let mut output = String::new();
+    let mut output = format!("# Rust File Review Results\n\n{}", format_findings(result));
+    
+    if !result.function_metrics.is_empty() {
+        if !output.ends_with("\n\n") {
+            output.push('\n');
+        }
+        output.push_str("## Function Metrics\n\n| Function | Line | Lines | Parameters | Cyclomatic Complexity | Nesting Depth |\n|----------|------|-------|------------|-----------------------|---------------|\n");
+        for metrics in &result.function_metrics {
+            output.push_str(&format!(
+                "| `{}` | {} | {} | {} | {} | {} |\n",
+                metrics.name,
+                metrics.span.start_line,
+                metrics.lines,
+                metrics.parameters,
+                metrics.cyclomatic_complexity,
+                metrics.nesting_depth
+            ));
+        }
+    }
     
-    output.push_str("# Rust File Review Results\n\n");
+    output
+}
+
+/// Format the findings of a review, one section per category
+fn format_findings(result: &ReviewResult) -> String {
+    let mut output = String::new();
     
     if !result.translation_opportunities.is_empty() {
         output.push_str("## Translation Opportunities\n\n");
         for opportunity in &result.translation_opportunities {
             output.push_str(&format!(
-                "**Line {}**: {}\n*Suggestion*: {}\n\n",
+                "**Line {}, column {}** [{}, {}]: {}\n*Suggestion*: {}\n",
                 opportunity.line,
+                opportunity.span.start_column,
+                opportunity.severity,
+                opportunity.rule,
                 opportunity.description,
                 opportunity.suggestion
             ));
+            if let Some(fix) = &opportunity.fix {
+                output.push_str(&format!("*Autofix*: {}\n```diff\n{}```\n", fix.description, fix.patch));
+            }
+            output.push('\n');
         }
     }
     
@@ -24,8 +57,10 @@ pub fn format_review_suggestions(result: &ReviewResult) -> String {
         output.push_str("## Architectural Improvements\n\n");
         for improvement in &result.architectural_improvements {
             output.push_str(&format!(
-                "**{}**\n*Current*: {}\n*Recommended*: {}\n*Rationale*: {}\n\n",
+                "**{}** [{}, {}]\n*Current*: {}\n*Recommended*: {}\n*Rationale*: {}\n\n",
                 improvement.area,
+                improvement.severity,
+                improvement.rule,
                 improvement.current_pattern,
                 improvement.recommended_pattern,
                 improvement.rationale
@@ -37,8 +72,35 @@ pub fn format_review_suggestions(result: &ReviewResult) -> String {
         output.push_str("## ADK Compliance Issues\n\n");
         for issue in &result.compliance_issues {
             output.push_str(&format!(
-                "**{}**: {}\n*Fix*: {}\n\n",
+                "**{}** [{}, {}]: {}\n*Fix*: {}\n",
+                issue.issue_type,
+                issue.severity,
+                issue.rule,
+                issue.description,
+                issue.fix_suggestion
+            ));
+            if !issue.locations.is_empty() {
+                let locations: Vec<String> = issue.locations.iter().map(|span| span.to_string()).collect();
+                output.push_str(&format!("*Locations*: {}\n", locations.join(", ")));
+            }
+            for fix in &issue.fixes {
+                output.push_str(&format!("*Autofix* ({}): {}\n```diff\n{}```\n", fix.span, fix.description, fix.patch));
+            }
+            output.push('\n');
+        }
+    }
+    
+    if !result.manifest_issues.is_empty() {
+        output.push_str("## Manifest Issues\n\n");
+        for issue in &result.manifest_issues {
+            let line = issue.line.map(|line| format!(" (line {})", line)).unwrap_or_default();
+            let key = if issue.key.is_empty() { String::new() } else { format!(" `{}`", issue.key) };
+            output.push_str(&format!(
+                "**{}** [{}]{}{}: {}\n*Fix*: {}\n\n",
                 issue.issue_type,
+                issue.severity,
+                key,
+                line,
                 issue.description,
                 issue.fix_suggestion
             ));
@@ -49,8 +111,10 @@ pub fn format_review_suggestions(result: &ReviewResult) -> String {
         output.push_str("## File Organization Suggestions\n\n");
         for suggestion in &result.organization_suggestions {
             output.push_str(&format!(
-                "**{}**: {}\n*Action*: {}\n\n",
+                "**{}** [{}, {}]: {}\n*Action*: {}\n\n",
                 suggestion.suggestion_type,
+                suggestion.severity,
+                suggestion.rule,
                 suggestion.description,
                 suggestion.action
             ));
@@ -60,9 +124,173 @@ pub fn format_review_suggestions(result: &ReviewResult) -> String {
     if result.translation_opportunities.is_empty() 
         && result.architectural_improvements.is_empty()
         && result.compliance_issues.is_empty()
-        && result.organization_suggestions.is_empty() {
+        && result.organization_suggestions.is_empty()
+        && result.manifest_issues.is_empty() {
         output.push_str("No issues found. The code appears to follow good practices.\n");
     }
     
+    if let Some(formatted) = result.formatted {
+        output.push_str(if formatted { "\n**Formatting:** matches rustfmt\n" } else { "\n**Formatting:** differs from rustfmt\n" });
+    }
+    
+    if !result.suppressed.is_empty() {
+        let rules: Vec<String> = result.suppressed.iter().map(|(rule, count)| format!("{}: {}", rule, count)).collect();
+        output.push_str(&format!(
+            "\n**Suppressed:** {} ({})\n",
+            result.suppressed.values().sum::<usize>(),
+            rules.join(", ")
+        ));
+    }
+    
+    output
+}
+/// Generate a project summary with hotspots, followed by each file's findings
+pub fn format_project_review(review: &ProjectReview) -> String {
+    let summary = &review.summary;
+    let mut output = format!(
+        "# Rust Project Review Results\n\n**Files Reviewed:** {}\n**Manifests:** {}\n**Lockfiles:** {}\n**Suppressed Findings:** {}\n\n\
+        | Category | Findings |\n|----------|----------|\n\
+        | Translation Opportunities | {} |\n| Architectural Improvements | {} |\n\
+        | ADK Compliance Issues | {} |\n| File Organization Suggestions | {} |\n| Manifest Issues | {} |\n\
+        | Dependencies | {} |\n\n",
+        summary.files_reviewed,
+        if summary.manifests.is_empty() { "none".to_string() } else { summary.manifests.join(", ") },
+        if summary.lockfiles.is_empty() { "none".to_string() } else { summary.lockfiles.join(", ") },
+        summary.suppressed_findings,
+        summary.translation_opportunities,
+        summary.architectural_improvements,
+        summary.compliance_issues,
+        summary.organization_suggestions,
+        summary.manifest_issues,
+        summary.dependency_findings
+    );
+    
+    if !summary.hotspots.is_empty() {
+        output.push_str("## Hotspots\n\n| File | Compliance Issues | Findings |\n|------|-------------------|----------|\n");
+        for hotspot in &summary.hotspots {
+            output.push_str(&format!("| `{}` | {} | {} |\n", hotspot.path, hotspot.compliance_issues, hotspot.total_findings));
+        }
+        output.push('\n');
+    }
+    
+    if !review.dependencies.is_empty() {
+        output.push_str("## Dependencies\n\n| Crate | Version | Finding | Advisory | Upgrade |\n|-------|---------|---------|----------|---------|\n");
+        for finding in &review.dependencies {
+            let advisory = finding
+                .advisory_id
+                .as_ref()
+                .map(|id| format!("[{}](https://rustsec.org/advisories/{}): {}", id, id, finding.title))
+                .unwrap_or_else(|| finding.title.clone());
+            output.push_str(&format!(
+                "| `{}` | {} | {} | {} | {} |\n",
+                finding.package,
+                finding.version,
+                finding.kind,
+                advisory,
+                finding.suggestion
+            ));
+        }
+        output.push('\n');
+    }
+    
+    if !review.project_suggestions.is_empty() {
+        output.push_str("## Project Suggestions\n\n");
+        for suggestion in &review.project_suggestions {
+            output.push_str(&format!(
+                "**{}** [{}, {}]: {}\n*Action*: {}\n\n",
+                suggestion.suggestion_type,
+                suggestion.severity,
+                suggestion.rule,
+                suggestion.description,
+                suggestion.action
+            ));
+        }
+    }
+    
+    if !review.module_trees.is_empty() {
+        output.push_str("## Module Tree\n\n```text\n");
+        for tree in &review.module_trees {
+            output.push_str(&tree.render());
+        }
+        output.push_str("```\n\n");
+    }
+    
+    for file in review.files.iter().filter(|file| file.result.finding_count() > 0) {
+        push_file_section(&mut output, &file.path, &file.result);
+    }
+    
+    output
+}
+
+/// Format an agent config review: declared agents, referenced environment variables, and issues
+pub fn format_config_review(path: &str, review: &ConfigReview) -> String {
+    let mut output = format!(
+        "# Agent Config Review Results\n\n**File:** `{}` ({})\n**Agents:** {}\n**Environment Variables:** {}\n\n",
+        path,
+        review.format.label(),
+        if review.agents.is_empty() { "none".to_string() } else { review.agents.join(", ") },
+        if review.env_vars.is_empty() { "none".to_string() } else { review.env_vars.join(", ") }
+    );
+    
+    if review.issues.is_empty() {
+        output.push_str("No issues found. The agent config follows the expected schema.\n");
+        return output;
+    }
+    
+    output.push_str("## Config Issues\n\n");
+    for issue in &review.issues {
+        let line = issue.line.map(|line| format!(" (line {})", line)).unwrap_or_default();
+        let key = if issue.key.is_empty() { String::new() } else { format!(" `{}`", issue.key) };
+        output.push_str(&format!(
+            "**{}** [{}, {}]{}{}: {}\n*Fix*: {}\n\n",
+            issue.issue_type,
+            issue.severity,
+            issue.rule,
+            key,
+            line,
+            issue.description,
+            issue.fix_suggestion
+        ));
+    }
+    
+    output
+}
+
+/// Format a diff review: totals, skipped files, and the findings on changed lines per file
+pub fn format_diff_review(review: &DiffReview) -> String {
+    let mut output = format!(
+        "# Rust Diff Review Results\n\n**Files Reviewed:** {}\n**Lines Added:** {}\n**Findings on Changed Lines:** {}\n\n",
+        review.files.len(),
+        review.files.iter().map(|file| file.added_lines).sum::<usize>(),
+        review.finding_count()
+    );
+    
+    if !review.skipped.is_empty() {
+        output.push_str("## Skipped Files\n\n");
+        for (path, reason) in &review.skipped {
+            output.push_str(&format!("- `{}`: {}\n", path, reason));
+        }
+        output.push('\n');
+    }
+    
+    if review.finding_count() == 0 {
+        output.push_str("No issues found on the changed lines.\n");
+    }
+    for file in review.files.iter().filter(|file| file.result.finding_count() > 0) {
+        push_file_section(&mut output, &file.path, &file.result);
+    }
+    
     output
-}
\ No newline at end of file
+}
+
+/// Append a file's findings under a heading naming the file, its sections one heading level down
+fn push_file_section(output: &mut String, path: &str, result: &ReviewResult) {
+    output.push_str(&format!("---\n\n## `{}`\n\n", path));
+    for line in format_findings(result).lines() {
+        if line.starts_with('#') {
+            output.push('#');
+        }
+        output.push_str(line);
+        output.push('\n');
+    }
+}
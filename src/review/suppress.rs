@@ -0,0 +1,137 @@
+//! Inline suppression of review findings with `arkaft-ignore` comments
+//!
+//! `// arkaft-ignore: unwrap, panic` silences the named rules on its own line and the line
+//! after it, so it works both trailing a statement and on the line above one. A file-level
+//! `// arkaft-ignore-file: missing-docs` silences rules across the whole file, including
+//! findings without a location such as architectural improvements. Each rule may be
+//! followed by a reason, and the rule `all` matches every rule.
+
+use super::{ComplianceIssue, ReviewResult};
+use std::collections::BTreeMap;
+
+/// Comment marker silencing rules on its line and the next one
+pub const IGNORE_MARKER: &str = "arkaft-ignore:";
+
+/// Comment marker silencing rules in the whole file
+pub const IGNORE_FILE_MARKER: &str = "arkaft-ignore-file:";
+
+/// Rules silenced by the `arkaft-ignore` comments of a file
+#[derive(Debug, Default, PartialEq)]
+pub struct Suppressions {
+    /// Rules silenced across the file
+    pub file_rules: Vec<String>,
+    /// Rules silenced per line (1-based)
+    pub line_rules: BTreeMap<usize, Vec<String>>,
+}
+
+impl Suppressions {
+    /// Collect the `arkaft-ignore` and `arkaft-ignore-file` comments of Rust source
+    pub fn parse(content: &str) -> Self {
+        let mut suppressions = Self::default();
+        for (index, line) in content.lines().enumerate() {
+            let Some(start) = line.find("//") else {
+                continue;
+            };
+            let comment = line[start..].trim_start_matches(['/', '!']).trim_start();
+            
+            if let Some(rules) = comment.strip_prefix(IGNORE_FILE_MARKER) {
+                suppressions.file_rules.extend(parse_rules(rules));
+            } else if let Some(rules) = comment.strip_prefix(IGNORE_MARKER) {
+                let rules = parse_rules(rules);
+                for line in [index + 1, index + 2] {
+                    suppressions.line_rules.entry(line).or_default().extend(rules.iter().cloned());
+                }
+            }
+        }
+        suppressions
+    }
+    
+    /// Whether the file has no `arkaft-ignore` comments
+    pub fn is_empty(&self) -> bool {
+        self.file_rules.is_empty() && self.line_rules.is_empty()
+    }
+    
+    /// Whether `rule` is silenced for the whole file
+    pub fn suppresses_file(&self, rule: &str) -> bool {
+        matches_rule(&self.file_rules, rule)
+    }
+    
+    /// Whether `rule` is silenced on `line`
+    pub fn suppresses(&self, rule: &str, line: usize) -> bool {
+        self.suppresses_file(rule) || self.line_rules.get(&line).is_some_and(|rules| matches_rule(rules, rule))
+    }
+}
+
+/// Rule names of a comma-separated list, each optionally followed by a reason
+fn parse_rules(list: &str) -> Vec<String> {
+    list.split(',')
+        .filter_map(|entry| entry.split_whitespace().next())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Whether a list of silenced rules covers `rule`
+fn matches_rule(rules: &[String], rule: &str) -> bool {
+    rules.iter().any(|silenced| silenced == rule || silenced == "all")
+}
+
+/// Drop the findings of a Rust file that its `arkaft-ignore` comments silence
+///
+/// Suppressed findings are counted per rule in `result.suppressed`; each silenced location
+/// of a compliance issue counts once, and an issue is dropped once all its locations are.
+pub fn apply(result: &mut ReviewResult, content: &str) {
+    let suppressions = Suppressions::parse(content);
+    if suppressions.is_empty() {
+        return;
+    }
+    let mut suppressed: BTreeMap<&'static str, usize> = BTreeMap::new();
+    let mut count = |rule: &'static str, silenced: usize| {
+        if silenced > 0 {
+            *suppressed.entry(rule).or_default() += silenced;
+        }
+    };
+    
+    result.translation_opportunities.retain(|opportunity| {
+        let silenced = suppressions.suppresses(opportunity.rule, opportunity.span.start_line);
+        count(opportunity.rule, usize::from(silenced));
+        !silenced
+    });
+    result.architectural_improvements.retain(|improvement| {
+        let silenced = suppressions.suppresses_file(improvement.rule);
+        count(improvement.rule, usize::from(silenced));
+        !silenced
+    });
+    result.organization_suggestions.retain(|suggestion| {
+        let silenced = suppressions.suppresses_file(suggestion.rule);
+        count(suggestion.rule, usize::from(silenced));
+        !silenced
+    });
+    
+    result.compliance_issues = std::mem::take(&mut result.compliance_issues)
+        .into_iter()
+        .filter_map(|issue| {
+            let total = issue.locations.len();
+            let locations: Vec<_> = issue
+                .locations
+                .iter()
+                .copied()
+                .filter(|span| !suppressions.suppresses(issue.rule, span.start_line))
+                .collect();
+            if suppressions.suppresses_file(issue.rule) || (total > 0 && locations.is_empty()) {
+                count(issue.rule, total.max(1));
+                return None;
+            }
+            if locations.len() == total {
+                return Some(issue);
+            }
+            count(issue.rule, total - locations.len());
+            let description = format!("{} ({} suppressed)", issue.description, total - locations.len());
+            let fixes = issue.fixes.into_iter().filter(|fix| locations.contains(&fix.span)).collect();
+            Some(ComplianceIssue { description, locations, fixes, ..issue })
+        })
+        .collect();
+    
+    for (rule, silenced) in suppressed {
+        *result.suppressed.entry(rule).or_default() += silenced;
+    }
+}
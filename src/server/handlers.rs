@@ -4,7 +4,23 @@ use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tracing::{info, warn, error};
-use crate::expert::DocumentationExpert;
+use std::collections::{BTreeMap, HashMap};
+use crate::expert::SharedExperts;
+use crate::expert::adk_knowledge::VersionOverrides;
+use crate::expert::best_practices::{
+    ArchitectureDesign, ChecklistStatus, DesignValidation, MAX_BATCH_DESIGNS, RuleOverrides, ValidationOptions, ValidationSeverity,
+    ValidationView,
+};
+use crate::expert::i18n::{Locale, localize};
+use crate::expert::documentation::{OutputFormat, RenderOptions, ResponseDetail, format_analytics_report, format_freshness_note, format_score_trend, format_upstream_sections};
+use crate::expert::snapshot::{resolve_snapshot_path, KnowledgeSnapshot};
+use crate::expert::knowledge_store::{DATA_DIR_ENV, unix_timestamp};
+use crate::expert::validation_history::{DEFAULT_TREND_LIMIT, ValidationRecord};
+use crate::expert::compliance_report::{ComplianceReport, REPORT_FORMAT_VERSION, ReportMetadata};
+use crate::review::project::{MAX_PROJECT_FILES, collect_project_files};
+
+/// Default number of terms listed per section of the query analytics report
+const DEFAULT_ANALYTICS_LIMIT: usize = 10;
 
 /// Parameters for adk_query tool
 #[derive(Debug, Deserialize, Serialize)]
@@ -13,17 +29,27 @@ pub struct AdkQueryParams {
     pub query: String,
     /// Optional specific ADK version to reference (defaults to latest)
     pub version: Option<String>,
+    /// Optional response language tag, e.g. "pt-BR" or "ja" (defaults to English)
+    pub language: Option<String>,
+    /// Optional response detail, "full" or "summary" (defaults to full)
+    pub detail: Option<String>,
+    /// Optional maximum response length in characters
+    pub max_length: Option<usize>,
+    /// Optional output format, "markdown", "plain", or "json" (defaults to markdown)
+    pub format: Option<String>,
+    /// Whether to include answers from configured upstream documentation servers (defaults to true)
+    pub federated: Option<bool>,
 }
 
 /// Handle adk_query tool calls with comprehensive ADK documentation expertise
-pub async fn handle_adk_query(params: Value) -> Result<Value> {
+pub async fn handle_adk_query(params: Value, experts: &SharedExperts) -> Result<Value> {
     info!("Handling adk_query request with params: {:?}", params);
     
     // Parse and validate parameters
     let query_params: AdkQueryParams = serde_json::from_value(params)
         .map_err(|e| {
             warn!("Failed to parse adk_query parameters: {}", e);
-            anyhow!("Invalid parameters for adk_query. Expected 'query' (string) and optional 'version' (string). Error: {}", e)
+            anyhow!("Invalid parameters for adk_query. Expected 'query' (string), optional 'version' (string), optional 'language' (string), optional 'detail' (string), optional 'max_length' (integer), optional 'format' (string), and optional 'federated' (boolean). Error: {}", e)
         })?;
     
     // Validate query parameter
@@ -32,18 +58,40 @@ pub async fn handle_adk_query(params: Value) -> Result<Value> {
         return Err(anyhow!("Query parameter cannot be empty"));
     }
     
-    // Create Documentation Expert instance
-    let expert = DocumentationExpert::new();
+    // Validate detail, max_length, and format parameters
+    let render_options = RenderOptions {
+        locale: Locale::from_tag(query_params.language.as_deref()),
+        detail: parse_response_detail(query_params.detail.as_deref(), query_params.max_length)?,
+        max_length: query_params.max_length,
+        format: parse_output_format(query_params.format.as_deref())?,
+    };
     
-    // Process the query with version-specific information retrieval
-    match expert.query_documentation(&query_params.query, query_params.version.as_deref()).await {
-        Ok(response) => {
+    // Reject unknown versions when strict version validation is enabled
+    experts.validate_version(query_params.version.as_deref()).await?;
+    
+    // Process the query with version-specific information retrieval, releasing the
+    // shared Documentation Expert before any upstream server is contacted
+    let result = experts.documentation.read().await
+        .query_documentation(&query_params.query, query_params.version.as_deref())
+        .await;
+    
+    match result {
+        Ok(mut response) => {
+            // Append upstream answers that add to the local one, attributed to their servers
+            if query_params.federated.unwrap_or(true) {
+                let sections = experts.federation.federate(&query_params.query, &response).await;
+                if !sections.is_empty() {
+                    response.push_str("\n\n");
+                    response.push_str(&format_upstream_sections(&sections));
+                }
+            }
+            
             info!("Successfully processed adk_query for: {}", query_params.query);
             Ok(serde_json::json!({
                 "content": [
                     {
                         "type": "text",
-                        "text": response
+                        "text": render_options.render(&response, None)
                     }
                 ]
             }))
@@ -55,6 +103,655 @@ pub async fn handle_adk_query(params: Value) -> Result<Value> {
     }
 }
 
+/// Parameters for adk_glossary tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AdkGlossaryParams {
+    /// Optional term to define (lists all terms when omitted)
+    pub term: Option<String>,
+    /// Optional specific ADK version to reference (defaults to latest)
+    pub version: Option<String>,
+    /// Optional response language tag, e.g. "pt-BR" or "ja" (defaults to English)
+    pub language: Option<String>,
+}
+
+/// Handle adk_glossary tool calls
+pub async fn handle_adk_glossary(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling adk_glossary request with params: {:?}", params);
+    
+    // Parse and validate parameters
+    let glossary_params: AdkGlossaryParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse adk_glossary parameters: {}", e);
+            anyhow!("Invalid parameters for adk_glossary. Expected optional 'term' (string), optional 'version' (string), and optional 'language' (string). Error: {}", e)
+        })?;
+    
+    // Validate term parameter if provided
+    if glossary_params.term.as_deref().is_some_and(|t| t.trim().is_empty()) {
+        warn!("Empty term provided to adk_glossary");
+        return Err(anyhow!("Term parameter cannot be empty"));
+    }
+    
+    // Reject unknown versions when strict version validation is enabled
+    experts.validate_version(glossary_params.version.as_deref()).await?;
+    
+    // Use the shared Documentation Expert instance
+    let expert = experts.documentation.read().await;
+    
+    match expert.get_glossary(glossary_params.term.as_deref(), glossary_params.version.as_deref()).await {
+        Ok(response) => {
+            info!("Successfully processed adk_glossary request");
+            Ok(serde_json::json!({
+                "content": [
+                    {
+                        "type": "text",
+                        "text": localize(&response, Locale::from_tag(glossary_params.language.as_deref()))
+                    }
+                ]
+            }))
+        }
+        Err(e) => {
+            error!("Error processing adk_glossary: {}", e);
+            Err(anyhow!("Failed to retrieve ADK glossary: {}", e))
+        }
+    }
+}
+
+/// Parameters for adk_pattern tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AdkPatternParams {
+    /// Optional pattern key (lists all patterns when omitted)
+    pub pattern_name: Option<String>,
+    /// Optional specific ADK version to reference (defaults to latest)
+    pub version: Option<String>,
+    /// Optional response language tag, e.g. "pt-BR" or "ja" (defaults to English)
+    pub language: Option<String>,
+}
+
+/// Handle adk_pattern tool calls
+pub async fn handle_adk_pattern(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling adk_pattern request with params: {:?}", params);
+    
+    // Parse and validate parameters
+    let pattern_params: AdkPatternParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse adk_pattern parameters: {}", e);
+            anyhow!("Invalid parameters for adk_pattern. Expected optional 'pattern_name' (string), optional 'version' (string), and optional 'language' (string). Error: {}", e)
+        })?;
+    
+    // Validate pattern_name parameter if provided
+    if pattern_params.pattern_name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+        warn!("Empty pattern_name provided to adk_pattern");
+        return Err(anyhow!("pattern_name parameter cannot be empty"));
+    }
+    
+    // Reject unknown versions when strict version validation is enabled
+    experts.validate_version(pattern_params.version.as_deref()).await?;
+    
+    // Use the shared Documentation Expert instance
+    let expert = experts.documentation.read().await;
+    
+    let result = match pattern_params.pattern_name.as_deref() {
+        Some(name) => expert.get_implementation_pattern(name.trim(), pattern_params.version.as_deref()).await,
+        None => expert.list_implementation_patterns(pattern_params.version.as_deref()).await,
+    };
+    
+    match result {
+        Ok(response) => {
+            info!("Successfully processed adk_pattern request");
+            Ok(serde_json::json!({
+                "content": [
+                    {
+                        "type": "text",
+                        "text": localize(&response, Locale::from_tag(pattern_params.language.as_deref()))
+                    }
+                ]
+            }))
+        }
+        Err(e) => {
+            error!("Error processing adk_pattern: {}", e);
+            Err(anyhow!("Failed to retrieve ADK implementation pattern: {}", e))
+        }
+    }
+}
+
+/// Parameters for get_adk_template tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetAdkTemplateParams {
+    /// Optional template key (lists all templates when omitted)
+    pub template_name: Option<String>,
+    /// Optional specific ADK version to reference (defaults to latest)
+    pub version: Option<String>,
+    /// Optional response language tag, e.g. "pt-BR" or "ja" (defaults to English)
+    pub language: Option<String>,
+}
+
+/// Handle get_adk_template tool calls
+pub async fn handle_get_adk_template(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling get_adk_template request with params: {:?}", params);
+    
+    // Parse and validate parameters
+    let template_params: GetAdkTemplateParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse get_adk_template parameters: {}", e);
+            anyhow!("Invalid parameters for get_adk_template. Expected optional 'template_name' (string), optional 'version' (string), and optional 'language' (string). Error: {}", e)
+        })?;
+    
+    // Validate template_name parameter if provided
+    if template_params.template_name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+        warn!("Empty template_name provided to get_adk_template");
+        return Err(anyhow!("template_name parameter cannot be empty"));
+    }
+    
+    // Reject unknown versions when strict version validation is enabled
+    experts.validate_version(template_params.version.as_deref()).await?;
+    
+    // Use the shared Documentation Expert instance
+    let expert = experts.documentation.read().await;
+    
+    match expert.get_project_template(template_params.template_name.as_deref(), template_params.version.as_deref()).await {
+        Ok(response) => {
+            info!("Successfully processed get_adk_template request");
+            Ok(serde_json::json!({
+                "content": [
+                    {
+                        "type": "text",
+                        "text": localize(&response, Locale::from_tag(template_params.language.as_deref()))
+                    }
+                ]
+            }))
+        }
+        Err(e) => {
+            error!("Error processing get_adk_template: {}", e);
+            Err(anyhow!("Failed to retrieve ADK project template: {}", e))
+        }
+    }
+}
+
+/// Parameters for search_code_examples tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SearchCodeExamplesParams {
+    /// Keywords describing the example to find
+    pub query: String,
+    /// Optional language filter, e.g. "python" or "yaml"
+    pub language: Option<String>,
+    /// Optional specific ADK version to reference (defaults to latest)
+    pub version: Option<String>,
+}
+
+/// Handle search_code_examples tool calls
+pub async fn handle_search_code_examples(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling search_code_examples request with params: {:?}", params);
+    
+    // Parse and validate parameters
+    let search_params: SearchCodeExamplesParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse search_code_examples parameters: {}", e);
+            anyhow!("Invalid parameters for search_code_examples. Expected 'query' (string), optional 'language' (string), and optional 'version' (string). Error: {}", e)
+        })?;
+    
+    // Validate query parameter
+    if search_params.query.trim().is_empty() {
+        warn!("Empty query provided to search_code_examples");
+        return Err(anyhow!("query parameter cannot be empty"));
+    }
+    
+    // Reject unknown versions when strict version validation is enabled
+    experts.validate_version(search_params.version.as_deref()).await?;
+    
+    // Use the shared Documentation Expert instance
+    let expert = experts.documentation.read().await;
+    
+    match expert.search_code_examples(
+        search_params.query.trim(),
+        search_params.language.as_deref().filter(|lang| !lang.trim().is_empty()),
+        search_params.version.as_deref(),
+    ).await {
+        Ok(response) => {
+            info!("Successfully processed search_code_examples request");
+            Ok(serde_json::json!({
+                "content": [
+                    {
+                        "type": "text",
+                        "text": response
+                    }
+                ]
+            }))
+        }
+        Err(e) => {
+            error!("Error processing search_code_examples: {}", e);
+            Err(anyhow!("Failed to search ADK code examples: {}", e))
+        }
+    }
+}
+
+/// Parameters for adk_availability tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AdkAvailabilityParams {
+    /// Concept key or name, or version feature name
+    pub name: String,
+    /// Optional response language tag, e.g. "pt-BR" or "ja" (defaults to English)
+    pub language: Option<String>,
+}
+
+/// Handle adk_availability tool calls
+pub async fn handle_adk_availability(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling adk_availability request with params: {:?}", params);
+    
+    // Parse and validate parameters
+    let availability_params: AdkAvailabilityParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse adk_availability parameters: {}", e);
+            anyhow!("Invalid parameters for adk_availability. Expected 'name' (string) and optional 'language' (string). Error: {}", e)
+        })?;
+    
+    // Validate name parameter
+    if availability_params.name.trim().is_empty() {
+        warn!("Empty name provided to adk_availability");
+        return Err(anyhow!("name parameter cannot be empty"));
+    }
+    
+    // Use the shared Documentation Expert instance
+    let response = experts.documentation.read().await.get_feature_availability(availability_params.name.trim());
+    
+    info!("Successfully processed adk_availability request");
+    Ok(serde_json::json!({
+        "content": [
+            {
+                "type": "text",
+                "text": localize(&response, Locale::from_tag(availability_params.language.as_deref()))
+            }
+        ]
+    }))
+}
+
+/// Parameters for list_adk_versions tool
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ListAdkVersionsParams {
+    /// Optional response language tag, e.g. "pt-BR" or "ja" (defaults to English)
+    pub language: Option<String>,
+}
+
+/// Handle list_adk_versions tool calls
+pub async fn handle_list_adk_versions(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling list_adk_versions request with params: {:?}", params);
+    
+    // Parse parameters (all optional, so a missing arguments object is accepted)
+    let versions_params: ListAdkVersionsParams = if params.is_null() {
+        ListAdkVersionsParams::default()
+    } else {
+        serde_json::from_value(params)
+            .map_err(|e| {
+                warn!("Failed to parse list_adk_versions parameters: {}", e);
+                anyhow!("Invalid parameters for list_adk_versions. Expected optional 'language' (string). Error: {}", e)
+            })?
+    };
+    
+    // Use the shared Documentation Expert instance
+    let response = experts.documentation.read().await.list_versions();
+    
+    info!("Successfully processed list_adk_versions request");
+    Ok(serde_json::json!({
+        "content": [
+            {
+                "type": "text",
+                "text": localize(&response, Locale::from_tag(versions_params.language.as_deref()))
+            }
+        ]
+    }))
+}
+
+/// Parameters for list_best_practice_categories tool
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ListBestPracticeCategoriesParams {
+    /// Optional specific ADK version to reference (defaults to latest)
+    pub version: Option<String>,
+    /// Optional response language tag, e.g. "pt-BR" or "ja" (defaults to English)
+    pub language: Option<String>,
+}
+
+/// Handle list_best_practice_categories tool calls
+pub async fn handle_list_best_practice_categories(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling list_best_practice_categories request with params: {:?}", params);
+    
+    // Parse parameters (all optional, so a missing arguments object is accepted)
+    let categories_params: ListBestPracticeCategoriesParams = if params.is_null() {
+        ListBestPracticeCategoriesParams::default()
+    } else {
+        serde_json::from_value(params)
+            .map_err(|e| {
+                warn!("Failed to parse list_best_practice_categories parameters: {}", e);
+                anyhow!("Invalid parameters for list_best_practice_categories. Expected optional 'version' (string) and optional 'language' (string). Error: {}", e)
+            })?
+    };
+    
+    // Reject unknown versions when strict version validation is enabled
+    experts.validate_version(categories_params.version.as_deref()).await?;
+    
+    // Use the shared Documentation Expert instance
+    let response = experts.documentation
+        .read()
+        .await
+        .list_best_practice_categories(categories_params.version.as_deref());
+    
+    info!("Successfully processed list_best_practice_categories request");
+    Ok(serde_json::json!({
+        "content": [
+            {
+                "type": "text",
+                "text": localize(&response, Locale::from_tag(categories_params.language.as_deref()))
+            }
+        ]
+    }))
+}
+
+/// Parameters for whats_new_in tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WhatsNewInParams {
+    /// ADK version or alias to summarize
+    pub version: String,
+    /// Optional response language tag, e.g. "pt-BR" or "ja" (defaults to English)
+    pub language: Option<String>,
+}
+
+/// Handle whats_new_in tool calls
+pub async fn handle_whats_new_in(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling whats_new_in request with params: {:?}", params);
+    
+    // Parse and validate parameters
+    let whats_new_params: WhatsNewInParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse whats_new_in parameters: {}", e);
+            anyhow!("Invalid parameters for whats_new_in. Expected 'version' (string) and optional 'language' (string). Error: {}", e)
+        })?;
+    
+    // Validate version parameter
+    if whats_new_params.version.trim().is_empty() {
+        warn!("Empty version provided to whats_new_in");
+        return Err(anyhow!("version parameter cannot be empty"));
+    }
+    
+    // Reject unknown versions when strict version validation is enabled
+    experts.validate_version(Some(whats_new_params.version.trim())).await?;
+    
+    // Use the shared Documentation Expert instance
+    let response = experts.documentation.read().await.whats_new_in(whats_new_params.version.trim());
+    
+    info!("Successfully processed whats_new_in request");
+    Ok(serde_json::json!({
+        "content": [
+            {
+                "type": "text",
+                "text": localize(&response, Locale::from_tag(whats_new_params.language.as_deref()))
+            }
+        ]
+    }))
+}
+
+/// Parameters for admin_export_knowledge tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AdminExportKnowledgeParams {
+    /// Destination path of the JSON snapshot file, relative to the data directory
+    pub path: String,
+    /// Replace the file if it already exists (defaults to false)
+    pub overwrite: Option<bool>,
+}
+
+/// Handle admin_export_knowledge tool calls
+pub async fn handle_admin_export_knowledge(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling admin_export_knowledge request with params: {:?}", params);
+    
+    // Parse and validate parameters
+    let export_params: AdminExportKnowledgeParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse admin_export_knowledge parameters: {}", e);
+            anyhow!("Invalid parameters for admin_export_knowledge. Expected 'path' (string) and optional 'overwrite' (boolean). Error: {}", e)
+        })?;
+    
+    // Validate path parameter
+    if export_params.path.trim().is_empty() {
+        warn!("Empty path provided to admin_export_knowledge");
+        return Err(anyhow!("path parameter cannot be empty"));
+    }
+    
+    // Snapshot under the read lock, then release it before writing to disk
+    let (snapshot, data_dir) = {
+        let documentation = experts.documentation.read().await;
+        (documentation.export_snapshot(), documentation.data_dir().map(std::path::Path::to_path_buf))
+    };
+    let path = snapshot_path("admin_export_knowledge", data_dir.as_deref(), &export_params.path)?;
+    let path = path.as_path();
+    
+    match snapshot.write_to(path, export_params.overwrite.unwrap_or(false)).await {
+        Ok(bytes) => {
+            let summary = snapshot.summary();
+            info!("Exported knowledge base snapshot to {} ({} bytes)", path.display(), bytes);
+            Ok(serde_json::json!({
+                "content": [
+                    {
+                        "type": "text",
+                        "text": format!(
+                            "## Knowledge Base Exported\n\n\
+                            **File:** {}\n\
+                            **Size:** {} bytes\n\
+                            **Versions:** {}\n\
+                            **Concepts:** {}\n\
+                            **Best Practices:** {}\n\
+                            **Implementation Patterns:** {}",
+                            path.display(),
+                            bytes,
+                            snapshot.versions.iter().map(|docs| docs.version.as_str()).collect::<Vec<_>>().join(", "),
+                            summary.concepts,
+                            summary.best_practices,
+                            summary.patterns
+                        )
+                    }
+                ]
+            }))
+        }
+        Err(e) => {
+            error!("Error exporting knowledge base: {}", e);
+            Err(anyhow!("Failed to export knowledge base: {}", e))
+        }
+    }
+}
+
+/// Snapshot file path of an admin tool call, confined to the server's data directory
+fn snapshot_path(tool: &str, data_dir: Option<&std::path::Path>, requested: &str) -> Result<std::path::PathBuf> {
+    let Some(data_dir) = data_dir else {
+        warn!("{} called without a data directory", tool);
+        return Err(anyhow!("{} needs a data directory; set {} to enable knowledge snapshots", tool, DATA_DIR_ENV));
+    };
+    resolve_snapshot_path(data_dir, requested.trim()).map_err(|e| {
+        warn!("Rejected snapshot path for {}: {}", tool, e);
+        anyhow!("Invalid path for {}: {}", tool, e)
+    })
+}
+
+/// Parameters for admin_import_knowledge tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AdminImportKnowledgeParams {
+    /// Path of the JSON snapshot file to import, relative to the data directory
+    pub path: String,
+}
+
+/// Handle admin_import_knowledge tool calls
+pub async fn handle_admin_import_knowledge(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling admin_import_knowledge request with params: {:?}", params);
+    
+    // Parse and validate parameters
+    let import_params: AdminImportKnowledgeParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse admin_import_knowledge parameters: {}", e);
+            anyhow!("Invalid parameters for admin_import_knowledge. Expected 'path' (string). Error: {}", e)
+        })?;
+    
+    // Validate path parameter
+    if import_params.path.trim().is_empty() {
+        warn!("Empty path provided to admin_import_knowledge");
+        return Err(anyhow!("path parameter cannot be empty"));
+    }
+    
+    let data_dir = experts.documentation.read().await.data_dir().map(std::path::Path::to_path_buf);
+    let path = snapshot_path("admin_import_knowledge", data_dir.as_deref(), &import_params.path)?;
+    let path = path.as_path();
+    let result = match KnowledgeSnapshot::read_from(path).await {
+        Ok(snapshot) => experts.import_snapshot(snapshot).await,
+        Err(e) => Err(e.into()),
+    };
+    
+    match result {
+        Ok(report) => {
+            info!("Imported knowledge base snapshot from {}", path.display());
+            let list = |items: &[String]| if items.is_empty() { "None".to_string() } else { items.join(", ") };
+            Ok(serde_json::json!({
+                "content": [
+                    {
+                        "type": "text",
+                        "text": format!(
+                            "## Knowledge Base Imported\n\n\
+                            **File:** {}\n\
+                            **Updated Versions:** {}\n\
+                            **Gap-Filled Versions:** {}\n\
+                            **Entries Merged:** {}\n\
+                            **Aliases Added:** {}\n\n\
+                            Official entries were kept over community entries. Versions refreshed after the \
+                            snapshot was exported only gained entries they were missing.",
+                            path.display(),
+                            list(&report.updated_versions),
+                            list(&report.gap_filled_versions),
+                            report.entries_merged,
+                            list(&report.aliases_added)
+                        )
+                    }
+                ]
+            }))
+        }
+        Err(e) => {
+            error!("Error importing knowledge base: {}", e);
+            Err(anyhow!("Failed to import knowledge base: {}", e))
+        }
+    }
+}
+
+/// Parameters for admin_query_analytics tool
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct AdminQueryAnalyticsParams {
+    /// Optional number of terms listed per section (defaults to 10)
+    pub limit: Option<usize>,
+    /// Clear the recorded analytics after reporting (defaults to false)
+    pub reset: Option<bool>,
+}
+
+/// Handle admin_query_analytics tool calls
+pub async fn handle_admin_query_analytics(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling admin_query_analytics request with params: {:?}", params);
+    
+    // Parse parameters (all optional, so a missing arguments object is accepted)
+    let analytics_params: AdminQueryAnalyticsParams = if params.is_null() {
+        AdminQueryAnalyticsParams::default()
+    } else {
+        serde_json::from_value(params)
+            .map_err(|e| {
+                warn!("Failed to parse admin_query_analytics parameters: {}", e);
+                anyhow!("Invalid parameters for admin_query_analytics. Expected optional 'limit' (integer) and optional 'reset' (boolean). Error: {}", e)
+            })?
+    };
+    
+    // Validate limit parameter if provided
+    if analytics_params.limit == Some(0) {
+        warn!("Zero limit provided to admin_query_analytics");
+        return Err(anyhow!("limit parameter must be greater than zero"));
+    }
+    
+    // Use the shared Documentation Expert instance
+    let expert = experts.documentation.read().await;
+    let report = expert.query_analytics(analytics_params.limit.unwrap_or(DEFAULT_ANALYTICS_LIMIT));
+    
+    let mut response = format_analytics_report(&report);
+    if analytics_params.reset.unwrap_or(false) {
+        expert.reset_query_analytics();
+        info!("Reset query analytics");
+        response.push_str("\n\n*Analytics were reset after this report.*");
+    }
+    
+    info!("Successfully processed admin_query_analytics request");
+    Ok(serde_json::json!({
+        "content": [
+            {
+                "type": "text",
+                "text": response
+            }
+        ]
+    }))
+}
+
+/// Parameters for admin_version_aliases tool
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct AdminVersionAliasesParams {
+    /// Optional versions to add to the available versions
+    pub versions: Option<Vec<String>>,
+    /// Optional aliases to define or repoint, keyed by alias name
+    pub aliases: Option<HashMap<String, String>>,
+}
+
+/// Handle admin_version_aliases tool calls
+pub async fn handle_admin_version_aliases(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling admin_version_aliases request with params: {:?}", params);
+    
+    // Parse parameters (all optional, so a missing arguments object just lists the configuration)
+    let alias_params: AdminVersionAliasesParams = if params.is_null() {
+        AdminVersionAliasesParams::default()
+    } else {
+        serde_json::from_value(params)
+            .map_err(|e| {
+                warn!("Failed to parse admin_version_aliases parameters: {}", e);
+                anyhow!("Invalid parameters for admin_version_aliases. Expected optional 'versions' (array of strings) and optional 'aliases' (object mapping alias to version). Error: {}", e)
+            })?
+    };
+    
+    let overrides = VersionOverrides {
+        versions: alias_params.versions.unwrap_or_default(),
+        aliases: alias_params.aliases.unwrap_or_default(),
+        ..VersionOverrides::default()
+    };
+    
+    let mut response = String::new();
+    if overrides != VersionOverrides::default() {
+        let mut aliases: Vec<String> = overrides.aliases
+            .iter()
+            .map(|(alias, target)| format!("{} → {}", alias.trim(), target.trim()))
+            .collect();
+        aliases.sort();
+        let versions: Vec<String> = overrides.versions.iter().map(|version| version.trim().to_string()).collect();
+        let list = |items: &[String]| if items.is_empty() { "None".to_string() } else { items.join(", ") };
+        
+        if let Err(e) = experts.apply_version_overrides(overrides).await {
+            error!("Error updating version aliases: {}", e);
+            return Err(anyhow!("Failed to update version aliases: {}", e));
+        }
+        
+        response.push_str(&format!(
+            "## Version Aliases Updated\n\n\
+            **Versions Added:** {}\n\
+            **Aliases Set:** {}\n\n\
+            Changes apply until the server restarts; set ADK_VERSION_ALIASES_FILE to keep them.\n\n",
+            list(&versions),
+            list(&aliases)
+        ));
+    }
+    response.push_str(&experts.documentation.read().await.list_versions());
+    
+    info!("Successfully processed admin_version_aliases request");
+    Ok(serde_json::json!({
+        "content": [
+            {
+                "type": "text",
+                "text": response
+            }
+        ]
+    }))
+}
+
 /// Parameters for review_rust_file tool
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ReviewRustFileParams {
@@ -62,6 +759,22 @@ pub struct ReviewRustFileParams {
     pub file_path: String,
     /// Content of the Rust file to analyze
     pub file_content: String,
+    /// Optional output format, "markdown", "plain", or "json" (defaults to markdown)
+    pub format: Option<String>,
+    /// Optional checks to run, from "translations", "architecture", "compliance", and "organization" (defaults to all)
+    pub checks: Option<Vec<String>>,
+    /// Optional switch for translation opportunity detection, applied after `checks`
+    pub detect_translations: Option<bool>,
+    /// Optional highest cyclomatic complexity accepted for a function (defaults to 10)
+    pub max_complexity: Option<usize>,
+    /// Optional deepest control-flow nesting accepted in a function (defaults to 4)
+    pub max_nesting_depth: Option<usize>,
+    /// Optional most lines accepted in a function (defaults to 50)
+    pub max_function_lines: Option<usize>,
+    /// Optional most parameters accepted in a function, not counting `self` (defaults to 5)
+    pub max_parameters: Option<usize>,
+    /// Optional flag to check the file against rustfmt (defaults to false)
+    pub check_formatting: Option<bool>,
 }
 
 /// Handle review_rust_file tool calls
@@ -92,8 +805,52 @@ pub async fn handle_review_rust_file(params: Value) -> Result<Value> {
         return Err(anyhow!("Only .rs files can be reviewed. Provided file: {}", review_params.file_path));
     }
     
+    let render_options = RenderOptions {
+        format: parse_output_format(review_params.format.as_deref())?,
+        ..RenderOptions::default()
+    };
+    
+    // Select the analyzers to run
+    let mut review_config = match &review_params.checks {
+        Some(checks) if checks.is_empty() => {
+            warn!("Empty checks provided to review_rust_file");
+            return Err(anyhow!("checks parameter cannot be empty; omit it to run every check"));
+        }
+        Some(checks) => crate::review::ReviewConfig::from_checks(checks).map_err(|e| {
+            warn!("Invalid checks provided to review_rust_file: {}", e);
+            e
+        })?,
+        None => crate::review::ReviewConfig::default(),
+    };
+    if let Some(detect_translations) = review_params.detect_translations {
+        review_config.detect_translations = detect_translations;
+    }
+    let thresholds = [
+        review_params.max_complexity,
+        review_params.max_nesting_depth,
+        review_params.max_function_lines,
+        review_params.max_parameters,
+    ];
+    if thresholds.contains(&Some(0)) {
+        warn!("Zero function threshold provided to review_rust_file");
+        return Err(anyhow!("max_complexity, max_nesting_depth, max_function_lines, and max_parameters must be at least 1"));
+    }
+    if let Some(max_complexity) = review_params.max_complexity {
+        review_config.max_cyclomatic_complexity = max_complexity;
+    }
+    if let Some(max_nesting_depth) = review_params.max_nesting_depth {
+        review_config.max_nesting_depth = max_nesting_depth;
+    }
+    if let Some(max_function_lines) = review_params.max_function_lines {
+        review_config.max_function_lines = max_function_lines;
+    }
+    if let Some(max_parameters) = review_params.max_parameters {
+        review_config.max_parameters = max_parameters;
+    }
+    review_config.check_formatting = review_params.check_formatting.unwrap_or(false);
+    
     // Create Code Review Engine instance
-    let review_engine = crate::review::CodeReviewEngine::new();
+    let review_engine = crate::review::CodeReviewEngine::new().with_config(review_config);
     
     // Perform comprehensive file analysis
     match review_engine.review_file(&review_params.file_path, &review_params.file_content).await {
@@ -107,7 +864,7 @@ pub async fn handle_review_rust_file(params: Value) -> Result<Value> {
                 "content": [
                     {
                         "type": "text",
-                        "text": formatted_response
+                        "text": render_options.render(&formatted_response, serde_json::to_value(&review_result).ok())
                     }
                 ]
             }))
@@ -119,6 +876,189 @@ pub async fn handle_review_rust_file(params: Value) -> Result<Value> {
     }
 }
 
+/// Parameters for review_config_file tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReviewConfigFileParams {
+    /// Path to the agent config, ending in .yaml, .yml, .toml, or .json
+    pub file_path: String,
+    /// Content of the agent config to analyze
+    pub file_content: String,
+    /// Optional output format, "markdown", "plain", or "json" (defaults to markdown)
+    pub format: Option<String>,
+}
+
+/// Handle review_config_file tool calls
+pub async fn handle_review_config_file(params: Value) -> Result<Value> {
+    info!("Handling review_config_file request");
+    
+    // Parse and validate parameters
+    let config_params: ReviewConfigFileParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse review_config_file parameters: {}", e);
+            anyhow!("Invalid parameters for review_config_file. Expected 'file_path' (string) and 'file_content' (string). Error: {}", e)
+        })?;
+    
+    if config_params.file_path.trim().is_empty() {
+        warn!("Empty file_path provided to review_config_file");
+        return Err(anyhow!("file_path parameter cannot be empty"));
+    }
+    
+    if config_params.file_content.trim().is_empty() {
+        warn!("Empty file_content provided to review_config_file");
+        return Err(anyhow!("file_content parameter cannot be empty"));
+    }
+    
+    let render_options = RenderOptions {
+        format: parse_output_format(config_params.format.as_deref())?,
+        ..RenderOptions::default()
+    };
+    
+    let review_engine = crate::review::CodeReviewEngine::new();
+    match review_engine.review_agent_config(&config_params.file_path, &config_params.file_content) {
+        Ok(review) => {
+            info!("Successfully reviewed agent config {} with {} issues", config_params.file_path, review.issues.len());
+            let formatted_response = crate::review::suggestions::format_config_review(&config_params.file_path, &review);
+            
+            Ok(serde_json::json!({
+                "content": [
+                    {
+                        "type": "text",
+                        "text": render_options.render(&formatted_response, serde_json::to_value(&review).ok())
+                    }
+                ]
+            }))
+        }
+        Err(e) => {
+            warn!("Error reviewing agent config {}: {}", config_params.file_path, e);
+            Err(anyhow!("Failed to review agent config: {}", e))
+        }
+    }
+}
+
+/// Parameters for review_project tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReviewProjectParams {
+    /// Optional map of file path, relative to the project root, to file content
+    pub files: Option<BTreeMap<String, String>>,
+    /// Optional workspace root directory to read the files from
+    pub root: Option<String>,
+    /// Optional flag to look up locked dependencies in the crates.io index for yanked releases
+    pub check_yanked: Option<bool>,
+}
+
+/// Handle review_project tool calls
+pub async fn handle_review_project(params: Value) -> Result<Value> {
+    info!("Handling review_project request");
+    
+    // Parse and validate parameters
+    let project_params: ReviewProjectParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse review_project parameters: {}", e);
+            anyhow!("Invalid parameters for review_project. Expected either 'files' (object of file path to content) or 'root' (string). Error: {}", e)
+        })?;
+    
+    // Files come from the request or from the workspace root, never both
+    let files = match (project_params.files, project_params.root) {
+        (Some(files), None) => {
+            if files.len() > MAX_PROJECT_FILES {
+                warn!("Too many files provided to review_project: {}", files.len());
+                return Err(anyhow!("files parameter accepts at most {} files", MAX_PROJECT_FILES));
+            }
+            files
+        }
+        (None, Some(root)) if !root.trim().is_empty() => {
+            let path = std::path::PathBuf::from(root.trim());
+            tokio::task::spawn_blocking(move || collect_project_files(&path))
+                .await
+                .map_err(|e| anyhow!("Failed to read workspace root: {}", e))?
+                .map_err(|e| {
+                    warn!("Failed to read workspace root for review_project: {}", e);
+                    anyhow!("Failed to read workspace root: {}", e)
+                })?
+        }
+        _ => {
+            warn!("review_project called without exactly one of 'files' and 'root'");
+            return Err(anyhow!("Provide either 'files' or a non-empty 'root', but not both"));
+        }
+    };
+    
+    let review_engine = crate::review::CodeReviewEngine::new()
+        .with_advisories(crate::review::dependencies::configured_advisories().to_vec());
+    match review_engine.review_project(&files).await {
+        Ok(mut review) => {
+            info!("Successfully reviewed {} files of the project", review.summary.files_reviewed);
+            if project_params.check_yanked.unwrap_or(false) {
+                let yanked = crate::review::dependencies::fetch_yanked_findings(&review.locked_packages).await;
+                review.add_dependency_findings(yanked);
+            }
+            
+            Ok(serde_json::json!({
+                "content": [
+                    {
+                        "type": "text",
+                        "text": crate::review::suggestions::format_project_review(&review)
+                    }
+                ]
+            }))
+        }
+        Err(e) => {
+            error!("Error reviewing project: {}", e);
+            Err(anyhow!("Failed to review project: {}", e))
+        }
+    }
+}
+
+/// Parameters for review_diff tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReviewDiffParams {
+    /// Unified diff, as produced by `git diff`
+    pub diff: String,
+    /// Optional map of base path to the file's content before the diff, for modified files
+    pub base_files: Option<BTreeMap<String, String>>,
+}
+
+/// Handle review_diff tool calls
+pub async fn handle_review_diff(params: Value) -> Result<Value> {
+    info!("Handling review_diff request");
+    
+    // Parse and validate parameters
+    let diff_params: ReviewDiffParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse review_diff parameters: {}", e);
+            anyhow!("Invalid parameters for review_diff. Expected 'diff' (string) and optional 'base_files' (object of file path to content). Error: {}", e)
+        })?;
+    
+    if diff_params.diff.trim().is_empty() {
+        warn!("Empty diff provided to review_diff");
+        return Err(anyhow!("diff parameter cannot be empty"));
+    }
+    let base_files = diff_params.base_files.unwrap_or_default();
+    if base_files.len() > MAX_PROJECT_FILES {
+        warn!("Too many base files provided to review_diff: {}", base_files.len());
+        return Err(anyhow!("base_files parameter accepts at most {} files", MAX_PROJECT_FILES));
+    }
+    
+    let review_engine = crate::review::CodeReviewEngine::new();
+    match review_engine.review_diff(&diff_params.diff, &base_files).await {
+        Ok(review) => {
+            info!("Successfully reviewed {} files of the diff", review.files.len());
+            
+            Ok(serde_json::json!({
+                "content": [
+                    {
+                        "type": "text",
+                        "text": crate::review::suggestions::format_diff_review(&review)
+                    }
+                ]
+            }))
+        }
+        Err(e) => {
+            error!("Error reviewing diff: {}", e);
+            Err(anyhow!("Failed to review diff: {}", e))
+        }
+    }
+}
+
 /// Parameters for validate_architecture tool
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ValidateArchitectureParams {
@@ -128,17 +1068,39 @@ pub struct ValidateArchitectureParams {
     pub code_snippets: Option<Vec<String>>,
     /// Optional ADK version to validate against
     pub version: Option<String>,
+    /// Optional response language tag, e.g. "pt-BR" or "ja" (defaults to English)
+    pub language: Option<String>,
+    /// Optional output format, "markdown", "plain", or "json" (defaults to markdown)
+    pub format: Option<String>,
+    /// Optional rule ids to disable for this call
+    pub disabled_rules: Option<Vec<String>>,
+    /// Optional rule ids to re-enable for this call when configured rule sets disable them
+    pub enabled_rules: Option<Vec<String>>,
+    /// Optional severity per rule id for this call, "error", "warning", or "info"
+    pub severity_overrides: Option<HashMap<String, ValidationSeverity>>,
+    /// Optional view, "report" or "checklist" (defaults to report)
+    pub view: Option<String>,
+    /// Optional architecture pattern to validate against, e.g. "adk_standard" or "multi_agent_team"
+    pub pattern: Option<String>,
+    /// Optional project identifier to record the result in the project's validation history
+    pub project_id: Option<String>,
+    /// Optional flag to return a standalone compliance report in Markdown and JSON
+    pub artifact: Option<bool>,
+    /// Optional minimum compliance score (0-100) for a passing verdict, replacing the configured threshold
+    pub min_score: Option<u8>,
+    /// Optional flag to fail the verdict on warning findings too
+    pub strict: Option<bool>,
 }
 
 /// Handle validate_architecture tool calls
-pub async fn handle_validate_architecture(params: Value) -> Result<Value> {
+pub async fn handle_validate_architecture(params: Value, experts: &SharedExperts) -> Result<Value> {
     info!("Handling validate_architecture request with params: {:?}", params);
     
     // Parse and validate parameters
     let validation_params: ValidateArchitectureParams = serde_json::from_value(params)
         .map_err(|e| {
             warn!("Failed to parse validate_architecture parameters: {}", e);
-            anyhow!("Invalid parameters for validate_architecture. Expected 'description' (string), optional 'code_snippets' (array of strings), and optional 'version' (string). Error: {}", e)
+            anyhow!("Invalid parameters for validate_architecture. Expected 'description' (string), optional 'code_snippets' (array of strings), optional 'version' (string), optional 'language' (string), optional 'format' (string), optional 'view' (string), optional 'pattern' (string), optional 'project_id' (string), optional 'artifact' (boolean), optional 'min_score' (integer 0-100), optional 'strict' (boolean), optional 'disabled_rules' and 'enabled_rules' (arrays of rule ids), and optional 'severity_overrides' (object of rule id to \"error\", \"warning\", or \"info\"). Error: {}", e)
         })?;
     
     // Validate description parameter
@@ -147,26 +1109,116 @@ pub async fn handle_validate_architecture(params: Value) -> Result<Value> {
         return Err(anyhow!("Description parameter cannot be empty"));
     }
     
-    // Create Best Practices Enforcer instance
-    let enforcer = crate::expert::best_practices::BestPracticesEnforcer::new();
+    // Validate format and view parameters
+    let render_options = RenderOptions {
+        locale: Locale::from_tag(validation_params.language.as_deref()),
+        format: parse_output_format(validation_params.format.as_deref())?,
+        ..RenderOptions::default()
+    };
+    let view = parse_validation_view(validation_params.view.as_deref())?;
+    
+    // Validate project_id parameter if provided
+    let project_id = validation_params.project_id.map(|id| id.trim().to_string());
+    if project_id.as_deref() == Some("") {
+        warn!("Empty project_id provided to validate_architecture");
+        return Err(anyhow!("project_id parameter cannot be empty"));
+    }
+    
+    // Validate min_score parameter if provided
+    if let Some(min_score) = validation_params.min_score.filter(|score| *score > 100) {
+        warn!("Out of range min_score provided to validate_architecture: {}", min_score);
+        return Err(anyhow!("min_score parameter must be between 0 and 100"));
+    }
+    
+    // Reject unknown versions when strict version validation is enabled
+    experts.validate_version(validation_params.version.as_deref()).await?;
+    
+    // Collect per-call rule overrides and the requested pattern
+    let overrides = RuleOverrides {
+        disabled_rules: validation_params.disabled_rules.unwrap_or_default(),
+        enabled_rules: validation_params.enabled_rules.unwrap_or_default(),
+        severity_overrides: validation_params.severity_overrides.unwrap_or_default(),
+    };
+    let options = ValidationOptions {
+        overrides: (!overrides.is_empty()).then_some(overrides),
+        pattern: validation_params.pattern,
+        min_score: validation_params.min_score,
+        strict: validation_params.strict.unwrap_or(false),
+    };
+    
+    // Use the shared Best Practices Enforcer instance
+    let enforcer = experts.enforcer.read().await;
     
     // Perform architecture validation
-    match enforcer.validate_architecture(
+    match enforcer.validate_architecture_with(
         &validation_params.description,
         validation_params.code_snippets.as_deref(),
         validation_params.version.as_deref(),
+        &options,
     ).await {
         Ok(validation_result) => {
             info!("Successfully completed architecture validation");
             
             // Format the validation results
-            let formatted_response = format_architecture_validation_result(&validation_result);
+            let mut formatted_response = match view {
+                ValidationView::Report => format_architecture_validation_result(&validation_result),
+                ValidationView::Checklist => format_architecture_checklist(&validation_result),
+            };
+            
+            // Record the score in the project's history; a storage failure does not fail the validation
+            let generated_at = unix_timestamp();
+            if let Some(project_id) = &project_id {
+                let record = ValidationRecord::from_result(project_id, &validation_result, generated_at);
+                match experts.history.record(record) {
+                    Ok(()) => formatted_response.push_str(&format!(
+                        "\n\n*Recorded in the validation history of project `{}`; use get_validation_trend to see its score trend.*",
+                        project_id
+                    )),
+                    Err(e) => warn!("Failed to record validation history for project '{}': {}", project_id, e),
+                }
+            }
+            
+            // Wrap the result in a standalone report for design docs and change tickets
+            if validation_params.artifact.unwrap_or(false) {
+                let adk_version = validation_params.version
+                    .as_deref()
+                    .map(|version| enforcer.knowledge_base.resolve_version(version))
+                    .unwrap_or_else(|| enforcer.knowledge_base.default_version.clone());
+                let report = ComplianceReport {
+                    metadata: ReportMetadata {
+                        format_version: REPORT_FORMAT_VERSION,
+                        project_id,
+                        generated_at,
+                        generator: format!("arkaft-google-adk {}", env!("CARGO_PKG_VERSION")),
+                        adk_version,
+                        rule_set_version: enforcer.validation_rules.version(),
+                        rule_sets: enforcer.validation_rules.rule_sets.clone(),
+                        description: validation_params.description.clone(),
+                        code_snippets: validation_params.code_snippets.as_ref().map_or(0, Vec::len),
+                    },
+                    result: validation_result,
+                };
+                let json = report.to_json().map_err(|e| anyhow!("Failed to serialize compliance report: {}", e))?;
+                
+                return Ok(serde_json::json!({
+                    "content": [
+                        {
+                            "type": "text",
+                            "text": localize(&report.to_markdown(&formatted_response), render_options.locale)
+                        },
+                        {
+                            "type": "text",
+                            "text": json
+                        }
+                    ]
+                }));
+            }
             
             Ok(serde_json::json!({
                 "content": [
                     {
                         "type": "text",
-                        "text": formatted_response
+                        "text": render_options.render(&formatted_response, serde_json::to_value(&validation_result).ok())
                     }
                 ]
             }))
@@ -178,6 +1230,146 @@ pub async fn handle_validate_architecture(params: Value) -> Result<Value> {
     }
 }
 
+/// Parameters for validate_architecture_batch tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ValidateArchitectureBatchParams {
+    /// Designs to validate, each with a description and optional name and code snippets
+    pub designs: Vec<ArchitectureDesign>,
+    /// Optional ADK version to validate against
+    pub version: Option<String>,
+    /// Optional response language tag, e.g. "pt-BR" or "ja" (defaults to English)
+    pub language: Option<String>,
+    /// Optional output format, "markdown", "plain", or "json" (defaults to markdown)
+    pub format: Option<String>,
+    /// Optional rule ids to disable for every design
+    pub disabled_rules: Option<Vec<String>>,
+    /// Optional rule ids to re-enable for every design when configured rule sets disable them
+    pub enabled_rules: Option<Vec<String>>,
+    /// Optional severity per rule id for every design, "error", "warning", or "info"
+    pub severity_overrides: Option<HashMap<String, ValidationSeverity>>,
+    /// Optional architecture pattern every design is validated against
+    pub pattern: Option<String>,
+}
+
+/// Handle validate_architecture_batch tool calls
+pub async fn handle_validate_architecture_batch(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling validate_architecture_batch request with params: {:?}", params);
+    
+    // Parse and validate parameters
+    let batch_params: ValidateArchitectureBatchParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse validate_architecture_batch parameters: {}", e);
+            anyhow!("Invalid parameters for validate_architecture_batch. Expected 'designs' (array of objects with 'description' (string) and optional 'name' (string) and 'code_snippets' (array of strings)), optional 'version' (string), optional 'language' (string), optional 'format' (string), optional 'pattern' (string), optional 'disabled_rules' and 'enabled_rules' (arrays of rule ids), and optional 'severity_overrides' (object of rule id to \"error\", \"warning\", or \"info\"). Error: {}", e)
+        })?;
+    
+    if batch_params.designs.is_empty() {
+        warn!("Empty designs provided to validate_architecture_batch");
+        return Err(anyhow!("designs parameter must contain at least one design"));
+    }
+    if batch_params.designs.len() > MAX_BATCH_DESIGNS {
+        warn!("Too many designs provided to validate_architecture_batch: {}", batch_params.designs.len());
+        return Err(anyhow!("designs parameter accepts at most {} designs", MAX_BATCH_DESIGNS));
+    }
+    if let Some(index) = batch_params.designs.iter().position(|design| design.description.trim().is_empty()) {
+        warn!("Empty description provided for design {} to validate_architecture_batch", index + 1);
+        return Err(anyhow!("Design {} has an empty description", index + 1));
+    }
+    
+    let render_options = RenderOptions {
+        locale: Locale::from_tag(batch_params.language.as_deref()),
+        format: parse_output_format(batch_params.format.as_deref())?,
+        ..RenderOptions::default()
+    };
+    
+    // Reject unknown versions when strict version validation is enabled
+    experts.validate_version(batch_params.version.as_deref()).await?;
+    
+    // The same overrides and pattern apply to every design
+    let overrides = RuleOverrides {
+        disabled_rules: batch_params.disabled_rules.unwrap_or_default(),
+        enabled_rules: batch_params.enabled_rules.unwrap_or_default(),
+        severity_overrides: batch_params.severity_overrides.unwrap_or_default(),
+    };
+    let options = ValidationOptions {
+        overrides: (!overrides.is_empty()).then_some(overrides),
+        pattern: batch_params.pattern,
+        ..ValidationOptions::default()
+    };
+    
+    match experts.validate_architectures(batch_params.designs, batch_params.version, options).await {
+        Ok(validations) => {
+            info!("Successfully validated {} architecture designs", validations.len());
+            
+            let formatted_response = format_batch_validation(&validations);
+            
+            Ok(serde_json::json!({
+                "content": [
+                    {
+                        "type": "text",
+                        "text": render_options.render(&formatted_response, serde_json::to_value(&validations).ok())
+                    }
+                ]
+            }))
+        }
+        Err(e) => {
+            error!("Error validating architecture batch: {}", e);
+            Err(anyhow!("Failed to validate architectures: {}", e))
+        }
+    }
+}
+
+/// Format batch validation results as a comparison table followed by each design's report
+fn format_batch_validation(validations: &[DesignValidation]) -> String {
+    let compliant = validations.iter().filter(|validation| validation.result.is_compliant).count();
+    let mut response = format!(
+        "# Batch Architecture Validation\n\n**Designs:** {}\n**Compliant:** {} of {}\n\n\
+        | Design | Status | Score | Errors | Warnings | Info |\n\
+        |--------|--------|-------|--------|----------|------|\n",
+        validations.len(),
+        compliant,
+        validations.len()
+    );
+    
+    for validation in validations {
+        let result = &validation.result;
+        let count = |severity: ValidationSeverity| result.findings.iter().filter(|f| f.severity == severity).count();
+        response.push_str(&format!(
+            "| {} | {} | {}/100 | {} | {} | {} |\n",
+            validation.name,
+            if result.is_compliant { "✅ Compliant" } else { "❌ Non-compliant" },
+            result.compliance_score,
+            count(ValidationSeverity::Error),
+            count(ValidationSeverity::Warning),
+            count(ValidationSeverity::Info)
+        ));
+    }
+    
+    if let Some(lowest) = validations.iter().filter(|_| validations.len() > 1).min_by_key(|validation| validation.result.compliance_score) {
+        response.push_str(&format!(
+            "\n**Lowest score:** {} ({}/100); review it first.\n",
+            lowest.name, lowest.result.compliance_score
+        ));
+    }
+    
+    // Each design's full report, one heading level down
+    for validation in validations {
+        let report = format_architecture_validation_result(&validation.result);
+        let body = report.split_once("\n\n").map(|(_, body)| body).unwrap_or(&report);
+        response.push_str(&format!("\n---\n\n## {}\n\n{}\n", validation.name, demote_headings(body)));
+    }
+    
+    response
+}
+
+/// Move every markdown heading one level down
+fn demote_headings(markdown: &str) -> String {
+    markdown
+        .lines()
+        .map(|line| if line.starts_with('#') { format!("#{}", line) } else { line.to_string() })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Parameters for get_best_practices tool
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GetBestPracticesParams {
@@ -187,17 +1379,25 @@ pub struct GetBestPracticesParams {
     pub category: Option<String>,
     /// Optional ADK version to reference
     pub version: Option<String>,
+    /// Optional response language tag, e.g. "pt-BR" or "ja" (defaults to English)
+    pub language: Option<String>,
+    /// Optional response detail, "full" or "summary" (defaults to full)
+    pub detail: Option<String>,
+    /// Optional maximum response length in characters
+    pub max_length: Option<usize>,
+    /// Optional output format, "markdown", "plain", or "json" (defaults to markdown)
+    pub format: Option<String>,
 }
 
 /// Handle get_best_practices tool calls  
-pub async fn handle_get_best_practices(params: Value) -> Result<Value> {
+pub async fn handle_get_best_practices(params: Value, experts: &SharedExperts) -> Result<Value> {
     info!("Handling get_best_practices request with params: {:?}", params);
     
     // Parse and validate parameters
     let practices_params: GetBestPracticesParams = serde_json::from_value(params)
         .map_err(|e| {
             warn!("Failed to parse get_best_practices parameters: {}", e);
-            anyhow!("Invalid parameters for get_best_practices. Expected 'scenario' (string), optional 'category' (string), and optional 'version' (string). Error: {}", e)
+            anyhow!("Invalid parameters for get_best_practices. Expected 'scenario' (string), optional 'category' (string), optional 'version' (string), optional 'language' (string), optional 'detail' (string), optional 'max_length' (integer), and optional 'format' (string). Error: {}", e)
         })?;
     
     // Validate scenario parameter
@@ -206,8 +1406,19 @@ pub async fn handle_get_best_practices(params: Value) -> Result<Value> {
         return Err(anyhow!("Scenario parameter cannot be empty"));
     }
     
-    // Create Best Practices Enforcer instance
-    let enforcer = crate::expert::best_practices::BestPracticesEnforcer::new();
+    // Validate detail, max_length, and format parameters
+    let render_options = RenderOptions {
+        locale: Locale::from_tag(practices_params.language.as_deref()),
+        detail: parse_response_detail(practices_params.detail.as_deref(), practices_params.max_length)?,
+        max_length: practices_params.max_length,
+        format: parse_output_format(practices_params.format.as_deref())?,
+    };
+    
+    // Reject unknown versions when strict version validation is enabled
+    experts.validate_version(practices_params.version.as_deref()).await?;
+    
+    // Use the shared Best Practices Enforcer instance
+    let enforcer = experts.enforcer.read().await;
     
     // Retrieve best practices for the scenario
     match enforcer.get_best_practices(
@@ -225,7 +1436,7 @@ pub async fn handle_get_best_practices(params: Value) -> Result<Value> {
                 "content": [
                     {
                         "type": "text",
-                        "text": formatted_response
+                        "text": render_options.render(&formatted_response, serde_json::to_value(&practices_result).ok())
                     }
                 ]
             }))
@@ -237,17 +1448,142 @@ pub async fn handle_get_best_practices(params: Value) -> Result<Value> {
     }
 }
 
+/// Parse the requested response detail, rejecting unknown levels and a zero max_length
+fn parse_response_detail(detail: Option<&str>, max_length: Option<usize>) -> Result<ResponseDetail> {
+    if max_length == Some(0) {
+        warn!("Zero max_length provided");
+        return Err(anyhow!("max_length parameter must be greater than zero"));
+    }
+    
+    match detail {
+        Some(name) => ResponseDetail::parse(name).ok_or_else(|| {
+            warn!("Unknown detail level provided: {}", name);
+            anyhow!("Unknown detail level '{}'. Expected 'full' or 'summary'", name)
+        }),
+        None => Ok(ResponseDetail::default()),
+    }
+}
+
+/// Parse the requested output format, rejecting unknown formats
+fn parse_output_format(format: Option<&str>) -> Result<OutputFormat> {
+    match format {
+        Some(name) => OutputFormat::parse(name).ok_or_else(|| {
+            warn!("Unknown output format provided: {}", name);
+            anyhow!("Unknown output format '{}'. Expected 'markdown', 'plain', or 'json'", name)
+        }),
+        None => Ok(OutputFormat::default()),
+    }
+}
+
+/// Parse the requested validation view, rejecting unknown views
+fn parse_validation_view(view: Option<&str>) -> Result<ValidationView> {
+    match view {
+        Some(name) => ValidationView::parse(name).ok_or_else(|| {
+            warn!("Unknown validation view provided: {}", name);
+            anyhow!("Unknown view '{}'. Expected 'report' or 'checklist'", name)
+        }),
+        None => Ok(ValidationView::default()),
+    }
+}
+
+/// Format architecture validation result as a pass/fail checklist per ADK criterion
+fn format_architecture_checklist(result: &crate::expert::best_practices::ArchitectureValidationResult) -> String {
+    let mut response = format!(
+        "# Architecture Validation Checklist\n\n**Compliance Status:** {}\n**Compliance Score:** {}/100\n\n",
+        if result.is_compliant { "✅ COMPLIANT" } else { "❌ NON-COMPLIANT" },
+        result.compliance_score
+    );
+    
+    for item in &result.checklist {
+        match item.status {
+            ChecklistStatus::Pass => response.push_str(&format!("- [x] **{}**\n", item.criterion)),
+            ChecklistStatus::NotAddressed => {
+                response.push_str(&format!("- [ ] **{}** — **Not addressed:** {}\n", item.criterion, item.guidance));
+            }
+            ChecklistStatus::Fail => {
+                response.push_str(&format!("- [ ] **{}** — {}\n", item.criterion, item.guidance));
+                for finding in result.findings.iter().filter(|finding| item.finding_ids.contains(&finding.id)) {
+                    let location = finding.location.as_ref().map(|l| format!(" ({})", l)).unwrap_or_default();
+                    response.push_str(&format!("  - {}{}\n", finding.description, location));
+                }
+            }
+        }
+    }
+    
+    response.push('\n');
+    response.push_str(&format_suppressed_findings(result));
+    response.push_str(&format_positive_findings(result));
+    response.push_str(&format_inferred_architecture(result));
+    
+    response.push_str("---\n\n*Each criterion follows official Google ADK best practices; checked items pass.*");
+    
+    response
+}
+
+/// Format the inferred component diagram, if any, as a Mermaid block
+fn format_inferred_architecture(result: &crate::expert::best_practices::ArchitectureValidationResult) -> String {
+    match &result.diagram {
+        Some(diagram) => format!(
+            "## Inferred Architecture\n\nComponents as understood from the description; check that they match the intended design.\n\n```mermaid\n{}```\n\n",
+            diagram
+        ),
+        None => String::new(),
+    }
+}
+
+/// Format findings excluded by inline suppression markers, if any
+fn format_suppressed_findings(result: &crate::expert::best_practices::ArchitectureValidationResult) -> String {
+    if result.suppressed.is_empty() {
+        return String::new();
+    }
+    
+    let mut response = String::from("## Acknowledged Suppressions\n\nExcluded from the score by `arkaft-allow` markers.\n\n");
+    for suppressed in &result.suppressed {
+        response.push_str(&format!(
+            "- `{}`: {} (acknowledged in {})\n",
+            suppressed.finding.rule_id, suppressed.finding.description, suppressed.marker
+        ));
+    }
+    response.push('\n');
+    response
+}
+
+/// Format good patterns credited back to the score, if any
+fn format_positive_findings(result: &crate::expert::best_practices::ArchitectureValidationResult) -> String {
+    if result.positive_findings.is_empty() {
+        return String::new();
+    }
+    
+    let mut response = String::from("## Positive Findings\n\n");
+    for finding in &result.positive_findings {
+        let location = finding.location.as_ref().map(|l| format!(" ({})", l)).unwrap_or_default();
+        response.push_str(&format!("- ✅ {}{}: +{} points\n", finding.description, location, finding.credit));
+    }
+    response.push('\n');
+    response
+}
+
 /// Format architecture validation result for display
 fn format_architecture_validation_result(result: &crate::expert::best_practices::ArchitectureValidationResult) -> String {
     let mut response = String::new();
     
     // Header with compliance status
     response.push_str(&format!(
-        "# Architecture Validation Result\n\n**Compliance Status:** {}\n**Compliance Score:** {}/100\n\n",
+        "# Architecture Validation Result\n\n**Compliance Status:** {}\n**Compliance Score:** {}/100\n**Compliance Threshold:** {}/100\n\n",
         if result.is_compliant { "✅ COMPLIANT" } else { "❌ NON-COMPLIANT" },
-        result.compliance_score
+        result.compliance_score,
+        result.scoring.compliance_threshold
     ));
     
+    // Why the verdict failed, so CI logs show the policy that was violated
+    if !result.non_compliant_reasons.is_empty() {
+        response.push_str("## Non-Compliant Reasons\n\n");
+        for reason in &result.non_compliant_reasons {
+            response.push_str(&format!("- {}\n", reason));
+        }
+        response.push('\n');
+    }
+    
     // Findings section
     if !result.findings.is_empty() {
         response.push_str("## Validation Findings\n\n");
@@ -274,6 +1610,10 @@ fn format_architecture_validation_result(result: &crate::expert::best_practices:
         }
     }
     
+    // Findings acknowledged by inline markers
+    response.push_str(&format_suppressed_findings(result));
+    response.push_str(&format_positive_findings(result));
+    
     // Recommendations section
     if !result.recommendations.is_empty() {
         response.push_str("## Recommendations\n\n");
@@ -305,8 +1645,20 @@ fn format_architecture_validation_result(result: &crate::expert::best_practices:
             response.push_str(&format!("**Reference:** [{}]({})\n\n", rec.documentation_ref, rec.documentation_ref));
             response.push_str("---\n\n");
         }
+        
+        // Recommendations beyond the cap are listed by title only
+        if !result.additional_recommendations.is_empty() {
+            response.push_str(&format!("**More Recommendations:** {}\n", result.additional_recommendations.len()));
+            for rec in &result.additional_recommendations {
+                response.push_str(&format!("- {} (Priority: {})\n", rec.description, rec.priority));
+            }
+            response.push('\n');
+        }
     }
     
+    // Diagram of the design as understood from the description
+    response.push_str(&format_inferred_architecture(result));
+    
     // Documentation references
     if !result.documentation_refs.is_empty() {
         response.push_str("## Official Documentation References\n\n");
@@ -332,6 +1684,12 @@ fn format_best_practices_result(result: &crate::expert::best_practices::BestPrac
         result.version
     ));
     
+    if result.general {
+        response.push_str(
+            "**General guidance:** No best practices in the knowledge base match this scenario, so these general ADK practices apply instead.\n\n",
+        );
+    }
+    
     // Best practices section
     if !result.practices.is_empty() {
         response.push_str("## Best Practices\n\n");
@@ -353,6 +1711,7 @@ fn format_best_practices_result(result: &crate::expert::best_practices::BestPrac
             }
             
             response.push_str(&format!("**Reference:** [{}]({})\n\n", practice.documentation_ref, practice.documentation_ref));
+            response.push_str(&format!("{}\n\n", format_freshness_note(practice.source_url.as_deref(), practice.last_updated)));
             response.push_str("---\n\n");
         }
     }
@@ -413,4 +1772,86 @@ fn format_best_practices_result(result: &crate::expert::best_practices::BestPrac
     response.push_str("---\n\n*These best practices are based on official Google ADK documentation and guidelines.*");
     
     response
-}
\ No newline at end of file
+}
+
+/// Handle admin_knowledge_stats tool calls
+pub async fn handle_admin_knowledge_stats(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling admin_knowledge_stats request with params: {:?}", params);
+    
+    // Use the shared Documentation Expert instance
+    let response = experts.documentation.read().await.knowledge_base_stats();
+    
+    info!("Successfully processed admin_knowledge_stats request");
+    Ok(serde_json::json!({
+        "content": [
+            {
+                "type": "text",
+                "text": response
+            }
+        ]
+    }))
+}
+
+/// Parameters for get_validation_trend tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetValidationTrendParams {
+    /// Project identifier passed to validate_architecture
+    pub project_id: String,
+    /// Optional number of most recent validations to include (defaults to 20)
+    pub limit: Option<usize>,
+    /// Optional output format, "markdown", "plain", or "json" (defaults to markdown)
+    pub format: Option<String>,
+}
+
+/// Handle get_validation_trend tool calls
+pub async fn handle_get_validation_trend(params: Value, experts: &SharedExperts) -> Result<Value> {
+    info!("Handling get_validation_trend request with params: {:?}", params);
+    
+    // Parse and validate parameters
+    let trend_params: GetValidationTrendParams = serde_json::from_value(params)
+        .map_err(|e| {
+            warn!("Failed to parse get_validation_trend parameters: {}", e);
+            anyhow!("Invalid parameters for get_validation_trend. Expected 'project_id' (string), optional 'limit' (integer), and optional 'format' (string). Error: {}", e)
+        })?;
+    
+    let project_id = trend_params.project_id.trim();
+    if project_id.is_empty() {
+        warn!("Empty project_id provided to get_validation_trend");
+        return Err(anyhow!("project_id parameter cannot be empty"));
+    }
+    if trend_params.limit == Some(0) {
+        warn!("Zero limit provided to get_validation_trend");
+        return Err(anyhow!("limit parameter must be greater than zero"));
+    }
+    let render_options = RenderOptions {
+        format: parse_output_format(trend_params.format.as_deref())?,
+        ..RenderOptions::default()
+    };
+    
+    let trend = experts
+        .history
+        .trend(project_id, trend_params.limit.unwrap_or(DEFAULT_TREND_LIMIT))
+        .map_err(|e| {
+            error!("Error loading validation history: {}", e);
+            anyhow!("Failed to load validation history: {}", e)
+        })?;
+    
+    let response = match &trend {
+        Some(trend) => format_score_trend(trend),
+        None => format!(
+            "## Validation Score Trend\n\nNo validations have been recorded for project `{}`. \
+            Pass `project_id` to validate_architecture to start its history.",
+            project_id
+        ),
+    };
+    
+    info!("Successfully processed get_validation_trend request");
+    Ok(serde_json::json!({
+        "content": [
+            {
+                "type": "text",
+                "text": render_options.render(&response, trend.and_then(|trend| serde_json::to_value(trend).ok()))
+            }
+        ]
+    }))
+}
@@ -2,6 +2,7 @@
 
 use super::handlers::*;
 use super::ToolHandler;
+use crate::expert::SharedExperts;
 use serde_json::json;
 
 #[tokio::test]
@@ -49,6 +50,71 @@ async fn test_review_rust_file_handler_with_issues() {
     assert!(text_content.contains("Translation Opportunities") || text_content.contains("ADK Compliance Issues"));
 }
 
+#[tokio::test]
+async fn test_review_rust_file_handler_json_format() {
+    let params = json!({
+        "file_path": "problematic.rs",
+        "file_content": "pub fn risky_function() {\n    panic!(\"Something went wrong\");\n}\n",
+        "format": "json"
+    });
+    
+    let response = handle_review_rust_file(params).await.unwrap();
+    let document: serde_json::Value = serde_json::from_str(response["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(document["title"], "Rust File Review Results");
+    
+    // Findings keep their structure and exact spans
+    let issues = document["result"]["compliance_issues"].as_array().unwrap();
+    let panic_issue = issues.iter().find(|issue| issue["description"].as_str().unwrap().contains("panic!")).unwrap();
+    assert_eq!(panic_issue["issue_type"], "Error Handling Compliance");
+    assert_eq!(panic_issue["locations"][0], json!({"start_line": 2, "start_column": 5, "end_line": 2, "end_column": 34}));
+    assert!(document["result"]["translation_opportunities"].is_array());
+    
+    let result = handle_review_rust_file(json!({ "file_path": "a.rs", "file_content": "fn a() {}", "format": "yaml" })).await;
+    assert!(result.unwrap_err().to_string().contains("Unknown output format"));
+}
+
+#[tokio::test]
+async fn test_review_rust_file_handler_checks() {
+    let code = "pub fn risky_function() {\n    let value = Some(1).unwrap();\n    panic!(\"{}\", value);\n}\n";
+    let text = |response: serde_json::Value| response["content"][0]["text"].as_str().unwrap().to_string();
+    
+    let response = text(handle_review_rust_file(json!({ "file_path": "risky.rs", "file_content": code, "checks": ["compliance"] })).await.unwrap());
+    assert!(response.contains("## ADK Compliance Issues"));
+    assert!(!response.contains("## Translation Opportunities"));
+    assert!(!response.contains("## Architectural Improvements"));
+    
+    let response = text(handle_review_rust_file(json!({ "file_path": "risky.rs", "file_content": code, "detect_translations": false })).await.unwrap());
+    assert!(!response.contains("## Translation Opportunities"));
+    assert!(response.contains("## Architectural Improvements"));
+    
+    assert!(handle_review_rust_file(json!({ "file_path": "risky.rs", "file_content": code, "checks": [] })).await.is_err());
+    let error = handle_review_rust_file(json!({ "file_path": "risky.rs", "file_content": code, "checks": ["style"] })).await.unwrap_err();
+    assert!(error.to_string().contains("Unknown review check 'style'"));
+}
+
+#[tokio::test]
+async fn test_review_rust_file_handler_function_thresholds() {
+    let code = "pub fn pick(a: bool, b: bool) -> u8 {\n    if a && b {\n        if a {\n            return 1;\n        }\n    }\n    0\n}\n";
+    let text = |response: serde_json::Value| response["content"][0]["text"].as_str().unwrap().to_string();
+    
+    let response = text(handle_review_rust_file(json!({ "file_path": "pick.rs", "file_content": code })).await.unwrap());
+    assert!(response.contains("| `pick` | 1 | 8 | 2 | 4 | 2 |"));
+    assert!(!response.contains("[warning, cyclomatic-complexity]"));
+    
+    let params = json!({ "file_path": "pick.rs", "file_content": code, "max_complexity": 3, "max_nesting_depth": 1 });
+    let response = text(handle_review_rust_file(params).await.unwrap());
+    assert!(response.contains("**Complexity** [warning, cyclomatic-complexity]"));
+    assert!(response.contains("**Nesting Depth** [warning, nesting-depth]"));
+    
+    let params = json!({ "file_path": "pick.rs", "file_content": code, "max_function_lines": 5, "max_parameters": 1 });
+    let response = text(handle_review_rust_file(params).await.unwrap());
+    assert!(response.contains("**Function Length** [info, function-length]"));
+    assert!(response.contains("**Parameter Count** [info, parameter-count]"));
+    
+    let error = handle_review_rust_file(json!({ "file_path": "pick.rs", "file_content": code, "max_parameters": 0 })).await.unwrap_err();
+    assert!(error.to_string().contains("must be at least 1"));
+}
+
 #[tokio::test]
 async fn test_review_rust_file_handler_invalid_params() {
     let params = json!({
@@ -91,6 +157,99 @@ async fn test_review_rust_file_handler_non_rust_file() {
     assert!(error_msg.contains("Only .rs files can be reviewed"));
 }
 
+#[tokio::test]
+async fn test_review_project_handler() {
+    let text = |result: anyhow::Result<serde_json::Value>| result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    
+    // A file map is reviewed per file, with the worst file ranked first
+    let response = text(handle_review_project(json!({
+        "files": {
+            "Cargo.toml": "[package]\nname = \"demo\"\n",
+            "Cargo.lock": "[[package]]\nname = \"h2\"\nversion = \"0.3.20\"\n",
+            "src/lib.rs": "/// Entry point\npub fn run() -> anyhow::Result<()> {\n    Ok(())\n}\n",
+            "src/risky.rs": "pub fn load() {\n    panic!(\"boom\");\n    todo!()\n}\n",
+            "README.md": "# Demo"
+        }
+    })).await);
+    assert!(response.contains("**Files Reviewed:** 2"));
+    assert!(response.contains("**Manifests:** Cargo.toml"));
+    assert!(response.contains("## Hotspots"));
+    assert!(response.contains("| `src/risky.rs` |"));
+    assert!(response.contains("## `src/risky.rs`"));
+    assert!(response.contains("### ADK Compliance Issues"));
+    assert!(response.contains("## `Cargo.toml`"));
+    assert!(response.contains("### Manifest Issues"));
+    assert!(response.contains("**Missing rust-version** [info] `package.rust-version` (line 1)"));
+    assert!(response.contains("**Lockfiles:** Cargo.lock"));
+    assert!(response.contains("## Dependencies"));
+    assert!(response.contains("| `h2` | 0.3.20 | Vulnerability | [RUSTSEC-2024-0003](https://rustsec.org/advisories/RUSTSEC-2024-0003)"));
+    assert!(response.contains("## Module Tree\n\n```text\ncrate (src/lib.rs)\n```"));
+    assert!(response.contains("**Unreachable File** [warning, unreachable-file]: src/risky.rs is not reached"));
+    
+    // A workspace root is walked, skipping target directories
+    let root = std::env::temp_dir().join(format!("arkaft-review-project-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    std::fs::create_dir_all(root.join("target/debug")).unwrap();
+    std::fs::write(root.join("src/main.rs"), "fn main() {\n    let x = Some(1).unwrap();\n}\n").unwrap();
+    std::fs::write(root.join("target/debug/build.rs"), "fn main() { panic!() }").unwrap();
+    // Files that are not UTF-8 are skipped instead of failing the review
+    std::fs::write(root.join("src/generated.rs"), [0xff, 0xfe, 0x00]).unwrap();
+    let response = text(handle_review_project(json!({ "root": root.to_string_lossy() })).await);
+    assert!(response.contains("**Files Reviewed:** 1"));
+    assert!(response.contains("## `src/main.rs`"));
+    assert!(response.contains("No Cargo.toml was found"));
+    let _ = std::fs::remove_dir_all(&root);
+    
+    // Exactly one source is required
+    assert!(handle_review_project(json!({})).await.is_err());
+    assert!(handle_review_project(json!({ "files": {"a.rs": "fn a() {}"}, "root": "." })).await.is_err());
+    assert!(handle_review_project(json!({ "files": {"notes.txt": "hello"} })).await.is_err());
+}
+
+#[tokio::test]
+async fn test_review_diff_handler() {
+    let diff = "--- a/src/agent.rs\n+++ b/src/agent.rs\n@@ -1,3 +1,4 @@\n /// Runs the agent\n pub fn run() -> anyhow::Result<()> {\n+    let config = std::env::var(\"CONFIG\").unwrap();\n     Ok(())\n";
+    let base = "/// Runs the agent\npub fn run() -> anyhow::Result<()> {\n    Ok(())\n}\n";
+    
+    let response = handle_review_diff(json!({ "diff": diff, "base_files": { "src/agent.rs": base } })).await.unwrap();
+    let text = response["content"][0]["text"].as_str().unwrap();
+    assert!(text.contains("# Rust Diff Review Results"));
+    assert!(text.contains("**Files Reviewed:** 1"));
+    assert!(text.contains("**Lines Added:** 1"));
+    
+    // Without the base content the modified file is skipped
+    let response = handle_review_diff(json!({ "diff": diff })).await.unwrap();
+    let text = response["content"][0]["text"].as_str().unwrap();
+    assert!(text.contains("## Skipped Files"));
+    assert!(text.contains("- `src/agent.rs`: src/agent.rs is modified but its base content was not provided"));
+    
+    assert!(handle_review_diff(json!({ "diff": "  " })).await.is_err());
+    assert!(handle_review_diff(json!({ "diff": "--- a/README.md\n+++ b/README.md\n@@ -1 +1 @@\n-old\n+new\n" })).await.is_err());
+}
+
+#[tokio::test]
+async fn test_review_config_file_handler() {
+    let config = "name: assistant\nmodel: gemini-2.0-flash\ninstruction: ${SYSTEM_PROMPT}\napi_key: sk-abcdefghijklmnopqrstuvwx\n";
+    
+    let response = handle_review_config_file(json!({ "file_path": "root_agent.yaml", "file_content": config })).await.unwrap();
+    let text = response["content"][0]["text"].as_str().unwrap();
+    assert!(text.contains("# Agent Config Review Results"));
+    assert!(text.contains("**Agents:** assistant"));
+    assert!(text.contains("**Environment Variables:** SYSTEM_PROMPT"));
+    assert!(text.contains("[error, hardcoded-secret] `api_key` (line 4)"));
+    
+    let clean = "name: assistant\nmodel: gemini-2.0-flash\ninstruction: Answer questions.\n";
+    let response = handle_review_config_file(json!({ "file_path": "root_agent.yaml", "file_content": clean, "format": "json" })).await.unwrap();
+    let document: serde_json::Value = serde_json::from_str(response["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(document["title"], "Agent Config Review Results");
+    assert_eq!(document["result"]["format"], "yaml");
+    assert!(document["result"]["issues"].as_array().unwrap().is_empty());
+    
+    assert!(handle_review_config_file(json!({ "file_path": "agent.txt", "file_content": clean })).await.is_err());
+    assert!(handle_review_config_file(json!({ "file_path": "agent.yaml", "file_content": " " })).await.is_err());
+}
+
 // Additional comprehensive integration tests for complete MCP functionality
 
 #[tokio::test]
@@ -100,7 +259,7 @@ async fn test_adk_query_handler_integration() {
         "version": "latest"
     });
     
-    let result = handle_adk_query(params).await;
+    let result = handle_adk_query(params, &SharedExperts::new()).await;
     assert!(result.is_ok());
     
     let response = result.unwrap();
@@ -121,7 +280,7 @@ async fn test_validate_architecture_handler_integration() {
         ]
     });
     
-    let result = handle_validate_architecture(params).await;
+    let result = handle_validate_architecture(params, &SharedExperts::new()).await;
     assert!(result.is_ok());
     
     let response = result.unwrap();
@@ -139,7 +298,7 @@ async fn test_get_best_practices_handler_integration() {
         "category": "architecture"
     });
     
-    let result = handle_get_best_practices(params).await;
+    let result = handle_get_best_practices(params, &SharedExperts::new()).await;
     assert!(result.is_ok());
     
     let response = result.unwrap();
@@ -210,7 +369,7 @@ async fn test_complete_mcp_server_integration() {
     assert!(start_result.is_ok());
     
     // Test server metrics and health
-    let metrics = server.metrics();
+    let _metrics = server.metrics();
     let health = server.health_check();
     assert!(health.is_ok());
     
@@ -277,4 +436,801 @@ async fn test_review_rust_file_handler_empty_content() {
     
     let error_msg = result.unwrap_err().to_string();
     assert!(error_msg.contains("file_content parameter cannot be empty"));
-}
\ No newline at end of file
+}
+#[tokio::test]
+async fn test_adk_glossary_handler_lists_terms() {
+    let result = handle_adk_glossary(json!({}), &SharedExperts::new()).await;
+    assert!(result.is_ok());
+    
+    let response = result.unwrap();
+    let text_content = response["content"][0]["text"].as_str().unwrap();
+    assert!(text_content.contains("| Term | Definition |"));
+    for term in ["Artifact", "Session", "Runner", "Planner", "Flow"] {
+        assert!(text_content.contains(term), "Missing glossary term: {}", term);
+    }
+}
+
+#[tokio::test]
+async fn test_adk_glossary_handler_single_term() {
+    let result = handle_adk_glossary(json!({ "term": "runner" }), &SharedExperts::new()).await;
+    assert!(result.is_ok());
+    
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("**Runner**"));
+    assert!(!text_content.contains("**Artifact**"));
+    
+    let result = handle_adk_glossary(json!({ "term": "flux capacitor" }), &SharedExperts::new()).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("not in the ADK glossary"));
+    
+    let result = handle_adk_glossary(json!({ "term": "  " }), &SharedExperts::new()).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_tool_handler_reuses_shared_experts() {
+    let metrics = std::sync::Arc::new(crate::utils::ServerMetrics::new());
+    let experts = SharedExperts::new();
+    let handler = ToolHandler::with_experts(vec![], metrics, experts.clone());
+    
+    // The second identical query is answered from the shared expert's cache
+    for _ in 0..2 {
+        let result = handler.handle_tool_call("adk_query", json!({ "query": "What is a session?" })).await;
+        assert!(result.is_ok());
+    }
+    
+    let stats = experts.documentation.read().await.cache_stats();
+    assert_eq!(stats.hits, 1);
+    assert_eq!(stats.misses, 1);
+}
+
+#[tokio::test]
+async fn test_adk_pattern_handler_lists_patterns() {
+    let result = handle_adk_pattern(json!({}), &SharedExperts::new()).await;
+    assert!(result.is_ok());
+    
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("| Pattern | Name | Summary |"));
+    for key in ["session_management", "deploy_cloud_run", "input_guardrail_callback"] {
+        assert!(text_content.contains(&format!("`{}`", key)), "Missing pattern: {}", key);
+    }
+}
+
+#[tokio::test]
+async fn test_adk_pattern_handler_single_pattern() {
+    let experts = SharedExperts::new();
+    
+    let result = handle_adk_pattern(json!({ "pattern_name": "deploy_cloud_run" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("Implementation Pattern: deploy_cloud_run"));
+    assert!(text_content.contains("```"));
+    
+    let result = handle_adk_pattern(json!({ "pattern_name": "time_travel" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("not found"));
+    assert!(text_content.contains("`session_management`"));
+    
+    let result = handle_adk_pattern(json!({ "pattern_name": " " }), &experts).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_get_adk_template_handler() {
+    let experts = SharedExperts::new();
+    
+    let result = handle_get_adk_template(json!({}), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("| Template | Name | Summary |"));
+    for key in ["single_agent", "multi_agent_team", "agent_with_tools"] {
+        assert!(text_content.contains(&format!("`{}`", key)), "Missing template: {}", key);
+    }
+    
+    let result = handle_get_adk_template(json!({ "template_name": "agent_with_tools" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("Project Template: agent_with_tools"));
+    assert!(text_content.contains("#### File: `tool_agent/agent.py`"));
+    assert!(text_content.contains("tools=[get_weather]"));
+    assert!(text_content.contains("**Setup Steps:**"));
+    
+    let result = handle_get_adk_template(json!({ "template_name": "kitchen_sink" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("not found"));
+    assert!(text_content.contains("`single_agent`"));
+    
+    let result = handle_get_adk_template(json!({ "template_name": "" }), &experts).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_list_adk_versions_handler() {
+    let result = handle_list_adk_versions(json!({}), &SharedExperts::new()).await;
+    assert!(result.is_ok());
+    
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("**Default version:** 1.0.0"));
+    assert!(text_content.contains("| Version | Status | Aliases |"));
+    assert!(text_content.contains("| `1.0.0` | Default | `latest`, `stable` |"));
+}
+
+#[tokio::test]
+async fn test_list_best_practice_categories_handler() {
+    let result = handle_list_best_practice_categories(json!({}), &SharedExperts::new()).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("## Best Practice Categories"));
+    assert!(text_content.contains("| `deployment` | 6 |"));
+    assert!(text_content.contains("| `architecture` | 1 |"));
+    
+    let result = handle_list_best_practice_categories(json!(null), &SharedExperts::new()).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_adk_query_cites_documentation_sections() {
+    let experts = SharedExperts::new();
+    
+    let result = handle_adk_query(json!({ "query": "What is session state?" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains(
+        "- [Sessions → State → What is session.state?](https://google.github.io/adk-docs/sessions/state/#what-is-sessionstate)"
+    ));
+    
+    let result = handle_get_best_practices(json!({ "scenario": "session", "category": "sessions" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("https://google.github.io/adk-docs/sessions/state/#organizing-state-with-prefixes-scope-matters"));
+}
+
+#[tokio::test]
+async fn test_adk_query_suggests_follow_up_questions() {
+    let experts = SharedExperts::new();
+    
+    let result = handle_adk_query(json!({ "query": "What is a session?" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    let (_, follow_ups) = text_content.split_once("### Suggested Follow-up Questions:\n").unwrap();
+    assert_eq!(follow_ups.lines().filter(|line| line.starts_with("- ")).count(), 3);
+    
+    let result = handle_adk_query(json!({ "query": "quantum teleportation flux" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(!text_content.contains("Suggested Follow-up Questions"));
+}
+
+#[tokio::test]
+async fn test_whats_new_in_handler() {
+    use crate::expert::adk_knowledge::{AdkKnowledgeBase, ChangeKind, ChangelogEntry};
+    
+    let experts = SharedExperts::new();
+    
+    let result = handle_whats_new_in(json!({ "version": "latest" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("## What's New in Google ADK: 1.0.0"));
+    assert!(text_content.contains("https://github.com/google/adk-python/releases/tag/v1.0.0"));
+    assert!(text_content.contains("No changelog data has been ingested"));
+    
+    let mut docs = AdkKnowledgeBase::bundled_version_docs("1.0.0".to_string());
+    docs.changelog.push(ChangelogEntry {
+        kind: ChangeKind::Breaking,
+        title: "Async-only runner".to_string(),
+        description: "Runner.run now requires an event loop.".to_string(),
+        link: Some("https://github.com/google/adk-python/pull/42".to_string()),
+    });
+    experts.merge_version_docs(vec![docs]).await.unwrap();
+    
+    let result = handle_whats_new_in(json!({ "version": "1.0.0", "language": "es" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("## Novedades de Google ADK: 1.0.0"));
+    assert!(text_content.contains("### Cambios Incompatibles"));
+    assert!(text_content.contains(
+        "- **Async-only runner** - Runner.run now requires an event loop. ([details](https://github.com/google/adk-python/pull/42))"
+    ));
+    
+    assert!(handle_whats_new_in(json!({ "version": " " }), &experts).await.is_err());
+    assert!(handle_whats_new_in(json!({}), &experts).await.is_err());
+}
+
+#[tokio::test]
+async fn test_strict_version_validation_in_handlers() {
+    let experts = SharedExperts::new();
+    
+    let result = handle_adk_query(json!({ "query": "sessions", "version": "1.0" }), &experts).await;
+    assert!(result.is_ok());
+    
+    experts.documentation.write().await.knowledge_base.version_config.strict_versions = true;
+    
+    let result = handle_adk_query(json!({ "query": "sessions", "version": "1.0" }), &experts).await;
+    let message = result.unwrap_err().to_string();
+    assert!(message.contains("Parameter validation error: Unknown ADK version '1.0'. Did you mean '1.0.0'?"));
+    
+    assert!(handle_get_best_practices(json!({ "scenario": "session", "version": "2.0.0" }), &experts).await.is_err());
+    assert!(handle_whats_new_in(json!({ "version": "lastest" }), &experts).await.is_err());
+    assert!(handle_adk_glossary(json!({ "version": "stable" }), &experts).await.is_ok());
+    assert!(handle_adk_pattern(json!({}), &experts).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_admin_version_aliases_handler() {
+    let experts = SharedExperts::new();
+    
+    let result = handle_admin_version_aliases(json!(null), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(!text_content.contains("## Version Aliases Updated"));
+    assert!(text_content.contains("## Google ADK Versions"));
+    
+    let params = json!({ "versions": ["1.1.0"], "aliases": { "stable": "1.1.0" } });
+    let result = handle_admin_version_aliases(params, &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("**Versions Added:** 1.1.0"));
+    assert!(text_content.contains("**Aliases Set:** stable → 1.1.0"));
+    assert_eq!(experts.enforcer.read().await.knowledge_base.resolve_version("stable"), "1.1.0");
+    
+    let result = handle_admin_version_aliases(json!({ "aliases": { "stable": "7.0.0" } }), &experts).await;
+    assert!(result.unwrap_err().to_string().contains("Failed to update version aliases"));
+    assert!(handle_admin_version_aliases(json!({ "aliases": ["stable"] }), &experts).await.is_err());
+}
+
+#[tokio::test]
+async fn test_response_detail_and_max_length() {
+    let experts = SharedExperts::new();
+    let text = |result: anyhow::Result<serde_json::Value>| result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    
+    let full = text(handle_adk_query(json!({ "query": "What is a session?" }), &experts).await);
+    let summary = text(handle_adk_query(json!({ "query": "What is a session?", "detail": "summary" }), &experts).await);
+    assert!(summary.len() < full.len());
+    assert!(summary.contains("A Session tracks a single conversation thread"));
+    assert!(summary.contains("### Official References:"));
+    assert!(!summary.contains("### Suggested Follow-up Questions"));
+    assert!(summary.ends_with("*Summarized response; request full detail for the complete answer.*"));
+    
+    // A budget the full response fits in leaves it untouched
+    let roomy = text(handle_adk_query(json!({ "query": "What is a session?", "max_length": 100_000 }), &experts).await);
+    assert_eq!(roomy, full);
+    
+    for max_length in [120, 400, 900] {
+        let fitted = text(handle_adk_query(json!({ "query": "What is a session?", "max_length": max_length }), &experts).await);
+        assert!(fitted.chars().count() <= max_length);
+        let fitted = text(handle_get_best_practices(json!({ "scenario": "safety", "max_length": max_length }), &experts).await);
+        assert!(fitted.chars().count() <= max_length);
+    }
+    
+    let localized = text(handle_get_best_practices(json!({ "scenario": "safety", "detail": "SUMMARY", "language": "pt-BR" }), &experts).await);
+    assert!(localized.starts_with("# Boas Práticas do Google ADK"));
+    assert!(localized.contains("### Referências Oficiais:"));
+    
+    assert!(handle_adk_query(json!({ "query": "session", "detail": "brief" }), &experts).await.is_err());
+    assert!(handle_get_best_practices(json!({ "scenario": "safety", "max_length": 0 }), &experts).await.is_err());
+}
+
+#[tokio::test]
+async fn test_output_formats() {
+    let experts = SharedExperts::new();
+    let text = |result: anyhow::Result<serde_json::Value>| result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    
+    let markdown = text(handle_adk_query(json!({ "query": "What is a session?" }), &experts).await);
+    let explicit = text(handle_adk_query(json!({ "query": "What is a session?", "format": "markdown" }), &experts).await);
+    assert_eq!(markdown, explicit);
+    
+    let plain = text(handle_adk_query(json!({ "query": "What is a session?", "format": "plain" }), &experts).await);
+    assert!(plain.starts_with("Google ADK Documentation Query"));
+    assert!(!plain.contains("**") && !plain.contains("## "));
+    
+    let practices = text(handle_get_best_practices(json!({ "scenario": "safety", "format": "JSON" }), &experts).await);
+    let document: serde_json::Value = serde_json::from_str(&practices).unwrap();
+    assert_eq!(document["title"], "Google ADK Best Practices");
+    assert!(!document["result"]["practices"].as_array().unwrap().is_empty());
+    assert!(!document["references"].as_array().unwrap().is_empty());
+    
+    // Condensed responses keep the document structure but drop the full result
+    let summary = text(handle_get_best_practices(json!({ "scenario": "safety", "format": "json", "detail": "summary" }), &experts).await);
+    let document: serde_json::Value = serde_json::from_str(&summary).unwrap();
+    assert!(document.get("result").is_none());
+    
+    let validation = text(handle_validate_architecture(json!({
+        "description": "Agent with tools and no guardrails",
+        "format": "json",
+        "language": "es"
+    }), &experts).await);
+    let document: serde_json::Value = serde_json::from_str(&validation).unwrap();
+    assert_eq!(document["title"], "Resultado de la Validación de Arquitectura");
+    assert!(document["result"]["is_compliant"].is_boolean());
+    
+    assert!(handle_adk_query(json!({ "query": "session", "format": "html" }), &experts).await.is_err());
+    assert!(handle_validate_architecture(json!({ "description": "Agent", "format": "yaml" }), &experts).await.is_err());
+}
+
+/// Upstream documentation server answering every search with one fixed section
+struct StaticUpstream;
+
+#[async_trait::async_trait]
+impl crate::expert::federation::UpstreamDocs for StaticUpstream {
+    fn name(&self) -> &str {
+        "general-docs"
+    }
+    
+    async fn search(&self, query: &str) -> crate::utils::error::ArkaftResult<Vec<crate::expert::federation::UpstreamSection>> {
+        let text = format!("# Search results\n\nGeneral guidance about {} from https://docs.example.com/guide.", query);
+        Ok(crate::expert::federation::UpstreamSection::from_text(self.name(), &text).into_iter().collect())
+    }
+}
+
+#[tokio::test]
+async fn test_adk_query_federates_to_upstream_servers() {
+    use crate::expert::federation::Federation;
+    use std::sync::Arc;
+    
+    let experts = SharedExperts::new().with_federation(Federation::new(vec![Arc::new(StaticUpstream)]));
+    let text = |result: anyhow::Result<serde_json::Value>| result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    
+    let federated = text(handle_adk_query(json!({ "query": "What is a session?" }), &experts).await);
+    let (local, upstream) = federated.split_once("## Upstream Documentation").unwrap();
+    assert!(local.contains("A Session tracks a single conversation thread"));
+    assert!(upstream.contains("### Search results"));
+    assert!(upstream.contains("**Source:** upstream MCP server 'general-docs' ([https://docs.example.com/guide](https://docs.example.com/guide))"));
+    assert!(upstream.contains("General guidance about What is a session?"));
+    
+    let local_only = text(handle_adk_query(json!({ "query": "What is a session?", "federated": false }), &experts).await);
+    assert!(!local_only.contains("## Upstream Documentation"));
+    assert_eq!(local_only.trim_end(), local.trim_end());
+}
+
+#[tokio::test]
+async fn test_validate_architecture_rule_overrides() {
+    use crate::expert::best_practices::ValidationRules;
+    use crate::expert::rule_sets::RuleSet;
+    
+    // Configured rule sets silence todo_usage for prototypes
+    let rule_set = RuleSet::parse(std::path::Path::new("prototype.yaml"), r#"
+disabled_rules: [todo_usage]
+severity_overrides:
+  unwrap_usage: info
+"#).unwrap();
+    let mut rules = ValidationRules::new();
+    rules.merge_rule_set(rule_set);
+    let experts = SharedExperts::new();
+    experts.set_validation_rules(rules).await;
+    
+    let text = |result: anyhow::Result<serde_json::Value>| result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    let findings = |document: &str| -> Vec<(String, String)> {
+        let document: serde_json::Value = serde_json::from_str(document).unwrap();
+        document["result"]["findings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| (f["rule_id"].as_str().unwrap().to_string(), f["severity"].as_str().unwrap().to_string()))
+            .collect()
+    };
+    let params = |extra: serde_json::Value| {
+        let mut params = json!({
+            "description": "A simple agent",
+            "code_snippets": ["let value = load().unwrap();\ntodo!()"],
+            "format": "json"
+        });
+        params.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+        params
+    };
+    
+    let configured = findings(&text(handle_validate_architecture(params(json!({})), &experts).await));
+    assert!(configured.contains(&("unwrap_usage".to_string(), "Info".to_string())));
+    assert!(!configured.iter().any(|(rule_id, _)| rule_id == "todo_usage"));
+    
+    // Release branches re-enable todo_usage as an error and drop unwrap_usage
+    let release = findings(&text(handle_validate_architecture(params(json!({
+        "enabled_rules": ["todo_usage"],
+        "disabled_rules": ["unwrap_usage"],
+        "severity_overrides": {"todo_usage": "error"}
+    })), &experts).await));
+    assert!(release.contains(&("todo_usage".to_string(), "Error".to_string())));
+    assert!(!release.iter().any(|(rule_id, _)| rule_id == "unwrap_usage"));
+    
+    assert!(handle_validate_architecture(params(json!({"severity_overrides": {"todo_usage": "fatal"}})), &experts).await.is_err());
+}
+
+#[tokio::test]
+async fn test_validate_architecture_checklist_view() {
+    let experts = SharedExperts::new();
+    let text = |result: anyhow::Result<serde_json::Value>| result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    let params = json!({
+        "description": "A support agent keeps conversation state in a database session service and has no guardrails",
+        "code_snippets": ["pub fn load() -> String {\n    std::fs::read_to_string(\"config\").unwrap()\n}"],
+        "view": "checklist"
+    });
+    
+    let checklist = text(handle_validate_architecture(params.clone(), &experts).await);
+    assert!(checklist.starts_with("# Architecture Validation Checklist"));
+    assert!(checklist.contains("- [x] **Project structure**"));
+    assert!(checklist.contains("- [x] **Session handling**"));
+    assert!(checklist.contains("- [ ] **Error handling**"));
+    assert!(checklist.contains("Code Snippet 1, line 2 in `load`"));
+    assert!(checklist.contains("- [ ] **Safety**"));
+    assert!(checklist.contains("- [ ] **Deployment readiness** — **Not addressed:**"));
+    assert!(checklist.contains("## Inferred Architecture"));
+    assert!(checklist.contains("    support_agent --> session_service\n"));
+    
+    // The checklist is part of the structured result in every view
+    let mut json_params = params.clone();
+    json_params["format"] = json!("json");
+    json_params["view"] = json!("report");
+    let document: serde_json::Value = serde_json::from_str(&text(handle_validate_architecture(json_params, &experts).await)).unwrap();
+    let statuses: Vec<&str> = document["result"]["checklist"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["status"].as_str().unwrap())
+        .collect();
+    assert_eq!(statuses, vec!["pass", "fail", "pass", "pass", "fail", "not_addressed"]);
+    
+    let mut invalid = params;
+    invalid["view"] = json!("table");
+    assert!(handle_validate_architecture(invalid, &experts).await.is_err());
+}
+
+#[tokio::test]
+async fn test_validate_architecture_json_gates_ci() {
+    let experts = SharedExperts::new();
+    let text = |result: anyhow::Result<serde_json::Value>| result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    let validate = |snippet: &str| handle_validate_architecture(json!({
+        "description": "A support agent with a database session service",
+        "code_snippets": [snippet],
+        "format": "json"
+    }), &experts);
+    
+    // CI pipelines read the structured result straight from the response text
+    let failing: serde_json::Value = serde_json::from_str(&text(validate("fn main() {\n    let config = load().unwrap();\n    panic!(\"unreachable\")\n}").await)).unwrap();
+    let result = &failing["result"];
+    assert_eq!(result["is_compliant"], false);
+    assert!(result["compliance_score"].as_u64().unwrap() < result["scoring"]["compliance_threshold"].as_u64().unwrap());
+    assert!(result["findings"].as_array().unwrap().iter().any(|f| f["rule_id"] == "unwrap_usage"));
+    
+    let passing: serde_json::Value = serde_json::from_str(&text(validate("fn main() -> anyhow::Result<()> {\n    let config = load()?;\n    run(config)\n}").await)).unwrap();
+    assert!(passing["result"]["compliance_score"].as_u64().unwrap() > result["compliance_score"].as_u64().unwrap());
+}
+
+#[tokio::test]
+async fn test_validate_architecture_verdict_policy() {
+    let experts = SharedExperts::new();
+    let validate = |extra: serde_json::Value| {
+        let mut params = json!({
+            "description": "Agents follow a non-standard project layout, with an evaluation set, tracing, and structured logging",
+            "format": "json"
+        });
+        params.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+        let experts = &experts;
+        async move {
+            let response = handle_validate_architecture(params, experts).await.unwrap();
+            let document: serde_json::Value = serde_json::from_str(response["content"][0]["text"].as_str().unwrap()).unwrap();
+            document["result"].clone()
+        }
+    };
+    
+    // A single warning passes the default policy
+    let result = validate(json!({})).await;
+    assert_eq!(result["verdict"], "pass");
+    assert_eq!(result["non_compliant_reasons"], json!([]));
+    
+    // A higher minimum score fails it, and the reported threshold follows the call
+    let result = validate(json!({ "min_score": 100 })).await;
+    assert_eq!(result["verdict"], "fail");
+    assert_eq!(result["is_compliant"], false);
+    assert_eq!(result["scoring"]["compliance_threshold"], 100);
+    assert!(result["non_compliant_reasons"][0].as_str().unwrap().contains("below the minimum of 100"));
+    
+    // Strict mode fails on the warning itself
+    let result = validate(json!({ "strict": true })).await;
+    assert_eq!(result["verdict"], "fail");
+    let reasons = result["non_compliant_reasons"].as_array().unwrap();
+    assert_eq!(reasons.len(), 1);
+    assert!(reasons[0].as_str().unwrap().starts_with("Warning in strict mode:"));
+    
+    let markdown = handle_validate_architecture(json!({ "description": "Agents follow a non-standard project layout", "strict": true }), &experts).await.unwrap();
+    assert!(markdown["content"][0]["text"].as_str().unwrap().contains("## Non-Compliant Reasons"));
+    assert!(handle_validate_architecture(json!({ "description": "A single agent", "min_score": 101 }), &experts).await.is_err());
+}
+
+#[tokio::test]
+async fn test_validate_architecture_reports_suppressions() {
+    let experts = SharedExperts::new();
+    let text = |result: anyhow::Result<serde_json::Value>| result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    
+    let report = text(handle_validate_architecture(json!({
+        "description": "A support agent with a database session service",
+        "code_snippets": ["fn main() {\n    let config = load().unwrap(); // arkaft-allow: unwrap_usage\n    run(config)\n}"]
+    }), &experts).await);
+    assert!(report.contains("## Acknowledged Suppressions"));
+    assert!(report.contains("(acknowledged in Code Snippet 1, line 2)"));
+    
+    let checklist = text(handle_validate_architecture(json!({
+        "description": "A support agent with a database session service",
+        "code_snippets": ["fn main() {\n    let config = load().unwrap(); // arkaft-allow: unwrap_usage\n    run(config)\n}"],
+        "view": "checklist"
+    }), &experts).await);
+    assert!(checklist.contains("- [x] **Error handling**"));
+    assert!(checklist.contains("## Acknowledged Suppressions"));
+}
+
+#[tokio::test]
+async fn test_validate_architecture_compliance_report_artifact() {
+    let experts = SharedExperts::new();
+    let response = handle_validate_architecture(json!({
+        "description": "A support agent with a database session service",
+        "code_snippets": ["fn main() {\n    load().unwrap();\n}"],
+        "project_id": "checkout",
+        "artifact": true
+    }), &experts).await.unwrap();
+    
+    let markdown = response["content"][0]["text"].as_str().unwrap();
+    assert!(markdown.starts_with("# ADK Compliance Report"));
+    assert!(markdown.contains("| Project | `checkout` |"));
+    assert!(markdown.contains("| Rule sets | built-in only |"));
+    assert!(markdown.contains("> A support agent with a database session service"));
+    assert!(markdown.contains("## Architecture Validation Result"));
+    
+    let report: serde_json::Value = serde_json::from_str(response["content"][1]["text"].as_str().unwrap()).unwrap();
+    let metadata = &report["metadata"];
+    assert_eq!(metadata["format_version"], 1);
+    assert_eq!(metadata["project_id"], "checkout");
+    assert_eq!(metadata["code_snippets"], 1);
+    assert!(metadata["generated_at"].as_i64().unwrap() > 0);
+    assert!(metadata["rule_set_version"].as_str().unwrap().starts_with(env!("CARGO_PKG_VERSION")));
+    assert!(report["result"]["findings"].as_array().unwrap().iter().any(|f| f["rule_id"] == "unwrap_usage"));
+    
+    // The same rules always report the same version
+    let again = handle_validate_architecture(json!({ "description": "Another design", "artifact": true }), &experts).await.unwrap();
+    let again: serde_json::Value = serde_json::from_str(again["content"][1]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(again["metadata"]["rule_set_version"], metadata["rule_set_version"]);
+    assert!(again["metadata"]["project_id"].is_null());
+}
+
+#[tokio::test]
+async fn test_validate_architecture_batch_handler() {
+    let experts = SharedExperts::new();
+    let text = |result: anyhow::Result<serde_json::Value>| result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    let designs = json!([
+        {
+            "name": "checkout-service",
+            "description": "A checkout agent with a database session service",
+            "code_snippets": ["fn main() {\n    load().unwrap();\n    panic!(\"unreachable\")\n}"]
+        },
+        { "name": "search-service", "description": "A search agent with a database session service" },
+        { "description": "A notification agent with a database session service" }
+    ]);
+    
+    let report = text(handle_validate_architecture_batch(json!({ "designs": designs }), &experts).await);
+    assert!(report.starts_with("# Batch Architecture Validation"));
+    assert!(report.contains("**Designs:** 3"));
+    assert!(report.contains("| checkout-service | ❌ Non-compliant |"));
+    assert!(report.contains("| search-service | ✅ Compliant |"));
+    assert!(report.contains("| Design 3 |"));
+    assert!(report.contains("**Lowest score:** checkout-service"));
+    assert!(report.contains("## search-service\n\n**Compliance Status:**"));
+    assert!(report.contains("### Validation Findings"));
+    
+    // JSON output keeps the individual results in input order
+    let json_report: serde_json::Value = serde_json::from_str(&text(handle_validate_architecture_batch(json!({ "designs": designs, "format": "json" }), &experts).await)).unwrap();
+    let names: Vec<&str> = json_report["result"].as_array().unwrap().iter().map(|v| v["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["checkout-service", "search-service", "Design 3"]);
+    
+    let error = handle_validate_architecture_batch(json!({ "designs": [] }), &experts).await.unwrap_err();
+    assert!(error.to_string().contains("at least one design"));
+    let error = handle_validate_architecture_batch(json!({ "designs": [{ "description": " " }] }), &experts).await.unwrap_err();
+    assert!(error.to_string().contains("Design 1 has an empty description"));
+    let error = handle_validate_architecture_batch(json!({ "designs": designs, "pattern": "unknown" }), &experts).await.unwrap_err();
+    assert!(error.to_string().contains("Failed to validate architectures"));
+}
+
+#[tokio::test]
+async fn test_validation_trend_handler() {
+    let experts = SharedExperts::new().with_validation_history(crate::expert::validation_history::ValidationHistory::new(None));
+    let text = |result: anyhow::Result<serde_json::Value>| result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    
+    let empty = text(handle_get_validation_trend(json!({ "project_id": "checkout" }), &experts).await);
+    assert!(empty.contains("No validations have been recorded for project `checkout`"));
+    
+    // A failing sprint followed by a fixed one
+    for snippet in ["fn main() {\n    load().unwrap();\n    panic!(\"unreachable\")\n}", "fn main() -> anyhow::Result<()> {\n    load()\n}"] {
+        let report = text(handle_validate_architecture(json!({
+            "description": "A support agent with a database session service",
+            "code_snippets": [snippet],
+            "project_id": "checkout"
+        }), &experts).await);
+        assert!(report.contains("validation history of project `checkout`"));
+    }
+    
+    let trend = text(handle_get_validation_trend(json!({ "project_id": "checkout" }), &experts).await);
+    assert!(trend.starts_with("## Validation Score Trend"));
+    assert!(trend.contains("**Validations:** 2"));
+    assert!(trend.contains("(improving)"));
+    
+    let document: serde_json::Value = serde_json::from_str(&text(handle_get_validation_trend(json!({
+        "project_id": "checkout",
+        "limit": 1,
+        "format": "json"
+    }), &experts).await)).unwrap();
+    assert_eq!(document["result"]["records"].as_array().unwrap().len(), 1);
+    assert_eq!(document["result"]["direction"], "steady");
+    
+    assert!(handle_get_validation_trend(json!({ "project_id": " " }), &experts).await.is_err());
+    assert!(handle_get_validation_trend(json!({ "project_id": "checkout", "limit": 0 }), &experts).await.is_err());
+    assert!(handle_validate_architecture(json!({ "description": "Agent", "project_id": "" }), &experts).await.is_err());
+}
+
+#[tokio::test]
+async fn test_admin_knowledge_stats_handler() {
+    use crate::expert::adk_knowledge::AdkKnowledgeBase;
+    
+    let experts = SharedExperts::new();
+    
+    let result = handle_admin_knowledge_stats(json!(null), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("## Knowledge Base Statistics"));
+    assert!(text_content.contains("**Loaded versions:** 1"));
+    assert!(text_content.contains("| `1.0.0` (default) |"));
+    assert!(text_content.contains("| never | bundled |"));
+    
+    experts.merge_version_docs(vec![AdkKnowledgeBase::bundled_version_docs("1.1.0".to_string())]).await.unwrap();
+    
+    let result = handle_admin_knowledge_stats(json!({}), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("**Loaded versions:** 2"));
+    let row = text_content.lines().find(|line| line.starts_with("| `1.1.0` |")).unwrap();
+    assert!(row.contains(" UTC | refresh |"));
+}
+
+#[tokio::test]
+async fn test_admin_query_analytics_handler() {
+    let experts = SharedExperts::new();
+    
+    let result = handle_admin_query_analytics(json!(null), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("**Total queries:** 0"));
+    
+    for query in ["What is a session?", "session state", "quantum teleportation flux"] {
+        handle_adk_query(json!({ "query": query }), &experts).await.unwrap();
+    }
+    // Repeated queries answered from the cache still count
+    handle_adk_query(json!({ "query": "quantum teleportation flux" }), &experts).await.unwrap();
+    
+    let result = handle_admin_query_analytics(json!({ "limit": 3, "reset": true }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("**Total queries:** 4"));
+    assert!(text_content.contains("**Unanswered queries:** 2 (50.0%)"));
+    assert!(text_content.contains("| `session` | 2 | 0 |"));
+    assert!(text_content.contains("| `quantum` | 2 | 2 |"));
+    assert!(!text_content.contains("What is a session?"));
+    
+    let result = handle_admin_query_analytics(json!({}), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("**Total queries:** 0"));
+    
+    let result = handle_admin_query_analytics(json!({ "limit": 0 }), &experts).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_search_code_examples_handler() {
+    let experts = SharedExperts::new();
+    
+    let result = handle_search_code_examples(json!({ "query": "guardrail", "language": "python" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("### Keyword guardrail"));
+    assert!(text_content.contains("```python"));
+    
+    let result = handle_search_code_examples(json!({ "query": "quantum teleportation" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("No code examples matched"));
+    
+    let result = handle_search_code_examples(json!({ "query": "" }), &experts).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_adk_availability_handler() {
+    let experts = SharedExperts::new();
+    
+    let result = handle_adk_availability(json!({ "name": "session" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("## Version Availability: Session"));
+    assert!(text_content.contains("**Minimum version:** 1.0.0"));
+    assert!(text_content.contains("| `1.0.0` | Available |"));
+    
+    let result = handle_adk_availability(json!({ "name": "sesion" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("was found"));
+    assert!(text_content.contains("Closest Related Topics"));
+    
+    assert!(handle_adk_availability(json!({}), &experts).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handlers_localize_response_scaffolding() {
+    let experts = SharedExperts::new();
+    
+    let result = handle_adk_query(json!({ "query": "What is a session?", "language": "pt-BR" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("**Versão:**"));
+    assert!(text_content.contains("### Referências Oficiais:"));
+    assert!(!text_content.contains("### Official References:"));
+    
+    let result = handle_get_best_practices(json!({ "scenario": "session", "language": "ja" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.starts_with("# Google ADK ベストプラクティス"));
+    
+    // Unsupported languages fall back to English
+    let result = handle_list_adk_versions(json!({ "language": "tlh" }), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("## Google ADK Versions"));
+    
+    assert!(handle_list_adk_versions(serde_json::Value::Null, &experts).await.is_ok());
+}
+
+/// Shared experts backed by a knowledge store in a fresh temporary data directory
+fn experts_with_data_dir(name: &str) -> (SharedExperts, std::path::PathBuf) {
+    use crate::expert::DocumentationExpert;
+    use crate::expert::knowledge_store::KnowledgeStore;
+    
+    let data_dir = std::env::temp_dir().join(format!("arkaft-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&data_dir);
+    let documentation = DocumentationExpert::with_store(KnowledgeStore::open(&data_dir).unwrap()).unwrap();
+    (SharedExperts::with_documentation(documentation), data_dir)
+}
+
+#[tokio::test]
+async fn test_admin_export_knowledge_handler() {
+    let (experts, data_dir) = experts_with_data_dir("export-handler");
+    let params = json!({ "path": "snapshots/knowledge.json" });
+    
+    let result = handle_admin_export_knowledge(params.clone(), &experts).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("## Knowledge Base Exported"));
+    assert!(text_content.contains("**Versions:** 1.0.0"));
+    assert!(data_dir.join("snapshots/knowledge.json").exists());
+    
+    let result = handle_admin_export_knowledge(params, &experts).await;
+    assert!(result.unwrap_err().to_string().contains("already exists"));
+    
+    assert!(handle_admin_export_knowledge(json!({ "path": " " }), &experts).await.is_err());
+    
+    // Paths outside the data directory are rejected
+    let outside = std::env::temp_dir().join(format!("arkaft-export-outside-{}.json", std::process::id()));
+    for path in ["../escape.json".to_string(), outside.to_string_lossy().to_string()] {
+        let result = handle_admin_export_knowledge(json!({ "path": path }), &experts).await;
+        assert!(result.unwrap_err().to_string().contains("Invalid path for admin_export_knowledge"));
+    }
+    assert!(!outside.exists());
+    assert!(!data_dir.with_file_name("escape.json").exists());
+    
+    // Without a data directory there is nowhere to export to
+    let result = handle_admin_export_knowledge(json!({ "path": "knowledge.json" }), &SharedExperts::new()).await;
+    assert!(result.unwrap_err().to_string().contains("needs a data directory"));
+    
+    let _ = std::fs::remove_dir_all(&data_dir);
+}
+
+#[tokio::test]
+async fn test_admin_import_knowledge_round_trip() {
+    let (source, data_dir) = experts_with_data_dir("import-handler");
+    let path = data_dir.join("knowledge.json");
+    handle_admin_export_knowledge(json!({ "path": "knowledge.json" }), &source).await.unwrap();
+    
+    let (target, target_dir) = experts_with_data_dir("import-handler-target");
+    std::fs::copy(&path, target_dir.join("knowledge.json")).unwrap();
+    let result = handle_admin_import_knowledge(json!({ "path": "knowledge.json" }), &target).await;
+    let text_content = result.unwrap()["content"][0]["text"].as_str().unwrap().to_string();
+    assert!(text_content.contains("## Knowledge Base Imported"));
+    assert!(text_content.contains("**Updated Versions:** 1.0.0"));
+    assert!(target.enforcer.read().await.knowledge_base.content_updated_at("1.0.0") > 0);
+    
+    // Files outside the data directory, including through symlinks, are not read
+    let result = handle_admin_import_knowledge(json!({ "path": path.to_string_lossy() }), &target).await;
+    assert!(result.unwrap_err().to_string().contains("Invalid path for admin_import_knowledge"));
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(&data_dir, target_dir.join("linked")).unwrap();
+        let result = handle_admin_import_knowledge(json!({ "path": "linked/knowledge.json" }), &target).await;
+        assert!(result.unwrap_err().to_string().contains("outside the data directory"));
+    }
+    let result = handle_admin_import_knowledge(json!({ "path": "knowledge.json" }), &SharedExperts::new()).await;
+    assert!(result.unwrap_err().to_string().contains("needs a data directory"));
+    
+    let _ = std::fs::remove_dir_all(&data_dir);
+    let _ = std::fs::remove_dir_all(&target_dir);
+    let result = handle_admin_import_knowledge(json!({ "path": "knowledge.json" }), &target).await;
+    assert!(result.is_err());
+}
+
@@ -10,9 +10,16 @@ mod integration_tests;
 
 use anyhow::Result;
 use serde_json::{json, Value};
-use tracing::{info, error, debug};
+use tracing::{info, warn, error, debug};
 use crate::utils::{error::ArkaftResult, ServerConfig, ServerMetrics, log_error_with_severity, validate_server_health};
 use std::sync::Arc;
+use tokio::task::JoinHandle;
+use crate::expert::SharedExperts;
+use crate::expert::adk_knowledge::{AdkKnowledgeBase, VersionOverrides};
+use crate::expert::auto_update::{AutoUpdateSettings, AutoUpdater, WebDocsFetcher};
+use crate::expert::knowledge_source::default_sources;
+use crate::expert::federation::{Federation, FederationConfig};
+use crate::expert::rule_sets::load_validation_rules;
 
 // Import rmcp components
 use rmcp::{
@@ -34,6 +41,10 @@ pub struct ArkaftMcpServer {
     metrics: Arc<ServerMetrics>,
     /// Tool handler for MCP protocol integration
     tool_handler: Option<ToolHandler>,
+    /// Experts shared across tool calls, built once on start
+    experts: Option<SharedExperts>,
+    /// Background task running scheduled documentation updates
+    update_task: Option<JoinHandle<()>>,
 }
 
 impl ArkaftMcpServer {
@@ -42,10 +53,12 @@ impl ArkaftMcpServer {
         let config = crate::utils::init_server_config();
         
         // Configure server capabilities for MCP protocol
-        let mut capabilities = ServerCapabilities::default();
-        capabilities.tools = Some(ToolsCapability {
-            list_changed: Some(false),
-        });
+        let capabilities = ServerCapabilities {
+            tools: Some(ToolsCapability {
+                list_changed: Some(false),
+            }),
+            ..Default::default()
+        };
         
         let metrics = Arc::new(ServerMetrics::new());
         
@@ -56,13 +69,15 @@ impl ArkaftMcpServer {
             initialized: false,
             metrics,
             tool_handler: None,
+            experts: None,
+            update_task: None,
         }
     }
-
+    
     /// Initialize the MCP server with proper protocol handling
     pub async fn initialize(&mut self) -> ArkaftResult<()> {
         info!("Initializing Arkaft Google ADK MCP Server v{}", self.version);
-
+        
         // Initialize metrics tracking
         self.metrics.initialize_start_time();
         
@@ -76,20 +91,20 @@ impl ArkaftMcpServer {
         })?;
         
         info!("Created {} tool definitions", tools.len());
-
+        
         self.initialized = true;
         
         info!("MCP server initialized with protocol handling capabilities and monitoring");
         
         Ok(())
     }
-
+    
     /// Create MCP tool definitions with proper schemas
     fn create_tool_definitions(&self) -> ArkaftResult<Vec<Tool>> {
         info!("Creating MCP tool definitions");
-
+        
         let mut tools = Vec::new();
-
+        
         // Create adk_query tool
         let adk_query_schema = json!({
             "type": "object",
@@ -101,11 +116,34 @@ impl ArkaftMcpServer {
                 "version": {
                     "type": "string",
                     "description": "Specific ADK version to reference (optional, defaults to latest)"
+                },
+                "language": {
+                    "type": "string",
+                    "description": "Response language tag: en, pt-BR, es, or ja (optional, defaults to English)"
+                },
+                "detail": {
+                    "type": "string",
+                    "enum": ["full", "summary"],
+                    "description": "Response detail: full markdown or a summary with the headline answer and top references (optional, defaults to full)"
+                },
+                "max_length": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Maximum response length in characters; longer responses are summarized to fit (optional)"
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["markdown", "plain", "json"],
+                    "description": "Output format: markdown, plain text, or a JSON document with fields, sections, and references (optional, defaults to markdown)"
+                },
+                "federated": {
+                    "type": "boolean",
+                    "description": "Include answers from configured upstream documentation servers, attributed to each server (optional, defaults to true)"
                 }
             },
             "required": ["query"]
         });
-
+        
         let adk_query_tool = Tool {
             name: "adk_query".into(),
             description: Some("Query Google ADK documentation and concepts with current version awareness".into()),
@@ -114,7 +152,7 @@ impl ArkaftMcpServer {
             output_schema: None,
         };
         tools.push(adk_query_tool);
-
+        
         // Create review_rust_file tool
         let review_rust_file_schema = json!({
             "type": "object",
@@ -126,11 +164,52 @@ impl ArkaftMcpServer {
                 "file_content": {
                     "type": "string",
                     "description": "Content of the Rust file to analyze"
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["markdown", "plain", "json"],
+                    "description": "Output format: markdown, plain text, or a JSON document whose result field holds the findings with their exact source spans (optional, defaults to markdown)"
+                },
+                "checks": {
+                    "type": "array",
+                    "items": {
+                        "type": "string",
+                        "enum": ["translations", "architecture", "compliance", "organization"]
+                    },
+                    "description": "Analyzers to run (optional, defaults to all of them)"
+                },
+                "detect_translations": {
+                    "type": "boolean",
+                    "description": "Turn translation opportunity detection on or off, overriding checks (optional)"
+                },
+                "max_complexity": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Highest cyclomatic complexity accepted for a function before it is flagged (optional, defaults to 10)"
+                },
+                "max_nesting_depth": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Deepest control-flow nesting accepted in a function before it is flagged (optional, defaults to 4)"
+                },
+                "max_function_lines": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Most lines accepted in a function before it is flagged (optional, defaults to 50)"
+                },
+                "max_parameters": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Most parameters accepted in a function, not counting self, before it is flagged (optional, defaults to 5)"
+                },
+                "check_formatting": {
+                    "type": "boolean",
+                    "description": "Check the file against rustfmt and summarize the changes it would make; needs rustfmt on the server (optional, defaults to false)"
                 }
             },
             "required": ["file_path", "file_content"]
         });
-
+        
         let review_rust_file_tool = Tool {
             name: "review_rust_file".into(),
             description: Some("Review a Rust file for translation needs, ADK compliance, and architectural improvements".into()),
@@ -139,7 +218,92 @@ impl ArkaftMcpServer {
             output_schema: None,
         };
         tools.push(review_rust_file_tool);
-
+        
+        // Create review_config_file tool
+        let review_config_file_schema = json!({
+            "type": "object",
+            "properties": {
+                "file_path": {
+                    "type": "string",
+                    "description": "Path to the ADK agent config, ending in .yaml, .yml, .toml, or .json; the extension selects the parser"
+                },
+                "file_content": {
+                    "type": "string",
+                    "description": "Content of the agent config to analyze"
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["markdown", "plain", "json"],
+                    "description": "Output format: markdown, plain text, or a JSON document whose result field holds the issues (optional, defaults to markdown)"
+                }
+            },
+            "required": ["file_path", "file_content"]
+        });
+        
+        let review_config_file_tool = Tool {
+            name: "review_config_file".into(),
+            description: Some("Review an ADK agent config in YAML, TOML, or JSON for schema errors, unknown model identifiers, hard-coded API keys, and malformed environment variable references".into()),
+            input_schema: Arc::new(review_config_file_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(review_config_file_tool);
+        
+        // Create review_project tool
+        let review_project_schema = json!({
+            "type": "object",
+            "properties": {
+                "files": {
+                    "type": "object",
+                    "additionalProperties": {"type": "string"},
+                    "description": "Project files as a map of path, relative to the project root, to content; .rs files and Cargo.toml manifests are reviewed, Cargo.lock dependencies are audited, and other files ignored (provide this or root)"
+                },
+                "root": {
+                    "type": "string",
+                    "description": "Workspace root directory to read .rs files, Cargo.toml manifests, and Cargo.lock files from, skipping hidden directories and target (provide this or files)"
+                },
+                "check_yanked": {
+                    "type": "boolean",
+                    "description": "Look up locked dependencies in the crates.io index and report yanked releases; needs network access (optional, defaults to false)"
+                }
+            }
+        });
+        
+        let review_project_tool = Tool {
+            name: "review_project".into(),
+            description: Some("Review every Rust file and Cargo manifest of a project and audit its locked dependencies against RustSec advisories, returning totals per category, the files with the most findings, the module tree of each crate root, and each file's findings".into()),
+            input_schema: Arc::new(review_project_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(review_project_tool);
+        
+        // Create review_diff tool
+        let review_diff_schema = json!({
+            "type": "object",
+            "properties": {
+                "diff": {
+                    "type": "string",
+                    "description": "Unified diff to review, as produced by git diff; findings are reported only on added lines of .rs files and Cargo.toml manifests"
+                },
+                "base_files": {
+                    "type": "object",
+                    "additionalProperties": {"type": "string"},
+                    "description": "Content of each modified file before the diff, keyed by its path in the diff (optional; files added by the diff need no base, and modified files without one are skipped)"
+                }
+            },
+            "required": ["diff"]
+        });
+        
+        let review_diff_tool = Tool {
+            name: "review_diff".into(),
+            description: Some("Review the Rust files and Cargo manifests changed by a unified diff, reporting only findings on added lines so pre-existing issues are not flagged again".into()),
+            input_schema: Arc::new(review_diff_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(review_diff_tool);
+        
         // Create validate_architecture tool
         let validate_architecture_schema = json!({
             "type": "object",
@@ -154,11 +318,62 @@ impl ArkaftMcpServer {
                         "type": "string"
                     },
                     "description": "Optional code examples to validate (array of strings)"
+                },
+                "language": {
+                    "type": "string",
+                    "description": "Response language tag: en, pt-BR, es, or ja (optional, defaults to English)"
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["markdown", "plain", "json"],
+                    "description": "Output format: markdown, plain text, or a JSON document with fields, sections, and references (optional, defaults to markdown)"
+                },
+                "pattern": {
+                    "type": "string",
+                    "description": "Architecture pattern to validate against, e.g. adk_standard or multi_agent_team; its required components missing from the design are reported as findings (optional)"
+                },
+                "project_id": {
+                    "type": "string",
+                    "description": "Project identifier; the compliance score is recorded in the project's history for get_validation_trend (optional)"
+                },
+                "artifact": {
+                    "type": "boolean",
+                    "description": "Return a standalone compliance report, as Markdown followed by JSON, with the timestamp, ADK version, and rule-set version, for attaching to design docs or change tickets (optional, default false)"
+                },
+                "min_score": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": 100,
+                    "description": "Minimum compliance score for a pass verdict, replacing the configured threshold (optional)"
+                },
+                "strict": {
+                    "type": "boolean",
+                    "description": "Fail the verdict on warning findings as well as errors (optional, default false)"
+                },
+                "view": {
+                    "type": "string",
+                    "enum": ["report", "checklist"],
+                    "description": "Result view: findings and recommendations, or a pass/fail checklist per ADK criterion for design reviews (optional, defaults to report)"
+                },
+                "disabled_rules": {
+                    "type": "array",
+                    "items": {"type": "string"},
+                    "description": "Rule ids whose findings are dropped for this call, e.g. [\"todo_usage\"] (optional)"
+                },
+                "enabled_rules": {
+                    "type": "array",
+                    "items": {"type": "string"},
+                    "description": "Rule ids to re-enable for this call when the configured rule sets disable them (optional)"
+                },
+                "severity_overrides": {
+                    "type": "object",
+                    "additionalProperties": {"type": "string", "enum": ["error", "warning", "info"]},
+                    "description": "Severity to report per rule id for this call, e.g. {\"todo_usage\": \"error\"} (optional)"
                 }
             },
             "required": ["description"]
         });
-
+        
         let validate_architecture_tool = Tool {
             name: "validate_architecture".into(),
             description: Some("Validate architectural patterns against official Google ADK best practices".into()),
@@ -167,7 +382,7 @@ impl ArkaftMcpServer {
             output_schema: None,
         };
         tools.push(validate_architecture_tool);
-
+        
         // Create get_best_practices tool
         let get_best_practices_schema = json!({
             "type": "object",
@@ -178,12 +393,31 @@ impl ArkaftMcpServer {
                 },
                 "category": {
                     "type": "string",
-                    "description": "Specific category (architecture, performance, security, etc.) - optional"
+                    "description": "Specific category (see list_best_practice_categories for available categories) - optional"
+                },
+                "language": {
+                    "type": "string",
+                    "description": "Response language tag: en, pt-BR, es, or ja (optional, defaults to English)"
+                },
+                "detail": {
+                    "type": "string",
+                    "enum": ["full", "summary"],
+                    "description": "Response detail: full markdown or a summary with the headline answer and top references (optional, defaults to full)"
+                },
+                "max_length": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Maximum response length in characters; longer responses are summarized to fit (optional)"
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["markdown", "plain", "json"],
+                    "description": "Output format: markdown, plain text, or a JSON document with fields, sections, and references (optional, defaults to markdown)"
                 }
             },
             "required": ["scenario"]
         });
-
+        
         let get_best_practices_tool = Tool {
             name: "get_best_practices".into(),
             description: Some("Get official Google ADK best practices for specific scenarios".into()),
@@ -192,12 +426,432 @@ impl ArkaftMcpServer {
             output_schema: None,
         };
         tools.push(get_best_practices_tool);
-
+        
+        // Create adk_glossary tool
+        let adk_glossary_schema = json!({
+            "type": "object",
+            "properties": {
+                "term": {
+                    "type": "string",
+                    "description": "ADK term to define (optional, lists all terms when omitted)"
+                },
+                "version": {
+                    "type": "string",
+                    "description": "Specific ADK version to reference (optional, defaults to latest)"
+                },
+                "language": {
+                    "type": "string",
+                    "description": "Response language tag: en, pt-BR, es, or ja (optional, defaults to English)"
+                }
+            }
+        });
+        
+        let adk_glossary_tool = Tool {
+            name: "adk_glossary".into(),
+            description: Some("List or define Google ADK terminology (artifact, session, runner, planner, flow, etc.)".into()),
+            input_schema: Arc::new(adk_glossary_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(adk_glossary_tool);
+        
+        // Create adk_pattern tool
+        let adk_pattern_schema = json!({
+            "type": "object",
+            "properties": {
+                "pattern_name": {
+                    "type": "string",
+                    "description": "Implementation pattern key, e.g. 'session_management' (optional, lists all patterns when omitted)"
+                },
+                "version": {
+                    "type": "string",
+                    "description": "Specific ADK version to reference (optional, defaults to latest)"
+                },
+                "language": {
+                    "type": "string",
+                    "description": "Response language tag: en, pt-BR, es, or ja (optional, defaults to English)"
+                }
+            }
+        });
+        
+        let adk_pattern_tool = Tool {
+            name: "adk_pattern".into(),
+            description: Some("Get a Google ADK implementation pattern with code examples, or list available patterns".into()),
+            input_schema: Arc::new(adk_pattern_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(adk_pattern_tool);
+        
+        // Create list_adk_versions tool
+        let list_adk_versions_schema = json!({
+            "type": "object",
+            "properties": {
+                "language": {
+                    "type": "string",
+                    "description": "Response language tag: en, pt-BR, es, or ja (optional, defaults to English)"
+                }
+            }
+        });
+        
+        let list_adk_versions_tool = Tool {
+            name: "list_adk_versions".into(),
+            description: Some("List available Google ADK versions, aliases (latest/stable), the default version, and per-version support status".into()),
+            input_schema: Arc::new(list_adk_versions_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(list_adk_versions_tool);
+        
+        // Create search_code_examples tool
+        let search_code_examples_schema = json!({
+            "type": "object",
+            "properties": {
+                "query": {
+                    "type": "string",
+                    "description": "Keywords describing the example, e.g. 'guardrail callback' or 'cloud run deployment'"
+                },
+                "language": {
+                    "type": "string",
+                    "description": "Only return examples in this language, e.g. 'python', 'bash', 'yaml' (optional)"
+                },
+                "version": {
+                    "type": "string",
+                    "description": "Specific ADK version to reference (optional, defaults to latest)"
+                }
+            },
+            "required": ["query"]
+        });
+        
+        let search_code_examples_tool = Tool {
+            name: "search_code_examples".into(),
+            description: Some("Search code examples across all Google ADK implementation patterns by keyword and language, returning ranked snippets with explanations".into()),
+            input_schema: Arc::new(search_code_examples_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(search_code_examples_tool);
+        
+        // Create adk_availability tool
+        let adk_availability_schema = json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "description": "Concept or feature to look up, e.g. 'session' or 'memory service'"
+                },
+                "language": {
+                    "type": "string",
+                    "description": "Response language tag: en, pt-BR, es, or ja (optional, defaults to English)"
+                }
+            },
+            "required": ["name"]
+        });
+        
+        let adk_availability_tool = Tool {
+            name: "adk_availability".into(),
+            description: Some("Report which Google ADK versions support a concept or feature, when it was introduced, and when it was deprecated".into()),
+            input_schema: Arc::new(adk_availability_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(adk_availability_tool);
+        
+        // Create admin_export_knowledge tool
+        let admin_export_knowledge_schema = json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "Destination path of the JSON snapshot file, relative to the ADK_DATA_DIR data directory and confined to it"
+                },
+                "overwrite": {
+                    "type": "boolean",
+                    "description": "Replace the file if it already exists (optional, defaults to false)"
+                }
+            },
+            "required": ["path"]
+        });
+        
+        let admin_export_knowledge_tool = Tool {
+            name: "admin_export_knowledge".into(),
+            description: Some("Export the full in-memory knowledge base (all versions) to a JSON snapshot file for provisioning air-gapped deployments".into()),
+            input_schema: Arc::new(admin_export_knowledge_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(admin_export_knowledge_tool);
+        
+        // Create admin_import_knowledge tool
+        let admin_import_knowledge_schema = json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "Path of the JSON snapshot file produced by admin_export_knowledge, relative to the ADK_DATA_DIR data directory and confined to it"
+                }
+            },
+            "required": ["path"]
+        });
+        
+        let admin_import_knowledge_tool = Tool {
+            name: "admin_import_knowledge".into(),
+            description: Some("Merge a knowledge base snapshot file into the running server, preferring official and newer entries".into()),
+            input_schema: Arc::new(admin_import_knowledge_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(admin_import_knowledge_tool);
+        
+        // Create get_adk_template tool
+        let get_adk_template_schema = json!({
+            "type": "object",
+            "properties": {
+                "template_name": {
+                    "type": "string",
+                    "description": "Project template key: 'single_agent', 'multi_agent_team', or 'agent_with_tools' (optional, lists all templates when omitted)"
+                },
+                "version": {
+                    "type": "string",
+                    "description": "Specific ADK version to reference (optional, defaults to latest)"
+                },
+                "language": {
+                    "type": "string",
+                    "description": "Response language tag: en, pt-BR, es, or ja (optional, defaults to English)"
+                }
+            }
+        });
+        
+        let get_adk_template_tool = Tool {
+            name: "get_adk_template".into(),
+            description: Some("Get a ready-to-use Google ADK project scaffold (files with contents and setup steps), or list available templates".into()),
+            input_schema: Arc::new(get_adk_template_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(get_adk_template_tool);
+        
+        // Create list_best_practice_categories tool
+        let list_best_practice_categories_schema = json!({
+            "type": "object",
+            "properties": {
+                "version": {
+                    "type": "string",
+                    "description": "Specific ADK version to reference (optional, defaults to latest)"
+                },
+                "language": {
+                    "type": "string",
+                    "description": "Response language tag: en, pt-BR, es, or ja (optional, defaults to English)"
+                }
+            }
+        });
+        
+        let list_best_practice_categories_tool = Tool {
+            name: "list_best_practice_categories".into(),
+            description: Some("List the best practice categories in the knowledge base with practice counts, for use as the get_best_practices category".into()),
+            input_schema: Arc::new(list_best_practice_categories_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(list_best_practice_categories_tool);
+        
+        // Create admin_query_analytics tool
+        let admin_query_analytics_schema = json!({
+            "type": "object",
+            "properties": {
+                "limit": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Number of terms listed in each section (optional, defaults to 10)"
+                },
+                "reset": {
+                    "type": "boolean",
+                    "description": "Clear the recorded analytics after reporting (optional, defaults to false)"
+                }
+            }
+        });
+        
+        let admin_query_analytics_tool = Tool {
+            name: "admin_query_analytics".into(),
+            description: Some("Report the most-asked and most-missed adk_query topics from anonymized query terms, to show which knowledge areas to expand".into()),
+            input_schema: Arc::new(admin_query_analytics_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(admin_query_analytics_tool);
+        
+        // Create whats_new_in tool
+        let whats_new_in_schema = json!({
+            "type": "object",
+            "properties": {
+                "version": {
+                    "type": "string",
+                    "description": "ADK version or alias to summarize, e.g. '1.0.0' or 'latest'"
+                },
+                "language": {
+                    "type": "string",
+                    "description": "Response language tag: en, pt-BR, es, or ja (optional, defaults to English)"
+                }
+            },
+            "required": ["version"]
+        });
+        
+        let whats_new_in_tool = Tool {
+            name: "whats_new_in".into(),
+            description: Some("Summarize a Google ADK release: new features, breaking changes, deprecations, and notable fixes, with links, for evaluating an upgrade".into()),
+            input_schema: Arc::new(whats_new_in_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(whats_new_in_tool);
+        
+        // Create admin_version_aliases tool
+        let admin_version_aliases_schema = json!({
+            "type": "object",
+            "properties": {
+                "versions": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Versions to add to the available versions, e.g. ['1.2.0'] (optional)"
+                },
+                "aliases": {
+                    "type": "object",
+                    "additionalProperties": { "type": "string" },
+                    "description": "Aliases to define or repoint, e.g. {\"stable\": \"1.2.0\"} (optional)"
+                }
+            }
+        });
+        
+        let admin_version_aliases_tool = Tool {
+            name: "admin_version_aliases".into(),
+            description: Some("Add ADK versions and map aliases such as 'stable' to a release at runtime, then list the resulting version configuration".into()),
+            input_schema: Arc::new(admin_version_aliases_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(admin_version_aliases_tool);
+        
+        // Create admin_knowledge_stats tool
+        let admin_knowledge_stats_schema = json!({
+            "type": "object",
+            "properties": {}
+        });
+        
+        let admin_knowledge_stats_tool = Tool {
+            name: "admin_knowledge_stats".into(),
+            description: Some("Report knowledge base size per ADK version (concepts, best practices, patterns), last refresh time, and contributing sources, to verify that knowledge packs and documentation fetches loaded".into()),
+            input_schema: Arc::new(admin_knowledge_stats_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(admin_knowledge_stats_tool);
+        
+        // Create get_validation_trend tool
+        let get_validation_trend_schema = json!({
+            "type": "object",
+            "properties": {
+                "project_id": {
+                    "type": "string",
+                    "description": "Project identifier passed to validate_architecture"
+                },
+                "limit": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Number of most recent validations to include (optional, defaults to 20)"
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["markdown", "plain", "json"],
+                    "description": "Output format (optional, defaults to markdown)"
+                }
+            },
+            "required": ["project_id"]
+        });
+        
+        let get_validation_trend_tool = Tool {
+            name: "get_validation_trend".into(),
+            description: Some("Show a project's validate_architecture compliance scores over time, with the change since its first recorded validation, to track ADK compliance across sprints".into()),
+            input_schema: Arc::new(get_validation_trend_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(get_validation_trend_tool);
+        
+        // Create validate_architecture_batch tool
+        let validate_architecture_batch_schema = json!({
+            "type": "object",
+            "properties": {
+                "designs": {
+                    "type": "array",
+                    "minItems": 1,
+                    "maxItems": 20,
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "name": {
+                                "type": "string",
+                                "description": "Design name shown in the comparison, e.g. checkout-service (optional, designs are numbered otherwise)"
+                            },
+                            "description": {
+                                "type": "string",
+                                "description": "Description of the design"
+                            },
+                            "code_snippets": {
+                                "type": "array",
+                                "items": {"type": "string"},
+                                "description": "Code examples to validate with the description (optional)"
+                            }
+                        },
+                        "required": ["description"]
+                    },
+                    "description": "Designs to validate, e.g. one per microservice"
+                },
+                "language": {
+                    "type": "string",
+                    "description": "Response language tag: en, pt-BR, es, or ja (optional, defaults to English)"
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["markdown", "plain", "json"],
+                    "description": "Output format (optional, defaults to markdown)"
+                },
+                "pattern": {
+                    "type": "string",
+                    "description": "Architecture pattern every design is validated against, e.g. adk_standard (optional)"
+                },
+                "disabled_rules": {
+                    "type": "array",
+                    "items": {"type": "string"},
+                    "description": "Rule ids whose findings are dropped for every design (optional)"
+                },
+                "enabled_rules": {
+                    "type": "array",
+                    "items": {"type": "string"},
+                    "description": "Rule ids to re-enable when the configured rule sets disable them (optional)"
+                },
+                "severity_overrides": {
+                    "type": "object",
+                    "additionalProperties": {"type": "string", "enum": ["error", "warning", "info"]},
+                    "description": "Severity to report per rule id for every design (optional)"
+                }
+            },
+            "required": ["designs"]
+        });
+        
+        let validate_architecture_batch_tool = Tool {
+            name: "validate_architecture_batch".into(),
+            description: Some("Validate several architecture designs, such as the services of a system, concurrently, returning a table comparing their compliance followed by each design's findings".into()),
+            input_schema: Arc::new(validate_architecture_batch_schema.as_object().unwrap().clone()),
+            annotations: None,
+            output_schema: None,
+        };
+        tools.push(validate_architecture_batch_tool);
+        
         info!("Created {} MCP tools with proper schemas", tools.len());
         
         Ok(tools)
     }
-
+    
     /// Start the MCP server and begin protocol handling
     pub async fn start(&mut self) -> Result<()> {
         // Initialize server if not already done
@@ -207,7 +861,7 @@ impl ArkaftMcpServer {
                 anyhow::anyhow!("Server initialization failed: {}", e)
             })?;
         }
-
+        
         info!("Starting Arkaft Google ADK MCP Server v{}", self.version);
         
         // Create tools for the server
@@ -216,10 +870,73 @@ impl ArkaftMcpServer {
             anyhow::anyhow!("Tool creation failed: {}", e)
         })?;
         
-        // Create tool handler with the defined tools and metrics
-        let tool_handler = ToolHandler::new(tools.clone(), Arc::clone(&self.metrics));
+        // Read operator-configured versions, aliases, and release channels
+        let overrides = match self.config.version_aliases_file.as_deref() {
+            Some(path) => match VersionOverrides::read_from(std::path::Path::new(path)).await {
+                Ok(overrides) => Some(overrides),
+                Err(e) => {
+                    warn!("Ignoring version aliases file {}: {}", path, e);
+                    None
+                }
+            },
+            None => None,
+        };
+        
+        // Build the experts once, aggregating bundled knowledge with any configured local packs;
+        // release channels with their own feed contribute pre-release documentation
+        let mut sources = default_sources(self.config.knowledge_packs.as_deref());
+        for (channel, feed_url) in overrides.iter().flat_map(VersionOverrides::channel_feeds) {
+            match WebDocsFetcher::new(feed_url) {
+                Ok(fetcher) => sources.push(Arc::new(fetcher)),
+                Err(e) => warn!("Skipping documentation feed of the {} channel: {}", channel.name(), e),
+            }
+        }
+        let mut knowledge_base = AdkKnowledgeBase::from_sources(&sources).await;
+        
+        // Apply operator-configured versions, aliases, and channels; invalid ones keep the bundled aliases
+        if let Some(overrides) = overrides {
+            match knowledge_base.apply_version_overrides(overrides) {
+                Ok(()) => info!("Applied version aliases and release channels"),
+                Err(e) => warn!("Ignoring version aliases file: {}", e),
+            }
+        }
+        
+        // Query operator-configured upstream documentation servers alongside the knowledge base
+        let federation = match self.config.upstream_servers_file.as_deref() {
+            Some(path) => match FederationConfig::read_from(std::path::Path::new(path)).await {
+                Ok(config) => Federation::from_config(config),
+                Err(e) => {
+                    warn!("Ignoring upstream servers file {}: {}", path, e);
+                    Federation::default()
+                }
+            },
+            None => Federation::default(),
+        };
+        if !federation.is_empty() {
+            info!("Federating adk_query to upstream servers: {}", federation.upstream_names().join(", "));
+        }
+        
+        let experts = SharedExperts::with_knowledge_base(knowledge_base).with_federation(federation);
+        self.experts = Some(experts.clone());
+        
+        // Layer team rule sets over the built-in validation rules; invalid ones keep the built-ins
+        if let Some(path) = self.config.rule_sets.as_deref() {
+            match load_validation_rules(std::path::Path::new(path)).await {
+                Ok(rules) => {
+                    info!("Loaded validation rule sets from {}", path);
+                    experts.set_validation_rules(rules).await;
+                }
+                Err(e) => warn!("Ignoring validation rule sets at {}: {}", path, e),
+            }
+        }
+        
+        // Create tool handler with the defined tools, metrics, and shared experts
+        let tool_handler = ToolHandler::with_experts(tools.clone(), Arc::clone(&self.metrics), experts.clone());
         self.tool_handler = Some(tool_handler);
         
+        // Keep documentation fresh in the background when enabled
+        self.start_auto_update(experts).await;
+        
         // Initialize MCP protocol integration
         info!("Initializing MCP protocol integration with stdio transport");
         
@@ -253,28 +970,66 @@ impl ArkaftMcpServer {
         
         Ok(())
     }
-
+    
     /// Shutdown the MCP server gracefully
     pub async fn shutdown(&mut self) -> Result<()> {
         info!("Shutting down Arkaft Google ADK MCP Server");
         
+        if let Some(task) = self.update_task.take() {
+            task.abort();
+        }
+        
         self.initialized = false;
         
         info!("MCP server shutdown completed");
         
         Ok(())
     }
-
+    
     /// Get server information
     pub fn info(&self) -> (String, String) {
         (self.config.server_name.clone(), self.version.clone())
     }
-
+    
     /// Get server configuration
     pub fn config(&self) -> &ServerConfig {
         &self.config
     }
     
+    /// Get the experts shared across tool calls, once the server has started
+    pub fn experts(&self) -> Option<SharedExperts> {
+        self.experts.clone()
+    }
+    
+    /// Start scheduled documentation updates if a feed is configured and auto-update is enabled
+    async fn start_auto_update(&mut self, experts: SharedExperts) {
+        if self.update_task.is_some() {
+            return;
+        }
+        
+        let Some(feed_url) = self.config.update_feed_url.clone() else {
+            info!("No documentation update feed configured; scheduled updates are off");
+            return;
+        };
+        
+        let fetcher = match WebDocsFetcher::new(feed_url) {
+            Ok(fetcher) => fetcher,
+            Err(e) => {
+                log_error_with_severity(&e, "auto_update_setup");
+                return;
+            }
+        };
+        
+        let updater = AutoUpdater::new(
+            AutoUpdateSettings::from_env(),
+            Arc::new(fetcher),
+            experts,
+            Arc::clone(&self.metrics),
+        );
+        
+        self.update_task = updater.spawn_if_enabled().await;
+    }
+    
     /// Get server metrics
     pub fn metrics(&self) -> Arc<ServerMetrics> {
         Arc::clone(&self.metrics)
@@ -292,11 +1047,17 @@ impl ArkaftMcpServer {
 pub struct ToolHandler {
     tools: Vec<Tool>,
     metrics: Arc<ServerMetrics>,
+    experts: SharedExperts,
 }
 
 impl ToolHandler {
     pub fn new(tools: Vec<Tool>, metrics: Arc<ServerMetrics>) -> Self {
-        Self { tools, metrics }
+        Self::with_experts(tools, metrics, SharedExperts::new())
+    }
+    
+    /// Create a tool handler that reuses already-built experts
+    pub fn with_experts(tools: Vec<Tool>, metrics: Arc<ServerMetrics>, experts: SharedExperts) -> Self {
+        Self { tools, metrics, experts }
     }
     
     /// Get available tools
@@ -311,16 +1072,70 @@ impl ToolHandler {
         
         let result = match tool_name {
             "adk_query" => {
-                handlers::handle_adk_query(arguments).await
+                handlers::handle_adk_query(arguments, &self.experts).await
             },
             "review_rust_file" => {
                 handlers::handle_review_rust_file(arguments).await
             },
+            "review_config_file" => {
+                handlers::handle_review_config_file(arguments).await
+            },
+            "review_project" => {
+                handlers::handle_review_project(arguments).await
+            },
+            "review_diff" => {
+                handlers::handle_review_diff(arguments).await
+            },
             "validate_architecture" => {
-                handlers::handle_validate_architecture(arguments).await
+                handlers::handle_validate_architecture(arguments, &self.experts).await
             },
             "get_best_practices" => {
-                handlers::handle_get_best_practices(arguments).await
+                handlers::handle_get_best_practices(arguments, &self.experts).await
+            },
+            "adk_glossary" => {
+                handlers::handle_adk_glossary(arguments, &self.experts).await
+            },
+            "adk_pattern" => {
+                handlers::handle_adk_pattern(arguments, &self.experts).await
+            },
+            "list_adk_versions" => {
+                handlers::handle_list_adk_versions(arguments, &self.experts).await
+            },
+            "search_code_examples" => {
+                handlers::handle_search_code_examples(arguments, &self.experts).await
+            },
+            "adk_availability" => {
+                handlers::handle_adk_availability(arguments, &self.experts).await
+            },
+            "admin_export_knowledge" => {
+                handlers::handle_admin_export_knowledge(arguments, &self.experts).await
+            },
+            "admin_import_knowledge" => {
+                handlers::handle_admin_import_knowledge(arguments, &self.experts).await
+            },
+            "get_adk_template" => {
+                handlers::handle_get_adk_template(arguments, &self.experts).await
+            },
+            "list_best_practice_categories" => {
+                handlers::handle_list_best_practice_categories(arguments, &self.experts).await
+            },
+            "admin_query_analytics" => {
+                handlers::handle_admin_query_analytics(arguments, &self.experts).await
+            },
+            "whats_new_in" => {
+                handlers::handle_whats_new_in(arguments, &self.experts).await
+            },
+            "admin_version_aliases" => {
+                handlers::handle_admin_version_aliases(arguments, &self.experts).await
+            },
+            "admin_knowledge_stats" => {
+                handlers::handle_admin_knowledge_stats(arguments, &self.experts).await
+            },
+            "get_validation_trend" => {
+                handlers::handle_get_validation_trend(arguments, &self.experts).await
+            },
+            "validate_architecture_batch" => {
+                handlers::handle_validate_architecture_batch(arguments, &self.experts).await
             },
             _ => {
                 let error = crate::utils::error::ArkaftMcpError::tool_execution(
@@ -350,7 +1165,7 @@ impl ToolHandler {
         }
         
         // Perform periodic health checks
-        if self.metrics.total_tool_calls.load(std::sync::atomic::Ordering::Relaxed) % 100 == 0 {
+        if self.metrics.total_tool_calls.load(std::sync::atomic::Ordering::Relaxed).is_multiple_of(100) {
             if let Err(e) = validate_server_health(&self.metrics) {
                 log_error_with_severity(&e, "periodic_health_check");
             }
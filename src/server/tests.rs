@@ -1,12 +1,13 @@
 //! Unit tests for MCP server protocol compliance
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
     use crate::ArkaftMcpServer;
     use crate::server::ToolHandler;
     use serde_json::json;
     use tokio;
-
+    
     #[tokio::test]
     async fn test_server_initialization() {
         let mut server = ArkaftMcpServer::new();
@@ -20,7 +21,7 @@ mod tests {
         assert!(result.is_ok());
         assert!(server.initialized);
     }
-
+    
     #[tokio::test]
     async fn test_server_capabilities() {
         let server = ArkaftMcpServer::new();
@@ -32,23 +33,41 @@ mod tests {
         let tools_cap = server.capabilities.tools.as_ref().unwrap();
         assert_eq!(tools_cap.list_changed, Some(false));
     }
-
+    
     #[tokio::test]
     async fn test_tool_definitions() {
         let server = ArkaftMcpServer::new();
         
         // Test tool creation
         let tools = server.create_tool_definitions().unwrap();
-        assert_eq!(tools.len(), 4);
+        assert_eq!(tools.len(), 22);
         
         // Test tool names
         let tool_names: Vec<&str> = tools.iter().map(|t| t.name.as_ref()).collect();
         assert!(tool_names.contains(&"adk_query"));
         assert!(tool_names.contains(&"review_rust_file"));
+        assert!(tool_names.contains(&"review_config_file"));
+        assert!(tool_names.contains(&"review_project"));
+        assert!(tool_names.contains(&"review_diff"));
         assert!(tool_names.contains(&"validate_architecture"));
         assert!(tool_names.contains(&"get_best_practices"));
+        assert!(tool_names.contains(&"adk_glossary"));
+        assert!(tool_names.contains(&"adk_pattern"));
+        assert!(tool_names.contains(&"list_adk_versions"));
+        assert!(tool_names.contains(&"search_code_examples"));
+        assert!(tool_names.contains(&"adk_availability"));
+        assert!(tool_names.contains(&"admin_export_knowledge"));
+        assert!(tool_names.contains(&"admin_import_knowledge"));
+        assert!(tool_names.contains(&"get_adk_template"));
+        assert!(tool_names.contains(&"list_best_practice_categories"));
+        assert!(tool_names.contains(&"admin_query_analytics"));
+        assert!(tool_names.contains(&"whats_new_in"));
+        assert!(tool_names.contains(&"admin_version_aliases"));
+        assert!(tool_names.contains(&"admin_knowledge_stats"));
+        assert!(tool_names.contains(&"get_validation_trend"));
+        assert!(tool_names.contains(&"validate_architecture_batch"));
     }
-
+    
     #[tokio::test]
     async fn test_adk_query_tool_schema() {
         let server = ArkaftMcpServer::new();
@@ -65,7 +84,7 @@ mod tests {
         assert!(schema.contains_key("properties"));
         assert!(schema.contains_key("required"));
     }
-
+    
     #[tokio::test]
     async fn test_tool_handler_creation() {
         let server = ArkaftMcpServer::new();
@@ -75,9 +94,9 @@ mod tests {
         let handler = ToolHandler::new(tools.clone(), metrics);
         
         // Test handler has correct number of tools
-        assert_eq!(handler.get_tools().len(), 4);
+        assert_eq!(handler.get_tools().len(), 22);
     }
-
+    
     #[tokio::test]
     async fn test_tool_handler_adk_query() {
         let server = ArkaftMcpServer::new();
@@ -97,7 +116,7 @@ mod tests {
         let response = result.unwrap();
         assert!(response.is_object());
     }
-
+    
     #[tokio::test]
     async fn test_tool_handler_review_rust_file() {
         let server = ArkaftMcpServer::new();
@@ -118,7 +137,7 @@ mod tests {
         let response = result.unwrap();
         assert!(response.is_object());
     }
-
+    
     #[tokio::test]
     async fn test_tool_handler_validate_architecture() {
         let server = ArkaftMcpServer::new();
@@ -138,7 +157,7 @@ mod tests {
         let response = result.unwrap();
         assert!(response.is_object());
     }
-
+    
     #[tokio::test]
     async fn test_tool_handler_get_best_practices() {
         let server = ArkaftMcpServer::new();
@@ -157,8 +176,14 @@ mod tests {
         // Test result contains expected structure
         let response = result.unwrap();
         assert!(response.is_object());
+        
+        // Unmatched scenarios get general guidance, marked as such
+        let result = handler.handle_tool_call("get_best_practices", json!({"scenario": "xyzzy frobnicator"})).await.unwrap();
+        let text = result["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("**General guidance:**"));
+        assert!(text.contains("Start from a Single Root Agent"));
     }
-
+    
     #[tokio::test]
     async fn test_tool_handler_unknown_tool() {
         let server = ArkaftMcpServer::new();
@@ -176,7 +201,7 @@ mod tests {
         let error = result.unwrap_err();
         assert!(error.to_string().contains("Unknown tool"));
     }
-
+    
     #[tokio::test]
     async fn test_server_startup_and_shutdown() {
         let mut server = ArkaftMcpServer::new();
@@ -190,7 +215,7 @@ mod tests {
         assert!(shutdown_result.is_ok());
         assert!(!server.initialized);
     }
-
+    
     #[tokio::test]
     async fn test_server_info() {
         let server = ArkaftMcpServer::new();
@@ -200,7 +225,7 @@ mod tests {
         assert!(!version.is_empty());
         assert_eq!(version, env!("CARGO_PKG_VERSION"));
     }
-
+    
     #[tokio::test]
     async fn test_server_config() {
         let server = ArkaftMcpServer::new();
@@ -215,8 +240,9 @@ mod tests {
   // Comprehensive tests for adk_query functionality
     mod adk_query_tests {
         use crate::server::handlers::{handle_adk_query, AdkQueryParams};
+        use crate::expert::SharedExperts;
         use serde_json::json;
-
+        
         #[tokio::test]
         async fn test_adk_query_valid_parameters() {
             // Test with valid query parameter
@@ -224,7 +250,7 @@ mod tests {
                 "query": "What is Google ADK?"
             });
             
-            let result = handle_adk_query(params).await;
+            let result = handle_adk_query(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -233,7 +259,7 @@ mod tests {
             assert!(response["content"][0]["type"] == "text");
             assert!(response["content"][0]["text"].is_string());
         }
-
+        
         #[tokio::test]
         async fn test_adk_query_with_version() {
             // Test with query and version parameters
@@ -242,7 +268,7 @@ mod tests {
                 "version": "1.0.0"
             });
             
-            let result = handle_adk_query(params).await;
+            let result = handle_adk_query(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -253,7 +279,7 @@ mod tests {
             let text = response["content"][0]["text"].as_str().unwrap();
             assert!(text.contains("1.0.0") || text.contains("version"));
         }
-
+        
         #[tokio::test]
         async fn test_adk_query_empty_query() {
             // Test with empty query
@@ -261,13 +287,13 @@ mod tests {
                 "query": ""
             });
             
-            let result = handle_adk_query(params).await;
+            let result = handle_adk_query(params, &SharedExperts::new()).await;
             assert!(result.is_err());
             
             let error = result.unwrap_err();
             assert!(error.to_string().contains("empty"));
         }
-
+        
         #[tokio::test]
         async fn test_adk_query_whitespace_only_query() {
             // Test with whitespace-only query
@@ -275,13 +301,13 @@ mod tests {
                 "query": "   \t\n   "
             });
             
-            let result = handle_adk_query(params).await;
+            let result = handle_adk_query(params, &SharedExperts::new()).await;
             assert!(result.is_err());
             
             let error = result.unwrap_err();
             assert!(error.to_string().contains("empty"));
         }
-
+        
         #[tokio::test]
         async fn test_adk_query_missing_query_parameter() {
             // Test with missing query parameter
@@ -289,13 +315,13 @@ mod tests {
                 "version": "1.0.0"
             });
             
-            let result = handle_adk_query(params).await;
+            let result = handle_adk_query(params, &SharedExperts::new()).await;
             assert!(result.is_err());
             
             let error = result.unwrap_err();
             assert!(error.to_string().contains("Invalid parameters"));
         }
-
+        
         #[tokio::test]
         async fn test_adk_query_invalid_json() {
             // Test with invalid parameter structure
@@ -303,13 +329,13 @@ mod tests {
                 "query": 123  // Should be string, not number
             });
             
-            let result = handle_adk_query(params).await;
+            let result = handle_adk_query(params, &SharedExperts::new()).await;
             assert!(result.is_err());
             
             let error = result.unwrap_err();
             assert!(error.to_string().contains("Invalid parameters"));
         }
-
+        
         #[tokio::test]
         async fn test_adk_query_response_structure() {
             // Test response structure compliance
@@ -317,7 +343,7 @@ mod tests {
                 "query": "Google ADK architecture patterns"
             });
             
-            let result = handle_adk_query(params).await;
+            let result = handle_adk_query(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -334,7 +360,7 @@ mod tests {
             assert!(content_item.get("text").is_some());
             assert!(content_item["text"].is_string());
         }
-
+        
         #[tokio::test]
         async fn test_adk_query_official_documentation_references() {
             // Test that responses include official documentation references
@@ -342,7 +368,7 @@ mod tests {
                 "query": "ADK quickstart guide"
             });
             
-            let result = handle_adk_query(params).await;
+            let result = handle_adk_query(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -353,7 +379,7 @@ mod tests {
                    text.contains("Official References") ||
                    text.contains("quickstart"));
         }
-
+        
         #[tokio::test]
         async fn test_adk_query_version_specific_information() {
             // Test version-specific information retrieval
@@ -367,8 +393,8 @@ mod tests {
                 "version": "1.0.0"
             });
             
-            let result_latest = handle_adk_query(params_latest).await;
-            let result_specific = handle_adk_query(params_specific).await;
+            let result_latest = handle_adk_query(params_latest, &SharedExperts::new()).await;
+            let result_specific = handle_adk_query(params_specific, &SharedExperts::new()).await;
             
             assert!(result_latest.is_ok());
             assert!(result_specific.is_ok());
@@ -383,23 +409,28 @@ mod tests {
             assert!(text_latest.contains("latest") || text_latest.contains("version"));
             assert!(text_specific.contains("1.0.0") || text_specific.contains("version"));
         }
-
+        
         #[tokio::test]
         async fn test_adk_query_parameter_validation() {
             // Test parameter validation with AdkQueryParams struct
             let valid_params = AdkQueryParams {
                 query: "Valid query".to_string(),
                 version: Some("1.0.0".to_string()),
+                language: None,
+                detail: None,
+                max_length: None,
+                format: None,
+                federated: None,
             };
             
             assert!(!valid_params.query.is_empty());
             assert!(valid_params.version.is_some());
             
             let params_json = serde_json::to_value(&valid_params).unwrap();
-            let result = handle_adk_query(params_json).await;
+            let result = handle_adk_query(params_json, &SharedExperts::new()).await;
             assert!(result.is_ok());
         }
-
+        
         #[tokio::test]
         async fn test_adk_query_concept_search() {
             // Test searching for specific ADK concepts
@@ -415,7 +446,7 @@ mod tests {
                     "query": query
                 });
                 
-                let result = handle_adk_query(params).await;
+                let result = handle_adk_query(params, &SharedExperts::new()).await;
                 assert!(result.is_ok(), "Failed for query: {}", query);
                 
                 let response = result.unwrap();
@@ -423,7 +454,7 @@ mod tests {
                 assert!(!text.is_empty(), "Empty response for query: {}", query);
             }
         }
-
+        
         #[tokio::test]
         async fn test_adk_query_implementation_guidance() {
             // Test that queries return implementation guidance
@@ -431,7 +462,7 @@ mod tests {
                 "query": "How to implement ADK patterns?"
             });
             
-            let result = handle_adk_query(params).await;
+            let result = handle_adk_query(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -444,12 +475,13 @@ mod tests {
                    text.to_lowercase().contains("practice"));
         }
     }
-
+    
     // Comprehensive tests for validate_architecture functionality
     mod validate_architecture_tests {
         use crate::server::handlers::{handle_validate_architecture, ValidateArchitectureParams};
+        use crate::expert::SharedExperts;
         use serde_json::json;
-
+        
         #[tokio::test]
         async fn test_validate_architecture_valid_parameters() {
             // Test with valid description parameter
@@ -457,7 +489,7 @@ mod tests {
                 "description": "Microservices architecture using async patterns and proper error handling"
             });
             
-            let result = handle_validate_architecture(params).await;
+            let result = handle_validate_architecture(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -466,7 +498,7 @@ mod tests {
             assert!(response["content"][0]["type"] == "text");
             assert!(response["content"][0]["text"].is_string());
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_with_code_snippets() {
             // Test with description and code snippets
@@ -478,7 +510,7 @@ mod tests {
                 ]
             });
             
-            let result = handle_validate_architecture(params).await;
+            let result = handle_validate_architecture(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -487,7 +519,7 @@ mod tests {
             // Should detect issues with panic! and unwrap()
             assert!(text.contains("panic") || text.contains("unwrap") || text.contains("finding"));
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_with_version() {
             // Test with specific ADK version
@@ -496,14 +528,14 @@ mod tests {
                 "version": "1.0.0"
             });
             
-            let result = handle_validate_architecture(params).await;
+            let result = handle_validate_architecture(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
             let text = response["content"][0]["text"].as_str().unwrap();
             assert!(text.contains("1.0.0") || text.contains("version") || text.contains("Compliance"));
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_empty_description() {
             // Test with empty description
@@ -511,13 +543,13 @@ mod tests {
                 "description": ""
             });
             
-            let result = handle_validate_architecture(params).await;
+            let result = handle_validate_architecture(params, &SharedExperts::new()).await;
             assert!(result.is_err());
             
             let error = result.unwrap_err();
             assert!(error.to_string().contains("empty"));
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_whitespace_description() {
             // Test with whitespace-only description
@@ -525,13 +557,13 @@ mod tests {
                 "description": "   \t\n   "
             });
             
-            let result = handle_validate_architecture(params).await;
+            let result = handle_validate_architecture(params, &SharedExperts::new()).await;
             assert!(result.is_err());
             
             let error = result.unwrap_err();
             assert!(error.to_string().contains("empty"));
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_missing_description() {
             // Test with missing description parameter
@@ -539,13 +571,13 @@ mod tests {
                 "code_snippets": ["fn main() {}"]
             });
             
-            let result = handle_validate_architecture(params).await;
+            let result = handle_validate_architecture(params, &SharedExperts::new()).await;
             assert!(result.is_err());
             
             let error = result.unwrap_err();
             assert!(error.to_string().contains("Invalid parameters"));
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_compliance_scoring() {
             // Test that response includes compliance scoring
@@ -553,7 +585,7 @@ mod tests {
                 "description": "Well-designed ADK application with proper async patterns and error handling"
             });
             
-            let result = handle_validate_architecture(params).await;
+            let result = handle_validate_architecture(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -564,7 +596,7 @@ mod tests {
                    text.contains("Score") ||
                    text.contains("COMPLIANT"));
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_findings_and_recommendations() {
             // Test that problematic architecture generates findings and recommendations
@@ -576,7 +608,7 @@ mod tests {
                 ]
             });
             
-            let result = handle_validate_architecture(params).await;
+            let result = handle_validate_architecture(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -588,7 +620,7 @@ mod tests {
                    text.contains("Suggested Fix") ||
                    text.contains("🔴") || text.contains("🟡"));
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_official_documentation_refs() {
             // Test that response includes official documentation references
@@ -596,7 +628,7 @@ mod tests {
                 "description": "Standard microservices architecture"
             });
             
-            let result = handle_validate_architecture(params).await;
+            let result = handle_validate_architecture(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -607,7 +639,7 @@ mod tests {
                    text.contains("Official Documentation") ||
                    text.contains("Reference"));
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_parameter_struct() {
             // Test parameter validation with ValidateArchitectureParams struct
@@ -615,6 +647,17 @@ mod tests {
                 description: "Valid architecture description".to_string(),
                 code_snippets: Some(vec!["fn main() {}".to_string()]),
                 version: Some("1.0.0".to_string()),
+                language: None,
+                format: None,
+                disabled_rules: None,
+                enabled_rules: None,
+                severity_overrides: None,
+                view: None,
+                pattern: None,
+                project_id: None,
+                artifact: None,
+                min_score: None,
+                strict: None,
             };
             
             assert!(!valid_params.description.is_empty());
@@ -622,10 +665,10 @@ mod tests {
             assert!(valid_params.version.is_some());
             
             let params_json = serde_json::to_value(&valid_params).unwrap();
-            let result = handle_validate_architecture(params_json).await;
+            let result = handle_validate_architecture(params_json, &SharedExperts::new()).await;
             assert!(result.is_ok());
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_adk_patterns() {
             // Test validation against specific ADK patterns
@@ -640,7 +683,7 @@ mod tests {
                     "description": pattern
                 });
                 
-                let result = handle_validate_architecture(params).await;
+                let result = handle_validate_architecture(params, &SharedExperts::new()).await;
                 assert!(result.is_ok(), "Failed for pattern: {}", pattern);
                 
                 let response = result.unwrap();
@@ -648,7 +691,7 @@ mod tests {
                 assert!(!text.is_empty(), "Empty response for pattern: {}", pattern);
             }
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_anti_patterns() {
             // Test detection of anti-patterns
@@ -663,7 +706,7 @@ mod tests {
                     "description": pattern
                 });
                 
-                let result = handle_validate_architecture(params).await;
+                let result = handle_validate_architecture(params, &SharedExperts::new()).await;
                 assert!(result.is_ok(), "Failed for anti-pattern: {}", pattern);
                 
                 let response = result.unwrap();
@@ -677,7 +720,7 @@ mod tests {
                        "No issues detected for anti-pattern: {}", pattern);
             }
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_response_structure() {
             // Test MCP response structure compliance
@@ -685,7 +728,7 @@ mod tests {
                 "description": "Standard web application architecture"
             });
             
-            let result = handle_validate_architecture(params).await;
+            let result = handle_validate_architecture(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -702,7 +745,7 @@ mod tests {
             assert!(content_item.get("text").is_some());
             assert!(content_item["text"].is_string());
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_error_handling_validation() {
             // Test specific validation of error handling patterns
@@ -714,7 +757,7 @@ mod tests {
                 ]
             });
             
-            let result = handle_validate_architecture(params).await;
+            let result = handle_validate_architecture(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -723,7 +766,7 @@ mod tests {
             // Should recognize good Result pattern and flag panic usage
             assert!(text.contains("panic") || text.contains("error handling"));
         }
-
+        
         #[tokio::test]
         async fn test_validate_architecture_async_pattern_validation() {
             // Test validation of async patterns
@@ -731,7 +774,7 @@ mod tests {
                 "description": "Async-based architecture with non-blocking operations"
             });
             
-            let result = handle_validate_architecture(params).await;
+            let result = handle_validate_architecture(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -744,12 +787,13 @@ mod tests {
                    text.contains("architecture"));
         }
     }
-
+    
     // Comprehensive tests for get_best_practices functionality  
     mod get_best_practices_tests {
         use crate::server::handlers::{handle_get_best_practices, GetBestPracticesParams};
+        use crate::expert::SharedExperts;
         use serde_json::json;
-
+        
         #[tokio::test]
         async fn test_get_best_practices_valid_parameters() {
             // Test with valid scenario parameter
@@ -757,7 +801,7 @@ mod tests {
                 "scenario": "API development"
             });
             
-            let result = handle_get_best_practices(params).await;
+            let result = handle_get_best_practices(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -766,7 +810,7 @@ mod tests {
             assert!(response["content"][0]["type"] == "text");
             assert!(response["content"][0]["text"].is_string());
         }
-
+        
         #[tokio::test]
         async fn test_get_best_practices_with_category() {
             // Test with scenario and category parameters
@@ -775,7 +819,7 @@ mod tests {
                 "category": "architecture"
             });
             
-            let result = handle_get_best_practices(params).await;
+            let result = handle_get_best_practices(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -784,7 +828,7 @@ mod tests {
             // Should contain category-specific information
             assert!(text.contains("architecture") || text.contains("Best Practices"));
         }
-
+        
         #[tokio::test]
         async fn test_get_best_practices_with_version() {
             // Test with specific ADK version
@@ -793,14 +837,14 @@ mod tests {
                 "version": "1.0.0"
             });
             
-            let result = handle_get_best_practices(params).await;
+            let result = handle_get_best_practices(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
             let text = response["content"][0]["text"].as_str().unwrap();
             assert!(text.contains("1.0.0") || text.contains("Version"));
         }
-
+        
         #[tokio::test]
         async fn test_get_best_practices_empty_scenario() {
             // Test with empty scenario
@@ -808,13 +852,13 @@ mod tests {
                 "scenario": ""
             });
             
-            let result = handle_get_best_practices(params).await;
+            let result = handle_get_best_practices(params, &SharedExperts::new()).await;
             assert!(result.is_err());
             
             let error = result.unwrap_err();
             assert!(error.to_string().contains("empty"));
         }
-
+        
         #[tokio::test]
         async fn test_get_best_practices_missing_scenario() {
             // Test with missing scenario parameter
@@ -822,13 +866,13 @@ mod tests {
                 "category": "performance"
             });
             
-            let result = handle_get_best_practices(params).await;
+            let result = handle_get_best_practices(params, &SharedExperts::new()).await;
             assert!(result.is_err());
             
             let error = result.unwrap_err();
             assert!(error.to_string().contains("Invalid parameters"));
         }
-
+        
         #[tokio::test]
         async fn test_get_best_practices_response_structure() {
             // Test MCP response structure compliance
@@ -836,7 +880,7 @@ mod tests {
                 "scenario": "microservices development"
             });
             
-            let result = handle_get_best_practices(params).await;
+            let result = handle_get_best_practices(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -853,7 +897,7 @@ mod tests {
             assert!(content_item.get("text").is_some());
             assert!(content_item["text"].is_string());
         }
-
+        
         #[tokio::test]
         async fn test_get_best_practices_official_documentation() {
             // Test that response includes official documentation references
@@ -861,7 +905,7 @@ mod tests {
                 "scenario": "ADK project setup"
             });
             
-            let result = handle_get_best_practices(params).await;
+            let result = handle_get_best_practices(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -872,7 +916,7 @@ mod tests {
                    text.contains("Official Documentation") ||
                    text.contains("Reference"));
         }
-
+        
         #[tokio::test]
         async fn test_get_best_practices_parameter_struct() {
             // Test parameter validation with GetBestPracticesParams struct
@@ -880,6 +924,10 @@ mod tests {
                 scenario: "Valid scenario".to_string(),
                 category: Some("architecture".to_string()),
                 version: Some("1.0.0".to_string()),
+                language: None,
+                detail: None,
+                max_length: None,
+                format: None,
             };
             
             assert!(!valid_params.scenario.is_empty());
@@ -887,10 +935,10 @@ mod tests {
             assert!(valid_params.version.is_some());
             
             let params_json = serde_json::to_value(&valid_params).unwrap();
-            let result = handle_get_best_practices(params_json).await;
+            let result = handle_get_best_practices(params_json, &SharedExperts::new()).await;
             assert!(result.is_ok());
         }
-
+        
         #[tokio::test]
         async fn test_get_best_practices_categories() {
             // Test different categories
@@ -907,7 +955,7 @@ mod tests {
                     "category": category
                 });
                 
-                let result = handle_get_best_practices(params).await;
+                let result = handle_get_best_practices(params, &SharedExperts::new()).await;
                 assert!(result.is_ok(), "Failed for category: {}", category);
                 
                 let response = result.unwrap();
@@ -915,7 +963,7 @@ mod tests {
                 assert!(!text.is_empty(), "Empty response for category: {}", category);
             }
         }
-
+        
         #[tokio::test]
         async fn test_get_best_practices_scenarios() {
             // Test different development scenarios
@@ -932,7 +980,7 @@ mod tests {
                     "scenario": scenario
                 });
                 
-                let result = handle_get_best_practices(params).await;
+                let result = handle_get_best_practices(params, &SharedExperts::new()).await;
                 assert!(result.is_ok(), "Failed for scenario: {}", scenario);
                 
                 let response = result.unwrap();
@@ -940,7 +988,7 @@ mod tests {
                 assert!(!text.is_empty(), "Empty response for scenario: {}", scenario);
             }
         }
-
+        
         #[tokio::test]
         async fn test_get_best_practices_implementation_patterns() {
             // Test that response includes implementation patterns
@@ -948,7 +996,7 @@ mod tests {
                 "scenario": "application architecture"
             });
             
-            let result = handle_get_best_practices(params).await;
+            let result = handle_get_best_practices(params, &SharedExperts::new()).await;
             assert!(result.is_ok());
             
             let response = result.unwrap();
@@ -45,6 +45,14 @@ pub enum ArkaftMcpError {
     #[error("Operation timeout: {0}")]
     Timeout(String),
     
+    /// Persistent storage errors
+    #[error("Storage error: {0}")]
+    Storage(String),
+    
+    /// Scheduled knowledge update errors
+    #[error("Knowledge update error: {0}")]
+    KnowledgeUpdate(String),
+    
     /// IO errors
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
@@ -84,6 +92,16 @@ impl ArkaftMcpError {
         Self::Timeout(msg.into())
     }
     
+    /// Create a storage error
+    pub fn storage<S: Into<String>>(msg: S) -> Self {
+        Self::Storage(msg.into())
+    }
+    
+    /// Create a knowledge update error
+    pub fn knowledge_update<S: Into<String>>(msg: S) -> Self {
+        Self::KnowledgeUpdate(msg.into())
+    }
+    
     /// Check if error is recoverable
     pub fn is_recoverable(&self) -> bool {
         matches!(
@@ -92,7 +110,8 @@ impl ArkaftMcpError {
             Self::DocumentationQuery(_) | 
             Self::CodeReview(_) | 
             Self::BestPractices(_) |
-            Self::Timeout(_)
+            Self::Timeout(_) |
+            Self::KnowledgeUpdate(_)
         )
     }
     
@@ -101,7 +120,8 @@ impl ArkaftMcpError {
         match self {
             Self::McpProtocol(_) | Self::ServerInitialization(_) => ErrorSeverity::Critical,
             Self::Configuration(_) | Self::ResourceLimit(_) => ErrorSeverity::High,
-            Self::ToolExecution(_) | Self::Io(_) => ErrorSeverity::Medium,
+            Self::ToolExecution(_) | Self::Io(_) | Self::Storage(_) |
+            Self::KnowledgeUpdate(_) => ErrorSeverity::Medium,
             Self::ParameterValidation(_) | Self::DocumentationQuery(_) | 
             Self::CodeReview(_) | Self::BestPractices(_) | Self::Timeout(_) => ErrorSeverity::Low,
             Self::Json(_) | Self::Internal(_) => ErrorSeverity::Medium,
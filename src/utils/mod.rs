@@ -27,6 +27,20 @@ pub fn init_server_config() -> ServerConfig {
         adk_docs_version: get_env_or_default("ADK_DOCS_VERSION", "latest"),
         log_level: get_env_or_default("RUST_LOG", "info"),
         server_name: get_env_or_default("MCP_SERVER_NAME", "arkaft-google-adk"),
+        data_dir: std::env::var(crate::expert::knowledge_store::DATA_DIR_ENV).ok(),
+        update_feed_url: std::env::var(crate::expert::auto_update::UPDATE_FEED_ENV)
+            .ok()
+            .filter(|url| !url.trim().is_empty()),
+        knowledge_packs: std::env::var(crate::expert::knowledge_source::PACKS_PATH_ENV).ok(),
+        version_aliases_file: std::env::var(crate::expert::adk_knowledge::VERSION_ALIASES_FILE_ENV)
+            .ok()
+            .filter(|path| !path.trim().is_empty()),
+        upstream_servers_file: std::env::var(crate::expert::federation::UPSTREAM_SERVERS_FILE_ENV)
+            .ok()
+            .filter(|path| !path.trim().is_empty()),
+        rule_sets: std::env::var(crate::expert::rule_sets::RULE_SETS_PATH_ENV)
+            .ok()
+            .filter(|path| !path.trim().is_empty()),
     }
 }
 
@@ -39,6 +53,18 @@ pub struct ServerConfig {
     pub log_level: String,
     /// Server name
     pub server_name: String,
+    /// Data directory for persistent knowledge storage (in-memory only when unset)
+    pub data_dir: Option<String>,
+    /// JSON feed polled for documentation updates (scheduled updates are off when unset)
+    pub update_feed_url: Option<String>,
+    /// Knowledge pack file or directory merged over the bundled defaults
+    pub knowledge_packs: Option<String>,
+    /// JSON file of extra versions and alias overrides applied at startup
+    pub version_aliases_file: Option<String>,
+    /// JSON file of upstream MCP documentation servers queried by adk_query (federation is off when unset)
+    pub upstream_servers_file: Option<String>,
+    /// Rule-set file or directory layered over the built-in validation rules
+    pub rule_sets: Option<String>,
 }
 
 /// Metrics tracking for monitoring server performance
@@ -52,6 +78,10 @@ pub struct ServerMetrics {
     pub failed_tool_calls: std::sync::atomic::AtomicU64,
     /// Total response time in milliseconds
     pub total_response_time_ms: std::sync::atomic::AtomicU64,
+    /// Number of scheduled documentation refresh attempts
+    pub refresh_attempts: std::sync::atomic::AtomicU64,
+    /// Number of scheduled documentation refreshes that failed
+    pub refresh_failures: std::sync::atomic::AtomicU64,
     /// Server start time
     pub server_start_time: std::sync::OnceLock<std::time::Instant>,
 }
@@ -79,6 +109,20 @@ impl ServerMetrics {
         self.failed_tool_calls.fetch_add(1, Ordering::Relaxed);
     }
     
+    /// Record a scheduled documentation refresh attempt
+    pub fn record_refresh_attempt(&self) {
+        use std::sync::atomic::Ordering;
+        
+        self.refresh_attempts.fetch_add(1, Ordering::Relaxed);
+    }
+    
+    /// Record a failed scheduled documentation refresh
+    pub fn record_refresh_failure(&self) {
+        use std::sync::atomic::Ordering;
+        
+        self.refresh_failures.fetch_add(1, Ordering::Relaxed);
+    }
+    
     /// Get success rate as percentage
     pub fn success_rate(&self) -> f64 {
         use std::sync::atomic::Ordering;
@@ -130,6 +174,8 @@ impl ServerMetrics {
             success_rate: self.success_rate(),
             average_response_time_ms: self.average_response_time_ms(),
             uptime_seconds: self.uptime_seconds(),
+            refresh_attempts: self.refresh_attempts.load(Ordering::Relaxed),
+            refresh_failures: self.refresh_failures.load(Ordering::Relaxed),
         }
     }
 }
@@ -143,6 +189,8 @@ pub struct HealthSummary {
     pub success_rate: f64,
     pub average_response_time_ms: f64,
     pub uptime_seconds: u64,
+    pub refresh_attempts: u64,
+    pub refresh_failures: u64,
 }
 
 /// Log error with appropriate severity level